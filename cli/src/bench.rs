@@ -0,0 +1,214 @@
+//! The `bench` subcommand, measuring the time it takes to run a script.
+
+use std::{
+    path::PathBuf,
+    time::{Duration, Instant},
+};
+
+use boa_engine::{script::Script, Context, Source};
+use boa_profiler::Profiler;
+use boa_runtime::permissions::Permissions;
+use clap::{Args, ValueHint};
+use color_eyre::{eyre::WrapErr, Result};
+
+use crate::add_runtime;
+
+/// Runs a script several times, reporting the time spent parsing, compiling and executing it.
+#[derive(Debug, Args)]
+pub(crate) struct BenchArgs {
+    /// The JavaScript file to benchmark.
+    #[arg(value_hint = ValueHint::FilePath)]
+    file: PathBuf,
+
+    /// Number of measured runs.
+    #[arg(long, short = 'n', default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+    iterations: u32,
+
+    /// Number of runs before the measured ones, to warm up the caches.
+    #[arg(long, default_value_t = 3)]
+    warmup: u32,
+
+    /// Run in strict mode.
+    #[arg(long)]
+    strict: bool,
+}
+
+/// The time spent in each phase of a run.
+#[derive(Debug, Clone, Copy)]
+struct Sample {
+    parse: Duration,
+    compile: Duration,
+    execute: Duration,
+}
+
+/// Runs the benchmark described by `args`, printing the statistics of each phase to stdout.
+pub(crate) fn run(args: &BenchArgs) -> Result<()> {
+    let source = std::fs::read(&args.file)
+        .wrap_err_with(|| format!("could not read file `{}`", args.file.display()))?;
+
+    for _ in 0..args.warmup {
+        run_once(&source, args)?;
+    }
+
+    let samples = (0..args.iterations)
+        .map(|_| run_once(&source, args))
+        .collect::<Result<Vec<_>>>()?;
+
+    println!(
+        "{}: {} iterations, {} warmup",
+        args.file.display(),
+        args.iterations,
+        args.warmup
+    );
+    println!("{:<10}{:>14}{:>14}{:>14}", "phase", "mean", "median", "p95");
+    let phases: [(&str, fn(&Sample) -> Duration); 4] = [
+        ("parse", |s| s.parse),
+        ("compile", |s| s.compile),
+        ("execute", |s| s.execute),
+        ("total", |s| s.parse + s.compile + s.execute),
+    ];
+    for (name, phase) in phases {
+        let stats = Statistics::new(samples.iter().map(phase).collect());
+        println!(
+            "{name:<10}{:>14}{:>14}{:>14}",
+            format!("{:.3?}", stats.mean),
+            format!("{:.3?}", stats.median),
+            format!("{:.3?}", stats.p95)
+        );
+    }
+
+    // Write the trace of the recorded spans, if the profiler is enabled.
+    Profiler::global().drop();
+
+    Ok(())
+}
+
+/// Runs the script once in a new context, measuring each phase.
+fn run_once(source: &[u8], args: &BenchArgs) -> Result<Sample> {
+    let context = &mut Context::default();
+    context.strict(args.strict);
+    add_runtime(context, Permissions::new(), &[]);
+
+    let (script, parse) = span("Bench parsing", || {
+        Script::parse(
+            Source::from_bytes(source).with_path(&args.file),
+            None,
+            context,
+        )
+    });
+    let script = script.map_err(|e| e.into_erased(context))?;
+    let (result, compile) = span("Bench compilation", || script.codeblock(context));
+    result.map_err(|e| e.into_erased(context))?;
+    let (result, execute) = span("Bench execution", || {
+        script.evaluate(context)?;
+        context.run_jobs()
+    });
+    result.map_err(|e| e.into_erased(context))?;
+
+    Ok(Sample {
+        parse,
+        compile,
+        execute,
+    })
+}
+
+/// Runs `phase` inside a profiler span named `label`, returning its result and the duration of
+/// the span.
+///
+/// With the `profiler` feature, the spans are written to the trace together with the events of
+/// the engine, so a slow phase can be broken down further.
+fn span<T>(label: &str, phase: impl FnOnce() -> T) -> (T, Duration) {
+    let _timer = Profiler::global().start_event(label, "Bench");
+    let start = Instant::now();
+    let result = phase();
+    (result, start.elapsed())
+}
+
+/// The statistics of the durations of a phase.
+struct Statistics {
+    mean: Duration,
+    median: Duration,
+    p95: Duration,
+}
+
+impl Statistics {
+    fn new(mut durations: Vec<Duration>) -> Self {
+        durations.sort_unstable();
+        let len = durations.len();
+        let mean = durations.iter().sum::<Duration>()
+            / u32::try_from(len).expect("the number of iterations is an u32");
+        let median = if len % 2 == 0 {
+            (durations[len / 2 - 1] + durations[len / 2]) / 2
+        } else {
+            durations[len / 2]
+        };
+        // Nearest-rank percentile.
+        let p95 = durations[(len * 95).div_ceil(100) - 1];
+
+        Self { mean, median, p95 }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{run_once, BenchArgs, Statistics};
+    use std::{path::PathBuf, time::Duration};
+
+    fn args(strict: bool) -> BenchArgs {
+        BenchArgs {
+            file: PathBuf::from("bench.js"),
+            iterations: 1,
+            warmup: 0,
+            strict,
+        }
+    }
+
+    fn millis(durations: impl IntoIterator<Item = u64>) -> Vec<Duration> {
+        durations.into_iter().map(Duration::from_millis).collect()
+    }
+
+    #[test]
+    fn statistics_of_a_single_run() {
+        let stats = Statistics::new(millis([7]));
+        assert_eq!(stats.mean, Duration::from_millis(7));
+        assert_eq!(stats.median, Duration::from_millis(7));
+        assert_eq!(stats.p95, Duration::from_millis(7));
+    }
+
+    #[test]
+    fn statistics_sort_the_runs() {
+        let stats = Statistics::new(millis([9, 1, 5]));
+        assert_eq!(stats.mean, Duration::from_millis(5));
+        assert_eq!(stats.median, Duration::from_millis(5));
+        assert_eq!(stats.p95, Duration::from_millis(9));
+
+        let stats = Statistics::new(millis([4, 1, 3, 2]));
+        assert_eq!(stats.mean, Duration::from_micros(2500));
+        assert_eq!(stats.median, Duration::from_micros(2500));
+        assert_eq!(stats.p95, Duration::from_millis(4));
+    }
+
+    #[test]
+    fn statistics_use_the_nearest_rank_percentile() {
+        let stats = Statistics::new(millis((1..=20).rev()));
+        assert_eq!(stats.mean, Duration::from_micros(10500));
+        assert_eq!(stats.median, Duration::from_micros(10500));
+        assert_eq!(stats.p95, Duration::from_millis(19));
+    }
+
+    #[test]
+    fn run_measures_every_phase() {
+        let source =
+            b"let sum = 0; for (let i = 0; i < 100; i++) { sum += i; } Promise.resolve(sum);";
+        let sample = run_once(source, &args(false)).expect("the script should run");
+        assert!(sample.parse + sample.compile + sample.execute > Duration::ZERO);
+    }
+
+    #[test]
+    fn run_reports_errors() {
+        assert!(run_once(b"let x = ;", &args(false)).is_err());
+        assert!(run_once(b"throw new Error('failed');", &args(false)).is_err());
+        assert!(run_once(b"undeclared = 1;", &args(false)).is_ok());
+        assert!(run_once(b"undeclared = 1;", &args(true)).is_err());
+    }
+}
@@ -0,0 +1,147 @@
+//! Reporting of uncaught errors, with an excerpt of the source code that threw them.
+
+use boa_engine::{js_string, Context, JsError, JsString, JsValue};
+use boa_runtime::process::Process;
+use colored::Colorize;
+use std::{
+    borrow::Cow,
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// The number of lines shown before and after the line of an error.
+const CONTEXT_LINES: usize = 2;
+
+/// The maximum number of causes followed to find the location of an error.
+const MAX_CAUSE_DEPTH: usize = 16;
+
+/// The location in the source code where an error was thrown.
+struct Location {
+    path: Option<PathBuf>,
+    line: u32,
+    column: u32,
+}
+
+impl Location {
+    /// Gets the location of `error`, or the location of its innermost cause that has one.
+    ///
+    /// Errors thrown while running JavaScript code carry their location, while errors that were
+    /// caught and rethrown as values, like the rejections of modules, only keep it in the
+    /// `fileName`, `lineNumber` and `columnNumber` properties of the error object.
+    fn of(error: &JsError, context: &mut Context) -> Option<Self> {
+        let mut error = Cow::Borrowed(error);
+        for _ in 0..MAX_CAUSE_DEPTH {
+            if let Some(position) = error.position() {
+                return Some(Self {
+                    path: error.path().map(Path::to_path_buf),
+                    line: position.line_number(),
+                    column: position.column_number(),
+                });
+            }
+
+            let cause = if let Some(native) = error.as_native() {
+                native.cause().cloned()?
+            } else {
+                let object = error.as_opaque()?.as_object()?.clone();
+                let mut number = |key: JsString| {
+                    object
+                        .get(key, context)
+                        .ok()
+                        .and_then(|value| value.try_js_into::<u32>(context).ok())
+                };
+
+                let line = number(js_string!("lineNumber"));
+                let column = number(js_string!("columnNumber"));
+                if let (Some(line), Some(column)) = (line, column) {
+                    let path = object
+                        .get(js_string!("fileName"), context)
+                        .ok()
+                        .as_ref()
+                        .and_then(JsValue::as_string)
+                        .map(|path| PathBuf::from(path.to_std_string_escaped()));
+                    return Some(Self { path, line, column });
+                }
+
+                let cause = object.get(js_string!("cause"), context).ok()?;
+                if cause.is_undefined() {
+                    return None;
+                }
+                JsError::from_opaque(cause)
+            };
+            error = Cow::Owned(cause);
+        }
+
+        None
+    }
+}
+
+/// Prints an uncaught `error` to stderr, followed by an excerpt of the source code that threw it
+/// if it can be found.
+///
+/// The source code is read from the file where the error was thrown, or taken from `source` if
+/// the error was thrown by code that doesn't come from a file, like a line of the REPL.
+///
+/// Nothing is printed if the error was thrown by `process.exit()`.
+#[allow(clippy::redundant_pub_crate)]
+pub(crate) fn report_uncaught(error: &JsError, source: Option<&str>, context: &mut Context) {
+    if Process::has_exited(context) {
+        return;
+    }
+
+    eprintln!("{}: {}", "Uncaught".red().bold(), error.to_string().bold());
+
+    let Some(location) = Location::of(error, context) else {
+        return;
+    };
+
+    let (name, text) = match &location.path {
+        Some(path) => (
+            path.display().to_string(),
+            fs::read_to_string(path).ok().map(Cow::Owned),
+        ),
+        None => ("<anonymous>".to_owned(), source.map(Cow::Borrowed)),
+    };
+
+    eprintln!(
+        "{} {name}:{}:{}",
+        "-->".blue().bold(),
+        location.line,
+        location.column
+    );
+
+    if let Some(text) = text {
+        print_excerpt(&text, &location);
+    }
+}
+
+/// Prints the lines of `text` around `location`, with a caret under the column of the location.
+fn print_excerpt(text: &str, location: &Location) {
+    let lines: Vec<&str> = text.lines().collect();
+    let Some(index) = (location.line as usize)
+        .checked_sub(1)
+        .filter(|&i| i < lines.len())
+    else {
+        return;
+    };
+
+    let first = index.saturating_sub(CONTEXT_LINES);
+    let last = (index + CONTEXT_LINES).min(lines.len() - 1);
+    let width = (last + 1).to_string().len();
+    let gutter = |number: &str| format!("{number:>width$} |").blue().bold();
+
+    eprintln!("{}", gutter(""));
+    for (i, line) in lines.iter().enumerate().take(last + 1).skip(first) {
+        eprintln!("{} {line}", gutter(&(i + 1).to_string()));
+
+        if i == index {
+            // Keep the tabs of the line, so the caret is aligned in any tab width.
+            let indent: String = line
+                .chars()
+                .take((location.column as usize).saturating_sub(1))
+                .map(|c| if c == '\t' { '\t' } else { ' ' })
+                .collect();
+            eprintln!("{} {indent}{}", gutter(""), "^".red().bold());
+        }
+    }
+    eprintln!("{}", gutter(""));
+}
@@ -7,27 +7,35 @@
 #![cfg_attr(not(test), deny(clippy::unwrap_used))]
 #![allow(clippy::print_stdout, clippy::print_stderr)]
 
+mod bench;
 mod debug;
+mod diagnostic;
 mod helper;
 
 use boa_engine::{
     builtins::promise::PromiseState,
     context::ContextBuilder,
     job::{Job, JobExecutor, NativeAsyncJob, PromiseJob},
-    module::{Module, SimpleModuleLoader},
+    module::{Module, ModuleLoader, Referrer, SimpleModuleLoader},
     optimizer::OptimizerOptions,
     script::Script,
     vm::flowgraph::{Direction, Graph},
-    Context, JsError, JsResult, Source,
+    Context, JsError, JsObject, JsResult, JsString, Source,
 };
 use boa_parser::source::ReadChar;
-use clap::{Parser, ValueEnum, ValueHint};
+use boa_runtime::{
+    extensions::{CommandExtension, FsExtension, ProcessExtension},
+    loader::NodeModuleLoader,
+    permissions::{Allow, Permissions},
+    process::{DefaultEnvironment, Process},
+};
+use clap::{Parser, Subcommand, ValueEnum, ValueHint};
 use color_eyre::{
     eyre::{eyre, WrapErr},
     Result, Section,
 };
-use colored::Colorize;
 use debug::init_boa_debug_object;
+use diagnostic::report_uncaught;
 use rustyline::{config::Config, error::ReadlineError, EditMode, Editor};
 use std::{
     cell::RefCell,
@@ -37,6 +45,7 @@ use std::{
     io,
     path::{Path, PathBuf},
     println,
+    process::ExitCode,
     rc::Rc,
 };
 
@@ -68,9 +77,18 @@ static CLI_HISTORY: &str = ".boa_history";
 // is an optional argument that optionally takes a value ([--opt=[val]]).
 // https://docs.rs/structopt/0.3.11/structopt/#type-magic
 #[derive(Debug, Parser)]
-#[command(author, version, about, name = "boa")]
+#[command(
+    author,
+    version,
+    about,
+    name = "boa",
+    args_conflicts_with_subcommands = true
+)]
 #[allow(clippy::struct_excessive_bools)] // NOTE: Allow having more than 3 bools in struct
 struct Opt {
+    #[command(subcommand)]
+    command: Option<Command>,
+
     /// The JavaScript file(s) to be evaluated.
     #[arg(name = "FILE", value_hint = ValueHint::FilePath)]
     files: Vec<PathBuf>,
@@ -137,6 +155,80 @@ struct Opt {
     /// Root path from where the module resolver will try to load the modules.
     #[arg(long, short = 'r', default_value_os_t = PathBuf::from("."), requires = "mod")]
     root: PathBuf,
+
+    /// Resolve the imports of modules like Node.js, supporting packages in `node_modules`.
+    #[arg(long, requires = "mod")]
+    node_resolution: bool,
+
+    /// Print the dependency graph of the modules after evaluating them.
+    #[arg(long, requires = "mod")]
+    print_module_graph: bool,
+
+    /// Allow the scripts to read the given paths, or any path if none are given.
+    #[arg(
+        long,
+        value_name = "PATH",
+        num_args = 0..,
+        value_delimiter = ',',
+        require_equals = true
+    )]
+    allow_read: Option<Vec<PathBuf>>,
+
+    /// Allow the scripts to write the given paths, or any path if none are given.
+    #[arg(
+        long,
+        value_name = "PATH",
+        num_args = 0..,
+        value_delimiter = ',',
+        require_equals = true
+    )]
+    allow_write: Option<Vec<PathBuf>>,
+
+    /// Allow the scripts to connect to the given hosts, or any host if none are given.
+    #[arg(
+        long,
+        value_name = "HOST",
+        num_args = 0..,
+        value_delimiter = ',',
+        require_equals = true
+    )]
+    allow_net: Option<Vec<String>>,
+
+    /// Allow the scripts to access the given environment variables, or all of them if none are
+    /// given.
+    #[arg(
+        long,
+        value_name = "NAME",
+        num_args = 0..,
+        value_delimiter = ',',
+        require_equals = true
+    )]
+    allow_env: Option<Vec<String>>,
+
+    /// Allow the scripts to run the given programs, or any program if none are given.
+    #[arg(
+        long,
+        value_name = "PROGRAM",
+        num_args = 0..,
+        value_delimiter = ',',
+        require_equals = true
+    )]
+    allow_run: Option<Vec<String>>,
+
+    /// Allow the scripts to access the whole system.
+    #[arg(long, short = 'A')]
+    allow_all: bool,
+
+    /// The arguments of the scripts, given after `--` and available in `process.argv`.
+    #[arg(name = "ARGS", last = true)]
+    script_args: Vec<String>,
+}
+
+/// The subcommands of the CLI.
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Run a script several times, reporting the time spent parsing, compiling and executing it.
+    Bench(bench::BenchArgs),
 }
 
 impl Opt {
@@ -144,6 +236,30 @@ impl Opt {
     const fn has_dump_flag(&self) -> bool {
         self.dump_ast.is_some()
     }
+
+    /// Returns the permissions granted with the `--allow-*` flags, which deny all the accesses to
+    /// the system by default.
+    fn permissions(&self) -> Permissions {
+        if self.allow_all {
+            return Permissions::all();
+        }
+
+        Permissions::new()
+            .with_read(allow(self.allow_read.as_deref()))
+            .with_write(allow(self.allow_write.as_deref()))
+            .with_net(allow(self.allow_net.as_deref()))
+            .with_env(allow(self.allow_env.as_deref()))
+            .with_run(allow(self.allow_run.as_deref()))
+    }
+}
+
+/// Converts the values of an `--allow-*` flag to the resources it allows.
+fn allow<T: Clone>(values: Option<&[T]>) -> Allow<T> {
+    match values {
+        None => Allow::None,
+        Some([]) => Allow::All,
+        Some(values) => Allow::Only(values.to_vec()),
+    }
 }
 
 /// The different types of format available for dumping.
@@ -260,7 +376,7 @@ fn evaluate_file(
     file: &Path,
     args: &Opt,
     context: &mut Context,
-    loader: &SimpleModuleLoader,
+    loader: &CliModuleLoader,
 ) -> Result<()> {
     if args.has_dump_flag() {
         return dump(Source::from_filepath(file)?, args, context);
@@ -280,50 +396,65 @@ fn evaluate_file(
     }
 
     if args.module {
-        let module = Module::parse(Source::from_filepath(file)?, None, context)
-            .map_err(|e| e.into_erased(context))?;
+        let module = match Module::parse(Source::from_filepath(file)?, None, context) {
+            Ok(module) => module,
+            Err(err) => {
+                report_uncaught(&err, None, context);
+                return Ok(());
+            }
+        };
 
-        loader.insert(
-            file.canonicalize()
-                .wrap_err("could not canonicalize input file path")?,
+        let path = file
+            .canonicalize()
+            .wrap_err("could not canonicalize input file path")?;
+        loader.register_module(
+            JsString::from(path.to_string_lossy().as_ref()),
             module.clone(),
         );
 
         let promise = module.load_link_evaluate(context);
         context.run_jobs().map_err(|err| err.into_erased(context))?;
+        if args.print_module_graph {
+            println!("{}", module.graph());
+        }
         let result = promise.state();
 
         return match result {
             PromiseState::Pending => Err(eyre!("module didn't execute")),
             PromiseState::Fulfilled(_) => Ok(()),
             PromiseState::Rejected(err) => {
-                return Err(JsError::from_opaque(err).into_erased(context).into())
+                report_uncaught(&JsError::from_opaque(err), None, context);
+                Ok(())
             }
         };
     }
 
     match context.eval(Source::from_filepath(file)?) {
         Ok(v) => println!("{}", v.display()),
-        Err(v) => eprintln!("Uncaught {v}"),
+        Err(v) => report_uncaught(&v, None, context),
+    }
+    if Process::has_exited(context) {
+        return Ok(());
     }
     context
         .run_jobs()
         .map_err(|err| err.into_erased(context).into())
 }
 
-fn evaluate_files(args: &Opt, context: &mut Context, loader: &SimpleModuleLoader) {
+fn evaluate_files(args: &Opt, context: &mut Context, loader: &CliModuleLoader) {
     for file in &args.files {
-        let Err(err) = evaluate_file(file, args, context, loader)
-            .wrap_err_with(|| eyre!("could not evaluate file `{}`", file.display()))
-        else {
-            continue;
-        };
-
-        eprintln!("{err:?}");
+        let result = evaluate_file(file, args, context, loader)
+            .wrap_err_with(|| eyre!("could not evaluate file `{}`", file.display()));
+        if Process::has_exited(context) {
+            break;
+        }
+        if let Err(err) = result {
+            eprintln!("{err:?}");
+        }
     }
 }
 
-fn main() -> Result<()> {
+fn main() -> Result<ExitCode> {
     color_eyre::config::HookBuilder::default()
         .display_location_section(false)
         .display_env_section(false)
@@ -334,11 +465,23 @@ fn main() -> Result<()> {
 
     let args = Opt::parse();
 
+    if let Some(Command::Bench(bench)) = &args.command {
+        return bench::run(bench).map(|()| ExitCode::SUCCESS);
+    }
+
     let executor = Rc::new(Executor::default());
-    let loader = Rc::new(SimpleModuleLoader::new(&args.root).map_err(|e| eyre!(e.to_string()))?);
+    let loader = if args.node_resolution {
+        CliModuleLoader::Node(NodeModuleLoader::new(&args.root).map_err(|e| eyre!(e.to_string()))?)
+    } else {
+        CliModuleLoader::Simple(
+            SimpleModuleLoader::new(&args.root).map_err(|e| eyre!(e.to_string()))?,
+        )
+    };
+    let loader = Rc::new(loader);
     let mut context = ContextBuilder::new()
         .job_executor(executor)
         .module_loader(loader.clone())
+        .error_positions(true)
         .build()
         .map_err(|e| eyre!(e.to_string()))?;
 
@@ -346,7 +489,7 @@ fn main() -> Result<()> {
     context.strict(args.strict);
 
     // Add `console`.
-    add_runtime(&mut context);
+    add_runtime(&mut context, args.permissions(), &args.script_args);
 
     // Trace Output
     context.set_trace(args.trace);
@@ -363,7 +506,7 @@ fn main() -> Result<()> {
 
     if !args.files.is_empty() {
         evaluate_files(&args, &mut context, &loader);
-        return Ok(());
+        return Ok(exit_code(&context));
     }
 
     let config = Config::builder()
@@ -417,11 +560,15 @@ fn main() -> Result<()> {
                         Ok(v) => {
                             println!("{}", v.display());
                         }
-                        Err(v) => {
-                            eprintln!("{}: {}", "Uncaught".red(), v.to_string().red());
-                        }
+                        Err(v) => report_uncaught(&v, Some(&line), &mut context),
+                    }
+                    if Process::has_exited(&context) {
+                        break;
                     }
                     if let Err(err) = context.run_jobs() {
+                        if Process::has_exited(&context) {
+                            break;
+                        }
                         eprintln!("{err}");
                     }
                 }
@@ -441,13 +588,75 @@ fn main() -> Result<()> {
 
     editor.save_history(CLI_HISTORY)?;
 
-    Ok(())
+    Ok(exit_code(&context))
+}
+
+/// Returns the exit code set by the scripts with `process.exitCode` or `process.exit(code)`.
+fn exit_code(context: &Context) -> ExitCode {
+    // Like on Unix, only the lowest 8 bits of the code are kept.
+    Process::exit_code(context).map_or(ExitCode::SUCCESS, |code| {
+        ExitCode::from(u8::try_from(code & 0xFF).unwrap_or(u8::MAX))
+    })
+}
+
+/// Adds the CLI runtime to the context with default options, the given `permissions`, and the
+/// arguments of the scripts.
+fn add_runtime(context: &mut Context, permissions: Permissions, script_args: &[String]) {
+    let options = boa_runtime::RegisterOptions::new().with_permissions(permissions);
+    boa_runtime::register(context, options).expect("should not fail while registering the runtime");
+    context
+        .register_extension(FsExtension::default())
+        .expect("should not fail while registering the `fs` object");
+    context
+        .register_extension(CommandExtension)
+        .expect("should not fail while registering the `Command` class");
+
+    let args = std::env::args().take(1).chain(script_args.iter().cloned());
+    context
+        .register_extension(ProcessExtension::with_environment(
+            DefaultEnvironment::with_args(args),
+        ))
+        .expect("should not fail while registering the `process` object");
+}
+
+/// The module loader selected with the command line options.
+enum CliModuleLoader {
+    Simple(SimpleModuleLoader),
+    Node(NodeModuleLoader),
+}
+
+impl CliModuleLoader {
+    fn inner(&self) -> &dyn ModuleLoader {
+        match self {
+            Self::Simple(loader) => loader,
+            Self::Node(loader) => loader,
+        }
+    }
 }
 
-/// Adds the CLI runtime to the context with default options.
-fn add_runtime(context: &mut Context) {
-    boa_runtime::register(context, boa_runtime::RegisterOptions::new())
-        .expect("should not fail while registering the runtime");
+impl ModuleLoader for CliModuleLoader {
+    fn load_imported_module(
+        &self,
+        referrer: Referrer,
+        specifier: JsString,
+        finish_load: Box<dyn FnOnce(JsResult<Module>, &mut Context)>,
+        context: &mut Context,
+    ) {
+        self.inner()
+            .load_imported_module(referrer, specifier, finish_load, context);
+    }
+
+    fn register_module(&self, specifier: JsString, module: Module) {
+        self.inner().register_module(specifier, module);
+    }
+
+    fn get_module(&self, specifier: JsString) -> Option<Module> {
+        self.inner().get_module(specifier)
+    }
+
+    fn init_import_meta(&self, import_meta: &JsObject, module: &Module, context: &mut Context) {
+        self.inner().init_import_meta(import_meta, module, context);
+    }
 }
 
 #[derive(Default)]
@@ -474,22 +683,86 @@ impl JobExecutor for Executor {
             let jobs = std::mem::take(&mut *self.promise_jobs.borrow_mut());
             for job in jobs {
                 if let Err(e) = job.call(context) {
-                    eprintln!("Uncaught {e}");
+                    report_uncaught(&e, None, context);
+                }
+                if self.stop_if_exited(context) {
+                    return Ok(());
                 }
             }
 
             let async_jobs = std::mem::take(&mut *self.async_jobs.borrow_mut());
             for async_job in async_jobs {
-                if let Err(err) = pollster::block_on(async_job.call(&RefCell::new(context))) {
-                    eprintln!("Uncaught {err}");
+                let result = pollster::block_on(async_job.call(&RefCell::new(context)));
+                if let Err(err) = result {
+                    report_uncaught(&err, None, context);
+                }
+                if self.stop_if_exited(context) {
+                    return Ok(());
                 }
                 let jobs = std::mem::take(&mut *self.promise_jobs.borrow_mut());
                 for job in jobs {
                     if let Err(e) = job.call(context) {
-                        eprintln!("Uncaught {e}");
+                        report_uncaught(&e, None, context);
+                    }
+                    if self.stop_if_exited(context) {
+                        return Ok(());
                     }
                 }
             }
         }
     }
 }
+
+impl Executor {
+    /// Drops all the pending jobs if a script called `process.exit()`, returning `true` if it
+    /// did.
+    fn stop_if_exited(&self, context: &Context) -> bool {
+        if !Process::has_exited(context) {
+            return false;
+        }
+
+        self.promise_jobs.borrow_mut().clear();
+        self.async_jobs.borrow_mut().clear();
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{add_runtime, Executor};
+    use boa_engine::{context::ContextBuilder, js_string, JsValue, Source};
+    use boa_runtime::{permissions::Permissions, process::Process};
+    use std::rc::Rc;
+
+    #[test]
+    fn jobs_stop_after_exit() {
+        let executor = Rc::new(Executor::default());
+        let context = &mut ContextBuilder::new()
+            .job_executor(executor.clone())
+            .build()
+            .expect("the context should be built");
+        add_runtime(context, Permissions::new(), &[]);
+
+        context
+            .eval(Source::from_bytes(
+                r#"
+                var reached = false;
+                Promise.resolve().then(() => process.exit(3)).then(() => { reached = true; });
+                Promise.resolve().then(() => { reached = true; });
+                "#,
+            ))
+            .expect("the script should not throw");
+        context.run_jobs().expect("the jobs should not throw");
+
+        assert!(Process::has_exited(context));
+        assert_eq!(Process::exit_code(context), Some(3));
+        assert!(executor.promise_jobs.borrow().is_empty());
+        assert_eq!(
+            context
+                .global_object()
+                .get(js_string!("reached"), context)
+                .expect("the global variable should exist"),
+            JsValue::from(false)
+        );
+    }
+}
@@ -15,6 +15,7 @@ use crate::{
         AsyncFunctionDeclaration, AsyncGeneratorDeclaration, ClassDeclaration, FunctionDeclaration,
         GeneratorDeclaration,
     },
+    node_id::NodeIdSlot,
     visitor::{VisitWith, Visitor, VisitorMut},
     Declaration, Expression,
 };
@@ -183,6 +184,7 @@ pub struct ExportSpecifier {
     alias: Sym,
     private_name: Sym,
     string_literal: bool,
+    pub(crate) id: NodeIdSlot,
 }
 
 impl ExportSpecifier {
@@ -194,6 +196,7 @@ impl ExportSpecifier {
             alias,
             private_name,
             string_literal,
+            id: NodeIdSlot::EMPTY,
         }
     }
 
@@ -13,6 +13,7 @@ use std::ops::ControlFlow;
 
 use crate::{
     expression::Identifier,
+    node_id::NodeIdSlot,
     visitor::{VisitWith, Visitor, VisitorMut},
 };
 use boa_interner::Sym;
@@ -36,6 +37,24 @@ pub enum ImportKind {
         /// List of the required exports of the imported module.
         names: Box<[ImportSpecifier]>,
     },
+    /// Source phase import (`import source name from "module-name"`).
+    ///
+    /// This is part of the [source phase imports proposal][proposal].
+    ///
+    /// [proposal]: https://github.com/tc39/proposal-source-phase-imports
+    Source {
+        /// Binding for the source object of the imported module.
+        binding: Identifier,
+    },
+    /// Deferred namespace import (`import defer * as name from "module-name"`).
+    ///
+    /// This is part of the [deferred module evaluation proposal][proposal].
+    ///
+    /// [proposal]: https://github.com/tc39/proposal-defer-import-eval
+    Deferred {
+        /// Binding for the deferred namespace created from the exports of the imported module.
+        binding: Identifier,
+    },
 }
 
 impl VisitWith for ImportKind {
@@ -45,7 +64,9 @@ impl VisitWith for ImportKind {
     {
         match self {
             Self::DefaultOrUnnamed => ControlFlow::Continue(()),
-            Self::Namespaced { binding } => visitor.visit_identifier(binding),
+            Self::Namespaced { binding }
+            | Self::Source { binding }
+            | Self::Deferred { binding } => visitor.visit_identifier(binding),
             Self::Named { names } => {
                 for name in &**names {
                     visitor.visit_import_specifier(name)?;
@@ -61,7 +82,9 @@ impl VisitWith for ImportKind {
     {
         match self {
             Self::DefaultOrUnnamed => ControlFlow::Continue(()),
-            Self::Namespaced { binding } => visitor.visit_identifier_mut(binding),
+            Self::Namespaced { binding }
+            | Self::Source { binding }
+            | Self::Deferred { binding } => visitor.visit_identifier_mut(binding),
             Self::Named { names } => {
                 for name in &mut **names {
                     visitor.visit_import_specifier_mut(name)?;
@@ -87,6 +110,7 @@ pub struct ImportDeclaration {
     kind: ImportKind,
     /// Module specifier.
     specifier: ModuleSpecifier,
+    pub(crate) id: NodeIdSlot,
 }
 
 impl ImportDeclaration {
@@ -102,6 +126,7 @@ impl ImportDeclaration {
             default,
             kind,
             specifier,
+            id: NodeIdSlot::EMPTY,
         }
     }
 
@@ -163,6 +188,7 @@ impl VisitWith for ImportDeclaration {
 pub struct ImportSpecifier {
     binding: Identifier,
     export_name: Sym,
+    pub(crate) id: NodeIdSlot,
 }
 
 impl ImportSpecifier {
@@ -173,6 +199,7 @@ impl ImportSpecifier {
         Self {
             binding,
             export_name,
+            id: NodeIdSlot::EMPTY,
         }
     }
 
@@ -216,6 +243,10 @@ pub enum ImportName {
     Namespace,
     /// A binding of the imported module.
     Name(Sym),
+    /// The source object of the imported module, for source phase imports.
+    Source,
+    /// The deferred namespace object of the imported module, for deferred imports.
+    DeferredNamespace,
 }
 
 /// [`ImportEntry`][spec] record.
@@ -19,6 +19,7 @@ use super::function::{
 };
 use crate::{
     function::ClassDeclaration,
+    node_id::NodeIdSlot,
     visitor::{VisitWith, Visitor, VisitorMut},
 };
 use boa_interner::{Interner, Sym, ToIndentedString, ToInternedString};
@@ -117,13 +118,17 @@ impl VisitWith for Declaration {
 #[derive(Clone, Debug, Copy, PartialEq, Eq)]
 pub struct ModuleSpecifier {
     module: Sym,
+    pub(crate) id: NodeIdSlot,
 }
 
 impl ModuleSpecifier {
     /// Creates a `ModuleSpecifier` from a `Sym`.
     #[must_use]
     pub const fn new(module: Sym) -> Self {
-        Self { module }
+        Self {
+            module,
+            id: NodeIdSlot::EMPTY,
+        }
     }
 
     /// Gets the inner `Sym` of the module specifier.
@@ -4,6 +4,7 @@ use super::Declaration;
 use crate::{
     expression::{Expression, Identifier},
     join_nodes,
+    node_id::NodeIdSlot,
     pattern::Pattern,
     visitor::{VisitWith, Visitor, VisitorMut},
     Statement,
@@ -164,6 +165,7 @@ impl VisitWith for LexicalDeclaration {
 #[derive(Clone, Debug, PartialEq)]
 pub struct VariableList {
     list: Box<[Variable]>,
+    pub(crate) id: NodeIdSlot,
 }
 
 impl VariableList {
@@ -174,7 +176,10 @@ impl VariableList {
             return None;
         }
 
-        Some(Self { list })
+        Some(Self {
+            list,
+            id: NodeIdSlot::EMPTY,
+        })
     }
 }
 
@@ -256,6 +261,7 @@ impl TryFrom<Vec<Variable>> for VariableList {
 pub struct Variable {
     binding: Binding,
     init: Option<Expression>,
+    pub(crate) id: NodeIdSlot,
 }
 
 impl ToInternedString for Variable {
@@ -277,6 +283,7 @@ impl Variable {
         Self {
             binding: Binding::Identifier(ident),
             init,
+            id: NodeIdSlot::EMPTY,
         }
     }
 
@@ -287,6 +294,7 @@ impl Variable {
         Self {
             binding: Binding::Pattern(pattern),
             init,
+            id: NodeIdSlot::EMPTY,
         }
     }
     /// Gets the variable declaration binding.
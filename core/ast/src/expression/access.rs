@@ -14,10 +14,10 @@
 //! [spec]: https://tc39.es/ecma262/multipage/ecmascript-language-expressions.html#sec-property-accessors
 //! [access]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Property_Accessors
 
-use crate::expression::Expression;
 use crate::function::PrivateName;
 use crate::visitor::{VisitWith, Visitor, VisitorMut};
 use crate::Span;
+use crate::{expression::Expression, node_id::NodeIdSlot};
 use boa_interner::{Interner, ToInternedString};
 use core::ops::ControlFlow;
 
@@ -161,6 +161,7 @@ impl VisitWith for PropertyAccess {
 pub struct SimplePropertyAccess {
     target: Box<Expression>,
     field: PropertyAccessField,
+    pub(crate) id: NodeIdSlot,
 }
 
 impl SimplePropertyAccess {
@@ -186,6 +187,7 @@ impl SimplePropertyAccess {
         Self {
             target: target.into(),
             field: field.into(),
+            id: NodeIdSlot::EMPTY,
         }
     }
 
@@ -254,6 +256,7 @@ pub struct PrivatePropertyAccess {
     target: Box<Expression>,
     field: PrivateName,
     span: Span,
+    pub(crate) id: NodeIdSlot,
 }
 
 impl PrivatePropertyAccess {
@@ -265,6 +268,7 @@ impl PrivatePropertyAccess {
             target: value.into(),
             field,
             span,
+            id: NodeIdSlot::EMPTY,
         }
     }
 
@@ -339,13 +343,18 @@ impl VisitWith for PrivatePropertyAccess {
 pub struct SuperPropertyAccess {
     field: PropertyAccessField,
     span: Span,
+    pub(crate) id: NodeIdSlot,
 }
 
 impl SuperPropertyAccess {
     /// Creates a new property access field node.
     #[must_use]
     pub const fn new(field: PropertyAccessField, span: Span) -> Self {
-        Self { field, span }
+        Self {
+            field,
+            span,
+            id: NodeIdSlot::EMPTY,
+        }
     }
 
     /// Gets the name of the field to retrieve.
@@ -4,6 +4,7 @@ use core::ops::ControlFlow;
 
 use super::Expression;
 use crate::{
+    node_id::NodeIdSlot,
     visitor::{VisitWith, Visitor, VisitorMut},
     Span,
 };
@@ -24,13 +25,18 @@ use boa_interner::{Interner, ToIndentedString, ToInternedString};
 pub struct Await {
     target: Box<Expression>,
     span: Span,
+    pub(crate) id: NodeIdSlot,
 }
 
 impl Await {
     /// Create a new [`Await`] node.
     #[must_use]
     pub const fn new(target: Box<Expression>, span: Span) -> Self {
-        Self { target, span }
+        Self {
+            target,
+            span,
+            id: NodeIdSlot::EMPTY,
+        }
     }
 
     /// Return the target expression that should be awaited.
@@ -1,5 +1,5 @@
 use crate::visitor::{VisitWith, Visitor, VisitorMut};
-use crate::{join_nodes, Span};
+use crate::{join_nodes, node_id::NodeIdSlot, Span};
 use boa_interner::{Interner, ToInternedString};
 use core::ops::ControlFlow;
 
@@ -26,6 +26,7 @@ pub struct Call {
     function: Box<Expression>,
     args: Box<[Expression]>,
     span: Span,
+    pub(crate) id: NodeIdSlot,
 }
 
 impl Call {
@@ -37,6 +38,7 @@ impl Call {
             function: Box::new(function),
             args,
             span,
+            id: NodeIdSlot::EMPTY,
         }
     }
 
@@ -118,6 +120,7 @@ impl VisitWith for Call {
 pub struct SuperCall {
     args: Box<[Expression]>,
     span: Span,
+    pub(crate) id: NodeIdSlot,
 }
 
 impl SuperCall {
@@ -129,6 +132,7 @@ impl SuperCall {
         Self {
             args: args.into(),
             span,
+            id: NodeIdSlot::EMPTY,
         }
     }
 
@@ -198,6 +202,7 @@ impl VisitWith for SuperCall {
 pub struct ImportCall {
     arg: Box<Expression>,
     span: Span,
+    pub(crate) id: NodeIdSlot,
 }
 
 impl ImportCall {
@@ -209,6 +214,7 @@ impl ImportCall {
         Self {
             arg: Box::new(arg.into()),
             span,
+            id: NodeIdSlot::EMPTY,
         }
     }
 
@@ -1,6 +1,7 @@
 //! Local identifier Expression.
 
 use crate::{
+    node_id::NodeIdSlot,
     visitor::{VisitWith, Visitor, VisitorMut},
     Span, ToStringEscaped,
 };
@@ -44,6 +45,7 @@ pub const RESERVED_IDENTIFIERS_STRICT: [Sym; 9] = [
 pub struct Identifier {
     ident: Sym,
     span: Span,
+    pub(crate) id: NodeIdSlot,
 }
 
 impl PartialEq<Sym> for Identifier {
@@ -65,7 +67,11 @@ impl Identifier {
     #[inline]
     #[must_use]
     pub const fn new(ident: Sym, span: Span) -> Self {
-        Self { ident, span }
+        Self {
+            ident,
+            span,
+            id: NodeIdSlot::EMPTY,
+        }
     }
 
     /// Retrieves the identifier's string symbol in the interner.
@@ -1,6 +1,7 @@
 //! `import.meta` ECMAScript expression.
 
 use crate::{
+    node_id::NodeIdSlot,
     visitor::{VisitWith, Visitor, VisitorMut},
     Span,
 };
@@ -15,6 +16,7 @@ use super::Expression;
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct ImportMeta {
     span: Span,
+    pub(crate) id: NodeIdSlot,
 }
 
 impl ImportMeta {
@@ -22,7 +24,10 @@ impl ImportMeta {
     #[inline]
     #[must_use]
     pub const fn new(span: Span) -> Self {
-        Self { span }
+        Self {
+            span,
+            id: NodeIdSlot::EMPTY,
+        }
     }
 
     /// Get the [`Span`] of the [`ImportMeta`] node.
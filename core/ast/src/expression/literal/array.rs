@@ -1,10 +1,10 @@
 //! Array declaration Expression.
 
 use crate::expression::operator::assign::{AssignOp, AssignTarget};
-use crate::expression::Expression;
 use crate::pattern::{ArrayPattern, ArrayPatternElement, Pattern};
 use crate::visitor::{VisitWith, Visitor, VisitorMut};
 use crate::Span;
+use crate::{expression::Expression, node_id::NodeIdSlot};
 use boa_interner::{Interner, Sym, ToInternedString};
 use core::ops::ControlFlow;
 
@@ -31,6 +31,7 @@ pub struct ArrayLiteral {
     arr: Box<[Option<Expression>]>,
     has_trailing_comma_spread: bool,
     span: Span,
+    pub(crate) id: NodeIdSlot,
 }
 
 impl ArrayLiteral {
@@ -43,6 +44,7 @@ impl ArrayLiteral {
             arr: array.into(),
             has_trailing_comma_spread,
             span,
+            id: NodeIdSlot::EMPTY,
         }
     }
 
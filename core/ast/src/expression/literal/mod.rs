@@ -14,9 +14,10 @@ mod template;
 pub use array::ArrayLiteral;
 use core::ops::ControlFlow;
 pub use object::{ObjectLiteral, ObjectMethodDefinition, PropertyDefinition};
-pub use template::{TemplateElement, TemplateLiteral};
+pub use template::{TemplateElement, TemplateLiteral, TemplateString};
 
 use crate::{
+    node_id::NodeIdSlot,
     visitor::{VisitWith, Visitor, VisitorMut},
     Span,
 };
@@ -41,6 +42,7 @@ use super::Expression;
 pub struct Literal {
     kind: LiteralKind,
     span: Span,
+    pub(crate) id: NodeIdSlot,
 }
 
 impl Literal {
@@ -51,6 +53,7 @@ impl Literal {
         Self {
             kind: kind.into(),
             span,
+            id: NodeIdSlot::EMPTY,
         }
     }
 
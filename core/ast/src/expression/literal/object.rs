@@ -8,6 +8,7 @@ use crate::{
     },
     function::{FormalParameterList, FunctionBody},
     join_nodes,
+    node_id::NodeIdSlot,
     operations::{contains, ContainsSymbol},
     pattern::{ObjectPattern, ObjectPatternElement},
     property::{MethodDefinitionKind, PropertyName},
@@ -43,6 +44,7 @@ use core::{fmt::Write as _, ops::ControlFlow};
 pub struct ObjectLiteral {
     properties: Box<[PropertyDefinition]>,
     span: Span,
+    pub(crate) id: NodeIdSlot,
 }
 
 impl ObjectLiteral {
@@ -56,6 +58,7 @@ impl ObjectLiteral {
         Self {
             properties: properties.into(),
             span,
+            id: NodeIdSlot::EMPTY,
         }
     }
 
@@ -93,7 +96,7 @@ impl ObjectLiteral {
                         default_init: None,
                     });
                 }
-                PropertyDefinition::Property(name, expr) => match (name, expr) {
+                PropertyDefinition::Property(name, expr, _) => match (name, expr) {
                     (PropertyName::Literal(name), Expression::Identifier(ident))
                         if name.sym() == ident.sym() =>
                     {
@@ -193,7 +196,7 @@ impl ObjectLiteral {
                     }
                     _ => return None,
                 },
-                PropertyDefinition::SpreadObject(spread) => {
+                PropertyDefinition::SpreadObject(spread, _) => {
                     match spread {
                         Expression::Identifier(ident) => {
                             bindings.push(ObjectPatternElement::RestProperty { ident: *ident });
@@ -210,7 +213,7 @@ impl ObjectLiteral {
                     }
                 }
                 PropertyDefinition::MethodDefinition(_) => return None,
-                PropertyDefinition::CoverInitializedName(ident, expr) => {
+                PropertyDefinition::CoverInitializedName(ident, expr, _) => {
                     if strict && [Sym::EVAL, Sym::ARGUMENTS].contains(&ident.sym()) {
                         return None;
                     }
@@ -242,7 +245,7 @@ impl ToIndentedString for ObjectLiteral {
                         interner.resolve_expect(ident.sym())
                     );
                 }
-                PropertyDefinition::Property(key, value) => {
+                PropertyDefinition::Property(key, value, _) => {
                     let _ = writeln!(
                         buf,
                         "{indentation}{}: {},",
@@ -250,13 +253,13 @@ impl ToIndentedString for ObjectLiteral {
                         value.to_no_indent_string(interner, indent_n + 1)
                     );
                 }
-                PropertyDefinition::SpreadObject(key) => {
+                PropertyDefinition::SpreadObject(key, _) => {
                     let _ = writeln!(buf, "{indentation}...{},", key.to_interned_string(interner));
                 }
                 PropertyDefinition::MethodDefinition(m) => {
                     buf.push_str(&m.to_indented_string(interner, indent_n));
                 }
-                PropertyDefinition::CoverInitializedName(ident, expr) => {
+                PropertyDefinition::CoverInitializedName(ident, expr, _) => {
                     let _ = writeln!(
                         buf,
                         "{indentation}{} = {},",
@@ -307,6 +310,9 @@ impl VisitWith for ObjectLiteral {
 /// Note that when we say that "a property holds an object", that is shorthand for "a property holds an object reference".
 /// This distinction matters because the original referenced object remains unchanged when you change the property's value.
 ///
+/// The variants that don't wrap another node have their own [`NodeId`][crate::node_id::NodeId]
+/// slot, while the others are identified by the node they wrap.
+///
 /// More information:
 ///  - [ECMAScript reference][spec]
 ///  - [MDN documentation][mdn]
@@ -336,7 +342,7 @@ pub enum PropertyDefinition {
     ///
     /// [spec]: https://tc39.es/ecma262/#prod-PropertyDefinition
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Object_initializer#Property_definitions
-    Property(PropertyName, Expression),
+    Property(PropertyName, Expression, NodeIdSlot),
 
     /// A property of an object can also refer to a function or a getter or setter method.
     ///
@@ -359,7 +365,7 @@ pub enum PropertyDefinition {
     ///
     /// [spec]: https://tc39.es/ecma262/#prod-PropertyDefinition
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Object_initializer#Spread_properties
-    SpreadObject(Expression),
+    SpreadObject(Expression, NodeIdSlot),
 
     /// Cover grammar for when an object literal is used as an object binding pattern.
     ///
@@ -367,7 +373,7 @@ pub enum PropertyDefinition {
     ///  - [ECMAScript reference][spec]
     ///
     /// [spec]: https://tc39.es/ecma262/#prod-CoverInitializedName
-    CoverInitializedName(Identifier, Expression),
+    CoverInitializedName(Identifier, Expression, NodeIdSlot),
 }
 
 impl VisitWith for PropertyDefinition {
@@ -377,13 +383,13 @@ impl VisitWith for PropertyDefinition {
     {
         match self {
             Self::IdentifierReference(id) => visitor.visit_identifier(id),
-            Self::Property(pn, expr) => {
+            Self::Property(pn, expr, _) => {
                 visitor.visit_property_name(pn)?;
                 visitor.visit_expression(expr)
             }
             Self::MethodDefinition(m) => visitor.visit_object_method_definition(m),
-            Self::SpreadObject(expr) => visitor.visit_expression(expr),
-            Self::CoverInitializedName(id, expr) => {
+            Self::SpreadObject(expr, _) => visitor.visit_expression(expr),
+            Self::CoverInitializedName(id, expr, _) => {
                 visitor.visit_identifier(id)?;
                 visitor.visit_expression(expr)
             }
@@ -396,13 +402,13 @@ impl VisitWith for PropertyDefinition {
     {
         match self {
             Self::IdentifierReference(id) => visitor.visit_identifier_mut(id),
-            Self::Property(pn, expr) => {
+            Self::Property(pn, expr, _) => {
                 visitor.visit_property_name_mut(pn)?;
                 visitor.visit_expression_mut(expr)
             }
             Self::MethodDefinition(m) => visitor.visit_object_method_definition_mut(m),
-            Self::SpreadObject(expr) => visitor.visit_expression_mut(expr),
-            Self::CoverInitializedName(id, expr) => {
+            Self::SpreadObject(expr, _) => visitor.visit_expression_mut(expr),
+            Self::CoverInitializedName(id, expr, _) => {
                 visitor.visit_identifier_mut(id)?;
                 visitor.visit_expression_mut(expr)
             }
@@ -431,6 +437,7 @@ pub struct ObjectMethodDefinition {
     #[cfg_attr(feature = "serde", serde(skip))]
     pub(crate) scopes: FunctionScopes,
     linear_span: LinearSpanIgnoreEq,
+    pub(crate) id: NodeIdSlot,
 }
 
 impl ObjectMethodDefinition {
@@ -456,6 +463,7 @@ impl ObjectMethodDefinition {
             kind,
             scopes: FunctionScopes::default(),
             linear_span,
+            id: NodeIdSlot::EMPTY,
         }
     }
 
@@ -2,6 +2,7 @@
 
 use crate::{
     expression::Expression,
+    node_id::NodeIdSlot,
     visitor::{VisitWith, Visitor, VisitorMut},
     Span,
 };
@@ -21,6 +22,7 @@ use core::{fmt::Write as _, ops::ControlFlow};
 pub struct TemplateLiteral {
     elements: Box<[TemplateElement]>,
     span: Span,
+    pub(crate) id: NodeIdSlot,
 }
 
 /// Manual implementation, because string and expression in the element list must always appear in order.
@@ -33,7 +35,7 @@ impl<'a> arbitrary::Arbitrary<'a> for TemplateLiteral {
         for i in 0..len {
             if i & 1 == 0 {
                 elements.push(TemplateElement::String(
-                    <Sym as arbitrary::Arbitrary>::arbitrary(u)?,
+                    <TemplateString as arbitrary::Arbitrary>::arbitrary(u)?,
                 ));
             } else {
                 elements.push(TemplateElement::Expr(Expression::arbitrary(u)?));
@@ -56,7 +58,11 @@ impl TemplateLiteral {
     #[inline]
     #[must_use]
     pub fn new(elements: Box<[TemplateElement]>, span: Span) -> Self {
-        Self { elements, span }
+        Self {
+            elements,
+            span,
+            id: NodeIdSlot::EMPTY,
+        }
     }
 
     /// Gets the element list of this `TemplateLiteral`.
@@ -81,7 +87,7 @@ impl ToInternedString for TemplateLiteral {
         for elt in &self.elements {
             match elt {
                 TemplateElement::String(s) => {
-                    let _ = write!(buf, "{}", interner.resolve_expect(*s));
+                    let _ = write!(buf, "{}", interner.resolve_expect(s.raw()));
                 }
                 TemplateElement::Expr(n) => {
                     let _ = write!(buf, "${{{}}}", n.to_interned_string(interner));
@@ -127,7 +133,7 @@ impl VisitWith for TemplateLiteral {
 #[derive(Clone, Debug, PartialEq)]
 pub enum TemplateElement {
     /// A simple string.
-    String(Sym),
+    String(TemplateString),
     /// An expression that is evaluated and replaced by its string representation.
     Expr(Expression),
 }
@@ -138,7 +144,7 @@ impl VisitWith for TemplateElement {
         V: Visitor<'a>,
     {
         match self {
-            Self::String(sym) => visitor.visit_sym(sym),
+            Self::String(string) => string.visit_with(visitor),
             Self::Expr(expr) => visitor.visit_expression(expr),
         }
     }
@@ -148,8 +154,80 @@ impl VisitWith for TemplateElement {
         V: VisitorMut<'a>,
     {
         match self {
-            Self::String(sym) => visitor.visit_sym_mut(sym),
+            Self::String(string) => string.visit_with_mut(visitor),
             Self::Expr(expr) => visitor.visit_expression_mut(expr),
         }
     }
 }
+
+/// A string part of a template literal, also known as a template span.
+///
+/// Stores both the cooked string (the string value with its escape sequences interpreted) and
+/// the raw string (the source text of the string), as defined by the [spec]. The cooked string
+/// is `None` if the string contains an invalid escape sequence, which is only allowed in
+/// [`TaggedTemplate`][crate::expression::TaggedTemplate]s.
+///
+/// [spec]: https://tc39.es/ecma262/#sec-static-semantics-templatestrings
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TemplateString {
+    cooked: Option<Sym>,
+    raw: Sym,
+    span: Span,
+}
+
+impl TemplateString {
+    /// Creates a new `TemplateString` from its cooked and raw strings.
+    #[inline]
+    #[must_use]
+    pub const fn new(cooked: Option<Sym>, raw: Sym, span: Span) -> Self {
+        Self { cooked, raw, span }
+    }
+
+    /// Gets the cooked string of the template string, if it is valid.
+    #[inline]
+    #[must_use]
+    pub const fn cooked(&self) -> Option<Sym> {
+        self.cooked
+    }
+
+    /// Gets the raw string of the template string.
+    #[inline]
+    #[must_use]
+    pub const fn raw(&self) -> Sym {
+        self.raw
+    }
+
+    /// Get the [`Span`] of the [`TemplateString`] node.
+    ///
+    /// This includes the delimiters of the string, like the backticks and the `${` and `}` of the
+    /// surrounding substitutions.
+    #[inline]
+    #[must_use]
+    pub const fn span(&self) -> Span {
+        self.span
+    }
+}
+
+impl VisitWith for TemplateString {
+    fn visit_with<'a, V>(&'a self, visitor: &mut V) -> ControlFlow<V::BreakTy>
+    where
+        V: Visitor<'a>,
+    {
+        if let Some(cooked) = &self.cooked {
+            visitor.visit_sym(cooked)?;
+        }
+        visitor.visit_sym(&self.raw)
+    }
+
+    fn visit_with_mut<'a, V>(&'a mut self, visitor: &mut V) -> ControlFlow<V::BreakTy>
+    where
+        V: VisitorMut<'a>,
+    {
+        if let Some(cooked) = &mut self.cooked {
+            visitor.visit_sym_mut(cooked)?;
+        }
+        visitor.visit_sym_mut(&mut self.raw)
+    }
+}
@@ -1,6 +1,6 @@
-use crate::expression::Call;
 use crate::visitor::{VisitWith, Visitor, VisitorMut};
 use crate::Span;
+use crate::{expression::Call, node_id::NodeIdSlot};
 use boa_interner::{Interner, ToInternedString};
 use core::ops::ControlFlow;
 
@@ -26,6 +26,7 @@ use super::Expression;
 #[derive(Clone, Debug, PartialEq)]
 pub struct New {
     call: Call,
+    pub(crate) id: NodeIdSlot,
 }
 
 impl New {
@@ -60,7 +61,10 @@ impl New {
 impl From<Call> for New {
     #[inline]
     fn from(call: Call) -> Self {
-        Self { call }
+        Self {
+            call,
+            id: NodeIdSlot::EMPTY,
+        }
     }
 }
 
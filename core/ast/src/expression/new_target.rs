@@ -1,6 +1,7 @@
 //! `target.new` ECMAScript expression.
 
 use crate::{
+    node_id::NodeIdSlot,
     visitor::{VisitWith, Visitor, VisitorMut},
     Span,
 };
@@ -15,6 +16,7 @@ use super::Expression;
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct NewTarget {
     span: Span,
+    pub(crate) id: NodeIdSlot,
 }
 
 impl NewTarget {
@@ -22,7 +24,10 @@ impl NewTarget {
     #[inline]
     #[must_use]
     pub const fn new(span: Span) -> Self {
-        Self { span }
+        Self {
+            span,
+            id: NodeIdSlot::EMPTY,
+        }
     }
 
     /// Get the [`Span`] of the [`NewTarget`] node.
@@ -21,6 +21,7 @@ use boa_interner::{Interner, Sym, ToInternedString};
 
 use crate::{
     expression::{access::PropertyAccess, identifier::Identifier, Expression},
+    node_id::NodeIdSlot,
     pattern::Pattern,
     visitor::{VisitWith, Visitor, VisitorMut},
     Span,
@@ -36,6 +37,7 @@ pub struct Assign {
     op: AssignOp,
     lhs: Box<AssignTarget>,
     rhs: Box<Expression>,
+    pub(crate) id: NodeIdSlot,
 }
 
 impl Assign {
@@ -47,6 +49,7 @@ impl Assign {
             op,
             lhs: Box::new(lhs),
             rhs: Box::new(rhs),
+            id: NodeIdSlot::EMPTY,
         }
     }
 
@@ -19,6 +19,7 @@ mod op;
 use crate::{
     expression::Expression,
     function::PrivateName,
+    node_id::NodeIdSlot,
     visitor::{VisitWith, Visitor, VisitorMut},
     Span,
 };
@@ -37,6 +38,7 @@ pub struct Binary {
     op: BinaryOp,
     lhs: Box<Expression>,
     rhs: Box<Expression>,
+    pub(crate) id: NodeIdSlot,
 }
 
 impl Binary {
@@ -48,6 +50,7 @@ impl Binary {
             op,
             lhs: Box::new(lhs),
             rhs: Box::new(rhs),
+            id: NodeIdSlot::EMPTY,
         }
     }
 
@@ -142,6 +145,7 @@ impl VisitWith for Binary {
 pub struct BinaryInPrivate {
     lhs: PrivateName,
     rhs: Box<Expression>,
+    pub(crate) id: NodeIdSlot,
 }
 
 impl BinaryInPrivate {
@@ -152,6 +156,7 @@ impl BinaryInPrivate {
         Self {
             lhs,
             rhs: Box::new(rhs),
+            id: NodeIdSlot::EMPTY,
         }
     }
 
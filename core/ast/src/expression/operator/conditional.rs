@@ -1,5 +1,6 @@
 use crate::{
     expression::Expression,
+    node_id::NodeIdSlot,
     visitor::{VisitWith, Visitor, VisitorMut},
     Span,
 };
@@ -27,6 +28,7 @@ pub struct Conditional {
     condition: Box<Expression>,
     if_true: Box<Expression>,
     if_false: Box<Expression>,
+    pub(crate) id: NodeIdSlot,
 }
 
 impl Conditional {
@@ -59,6 +61,7 @@ impl Conditional {
             condition: Box::new(condition),
             if_true: Box::new(if_true),
             if_false: Box::new(if_false),
+            id: NodeIdSlot::EMPTY,
         }
     }
 
@@ -13,6 +13,7 @@ mod op;
 
 use crate::{
     expression::Expression,
+    node_id::NodeIdSlot,
     visitor::{VisitWith, Visitor, VisitorMut},
     Span,
 };
@@ -36,6 +37,7 @@ pub struct Unary {
     op: UnaryOp,
     target: Box<Expression>,
     span: Span,
+    pub(crate) id: NodeIdSlot,
 }
 
 impl Unary {
@@ -47,6 +49,7 @@ impl Unary {
             op,
             target: Box::new(target),
             span,
+            id: NodeIdSlot::EMPTY,
         }
     }
 
@@ -11,6 +11,7 @@ mod op;
 
 use crate::{
     expression::{access::PropertyAccess, Identifier},
+    node_id::NodeIdSlot,
     visitor::{VisitWith, Visitor, VisitorMut},
     Expression, Span,
 };
@@ -34,6 +35,7 @@ pub struct Update {
     op: UpdateOp,
     target: Box<UpdateTarget>,
     span: Span,
+    pub(crate) id: NodeIdSlot,
 }
 
 impl Update {
@@ -45,6 +47,7 @@ impl Update {
             op,
             target: Box::new(target),
             span,
+            id: NodeIdSlot::EMPTY,
         }
     }
 
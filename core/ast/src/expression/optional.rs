@@ -2,6 +2,7 @@ use super::{access::PropertyAccessField, Expression};
 use crate::{
     function::PrivateName,
     join_nodes,
+    node_id::NodeIdSlot,
     visitor::{VisitWith, Visitor, VisitorMut},
     Span,
 };
@@ -77,6 +78,7 @@ pub struct OptionalOperation {
     kind: OptionalOperationKind,
     shorted: bool,
     span: Span,
+    pub(crate) id: NodeIdSlot,
 }
 
 impl OptionalOperation {
@@ -88,6 +90,7 @@ impl OptionalOperation {
             kind,
             shorted,
             span,
+            id: NodeIdSlot::EMPTY,
         }
     }
     /// Gets the kind of operation.
@@ -196,6 +199,7 @@ pub struct Optional {
     target: Box<Expression>,
     chain: Box<[OptionalOperation]>,
     span: Span,
+    pub(crate) id: NodeIdSlot,
 }
 
 impl Optional {
@@ -207,6 +211,7 @@ impl Optional {
             target: Box::new(target),
             chain,
             span,
+            id: NodeIdSlot::EMPTY,
         }
     }
 
@@ -1,5 +1,6 @@
 use super::Expression;
 use crate::{
+    node_id::NodeIdSlot,
     visitor::{VisitWith, Visitor, VisitorMut},
     Span,
 };
@@ -20,6 +21,7 @@ use core::ops::ControlFlow;
 pub struct Parenthesized {
     pub(crate) expression: Box<Expression>,
     span: Span,
+    pub(crate) id: NodeIdSlot,
 }
 
 impl Parenthesized {
@@ -30,6 +32,7 @@ impl Parenthesized {
         Self {
             expression: Box::new(expression),
             span,
+            id: NodeIdSlot::EMPTY,
         }
     }
 
@@ -12,6 +12,7 @@ use std::ops::ControlFlow;
 use boa_interner::{Interner, Sym, ToInternedString};
 
 use crate::{
+    node_id::NodeIdSlot,
     visitor::{VisitWith, Visitor, VisitorMut},
     Span,
 };
@@ -33,6 +34,7 @@ pub struct RegExpLiteral {
     pattern: Sym,
     flags: Sym,
     span: Span,
+    pub(crate) id: NodeIdSlot,
 }
 
 impl RegExpLiteral {
@@ -44,6 +46,7 @@ impl RegExpLiteral {
             pattern,
             flags,
             span,
+            id: NodeIdSlot::EMPTY,
         }
     }
 
@@ -2,6 +2,7 @@ use boa_interner::{Interner, ToInternedString};
 use core::ops::ControlFlow;
 
 use crate::{
+    node_id::NodeIdSlot,
     visitor::{VisitWith, Visitor, VisitorMut},
     Span,
 };
@@ -30,6 +31,7 @@ use super::Expression;
 pub struct Spread {
     target: Box<Expression>,
     span: Span,
+    pub(crate) id: NodeIdSlot,
 }
 
 impl Spread {
@@ -40,6 +42,7 @@ impl Spread {
         Self {
             target: Box::new(target),
             span,
+            id: NodeIdSlot::EMPTY,
         }
     }
 
@@ -1,9 +1,10 @@
-use super::Expression;
+use super::{literal::TemplateString, Expression};
 use crate::{
+    node_id::NodeIdSlot,
     visitor::{VisitWith, Visitor, VisitorMut},
     Span,
 };
-use boa_interner::{Interner, Sym, ToInternedString};
+use boa_interner::{Interner, ToInternedString};
 use core::{fmt::Write as _, ops::ControlFlow};
 
 /// A [`TaggedTemplate`][moz] expression, as defined by the [spec].
@@ -18,33 +19,32 @@ use core::{fmt::Write as _, ops::ControlFlow};
 #[derive(Clone, Debug, PartialEq)]
 pub struct TaggedTemplate {
     tag: Box<Expression>,
-    raws: Box<[Sym]>,
-    cookeds: Box<[Option<Sym>]>,
+    strings: Box<[TemplateString]>,
     exprs: Box<[Expression]>,
     identifier: u64,
     span: Span,
+    pub(crate) id: NodeIdSlot,
 }
 
 impl TaggedTemplate {
-    /// Creates a new tagged template with a tag, the list of raw strings, the cooked strings and
-    /// the expressions.
+    /// Creates a new tagged template with a tag, the list of template strings and the
+    /// expressions.
     #[inline]
     #[must_use]
     pub fn new(
         tag: Expression,
-        raws: Box<[Sym]>,
-        cookeds: Box<[Option<Sym>]>,
+        strings: Box<[TemplateString]>,
         exprs: Box<[Expression]>,
         identifier: u64,
         span: Span,
     ) -> Self {
         Self {
             tag: tag.into(),
-            raws,
-            cookeds,
+            strings,
             exprs,
             identifier,
             span,
+            id: NodeIdSlot::EMPTY,
         }
     }
 
@@ -55,18 +55,11 @@ impl TaggedTemplate {
         &self.tag
     }
 
-    /// Gets the inner raw strings of the template.
+    /// Gets the strings of the template, with both their cooked and raw values.
     #[inline]
     #[must_use]
-    pub const fn raws(&self) -> &[Sym] {
-        &self.raws
-    }
-
-    /// Gets the cooked strings of the template.
-    #[inline]
-    #[must_use]
-    pub const fn cookeds(&self) -> &[Option<Sym>] {
-        &self.cookeds
+    pub const fn strings(&self) -> &[TemplateString] {
+        &self.strings
     }
 
     /// Gets the interpolated expressions of the template.
@@ -77,6 +70,9 @@ impl TaggedTemplate {
     }
 
     /// Gets the unique identifier of the template.
+    ///
+    /// The identifier is derived from the location of the template in the source code, and is
+    /// used to cache the template object of this template site.
     #[inline]
     #[must_use]
     pub const fn identifier(&self) -> u64 {
@@ -97,8 +93,8 @@ impl ToInternedString for TaggedTemplate {
         let mut buf = format!("{}`", self.tag.to_interned_string(interner));
         let mut exprs = self.exprs.iter();
 
-        for raw in &self.raws {
-            let _ = write!(buf, "{}", interner.resolve_expect(*raw));
+        for string in &self.strings {
+            let _ = write!(buf, "{}", interner.resolve_expect(string.raw()));
             if let Some(expr) = exprs.next() {
                 let _ = write!(buf, "${{{}}}", expr.to_interned_string(interner));
             }
@@ -122,11 +118,8 @@ impl VisitWith for TaggedTemplate {
         V: Visitor<'a>,
     {
         visitor.visit_expression(&self.tag)?;
-        for raw in &*self.raws {
-            visitor.visit_sym(raw)?;
-        }
-        for cooked in self.cookeds.iter().flatten() {
-            visitor.visit_sym(cooked)?;
+        for string in &*self.strings {
+            string.visit_with(visitor)?;
         }
         for expr in &*self.exprs {
             visitor.visit_expression(expr)?;
@@ -139,11 +132,8 @@ impl VisitWith for TaggedTemplate {
         V: VisitorMut<'a>,
     {
         visitor.visit_expression_mut(&mut self.tag)?;
-        for raw in &mut *self.raws {
-            visitor.visit_sym_mut(raw)?;
-        }
-        for cooked in self.cookeds.iter_mut().flatten() {
-            visitor.visit_sym_mut(cooked)?;
+        for string in &mut *self.strings {
+            string.visit_with_mut(visitor)?;
         }
         for expr in &mut *self.exprs {
             visitor.visit_expression_mut(expr)?;
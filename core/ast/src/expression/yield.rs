@@ -2,6 +2,7 @@ use boa_interner::{Interner, ToInternedString};
 use core::ops::ControlFlow;
 
 use crate::{
+    node_id::NodeIdSlot,
     visitor::{VisitWith, Visitor, VisitorMut},
     Span,
 };
@@ -23,6 +24,7 @@ pub struct Yield {
     target: Option<Box<Expression>>,
     delegate: bool,
     span: Span,
+    pub(crate) id: NodeIdSlot,
 }
 
 impl Yield {
@@ -34,6 +36,7 @@ impl Yield {
             target: expr.map(Box::new),
             delegate,
             span,
+            id: NodeIdSlot::EMPTY,
         }
     }
 
@@ -5,6 +5,7 @@ use crate::visitor::{VisitWith, Visitor, VisitorMut};
 use crate::{
     expression::{Expression, Identifier},
     join_nodes,
+    node_id::NodeIdSlot,
 };
 use crate::{LinearSpan, LinearSpanIgnoreEq, Span};
 use boa_interner::{Interner, ToIndentedString};
@@ -32,6 +33,7 @@ pub struct ArrowFunction {
     pub(crate) scopes: FunctionScopes,
     linear_span: LinearSpanIgnoreEq,
     span: Span,
+    pub(crate) id: NodeIdSlot,
 }
 
 impl ArrowFunction {
@@ -55,6 +57,7 @@ impl ArrowFunction {
             scopes: FunctionScopes::default(),
             linear_span: linear_span.into(),
             span,
+            id: NodeIdSlot::EMPTY,
         }
     }
 
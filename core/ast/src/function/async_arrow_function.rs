@@ -5,6 +5,7 @@ use crate::visitor::{VisitWith, Visitor, VisitorMut};
 use crate::{
     expression::{Expression, Identifier},
     join_nodes,
+    node_id::NodeIdSlot,
 };
 use crate::{LinearSpan, LinearSpanIgnoreEq, Span};
 use boa_interner::{Interner, ToIndentedString};
@@ -33,6 +34,7 @@ pub struct AsyncArrowFunction {
     linear_span: LinearSpanIgnoreEq,
 
     span: Span,
+    pub(crate) id: NodeIdSlot,
 }
 
 impl AsyncArrowFunction {
@@ -56,6 +58,7 @@ impl AsyncArrowFunction {
             scopes: FunctionScopes::default(),
             linear_span: linear_span.into(),
             span,
+            id: NodeIdSlot::EMPTY,
         }
     }
 
@@ -5,6 +5,7 @@ use crate::{
     block_to_string,
     expression::{Expression, Identifier},
     join_nodes,
+    node_id::NodeIdSlot,
     operations::{contains, ContainsSymbol},
     scope::{FunctionScopes, Scope},
     visitor::{VisitWith, Visitor, VisitorMut},
@@ -33,6 +34,7 @@ pub struct AsyncFunctionDeclaration {
     #[cfg_attr(feature = "serde", serde(skip))]
     pub(crate) scopes: FunctionScopes,
     linear_span: LinearSpanIgnoreEq,
+    pub(crate) id: NodeIdSlot,
 }
 
 impl AsyncFunctionDeclaration {
@@ -54,6 +56,7 @@ impl AsyncFunctionDeclaration {
             contains_direct_eval,
             scopes: FunctionScopes::default(),
             linear_span: linear_span.into(),
+            id: NodeIdSlot::EMPTY,
         }
     }
 
@@ -164,6 +167,7 @@ pub struct AsyncFunctionExpression {
     linear_span: LinearSpanIgnoreEq,
 
     span: Span,
+    pub(crate) id: NodeIdSlot,
 }
 
 impl AsyncFunctionExpression {
@@ -190,6 +194,7 @@ impl AsyncFunctionExpression {
             scopes: FunctionScopes::default(),
             linear_span: linear_span.into(),
             span,
+            id: NodeIdSlot::EMPTY,
         }
     }
 
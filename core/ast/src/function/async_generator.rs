@@ -7,7 +7,9 @@ use crate::visitor::{VisitWith, Visitor, VisitorMut};
 use crate::{
     block_to_string,
     expression::{Expression, Identifier},
-    join_nodes, Declaration,
+    join_nodes,
+    node_id::NodeIdSlot,
+    Declaration,
 };
 use crate::{LinearSpan, LinearSpanIgnoreEq, Span};
 use boa_interner::{Interner, ToIndentedString};
@@ -33,6 +35,7 @@ pub struct AsyncGeneratorDeclaration {
     #[cfg_attr(feature = "serde", serde(skip))]
     pub(crate) scopes: FunctionScopes,
     linear_span: LinearSpanIgnoreEq,
+    pub(crate) id: NodeIdSlot,
 }
 
 impl AsyncGeneratorDeclaration {
@@ -54,6 +57,7 @@ impl AsyncGeneratorDeclaration {
             contains_direct_eval,
             scopes: FunctionScopes::default(),
             linear_span: linear_span.into(),
+            id: NodeIdSlot::EMPTY,
         }
     }
 
@@ -164,6 +168,7 @@ pub struct AsyncGeneratorExpression {
     linear_span: LinearSpanIgnoreEq,
 
     span: Span,
+    pub(crate) id: NodeIdSlot,
 }
 
 impl AsyncGeneratorExpression {
@@ -190,6 +195,7 @@ impl AsyncGeneratorExpression {
             scopes: FunctionScopes::default(),
             linear_span: linear_span.into(),
             span,
+            id: NodeIdSlot::EMPTY,
         }
     }
 
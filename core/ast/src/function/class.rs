@@ -3,6 +3,7 @@ use crate::{
     block_to_string,
     expression::{Expression, Identifier},
     join_nodes,
+    node_id::NodeIdSlot,
     operations::{contains, ContainsSymbol},
     property::{MethodDefinitionKind, PropertyName},
     scope::{FunctionScopes, Scope},
@@ -32,6 +33,7 @@ pub struct ClassDeclaration {
 
     #[cfg_attr(feature = "serde", serde(skip))]
     pub(crate) name_scope: Scope,
+    pub(crate) id: NodeIdSlot,
 }
 
 impl ClassDeclaration {
@@ -50,6 +52,7 @@ impl ClassDeclaration {
             constructor,
             elements,
             name_scope: Scope::default(),
+            id: NodeIdSlot::EMPTY,
         }
     }
 
@@ -180,6 +183,7 @@ pub struct ClassExpression {
 
     #[cfg_attr(feature = "serde", serde(skip))]
     pub(crate) name_scope: Option<Scope>,
+    pub(crate) id: NodeIdSlot,
 }
 
 impl ClassExpression {
@@ -206,6 +210,7 @@ impl ClassExpression {
             elements,
             span,
             name_scope,
+            id: NodeIdSlot::EMPTY,
         }
     }
 
@@ -344,6 +349,7 @@ pub struct StaticBlockBody {
 
     #[cfg_attr(feature = "serde", serde(skip))]
     pub(crate) scopes: FunctionScopes,
+    pub(crate) id: NodeIdSlot,
 }
 
 impl StaticBlockBody {
@@ -354,6 +360,7 @@ impl StaticBlockBody {
         Self {
             body,
             scopes: FunctionScopes::default(),
+            id: NodeIdSlot::EMPTY,
         }
     }
 
@@ -414,20 +421,27 @@ pub enum ClassElement {
 pub struct ClassFieldDefinition {
     pub(crate) name: PropertyName,
     pub(crate) initializer: Option<Expression>,
+    pub(crate) is_accessor: bool,
 
     #[cfg_attr(feature = "serde", serde(skip))]
     pub(crate) scope: Scope,
+    pub(crate) id: NodeIdSlot,
 }
 
 impl ClassFieldDefinition {
     /// Creates a new class field definition.
+    ///
+    /// If `is_accessor` is `true`, the field is an auto-accessor, which stores its value in a
+    /// private field of the class, and is exposed through a getter and setter pair.
     #[inline]
     #[must_use]
-    pub fn new(name: PropertyName, initializer: Option<Expression>) -> Self {
+    pub fn new(name: PropertyName, initializer: Option<Expression>, is_accessor: bool) -> Self {
         Self {
             name,
             initializer,
+            is_accessor,
             scope: Scope::default(),
+            id: NodeIdSlot::EMPTY,
         }
     }
 
@@ -445,6 +459,14 @@ impl ClassFieldDefinition {
         self.initializer.as_ref()
     }
 
+    /// Returns `true` if the class field definition is an auto-accessor, declared with the `accessor`
+    /// keyword.
+    #[inline]
+    #[must_use]
+    pub const fn is_accessor(&self) -> bool {
+        self.is_accessor
+    }
+
     /// Returns the scope of the class field definition.
     #[inline]
     #[must_use]
@@ -465,20 +487,27 @@ impl ClassFieldDefinition {
 pub struct PrivateFieldDefinition {
     pub(crate) name: PrivateName,
     pub(crate) initializer: Option<Expression>,
+    pub(crate) is_accessor: bool,
 
     #[cfg_attr(feature = "serde", serde(skip))]
     pub(crate) scope: Scope,
+    pub(crate) id: NodeIdSlot,
 }
 
 impl PrivateFieldDefinition {
     /// Creates a new private field definition.
+    ///
+    /// If `is_accessor` is `true`, the field is an auto-accessor, which stores its value in a
+    /// private field of the class, and is exposed through a private getter and setter pair.
     #[inline]
     #[must_use]
-    pub fn new(name: PrivateName, initializer: Option<Expression>) -> Self {
+    pub fn new(name: PrivateName, initializer: Option<Expression>, is_accessor: bool) -> Self {
         Self {
             name,
             initializer,
+            is_accessor,
             scope: Scope::default(),
+            id: NodeIdSlot::EMPTY,
         }
     }
 
@@ -496,6 +525,14 @@ impl PrivateFieldDefinition {
         self.initializer.as_ref()
     }
 
+    /// Returns `true` if the private field definition is an auto-accessor, declared with the `accessor`
+    /// keyword.
+    #[inline]
+    #[must_use]
+    pub const fn is_accessor(&self) -> bool {
+        self.is_accessor
+    }
+
     /// Returns the scope of the private field definition.
     #[inline]
     #[must_use]
@@ -507,19 +544,22 @@ impl PrivateFieldDefinition {
 impl ToIndentedString for ClassElement {
     fn to_indented_string(&self, interner: &Interner, indent_n: usize) -> String {
         let indentation = "    ".repeat(indent_n + 1);
+        let accessor = |is_accessor: bool| if is_accessor { "accessor " } else { "" };
         match self {
             Self::MethodDefinition(m) => m.to_indented_string(interner, indent_n),
             Self::FieldDefinition(field) => match &field.initializer {
                 Some(expr) => {
                     format!(
-                        "{indentation}{} = {};\n",
+                        "{indentation}{}{} = {};\n",
+                        accessor(field.is_accessor),
                         field.name.to_interned_string(interner),
                         expr.to_no_indent_string(interner, indent_n + 1)
                     )
                 }
                 None => {
                     format!(
-                        "{indentation}{};\n",
+                        "{indentation}{}{};\n",
+                        accessor(field.is_accessor),
                         field.name.to_interned_string(interner),
                     )
                 }
@@ -527,31 +567,38 @@ impl ToIndentedString for ClassElement {
             Self::StaticFieldDefinition(field) => match &field.initializer {
                 Some(expr) => {
                     format!(
-                        "{indentation}static {} = {};\n",
+                        "{indentation}static {}{} = {};\n",
+                        accessor(field.is_accessor),
                         field.name.to_interned_string(interner),
                         expr.to_no_indent_string(interner, indent_n + 1)
                     )
                 }
                 None => {
                     format!(
-                        "{indentation}static {};\n",
+                        "{indentation}static {}{};\n",
+                        accessor(field.is_accessor),
                         field.name.to_interned_string(interner),
                     )
                 }
             },
             Self::PrivateFieldDefinition(PrivateFieldDefinition {
-                name, initializer, ..
+                name,
+                initializer,
+                is_accessor,
+                ..
             }) => match initializer {
                 Some(expr) => {
                     format!(
-                        "{indentation}#{} = {};\n",
+                        "{indentation}{}#{} = {};\n",
+                        accessor(*is_accessor),
                         interner.resolve_expect(name.description()),
                         expr.to_no_indent_string(interner, indent_n + 1)
                     )
                 }
                 None => {
                     format!(
-                        "{indentation}#{};\n",
+                        "{indentation}{}#{};\n",
+                        accessor(*is_accessor),
                         interner.resolve_expect(name.description()),
                     )
                 }
@@ -559,18 +606,21 @@ impl ToIndentedString for ClassElement {
             Self::PrivateStaticFieldDefinition(PrivateFieldDefinition {
                 name,
                 initializer,
+                is_accessor,
                 ..
             }) => match initializer {
                 Some(expr) => {
                     format!(
-                        "{indentation}static #{} = {};\n",
+                        "{indentation}static {}#{} = {};\n",
+                        accessor(*is_accessor),
                         interner.resolve_expect(name.description()),
                         expr.to_no_indent_string(interner, indent_n + 1)
                     )
                 }
                 None => {
                     format!(
-                        "{indentation}static #{};\n",
+                        "{indentation}static {}#{};\n",
+                        accessor(*is_accessor),
                         interner.resolve_expect(name.description()),
                     )
                 }
@@ -698,6 +748,7 @@ pub struct ClassMethodDefinition {
     #[cfg_attr(feature = "serde", serde(skip))]
     pub(crate) scopes: FunctionScopes,
     linear_span: LinearSpanIgnoreEq,
+    pub(crate) id: NodeIdSlot,
 }
 
 impl ClassMethodDefinition {
@@ -726,6 +777,7 @@ impl ClassMethodDefinition {
             is_static,
             scopes: FunctionScopes::default(),
             linear_span: linear_span.into(),
+            id: NodeIdSlot::EMPTY,
         }
     }
 
@@ -862,6 +914,7 @@ pub struct PrivateName {
     /// The `[[Description]]` internal slot of the private name.
     description: Sym,
     span: Span,
+    pub(crate) id: NodeIdSlot,
 }
 
 impl PrivateName {
@@ -869,7 +922,11 @@ impl PrivateName {
     #[inline]
     #[must_use]
     pub const fn new(description: Sym, span: Span) -> Self {
-        Self { description, span }
+        Self {
+            description,
+            span,
+            id: NodeIdSlot::EMPTY,
+        }
     }
 
     /// Get the description of the private name.
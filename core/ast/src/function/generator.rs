@@ -3,6 +3,7 @@ use crate::{
     block_to_string,
     expression::{Expression, Identifier},
     join_nodes,
+    node_id::NodeIdSlot,
     operations::{contains, ContainsSymbol},
     scope::{FunctionScopes, Scope},
     visitor::{VisitWith, Visitor, VisitorMut},
@@ -31,6 +32,7 @@ pub struct GeneratorDeclaration {
     #[cfg_attr(feature = "serde", serde(skip))]
     pub(crate) scopes: FunctionScopes,
     linear_span: LinearSpanIgnoreEq,
+    pub(crate) id: NodeIdSlot,
 }
 
 impl GeneratorDeclaration {
@@ -52,6 +54,7 @@ impl GeneratorDeclaration {
             contains_direct_eval,
             scopes: FunctionScopes::default(),
             linear_span: linear_span.into(),
+            id: NodeIdSlot::EMPTY,
         }
     }
 
@@ -162,6 +165,7 @@ pub struct GeneratorExpression {
     linear_span: LinearSpanIgnoreEq,
 
     span: Span,
+    pub(crate) id: NodeIdSlot,
 }
 
 impl GeneratorExpression {
@@ -188,6 +192,7 @@ impl GeneratorExpression {
             scopes: FunctionScopes::default(),
             linear_span: linear_span.into(),
             span,
+            id: NodeIdSlot::EMPTY,
         }
     }
 
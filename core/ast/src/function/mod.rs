@@ -48,6 +48,7 @@ pub use ordinary_function::{FunctionDeclaration, FunctionExpression};
 pub use parameters::{FormalParameter, FormalParameterList, FormalParameterListFlags};
 
 use crate::{
+    node_id::NodeIdSlot,
     visitor::{VisitWith, Visitor, VisitorMut},
     LinearPosition, Span, StatementList, StatementListItem,
 };
@@ -67,6 +68,7 @@ use crate::{
 pub struct FunctionBody {
     pub(crate) statements: StatementList,
     span: Span,
+    pub(crate) id: NodeIdSlot,
 }
 
 impl FunctionBody {
@@ -74,7 +76,11 @@ impl FunctionBody {
     #[inline]
     #[must_use]
     pub fn new(statements: StatementList, span: Span) -> Self {
-        Self { statements, span }
+        Self {
+            statements,
+            span,
+            id: NodeIdSlot::EMPTY,
+        }
     }
 
     /// Gets the list of statements.
@@ -3,6 +3,7 @@ use crate::{
     block_to_string,
     expression::{Expression, Identifier},
     join_nodes,
+    node_id::NodeIdSlot,
     operations::{contains, ContainsSymbol},
     scope::{FunctionScopes, Scope},
     scope_analyzer::{analyze_binding_escapes, collect_bindings},
@@ -32,6 +33,7 @@ pub struct FunctionDeclaration {
     #[cfg_attr(feature = "serde", serde(skip))]
     pub(crate) scopes: FunctionScopes,
     linear_span: LinearSpanIgnoreEq,
+    pub(crate) id: NodeIdSlot,
 }
 
 impl FunctionDeclaration {
@@ -53,6 +55,7 @@ impl FunctionDeclaration {
             contains_direct_eval,
             scopes: FunctionScopes::default(),
             linear_span: linear_span.into(),
+            id: NodeIdSlot::EMPTY,
         }
     }
 
@@ -164,6 +167,7 @@ pub struct FunctionExpression {
     span: Span,
 
     linear_span: Option<LinearSpan>,
+    pub(crate) id: NodeIdSlot,
 }
 
 impl PartialEq for FunctionExpression {
@@ -205,6 +209,7 @@ impl FunctionExpression {
             #[allow(clippy::redundant_closure_for_method_calls)]
             linear_span,
             span,
+            id: NodeIdSlot::EMPTY,
         }
     }
 
@@ -1,6 +1,7 @@
 use crate::{
     declaration::{Binding, Variable},
     expression::Expression,
+    node_id::NodeIdSlot,
     operations::bound_names,
     visitor::{VisitWith, Visitor, VisitorMut},
 };
@@ -18,6 +19,7 @@ pub struct FormalParameterList {
     parameters: Box<[FormalParameter]>,
     flags: FormalParameterListFlags,
     length: u32,
+    pub(crate) id: NodeIdSlot,
 }
 
 impl FormalParameterList {
@@ -28,6 +30,7 @@ impl FormalParameterList {
             parameters: Box::new([]),
             flags: FormalParameterListFlags::default(),
             length: 0,
+            id: NodeIdSlot::EMPTY,
         }
     }
 
@@ -74,6 +77,7 @@ impl FormalParameterList {
             parameters: parameters.into(),
             flags,
             length,
+            id: NodeIdSlot::EMPTY,
         }
     }
 
@@ -220,6 +224,7 @@ impl Default for FormalParameterListFlags {
 pub struct FormalParameter {
     variable: Variable,
     is_rest_param: bool,
+    pub(crate) id: NodeIdSlot,
 }
 
 impl FormalParameter {
@@ -231,6 +236,7 @@ impl FormalParameter {
         Self {
             variable: variable.into(),
             is_rest_param,
+            id: NodeIdSlot::EMPTY,
         }
     }
 
@@ -548,6 +548,55 @@ impl Keyword {
             Self::Yield => Sym::YIELD,
         }
     }
+
+    /// Gets the keyword that was interned as the given symbol, if any.
+    ///
+    /// All the keywords are static strings of the interner, so this doesn't need to resolve the
+    /// symbol to compare its string.
+    #[must_use]
+    pub const fn from_sym(sym: Sym) -> Option<Self> {
+        match sym {
+            Sym::AWAIT => Some(Self::Await),
+            Sym::ASYNC => Some(Self::Async),
+            Sym::BREAK => Some(Self::Break),
+            Sym::CASE => Some(Self::Case),
+            Sym::CATCH => Some(Self::Catch),
+            Sym::CLASS => Some(Self::Class),
+            Sym::CONTINUE => Some(Self::Continue),
+            Sym::CONST => Some(Self::Const),
+            Sym::DEBUGGER => Some(Self::Debugger),
+            Sym::DEFAULT => Some(Self::Default),
+            Sym::DELETE => Some(Self::Delete),
+            Sym::DO => Some(Self::Do),
+            Sym::ELSE => Some(Self::Else),
+            Sym::ENUM => Some(Self::Enum),
+            Sym::EXPORT => Some(Self::Export),
+            Sym::EXTENDS => Some(Self::Extends),
+            Sym::FINALLY => Some(Self::Finally),
+            Sym::FOR => Some(Self::For),
+            Sym::FUNCTION => Some(Self::Function),
+            Sym::IF => Some(Self::If),
+            Sym::IN => Some(Self::In),
+            Sym::INSTANCEOF => Some(Self::InstanceOf),
+            Sym::IMPORT => Some(Self::Import),
+            Sym::LET => Some(Self::Let),
+            Sym::NEW => Some(Self::New),
+            Sym::OF => Some(Self::Of),
+            Sym::RETURN => Some(Self::Return),
+            Sym::SUPER => Some(Self::Super),
+            Sym::SWITCH => Some(Self::Switch),
+            Sym::THIS => Some(Self::This),
+            Sym::THROW => Some(Self::Throw),
+            Sym::TRY => Some(Self::Try),
+            Sym::TYPEOF => Some(Self::TypeOf),
+            Sym::VAR => Some(Self::Var),
+            Sym::VOID => Some(Self::Void),
+            Sym::WHILE => Some(Self::While),
+            Sym::WITH => Some(Self::With),
+            Sym::YIELD => Some(Self::Yield),
+            _ => None,
+        }
+    }
 }
 
 // TODO: Should use a proper Error
@@ -35,6 +35,7 @@ pub mod declaration;
 pub mod expression;
 pub mod function;
 pub mod keyword;
+pub mod node_id;
 pub mod operations;
 pub mod pattern;
 pub mod property;
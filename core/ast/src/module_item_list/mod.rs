@@ -11,6 +11,7 @@ use crate::{
         ImportKind, ImportName, IndirectExportEntry, LocalExportEntry, ModuleSpecifier,
         ReExportImportName, ReExportKind,
     },
+    node_id::NodeIdSlot,
     operations::{bound_names, BoundNamesVisitor},
     visitor::{VisitWith, Visitor, VisitorMut},
     StatementListItem,
@@ -32,6 +33,7 @@ use std::{convert::Infallible, hash::BuildHasherDefault, ops::ControlFlow};
 #[derive(Clone, Debug, Default, PartialEq)]
 pub struct ModuleItemList {
     items: Box<[ModuleItem]>,
+    pub(crate) id: NodeIdSlot,
 }
 
 impl ModuleItemList {
@@ -230,6 +232,85 @@ impl ModuleItemList {
         requests
     }
 
+    /// Gets the list of modules that need to be linked before this module.
+    ///
+    /// This is the same as [`ModuleItemList::requests`], but excludes the modules that are only
+    /// imported with source phase imports (`import source name from "module-name"`), since those
+    /// are loaded but never linked nor evaluated.
+    #[inline]
+    #[must_use]
+    pub fn linking_requests(&self) -> IndexSet<Sym, BuildHasherDefault<FxHasher>> {
+        self.phase_requests(true)
+    }
+
+    /// Gets the list of modules that need to be evaluated before this module.
+    ///
+    /// This is the same as [`ModuleItemList::linking_requests`], but also excludes the modules
+    /// that are only imported with deferred imports (`import defer * as name from "module-name"`),
+    /// since those are evaluated on the first access to their namespace.
+    #[inline]
+    #[must_use]
+    pub fn evaluation_requests(&self) -> IndexSet<Sym, BuildHasherDefault<FxHasher>> {
+        self.phase_requests(false)
+    }
+
+    /// Gets the list of module requests, excluding source phase imports and optionally deferred
+    /// imports.
+    fn phase_requests(
+        &self,
+        include_deferred: bool,
+    ) -> IndexSet<Sym, BuildHasherDefault<FxHasher>> {
+        #[derive(Debug)]
+        struct PhaseRequestsVisitor<'vec> {
+            requests: &'vec mut IndexSet<Sym, BuildHasherDefault<FxHasher>>,
+            include_deferred: bool,
+        }
+
+        impl<'ast> Visitor<'ast> for PhaseRequestsVisitor<'_> {
+            type BreakTy = Infallible;
+
+            fn visit_statement_list_item(
+                &mut self,
+                _: &'ast StatementListItem,
+            ) -> ControlFlow<Self::BreakTy> {
+                ControlFlow::Continue(())
+            }
+            fn visit_import_declaration(
+                &mut self,
+                node: &'ast ImportDeclaration,
+            ) -> ControlFlow<Self::BreakTy> {
+                let included = match node.kind() {
+                    ImportKind::Source { .. } => false,
+                    ImportKind::Deferred { .. } => self.include_deferred,
+                    ImportKind::DefaultOrUnnamed
+                    | ImportKind::Namespaced { .. }
+                    | ImportKind::Named { .. } => true,
+                };
+                if included {
+                    self.requests.insert(node.specifier().sym());
+                }
+                ControlFlow::Continue(())
+            }
+            fn visit_module_specifier(
+                &mut self,
+                node: &'ast ModuleSpecifier,
+            ) -> ControlFlow<Self::BreakTy> {
+                self.requests.insert(node.sym());
+                ControlFlow::Continue(())
+            }
+        }
+
+        let mut requests = IndexSet::default();
+
+        let _ = PhaseRequestsVisitor {
+            requests: &mut requests,
+            include_deferred,
+        }
+        .visit_module_item_list(self);
+
+        requests
+    }
+
     /// Operation [`ImportEntries`][spec].
     ///
     /// Gets the list of import entries of this module.
@@ -273,6 +354,17 @@ impl ModuleItemList {
                         self.0
                             .push(ImportEntry::new(module, ImportName::Namespace, *binding));
                     }
+                    ImportKind::Source { binding } => {
+                        self.0
+                            .push(ImportEntry::new(module, ImportName::Source, *binding));
+                    }
+                    ImportKind::Deferred { binding } => {
+                        self.0.push(ImportEntry::new(
+                            module,
+                            ImportName::DeferredNamespace,
+                            *binding,
+                        ));
+                    }
                     ImportKind::Named { names } => {
                         for name in &**names {
                             self.0.push(ImportEntry::new(
@@ -410,6 +502,7 @@ where
     fn from(items: T) -> Self {
         Self {
             items: items.into(),
+            id: NodeIdSlot::EMPTY,
         }
     }
 }
@@ -0,0 +1,490 @@
+//! Stable identifiers of AST nodes, and side tables keyed by them.
+//!
+//! Every struct node of the AST has a [`NodeId`] slot, which the parser fills in pre-order once a
+//! source has been parsed. Enum nodes are identified by the node they wrap, except for class
+//! elements and the property definitions that don't wrap a node, which have their own slot. Analysis passes can then store their results in a [`SideTable`] keyed
+//! by those identifiers (e.g. inferred types, lint annotations or coverage counters), instead of
+//! storing them in the tree itself.
+
+use core::{
+    hash::{Hash, Hasher},
+    ops::ControlFlow,
+};
+
+use crate::{
+    declaration::{
+        ExportSpecifier, ImportDeclaration, ImportSpecifier, ModuleSpecifier, Variable,
+        VariableList,
+    },
+    expression::{
+        access::{PrivatePropertyAccess, SimplePropertyAccess, SuperPropertyAccess},
+        literal::{
+            ArrayLiteral, Literal, ObjectLiteral, ObjectMethodDefinition, PropertyDefinition,
+            TemplateLiteral,
+        },
+        operator::{Assign, Binary, BinaryInPrivate, Conditional, Unary, Update},
+        Await, Call, Identifier, ImportCall, ImportMeta, New, NewTarget, Optional,
+        OptionalOperation, Parenthesized, RegExpLiteral, Spread, SuperCall, TaggedTemplate, This,
+        Yield,
+    },
+    function::{
+        ArrowFunction, AsyncArrowFunction, AsyncFunctionDeclaration, AsyncFunctionExpression,
+        AsyncGeneratorDeclaration, AsyncGeneratorExpression, ClassDeclaration, ClassElement,
+        ClassExpression, FormalParameter, FormalParameterList, FunctionBody, FunctionDeclaration,
+        FunctionExpression, GeneratorDeclaration, GeneratorExpression, PrivateName,
+    },
+    pattern::{ArrayPattern, ObjectPattern},
+    statement::{
+        iteration::{Break, Continue, DoWhileLoop, ForInLoop, ForLoop, ForOfLoop, WhileLoop},
+        Block, Case, Catch, Finally, If, Labelled, Return, Switch, Throw, Try, With,
+    },
+    visitor::{NodeRef, NodeRefMut, VisitWith, VisitorMut},
+    Module, ModuleItemList, Script, StatementList,
+};
+
+/// The identifier of a node of the AST.
+///
+/// Identifiers are assigned by [`assign_node_ids`], in the order in which the nodes are visited,
+/// so they are dense indices starting at zero and are stable for a given source.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NodeId(u32);
+
+impl NodeId {
+    /// Creates a new `NodeId` from its index.
+    #[inline]
+    #[must_use]
+    pub const fn new(index: u32) -> Self {
+        Self(index)
+    }
+
+    /// Gets the index of the `NodeId`.
+    #[inline]
+    #[must_use]
+    pub const fn index(self) -> usize {
+        self.0 as usize
+    }
+}
+
+/// The [`NodeId`] slot of an AST node, which is empty until the node is assigned one.
+///
+/// Like [`LinearSpanIgnoreEq`][crate::LinearSpanIgnoreEq], it is ignored when comparing and
+/// hashing nodes, so that nodes built by hand compare equal to parsed ones.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NodeIdSlot(Option<NodeId>);
+
+impl NodeIdSlot {
+    /// A slot that has not been assigned a [`NodeId`] yet.
+    pub const EMPTY: Self = Self(None);
+
+    /// Gets the [`NodeId`] of the slot, if it was assigned one.
+    #[inline]
+    #[must_use]
+    pub const fn get(self) -> Option<NodeId> {
+        self.0
+    }
+}
+
+impl PartialEq for NodeIdSlot {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for NodeIdSlot {}
+
+impl Hash for NodeIdSlot {
+    fn hash<H: Hasher>(&self, _: &mut H) {}
+}
+
+/// A table that associates data to the nodes of an AST, keyed by their [`NodeId`].
+///
+/// Since node identifiers are dense, the table is stored as a vector indexed by them.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SideTable<T> {
+    entries: Vec<Option<T>>,
+    len: usize,
+}
+
+impl<T> Default for SideTable<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> SideTable<T> {
+    /// Creates a new, empty `SideTable`.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+            len: 0,
+        }
+    }
+
+    /// Creates a new, empty `SideTable` with room for the given number of nodes, e.g. the one
+    /// returned by [`assign_node_ids`].
+    #[inline]
+    #[must_use]
+    pub fn with_capacity(node_count: usize) -> Self {
+        Self {
+            entries: Vec::with_capacity(node_count),
+            len: 0,
+        }
+    }
+
+    /// Gets the number of nodes that have an entry in the table.
+    #[inline]
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if no node has an entry in the table.
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Inserts the entry of a node, returning its previous entry if there was one.
+    pub fn insert(&mut self, id: NodeId, value: T) -> Option<T> {
+        let index = id.index();
+        if index >= self.entries.len() {
+            self.entries.resize_with(index + 1, || None);
+        }
+
+        let previous = self.entries[index].replace(value);
+        if previous.is_none() {
+            self.len += 1;
+        }
+        previous
+    }
+
+    /// Gets the entry of a node.
+    #[inline]
+    #[must_use]
+    pub fn get(&self, id: NodeId) -> Option<&T> {
+        self.entries.get(id.index())?.as_ref()
+    }
+
+    /// Gets a mutable reference to the entry of a node.
+    #[inline]
+    #[must_use]
+    pub fn get_mut(&mut self, id: NodeId) -> Option<&mut T> {
+        self.entries.get_mut(id.index())?.as_mut()
+    }
+
+    /// Gets the entry of a node, inserting the one returned by `default` if there isn't one.
+    pub fn get_or_insert_with<F: FnOnce() -> T>(&mut self, id: NodeId, default: F) -> &mut T {
+        let index = id.index();
+        if index >= self.entries.len() {
+            self.entries.resize_with(index + 1, || None);
+        }
+
+        let entry = &mut self.entries[index];
+        if entry.is_none() {
+            self.len += 1;
+        }
+        entry.get_or_insert_with(default)
+    }
+
+    /// Returns `true` if the node has an entry in the table.
+    #[inline]
+    #[must_use]
+    pub fn contains(&self, id: NodeId) -> bool {
+        self.get(id).is_some()
+    }
+
+    /// Removes the entry of a node, returning it.
+    pub fn remove(&mut self, id: NodeId) -> Option<T> {
+        let removed = self.entries.get_mut(id.index())?.take();
+        if removed.is_some() {
+            self.len -= 1;
+        }
+        removed
+    }
+
+    /// Removes all the entries of the table.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.len = 0;
+    }
+
+    /// Gets an iterator over the entries of the table, ordered by [`NodeId`].
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn iter(&self) -> impl Iterator<Item = (NodeId, &T)> {
+        self.entries
+            .iter()
+            .enumerate()
+            .filter_map(|(index, entry)| Some((NodeId::new(index as u32), entry.as_ref()?)))
+    }
+}
+
+impl<T> FromIterator<(NodeId, T)> for SideTable<T> {
+    fn from_iter<I: IntoIterator<Item = (NodeId, T)>>(iter: I) -> Self {
+        let mut table = Self::new();
+        for (id, value) in iter {
+            table.insert(id, value);
+        }
+        table
+    }
+}
+
+/// Assigns a [`NodeId`] to `node` and to all of its descendants, in pre-order, returning the
+/// number of assigned identifiers.
+///
+/// Identifiers that were already assigned are overwritten, so calling it again on the same tree
+/// gives the same identifiers.
+pub fn assign_node_ids<'a, N>(node: &'a mut N) -> usize
+where
+    &'a mut N: Into<NodeRefMut<'a>>,
+{
+    let mut assigner = NodeIdAssigner { next: 0 };
+    let _ = assigner.visit(node);
+    assigner.next as usize
+}
+
+/// Generates the accessors of the [`NodeId`]s of the struct nodes, and the visitor that assigns
+/// them.
+macro_rules! node_ids {
+    ($($Node:ident => $visit:ident),* $(,)?) => {
+        $(
+            impl $Node {
+                #[doc = concat!(
+                    "Gets the [`NodeId`] of the `", stringify!($Node), "` node, if it was assigned one."
+                )]
+                #[inline]
+                #[must_use]
+                pub const fn id(&self) -> Option<NodeId> {
+                    self.id.0
+                }
+            }
+        )*
+
+        impl NodeRef<'_> {
+            /// Gets the [`NodeId`] of the referenced node.
+            ///
+            /// Returns `None` if the node was not assigned one, or if it is an enum node, which
+            /// is identified by the node it wraps instead.
+            #[must_use]
+            pub fn id(self) -> Option<NodeId> {
+                match self {
+                    Self::Script(node) => node.id(),
+                    Self::Module(node) => node.id(),
+                    Self::ClassElement(node) => node.id(),
+                    Self::PropertyDefinition(node) => node.id(),
+                    $(Self::$Node(node) => node.id(),)*
+                    _ => None,
+                }
+            }
+        }
+
+        impl<'ast> VisitorMut<'ast> for NodeIdAssigner {
+            type BreakTy = ();
+
+            // The statements of scripts and the items of modules are not visited through the
+            // visitor, so they are assigned their identifiers here.
+            fn visit_script_mut(&mut self, node: &'ast mut Script) -> ControlFlow<Self::BreakTy> {
+                self.assign(&mut node.id);
+                self.visit_statement_list_mut(node.statements_mut())
+            }
+
+            fn visit_module_mut(&mut self, node: &'ast mut Module) -> ControlFlow<Self::BreakTy> {
+                self.assign(&mut node.id);
+                self.visit_module_item_list_mut(&mut node.items)
+            }
+
+            fn visit_class_element_mut(
+                &mut self,
+                node: &'ast mut ClassElement,
+            ) -> ControlFlow<Self::BreakTy> {
+                self.assign(node.id_slot_mut());
+                node.visit_with_mut(self)
+            }
+
+            fn visit_property_definition_mut(
+                &mut self,
+                node: &'ast mut PropertyDefinition,
+            ) -> ControlFlow<Self::BreakTy> {
+                if let Some(slot) = node.id_slot_mut() {
+                    self.assign(slot);
+                }
+                node.visit_with_mut(self)
+            }
+
+            $(
+                fn $visit(&mut self, node: &'ast mut $Node) -> ControlFlow<Self::BreakTy> {
+                    self.assign(&mut node.id);
+                    node.visit_with_mut(self)
+                }
+            )*
+        }
+    };
+}
+
+/// Visitor that assigns the [`NodeId`]s of the nodes, in the order in which they are visited.
+struct NodeIdAssigner {
+    next: u32,
+}
+
+impl NodeIdAssigner {
+    fn assign(&mut self, slot: &mut NodeIdSlot) {
+        slot.0 = Some(NodeId::new(self.next));
+        self.next += 1;
+    }
+}
+
+impl Script {
+    /// Gets the [`NodeId`] of the `Script` node, if it was assigned one.
+    #[inline]
+    #[must_use]
+    pub const fn id(&self) -> Option<NodeId> {
+        self.id.0
+    }
+}
+
+impl Module {
+    /// Gets the [`NodeId`] of the `Module` node, if it was assigned one.
+    #[inline]
+    #[must_use]
+    pub const fn id(&self) -> Option<NodeId> {
+        self.id.0
+    }
+}
+
+impl ClassElement {
+    /// Gets the [`NodeId`] of the `ClassElement` node, if it was assigned one.
+    #[inline]
+    #[must_use]
+    pub const fn id(&self) -> Option<NodeId> {
+        match self {
+            Self::MethodDefinition(method) => method.id.0,
+            Self::FieldDefinition(field) | Self::StaticFieldDefinition(field) => field.id.0,
+            Self::PrivateFieldDefinition(field) | Self::PrivateStaticFieldDefinition(field) => {
+                field.id.0
+            }
+            Self::StaticBlock(block) => block.id.0,
+        }
+    }
+
+    fn id_slot_mut(&mut self) -> &mut NodeIdSlot {
+        match self {
+            Self::MethodDefinition(method) => &mut method.id,
+            Self::FieldDefinition(field) | Self::StaticFieldDefinition(field) => &mut field.id,
+            Self::PrivateFieldDefinition(field) | Self::PrivateStaticFieldDefinition(field) => {
+                &mut field.id
+            }
+            Self::StaticBlock(block) => &mut block.id,
+        }
+    }
+}
+
+impl PropertyDefinition {
+    /// Gets the [`NodeId`] of the `PropertyDefinition` node, if it was assigned one.
+    ///
+    /// Shorthand properties and methods are identified by the [`Identifier`] and the
+    /// [`ObjectMethodDefinition`] they wrap.
+    #[inline]
+    #[must_use]
+    pub const fn id(&self) -> Option<NodeId> {
+        match self {
+            Self::IdentifierReference(ident) => ident.id(),
+            Self::MethodDefinition(method) => method.id(),
+            Self::Property(_, _, id)
+            | Self::SpreadObject(_, id)
+            | Self::CoverInitializedName(_, _, id) => id.0,
+        }
+    }
+
+    fn id_slot_mut(&mut self) -> Option<&mut NodeIdSlot> {
+        match self {
+            Self::IdentifierReference(_) | Self::MethodDefinition(_) => None,
+            Self::Property(_, _, id)
+            | Self::SpreadObject(_, id)
+            | Self::CoverInitializedName(_, _, id) => Some(id),
+        }
+    }
+}
+
+node_ids! {
+    FunctionBody => visit_function_body_mut,
+    StatementList => visit_statement_list_mut,
+    FunctionExpression => visit_function_expression_mut,
+    FunctionDeclaration => visit_function_declaration_mut,
+    GeneratorExpression => visit_generator_expression_mut,
+    GeneratorDeclaration => visit_generator_declaration_mut,
+    AsyncFunctionExpression => visit_async_function_expression_mut,
+    AsyncFunctionDeclaration => visit_async_function_declaration_mut,
+    AsyncGeneratorExpression => visit_async_generator_expression_mut,
+    AsyncGeneratorDeclaration => visit_async_generator_declaration_mut,
+    ClassExpression => visit_class_expression_mut,
+    ClassDeclaration => visit_class_declaration_mut,
+    Block => visit_block_mut,
+    If => visit_if_mut,
+    DoWhileLoop => visit_do_while_loop_mut,
+    WhileLoop => visit_while_loop_mut,
+    ForLoop => visit_for_loop_mut,
+    ForInLoop => visit_for_in_loop_mut,
+    ForOfLoop => visit_for_of_loop_mut,
+    Switch => visit_switch_mut,
+    Continue => visit_continue_mut,
+    Break => visit_break_mut,
+    Return => visit_return_mut,
+    Labelled => visit_labelled_mut,
+    With => visit_with_mut,
+    Throw => visit_throw_mut,
+    Try => visit_try_mut,
+    This => visit_this_mut,
+    NewTarget => visit_new_target_mut,
+    ImportMeta => visit_import_meta_mut,
+    Identifier => visit_identifier_mut,
+    FormalParameterList => visit_formal_parameter_list_mut,
+    PrivateName => visit_private_name_mut,
+    VariableList => visit_variable_list_mut,
+    Variable => visit_variable_mut,
+    Literal => visit_literal_mut,
+    RegExpLiteral => visit_reg_exp_literal_mut,
+    ArrayLiteral => visit_array_literal_mut,
+    ObjectLiteral => visit_object_literal_mut,
+    Spread => visit_spread_mut,
+    ArrowFunction => visit_arrow_function_mut,
+    AsyncArrowFunction => visit_async_arrow_function_mut,
+    TemplateLiteral => visit_template_literal_mut,
+    New => visit_new_mut,
+    Call => visit_call_mut,
+    SuperCall => visit_super_call_mut,
+    ImportCall => visit_import_call_mut,
+    Optional => visit_optional_mut,
+    TaggedTemplate => visit_tagged_template_mut,
+    Assign => visit_assign_mut,
+    Unary => visit_unary_mut,
+    Update => visit_update_mut,
+    Binary => visit_binary_mut,
+    BinaryInPrivate => visit_binary_in_private_mut,
+    Conditional => visit_conditional_mut,
+    Await => visit_await_mut,
+    Yield => visit_yield_mut,
+    Parenthesized => visit_parenthesized_mut,
+    Case => visit_case_mut,
+    Catch => visit_catch_mut,
+    Finally => visit_finally_mut,
+    FormalParameter => visit_formal_parameter_mut,
+    ObjectMethodDefinition => visit_object_method_definition_mut,
+    ObjectPattern => visit_object_pattern_mut,
+    ArrayPattern => visit_array_pattern_mut,
+    SimplePropertyAccess => visit_simple_property_access_mut,
+    PrivatePropertyAccess => visit_private_property_access_mut,
+    SuperPropertyAccess => visit_super_property_access_mut,
+    OptionalOperation => visit_optional_operation_mut,
+    ModuleItemList => visit_module_item_list_mut,
+    ModuleSpecifier => visit_module_specifier_mut,
+    ImportDeclaration => visit_import_declaration_mut,
+    ImportSpecifier => visit_import_specifier_mut,
+    ExportSpecifier => visit_export_specifier_mut,
+}
@@ -23,13 +23,15 @@ use crate::{
         AsyncGeneratorDeclaration, AsyncGeneratorExpression, ClassDeclaration, ClassElement,
         ClassElementName, ClassExpression, FormalParameterList, FunctionBody, FunctionDeclaration,
         FunctionExpression, GeneratorDeclaration, GeneratorExpression, PrivateFieldDefinition,
+        PrivateName,
     },
+    property::PropertyName,
     statement::{
         iteration::{ForLoopInitializer, IterableLoopInitializer},
         LabelledItem, With,
     },
     visitor::{NodeRef, VisitWith, Visitor},
-    Declaration, Expression, LinearSpan, ModuleItem, Script, Statement, StatementList,
+    Declaration, Expression, LinearSpan, ModuleItem, Script, Span, Statement, StatementList,
     StatementListItem,
 };
 
@@ -339,15 +341,28 @@ pub fn contains_arguments<N>(node: &N) -> bool
 where
     N: VisitWith,
 {
-    /// Visitor used by the function to search for an identifier with the name `arguments`.
+    contains_identifier(node, Sym::ARGUMENTS)
+}
+
+/// Returns true if the node contains an identifier with the name `name`, without looking into
+/// nested functions other than arrow functions.
+///
+/// This is used to find the identifiers that would be reserved words in a cover grammar, like
+/// `await` in the parameters of an async arrow function.
+#[must_use]
+pub fn contains_identifier<N>(node: &N, name: Sym) -> bool
+where
+    N: VisitWith,
+{
+    /// Visitor used by the function to search for an identifier with the given name.
     #[derive(Debug, Clone, Copy)]
-    struct ContainsArgsVisitor;
+    struct ContainsIdentifierVisitor(Sym);
 
-    impl<'ast> Visitor<'ast> for ContainsArgsVisitor {
+    impl<'ast> Visitor<'ast> for ContainsIdentifierVisitor {
         type BreakTy = ();
 
         fn visit_identifier(&mut self, node: &'ast Identifier) -> ControlFlow<Self::BreakTy> {
-            if node.sym() == Sym::ARGUMENTS {
+            if node.sym() == self.0 {
                 ControlFlow::Break(())
             } else {
                 ControlFlow::Continue(())
@@ -430,7 +445,8 @@ where
             }
         }
     }
-    node.visit_with(&mut ContainsArgsVisitor).is_break()
+    node.visit_with(&mut ContainsIdentifierVisitor(name))
+        .is_break()
 }
 
 /// Returns `true` if `method` has a super call in its parameters or body.
@@ -1155,15 +1171,32 @@ pub fn all_private_identifiers_valid<'a, N>(node: &'a N, private_names: Vec<Sym>
 where
     &'a N: Into<NodeRef<'a>>,
 {
-    AllPrivateIdentifiersValidVisitor(private_names)
-        .visit(node.into())
-        .is_continue()
+    first_invalid_private_identifier(node, private_names).is_none()
+}
+
+/// Returns the first private identifier in a node that does not refer to a declared private name.
+///
+/// This is like [`all_private_identifiers_valid`], but returns the offending [`PrivateName`], to
+/// report the location of the invalid reference.
+#[must_use]
+#[inline]
+pub fn first_invalid_private_identifier<'a, N>(
+    node: &'a N,
+    private_names: Vec<Sym>,
+) -> Option<PrivateName>
+where
+    &'a N: Into<NodeRef<'a>>,
+{
+    match AllPrivateIdentifiersValidVisitor(private_names).visit(node.into()) {
+        ControlFlow::Break(name) => Some(name),
+        ControlFlow::Continue(()) => None,
+    }
 }
 
 struct AllPrivateIdentifiersValidVisitor(Vec<Sym>);
 
 impl<'ast> Visitor<'ast> for AllPrivateIdentifiersValidVisitor {
-    type BreakTy = ();
+    type BreakTy = PrivateName;
 
     fn visit_class_expression(
         &mut self,
@@ -1310,7 +1343,7 @@ impl<'ast> Visitor<'ast> for AllPrivateIdentifiersValidVisitor {
         if self.0.contains(&node.field().description()) {
             self.visit(node.target())
         } else {
-            ControlFlow::Break(())
+            ControlFlow::Break(node.field())
         }
     }
 
@@ -1321,7 +1354,7 @@ impl<'ast> Visitor<'ast> for AllPrivateIdentifiersValidVisitor {
         if self.0.contains(&node.lhs().description()) {
             self.visit(node.rhs())
         } else {
-            ControlFlow::Break(())
+            ControlFlow::Break(*node.lhs())
         }
     }
 
@@ -1337,7 +1370,7 @@ impl<'ast> Visitor<'ast> for AllPrivateIdentifiersValidVisitor {
                 if self.0.contains(&field.description()) {
                     ControlFlow::Continue(())
                 } else {
-                    ControlFlow::Break(())
+                    ControlFlow::Break(*field)
                 }
             }
             OptionalOperationKind::Call { args } => {
@@ -1699,9 +1732,52 @@ where
     }
 }
 
-/// Returns `true` if the given node contains a `CoverInitializedName`.
+/// An invalid object literal found by [`contains_invalid_object_literal`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InvalidObjectLiteralError {
+    /// A `CoverInitializedName` (e.g. `{ a = 1 }`) was used outside of a destructuring pattern.
+    CoverInitializedName(Span),
+
+    /// A `__proto__` property was defined more than once in the same object literal.
+    DuplicateProto(Span),
+}
+
+impl InvalidObjectLiteralError {
+    /// Returns the span of the offending property definition.
+    #[must_use]
+    pub const fn span(&self) -> Span {
+        match self {
+            Self::CoverInitializedName(span) | Self::DuplicateProto(span) => *span,
+        }
+    }
+
+    /// Returns an error message based on the error.
+    #[must_use]
+    pub const fn message(&self) -> &'static str {
+        match self {
+            Self::CoverInitializedName(_) => "invalid shorthand property initializer",
+            Self::DuplicateProto(_) => {
+                "Duplicate __proto__ fields are not allowed in object literals."
+            }
+        }
+    }
+}
+
+/// Returns the first object literal in the given node that is only valid as a destructuring
+/// pattern.
+///
+/// Object literals that are used as patterns are converted into patterns by the parser, so
+/// this is checked after parsing the whole statement list. The following early errors are
+/// checked:
+/// - An object literal containing a `CoverInitializedName`.
+/// - An object literal with duplicate `__proto__` properties.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-object-initializer-static-semantics-early-errors
 #[must_use]
-pub fn contains_invalid_object_literal<N>(node: &N) -> bool
+pub fn contains_invalid_object_literal<N>(node: &N) -> Option<InvalidObjectLiteralError>
 where
     N: VisitWith,
 {
@@ -1709,15 +1785,33 @@ where
     struct ContainsInvalidObjectLiteral {}
 
     impl<'ast> Visitor<'ast> for ContainsInvalidObjectLiteral {
-        type BreakTy = ();
+        type BreakTy = InvalidObjectLiteralError;
 
         fn visit_object_literal(
             &mut self,
             node: &'ast crate::expression::literal::ObjectLiteral,
         ) -> ControlFlow<Self::BreakTy> {
+            let mut has_proto = false;
             for pd in node.properties() {
-                if let PropertyDefinition::CoverInitializedName(..) = pd {
-                    return ControlFlow::Break(());
+                match pd {
+                    PropertyDefinition::CoverInitializedName(ident, _, _) => {
+                        return ControlFlow::Break(
+                            InvalidObjectLiteralError::CoverInitializedName(ident.span()),
+                        );
+                    }
+                    // Only literal property names count, so `["__proto__"]` and the
+                    // `__proto__` shorthand can be repeated.
+                    PropertyDefinition::Property(PropertyName::Literal(ident), _, _)
+                        if ident.sym() == Sym::__PROTO__ =>
+                    {
+                        if has_proto {
+                            return ControlFlow::Break(InvalidObjectLiteralError::DuplicateProto(
+                                ident.span(),
+                            ));
+                        }
+                        has_proto = true;
+                    }
+                    _ => {}
                 }
                 self.visit_property_definition(pd)?;
             }
@@ -1727,7 +1821,10 @@ where
 
     let mut visitor = ContainsInvalidObjectLiteral {};
 
-    node.visit_with(&mut visitor).is_break()
+    match node.visit_with(&mut visitor) {
+        ControlFlow::Break(error) => Some(error),
+        ControlFlow::Continue(()) => None,
+    }
 }
 
 /// The type of a lexically scoped declaration.
@@ -24,6 +24,7 @@
 
 use crate::{
     expression::{access::PropertyAccess, Identifier},
+    node_id::NodeIdSlot,
     property::PropertyName,
     visitor::{VisitWith, Visitor, VisitorMut},
     Expression, Span,
@@ -114,6 +115,7 @@ impl VisitWith for Pattern {
 pub struct ObjectPattern {
     elements: Box<[ObjectPatternElement]>,
     span: Span,
+    pub(crate) id: NodeIdSlot,
 }
 
 impl ToInternedString for ObjectPattern {
@@ -142,7 +144,11 @@ impl ObjectPattern {
     #[inline]
     #[must_use]
     pub const fn new(elements: Box<[ObjectPatternElement]>, span: Span) -> Self {
-        Self { elements, span }
+        Self {
+            elements,
+            span,
+            id: NodeIdSlot::EMPTY,
+        }
     }
 
     /// Gets the bindings for the object binding pattern.
@@ -207,6 +213,7 @@ impl VisitWith for ObjectPattern {
 pub struct ArrayPattern {
     bindings: Box<[ArrayPatternElement]>,
     span: Span,
+    pub(crate) id: NodeIdSlot,
 }
 
 impl ToInternedString for ArrayPattern {
@@ -236,7 +243,11 @@ impl ArrayPattern {
     #[inline]
     #[must_use]
     pub fn new(bindings: Box<[ArrayPatternElement]>, span: Span) -> Self {
-        Self { bindings, span }
+        Self {
+            bindings,
+            span,
+            id: NodeIdSlot::EMPTY,
+        }
     }
 
     /// Gets the bindings for the array binding pattern.
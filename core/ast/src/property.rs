@@ -4,6 +4,7 @@ use super::Expression;
 use crate::{
     expression::Identifier,
     visitor::{VisitWith, Visitor, VisitorMut},
+    Span,
 };
 use boa_interner::{Interner, ToInternedString};
 use core::ops::ControlFlow;
@@ -56,6 +57,17 @@ impl PropertyName {
         }
     }
 
+    /// Gets the span of the property name.
+    ///
+    /// For computed property names, this is the span of the expression inside the brackets.
+    #[must_use]
+    pub fn span(&self) -> Span {
+        match self {
+            Self::Literal(ident) => ident.span(),
+            Self::Computed(expr) => expr.span(),
+        }
+    }
+
     /// Returns either the literal property name or the computed const string property name.
     #[must_use]
     pub fn prop_name(&self) -> Option<Identifier> {
@@ -62,6 +62,8 @@ pub(crate) struct Inner {
     function: bool,
     // Has the `this` been accessed/escaped outside the function environment boundry.
     this_escaped: Cell<bool>,
+    // Can a direct `eval` call declare `var` bindings in this scope.
+    direct_eval: Cell<bool>,
 }
 
 impl Scope {
@@ -76,6 +78,7 @@ impl Scope {
                 bindings: RefCell::default(),
                 function: true,
                 this_escaped: Cell::new(false),
+                direct_eval: Cell::new(false),
             }),
         }
     }
@@ -92,6 +95,7 @@ impl Scope {
                 bindings: RefCell::default(),
                 function,
                 this_escaped: Cell::new(false),
+                direct_eval: Cell::new(false),
             }),
         }
     }
@@ -107,6 +111,16 @@ impl Scope {
             .all(|binding| !binding.escapes)
     }
 
+    /// Checks if any binding of this scope is accessed.
+    #[must_use]
+    pub(crate) fn any_binding_accessed(&self) -> bool {
+        self.inner
+            .bindings
+            .borrow()
+            .iter()
+            .any(|binding| binding.accessed)
+    }
+
     /// Marks all bindings in this scope as escaping.
     pub fn escape_all_bindings(&self) {
         for binding in self.inner.bindings.borrow_mut().iter_mut() {
@@ -131,6 +145,33 @@ impl Scope {
             .is_some_and(|binding| binding.lex)
     }
 
+    /// Marks that a direct `eval` call may declare `var` bindings in this scope.
+    pub(crate) fn set_direct_eval(&self) {
+        self.inner.direct_eval.set(true);
+    }
+
+    /// Checks if the binding of `locator`, declared in this scope or in one of its outer scopes,
+    /// is always the one found by a runtime lookup of its name from this scope.
+    ///
+    /// This is not the case if the binding is declared in, or outside of, a scope where a direct
+    /// `eval` call may declare `var` bindings, since they may replace or shadow it.
+    #[must_use]
+    pub fn is_binding_static(&self, locator: &BindingLocator) -> bool {
+        let mut current = self;
+        loop {
+            if current.inner.direct_eval.get() {
+                return false;
+            }
+            if current.inner.unique_id == locator.unique_scope_id {
+                return current.has_binding(locator.name());
+            }
+            match &current.inner.outer {
+                Some(outer) => current = outer,
+                None => return false,
+            }
+        }
+    }
+
     /// Check if the scope has a binding with the given name.
     #[must_use]
     pub fn has_binding(&self, name: &JsString) -> bool {
@@ -255,6 +296,22 @@ impl Scope {
             })
     }
 
+    /// Checks if `name` is bound in this scope, or in one of its outer scopes that are inside of
+    /// `boundary`.
+    pub(crate) fn has_binding_inside(&self, name: &JsString, boundary: &Self) -> bool {
+        let mut current = self;
+        while !Rc::ptr_eq(&current.inner, &boundary.inner) {
+            if current.has_binding(name) {
+                return true;
+            }
+            let Some(outer) = &current.inner.outer else {
+                return false;
+            };
+            current = outer;
+        }
+        false
+    }
+
     /// Simulate a binding access.
     ///
     /// - If the binding access crosses a function border, the binding is marked as escaping.
@@ -627,6 +684,9 @@ impl FunctionScopes {
     }
 
     /// Returns if the arguments object is accessed in this function.
+    ///
+    /// The arguments object is also considered accessed if its binding escapes,
+    /// because a direct `eval` may access it without naming it in the source.
     #[must_use]
     pub fn arguments_object_accessed(&self) -> bool {
         if self
@@ -635,7 +695,7 @@ impl FunctionScopes {
             .bindings
             .borrow()
             .first()
-            .filter(|b| b.name == "arguments" && b.accessed)
+            .filter(|b| b.name == "arguments" && (b.accessed || b.escapes))
             .is_some()
         {
             return true;
@@ -647,7 +707,7 @@ impl FunctionScopes {
                 .bindings
                 .borrow()
                 .first()
-                .filter(|b| b.name == "arguments" && b.accessed)
+                .filter(|b| b.name == "arguments" && (b.accessed || b.escapes))
                 .is_some()
             {
                 return true;
@@ -681,6 +741,17 @@ impl FunctionScopes {
         self.lexical_scope.as_ref()
     }
 
+    /// Marks that a direct `eval` call in this function may declare `var` bindings in its scopes.
+    pub(crate) fn set_direct_eval(&self) {
+        self.function_scope.set_direct_eval();
+        if let Some(scope) = &self.parameters_eval_scope {
+            scope.set_direct_eval();
+        }
+        if let Some(scope) = &self.parameters_scope {
+            scope.set_direct_eval();
+        }
+    }
+
     /// Returns the effective paramter scope for this function.
     #[must_use]
     pub fn parameter_scope(&self) -> Scope {
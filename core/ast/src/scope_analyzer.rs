@@ -12,7 +12,7 @@ use crate::{
     function::{
         ArrowFunction, AsyncArrowFunction, AsyncFunctionDeclaration, AsyncFunctionExpression,
         AsyncGeneratorDeclaration, AsyncGeneratorExpression, ClassDeclaration, ClassElement,
-        ClassExpression, FormalParameterList, FunctionBody, FunctionDeclaration,
+        ClassElementName, ClassExpression, FormalParameterList, FunctionBody, FunctionDeclaration,
         FunctionExpression, GeneratorDeclaration, GeneratorExpression,
     },
     operations::{
@@ -27,7 +27,7 @@ use crate::{
         Block, Catch, ForInLoop, ForLoop, ForOfLoop, Switch, With,
     },
     visitor::{NodeRef, NodeRefMut, VisitorMut},
-    Declaration, Module, Script, StatementListItem, ToJsString,
+    Declaration, Expression, Module, Script, StatementListItem, ToJsString,
 };
 use boa_interner::{Interner, Sym};
 use rustc_hash::FxHashMap;
@@ -69,7 +69,7 @@ where
     let mut visitor = BindingEscapeAnalyzer {
         scope,
         direct_eval: in_eval,
-        with: false,
+        with: None,
         interner,
     };
     !visitor.visit(node.into()).is_break()
@@ -78,7 +78,8 @@ where
 struct BindingEscapeAnalyzer<'interner> {
     scope: Scope,
     direct_eval: bool,
-    with: bool,
+    /// The scope of the innermost `with` statement, if any.
+    with: Option<Scope>,
     interner: &'interner Interner,
 }
 
@@ -87,8 +88,12 @@ impl<'ast> VisitorMut<'ast> for BindingEscapeAnalyzer<'_> {
 
     fn visit_identifier_mut(&mut self, node: &'ast mut Identifier) -> ControlFlow<Self::BreakTy> {
         let name = node.to_js_string(self.interner);
-        self.scope
-            .access_binding(&name, self.direct_eval || self.with);
+        // Bindings declared inside of a `with` statement cannot be shadowed by its object.
+        let with = self
+            .with
+            .as_ref()
+            .is_some_and(|with| !self.scope.has_binding_inside(&name, with));
+        self.scope.access_binding(&name, self.direct_eval || with);
         ControlFlow::Continue(())
     }
 
@@ -133,12 +138,11 @@ impl<'ast> VisitorMut<'ast> for BindingEscapeAnalyzer<'_> {
     }
 
     fn visit_with_mut(&mut self, node: &'ast mut With) -> ControlFlow<Self::BreakTy> {
-        let with = self.with;
-        self.with = true;
         if self.direct_eval {
             node.scope.escape_all_bindings();
         }
         self.visit_expression_mut(&mut node.expression)?;
+        let with = self.with.replace(node.scope.clone());
         std::mem::swap(&mut self.scope, &mut node.scope);
         self.visit_statement_mut(&mut node.statement)?;
         std::mem::swap(&mut self.scope, &mut node.scope);
@@ -379,7 +383,6 @@ impl<'ast> VisitorMut<'ast> for BindingEscapeAnalyzer<'_> {
         &mut self,
         node: &'ast mut ClassDeclaration,
     ) -> ControlFlow<Self::BreakTy> {
-        node.name_scope.escape_all_bindings();
         std::mem::swap(&mut self.scope, &mut node.name_scope);
         if let Some(super_ref) = &mut node.super_ref {
             self.visit_expression_mut(super_ref)?;
@@ -391,6 +394,16 @@ impl<'ast> VisitorMut<'ast> for BindingEscapeAnalyzer<'_> {
             self.visit_class_element_mut(element)?;
         }
         std::mem::swap(&mut self.scope, &mut node.name_scope);
+        if self.direct_eval
+            || node.name_scope.any_binding_accessed()
+            || class_contains_direct_eval(
+                node.super_ref.as_ref(),
+                node.constructor.as_ref(),
+                &node.elements,
+            )
+        {
+            node.name_scope.escape_all_bindings();
+        }
         node.name_scope.reorder_binding_indices();
         ControlFlow::Continue(())
     }
@@ -400,10 +413,6 @@ impl<'ast> VisitorMut<'ast> for BindingEscapeAnalyzer<'_> {
         node: &'ast mut ClassExpression,
     ) -> ControlFlow<Self::BreakTy> {
         if let Some(name_scope) = &mut node.name_scope {
-            if self.direct_eval {
-                name_scope.escape_all_bindings();
-            }
-            name_scope.escape_all_bindings();
             std::mem::swap(&mut self.scope, name_scope);
         }
         if let Some(super_ref) = &mut node.super_ref {
@@ -417,6 +426,16 @@ impl<'ast> VisitorMut<'ast> for BindingEscapeAnalyzer<'_> {
         }
         if let Some(name_scope) = &mut node.name_scope {
             std::mem::swap(&mut self.scope, name_scope);
+            if self.direct_eval
+                || name_scope.any_binding_accessed()
+                || class_contains_direct_eval(
+                    node.super_ref.as_ref(),
+                    node.constructor.as_ref(),
+                    &node.elements,
+                )
+            {
+                name_scope.escape_all_bindings();
+            }
             name_scope.reorder_binding_indices();
         }
         ControlFlow::Continue(())
@@ -435,16 +454,28 @@ impl<'ast> VisitorMut<'ast> for BindingEscapeAnalyzer<'_> {
             ),
             ClassElement::FieldDefinition(field) | ClassElement::StaticFieldDefinition(field) => {
                 self.visit_property_name_mut(&mut field.name)?;
+                // The initializer is evaluated in its own function, so the bindings it accesses
+                // escape.
+                std::mem::swap(&mut self.scope, &mut field.scope);
                 if let Some(e) = &mut field.initializer {
+                    if contains(&*e, ContainsSymbol::DirectEval) {
+                        self.scope.set_direct_eval();
+                    }
                     self.visit_expression_mut(e)?;
                 }
+                std::mem::swap(&mut self.scope, &mut field.scope);
                 ControlFlow::Continue(())
             }
             ClassElement::PrivateFieldDefinition(field)
             | ClassElement::PrivateStaticFieldDefinition(field) => {
+                std::mem::swap(&mut self.scope, &mut field.scope);
                 if let Some(e) = &mut field.initializer {
+                    if contains(&*e, ContainsSymbol::DirectEval) {
+                        self.scope.set_direct_eval();
+                    }
                     self.visit_expression_mut(e)?;
                 }
+                std::mem::swap(&mut self.scope, &mut field.scope);
                 ControlFlow::Continue(())
             }
             ClassElement::StaticBlock(node) => {
@@ -535,6 +566,9 @@ impl BindingEscapeAnalyzer<'_> {
         if self.direct_eval {
             scopes.escape_all_bindings();
         }
+        if contains_direct_eval {
+            scopes.set_direct_eval();
+        }
         let mut scope = scopes.parameter_scope();
         std::mem::swap(&mut self.scope, &mut scope);
         self.visit_formal_parameter_list_mut(parameters)?;
@@ -557,6 +591,40 @@ impl BindingEscapeAnalyzer<'_> {
     }
 }
 
+/// Checks if a class contains a direct `eval` call that could access the binding of its name.
+///
+/// Unlike [`contains`], this also looks into the methods and the field initializers of the class.
+fn class_contains_direct_eval(
+    super_ref: Option<&Expression>,
+    constructor: Option<&FunctionExpression>,
+    elements: &[ClassElement],
+) -> bool {
+    let contains_direct_eval = |node: &Expression| contains(node, ContainsSymbol::DirectEval);
+    let computed_name_contains_direct_eval = |name: &PropertyName| matches!(name, PropertyName::Computed(name) if contains_direct_eval(name));
+
+    super_ref.is_some_and(contains_direct_eval)
+        || constructor.is_some_and(FunctionExpression::contains_direct_eval)
+        || elements.iter().any(|element| match element {
+            ClassElement::MethodDefinition(method) => {
+                method.contains_direct_eval
+                    || matches!(
+                        method.name(),
+                        ClassElementName::PropertyName(name)
+                            if computed_name_contains_direct_eval(name)
+                    )
+            }
+            ClassElement::FieldDefinition(field) | ClassElement::StaticFieldDefinition(field) => {
+                computed_name_contains_direct_eval(&field.name)
+                    || field.initializer.as_ref().is_some_and(contains_direct_eval)
+            }
+            ClassElement::PrivateFieldDefinition(field)
+            | ClassElement::PrivateStaticFieldDefinition(field) => {
+                field.initializer.as_ref().is_some_and(contains_direct_eval)
+            }
+            ClassElement::StaticBlock(block) => contains(&block.body, ContainsSymbol::DirectEval),
+        })
+}
+
 struct BindingCollectorVisitor<'interner> {
     strict: bool,
     eval: bool,
@@ -3,6 +3,7 @@ use std::ops::ControlFlow;
 use boa_interner::{Interner, Sym, ToIndentedString};
 
 use crate::{
+    node_id::NodeIdSlot,
     scope::Scope,
     scope_analyzer::{
         analyze_binding_escapes, collect_bindings, eval_declaration_instantiation_scope,
@@ -22,13 +23,17 @@ use crate::{
 #[derive(Clone, Debug, Default)]
 pub struct Script {
     statements: StatementList,
+    pub(crate) id: NodeIdSlot,
 }
 
 impl Script {
     /// Creates a new `ScriptNode`.
     #[must_use]
     pub const fn new(statements: StatementList) -> Self {
-        Self { statements }
+        Self {
+            statements,
+            id: NodeIdSlot::EMPTY,
+        }
     }
 
     /// Gets the list of statements of this `ScriptNode`.
@@ -131,7 +136,10 @@ impl PartialEq for Script {
 impl<'a> arbitrary::Arbitrary<'a> for Script {
     fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
         let statements = StatementList::arbitrary(u)?;
-        Ok(Self { statements })
+        Ok(Self {
+            statements,
+            id: NodeIdSlot::EMPTY,
+        })
     }
 }
 
@@ -148,6 +156,7 @@ pub struct Module {
 
     #[cfg_attr(feature = "serde", serde(skip))]
     pub(crate) scope: Scope,
+    pub(crate) id: NodeIdSlot,
 }
 
 impl Module {
@@ -157,6 +166,7 @@ impl Module {
         Self {
             items,
             scope: Scope::default(),
+            id: NodeIdSlot::EMPTY,
         }
     }
 
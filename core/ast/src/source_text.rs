@@ -62,6 +62,14 @@ impl SourceText {
         self.push(cu2);
     }
 
+    /// Collect a run of ASCII characters.
+    #[inline]
+    pub fn collect_ascii(&mut self, ascii: &[u8]) {
+        debug_assert!(ascii.is_ascii());
+        self.source_text
+            .extend(ascii.iter().copied().map(u16::from));
+    }
+
     #[inline]
     fn push(&mut self, cp: u16) {
         self.source_text.push(cp);
@@ -1,6 +1,7 @@
 //! Block AST node.
 
 use crate::{
+    node_id::NodeIdSlot,
     operations::{contains, ContainsSymbol},
     scope::Scope,
     visitor::{VisitWith, Visitor, VisitorMut},
@@ -34,6 +35,7 @@ pub struct Block {
 
     #[cfg_attr(feature = "serde", serde(skip))]
     pub(crate) scope: Option<Scope>,
+    pub(crate) id: NodeIdSlot,
 }
 
 impl Block {
@@ -63,6 +65,7 @@ where
             statements,
             scope: None,
             contains_direct_eval,
+            id: NodeIdSlot::EMPTY,
         }
     }
 }
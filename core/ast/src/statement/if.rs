@@ -2,6 +2,7 @@
 
 use crate::{
     expression::Expression,
+    node_id::NodeIdSlot,
     statement::Statement,
     visitor::{VisitWith, Visitor, VisitorMut},
 };
@@ -31,6 +32,7 @@ pub struct If {
     condition: Expression,
     body: Box<Statement>,
     else_node: Option<Box<Statement>>,
+    pub(crate) id: NodeIdSlot,
 }
 
 impl If {
@@ -62,6 +64,7 @@ impl If {
             condition,
             body: body.into(),
             else_node: else_node.map(Box::new),
+            id: NodeIdSlot::EMPTY,
         }
     }
 }
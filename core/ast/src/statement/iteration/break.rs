@@ -2,7 +2,7 @@ use boa_interner::{Interner, Sym, ToInternedString};
 use core::ops::ControlFlow;
 
 use crate::visitor::{VisitWith, Visitor, VisitorMut};
-use crate::Statement;
+use crate::{node_id::NodeIdSlot, Statement};
 
 /// The `break` statement terminates the current loop, switch, or label statement and transfers
 /// program control to the statement following the terminated statement.
@@ -23,13 +23,17 @@ use crate::Statement;
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Break {
     label: Option<Sym>,
+    pub(crate) id: NodeIdSlot,
 }
 
 impl Break {
     /// Creates a `Break` AST node.
     #[must_use]
     pub const fn new(label: Option<Sym>) -> Self {
-        Self { label }
+        Self {
+            label,
+            id: NodeIdSlot::EMPTY,
+        }
     }
 
     /// Gets the label of the break statement, if any.
@@ -1,5 +1,5 @@
-use crate::statement::Statement;
 use crate::visitor::{VisitWith, Visitor, VisitorMut};
+use crate::{node_id::NodeIdSlot, statement::Statement};
 use boa_interner::{Interner, Sym, ToInternedString};
 use core::ops::ControlFlow;
 
@@ -21,13 +21,17 @@ use core::ops::ControlFlow;
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct Continue {
     label: Option<Sym>,
+    pub(crate) id: NodeIdSlot,
 }
 
 impl Continue {
     /// Creates a `Continue` AST node.
     #[must_use]
     pub const fn new(label: Option<Sym>) -> Self {
-        Self { label }
+        Self {
+            label,
+            id: NodeIdSlot::EMPTY,
+        }
     }
 
     /// Gets the label of this `Continue` statement.
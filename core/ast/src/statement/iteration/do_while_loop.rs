@@ -1,5 +1,6 @@
 use crate::{
     expression::Expression,
+    node_id::NodeIdSlot,
     statement::Statement,
     visitor::{VisitWith, Visitor, VisitorMut},
 };
@@ -24,6 +25,7 @@ use core::ops::ControlFlow;
 pub struct DoWhileLoop {
     body: Box<Statement>,
     condition: Expression,
+    pub(crate) id: NodeIdSlot,
 }
 
 impl DoWhileLoop {
@@ -47,6 +49,7 @@ impl DoWhileLoop {
         Self {
             body: body.into(),
             condition,
+            id: NodeIdSlot::EMPTY,
         }
     }
 }
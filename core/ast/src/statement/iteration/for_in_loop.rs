@@ -3,6 +3,7 @@ use crate::scope::Scope;
 use crate::visitor::{VisitWith, Visitor, VisitorMut};
 use crate::{
     expression::Expression,
+    node_id::NodeIdSlot,
     statement::{iteration::IterableLoopInitializer, Statement},
 };
 use boa_interner::{Interner, ToIndentedString, ToInternedString};
@@ -30,6 +31,7 @@ pub struct ForInLoop {
 
     #[cfg_attr(feature = "serde", serde(skip))]
     pub(crate) scope: Option<Scope>,
+    pub(crate) id: NodeIdSlot,
 }
 
 impl ForInLoop {
@@ -48,6 +50,7 @@ impl ForInLoop {
             contains_direct_eval,
             target_scope: None,
             scope: None,
+            id: NodeIdSlot::EMPTY,
         }
     }
 
@@ -3,6 +3,7 @@ use crate::scope::Scope;
 use crate::visitor::{VisitWith, Visitor, VisitorMut};
 use crate::{
     declaration::{LexicalDeclaration, VarDeclaration},
+    node_id::NodeIdSlot,
     statement::Statement,
     Expression,
 };
@@ -25,6 +26,7 @@ use core::{fmt::Write as _, ops::ControlFlow};
 pub struct ForLoop {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub(crate) inner: Box<InnerForLoop>,
+    pub(crate) id: NodeIdSlot,
 }
 
 impl ForLoop {
@@ -39,6 +41,7 @@ impl ForLoop {
     ) -> Self {
         Self {
             inner: Box::new(InnerForLoop::new(init, condition, final_expr, body)),
+            id: NodeIdSlot::EMPTY,
         }
     }
 
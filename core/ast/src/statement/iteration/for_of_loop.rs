@@ -3,6 +3,7 @@ use crate::scope::Scope;
 use crate::visitor::{VisitWith, Visitor, VisitorMut};
 use crate::{
     expression::Expression,
+    node_id::NodeIdSlot,
     statement::{iteration::IterableLoopInitializer, Statement},
 };
 use boa_interner::{Interner, ToIndentedString, ToInternedString};
@@ -36,6 +37,7 @@ pub struct ForOfLoop {
 
     #[cfg_attr(feature = "serde", serde(skip))]
     pub(crate) scope: Option<Scope>,
+    pub(crate) id: NodeIdSlot,
 }
 
 impl ForOfLoop {
@@ -60,6 +62,7 @@ impl ForOfLoop {
             r#await,
             iterable_scope: None,
             scope: None,
+            id: NodeIdSlot::EMPTY,
         }
     }
 
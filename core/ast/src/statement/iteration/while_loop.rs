@@ -1,5 +1,6 @@
 use crate::{
     expression::Expression,
+    node_id::NodeIdSlot,
     statement::Statement,
     visitor::{VisitWith, Visitor, VisitorMut},
 };
@@ -23,6 +24,7 @@ use core::ops::ControlFlow;
 pub struct WhileLoop {
     condition: Expression,
     body: Box<Statement>,
+    pub(crate) id: NodeIdSlot,
 }
 
 impl WhileLoop {
@@ -33,6 +35,7 @@ impl WhileLoop {
         Self {
             condition,
             body: body.into(),
+            id: NodeIdSlot::EMPTY,
         }
     }
 
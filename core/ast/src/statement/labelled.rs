@@ -1,5 +1,6 @@
 use crate::{
     function::FunctionDeclaration,
+    node_id::NodeIdSlot,
     visitor::{VisitWith, Visitor, VisitorMut},
     Statement,
 };
@@ -88,6 +89,7 @@ impl VisitWith for LabelledItem {
 pub struct Labelled {
     item: Box<LabelledItem>,
     label: Sym,
+    pub(crate) id: NodeIdSlot,
 }
 
 impl Labelled {
@@ -98,6 +100,7 @@ impl Labelled {
         Self {
             item: Box::new(item),
             label,
+            id: NodeIdSlot::EMPTY,
         }
     }
 
@@ -1,5 +1,6 @@
 use crate::{
     expression::Expression,
+    node_id::NodeIdSlot,
     statement::Statement,
     visitor::{VisitWith, Visitor, VisitorMut},
 };
@@ -28,6 +29,7 @@ use core::ops::ControlFlow;
 #[derive(Clone, Debug, PartialEq)]
 pub struct Return {
     target: Option<Expression>,
+    pub(crate) id: NodeIdSlot,
 }
 
 impl Return {
@@ -40,7 +42,10 @@ impl Return {
     /// Creates a `Return` AST node.
     #[must_use]
     pub const fn new(expression: Option<Expression>) -> Self {
-        Self { target: expression }
+        Self {
+            target: expression,
+            id: NodeIdSlot::EMPTY,
+        }
     }
 }
 
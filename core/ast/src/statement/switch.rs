@@ -2,6 +2,7 @@
 
 use crate::{
     expression::Expression,
+    node_id::NodeIdSlot,
     operations::{contains, ContainsSymbol},
     scope::Scope,
     statement::Statement,
@@ -25,6 +26,7 @@ use core::{fmt::Write as _, ops::ControlFlow};
 pub struct Case {
     condition: Option<Expression>,
     body: StatementList,
+    pub(crate) id: NodeIdSlot,
 }
 
 impl Case {
@@ -35,6 +37,7 @@ impl Case {
         Self {
             condition: Some(condition),
             body,
+            id: NodeIdSlot::EMPTY,
         }
     }
 
@@ -45,6 +48,7 @@ impl Case {
         Self {
             condition: None,
             body,
+            id: NodeIdSlot::EMPTY,
         }
     }
 
@@ -122,6 +126,7 @@ pub struct Switch {
 
     #[cfg_attr(feature = "serde", serde(skip))]
     pub(crate) scope: Option<Scope>,
+    pub(crate) id: NodeIdSlot,
 }
 
 impl Switch {
@@ -138,6 +143,7 @@ impl Switch {
             cases,
             contains_direct_eval,
             scope: None,
+            id: NodeIdSlot::EMPTY,
         }
     }
 
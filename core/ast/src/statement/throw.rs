@@ -1,4 +1,5 @@
 use crate::{
+    node_id::NodeIdSlot,
     statement::Statement,
     visitor::{VisitWith, Visitor, VisitorMut},
     Expression,
@@ -25,6 +26,7 @@ use core::ops::ControlFlow;
 #[derive(Clone, Debug, PartialEq)]
 pub struct Throw {
     target: Expression,
+    pub(crate) id: NodeIdSlot,
 }
 
 impl Throw {
@@ -37,7 +39,10 @@ impl Throw {
     /// Creates a `Throw` AST node.
     #[must_use]
     pub const fn new(target: Expression) -> Self {
-        Self { target }
+        Self {
+            target,
+            id: NodeIdSlot::EMPTY,
+        }
     }
 }
 
@@ -5,6 +5,7 @@ use crate::scope::Scope;
 use crate::visitor::{VisitWith, Visitor, VisitorMut};
 use crate::{
     declaration::Binding,
+    node_id::NodeIdSlot,
     statement::{Block, Statement},
 };
 use boa_interner::{Interner, ToIndentedString, ToInternedString};
@@ -29,6 +30,7 @@ use core::{fmt::Write as _, ops::ControlFlow};
 pub struct Try {
     block: Block,
     handler: ErrorHandler,
+    pub(crate) id: NodeIdSlot,
 }
 
 /// The type of error handler in a [`Try`] statement.
@@ -49,7 +51,11 @@ impl Try {
     #[inline]
     #[must_use]
     pub const fn new(block: Block, handler: ErrorHandler) -> Self {
-        Self { block, handler }
+        Self {
+            block,
+            handler,
+            id: NodeIdSlot::EMPTY,
+        }
     }
 
     /// Gets the `try` block.
@@ -149,6 +155,7 @@ pub struct Catch {
 
     #[cfg_attr(feature = "serde", serde(skip))]
     pub(crate) scope: Scope,
+    pub(crate) id: NodeIdSlot,
 }
 
 impl Catch {
@@ -165,6 +172,7 @@ impl Catch {
             block,
             contains_direct_eval,
             scope: Scope::default(),
+            id: NodeIdSlot::EMPTY,
         }
     }
 
@@ -234,6 +242,7 @@ impl VisitWith for Catch {
 #[derive(Clone, Debug, PartialEq)]
 pub struct Finally {
     block: Block,
+    pub(crate) id: NodeIdSlot,
 }
 
 impl Finally {
@@ -257,7 +266,10 @@ impl ToIndentedString for Finally {
 impl From<Block> for Finally {
     #[inline]
     fn from(block: Block) -> Self {
-        Self { block }
+        Self {
+            block,
+            id: NodeIdSlot::EMPTY,
+        }
     }
 }
 
@@ -1,5 +1,6 @@
 use crate::{
     expression::Expression,
+    node_id::NodeIdSlot,
     scope::Scope,
     statement::Statement,
     visitor::{VisitWith, Visitor, VisitorMut},
@@ -24,6 +25,7 @@ pub struct With {
 
     #[cfg_attr(feature = "serde", serde(skip))]
     pub(crate) scope: Scope,
+    pub(crate) id: NodeIdSlot,
 }
 
 impl With {
@@ -34,6 +36,7 @@ impl With {
             expression,
             statement: Box::new(statement),
             scope: Scope::default(),
+            id: NodeIdSlot::EMPTY,
         }
     }
 
@@ -2,6 +2,7 @@
 
 use super::Declaration;
 use crate::{
+    node_id::NodeIdSlot,
     statement::Statement,
     visitor::{VisitWith, Visitor, VisitorMut},
     LinearPosition,
@@ -101,6 +102,7 @@ pub struct StatementList {
     pub(crate) statements: Box<[StatementListItem]>,
     linear_pos_end: LinearPosition,
     strict: bool,
+    pub(crate) id: NodeIdSlot,
 }
 
 impl PartialEq for StatementList {
@@ -120,6 +122,7 @@ impl StatementList {
             statements: statements.into(),
             linear_pos_end,
             strict,
+            id: NodeIdSlot::EMPTY,
         }
     }
 
@@ -218,6 +221,7 @@ impl<'a> arbitrary::Arbitrary<'a> for StatementList {
             statements: u.arbitrary()?,
             linear_pos_end: LinearPosition::default(),
             strict: false, // disable strictness; this is *not* in source data
+            id: NodeIdSlot::EMPTY,
         })
     }
 }
@@ -0,0 +1,15 @@
+(function () {
+  const data = [];
+  for (let i = 0; i < 1000; i++) {
+    data.push({
+      id: i,
+      name: "item \"" + i + "\"",
+      price: i * 1.25,
+      active: i % 2 === 0,
+      tags: ["alpha", "beta", "gamma"],
+      dimensions: { width: i / 3, height: 10, depth: null },
+    });
+  }
+
+  return data;
+})();
@@ -0,0 +1,8 @@
+(function () {
+  var total = 0;
+  for (var i = 0; i < 100; i++) {
+    var s = String(i);
+    total += Number(s) + +s + parseInt(s, 10) + parseFloat(s + ".5");
+  }
+  return total;
+})();
@@ -3,8 +3,8 @@
 //! Benchmarks of the whole execution engine in Boa.
 
 use boa_engine::{
-    context::DefaultHooks, object::shape::RootShape, optimizer::OptimizerOptions, realm::Realm,
-    script::Script, Context, Source,
+    context::DefaultHooks, js_string, object::shape::RootShape, optimizer::OptimizerOptions,
+    realm::Realm, script::Script, Context, JsString, JsValue, Source,
 };
 use criterion::{criterion_group, criterion_main, Criterion};
 use std::hint::black_box;
@@ -23,6 +23,58 @@ fn create_realm(c: &mut Criterion) {
     });
 }
 
+fn json_stringify(c: &mut Criterion) {
+    static CODE: &str = include_str!("bench_scripts/json_data.js");
+
+    let context = &mut Context::default();
+    let data = context.eval(Source::from_bytes(CODE)).unwrap();
+    let json = context.intrinsics().objects().json();
+    let stringify = json.get(js_string!("stringify"), context).unwrap();
+    let stringify = stringify.as_callable().unwrap().clone();
+
+    c.bench_function("JSON.stringify (Execution)", |b| {
+        b.iter(|| {
+            stringify
+                .call(&JsValue::undefined(), &[black_box(data.clone())], context)
+                .unwrap()
+        });
+    });
+
+    // Serializing the same data with `serde_json`, as a baseline for the engine's serializer.
+    let value = data.to_json(context).unwrap().unwrap();
+    c.bench_function("JSON.stringify (serde_json)", |b| {
+        b.iter(|| serde_json::to_string(black_box(&value)).unwrap());
+    });
+}
+
+fn number_conversions(c: &mut Criterion) {
+    let strings = [
+        "42",
+        "-1234567",
+        "3.14159",
+        "  1e21\n",
+        "0x1F",
+        "12345678901234567890",
+    ]
+    .map(JsString::from);
+
+    c.bench_function("String to number", |b| {
+        b.iter(|| {
+            for string in &strings {
+                black_box(black_box(string).to_number());
+            }
+        });
+    });
+
+    c.bench_function("Small integer to string", |b| {
+        b.iter(|| {
+            for i in 0..256 {
+                black_box(JsString::from(black_box(i)));
+            }
+        });
+    });
+}
+
 macro_rules! full_benchmarks {
     ($({$id:literal, $name:ident}),*) => {
         fn bench_parser(c: &mut Criterion) {
@@ -115,6 +167,7 @@ full_benchmarks!(
     {"Boolean Object Access", boolean_object_access},
     {"String Object Access", string_object_access},
     {"Arithmetic operations", arithmetic_operations},
+    {"Number conversion", number_conversion},
     {"Clean js", clean_js},
     {"Mini js", mini_js}
 );
@@ -122,6 +175,8 @@ full_benchmarks!(
 criterion_group!(
     benches,
     create_realm,
+    json_stringify,
+    number_conversions,
     bench_parser,
     bench_compile,
     bench_execution,
@@ -235,7 +235,7 @@ fn from_async_iterator(
     context: &mut Context,
 ) -> CoroutineState {
     let result = (|| {
-        let Some(mut sm) = state_machine.take() else {
+        let Some(mut sm) = boa_gc::take_traced(state_machine) else {
             return Ok(CoroutineState::Done);
         };
 
@@ -492,7 +492,7 @@ fn from_array_like(
     context: &mut Context,
 ) -> CoroutineState {
     let result: JsResult<_> = (|| {
-        let Some(mut sm) = state_machine.take() else {
+        let Some(mut sm) = boa_gc::take_traced(state_machine) else {
             return Ok(CoroutineState::Done);
         };
 
@@ -419,6 +419,111 @@ impl Array {
             )
     }
 
+    /// Returns the values that spreading `value` would produce, if they can be read directly
+    /// from its dense storage without observably running the iterator protocol.
+    ///
+    /// This is the case if `value` is a dense array with the original array shape, and both
+    /// `Array.prototype[@@iterator]` and `%ArrayIteratorPrototype%.next` are the original
+    /// built-in functions.
+    ///
+    /// If `take` is `true`, the values are moved out of the array, leaving it empty. This must
+    /// only be used for arrays that are not reachable from anywhere else.
+    pub(crate) fn spread_values(
+        value: &JsValue,
+        take: bool,
+        context: &Context,
+    ) -> Option<ThinVec<JsValue>> {
+        let array = value.as_object()?;
+        let intrinsics = context.intrinsics();
+
+        let is_original = |object: &JsObject, key: PropertyKey, original: &JsObject| {
+            object
+                .borrow()
+                .properties()
+                .get(&key)
+                .and_then(|desc| desc.value().and_then(JsValue::as_object).cloned())
+                .is_some_and(|function| function == *original)
+        };
+        if !is_original(
+            &intrinsics.constructors().array().prototype(),
+            JsSymbol::iterator().into(),
+            &intrinsics.objects().array_prototype_values(),
+        ) || !is_original(
+            &intrinsics.objects().iterator_prototypes().array(),
+            js_string!("next").into(),
+            &intrinsics.objects().array_iterator_prototype_next(),
+        ) {
+            return None;
+        }
+
+        let mut array = array.borrow_mut();
+        let properties = array.properties_mut();
+        if properties.shape.to_addr_usize()
+            != intrinsics.templates().array().shape().to_addr_usize()
+        {
+            return None;
+        }
+
+        let len = properties.storage[0]
+            .as_i32()
+            .and_then(|len| usize::try_from(len).ok())?;
+        match properties.dense_indexed_properties_mut() {
+            Some(values) if take && values.len() == len => {
+                let values = std::mem::take(values);
+                properties.storage[0] = JsValue::new(0);
+                Some(values)
+            }
+            _ => properties
+                .to_dense_indexed_properties()
+                .filter(|values| values.len() == len),
+        }
+    }
+
+    /// Appends `values` to the end of `array`.
+    ///
+    /// This is equivalent to calling `CreateDataPropertyOrThrow` for each value, but moves the
+    /// values directly into the dense storage of the array when possible.
+    pub(crate) fn append(
+        array: &JsObject,
+        values: ThinVec<JsValue>,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        {
+            let mut borrowed = array.borrow_mut();
+            let extensible = borrowed.extensible;
+            let properties = borrowed.properties_mut();
+            if extensible
+                && properties.shape.to_addr_usize()
+                    == context
+                        .intrinsics()
+                        .templates()
+                        .array()
+                        .shape()
+                        .to_addr_usize()
+            {
+                let len = properties.storage[0]
+                    .as_i32()
+                    .and_then(|len| usize::try_from(len).ok());
+                if let Some(mut elements) = properties.to_dense_indexed_properties() {
+                    if len == Some(elements.len())
+                        && elements.len() + values.len() < u32::MAX as usize
+                    {
+                        elements.extend(values);
+                        properties.storage[0] = JsValue::new(elements.len());
+                        properties.override_indexed_properties(elements);
+                        return Ok(());
+                    }
+                }
+            }
+        }
+
+        let len = array.length_of_array_like(context)?;
+        for (index, value) in values.into_iter().enumerate() {
+            array.create_data_property_or_throw(len + index as u64, value, context)?;
+        }
+        Ok(())
+    }
+
     /// Utility function for concatenating array objects.
     ///
     /// Returns a Boolean valued property that if `true` indicates that
@@ -469,7 +574,8 @@ impl Array {
     ) -> JsResult<JsObject> {
         // 1. Let isArray be ? IsArray(originalArray).
         // 2. If isArray is false, return ? ArrayCreate(length).
-        if !original_array.is_array_abstract()? {
+        // Hosts can disable species lookups, which behaves as if `constructor` was undefined.
+        if !original_array.is_array_abstract()? || context.species_lookups_disabled() {
             return Self::array_create(length, None, context);
         }
         // 3. Let C be ? Get(originalArray, "constructor").
@@ -512,6 +618,7 @@ impl Array {
         }
 
         if let Some(c) = c.as_constructor() {
+            context.check_pure_construct(c)?;
             // 8. Return ? Construct(C, « 𝔽(length) »).
             return c.construct(&[JsValue::new(length)], Some(c), context);
         }
@@ -574,7 +681,10 @@ impl Array {
             // 10. Else,
             //     a. Let A be ? ArrayCreate(len).
             let a = match this.as_constructor() {
-                Some(constructor) => constructor.construct(&[len.into()], None, context)?,
+                Some(constructor) => {
+                    context.check_pure_construct(constructor)?;
+                    constructor.construct(&[len.into()], None, context)?
+                }
                 _ => Self::array_create(len, None, context)?,
             };
 
@@ -614,7 +724,10 @@ impl Array {
         // b. Else,
         //     i. Let A be ? ArrayCreate(0en).
         let a = match this.as_constructor() {
-            Some(constructor) => constructor.construct(&[], None, context)?,
+            Some(constructor) => {
+                context.check_pure_construct(constructor)?;
+                constructor.construct(&[], None, context)?
+            }
             _ => Self::array_create(0, None, context)?,
         };
 
@@ -710,7 +823,10 @@ impl Array {
         // 5. Else,
         //     a. Let A be ? ArrayCreate(len).
         let a = match this.as_constructor() {
-            Some(constructor) => constructor.construct(&[len.into()], None, context)?,
+            Some(constructor) => {
+                context.check_pure_construct(constructor)?;
+                constructor.construct(&[len.into()], None, context)?
+            }
             _ => Self::array_create(len as u64, None, context)?,
         };
 
@@ -1494,6 +1610,19 @@ impl Array {
 
         let search_element = args.get_or_undefined(0);
 
+        if k < len {
+            if let Some(index) = search_dense_elements(
+                &o,
+                len as u64,
+                k as usize..len as usize,
+                Direction::Ascending,
+                search_element,
+                false,
+            ) {
+                return Ok(index.map_or(JsValue::new(-1), JsValue::new));
+            }
+        }
+
         // 10. Repeat, while k < len,
         while k < len {
             // a. Let kPresent be ? HasProperty(O, ! ToString(𝔽(k))).
@@ -1568,6 +1697,19 @@ impl Array {
 
         let search_element = args.get_or_undefined(0);
 
+        if k >= 0 {
+            if let Some(index) = search_dense_elements(
+                &o,
+                len as u64,
+                0..k as usize + 1,
+                Direction::Descending,
+                search_element,
+                false,
+            ) {
+                return Ok(index.map_or(JsValue::new(-1), JsValue::new));
+            }
+        }
+
         // 8. Repeat, while k ≥ 0,
         while k >= 0 {
             // a. Let kPresent be ? HasProperty(O, ! ToString(𝔽(k))).
@@ -2067,6 +2209,19 @@ impl Array {
 
         let search_element = args.get_or_undefined(0);
 
+        if k < len {
+            if let Some(index) = search_dense_elements(
+                &o,
+                len as u64,
+                k as usize..len as usize,
+                Direction::Ascending,
+                search_element,
+                true,
+            ) {
+                return Ok(JsValue::new(index.is_some()));
+            }
+        }
+
         // 10. Repeat, while k < len,
         while k < len {
             // a. Let elementK be ? Get(O, ! ToString(𝔽(k))).
@@ -3408,6 +3563,86 @@ pub(crate) fn find_via_predicate(
     Ok((JsValue::new(-1), JsValue::undefined()))
 }
 
+/// Searches the elements of an array in `range` directly on its dense storage, avoiding the
+/// `HasProperty` and `Get` operations done for every index by `indexOf`, `lastIndexOf` and
+/// `includes`.
+///
+/// Dense storage never has holes, so this can't skip any element inherited from the prototype
+/// chain. Elements are compared with `SameValueZero` if `same_value_zero` is `true`, or with
+/// `IsStrictlyEqual` otherwise.
+///
+/// Returns `None` if `o` is not an array whose elements are all in dense storage.
+fn search_dense_elements(
+    o: &JsObject,
+    len: u64,
+    range: std::ops::Range<usize>,
+    direction: Direction,
+    search_element: &JsValue,
+    same_value_zero: bool,
+) -> Option<Option<usize>> {
+    fn position<T>(
+        elements: &[T],
+        direction: Direction,
+        predicate: impl FnMut(&T) -> bool,
+    ) -> Option<usize> {
+        match direction {
+            Direction::Ascending => elements.iter().position(predicate),
+            Direction::Descending => elements.iter().rposition(predicate),
+        }
+    }
+
+    if !o.is_array() {
+        return None;
+    }
+
+    let o = o.borrow();
+    let start = range.start;
+    let index = match &o.properties().indexed_properties {
+        IndexedProperties::DenseI32(elements) if elements.len() as u64 >= len => {
+            let elements = &elements[range];
+            search_element.as_number().and_then(|x| {
+                // Numbers that can't be stored as an `i32` can't be equal to any element.
+                // `-0` is cast to `0`, which is equal under both comparisons.
+                let x_i32 = x as i32;
+                if f64::from(x_i32) == x {
+                    position(elements, direction, |element| *element == x_i32)
+                } else {
+                    None
+                }
+            })
+        }
+        IndexedProperties::DenseF64(elements) if elements.len() as u64 >= len => {
+            let elements = &elements[range];
+            match search_element.as_number() {
+                Some(x) if x.is_nan() => {
+                    if same_value_zero {
+                        position(elements, direction, |element| element.is_nan())
+                    } else {
+                        None
+                    }
+                }
+                Some(x) => position(elements, direction, |element| *element == x),
+                None => None,
+            }
+        }
+        IndexedProperties::DenseElement(elements) if elements.len() as u64 >= len => {
+            let elements = &elements[range];
+            if same_value_zero {
+                position(elements, direction, |element| {
+                    JsValue::same_value_zero(search_element, element)
+                })
+            } else {
+                position(elements, direction, |element| {
+                    search_element.strict_equals(element)
+                })
+            }
+        }
+        _ => return None,
+    };
+
+    Some(index.map(|index| start + index))
+}
+
 /// Define an own property for an array exotic object.
 ///
 /// More information:
@@ -343,6 +343,58 @@ fn last_index_of() {
     ]);
 }
 
+#[test]
+fn search_dense_elements() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+                var ints = [1, 2, 3, 2, 1];
+                var floats = [0.5, NaN, -0, 2.5, NaN];
+                var mixed = [1, "1", null, undefined, NaN, 1];
+            "#}),
+        TestAction::assert_eq("ints.indexOf(2)", 1),
+        TestAction::assert_eq("ints.indexOf(2.0, 2)", 3),
+        TestAction::assert_eq("ints.indexOf(2.5)", -1),
+        TestAction::assert_eq("ints.indexOf('2')", -1),
+        TestAction::assert_eq("ints.indexOf(2 ** 40)", -1),
+        TestAction::assert_eq("ints.lastIndexOf(1)", 4),
+        TestAction::assert_eq("ints.lastIndexOf(1, -2)", 0),
+        TestAction::assert("ints.includes(3, -3)"),
+        TestAction::assert("!ints.includes(3, 3)"),
+        TestAction::assert_eq("floats.indexOf(NaN)", -1),
+        TestAction::assert_eq("floats.lastIndexOf(NaN)", -1),
+        TestAction::assert("floats.includes(NaN)"),
+        TestAction::assert_eq("floats.indexOf(0)", 2),
+        TestAction::assert("floats.includes(-0)"),
+        TestAction::assert_eq("floats.lastIndexOf(2.5)", 3),
+        TestAction::assert_eq("mixed.indexOf(undefined)", 3),
+        TestAction::assert_eq("mixed.indexOf('1')", 1),
+        TestAction::assert_eq("mixed.lastIndexOf(1)", 5),
+        TestAction::assert_eq("mixed.indexOf(NaN)", -1),
+        TestAction::assert("mixed.includes(NaN)"),
+        TestAction::assert("!mixed.includes(0)"),
+    ]);
+}
+
+#[test]
+fn search_holes_observe_prototype() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+                var holes = [0, , 2];
+                holes.length = 5;
+                Array.prototype[1] = "proto";
+                Array.prototype[4] = "proto end";
+            "#}),
+        TestAction::assert_eq("holes.indexOf('proto')", 1),
+        TestAction::assert_eq("holes.lastIndexOf('proto end')", 4),
+        TestAction::assert("holes.includes('proto end')"),
+        TestAction::assert("[, 1].includes(undefined)"),
+        TestAction::assert_eq("[, 1].indexOf(undefined)", -1),
+        TestAction::run("delete Array.prototype[1]; delete Array.prototype[4];"),
+        TestAction::assert("holes.includes(undefined)"),
+        TestAction::assert_eq("holes.indexOf(undefined)", -1),
+    ]);
+}
+
 #[test]
 fn fill_obj_ref() {
     run_test_actions([
@@ -862,6 +914,70 @@ fn array_spread_arrays() {
     ]);
 }
 
+#[test]
+fn array_spread_fast_path() {
+    run_test_actions([
+        TestAction::run_harness(),
+        TestAction::run(indoc! {r#"
+                var a = [1, 2.5, "x"];
+                function args() { return Array.from(arguments); }
+            "#}),
+        TestAction::assert("arrayEquals([...a, ...a], [1, 2.5, 'x', 1, 2.5, 'x'])"),
+        TestAction::assert("arrayEquals([0, , ...[1, 2], ...[]], [0, undefined, 1, 2])"),
+        TestAction::assert("arrayEquals(args(...a, 3, ...[4]), [1, 2.5, 'x', 3, 4])"),
+        TestAction::assert("arrayEquals(a, [1, 2.5, 'x'])"),
+        TestAction::assert(indoc! {r#"
+                var holes = [1, , 3];
+                Array.prototype[1] = 2;
+                var result = [...holes];
+                delete Array.prototype[1];
+                arrayEquals(result, [1, 2, 3]) && Object.hasOwn(result, 1)
+            "#}),
+        TestAction::assert(indoc! {r#"
+                Object.defineProperty(Array.prototype, "0", {
+                    set(v) { throw new Error("setter called"); },
+                    configurable: true,
+                });
+                var result = [...[1, 2]];
+                delete Array.prototype[0];
+                arrayEquals(result, [1, 2])
+            "#}),
+    ]);
+}
+
+#[test]
+fn array_spread_modified_iterator() {
+    run_test_actions([
+        TestAction::run_harness(),
+        TestAction::assert(indoc! {r#"
+                var values = Array.prototype[Symbol.iterator];
+                Array.prototype[Symbol.iterator] = function* () { yield "x"; };
+                var result = [...[1, 2]];
+                Array.prototype[Symbol.iterator] = values;
+                arrayEquals(result, ["x"])
+            "#}),
+        TestAction::assert(indoc! {r#"
+                var ArrayIteratorPrototype = Object.getPrototypeOf([][Symbol.iterator]());
+                var next = ArrayIteratorPrototype.next;
+                ArrayIteratorPrototype.next = function () {
+                    var result = next.call(this);
+                    if (!result.done) {
+                        result.value *= 2;
+                    }
+                    return result;
+                };
+                var result = [...[1, 2]];
+                ArrayIteratorPrototype.next = next;
+                arrayEquals(result, [2, 4])
+            "#}),
+        TestAction::assert(indoc! {r#"
+                var a = [1, 2];
+                a[Symbol.iterator] = function* () { yield 3; };
+                arrayEquals([...a], [3]) && Math.max(...a) === 3
+            "#}),
+    ]);
+}
+
 #[test]
 fn array_spread_non_iterable() {
     run_test_actions([TestAction::assert_native_error(
@@ -233,6 +233,11 @@ impl ArrayBuffer {
         self.data.as_mut()
     }
 
+    /// Gets the maximum byte length of the buffer, if it is resizable.
+    pub(crate) const fn max_byte_len(&self) -> Option<u64> {
+        self.max_byte_len
+    }
+
     /// Sets the maximum byte length of the buffer, returning the previous value if present.
     pub(crate) fn set_max_byte_length(&mut self, max_byte_len: u64) -> Option<u64> {
         self.max_byte_len.replace(max_byte_len)
@@ -724,13 +729,21 @@ impl ArrayBuffer {
                     .into());
             };
 
-            // 26. Perform CopyDataBlockBytes(toBuf, 0, fromBuf, first, newLen).
+            // 26. Let currentLen be O.[[ArrayBufferByteLength]].
+            let current_len = from_buf.len();
+
+            // 27. If first < currentLen, then
             let first = first as usize;
-            let new_len = new_len as usize;
-            to_buf[..new_len].copy_from_slice(&from_buf[first..first + new_len]);
+            if first < current_len {
+                // a. Let count be min(newLen, currentLen - first).
+                let count = (new_len as usize).min(current_len - first);
+
+                // b. Perform CopyDataBlockBytes(toBuf, 0, fromBuf, first, count).
+                to_buf[..count].copy_from_slice(&from_buf[first..first + count]);
+            }
         }
 
-        // 27. Return new.
+        // 28. Return new.
         Ok(new.upcast().into())
     }
 
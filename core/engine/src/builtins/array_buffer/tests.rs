@@ -1,5 +1,8 @@
 use crate::object::JsArrayBuffer;
-use crate::{run_test_actions, Context, TestAction};
+use crate::{
+    js_string, property::Attribute, run_test_actions, run_test_actions_with, Context, TestAction,
+};
+use indoc::indoc;
 
 #[test]
 fn create_byte_data_block() {
@@ -37,6 +40,39 @@ fn resize() {
     assert!(arr.data_mut().resize(u64::MAX).is_err());
 }
 
+#[test]
+fn transfer_to_context() {
+    let source = &mut Context::default();
+    let target = &mut Context::default();
+
+    let js_arr = JsArrayBuffer::from_byte_block((0..8).collect(), source)
+        .unwrap()
+        .with_max_byte_length(16);
+    let transferred = js_arr.transfer_to(target).unwrap();
+
+    // Sunny day
+    assert!(js_arr.data().is_none());
+    assert_eq!(
+        transferred.data().as_deref(),
+        Some((0..8).collect::<Vec<u8>>().as_slice())
+    );
+    assert_eq!(transferred.borrow().data.max_byte_len(), Some(16));
+
+    target
+        .register_global_property(js_string!("buffer"), transferred, Attribute::all())
+        .unwrap();
+    run_test_actions_with(
+        [
+            TestAction::assert_eq("buffer instanceof ArrayBuffer", true),
+            TestAction::assert_eq("new Uint8Array(buffer)[7]", 7),
+        ],
+        target,
+    );
+
+    // Rainy day
+    assert!(js_arr.transfer_to(target).is_err());
+}
+
 #[test]
 fn get_values() {
     run_test_actions([
@@ -157,3 +193,22 @@ fn sort_negative_zero() {
         TestAction::assert("cmp(infinities[1], [-Infinity, 1, 2, 3, 4, Infinity])"),
     ]);
 }
+
+#[test]
+fn slice_shrunk_by_species_constructor() {
+    run_test_actions([TestAction::assert_eq(
+        indoc! {r#"
+            var buf = new ArrayBuffer(8, { maxByteLength: 8 });
+            new Uint8Array(buf).set([1, 2, 3, 4, 5, 6, 7, 8]);
+            buf.constructor = {
+                [Symbol.species]: function (len) {
+                    buf.resize(3);
+                    return new ArrayBuffer(len);
+                },
+            };
+            var sliced = buf.slice(1, 6);
+            [sliced.byteLength, ...new Uint8Array(sliced)].join()
+        "#},
+        "5,2,3,0,0,0",
+    )]);
+}
@@ -10,15 +10,18 @@
 use crate::{
     builtins::{
         date::utils::{
-            date_from_time, date_string, day, hour_from_time, local_time, make_date, make_day,
-            make_full_year, make_time, min_from_time, month_from_time, ms_from_time, pad_five,
-            pad_four, pad_six, pad_three, pad_two, parse_date, sec_from_time, time_clip,
-            time_string, time_within_day, time_zone_string, to_date_string_t, utc_t, week_day,
-            year_from_time, MS_PER_MINUTE,
+            date_from_time, date_string, day, hour_from_time, local_date_time_string, local_time,
+            make_date, make_day, make_full_year, make_time, min_from_time, month_from_time,
+            ms_from_time, pad_five, pad_four, pad_six, pad_three, pad_two, parse_date,
+            sec_from_time, time_clip, time_string, time_within_day, time_zone_string,
+            to_date_string_t, utc_t, year_from_time, DateComponents, MS_PER_MINUTE,
         },
         BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject,
     },
-    context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
+    context::{
+        intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
+        HostHooks,
+    },
     error::JsNativeError,
     js_string,
     object::{internal_methods::get_prototype_from_constructor, JsObject},
@@ -32,6 +35,7 @@ use crate::{
 use boa_gc::{Finalize, Trace};
 use boa_macros::js_str;
 use boa_profiler::Profiler;
+use std::cell::Cell;
 
 pub(crate) mod utils;
 
@@ -39,19 +43,88 @@ pub(crate) mod utils;
 mod tests;
 
 /// The internal representation of a `Date` object.
-#[derive(Debug, Copy, Clone, Trace, Finalize, JsData)]
+///
+/// Along with its time value, a `Date` caches the UTC and local time components of the value,
+/// which are computed the first time a getter or a formatting method needs them, and
+/// invalidated when the time value changes.
+///
+/// The offset of the local time zone can change between two calls (e.g. if the host changes
+/// its time zone), so the local components are cached along with the local time value they were
+/// computed from, and are only reused while the offset gives the same local time value.
+#[derive(Debug, Clone, Trace, Finalize, JsData)]
 #[boa_gc(empty_trace)]
-pub struct Date(f64);
+pub struct Date {
+    /// The time value in milliseconds since the epoch, or `None` if it is `NaN`.
+    time: Option<i64>,
+    utc: Cell<Option<DateComponents>>,
+    local: Cell<Option<(i64, DateComponents)>>,
+}
 
 impl Date {
-    /// Creates a new `Date`.
-    pub(crate) const fn new(dt: f64) -> Self {
-        Self(dt)
+    /// Creates a new `Date` from a time value, which must be `NaN` or the result of `TimeClip`.
+    pub(crate) fn new(tv: f64) -> Self {
+        Self {
+            time: (!tv.is_nan()).then_some(tv as i64),
+            utc: Cell::new(None),
+            local: Cell::new(None),
+        }
     }
 
     /// Creates a new `Date` from the current UTC time of the host.
     pub(crate) fn utc_now(context: &mut Context) -> Self {
-        Self(context.clock().now().millis_since_epoch() as f64)
+        Self::new(context.clock().now().millis_since_epoch() as f64)
+    }
+
+    /// Gets the time value of the `Date`, the `[[DateValue]]` internal slot.
+    pub(crate) fn time_value(&self) -> f64 {
+        self.time.map_or(f64::NAN, |t| t as f64)
+    }
+
+    /// Sets the time value of the `Date`, which must be `NaN` or the result of `TimeClip`.
+    fn set_time_value(&mut self, tv: f64) {
+        *self = Self::new(tv);
+    }
+
+    /// Gets the components of the time value on the UTC or the local time scale, or `None` if
+    /// the time value is `NaN`.
+    fn components<const LOCAL: bool>(&self, hooks: &dyn HostHooks) -> Option<DateComponents> {
+        let time = self.time?;
+        if LOCAL {
+            let time = local_time(time as f64, hooks) as i64;
+            if let Some((cached, components)) = self.local.get() {
+                if cached == time {
+                    return Some(components);
+                }
+            }
+
+            let components = DateComponents::from_time(time);
+            self.local.set(Some((time, components)));
+            return Some(components);
+        }
+
+        if let Some(components) = self.utc.get() {
+            return Some(components);
+        }
+
+        let components = DateComponents::from_time(time);
+        self.utc.set(Some(components));
+        Some(components)
+    }
+
+    /// Gets the components of the `Date` object `this` on the UTC or the local time scale, or
+    /// `None` if its time value is `NaN`.
+    ///
+    /// Throws a `TypeError` if `this` is not a `Date` object.
+    fn this_components<const LOCAL: bool>(
+        this: &JsValue,
+        context: &Context,
+    ) -> JsResult<Option<DateComponents>> {
+        let date = this
+            .as_object()
+            .and_then(JsObject::downcast_ref::<Self>)
+            .ok_or_else(|| JsNativeError::typ().with_message("'this' is not a Date"))?;
+
+        Ok(date.components::<LOCAL>(context.host_hooks().as_ref()))
     }
 }
 
@@ -229,7 +302,7 @@ impl BuiltInConstructor for Date {
                     value.as_object().and_then(JsObject::downcast_ref::<Self>)
                 {
                     // i. Let tv be value.[[DateValue]].
-                    date.0
+                    date.time_value()
                 }
                 // c. Else,
                 else {
@@ -255,7 +328,7 @@ impl BuiltInConstructor for Date {
                 };
 
                 // d. Let dv be TimeClip(tv).
-                Self(time_clip(tv))
+                Self::new(time_clip(tv))
             }
             // 5. Else,
             _ => {
@@ -293,7 +366,7 @@ impl BuiltInConstructor for Date {
                 let final_date = make_date(make_day(yr, m, dt), make_time(h, min, s, milli));
 
                 // k. Let dv be TimeClip(UTC(finalDate)).
-                Self(time_clip(utc_t(final_date, context.host_hooks().as_ref())))
+                Self::new(time_clip(utc_t(final_date, context.host_hooks().as_ref())))
             }
         };
 
@@ -413,27 +486,13 @@ impl Date {
         // 1. Let dateObject be the this value.
         // 2. Perform ? RequireInternalSlot(dateObject, [[DateValue]]).
         // 3. Let t be dateObject.[[DateValue]].
-        let t = this
-            .as_object()
-            .and_then(|obj| obj.downcast_ref::<Date>().as_deref().copied())
-            .ok_or_else(|| JsNativeError::typ().with_message("'this' is not a Date"))?
-            .0;
-
-        // 4. If t is NaN, return NaN.
-        if t.is_nan() {
-            return Ok(JsValue::new(f64::NAN));
-        }
+        let Some(t) = Self::this_components::<LOCAL>(this, context)? else {
+            // 4. If t is NaN, return NaN.
+            return Ok(JsValue::nan());
+        };
 
-        if LOCAL {
-            // 5. Return DateFromTime(LocalTime(t)).
-            Ok(JsValue::from(date_from_time(local_time(
-                t,
-                context.host_hooks().as_ref(),
-            ))))
-        } else {
-            // 5. Return DateFromTime(t).
-            Ok(JsValue::from(date_from_time(t)))
-        }
+        // 5. Return DateFromTime(LocalTime(t)), or DateFromTime(t) for the UTC variant.
+        Ok(JsValue::from(t.date))
     }
 
     /// [`Date.prototype.getDay ( )`][local] and
@@ -452,27 +511,13 @@ impl Date {
         // 1. Let dateObject be the this value.
         // 2. Perform ? RequireInternalSlot(dateObject, [[DateValue]]).
         // 3. Let t be dateObject.[[DateValue]].
-        let t = this
-            .as_object()
-            .and_then(|obj| obj.downcast_ref::<Date>().as_deref().copied())
-            .ok_or_else(|| JsNativeError::typ().with_message("'this' is not a Date"))?
-            .0;
-
-        // 4. If t is NaN, return NaN.
-        if t.is_nan() {
-            return Ok(JsValue::from(f64::NAN));
-        }
+        let Some(t) = Self::this_components::<LOCAL>(this, context)? else {
+            // 4. If t is NaN, return NaN.
+            return Ok(JsValue::nan());
+        };
 
-        if LOCAL {
-            // 5. Return WeekDay(LocalTime(t)).
-            Ok(JsValue::from(week_day(local_time(
-                t,
-                context.host_hooks().as_ref(),
-            ))))
-        } else {
-            // 5. Return WeekDay(t).
-            Ok(JsValue::from(week_day(t)))
-        }
+        // 5. Return WeekDay(LocalTime(t)), or WeekDay(t) for the UTC variant.
+        Ok(JsValue::from(t.week_day))
     }
 
     /// [`Date.prototype.getYear()`][spec].
@@ -494,21 +539,13 @@ impl Date {
         // 1. Let dateObject be the this value.
         // 2. Perform ? RequireInternalSlot(dateObject, [[DateValue]]).
         // 3. Let t be dateObject.[[DateValue]].
-        let t = this
-            .as_object()
-            .and_then(|obj| obj.downcast_ref::<Date>().as_deref().copied())
-            .ok_or_else(|| JsNativeError::typ().with_message("'this' is not a Date"))?
-            .0;
-
-        // 4. If t is NaN, return NaN.
-        if t.is_nan() {
-            return Ok(JsValue::from(f64::NAN));
-        }
+        let Some(t) = Self::this_components::<true>(this, context)? else {
+            // 4. If t is NaN, return NaN.
+            return Ok(JsValue::nan());
+        };
 
         // 5. Return YearFromTime(LocalTime(t)) - 1900𝔽.
-        Ok(JsValue::from(
-            year_from_time(local_time(t, context.host_hooks().as_ref())) - 1900,
-        ))
+        Ok(JsValue::from(t.year - 1900))
     }
 
     /// [`Date.prototype.getFullYear ( )`][local] and
@@ -526,27 +563,13 @@ impl Date {
         // 1. Let dateObject be the this value.
         // 2. Perform ? RequireInternalSlot(dateObject, [[DateValue]]).
         // 3. Let t be dateObject.[[DateValue]].
-        let t = this
-            .as_object()
-            .and_then(|obj| obj.downcast_ref::<Date>().as_deref().copied())
-            .ok_or_else(|| JsNativeError::typ().with_message("'this' is not a Date"))?
-            .0;
-
-        // 4. If t is NaN, return NaN.
-        if t.is_nan() {
-            return Ok(JsValue::from(f64::NAN));
-        }
+        let Some(t) = Self::this_components::<LOCAL>(this, context)? else {
+            // 4. If t is NaN, return NaN.
+            return Ok(JsValue::nan());
+        };
 
-        if LOCAL {
-            // 5. Return YearFromTime(LocalTime(t)).
-            Ok(JsValue::from(year_from_time(local_time(
-                t,
-                context.host_hooks().as_ref(),
-            ))))
-        } else {
-            // 5. Return YearFromTime(t).
-            Ok(JsValue::from(year_from_time(t)))
-        }
+        // 5. Return YearFromTime(LocalTime(t)), or YearFromTime(t) for the UTC variant.
+        Ok(JsValue::from(t.year))
     }
 
     /// [`Date.prototype.getHours ( )`][local] and
@@ -564,27 +587,13 @@ impl Date {
         // 1. Let dateObject be the this value.
         // 2. Perform ? RequireInternalSlot(dateObject, [[DateValue]]).
         // 3. Let t be dateObject.[[DateValue]].
-        let t = this
-            .as_object()
-            .and_then(|obj| obj.downcast_ref::<Date>().as_deref().copied())
-            .ok_or_else(|| JsNativeError::typ().with_message("'this' is not a Date"))?
-            .0;
-
-        // 4. If t is NaN, return NaN.
-        if t.is_nan() {
-            return Ok(JsValue::from(f64::NAN));
-        }
+        let Some(t) = Self::this_components::<LOCAL>(this, context)? else {
+            // 4. If t is NaN, return NaN.
+            return Ok(JsValue::nan());
+        };
 
-        if LOCAL {
-            // 5. Return HourFromTime(LocalTime(t)).
-            Ok(JsValue::from(hour_from_time(local_time(
-                t,
-                context.host_hooks().as_ref(),
-            ))))
-        } else {
-            // 5. Return HourFromTime(t).
-            Ok(JsValue::from(hour_from_time(t)))
-        }
+        // 5. Return HourFromTime(LocalTime(t)), or HourFromTime(t) for the UTC variant.
+        Ok(JsValue::from(t.hour))
     }
 
     /// [`Date.prototype.getMilliseconds ( )`][local] and
@@ -602,27 +611,13 @@ impl Date {
         // 1. Let dateObject be the this value.
         // 2. Perform ? RequireInternalSlot(dateObject, [[DateValue]]).
         // 3. Let t be dateObject.[[DateValue]].
-        let t = this
-            .as_object()
-            .and_then(|obj| obj.downcast_ref::<Date>().as_deref().copied())
-            .ok_or_else(|| JsNativeError::typ().with_message("'this' is not a Date"))?
-            .0;
-
-        // 4. If t is NaN, return NaN.
-        if t.is_nan() {
-            return Ok(JsValue::from(f64::NAN));
-        }
+        let Some(t) = Self::this_components::<LOCAL>(this, context)? else {
+            // 4. If t is NaN, return NaN.
+            return Ok(JsValue::nan());
+        };
 
-        if LOCAL {
-            // 5. Return msFromTime(LocalTime(t)).
-            Ok(JsValue::from(ms_from_time(local_time(
-                t,
-                context.host_hooks().as_ref(),
-            ))))
-        } else {
-            // 5. Return msFromTime(t).
-            Ok(JsValue::from(ms_from_time(t)))
-        }
+        // 5. Return msFromTime(LocalTime(t)), or msFromTime(t) for the UTC variant.
+        Ok(JsValue::from(t.millisecond))
     }
 
     /// [`Date.prototype.getMinutes ( )`][local] and
@@ -640,27 +635,13 @@ impl Date {
         // 1. Let dateObject be the this value.
         // 2. Perform ? RequireInternalSlot(dateObject, [[DateValue]]).
         // 3. Let t be dateObject.[[DateValue]].
-        let t = this
-            .as_object()
-            .and_then(|obj| obj.downcast_ref::<Date>().as_deref().copied())
-            .ok_or_else(|| JsNativeError::typ().with_message("'this' is not a Date"))?
-            .0;
-
-        // 4. If t is NaN, return NaN.
-        if t.is_nan() {
-            return Ok(JsValue::from(f64::NAN));
-        }
+        let Some(t) = Self::this_components::<LOCAL>(this, context)? else {
+            // 4. If t is NaN, return NaN.
+            return Ok(JsValue::nan());
+        };
 
-        if LOCAL {
-            // 5. Return MinFromTime(LocalTime(t)).
-            Ok(JsValue::from(min_from_time(local_time(
-                t,
-                context.host_hooks().as_ref(),
-            ))))
-        } else {
-            // 5. Return MinFromTime(t).
-            Ok(JsValue::from(min_from_time(t)))
-        }
+        // 5. Return MinFromTime(LocalTime(t)), or MinFromTime(t) for the UTC variant.
+        Ok(JsValue::from(t.minute))
     }
 
     /// [`Date.prototype.getMonth ( )`][local] and
@@ -679,27 +660,13 @@ impl Date {
         // 1. Let dateObject be the this value.
         // 2. Perform ? RequireInternalSlot(dateObject, [[DateValue]]).
         // 3. Let t be dateObject.[[DateValue]].
-        let t = this
-            .as_object()
-            .and_then(|obj| obj.downcast_ref::<Date>().as_deref().copied())
-            .ok_or_else(|| JsNativeError::typ().with_message("'this' is not a Date"))?
-            .0;
-
-        // 4. If t is NaN, return NaN.
-        if t.is_nan() {
-            return Ok(JsValue::from(f64::NAN));
-        }
+        let Some(t) = Self::this_components::<LOCAL>(this, context)? else {
+            // 4. If t is NaN, return NaN.
+            return Ok(JsValue::nan());
+        };
 
-        if LOCAL {
-            // 5. Return MonthFromTime(LocalTime(t)).
-            Ok(JsValue::from(month_from_time(local_time(
-                t,
-                context.host_hooks().as_ref(),
-            ))))
-        } else {
-            // 5. Return MonthFromTime(t).
-            Ok(JsValue::from(month_from_time(t)))
-        }
+        // 5. Return MonthFromTime(LocalTime(t)), or MonthFromTime(t) for the UTC variant.
+        Ok(JsValue::from(t.month))
     }
 
     /// [`Date.prototype.getSeconds ( )`][local] and
@@ -717,27 +684,13 @@ impl Date {
         // 1. Let dateObject be the this value.
         // 2. Perform ? RequireInternalSlot(dateObject, [[DateValue]]).
         // 3. Let t be dateObject.[[DateValue]].
-        let t = this
-            .as_object()
-            .and_then(|obj| obj.downcast_ref::<Date>().as_deref().copied())
-            .ok_or_else(|| JsNativeError::typ().with_message("'this' is not a Date"))?
-            .0;
-
-        // 4. If t is NaN, return NaN.
-        if t.is_nan() {
-            return Ok(JsValue::from(f64::NAN));
-        }
+        let Some(t) = Self::this_components::<LOCAL>(this, context)? else {
+            // 4. If t is NaN, return NaN.
+            return Ok(JsValue::nan());
+        };
 
-        if LOCAL {
-            // 5. Return SecFromTime(LocalTime(t)).
-            Ok(JsValue::from(sec_from_time(local_time(
-                t,
-                context.host_hooks().as_ref(),
-            ))))
-        } else {
-            // 5. Return SecFromTime(t).
-            Ok(JsValue::from(sec_from_time(t)))
-        }
+        // 5. Return SecFromTime(LocalTime(t)), or SecFromTime(t) for the UTC variant.
+        Ok(JsValue::from(t.second))
     }
 
     /// `Date.prototype.getTime()`.
@@ -760,9 +713,8 @@ impl Date {
         // 3. Return dateObject.[[DateValue]].
         Ok(this
             .as_object()
-            .and_then(|obj| obj.downcast_ref::<Date>().as_deref().copied())
+            .and_then(|obj| obj.downcast_ref::<Date>().map(|date| date.time_value()))
             .ok_or_else(|| JsNativeError::typ().with_message("'this' is not a Date"))?
-            .0
             .into())
     }
 
@@ -787,9 +739,8 @@ impl Date {
         // 3. Let t be dateObject.[[DateValue]].
         let t = this
             .as_object()
-            .and_then(|obj| obj.downcast_ref::<Date>().as_deref().copied())
-            .ok_or_else(|| JsNativeError::typ().with_message("'this' is not a Date"))?
-            .0;
+            .and_then(|obj| obj.downcast_ref::<Date>().map(|date| date.time_value()))
+            .ok_or_else(|| JsNativeError::typ().with_message("'this' is not a Date"))?;
 
         // 4. If t is NaN, return NaN.
         if t.is_nan() {
@@ -823,7 +774,7 @@ impl Date {
             .ok_or_else(|| JsNativeError::typ().with_message("'this' is not a Date"))?;
 
         // 3. Let t be dateObject.[[DateValue]].
-        let mut t = date.0;
+        let mut t = date.time_value();
 
         // NOTE (nekevss): `downcast_ref` is used and then dropped for a short lived borrow.
         // ToNumber() may call userland code which can modify the underlying date
@@ -864,7 +815,7 @@ impl Date {
             .ok_or_else(|| JsNativeError::typ().with_message("'this' is not a Date"))?;
 
         // 9. Set dateObject.[[DateValue]] to u.
-        date_mut.0 = u;
+        date_mut.set_time_value(u);
 
         // 10. Return u.
         Ok(JsValue::from(u))
@@ -891,7 +842,7 @@ impl Date {
             .ok_or_else(|| JsNativeError::typ().with_message("'this' is not a Date"))?;
 
         // 3. Let t be dateObject.[[DateValue]].
-        let t = date.0;
+        let t = date.time_value();
 
         // NOTE (nekevss): `downcast_ref` is used and then dropped for a short lived borrow.
         // ToNumber() may call userland code which can modify the underlying date
@@ -949,7 +900,7 @@ impl Date {
             .ok_or_else(|| JsNativeError::typ().with_message("'this' is not a Date"))?;
 
         // 10. Set dateObject.[[DateValue]] to u.
-        date_mut.0 = u;
+        date_mut.set_time_value(u);
 
         // 11. Return u.
         Ok(JsValue::from(u))
@@ -978,7 +929,7 @@ impl Date {
             .ok_or_else(|| JsNativeError::typ().with_message("'this' is not a Date"))?;
 
         // 3. Let t be dateObject.[[DateValue]].
-        let mut t = date.0;
+        let mut t = date.time_value();
 
         // NOTE (nekevss): `downcast_ref` is used and then dropped for a short lived borrow.
         // ToNumber() may call userland code which can modify the underlying date
@@ -1034,7 +985,7 @@ impl Date {
             .ok_or_else(|| JsNativeError::typ().with_message("'this' is not a Date"))?;
 
         // 15. Set dateObject.[[DateValue]] to u.
-        date_mut.0 = u;
+        date_mut.set_time_value(u);
 
         // 16. Return u.
         Ok(JsValue::from(u))
@@ -1060,7 +1011,7 @@ impl Date {
             .ok_or_else(|| JsNativeError::typ().with_message("'this' is not a Date"))?;
 
         // 3. Let t be dateObject.[[DateValue]].
-        let mut t = date.0;
+        let mut t = date.time_value();
 
         // NOTE (nekevss): `downcast_ref` is used and then dropped for a short lived borrow.
         // ToNumber() may call userland code which can modify the underlying date
@@ -1106,7 +1057,7 @@ impl Date {
             .ok_or_else(|| JsNativeError::typ().with_message("'this' is not a Date"))?;
 
         // 9. Set dateObject.[[DateValue]] to u.
-        date_mut.0 = u;
+        date_mut.set_time_value(u);
 
         // 10. Return u.
         Ok(JsValue::from(u))
@@ -1132,7 +1083,7 @@ impl Date {
             .ok_or_else(|| JsNativeError::typ().with_message("'this' is not a Date"))?;
 
         // 3. Let t be dateObject.[[DateValue]].
-        let mut t = date.0;
+        let mut t = date.time_value();
 
         // NOTE (nekevss): `downcast_ref` is used and then dropped for a short lived borrow.
         // ToNumber() may call userland code which can modify the underlying date
@@ -1182,7 +1133,7 @@ impl Date {
             .ok_or_else(|| JsNativeError::typ().with_message("'this' is not a Date"))?;
 
         // 13. Set dateObject.[[DateValue]] to u.
-        date_mut.0 = u;
+        date_mut.set_time_value(u);
 
         // 14. Return u.
         Ok(JsValue::from(u))
@@ -1209,7 +1160,7 @@ impl Date {
             .ok_or_else(|| JsNativeError::typ().with_message("'this' is not a Date"))?;
 
         // 3. Let t be dateObject.[[DateValue]].
-        let mut t = date.0;
+        let mut t = date.time_value();
 
         // NOTE (nekevss): `downcast_ref` is used and then dropped for a short lived borrow.
         // ToNumber() may call userland code which can modify the underlying date
@@ -1256,7 +1207,7 @@ impl Date {
             .ok_or_else(|| JsNativeError::typ().with_message("'this' is not a Date"))?;
 
         // 11. Set dateObject.[[DateValue]] to u.
-        date_mut.0 = u;
+        date_mut.set_time_value(u);
 
         // 12. Return u.
         Ok(JsValue::from(u))
@@ -1282,7 +1233,7 @@ impl Date {
             .ok_or_else(|| JsNativeError::typ().with_message("'this' is not a Date"))?;
 
         // 3. Let t be dateObject.[[DateValue]].
-        let mut t = date.0;
+        let mut t = date.time_value();
 
         // NOTE (nekevss): `downcast_ref` is used and then dropped for a short lived borrow.
         // ToNumber() may call userland code which can modify the underlying date
@@ -1329,7 +1280,7 @@ impl Date {
             .ok_or_else(|| JsNativeError::typ().with_message("'this' is not a Date"))?;
 
         // 11. Set dateObject.[[DateValue]] to u.
-        date_mut.0 = u;
+        date_mut.set_time_value(u);
 
         // 12. Return u.
         Ok(JsValue::from(u))
@@ -1362,7 +1313,7 @@ impl Date {
             .ok_or_else(|| JsNativeError::typ().with_message("'this' is not a Date"))?;
 
         // 3. Let t be dateObject.[[DateValue]].
-        let t = date.0;
+        let t = date.time_value();
 
         // NOTE (nekevss): `downcast_ref` is used and then dropped for a short lived borrow.
         // ToNumber() may call userland code which can modify the underlying date
@@ -1398,7 +1349,7 @@ impl Date {
             .ok_or_else(|| JsNativeError::typ().with_message("'this' is not a Date"))?;
 
         // 10. Set dateObject.[[DateValue]] to u.
-        date_mut.0 = u;
+        date_mut.set_time_value(u);
 
         // 11. Return u.
         Ok(JsValue::from(u))
@@ -1444,7 +1395,7 @@ impl Date {
             .ok_or_else(|| JsNativeError::typ().with_message("'this' is not a Date"))?;
 
         // 5. Set dateObject.[[DateValue]] to v.
-        date_mut.0 = v;
+        date_mut.set_time_value(v);
 
         // 6. Return v.
         Ok(JsValue::from(v))
@@ -1467,22 +1418,14 @@ impl Date {
         // 1. Let dateObject be the this value.
         // 2. Perform ? RequireInternalSlot(dateObject, [[DateValue]]).
         // 3. Let tv be dateObject.[[DateValue]].
-        let tv = this
-            .as_object()
-            .and_then(|obj| obj.downcast_ref::<Date>().as_deref().copied())
-            .ok_or_else(|| JsNativeError::typ().with_message("'this' is not a Date"))?
-            .0;
-
         // 4. If tv is NaN, return "Invalid Date".
-        if tv.is_nan() {
-            return Ok(js_string!("Invalid Date").into());
-        }
-
         // 5. Let t be LocalTime(tv).
-        let t = local_time(tv, context.host_hooks().as_ref());
+        let Some(t) = Self::this_components::<true>(this, context)? else {
+            return Ok(js_string!("Invalid Date").into());
+        };
 
         // 6. Return DateString(t).
-        Ok(JsValue::from(date_string(t)))
+        Ok(JsValue::from(date_string(&t)))
     }
 
     /// [`Date.prototype.toISOString()`][spec].
@@ -1499,28 +1442,22 @@ impl Date {
     pub(crate) fn to_iso_string(
         this: &JsValue,
         _: &[JsValue],
-        _: &mut Context,
+        context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let dateObject be the this value.
         // 2. Perform ? RequireInternalSlot(dateObject, [[DateValue]]).
         // 3. Let tv be dateObject.[[DateValue]].
-        let tv = this
-            .as_object()
-            .and_then(|obj| obj.downcast_ref::<Date>().as_deref().copied())
-            .ok_or_else(|| JsNativeError::typ().with_message("'this' is not a Date"))?
-            .0;
-
         // 4. If tv is not finite, throw a RangeError exception.
-        if !tv.is_finite() {
+        let Some(tv) = Self::this_components::<false>(this, context)? else {
             return Err(JsNativeError::range()
                 .with_message("Invalid time value")
                 .into());
-        }
+        };
 
         // 5. If tv corresponds with a year that cannot be represented in the Date Time String Format, throw a RangeError exception.
         // 6. Return a String representation of tv in the Date Time String Format on the UTC time scale,
         //    including all format elements and the UTC offset representation "Z".
-        let year = year_from_time(tv);
+        let year = tv.year;
         let year = if year.is_positive() && year >= 10000 {
             js_string!(js_str!("+"), pad_six(year.unsigned_abs(), &mut [0; 6]))
         } else if year.is_positive() {
@@ -1529,17 +1466,17 @@ impl Date {
             js_string!(js_str!("-"), pad_six(year.unsigned_abs(), &mut [0; 6]))
         };
         let mut binding = [0; 2];
-        let month = pad_two(month_from_time(tv) + 1, &mut binding);
+        let month = pad_two(tv.month + 1, &mut binding);
         let mut binding = [0; 2];
-        let day = pad_two(date_from_time(tv), &mut binding);
+        let day = pad_two(tv.date, &mut binding);
         let mut binding = [0; 2];
-        let hour = pad_two(hour_from_time(tv), &mut binding);
+        let hour = pad_two(tv.hour, &mut binding);
         let mut binding = [0; 2];
-        let minute = pad_two(min_from_time(tv), &mut binding);
+        let minute = pad_two(tv.minute, &mut binding);
         let mut binding = [0; 2];
-        let second = pad_two(sec_from_time(tv), &mut binding);
+        let second = pad_two(tv.second, &mut binding);
         let mut binding = [0; 3];
-        let millisecond = pad_three(ms_from_time(tv), &mut binding);
+        let millisecond = pad_three(tv.millisecond, &mut binding);
 
         Ok(JsValue::from(js_string!(
             &year,
@@ -1667,13 +1604,16 @@ impl Date {
         // 3. Let tv be dateObject.[[DateValue]].
         let tv = this
             .as_object()
-            .and_then(|obj| obj.downcast_ref::<Date>().as_deref().copied())
-            .ok_or_else(|| JsNativeError::typ().with_message("'this' is not a Date"))?
-            .0;
+            .and_then(|obj| obj.downcast_ref::<Date>().map(|date| date.time_value()))
+            .ok_or_else(|| JsNativeError::typ().with_message("'this' is not a Date"))?;
 
         // 4. Return ToDateString(tv).
-        Ok(JsValue::from(to_date_string_t(
+        let Some(t) = Self::this_components::<true>(this, context)? else {
+            return Ok(js_string!("Invalid Date").into());
+        };
+        Ok(JsValue::from(local_date_time_string(
             tv,
+            &t,
             context.host_hooks().as_ref(),
         )))
     }
@@ -1698,22 +1638,19 @@ impl Date {
         // 3. Let tv be dateObject.[[DateValue]].
         let tv = this
             .as_object()
-            .and_then(|obj| obj.downcast_ref::<Date>().as_deref().copied())
-            .ok_or_else(|| JsNativeError::typ().with_message("'this' is not a Date"))?
-            .0;
+            .and_then(|obj| obj.downcast_ref::<Date>().map(|date| date.time_value()))
+            .ok_or_else(|| JsNativeError::typ().with_message("'this' is not a Date"))?;
 
         // 4. If tv is NaN, return "Invalid Date".
-        if tv.is_nan() {
-            return Ok(js_string!("Invalid Date").into());
-        }
-
         // 5. Let t be LocalTime(tv).
-        let t = local_time(tv, context.host_hooks().as_ref());
+        let Some(t) = Self::this_components::<true>(this, context)? else {
+            return Ok(js_string!("Invalid Date").into());
+        };
 
         // 6. Return the string-concatenation of TimeString(t) and TimeZoneString(tv).
         Ok(JsValue::from(js_string!(
-            &time_string(t),
-            &time_zone_string(t, context.host_hooks().as_ref())
+            &time_string(&t),
+            &time_zone_string(tv, context.host_hooks().as_ref())
         )))
     }
 
@@ -1729,24 +1666,18 @@ impl Date {
     pub(crate) fn to_utc_string(
         this: &JsValue,
         _args: &[JsValue],
-        _context: &mut Context,
+        context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let dateObject be the this value.
         // 2. Perform ? RequireInternalSlot(dateObject, [[DateValue]]).
         // 3. Let tv be dateObject.[[DateValue]].
-        let tv = this
-            .as_object()
-            .and_then(|obj| obj.downcast_ref::<Date>().as_deref().copied())
-            .ok_or_else(|| JsNativeError::typ().with_message("'this' is not a Date"))?
-            .0;
-
         // 4. If tv is NaN, return "Invalid Date".
-        if tv.is_nan() {
+        let Some(tv) = Self::this_components::<false>(this, context)? else {
             return Ok(js_string!("Invalid Date").into());
-        }
+        };
 
         // 5. Let weekday be the Name of the entry in Table 63 with the Number WeekDay(tv).
-        let weekday = match week_day(tv) {
+        let weekday = match tv.week_day {
             0 => js_str!("Sun"),
             1 => js_str!("Mon"),
             2 => js_str!("Tue"),
@@ -1758,7 +1689,7 @@ impl Date {
         };
 
         // 6. Let month be the Name of the entry in Table 64 with the Number MonthFromTime(tv).
-        let month = match month_from_time(tv) {
+        let month = match tv.month {
             0 => js_str!("Jan"),
             1 => js_str!("Feb"),
             2 => js_str!("Mar"),
@@ -1776,10 +1707,10 @@ impl Date {
 
         // 7. Let day be ToZeroPaddedDecimalString(ℝ(DateFromTime(tv)), 2).
         let mut binding = [0; 2];
-        let day = pad_two(date_from_time(tv), &mut binding);
+        let day = pad_two(tv.date, &mut binding);
 
         // 8. Let yv be YearFromTime(tv).
-        let yv = year_from_time(tv);
+        let yv = tv.year;
 
         // 9. If yv is +0𝔽 or yv > +0𝔽, let yearSign be the empty String; otherwise, let yearSign be "-".
         let year_sign = if yv >= 0 { js_str!("") } else { js_str!("-") };
@@ -1817,7 +1748,7 @@ impl Date {
             year_sign,
             &padded_year,
             js_str!(" "),
-            &time_string(tv)
+            &time_string(&tv)
         )))
     }
 
@@ -1840,9 +1771,8 @@ impl Date {
         // 3. Return dateObject.[[DateValue]].
         Ok(this
             .as_object()
-            .and_then(|obj| obj.downcast_ref::<Date>().as_deref().copied())
+            .and_then(|obj| obj.downcast_ref::<Date>().map(|date| date.time_value()))
             .ok_or_else(|| JsNativeError::typ().with_message("'this' is not a Date"))?
-            .0
             .into())
     }
 
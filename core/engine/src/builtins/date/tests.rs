@@ -1,5 +1,10 @@
 use crate::{
-    builtins::date::utils::fast_atoi::{process_4, process_8},
+    builtins::date::utils::{
+        date_from_time,
+        fast_atoi::{process_4, process_8},
+        hour_from_time, min_from_time, month_from_time, ms_from_time, sec_from_time,
+        year_from_time, DateComponents,
+    },
     js_string, run_test_actions, JsNativeErrorKind, TestAction,
 };
 use boa_macros::js_str;
@@ -97,6 +102,50 @@ fn parse_ascii_digits() {
     assert_eq!(0, parse_4_ascii_digits(b"0000", 4));
 }
 
+#[test]
+fn date_components() {
+    const MAX_TIME: i64 = 8_640_000_000_000_000;
+
+    let times = [
+        -MAX_TIME,
+        -62_167_219_200_001,
+        -62_167_219_200_000,
+        -2_203_891_200_000,
+        -86_400_001,
+        -1,
+        0,
+        1,
+        951_782_400_000,
+        951_868_799_999,
+        1_594_199_775_779,
+        4_107_542_400_000,
+        MAX_TIME,
+    ];
+    for t in times
+        .into_iter()
+        .chain((-MAX_TIME..MAX_TIME).step_by(997_123_456_789))
+    {
+        let components = DateComponents::from_time(t);
+        let tv = t as f64;
+        assert_eq!(components.year, year_from_time(tv), "year of {t}");
+        assert_eq!(components.month, month_from_time(tv), "month of {t}");
+        assert_eq!(components.date, date_from_time(tv), "date of {t}");
+        assert_eq!(
+            i64::from(components.week_day),
+            (t.div_euclid(86_400_000) + 4).rem_euclid(7),
+            "week day of {t}"
+        );
+        assert_eq!(components.hour, hour_from_time(tv), "hour of {t}");
+        assert_eq!(components.minute, min_from_time(tv), "minute of {t}");
+        assert_eq!(components.second, sec_from_time(tv), "second of {t}");
+        assert_eq!(
+            components.millisecond,
+            ms_from_time(tv),
+            "millisecond of {t}"
+        );
+    }
+}
+
 #[test]
 fn date_this_time_value() {
     run_test_actions([TestAction::assert_native_error(
@@ -895,3 +944,60 @@ fn date_json() {
         js_string!(r#"{"date":"2020-07-08T09:16:15.779Z"}"#),
     )]);
 }
+
+#[test]
+fn date_components_cache() {
+    run_test_actions([
+        TestAction::run("var d = new Date(Date.UTC(2020, 6, 8, 9, 16, 15, 779));"),
+        TestAction::assert_eq("d.getUTCHours()", 9),
+        TestAction::assert_eq("d.setUTCHours(23)", 1_594_250_175_779_i64),
+        TestAction::assert_eq("d.getUTCHours()", 23),
+        TestAction::assert_eq("d.toISOString()", js_str!("2020-07-08T23:16:15.779Z")),
+        TestAction::assert_eq("d.setUTCDate(31)", 1_596_237_375_779_i64),
+        TestAction::assert_eq("d.getUTCMonth()", 6),
+        TestAction::assert_eq("d.getUTCDay()", 5),
+        TestAction::assert_eq("d.setTime(NaN)", f64::NAN),
+        TestAction::assert_eq("d.getUTCHours()", f64::NAN),
+        TestAction::assert_eq("d.toUTCString()", js_str!("Invalid Date")),
+        TestAction::assert_eq("d.setTime(-62167219200001)", -62_167_219_200_001_i64),
+        TestAction::assert_eq("d.toISOString()", js_str!("-000001-12-31T23:59:59.999Z")),
+        TestAction::assert_eq("d.toUTCString()", js_str!("Fri, 31 Dec -0001 23:59:59 GMT")),
+    ]);
+}
+
+#[test]
+fn date_local_components_follow_the_offset() {
+    use crate::{context::HostHooks, run_test_actions_with, Context};
+    use std::{cell::Cell, rc::Rc};
+
+    struct Hooks(Rc<Cell<i32>>);
+
+    impl HostHooks for Hooks {
+        fn local_timezone_offset_seconds(&self, _: i64) -> i32 {
+            self.0.get()
+        }
+    }
+
+    let offset = Rc::new(Cell::new(0));
+    let context = &mut Context::builder()
+        .host_hooks(Rc::new(Hooks(offset.clone())))
+        .build()
+        .unwrap();
+
+    run_test_actions_with(
+        [
+            TestAction::run("var d = new Date(Date.UTC(2020, 6, 8, 9, 16, 15, 779));"),
+            TestAction::assert_eq("d.getHours()", 9),
+        ],
+        context,
+    );
+
+    offset.set(3600);
+    run_test_actions_with(
+        [
+            TestAction::assert_eq("d.getHours()", 10),
+            TestAction::assert_eq("d.getUTCHours()", 9),
+        ],
+        context,
+    );
+}
@@ -56,6 +56,66 @@ pub(super) fn time_within_day(t: f64) -> f64 {
     t.rem_euclid(MS_PER_DAY)
 }
 
+/// The fields of a time value, as returned by the abstract operations `YearFromTime`,
+/// `MonthFromTime`, `DateFromTime`, `WeekDay`, `HourFromTime`, `MinFromTime`, `SecFromTime` and
+/// `msFromTime`.
+///
+/// Unlike these operations, all the fields are computed at once with integer arithmetic, which
+/// allows [`Date`](super::Date) objects to cache them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) struct DateComponents {
+    pub(super) year: i32,
+    /// The zero-based month.
+    pub(super) month: u8,
+    pub(super) date: u8,
+    pub(super) week_day: u8,
+    pub(super) hour: u8,
+    pub(super) minute: u8,
+    pub(super) second: u8,
+    pub(super) millisecond: u16,
+}
+
+impl DateComponents {
+    /// Breaks down the time value `t`, in milliseconds since the epoch.
+    pub(super) fn from_time(t: i64) -> Self {
+        const MS_PER_DAY: i64 = 86_400_000;
+
+        let days = t.div_euclid(MS_PER_DAY);
+        let ms = t.rem_euclid(MS_PER_DAY);
+
+        // Converts the number of days since the epoch to a date of the proleptic Gregorian
+        // calendar, using the `civil_from_days` algorithm of Howard Hinnant, with years starting
+        // in March so that the leap day is the last day of the year.
+        // See <https://howardhinnant.github.io/date_algorithms.html#civil_from_days>.
+        let z = days + 719_468;
+        let era = z.div_euclid(146_097);
+        let day_of_era = z.rem_euclid(146_097);
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let shifted_month = (5 * day_of_year + 2) / 153;
+        let date = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+        let month = if shifted_month < 10 {
+            shifted_month + 2
+        } else {
+            shifted_month - 10
+        };
+        let year = year_of_era + era * 400 + i64::from(month < 2);
+
+        Self {
+            year: year as i32,
+            month: month as u8,
+            date: date as u8,
+            // The epoch is a Thursday.
+            week_day: (days + 4).rem_euclid(7) as u8,
+            hour: (ms / 3_600_000) as u8,
+            minute: (ms / 60_000 % 60) as u8,
+            second: (ms / 1000 % 60) as u8,
+            millisecond: (ms % 1000) as u16,
+        }
+    }
+}
+
 /// Abstract operation `DaysInYear ( y )`
 ///
 /// More info:
@@ -251,17 +311,6 @@ pub(super) fn date_from_time(t: f64) -> u8 {
     date as u8
 }
 
-/// Abstract operation `WeekDay ( t )`
-///
-/// More info:
-/// - [ECMAScript reference][spec]
-///
-/// [spec]: https://tc39.es/ecma262/#sec-weekday
-pub(super) fn week_day(t: f64) -> u8 {
-    // 1. Return 𝔽(ℝ(Day(t) + 4𝔽) modulo 7).
-    (day(t) + 4.0).rem_euclid(7.0) as u8
-}
-
 /// Abstract operation `HourFromTime ( t )`
 ///
 /// More info:
@@ -499,18 +548,18 @@ pub(crate) fn time_clip(time: f64) -> f64 {
 /// - [ECMAScript reference][spec]
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-timestring
-pub(super) fn time_string(tv: f64) -> JsString {
+pub(super) fn time_string(tv: &DateComponents) -> JsString {
     // 1. Let hour be ToZeroPaddedDecimalString(ℝ(HourFromTime(tv)), 2).
     let mut binding = [0; 2];
-    let hour = pad_two(hour_from_time(tv), &mut binding);
+    let hour = pad_two(tv.hour, &mut binding);
 
     // 2. Let minute be ToZeroPaddedDecimalString(ℝ(MinFromTime(tv)), 2).
     let mut binding = [0; 2];
-    let minute = pad_two(min_from_time(tv), &mut binding);
+    let minute = pad_two(tv.minute, &mut binding);
 
-    // 3. Let second be ToZeroPaddedDecimalStringbindingFromTime(tv)), 2).
+    // 3. Let second be ToZeroPaddedDecimalString(ℝ(SecFromTime(tv)), 2).
     let mut binding = [0; 2];
-    let second = pad_two(sec_from_time(tv), &mut binding);
+    let second = pad_two(tv.second, &mut binding);
 
     // 4. Return the string-concatenation of
     //  hour,
@@ -536,9 +585,9 @@ pub(super) fn time_string(tv: f64) -> JsString {
 /// - [ECMAScript reference][spec]
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-datestring
-pub(super) fn date_string(tv: f64) -> JsString {
+pub(super) fn date_string(tv: &DateComponents) -> JsString {
     // 1. Let weekday be the Name of the entry in Table 63 with the Number WeekDay(tv).
-    let weekday = match week_day(tv) {
+    let weekday = match tv.week_day {
         0 => js_str!("Sun"),
         1 => js_str!("Mon"),
         2 => js_str!("Tue"),
@@ -550,7 +599,7 @@ pub(super) fn date_string(tv: f64) -> JsString {
     };
 
     // 2. Let month be the Name of the entry in Table 64 with the Number MonthFromTime(tv).
-    let month = match month_from_time(tv) {
+    let month = match tv.month {
         0 => js_str!("Jan"),
         1 => js_str!("Feb"),
         2 => js_str!("Mar"),
@@ -568,10 +617,10 @@ pub(super) fn date_string(tv: f64) -> JsString {
 
     // 3. Let day be ToZeroPaddedDecimalString(ℝ(DateFromTime(tv)), 2).
     let mut binding = [0; 2];
-    let day = pad_two(date_from_time(tv), &mut binding);
+    let day = pad_two(tv.date, &mut binding);
 
     // 4. Let yv be YearFromTime(tv).
-    let yv = year_from_time(tv);
+    let yv = tv.year;
 
     // 5. If yv is +0𝔽 or yv > +0𝔽, let yearSign be the empty String; otherwise, let yearSign be "-".
     let year_sign = if yv >= 0 { js_str!("") } else { js_str!("-") };
@@ -664,18 +713,28 @@ pub(super) fn to_date_string_t(tv: f64, hooks: &dyn HostHooks) -> JsString {
     }
 
     // 2. Let t be LocalTime(tv).
-    let t = local_time(tv, hooks);
+    let t = DateComponents::from_time(local_time(tv, hooks) as i64);
 
     // 3. Return the string-concatenation of
     // DateString(t),
     // the code unit 0x0020 (SPACE),
     // TimeString(t),
     // and TimeZoneString(tv).
+    local_date_time_string(tv, &t, hooks)
+}
+
+/// Concatenates `DateString(t)`, a space, `TimeString(t)` and `TimeZoneString(tv)`, where `t` are
+/// the components of `LocalTime(tv)`, as in the abstract operation `ToDateString ( tv )`.
+pub(super) fn local_date_time_string(
+    tv: f64,
+    t: &DateComponents,
+    hooks: &dyn HostHooks,
+) -> JsString {
     js_string!(
         &date_string(t),
         js_str!(" "),
         &time_string(t),
-        &time_zone_string(t, hooks)
+        &time_zone_string(tv, hooks)
     )
 }
 
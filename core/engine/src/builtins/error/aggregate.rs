@@ -106,6 +106,13 @@ impl BuiltInConstructor for AggregateError {
 
         // 4. Perform ? InstallErrorCause(O, options).
         Error::install_error_cause(&o, args.get_or_undefined(2), context)?;
+        Error::install_error_position(
+            &o,
+            context.vm.source_position(),
+            context.vm.source_path(),
+            context,
+        );
+        Error::install_error_stack(&o, context.capture_stack_trace().as_ref(), context);
 
         // 5. Let errorsList be ? IteratorToList(? GetIterator(errors, sync)).
         let errors = args.get_or_undefined(0);
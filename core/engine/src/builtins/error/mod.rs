@@ -15,15 +15,18 @@ use crate::{
     context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
     error::JsNativeError,
     js_string,
-    object::{internal_methods::get_prototype_from_constructor, JsObject},
-    property::Attribute,
+    object::{internal_methods::get_prototype_from_constructor, FunctionObjectBuilder, JsObject},
+    property::{Attribute, PropertyDescriptor},
     realm::Realm,
     string::StaticJsStrings,
-    Context, JsArgs, JsData, JsResult, JsString, JsValue,
+    vm::StackTrace,
+    Context, JsArgs, JsData, JsResult, JsString, JsValue, NativeFunction,
 };
-use boa_gc::{Finalize, Trace};
+use boa_ast::Position;
+use boa_gc::{Finalize, GcRefCell, Trace};
 use boa_macros::js_str;
 use boa_profiler::Profiler;
+use std::{path::Path, rc::Rc};
 
 pub(crate) mod aggregate;
 pub(crate) mod eval;
@@ -199,12 +202,28 @@ impl BuiltInConstructor for Error {
 
         // 4. Perform ? InstallErrorCause(O, options).
         Self::install_error_cause(&o, args.get_or_undefined(1), context)?;
+        Self::install_error_position(
+            &o,
+            context.vm.source_position(),
+            context.vm.source_path(),
+            context,
+        );
+        Self::install_error_stack(&o, context.capture_stack_trace().as_ref(), context);
 
         // 5. Return O.
         Ok(o.into())
     }
 }
 
+/// The captured stack trace of an error object, and its `stack` once formatted.
+#[derive(Trace, Finalize)]
+struct ErrorStack {
+    // SAFETY: Nothing in `StackTrace` requires tracing, so this is safe.
+    #[unsafe_ignore_trace]
+    trace: StackTrace,
+    formatted: GcRefCell<Option<JsString>>,
+}
+
 impl Error {
     pub(crate) fn install_error_cause(
         o: &JsObject,
@@ -224,6 +243,125 @@ impl Error {
         Ok(())
     }
 
+    /// Defines the non-standard `lineNumber` and `columnNumber` properties of an error object
+    /// created at `position`, and its `fileName` property if it was created in the source at
+    /// `path`, if enabled with [`ContextBuilder::error_positions`].
+    ///
+    /// [`ContextBuilder::error_positions`]: crate::context::ContextBuilder::error_positions
+    pub(crate) fn install_error_position(
+        o: &JsObject,
+        position: Option<Position>,
+        path: Option<Rc<Path>>,
+        context: &mut Context,
+    ) {
+        if !context.error_positions() {
+            return;
+        }
+        let Some(position) = position else {
+            return;
+        };
+
+        o.create_non_enumerable_data_property_or_throw(
+            js_string!("lineNumber"),
+            position.line_number(),
+            context,
+        );
+        o.create_non_enumerable_data_property_or_throw(
+            js_string!("columnNumber"),
+            position.column_number(),
+            context,
+        );
+        if let Some(path) = path {
+            o.create_non_enumerable_data_property_or_throw(
+                js_string!("fileName"),
+                JsString::from(&*path.to_string_lossy()),
+                context,
+            );
+        }
+    }
+
+    /// Defines the non-standard `stack` accessor of an error object, if enabled with
+    /// [`ContextBuilder::error_stack_traces`].
+    ///
+    /// The name and message of the error are only read when `stack` is first accessed, and
+    /// followed by `stack_trace`. Assigning to `stack` replaces it with a data property.
+    ///
+    /// [`ContextBuilder::error_stack_traces`]: crate::context::ContextBuilder::error_stack_traces
+    pub(crate) fn install_error_stack(
+        o: &JsObject,
+        stack_trace: Option<&StackTrace>,
+        context: &mut Context,
+    ) {
+        let Some(stack_trace) = stack_trace else {
+            return;
+        };
+
+        let get = FunctionObjectBuilder::new(
+            context.realm(),
+            NativeFunction::from_copy_closure_with_captures(
+                |this, _, stack: &ErrorStack, context| {
+                    if let Some(formatted) = stack.formatted.borrow().clone() {
+                        return Ok(formatted.into());
+                    }
+
+                    let header = Self::to_string(this, &[], context)?
+                        .to_string(context)?
+                        .to_std_string_escaped();
+                    let formatted = JsString::from(if stack.trace.is_empty() {
+                        header
+                    } else {
+                        format!("{header}\n{}", stack.trace)
+                    });
+
+                    *stack.formatted.borrow_mut() = Some(formatted.clone());
+                    Ok(formatted.into())
+                },
+                ErrorStack {
+                    trace: stack_trace.clone(),
+                    formatted: GcRefCell::new(None),
+                },
+            ),
+        )
+        .name(js_string!("get stack"))
+        .length(0)
+        .build();
+
+        let set = FunctionObjectBuilder::new(
+            context.realm(),
+            NativeFunction::from_fn_ptr(|this, args, context| {
+                let Some(this) = this.as_object() else {
+                    return Err(JsNativeError::typ()
+                        .with_message("cannot set the stack of a non-object")
+                        .into());
+                };
+                this.define_property_or_throw(
+                    js_string!("stack"),
+                    PropertyDescriptor::builder()
+                        .value(args.get_or_undefined(0).clone())
+                        .writable(true)
+                        .enumerable(false)
+                        .configurable(true),
+                    context,
+                )?;
+                Ok(JsValue::undefined())
+            }),
+        )
+        .name(js_string!("set stack"))
+        .length(1)
+        .build();
+
+        o.define_property_or_throw(
+            js_string!("stack"),
+            PropertyDescriptor::builder()
+                .get(get)
+                .set(set)
+                .enumerable(false)
+                .configurable(true),
+            context,
+        )
+        .expect("defining the stack of a new error object must not fail");
+    }
+
     /// `Error.prototype.toString()`
     ///
     /// The `toString()` method returns a string representing the specified Error object.
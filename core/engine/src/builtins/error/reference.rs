@@ -101,6 +101,13 @@ impl BuiltInConstructor for ReferenceError {
 
         // 4. Perform ? InstallErrorCause(O, options).
         Error::install_error_cause(&o, args.get_or_undefined(1), context)?;
+        Error::install_error_position(
+            &o,
+            context.vm.source_position(),
+            context.vm.source_path(),
+            context,
+        );
+        Error::install_error_stack(&o, context.capture_stack_trace().as_ref(), context);
 
         // 5. Return O.
         Ok(o.into())
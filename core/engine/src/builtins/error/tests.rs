@@ -1,6 +1,8 @@
-use crate::{run_test_actions, TestAction};
+use crate::{run_test_actions, run_test_actions_with, Context, JsErrorKind, Source, TestAction};
+use boa_ast::Position;
 use boa_macros::js_str;
 use indoc::indoc;
+use std::path::Path;
 
 #[test]
 fn error_to_string() {
@@ -68,3 +70,192 @@ fn error_lengths() {
         TestAction::assert_eq("AggregateError.length", 2),
     ]);
 }
+
+#[test]
+fn error_position_of_uncaught_errors() {
+    run_test_actions([TestAction::inspect_context(|context| {
+        let error = context
+            .eval(Source::from_bytes(indoc! {r#"
+                function f() {
+                    undefinedVariable;
+                }
+                f();
+            "#}))
+            .unwrap_err();
+        assert_eq!(error.position(), Some(Position::new(2, 5)));
+
+        let error = context
+            .eval(Source::from_bytes("let x = 1;\nthrow x;"))
+            .unwrap_err();
+        assert_eq!(error.position(), Some(Position::new(2, 7)));
+    })]);
+}
+
+#[test]
+fn error_position_properties() {
+    let context = &mut Context::builder().error_positions(true).build().unwrap();
+    run_test_actions_with(
+        [
+            TestAction::run(indoc! {r#"
+                let created = new TypeError("created");
+                let thrown;
+                try {
+                    null.foo;
+                } catch (e) {
+                    thrown = e;
+                }
+            "#}),
+            TestAction::assert_eq("created.lineNumber", 1),
+            TestAction::assert_eq("created.columnNumber", 15),
+            TestAction::assert_eq("thrown.lineNumber", 4),
+            TestAction::assert_eq("thrown.columnNumber", 5),
+            TestAction::assert("!Object.keys(thrown).includes('lineNumber')"),
+        ],
+        context,
+    );
+
+    run_test_actions([TestAction::assert(
+        "!('lineNumber' in new Error()) && !('columnNumber' in new Error())",
+    )]);
+}
+
+#[test]
+fn error_source_path() {
+    let context = &mut Context::builder().error_positions(true).build().unwrap();
+    let path = Path::new("main.js");
+
+    let error = context
+        .eval(Source::from_bytes("let x = 1;\nnull.foo;").with_path(path))
+        .unwrap_err();
+    assert_eq!(error.path(), Some(path));
+    assert_eq!(error.position(), Some(Position::new(2, 1)));
+
+    let error = context
+        .eval(Source::from_bytes("let y = 1;\n1 +;").with_path(path))
+        .unwrap_err();
+    assert_eq!(error.path(), Some(path));
+    assert_eq!(error.position().map(|p| p.line_number()), Some(2));
+
+    let file_name = context
+        .eval(Source::from_bytes("try { null.foo; } catch (e) { e.fileName }").with_path(path))
+        .unwrap();
+    assert_eq!(file_name, js_str!("main.js").into());
+
+    let error = context.eval(Source::from_bytes("null.foo;")).unwrap_err();
+    assert_eq!(error.path(), None);
+}
+
+#[test]
+fn error_stack_traces() {
+    let context = &mut Context::builder().error_stack_traces(true).build().unwrap();
+    run_test_actions_with(
+        [
+            TestAction::run(indoc! {r#"
+                function inner() { return new RangeError("created"); }
+                function outer() { return inner(); }
+                let created = outer().stack.split("\n");
+                let thrown;
+                try {
+                    (function thrower() { null.foo; })();
+                } catch (e) {
+                    thrown = e.stack.split("\n");
+                }
+            "#}),
+            TestAction::assert_eq("created.length", 4),
+            TestAction::assert_eq("created[0]", js_str!("RangeError: created")),
+            TestAction::assert("created[1].startsWith('    at inner (<anonymous>:1:')"),
+            TestAction::assert("created[2].startsWith('    at outer (<anonymous>:2:')"),
+            TestAction::assert("created[3].startsWith('    at <main> (<anonymous>:3:')"),
+            TestAction::assert_eq("thrown.length", 3),
+            TestAction::assert("thrown[0].startsWith('TypeError: ')"),
+            TestAction::assert("thrown[1].startsWith('    at thrower (<anonymous>:6:')"),
+            TestAction::assert(
+                "!Object.keys(new Error()).includes('stack') && typeof new Error().stack === 'string'",
+            ),
+            TestAction::assert_eq(
+                indoc! {r#"
+                    function recurse(n) { return n === 0 ? new Error() : recurse(n - 1); }
+                    recurse(20).stack.split("\n").length
+                "#},
+                11,
+            ),
+        ],
+        context,
+    );
+
+    run_test_actions([TestAction::assert("!('stack' in new Error())")]);
+}
+
+#[test]
+fn error_stack_is_formatted_lazily() {
+    let context = &mut Context::builder().error_stack_traces(true).build().unwrap();
+    run_test_actions_with(
+        [
+            TestAction::run(indoc! {r#"
+                let error = new Error("before");
+                error.message = "after";
+                let throwing = new (class extends Error {
+                    get name() { throw new Error("name"); }
+                })();
+                let replaced = new Error();
+                replaced.stack = "replaced";
+            "#}),
+            TestAction::assert("error.stack.startsWith('Error: after\n')"),
+            TestAction::assert(indoc! {r#"
+                (() => {
+                    try { throwing.stack; } catch (e) { return e.message === "name"; }
+                    return false;
+                })()
+            "#}),
+            TestAction::assert_eq("replaced.stack", js_str!("replaced")),
+            TestAction::assert("Object.getOwnPropertyDescriptor(replaced, 'stack').writable"),
+        ],
+        context,
+    );
+}
+
+#[test]
+fn error_async_stack_traces() {
+    let context = &mut Context::builder().error_stack_traces(true).build().unwrap();
+    run_test_actions_with(
+        [
+            TestAction::run(indoc! {r#"
+                async function leaf() { await null; throw new Error("async"); }
+                async function middle() { await leaf(); }
+                async function root() { await middle(); }
+                let stack;
+                root().catch((e) => { stack = e.stack.split("\n"); });
+            "#}),
+            TestAction::inspect_context(|ctx| ctx.run_jobs().unwrap()),
+            TestAction::assert_eq("stack.length", 5),
+            TestAction::assert_eq("stack[0]", js_str!("Error: async")),
+            TestAction::assert("stack[1].startsWith('    at leaf (<anonymous>:1:')"),
+            TestAction::assert("stack[2].startsWith('    at async middle (<anonymous>:2:')"),
+            TestAction::assert("stack[3].startsWith('    at async root (<anonymous>:3:')"),
+            TestAction::assert("stack[4].startsWith('    at async <main> (<anonymous>:5:')"),
+        ],
+        context,
+    );
+}
+
+#[test]
+fn error_kinds() {
+    run_test_actions([TestAction::inspect_context(|context| {
+        let mut kind_of =
+            |source: &str| context.eval(Source::from_bytes(source)).unwrap_err().kind();
+
+        assert_eq!(kind_of("undefinedVariable"), JsErrorKind::Reference);
+        assert_eq!(kind_of("null.foo"), JsErrorKind::Type);
+        assert_eq!(kind_of("throw new SyntaxError()"), JsErrorKind::Syntax);
+        assert_eq!(
+            kind_of("class MyError extends RangeError {}; throw new MyError()"),
+            JsErrorKind::Range
+        );
+        assert_eq!(
+            kind_of("throw 'message'"),
+            JsErrorKind::Value(js_str!("message").into())
+        );
+        assert!(matches!(kind_of("throw {}"), JsErrorKind::Custom(_)));
+        assert!(!kind_of("throw {}").is_builtin_error());
+    })]);
+}
@@ -120,6 +120,12 @@ impl Eval {
             .host_hooks()
             .ensure_can_compile_strings(eval_realm, &[], x, direct, context)?;
 
+        if direct && context.dynamic_scopes_denied() {
+            return Err(JsNativeError::eval()
+                .with_message("direct eval is disabled in this context")
+                .into());
+        }
+
         // 11. Perform the following substeps in an implementation-defined order, possibly interleaving parsing and error detection:
         //     a. Let script be ParseText(StringToCodePoints(x), Script).
         //     b. If script is a List of errors, throw a SyntaxError exception.
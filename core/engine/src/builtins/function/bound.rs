@@ -105,35 +105,52 @@ fn bound_function_exotic_call(
     argument_count: usize,
     context: &mut Context,
 ) -> JsResult<CallValue> {
-    let bound_function = obj
-        .downcast_ref::<BoundFunction>()
-        .expect("bound function exotic method should only be callable from bound function objects");
-
-    // 1. Let target be F.[[BoundTargetFunction]].
-    let target = bound_function.target_function();
-    context
-        .vm
-        .stack
-        .calling_convention_set_function(argument_count, target.clone().into());
-
-    // 2. Let boundThis be F.[[BoundThis]].
-    let bound_this = bound_function.this();
-    context
-        .vm
-        .stack
-        .calling_convention_set_this(argument_count, bound_this.clone());
+    let mut argument_count = argument_count;
+    let mut function = obj.clone();
+
+    // The targets that are bound functions themselves are unwrapped here, instead of going
+    // through a call of each of them.
+    loop {
+        let bound_function = function.downcast_ref::<BoundFunction>().expect(
+            "bound function exotic method should only be callable from bound function objects",
+        );
+
+        // 1. Let target be F.[[BoundTargetFunction]].
+        let target = bound_function.target_function().clone();
+
+        // 2. Let boundThis be F.[[BoundThis]].
+        let bound_this = bound_function.this();
+        context
+            .vm
+            .stack
+            .calling_convention_set_this(argument_count, bound_this.clone());
+
+        // 3. Let boundArgs be F.[[BoundArguments]].
+        let bound_args = bound_function.args();
+
+        // 4. Let args be the list-concatenation of boundArgs and argumentsList.
+        // NOTE: Functions that only bind `this` don't need to move the arguments.
+        if !bound_args.is_empty() {
+            context
+                .vm
+                .stack
+                .calling_convention_insert_arguments(argument_count, bound_args);
+            argument_count += bound_args.len();
+        }
+        drop(bound_function);
 
-    // 3. Let boundArgs be F.[[BoundArguments]].
-    let bound_args = bound_function.args();
+        if !target.is::<BoundFunction>() {
+            context
+                .vm
+                .stack
+                .calling_convention_set_function(argument_count, target.clone().into());
 
-    // 4. Let args be the list-concatenation of boundArgs and argumentsList.
-    context
-        .vm
-        .stack
-        .calling_convention_insert_arguments(argument_count, bound_args);
+            // 5. Return ? Call(target, boundThis, args).
+            return Ok(target.__call__(argument_count));
+        }
 
-    // 5. Return ? Call(target, boundThis, args).
-    Ok(target.__call__(bound_args.len() + argument_count))
+        function = target;
+    }
 }
 
 /// Internal method `[[Construct]]` for Bound Function Exotic Objects
@@ -165,10 +182,12 @@ fn bound_function_exotic_construct(
     let bound_args = bound_function.args();
 
     // 4. Let args be the list-concatenation of boundArgs and argumentsList.
-    context
-        .vm
-        .stack
-        .calling_convention_insert_arguments(argument_count, bound_args);
+    if !bound_args.is_empty() {
+        context
+            .vm
+            .stack
+            .calling_convention_insert_arguments(argument_count, bound_args);
+    }
 
     // 5. If SameValue(F, newTarget) is true, set newTarget to target.
     let function_object: JsValue = function_object.clone().into();
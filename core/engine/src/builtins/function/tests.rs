@@ -22,6 +22,93 @@ fn arguments_object() {
     ]);
 }
 
+#[test]
+fn mapped_arguments_object() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+                function aliased(a, b) {
+                    arguments[0] = 10;
+                    b = 20;
+                    return [a, arguments[1], arguments.length];
+                }
+                function duplicates(a, a) {
+                    arguments[0] = 10;
+                    arguments[1] = 20;
+                    return a;
+                }
+                function deleted(a) {
+                    delete arguments[0];
+                    arguments[0] = 10;
+                    return a;
+                }
+                function frozen(a) {
+                    Object.defineProperty(arguments, "0", { writable: false });
+                    a = 10;
+                    return arguments[0];
+                }
+                function missing(a, b) {
+                    arguments[1] = 10;
+                    return b;
+                }
+            "#}),
+        TestAction::assert_eq("aliased(1, 2).join()", js_str!("10,20,2")),
+        TestAction::assert_eq("duplicates(1, 2)", 20),
+        TestAction::assert_eq("deleted(1)", 1),
+        TestAction::assert_eq("frozen(1)", 1),
+        TestAction::assert_eq("missing(1)", JsValue::undefined()),
+    ]);
+}
+
+#[test]
+fn unmapped_arguments_object() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+                function strict(a) {
+                    "use strict";
+                    arguments[0] = 10;
+                    return a;
+                }
+                function defaults(a = 0) {
+                    arguments[0] = 10;
+                    return a;
+                }
+                function rest(a, ...b) {
+                    arguments[0] = 10;
+                    return a;
+                }
+                function destructured(a, { b }) {
+                    a = 10;
+                    return arguments[0];
+                }
+            "#}),
+        TestAction::assert_eq("strict(1)", 1),
+        TestAction::assert_eq("defaults(1)", 1),
+        TestAction::assert_eq("rest(1, 2)", 1),
+        TestAction::assert_eq("destructured(1, {})", 1),
+    ]);
+}
+
+#[test]
+fn arguments_object_accessed_indirectly() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+                function direct_eval(a) {
+                    eval("arguments[0] = 10");
+                    return a;
+                }
+                function nested_eval(a) {
+                    return (() => eval("arguments.length"))();
+                }
+                function arrow(a) {
+                    return (() => arguments[0])();
+                }
+            "#}),
+        TestAction::assert_eq("direct_eval(1)", 10),
+        TestAction::assert_eq("nested_eval(1, 2, 3)", 3),
+        TestAction::assert_eq("arrow(1)", 1),
+    ]);
+}
+
 #[test]
 fn self_mutating_function_when_calling() {
     run_test_actions([
@@ -61,7 +148,7 @@ fn function_prototype() {
         TestAction::assert_native_error(
             "new Function.prototype()",
             JsNativeErrorKind::Type,
-            "not a constructor",
+            "Function.prototype is not a constructor",
         ),
     ]);
 }
@@ -194,3 +281,29 @@ fn function_constructor_early_errors_super() {
         ),
     ]);
 }
+
+#[test]
+fn bound_function_calls() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            function f(...args) { return [this?.name, ...args].join(); }
+            var thisOnly = f.bind({ name: "a" });
+            var withArgs = f.bind({ name: "b" }, 1, 2);
+            var nested = withArgs.bind({ name: "ignored" }, 3).bind(null, 4);
+
+            class Point { constructor(x, y) { this.x = x; this.y = y; } }
+            var BoundPoint = Point.bind(null, 1).bind(null);
+        "#}),
+        TestAction::assert_eq("thisOnly()", js_str!("a")),
+        TestAction::assert_eq("thisOnly(1, 2)", js_str!("a,1,2")),
+        TestAction::assert_eq("withArgs(3)", js_str!("b,1,2,3")),
+        TestAction::assert_eq("nested(5, 6)", js_str!("b,1,2,3,4,5,6")),
+        TestAction::assert_eq(
+            "[0, 1].map(thisOnly).join(';')",
+            js_str!("a,0,0,0,1;a,1,1,0,1"),
+        ),
+        TestAction::assert_eq("nested.call({ name: 'c' })", js_str!("b,1,2,3,4")),
+        TestAction::assert_eq("new BoundPoint(2).y", 2),
+        TestAction::assert("new BoundPoint(2) instanceof Point"),
+    ]);
+}
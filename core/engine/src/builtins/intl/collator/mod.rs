@@ -15,8 +15,8 @@ use icu_provider::DataMarkerAttributes;
 
 use crate::{
     builtins::{
-        options::get_option, BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject,
-        OrdinaryObject,
+        options::{coerce_options_to_object, get_option},
+        Array, BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject, OrdinaryObject,
     },
     context::{
         icu::IntlProvider,
@@ -37,7 +37,7 @@ use crate::{
 
 use super::{
     locale::{canonicalize_locale_list, filter_locales, resolve_locale, validate_extension},
-    options::{coerce_options_to_object, IntlOptions},
+    options::IntlOptions,
     Service,
 };
 
@@ -567,6 +567,18 @@ impl Collator {
                 context,
             )
             .expect("operation must not fail per the spec");
+
+        // Non-standard: the collation types that could have been selected for the resolved
+        // locale, as returned by `Intl.Locale.prototype.getCollations`.
+        let collations = context
+            .intl_provider()
+            .collations(&collator.locale.id)
+            .into_iter()
+            .map(|co| JsValue::from(js_string!(co)));
+        let collations = Array::create_array_from_list(collations, context);
+        options
+            .create_data_property_or_throw(js_string!("collations"), collations, context)
+            .expect("operation must not fail per the spec");
         options
             .create_data_property_or_throw(js_string!("numeric"), collator.numeric, context)
             .expect("operation must not fail per the spec");
@@ -1,4 +1,8 @@
-use crate::{builtins::options::get_option, realm::Realm, string::StaticJsStrings};
+use crate::{
+    builtins::options::{coerce_options_to_object, get_option},
+    realm::Realm,
+    string::StaticJsStrings,
+};
 use boa_profiler::Profiler;
 use icu_locale::{
     extensions::unicode::Value,
@@ -15,7 +19,7 @@ pub(crate) use utils::*;
 mod options;
 
 use crate::{
-    builtins::{BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject},
+    builtins::{Array, BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject},
     context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
     js_string,
     object::{internal_methods::get_prototype_from_constructor, JsObject},
@@ -24,8 +28,6 @@ use crate::{
     Context, JsArgs, JsNativeError, JsResult, JsString, JsValue,
 };
 
-use super::options::coerce_options_to_object;
-
 #[derive(Debug, Clone)]
 pub(crate) struct Locale;
 
@@ -82,6 +84,7 @@ impl IntrinsicObject for Locale {
             .method(Self::maximize, js_string!("maximize"), 0)
             .method(Self::minimize, js_string!("minimize"), 0)
             .method(Self::to_string, js_string!("toString"), 0)
+            .method(Self::get_collations, js_string!("getCollations"), 0)
             .accessor(
                 js_string!("baseName"),
                 Some(base_name),
@@ -444,6 +447,54 @@ impl Locale {
         Ok(js_string!(loc.to_string()).into())
     }
 
+    /// [`Intl.Locale.prototype.getCollations ( )`][spec].
+    ///
+    /// Returns the collation types that can be used with the locale, or only the collation of the
+    /// locale if it has one.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/Locale/getCollations
+    /// [spec]: https://tc39.es/proposal-intl-locale-info/#sec-Intl.Locale.prototype.getCollations
+    pub(crate) fn get_collations(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let loc be the this value.
+        // 2. Perform ? RequireInternalSlot(loc, [[InitializedLocale]]).
+        let loc = this
+            .as_object()
+            .and_then(|o| o.downcast_ref::<icu_locale::Locale>())
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message(
+                    "`Locale.prototype.getCollations` can only be called on a `Locale` object",
+                )
+            })?;
+
+        // 3. Return CollationsOfLocale(loc).
+        let collations: Vec<JsValue> =
+            if let Some(co) = loc.extensions.unicode.keywords.get(&key!("co")) {
+                // CollationsOfLocale ( loc )
+                // 1. If loc.[[Collation]] is not undefined, then
+                //     a. Return CreateArrayFromList(« loc.[[Collation]] »).
+                vec![js_string!(co.to_string()).into()]
+            } else {
+                // 2. Let list be a List of unique collation types, sorted in code unit order,
+                //    that are commonly used for string comparison in the locale.
+                // 3. Return CreateArrayFromList(list).
+                context
+                    .intl_provider()
+                    .collations(&loc.id)
+                    .into_iter()
+                    .map(|co| js_string!(co).into())
+                    .collect()
+            };
+
+        Ok(Array::create_array_from_list(collations, context).into())
+    }
+
     /// [`get Intl.Locale.prototype.baseName`][spec].
     ///
     /// More information:
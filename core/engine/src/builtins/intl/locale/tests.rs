@@ -1,7 +1,8 @@
+use boa_macros::js_str;
 use icu_decimal::provider::DecimalSymbolsV1;
 use icu_locale::{
     extensions::unicode::Value, extensions_unicode_key as key, extensions_unicode_value as value,
-    locale, preferences::extensions::unicode::keywords::NumberingSystem, Locale,
+    langid, locale, preferences::extensions::unicode::keywords::NumberingSystem, Locale,
 };
 use icu_plurals::provider::PluralsCardinalV1;
 use icu_provider::{
@@ -15,6 +16,7 @@ use crate::{
         Service,
     },
     context::icu::IntlProvider,
+    run_test_actions, JsNativeErrorKind, TestAction,
 };
 
 #[derive(Debug)]
@@ -96,3 +98,32 @@ fn locale_resolution() {
         resolve_locale::<TestService>([locale!("bn-Arab")], &mut options, &provider).unwrap();
     assert_eq!(locale, "bn-u-nu-beng".parse().unwrap());
 }
+
+#[test]
+fn collations() {
+    let provider = IntlProvider::try_new_buffer(boa_icu_provider::buffer());
+
+    assert!(provider.collations(&langid!("de")).contains(&"phonebk"));
+    assert!(provider.collations(&langid!("es")).contains(&"trad"));
+    assert!(!provider.collations(&langid!("en")).contains(&"phonebk"));
+
+    let collations = provider.collations(&langid!("zh"));
+    assert!(collations.is_sorted());
+    assert!(!collations.contains(&"standard") && !collations.contains(&"search"));
+
+    run_test_actions([
+        TestAction::assert("new Intl.Locale('de').getCollations().includes('phonebk')"),
+        TestAction::assert_eq(
+            "new Intl.Locale('de-u-co-phonebk').getCollations().join()",
+            js_str!("phonebk"),
+        ),
+        TestAction::assert(
+            "new Intl.Collator('de').resolvedOptions().collations.includes('phonebk')",
+        ),
+        TestAction::assert_native_error(
+            "Intl.Locale.prototype.getCollations.call({})",
+            JsNativeErrorKind::Type,
+            "`Locale.prototype.getCollations` can only be called on a `Locale` object",
+        ),
+    ]);
+}
@@ -1,10 +1,10 @@
 use crate::{
     builtins::{
         intl::{
-            options::{coerce_options_to_object, IntlOptions, LocaleMatcher},
+            options::{IntlOptions, LocaleMatcher},
             Service,
         },
-        options::get_option,
+        options::{coerce_options_to_object, get_option},
         Array,
     },
     context::icu::IntlProvider,
@@ -467,21 +467,12 @@ where
 pub(in crate::builtins::intl) fn validate_extension<M: DataMarker>(
     language: LanguageIdentifier,
     attributes: &DataMarkerAttributes,
-    provider: &impl DryDataProvider<M>,
-) -> bool {
-    let locale = DataLocale::from(language);
-    let req = DataRequest {
-        id: DataIdentifierBorrowed::for_marker_attributes_and_locale(attributes, &locale),
-        metadata: {
-            let mut metadata = DataRequestMetadata::default();
-            metadata.silent = true;
-            metadata
-        },
-    };
-
-    provider
-        .dry_load(req)
-        .is_ok_and(|md| md.locale.is_none_or(|loc| loc == locale))
+    provider: &IntlProvider,
+) -> bool
+where
+    IntlProvider: DryDataProvider<M>,
+{
+    provider.has_data::<M>(language, attributes)
 }
 
 #[cfg(all(test, feature = "intl_bundled"))]
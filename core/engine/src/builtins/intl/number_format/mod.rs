@@ -11,6 +11,7 @@ use icu_decimal::{
 };
 
 mod options;
+mod range;
 use icu_locale::{
     extensions::unicode::{key, Value},
     Locale,
@@ -19,17 +20,20 @@ use icu_provider::DataMarkerAttributes;
 use num_bigint::BigInt;
 use num_traits::Num;
 pub(crate) use options::*;
+use range::RangePatterns;
 
 use super::{
     locale::{canonicalize_locale_list, filter_locales, resolve_locale, validate_extension},
-    options::{coerce_options_to_object, IntlOptions},
+    options::IntlOptions,
     Service,
 };
 use crate::value::JsVariant;
 use crate::{
     builtins::{
-        builder::BuiltInBuilder, options::get_option, string::is_trimmable_whitespace,
-        BuiltInConstructor, BuiltInObject, IntrinsicObject,
+        builder::BuiltInBuilder,
+        options::{coerce_options_to_object, get_option},
+        string::is_trimmable_whitespace,
+        Array, BuiltInConstructor, BuiltInObject, IntrinsicObject, OrdinaryObject,
     },
     context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
     js_string,
@@ -60,6 +64,7 @@ pub(crate) struct NumberFormat {
     notation: Notation,
     use_grouping: GroupingStrategy,
     sign_display: SignDisplay,
+    range_patterns: RangePatterns,
     bound_format: Option<JsFunction>,
 }
 
@@ -80,6 +85,172 @@ impl NumberFormat {
 
         self.formatter.format(value)
     }
+
+    /// [`PartitionNumberPattern ( numberFormat, x )`][spec].
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-partitionnumberpattern
+    fn format_to_parts(&self, value: &mut Decimal) -> Vec<NumberPart> {
+        use writeable::Writeable;
+
+        let mut parts = PartsCollector::default();
+        self.format(value)
+            .write_to_parts(&mut parts)
+            .expect("writing to a `PartsCollector` is infallible");
+        parts.parts
+    }
+
+    /// [`PartitionNumberRangePattern ( numberFormat, x, y )`][spec].
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-partitionnumberrangepattern
+    fn format_range_to_parts(
+        &self,
+        x: &mut Decimal,
+        y: &mut Decimal,
+    ) -> Vec<(NumberPart, RangeSource)> {
+        // 1. If x is NaN or y is NaN, throw a RangeError exception.
+        // NOTE: `to_intl_mathematical_value` already throws a `RangeError` for NaN.
+
+        // 2. Let result be a new empty List.
+        // 3. Let xResult be ? PartitionNumberPattern(numberFormat, x).
+        let x_result = self.format_to_parts(x);
+
+        // 4. Let yResult be ? PartitionNumberPattern(numberFormat, y).
+        let y_result = self.format_to_parts(y);
+
+        // 5. If ! FormatNumeric(numberFormat, x) is equal to ! FormatNumeric(numberFormat, y), then
+        let same_string = x_result.len() == y_result.len()
+            && x_result
+                .iter()
+                .zip(&y_result)
+                .all(|(x, y)| x.value == y.value);
+        if same_string {
+            // a. Let appxResult be ? FormatApproximately(numberFormat, xResult).
+            // b. For each element r in appxResult, do
+            //     i. Set r.[[Source]] to "shared".
+            // c. Return appxResult.
+
+            // FormatApproximately ( numberFormat, result )
+            // <https://tc39.es/ecma402/#sec-formatapproximately>
+            // 1. Let approximatelySign be an ILND String value used to signify that a number is approximate.
+            // 2. If approximatelySign is not empty, insert the Record { [[Type]]: "approximatelySign", [[Value]]: approximatelySign } at an ILND index in result.
+            return std::iter::once(NumberPart {
+                typ: "approximatelySign",
+                value: String::from(self.range_patterns.approximately_sign),
+            })
+            .chain(x_result)
+            .map(|part| (part, RangeSource::Shared))
+            .collect();
+        }
+
+        // 6. For each element r in xResult, do
+        //     a. Append the Record { [[Type]]: r.[[Type]], [[Value]]: r.[[Value]], [[Source]]: "startRange" } to result.
+        // 7. Let rangeSeparator be an ILND String value used to separate two numbers.
+        // 8. Append the Record { [[Type]]: "literal", [[Value]]: rangeSeparator, [[Source]]: "shared" } to result.
+        // 9. For each element r in yResult, do
+        //     a. Append the Record { [[Type]]: r.[[Type]], [[Value]]: r.[[Value]], [[Source]]: "endRange" } to result.
+        let mut separator = String::from(self.range_patterns.separator);
+
+        // Like ICU, surround the separator with spaces if the start has a sign or an affix, so
+        // that it isn't mistaken for part of the numbers.
+        if x_result
+            .iter()
+            .any(|part| matches!(part.typ, "minusSign" | "plusSign" | "literal"))
+        {
+            if !separator.starts_with(char::is_whitespace) {
+                separator.insert(0, ' ');
+            }
+            if !separator.ends_with(char::is_whitespace) {
+                separator.push(' ');
+            }
+        }
+
+        let separator = NumberPart {
+            typ: "literal",
+            value: separator,
+        };
+        let result = x_result
+            .into_iter()
+            .map(|part| (part, RangeSource::StartRange))
+            .chain(std::iter::once((separator, RangeSource::Shared)))
+            .chain(
+                y_result
+                    .into_iter()
+                    .map(|part| (part, RangeSource::EndRange)),
+            )
+            .collect();
+
+        // 10. Return ! CollapseNumberRange(numberFormat, result).
+        // NOTE: CollapseNumberRange is allowed to return result unchanged.
+        result
+    }
+}
+
+/// A Record { [[Type]], [[Value]] } of the result of `PartitionNumberPattern`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct NumberPart {
+    typ: &'static str,
+    value: String,
+}
+
+/// The `[[Source]]` of a part of the result of `PartitionNumberRangePattern`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RangeSource {
+    StartRange,
+    EndRange,
+    Shared,
+}
+
+impl RangeSource {
+    fn to_js_string(self) -> JsString {
+        match self {
+            Self::StartRange => js_string!("startRange"),
+            Self::EndRange => js_string!("endRange"),
+            Self::Shared => js_string!("shared"),
+        }
+    }
+}
+
+/// Collects the parts written by a [`FormattedDecimal`] into a list of [`NumberPart`]s.
+///
+/// Text written outside of any part is collected as a `"literal"` part.
+#[derive(Debug, Default)]
+struct PartsCollector {
+    parts: Vec<NumberPart>,
+    current: Vec<&'static str>,
+}
+
+impl std::fmt::Write for PartsCollector {
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        if s.is_empty() {
+            return Ok(());
+        }
+        let typ = self.current.last().copied().unwrap_or("literal");
+        match self.parts.last_mut() {
+            Some(part) if part.typ == typ => part.value.push_str(s),
+            _ => self.parts.push(NumberPart {
+                typ,
+                value: s.to_owned(),
+            }),
+        }
+        Ok(())
+    }
+}
+
+impl writeable::PartsWrite for PartsCollector {
+    type SubPartsWrite = Self;
+
+    fn with_part(
+        &mut self,
+        part: writeable::Part,
+        mut f: impl FnMut(&mut Self::SubPartsWrite) -> std::fmt::Result,
+    ) -> std::fmt::Result {
+        // The values of the parts of `icu_decimal` ("integer", "fraction", "group", "decimal",
+        // "minusSign" and "plusSign") match the types of the parts in ECMA-402.
+        self.current.push(part.value);
+        let result = f(self);
+        self.current.pop();
+        result
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -161,6 +332,12 @@ impl IntrinsicObject for NumberFormat {
                 Attribute::CONFIGURABLE,
             )
             .method(Self::resolved_options, js_string!("resolvedOptions"), 0)
+            .method(Self::format_range, js_string!("formatRange"), 2)
+            .method(
+                Self::format_range_to_parts,
+                js_string!("formatRangeToParts"),
+                2,
+            )
             .build();
     }
 
@@ -387,6 +564,8 @@ impl BuiltInConstructor for NumberFormat {
         )
         .map_err(|err| JsNativeError::typ().with_message(err.to_string()))?;
 
+        let range_patterns = RangePatterns::try_new(&locale, context.intl_provider())?;
+
         let number_format = JsObject::from_proto_and_data_with_shared_shape(
             context.root_shape(),
             prototype,
@@ -399,6 +578,7 @@ impl BuiltInConstructor for NumberFormat {
                 notation,
                 use_grouping,
                 sign_display,
+                range_patterns,
                 bound_format: None,
             },
         );
@@ -531,6 +711,110 @@ impl NumberFormat {
         Ok(bound_format.into())
     }
 
+    /// [`Intl.NumberFormat.prototype.formatRange ( start, end )`][spec].
+    ///
+    /// Returns a string representing the range between two numbers.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.numberformat.prototype.formatrange
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/NumberFormat/formatRange
+    fn format_range(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let nf be the this value.
+        // 2. Perform ? RequireInternalSlot(nf, [[InitializedNumberFormat]]).
+        // 3. If start is undefined or end is undefined, throw a TypeError exception.
+        // 4. Let x be ? ToIntlMathematicalValue(start).
+        // 5. Let y be ? ToIntlMathematicalValue(end).
+        let (nf, mut x, mut y) = range_arguments(this, args, "formatRange", context)?;
+
+        // 6. Return ? FormatNumericRange(nf, x, y).
+
+        // FormatNumericRange ( numberFormat, x, y )
+        // <https://tc39.es/ecma402/#sec-formatnumericrange>
+        // 1. Let parts be ? PartitionNumberRangePattern(numberFormat, x, y).
+        // 2. Let result be the empty String.
+        // 3. For each part in parts, do
+        //     a. Set result to the string-concatenation of result and part.[[Value]].
+        // 4. Return result.
+        let result: String = nf
+            .borrow()
+            .data
+            .format_range_to_parts(&mut x, &mut y)
+            .into_iter()
+            .map(|(part, _)| part.value)
+            .collect();
+
+        Ok(js_string!(result).into())
+    }
+
+    /// [`Intl.NumberFormat.prototype.formatRangeToParts ( start, end )`][spec].
+    ///
+    /// Returns an array of objects containing the locale-specific parts of the range between two
+    /// numbers.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.numberformat.prototype.formatrangetoparts
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/NumberFormat/formatRangeToParts
+    fn format_range_to_parts(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let nf be the this value.
+        // 2. Perform ? RequireInternalSlot(nf, [[InitializedNumberFormat]]).
+        // 3. If start is undefined or end is undefined, throw a TypeError exception.
+        // 4. Let x be ? ToIntlMathematicalValue(start).
+        // 5. Let y be ? ToIntlMathematicalValue(end).
+        let (nf, mut x, mut y) = range_arguments(this, args, "formatRangeToParts", context)?;
+
+        // 6. Return ? FormatNumericRangeToParts(nf, x, y).
+
+        // FormatNumericRangeToParts ( numberFormat, x, y )
+        // <https://tc39.es/ecma402/#sec-formatnumericrangetoparts>
+        // 1. Let parts be ? PartitionNumberRangePattern(numberFormat, x, y).
+        let parts = nf.borrow().data.format_range_to_parts(&mut x, &mut y);
+
+        // 2. Let result be ! ArrayCreate(0).
+        let result = Array::array_create(0, None, context)
+            .expect("creating an empty array with default proto must not fail");
+
+        // 3. Let n be 0.
+        // 4. For each element part of parts, do
+        for (n, (part, source)) in parts.into_iter().enumerate() {
+            // a. Let O be OrdinaryObjectCreate(%Object.prototype%).
+            let o = context
+                .intrinsics()
+                .templates()
+                .ordinary_object()
+                .create(OrdinaryObject, vec![]);
+
+            // b. Perform ! CreateDataPropertyOrThrow(O, "type", part.[[Type]]).
+            o.create_data_property_or_throw(js_string!("type"), js_string!(part.typ), context)
+                .expect("operation must not fail per the spec");
+
+            // c. Perform ! CreateDataPropertyOrThrow(O, "value", part.[[Value]]).
+            o.create_data_property_or_throw(js_string!("value"), js_string!(part.value), context)
+                .expect("operation must not fail per the spec");
+
+            // d. Perform ! CreateDataPropertyOrThrow(O, "source", part.[[Source]]).
+            o.create_data_property_or_throw(js_string!("source"), source.to_js_string(), context)
+                .expect("operation must not fail per the spec");
+
+            // e. Perform ! CreateDataPropertyOrThrow(result, ! ToString(𝔽(n)), O).
+            result
+                .create_data_property_or_throw(n, o, context)
+                .expect("operation must not fail per the spec");
+
+            // f. Increment n by 1.
+        }
+
+        // 5. Return result.
+        Ok(result.into())
+    }
+
     /// [`Intl.NumberFormat.prototype.resolvedOptions ( )`][spec].
     ///
     /// Returns a new object with properties reflecting the locale and options computed during the
@@ -762,6 +1046,110 @@ fn unwrap_number_format(nf: &JsValue, context: &mut Context) -> JsResult<JsObjec
         .into())
 }
 
+/// Formats `x` as `Number.prototype.toLocaleString` does.
+///
+/// The `Intl.NumberFormat` created when `locales` and `options` are both undefined is cached on
+/// the current realm, since it is by far the most common way to call `toLocaleString`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma402/#sup-number.prototype.tolocalestring
+pub(crate) fn format_number_to_locale_string(
+    x: f64,
+    locales: &JsValue,
+    options: &JsValue,
+    context: &mut Context,
+) -> JsResult<JsString> {
+    let construct = |context: &mut Context| -> JsResult<JsObject<NumberFormat>> {
+        let constructor = context
+            .intrinsics()
+            .constructors()
+            .number_format()
+            .constructor();
+        NumberFormat::constructor(
+            &constructor.into(),
+            &[locales.clone(), options.clone()],
+            context,
+        )?
+        .as_object()
+        .and_then(|nf| nf.clone().downcast::<NumberFormat>().ok())
+        .ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("constructor must return an `Intl.NumberFormat` object")
+                .into()
+        })
+    };
+
+    // 2. Let numberFormat be ? Construct(%Intl.NumberFormat%, « locales, options »).
+    let number_format = if locales.is_undefined() && options.is_undefined() {
+        context
+            .realm()
+            .clone()
+            .default_number_format(|| construct(context))?
+    } else {
+        construct(context)?
+    };
+
+    // 3. Return FormatNumeric(numberFormat, ! ToIntlMathematicalValue(x)).
+    // TODO: Add support in `Decimal` for infinity and NaN, which should remove these special
+    // cases.
+    if x.is_nan() {
+        return Ok(js_string!("NaN"));
+    }
+    if x.is_infinite() {
+        return Ok(if x.is_sign_negative() {
+            js_string!("-∞")
+        } else {
+            js_string!("∞")
+        });
+    }
+    let mut x = Decimal::try_from_f64(x, FloatPrecision::RoundTrip)
+        .map_err(|err| JsNativeError::range().with_message(err.to_string()))?;
+    Ok(js_string!(number_format
+        .borrow()
+        .data
+        .format(&mut x)
+        .to_string()))
+}
+
+/// Validates the `this` value and the arguments of `formatRange` and `formatRangeToParts`.
+fn range_arguments(
+    this: &JsValue,
+    args: &[JsValue],
+    method: &str,
+    context: &mut Context,
+) -> JsResult<(JsObject<NumberFormat>, Decimal, Decimal)> {
+    // 1. Let nf be the this value.
+    // 2. Perform ? RequireInternalSlot(nf, [[InitializedNumberFormat]]).
+    let nf = this
+        .as_object()
+        .and_then(|o| o.clone().downcast::<NumberFormat>().ok())
+        .ok_or_else(|| {
+            JsNativeError::typ().with_message(format!(
+                "`{method}` can only be called on an `Intl.NumberFormat` object"
+            ))
+        })?;
+
+    let start = args.get_or_undefined(0);
+    let end = args.get_or_undefined(1);
+
+    // 3. If start is undefined or end is undefined, throw a TypeError exception.
+    if start.is_undefined() || end.is_undefined() {
+        return Err(JsNativeError::typ()
+            .with_message(format!("`{method}` requires both a start and an end value"))
+            .into());
+    }
+
+    // 4. Let x be ? ToIntlMathematicalValue(start).
+    let x = to_intl_mathematical_value(start, context)?;
+
+    // 5. Let y be ? ToIntlMathematicalValue(end).
+    let y = to_intl_mathematical_value(end, context)?;
+
+    Ok((nf, x, y))
+}
+
 /// Abstract operation [`ToIntlMathematicalValue ( value )`][spec].
 ///
 /// [spec]: https://tc39.es/ecma402/#sec-tointlmathematicalvalue
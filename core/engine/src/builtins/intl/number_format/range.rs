@@ -0,0 +1,124 @@
+//! Locale data for the range and approximately patterns of `Intl.NumberFormat`.
+//!
+//! ICU4X doesn't export the `range` and `approximately` patterns of the CLDR `miscPatterns` yet,
+//! so they are taken from the CLDR data of ICU, keeping only the locales whose patterns differ
+//! from the ones they inherit. The locales are resolved with the locale fallback data of the
+//! ICU4X provider.
+
+use icu_locale::{fallback::LocaleFallbackConfig, Locale};
+
+use crate::context::icu::{IcuError, IntlProvider};
+
+/// The locale, the range separator and the approximately sign of each locale with its own
+/// patterns, sorted by locale.
+pub(super) const PATTERNS: &[(&str, &str, &str)] = &[
+    ("be", "–", "≈"),
+    ("bg", " – ", "~"),
+    ("bs", " – ", "≈"),
+    ("bs-Cyrl", "–", "~"),
+    ("bs-Latn", " – ", "≈"),
+    ("ca", "-", "~"),
+    ("da", "-", "~"),
+    ("de", "–", "≈"),
+    ("dsb", "–", "≈"),
+    ("eo", "–", "≈"),
+    ("es", "-", "~"),
+    ("et", "‒", "≈"),
+    ("eu", "-", "~"),
+    ("fil", "-", "~"),
+    ("fr", "–", "≃"),
+    ("fr-CA", "–", "≈"),
+    ("fy", "-", "~"),
+    ("gu", "-", "~"),
+    ("hr", " – ", "~"),
+    ("hsb", "–", "≈"),
+    ("it", "-", "~"),
+    ("ja", "～", "約"),
+    ("jv", " – ", "~"),
+    ("ka", "-", "≈"),
+    ("kea", " – ", "~"),
+    ("ko", "~", "~"),
+    ("lij", "-", "~"),
+    ("lt", "–", "∼"),
+    ("mk", " – ", "≈"),
+    ("ml", "-", "~"),
+    ("my", " - ", "~"),
+    ("nb", "–", "ca."),
+    ("nl", "-", "~"),
+    ("nn", "–", "≈"),
+    ("no", "–", "ca."),
+    ("oc-ES", "-", "~"),
+    ("pt-AO", " - ", "~"),
+    ("pt-CH", " - ", "~"),
+    ("pt-CV", " - ", "~"),
+    ("pt-GQ", " - ", "~"),
+    ("pt-GW", " - ", "~"),
+    ("pt-LU", " - ", "~"),
+    ("pt-MO", " - ", "~"),
+    ("pt-MZ", " - ", "~"),
+    ("pt-PT", " - ", "~"),
+    ("pt-ST", " - ", "~"),
+    ("pt-TL", " - ", "~"),
+    ("rm", "–", "≈"),
+    ("ro", " - ", "~"),
+    ("ru", "–", "≈"),
+    ("sk", " – ", "~"),
+    ("sq", "-", "≈"),
+    ("th", "-", "~"),
+    ("to", "—", "~"),
+    ("tt", "-", "~"),
+    ("vec", "-", "~"),
+    ("vi", "-", "~"),
+    ("wo", "–", "-"),
+    ("yo", "–", "dáàṣì"),
+    ("yo-BJ", "–", "dáàshì"),
+    ("yue", "-", "~"),
+    ("yue-Hans", "-", "~"),
+    ("yue-Hant", "-", "~"),
+    ("zh", "-", "~"),
+    ("zh-Hans", "-", "~"),
+    ("zh-Hant", "-", "~"),
+];
+
+/// The separator and the approximately sign used to format number ranges in a locale.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) struct RangePatterns {
+    /// The text between the start and the end of a range, from the CLDR `range` pattern.
+    pub(super) separator: &'static str,
+
+    /// The sign marking a range that formats as a single number, from the CLDR `approximately`
+    /// pattern.
+    pub(super) approximately_sign: &'static str,
+}
+
+impl RangePatterns {
+    /// The patterns of the root locale.
+    const ROOT: Self = Self {
+        separator: "–",
+        approximately_sign: "~",
+    };
+
+    /// Gets the range patterns of `locale`, falling back to the patterns of its parent locales.
+    pub(super) fn try_new(locale: &Locale, provider: &IntlProvider) -> Result<Self, IcuError> {
+        let fallbacker = provider.locale_fallbacker()?;
+        let mut iter = fallbacker
+            .for_config(LocaleFallbackConfig::default())
+            .fallback_for(locale.into());
+
+        while !iter.get().is_unknown() {
+            let current = iter.get();
+            if let Ok(index) =
+                PATTERNS.binary_search_by(|(id, _, _)| current.strict_cmp(id.as_bytes()).reverse())
+            {
+                let (_, separator, approximately_sign) = PATTERNS[index];
+                return Ok(Self {
+                    separator,
+                    approximately_sign,
+                });
+            }
+            iter.step();
+        }
+
+        Ok(Self::ROOT)
+    }
+}
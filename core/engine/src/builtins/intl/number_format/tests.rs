@@ -1,5 +1,11 @@
-use crate::builtins::intl::number_format::RoundingIncrement;
+use boa_macros::js_str;
 use fixed_decimal::RoundingIncrement::*;
+use indoc::indoc;
+
+use crate::{
+    builtins::intl::number_format::RoundingIncrement, run_test_actions, JsNativeErrorKind,
+    TestAction,
+};
 
 #[test]
 fn u16_to_rounding_increment_sunny_day() {
@@ -39,3 +45,113 @@ fn u16_to_rounding_increment_rainy_day() {
         assert!(RoundingIncrement::from_u16(num).is_none());
     }
 }
+
+#[test]
+fn format_range() {
+    run_test_actions([
+        TestAction::run("const nf = new Intl.NumberFormat('en');"),
+        TestAction::assert_eq("nf.formatRange(3, 5)", js_str!("3–5")),
+        TestAction::assert_eq("nf.formatRange(5, 3)", js_str!("5–3")),
+        TestAction::assert_eq("nf.formatRange(3, 3)", js_str!("~3")),
+        TestAction::assert_eq("nf.formatRange(-1, 1)", js_str!("-1 – 1")),
+        TestAction::assert_eq("nf.formatRange(1, -1)", js_str!("1–-1")),
+        TestAction::assert_eq("nf.formatRange(-0, 0)", js_str!("-0 – 0")),
+        TestAction::assert_eq(
+            "new Intl.NumberFormat('en', { maximumFractionDigits: 0 }).formatRange(2.9, 3.1)",
+            js_str!("~3"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.NumberFormat('en', { signDisplay: 'exceptZero' }).formatRange(-0.0001, 0.0001)",
+            js_str!("~0"),
+        ),
+        TestAction::assert_native_error(
+            "nf.formatRange(1)",
+            JsNativeErrorKind::Type,
+            "`formatRange` requires both a start and an end value",
+        ),
+        TestAction::assert(indoc! {r#"
+            try {
+                nf.formatRange(NaN, 1);
+                false
+            } catch (e) {
+                e instanceof RangeError
+            }
+        "#}),
+    ]);
+}
+
+#[test]
+fn format_range_to_parts() {
+    run_test_actions([
+        TestAction::run_harness(),
+        TestAction::run(indoc! {r#"
+            const nf = new Intl.NumberFormat('en');
+            const describe = (parts) =>
+                parts.map(({ type, value, source }) => `${type}:${value}:${source}`);
+        "#}),
+        TestAction::assert(indoc! {r#"
+            arrayEquals(
+                describe(nf.formatRangeToParts(-1000, 2.5)),
+                [
+                    "minusSign:-:startRange",
+                    "integer:1:startRange",
+                    "group:,:startRange",
+                    "integer:000:startRange",
+                    "literal: – :shared",
+                    "integer:2:endRange",
+                    "decimal:.:endRange",
+                    "fraction:5:endRange",
+                ],
+            )
+        "#}),
+        TestAction::assert(indoc! {r#"
+            arrayEquals(
+                describe(nf.formatRangeToParts(1, 1)),
+                ["approximatelySign:~:shared", "integer:1:shared"],
+            )
+        "#}),
+    ]);
+}
+
+#[test]
+fn format_range_locale_patterns() {
+    run_test_actions([
+        TestAction::assert_eq(
+            "new Intl.NumberFormat('de').formatRange(3, 3)",
+            js_str!("≈3"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.NumberFormat('de-AT').formatRange(3, 5)",
+            js_str!("3–5"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.NumberFormat('es').formatRange(3, 5)",
+            js_str!("3-5"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.NumberFormat('ja').formatRange(3, 5)",
+            js_str!("3～5"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.NumberFormat('ja').formatRange(3, 3)",
+            js_str!("約3"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.NumberFormat('pt-PT').formatRange(3, 5)",
+            js_str!("3 - 5"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.NumberFormat('pt-BR').formatRange(3, 5)",
+            js_str!("3–5"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.NumberFormat('zh-Hant-TW').formatRange(3, 5)",
+            js_str!("3-5"),
+        ),
+    ]);
+}
+
+#[test]
+fn range_patterns_are_sorted() {
+    assert!(super::range::PATTERNS.is_sorted_by(|(a, _, _), (b, _, _)| a < b));
+}
@@ -3,9 +3,8 @@ use std::{fmt, str::FromStr};
 use num_traits::FromPrimitive;
 
 use crate::{
-    builtins::{options::ParsableOptionType, OrdinaryObject},
-    object::JsObject,
-    Context, JsNativeError, JsResult, JsString, JsValue,
+    builtins::options::ParsableOptionType, object::JsObject, Context, JsNativeError, JsResult,
+    JsString, JsValue,
 };
 
 /// `IntlOptions` aggregates the `locale_matcher` selector and any other object
@@ -110,31 +109,3 @@ where
     // We already asserted the range of `value` with the conditional above.
     Ok(T::from_f64(value))
 }
-
-/// Abstract operation [`CoerceOptionsToObject ( options )`][spec]
-///
-/// Coerces `options` into a [`JsObject`] suitable for use with [`get_option`], defaulting to an
-/// empty `JsObject`.
-/// Because it coerces non-null primitive values into objects, its use is discouraged for new
-/// functionality in favour of [`get_options_object`].
-///
-/// [spec]: https://tc39.es/ecma402/#sec-coerceoptionstoobject
-/// [`get_option`]: crate::builtins::options::get_option
-/// [`get_options_object`]: crate::builtins::options::get_options_object
-pub(super) fn coerce_options_to_object(
-    options: &JsValue,
-    context: &mut Context,
-) -> JsResult<JsObject> {
-    // If options is undefined, then
-    if options.is_undefined() {
-        // a. Return OrdinaryObjectCreate(null).
-        return Ok(JsObject::from_proto_and_data_with_shared_shape(
-            context.root_shape(),
-            None,
-            OrdinaryObject,
-        ));
-    }
-
-    // 2. Return ? ToObject(options).
-    options.to_object(context)
-}
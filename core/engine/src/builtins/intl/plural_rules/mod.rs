@@ -11,8 +11,8 @@ use icu_plurals::{
 
 use crate::{
     builtins::{
-        options::get_option, Array, BuiltInBuilder, BuiltInConstructor, BuiltInObject,
-        IntrinsicObject,
+        options::{coerce_options_to_object, get_option},
+        Array, BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject,
     },
     context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
     js_string,
@@ -26,7 +26,7 @@ use crate::{
 use super::{
     locale::{canonicalize_locale_list, filter_locales, resolve_locale},
     number_format::{DigitFormatOptions, Extrema, NotationKind},
-    options::{coerce_options_to_object, IntlOptions},
+    options::IntlOptions,
     Service,
 };
 
@@ -1,11 +1,12 @@
 //! Boa's implementation of ECMAScript's `IteratorRecord` and iterator prototype objects.
 
 use crate::{
-    builtins::{BuiltInBuilder, IntrinsicObject},
+    builtins::{array::ArrayIterator, BuiltInBuilder, IntrinsicObject},
     context::intrinsics::Intrinsics,
     error::JsNativeError,
     js_string,
     object::JsObject,
+    property::PropertyNameKind,
     realm::Realm,
     symbol::JsSymbol,
     Context, JsResult, JsValue,
@@ -235,6 +236,24 @@ impl JsValue {
         method: &JsObject,
         context: &mut Context,
     ) -> JsResult<IteratorRecord> {
+        // Fast path for objects iterated with `%Array.prototype.values%`, which is what arrays use
+        // unless `Symbol.iterator` was changed: calling it only creates the array iterator, so
+        // creating it directly is indistinguishable from the call.
+        if let Some(object) = self.as_object() {
+            if *method == *context.intrinsics().objects().array_prototype_values() {
+                let iterator = ArrayIterator::create_array_iterator(
+                    object.clone(),
+                    PropertyNameKind::Value,
+                    context,
+                );
+                let iterator_obj = iterator
+                    .as_object()
+                    .expect("array iterators must be objects");
+                let next_method = iterator_obj.get(js_string!("next"), context)?;
+                return Ok(IteratorRecord::new(iterator_obj.clone(), next_method));
+            }
+        }
+
         // 1. Let iterator be ? Call(method, obj).
         let iterator = method.call(self, &[], context)?;
         // 2. If iterator is not an Object, throw a TypeError exception.
@@ -387,7 +406,18 @@ pub struct IteratorRecord {
     done: bool,
 
     /// The result of the last call to `next`.
-    last_result: IteratorResult,
+    last_result: LastResult,
+}
+
+/// The result of the last step of an [`IteratorRecord`].
+#[derive(Clone, Debug, Finalize, Trace)]
+enum LastResult {
+    /// The result object returned by the `next` method.
+    Object(IteratorResult),
+
+    /// The value of a step taken through the array iterator fast path, which doesn't allocate
+    /// a result object.
+    Value(JsValue),
 }
 
 impl IteratorRecord {
@@ -399,9 +429,9 @@ impl IteratorRecord {
             iterator,
             next_method,
             done: false,
-            last_result: IteratorResult {
+            last_result: LastResult::Object(IteratorResult {
                 object: JsObject::with_null_proto(),
-            },
+            }),
         }
     }
 
@@ -416,8 +446,18 @@ impl IteratorRecord {
     }
 
     /// Gets the last result object of the iterator record.
-    pub(crate) const fn last_result(&self) -> &IteratorResult {
-        &self.last_result
+    ///
+    /// If the last step was taken through the array iterator fast path, this creates the result
+    /// object that `next` would have returned.
+    pub(crate) fn last_result(&self, context: &mut Context) -> JsObject {
+        match &self.last_result {
+            LastResult::Object(result) => result.object().clone(),
+            LastResult::Value(value) => {
+                create_iter_result_object(value.clone(), self.done, context)
+                    .into_object()
+                    .expect("iterator result must be an object")
+            }
+        }
     }
 
     /// Runs `f`, setting the `done` field of this `IteratorRecord` to `true` if `f` returns
@@ -435,7 +475,10 @@ impl IteratorRecord {
 
     /// Gets the current value of the `IteratorRecord`.
     pub(crate) fn value(&mut self, context: &mut Context) -> JsResult<JsValue> {
-        self.set_done_on_err(|iter| iter.last_result.value(context))
+        self.set_done_on_err(|iter| match &iter.last_result {
+            LastResult::Object(result) => result.value(context),
+            LastResult::Value(value) => Ok(value.clone()),
+        })
     }
 
     /// Get the `[[Done]]` field of the `IteratorRecord`.
@@ -459,7 +502,7 @@ impl IteratorRecord {
             // 3. If done is true, return false.
             iter.done = result.complete(context)?;
 
-            iter.last_result = result;
+            iter.last_result = LastResult::Object(result);
 
             Ok(())
         })
@@ -519,6 +562,23 @@ impl IteratorRecord {
     /// [spec]: https://tc39.es/ecma262/#sec-iteratorstep
     pub(crate) fn step(&mut self, context: &mut Context) -> JsResult<bool> {
         self.set_done_on_err(|iter| {
+            // Fast path for built-in array iterators: if the `[[NextMethod]]` is the intrinsic
+            // `%ArrayIteratorPrototype%.next`, running its steps directly is indistinguishable from
+            // calling it, and reading `done` and `value` from the result object it would create is
+            // not observable, so the allocation of the result object can be skipped.
+            if iter.next_method.as_object().is_some_and(|next| {
+                *next
+                    == *context
+                        .intrinsics()
+                        .objects()
+                        .array_iterator_prototype_next()
+            }) {
+                let value = ArrayIterator::next_value(&iter.iterator.clone().into(), context)?;
+                iter.done = value.is_none();
+                iter.last_result = LastResult::Value(value.unwrap_or_default());
+                return Ok(iter.done);
+            }
+
             // 1. Let result be ? IteratorNext(iteratorRecord).
             let result = iter.next(None, context)?;
 
@@ -532,7 +592,7 @@ impl IteratorRecord {
             //     b. Return done.
             iter.done = result.complete(context)?;
 
-            iter.last_result = result;
+            iter.last_result = LastResult::Object(result);
 
             // 6. Return result.
             Ok(iter.done)
@@ -13,11 +13,8 @@
 //! [json]: https://www.json.org/json-en.html
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/JSON
 
-use std::{borrow::Cow, iter::once};
-
 use boa_ast::scope::Scope;
 use boa_macros::utf16;
-use itertools::Itertools;
 
 use crate::{
     builtins::BuiltInObject,
@@ -28,21 +25,30 @@ use crate::{
     object::{internal_methods::InternalMethodContext, JsObject},
     property::{Attribute, PropertyNameKind},
     realm::Realm,
-    string::{CodePoint, StaticJsStrings},
+    string::StaticJsStrings,
     symbol::JsSymbol,
-    value::IntegerOrInfinity,
+    value::{IntegerOrInfinity, JsVariant},
     vm::{CallFrame, CallFrameFlags},
-    Context, JsArgs, JsBigInt, JsResult, JsString, JsValue, SpannedSourceText,
+    Context, JsArgs, JsBigInt, JsResult, JsStr, JsString, JsValue, SpannedSourceText,
 };
 use boa_gc::Gc;
 use boa_parser::{Parser, Source};
 use boa_profiler::Profiler;
 
+use self::parse_record::JsonParseRecord;
 use super::{BuiltInBuilder, IntrinsicObject};
 
+mod parse_record;
+
 #[cfg(test)]
 mod tests;
 
+/// The `[[IsRawJSON]]` internal slot of objects created by `JSON.rawJSON`.
+#[cfg(feature = "experimental")]
+#[derive(Debug, Clone, Copy, boa_gc::Trace, boa_gc::Finalize, boa_macros::JsData)]
+#[boa_gc(empty_trace)]
+struct RawJson;
+
 /// JavaScript `JSON` global object.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub(crate) struct Json;
@@ -54,11 +60,17 @@ impl IntrinsicObject for Json {
         let to_string_tag = JsSymbol::to_string_tag();
         let attribute = Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE;
 
-        BuiltInBuilder::with_intrinsic::<Self>(realm)
+        let builder = BuiltInBuilder::with_intrinsic::<Self>(realm)
             .static_method(Self::parse, js_string!("parse"), 2)
             .static_method(Self::stringify, js_string!("stringify"), 3)
-            .static_property(to_string_tag, Self::NAME, attribute)
-            .build();
+            .static_property(to_string_tag, Self::NAME, attribute);
+
+        #[cfg(feature = "experimental")]
+        let builder = builder
+            .static_method(Self::raw_json, js_string!("rawJSON"), 1)
+            .static_method(Self::is_raw_json, js_string!("isRawJSON"), 1);
+
+        builder.build();
     }
 
     fn get(intrinsics: &Intrinsics) -> JsObject {
@@ -159,11 +171,18 @@ impl Json {
 
             // b. Let rootName be the empty String.
             // c. Perform ! CreateDataPropertyOrThrow(root, rootName, unfiltered).
-            root.create_data_property_or_throw(js_string!(), unfiltered, context)
+            root.create_data_property_or_throw(js_string!(), unfiltered.clone(), context)
                 .expect("CreateDataPropertyOrThrow should never throw here");
 
-            // d. Return ? InternalizeJSONProperty(root, rootName, reviver).
-            Self::internalize_json_property(&root, js_string!(), obj, context)
+            // d. Let snapshot be CreateJSONParseRecord(script, rootName, unfiltered).
+            let snapshot = if cfg!(feature = "experimental") {
+                Some(JsonParseRecord::new(&json_string, unfiltered, context)?)
+            } else {
+                None
+            };
+
+            // e. Return ? InternalizeJSONProperty(root, rootName, reviver, snapshot).
+            Self::internalize_json_property(&root, js_string!(), obj, snapshot.as_ref(), context)
         } else {
             // 12. Else,
             // a. Return unfiltered.
@@ -171,22 +190,51 @@ impl Json {
         }
     }
 
-    /// `25.5.1.1 InternalizeJSONProperty ( holder, name, reviver )`
+    /// `25.5.1.1 InternalizeJSONProperty ( holder, name, reviver, parseRecord )`
+    ///
+    /// The `parseRecord` is only provided if the JSON.parse source text access proposal is
+    /// enabled, in which case the reviver also receives a context object with the source text
+    /// of primitive values.
     ///
     /// More information:
     ///  - [ECMAScript reference][spec]
+    ///  - [JSON.parse source text access proposal][proposal]
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-internalizejsonproperty
+    /// [proposal]: https://tc39.es/proposal-json-parse-with-source/#sec-internalizejsonproperty
     fn internalize_json_property(
         holder: &JsObject,
         name: JsString,
         reviver: &JsObject,
+        parse_record: Option<&JsonParseRecord>,
         context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let val be ? Get(holder, name).
         let val = holder.get(name.clone(), context)?;
 
-        // 2. If Type(val) is Object, then
+        // 2. Let context be OrdinaryObjectCreate(%Object.prototype%).
+        let reviver_context = parse_record
+            .is_some()
+            .then(|| JsObject::with_object_proto(context.intrinsics()));
+
+        // 3. If parseRecord is a JSON Parse Record and SameValue(parseRecord.[[Value]], val) is true, then
+        //     a. If val is not an Object, then
+        //         i. Perform ! CreateDataPropertyOrThrow(context, "source", the source text matched by parseRecord.[[ParseNode]]).
+        //     b. Let elementRecords be parseRecord.[[Elements]].
+        //     c. Let entryRecords be parseRecord.[[Entries]].
+        // 4. Else,
+        //     a. Let elementRecords be a new empty List.
+        //     b. Let entryRecords be a new empty List.
+        let parse_record = parse_record.filter(|record| JsValue::same_value(&record.value, &val));
+        if let (Some(record), Some(reviver_context)) = (parse_record, &reviver_context) {
+            if let Some(source) = record.source() {
+                reviver_context
+                    .create_data_property_or_throw(js_string!("source"), source.clone(), context)
+                    .expect("CreateDataPropertyOrThrow should never throw here");
+            }
+        }
+
+        // 5. If Type(val) is Object, then
         if let Some(obj) = val.as_object() {
             // a. Let isArray be ? IsArray(val).
             // b. If isArray is true, then
@@ -197,9 +245,21 @@ impl Json {
                 let len = obj.length_of_array_like(context)? as i64;
                 for i in 0..len {
                     // 1. Let prop be ! ToString(𝔽(I)).
-                    // 2. Let newElement be ? InternalizeJSONProperty(val, prop, reviver).
-                    let new_element =
-                        Self::internalize_json_property(obj, i.into(), reviver, context)?;
+                    // 2. If I < the number of elements in elementRecords, let elementRecord be elementRecords[I]. Otherwise, let elementRecord be empty.
+                    let element_record = parse_record.and_then(|record| {
+                        usize::try_from(i)
+                            .ok()
+                            .and_then(|index| record.element(index))
+                    });
+
+                    // 3. Let newElement be ? InternalizeJSONProperty(val, prop, reviver, elementRecord).
+                    let new_element = Self::internalize_json_property(
+                        obj,
+                        i.into(),
+                        reviver,
+                        element_record,
+                        context,
+                    )?;
 
                     // 3. If newElement is undefined, then
                     if new_element.is_undefined() {
@@ -226,9 +286,17 @@ impl Json {
                         .expect("EnumerableOwnPropertyNames only returns strings")
                         .clone();
 
-                    // 1. Let newElement be ? InternalizeJSONProperty(val, P, reviver).
-                    let new_element =
-                        Self::internalize_json_property(obj, p.clone(), reviver, context)?;
+                    // 1. Let entryRecord be the element of entryRecords whose [[Key]] field is P. If there is no such element, let entryRecord be empty.
+                    let entry_record = parse_record.and_then(|record| record.entry(&p));
+
+                    // 2. Let newElement be ? InternalizeJSONProperty(val, P, reviver, entryRecord).
+                    let new_element = Self::internalize_json_property(
+                        obj,
+                        p.clone(),
+                        reviver,
+                        entry_record,
+                        context,
+                    )?;
 
                     // 2. If newElement is undefined, then
                     if new_element.is_undefined() {
@@ -244,8 +312,94 @@ impl Json {
             }
         }
 
-        // 3. Return ? Call(reviver, holder, « name, val »).
-        reviver.call(&holder.clone().into(), &[name.into(), val], context)
+        // 6. Return ? Call(reviver, holder, « name, val, context »).
+        if let Some(reviver_context) = reviver_context {
+            reviver.call(
+                &holder.clone().into(),
+                &[name.into(), val, reviver_context.into()],
+                context,
+            )
+        } else {
+            reviver.call(&holder.clone().into(), &[name.into(), val], context)
+        }
+    }
+
+    /// `JSON.rawJSON( text )`
+    ///
+    /// Creates a frozen object whose `rawJSON` property is serialized verbatim by
+    /// `JSON.stringify`.
+    ///
+    /// More information:
+    ///  - [JSON.parse source text access proposal][proposal]
+    ///
+    /// [proposal]: https://tc39.es/proposal-json-parse-with-source/#sec-json.rawjson
+    #[cfg(feature = "experimental")]
+    fn raw_json(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        use crate::{object::IntegrityLevel, value::JsonStreamParser};
+
+        // 1. Let jsonString be ? ToString(text).
+        let json_string = args.get_or_undefined(0).to_string(context)?;
+
+        // 2. Throw a SyntaxError exception if jsonString is the empty String, or if either the first or last code unit of jsonString is any of 0x0009 (CHARACTER TABULATION), 0x000A (LINE FEED), 0x000D (CARRIAGE RETURN), or 0x0020 (SPACE).
+        if json_string.is_empty() {
+            return Err(JsNativeError::syntax()
+                .with_message("JSON.rawJSON cannot be called with an empty string")
+                .into());
+        }
+        let is_whitespace = |c: Option<u16>| matches!(c, Some(0x0009 | 0x000A | 0x000D | 0x0020));
+        if is_whitespace(json_string.get(0))
+            || is_whitespace(json_string.get(json_string.len() - 1))
+        {
+            return Err(JsNativeError::syntax()
+                .with_message("JSON.rawJSON text cannot start or end with whitespace")
+                .into());
+        }
+
+        // 3. Parse StringToCodePoints(jsonString) as a JSON text as specified in ECMA-404. Throw a SyntaxError exception if it is not a valid JSON text as defined in that specification, or if its outermost value is an object or array as defined in that specification.
+        //    Lone surrogates are valid in JSON strings, so they are replaced before the text is
+        //    encoded to UTF-8, which doesn't change its validity.
+        let mut parser = JsonStreamParser::new();
+        parser.push(json_string.to_std_string_lossy().as_bytes(), context)?;
+        if parser.finish()?.is_object() {
+            return Err(JsNativeError::syntax()
+                .with_message("JSON.rawJSON text cannot be an object or an array")
+                .into());
+        }
+
+        // 4. Let internalSlotsList be « [[IsRawJSON]] ».
+        // 5. Let obj be OrdinaryObjectCreate(null, internalSlotsList).
+        let obj = JsObject::from_proto_and_data(None, RawJson);
+
+        // 6. Perform ! CreateDataPropertyOrThrow(obj, "rawJSON", jsonString).
+        obj.create_data_property_or_throw(js_string!("rawJSON"), json_string, context)
+            .expect("CreateDataPropertyOrThrow should never throw here");
+
+        // 7. Perform ! SetIntegrityLevel(obj, frozen).
+        obj.set_integrity_level(IntegrityLevel::Frozen, context)
+            .expect("SetIntegrityLevel should never throw here");
+
+        // 8. Return obj.
+        Ok(obj.into())
+    }
+
+    /// `JSON.isRawJSON( O )`
+    ///
+    /// Returns `true` if the argument was created by `JSON.rawJSON`.
+    ///
+    /// More information:
+    ///  - [JSON.parse source text access proposal][proposal]
+    ///
+    /// [proposal]: https://tc39.es/proposal-json-parse-with-source/#sec-json.israwjson
+    #[cfg(feature = "experimental")]
+    #[allow(clippy::unnecessary_wraps)]
+    fn is_raw_json(_: &JsValue, args: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 1. If Type(O) is Object and O has an [[IsRawJSON]] internal slot, return true.
+        // 2. Return false.
+        Ok(args
+            .get_or_undefined(0)
+            .as_object()
+            .is_some_and(|o| o.is::<RawJson>())
+            .into())
     }
 
     /// `JSON.stringify( value[, replacer[, space]] )`
@@ -273,7 +427,7 @@ impl Json {
         let stack = Vec::new();
 
         // 2. Let indent be the empty String.
-        let indent = js_string!();
+        let indent = Vec::new();
 
         // 3. Let PropertyList and ReplacerFunction be undefined.
         let mut property_list = None;
@@ -395,20 +549,24 @@ impl Json {
             replacer_function,
             stack,
             indent,
-            gap,
+            gap: gap.to_vec(),
             property_list,
+            output: Vec::new(),
         };
 
         // 12. Return ? SerializeJSONProperty(state, the empty String, wrapper).
-        Ok(
-            Self::serialize_json_property(&mut state, js_string!(), &wrapper, context)?
-                .map(Into::into)
-                .unwrap_or_default(),
-        )
+        if Self::serialize_json_property(&mut state, js_string!(), &wrapper, context)? {
+            Ok(js_string!(&state.output[..]).into())
+        } else {
+            Ok(JsValue::undefined())
+        }
     }
 
     /// `25.5.2.1 SerializeJSONProperty ( state, key, holder )`
     ///
+    /// Instead of returning the serialized value, this writes it to `state.[[Output]]`, returning
+    /// `false` if the value serializes to `undefined`, in which case nothing is written.
+    ///
     /// More information:
     ///  - [ECMAScript reference][spec]
     ///
@@ -418,7 +576,7 @@ impl Json {
         key: JsString,
         holder: &JsObject,
         context: &mut Context,
-    ) -> JsResult<Option<JsString>> {
+    ) -> JsResult<bool> {
         // 1. Let value be ? Get(holder, key).
         let mut value = holder.get(key.clone(), context)?;
 
@@ -444,6 +602,20 @@ impl Json {
 
         // 4. If Type(value) is Object, then
         if let Some(obj) = value.as_object().cloned() {
+            // NOTE: JSON.parse source text access proposal.
+            // If value has an [[IsRawJSON]] internal slot, then
+            //     Return ! Get(value, "rawJSON").
+            #[cfg(feature = "experimental")]
+            if obj.is::<RawJson>() {
+                let raw = obj
+                    .get(js_string!("rawJSON"), context)
+                    .expect("Get should never throw here");
+                if let Some(raw) = raw.as_string() {
+                    state.output.extend(raw.iter());
+                }
+                return Ok(true);
+            }
+
             // a. If value has a [[NumberData]] internal slot, then
             if obj.is::<f64>() {
                 // i. Set value to ? ToNumber(value).
@@ -466,91 +638,93 @@ impl Json {
             }
         }
 
-        // 5. If value is null, return "null".
-        if value.is_null() {
-            return Ok(Some(js_string!("null")));
-        }
-
-        // 6. If value is true, return "true".
-        // 7. If value is false, return "false".
-        if value.is_boolean() {
-            return Ok(Some(js_string!(if value.to_boolean() {
-                "true"
-            } else {
-                "false"
-            })));
-        }
-
-        // 8. If Type(value) is String, return QuoteJSONString(value).
-        if let Some(s) = value.as_string() {
-            return Ok(Some(Self::quote_json_string(s)));
-        }
-
-        // 9. If Type(value) is Number, then
-        if let Some(n) = value.as_number() {
-            // a. If value is finite, return ! ToString(value).
-            if n.is_finite() {
-                return Ok(Some(
-                    value
-                        .to_string(context)
-                        .expect("ToString should never fail here"),
-                ));
+        match value.variant() {
+            // 5. If value is null, return "null".
+            JsVariant::Null => state.output.extend_from_slice(utf16!("null")),
+            // 6. If value is true, return "true".
+            JsVariant::Boolean(true) => state.output.extend_from_slice(utf16!("true")),
+            // 7. If value is false, return "false".
+            JsVariant::Boolean(false) => state.output.extend_from_slice(utf16!("false")),
+            // 8. If Type(value) is String, return QuoteJSONString(value).
+            JsVariant::String(string) => {
+                Self::quote_json_string(&mut state.output, string.as_str())
             }
-
-            // b. Return "null".
-            return Ok(Some(js_string!("null")));
-        }
-
-        // 10. If Type(value) is BigInt, throw a TypeError exception.
-        if value.is_bigint() {
-            return Err(JsNativeError::typ()
-                .with_message("cannot serialize bigint to JSON")
-                .into());
-        }
-
-        // 11. If Type(value) is Object and IsCallable(value) is false, then
-        if let Some(obj) = value.as_object() {
-            if !obj.is_callable() {
+            // 9. If Type(value) is Number, then
+            //     a. If value is finite, return ! ToString(value).
+            //     b. Return "null".
+            JsVariant::Integer32(integer) => {
+                let mut buffer = itoa::Buffer::new();
+                let integer = buffer.format(integer);
+                state.output.extend(integer.bytes().map(u16::from));
+            }
+            JsVariant::Float64(number) if number.is_finite() => {
+                let mut buffer = ryu_js::Buffer::new();
+                let number = buffer.format_finite(number);
+                state.output.extend(number.bytes().map(u16::from));
+            }
+            JsVariant::Float64(_) => state.output.extend_from_slice(utf16!("null")),
+            // 10. If Type(value) is BigInt, throw a TypeError exception.
+            JsVariant::BigInt(_) => {
+                return Err(JsNativeError::typ()
+                    .with_message("cannot serialize bigint to JSON")
+                    .into());
+            }
+            // 11. If Type(value) is Object and IsCallable(value) is false, then
+            JsVariant::Object(obj) if !obj.is_callable() => {
                 // a. Let isArray be ? IsArray(value).
                 // b. If isArray is true, return ? SerializeJSONArray(state, value).
                 // c. Return ? SerializeJSONObject(state, value).
-                return if obj.is_array_abstract()? {
-                    Ok(Some(Self::serialize_json_array(state, obj, context)?))
+                if obj.is_array_abstract()? {
+                    Self::serialize_json_array(state, obj, context)?;
                 } else {
-                    Ok(Some(Self::serialize_json_object(state, obj, context)?))
-                };
+                    Self::serialize_json_object(state, obj, context)?;
+                }
             }
+            // 12. Return undefined.
+            JsVariant::Object(_) | JsVariant::Symbol(_) | JsVariant::Undefined => return Ok(false),
         }
 
-        // 12. Return undefined.
-        Ok(None)
+        Ok(true)
     }
 
     /// `25.5.2.2 QuoteJSONString ( value )`
     ///
+    /// Writes the quoted string to `output`.
+    ///
     /// More information:
     ///  - [ECMAScript reference][spec]
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-quotejsonstring
-    fn quote_json_string(value: &JsString) -> JsString {
-        let mut buf = [0; 2];
+    fn quote_json_string(output: &mut Vec<u16>, value: JsStr<'_>) {
+        /// Appends `UnicodeEscape(unit)` to `output`.
+        fn unicode_escape(output: &mut Vec<u16>, unit: u16) {
+            const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+            output.extend_from_slice(utf16!(r"\u"));
+            output.extend(
+                [12, 8, 4, 0]
+                    .map(|shift| u16::from(HEX_DIGITS[usize::from((unit >> shift) & 0xF)])),
+            );
+        }
+
         // 1. Let product be the String value consisting solely of the code unit 0x0022 (QUOTATION MARK).
-        let mut product = vec!['"' as u16];
+        output.reserve(value.len() + 2);
+        output.push(u16::from(b'"'));
 
         // 2. For each code point C of ! StringToCodePoints(value), do
-        for code_point in value.code_points() {
-            match code_point {
+        let mut units = value.iter().peekable();
+        while let Some(unit) = units.next() {
+            match unit {
                 // a. If C is listed in the “Code Point” column of Table 73, then
                 // i. Set product to the string-concatenation of product and the
                 // escape sequence for C as specified in the “Escape Sequence”
                 // column of the corresponding row.
-                CodePoint::Unicode('\u{0008}') => product.extend_from_slice(utf16!(r"\b")),
-                CodePoint::Unicode('\u{0009}') => product.extend_from_slice(utf16!(r"\t")),
-                CodePoint::Unicode('\u{000A}') => product.extend_from_slice(utf16!(r"\n")),
-                CodePoint::Unicode('\u{000C}') => product.extend_from_slice(utf16!(r"\f")),
-                CodePoint::Unicode('\u{000D}') => product.extend_from_slice(utf16!(r"\r")),
-                CodePoint::Unicode('\u{0022}') => product.extend_from_slice(utf16!(r#"\""#)),
-                CodePoint::Unicode('\u{005C}') => product.extend_from_slice(utf16!(r"\\")),
+                0x0008 => output.extend_from_slice(utf16!(r"\b")),
+                0x0009 => output.extend_from_slice(utf16!(r"\t")),
+                0x000A => output.extend_from_slice(utf16!(r"\n")),
+                0x000C => output.extend_from_slice(utf16!(r"\f")),
+                0x000D => output.extend_from_slice(utf16!(r"\r")),
+                0x0022 => output.extend_from_slice(utf16!(r#"\""#)),
+                0x005C => output.extend_from_slice(utf16!(r"\\")),
                 // b. Else if C has a numeric value less than 0x0020 (SPACE), or
                 // if C has the same numeric value as a leading surrogate or
                 // trailing surrogate, then
@@ -558,25 +732,23 @@ impl Json {
                 //     of C.
                 //     ii. Set product to the string-concatenation of product
                 //     and UnicodeEscape(unit).
-                CodePoint::Unicode(c) if c < '\u{0020}' => {
-                    product.extend(format!("\\u{:04x}", c as u32).encode_utf16());
-                }
-                CodePoint::UnpairedSurrogate(surr) => {
-                    product.extend(format!("\\u{surr:04x}").encode_utf16());
-                }
+                0x0000..0x0020 => unicode_escape(output, unit),
+                0xD800..=0xDBFF => match units.next_if(|next| (0xDC00..=0xDFFF).contains(next)) {
+                    // A leading surrogate followed by a trailing surrogate is a single code point,
+                    // which is written unescaped.
+                    Some(trail) => output.extend_from_slice(&[unit, trail]),
+                    None => unicode_escape(output, unit),
+                },
+                0xDC00..=0xDFFF => unicode_escape(output, unit),
                 // c. Else,
-                CodePoint::Unicode(c) => {
-                    // i. Set product to the string-concatenation of product and ! UTF16EncodeCodePoint(C).
-                    product.extend_from_slice(c.encode_utf16(&mut buf));
-                }
+                //     i. Set product to the string-concatenation of product and ! UTF16EncodeCodePoint(C).
+                _ => output.push(unit),
             }
         }
 
         // 3. Set product to the string-concatenation of product and the code unit 0x0022 (QUOTATION MARK).
-        product.push('"' as u16);
-
         // 4. Return product.
-        js_string!(&product[..])
+        output.push(u16::from(b'"'));
     }
 
     /// `25.5.2.4 SerializeJSONObject ( state, value )`
@@ -589,7 +761,7 @@ impl Json {
         state: &mut StateRecord,
         value: &JsObject,
         context: &mut Context,
-    ) -> JsResult<JsString> {
+    ) -> JsResult<()> {
         // 1. If state.[[Stack]] contains value, throw a TypeError exception because the structure is cyclical.
         if state.stack.contains(value) {
             return Err(JsNativeError::typ()
@@ -601,10 +773,10 @@ impl Json {
         state.stack.push(value.clone());
 
         // 3. Let stepback be state.[[Indent]].
-        let stepback = state.indent.clone();
+        let stepback = state.indent.len();
 
         // 4. Set state.[[Indent]] to the string-concatenation of state.[[Indent]] and state.[[Gap]].
-        state.indent = js_string!(&state.indent, &state.gap);
+        state.indent.extend_from_slice(&state.gap);
 
         // 5. If state.[[PropertyList]] is not undefined, then
         let k = if let Some(p) = &state.property_list {
@@ -624,92 +796,70 @@ impl Json {
         };
 
         // 7. Let partial be a new empty List.
-        let mut partial = Vec::new();
+        // NOTE: Instead of collecting the members in partial and joining them afterwards, each
+        // member is written directly to the output, and removed again if strP is undefined.
+        state.output.push(u16::from(b'{'));
+        let mut partial_is_empty = true;
 
         // 8. For each element P of K, do
         for p in &k {
-            // a. Let strP be ? SerializeJSONProperty(state, P, value).
-            let str_p = Self::serialize_json_property(state, p.clone(), value, context)?;
+            let member_start = state.output.len();
 
-            // b. If strP is not undefined, then
-            if let Some(str_p) = str_p {
-                // i. Let member be QuoteJSONString(P).
-                let mut member = Self::quote_json_string(p).iter().collect::<Vec<_>>();
+            // Separate the member from the previous one as specified in steps 10.a.i and 10.b.i-ii.
+            if !partial_is_empty {
+                state.output.push(u16::from(b','));
+            }
+            if !state.gap.is_empty() {
+                state.output.push(u16::from(b'\n'));
+                state.output.extend_from_slice(&state.indent);
+            }
 
-                // ii. Set member to the string-concatenation of member and ":".
-                member.push(':' as u16);
+            // b.i. Let member be QuoteJSONString(P).
+            Self::quote_json_string(&mut state.output, p.as_str());
 
-                // iii. If state.[[Gap]] is not the empty String, then
-                if !state.gap.is_empty() {
-                    // 1. Set member to the string-concatenation of member and the code unit 0x0020 (SPACE).
-                    member.push(' ' as u16);
-                }
+            // b.ii. Set member to the string-concatenation of member and ":".
+            state.output.push(u16::from(b':'));
 
-                // iv. Set member to the string-concatenation of member and strP.
-                member.extend(str_p.iter());
+            // b.iii. If state.[[Gap]] is not the empty String, then
+            if !state.gap.is_empty() {
+                // 1. Set member to the string-concatenation of member and the code unit 0x0020 (SPACE).
+                state.output.push(u16::from(b' '));
+            }
 
-                // v. Append member to partial.
-                partial.push(member);
+            // a. Let strP be ? SerializeJSONProperty(state, P, value).
+            // b. If strP is not undefined, then
+            //     iv. Set member to the string-concatenation of member and strP.
+            //     v. Append member to partial.
+            if Self::serialize_json_property(state, p.clone(), value, context)? {
+                partial_is_empty = false;
+            } else {
+                state.output.truncate(member_start);
             }
         }
 
         // 9. If partial is empty, then
-        let r#final = if partial.is_empty() {
-            // a. Let final be "{}".
-            js_string!("{}")
+        //     a. Let final be "{}".
         // 10. Else,
-        } else {
-            // a. If state.[[Gap]] is the empty String, then
-            if state.gap.is_empty() {
-                // i. Let properties be the String value formed by concatenating all the element Strings of partial
-                //    with each adjacent pair of Strings separated with the code unit 0x002C (COMMA).
-                //    A comma is not inserted either before the first String or after the last String.
-                // ii. Let final be the string-concatenation of "{", properties, and "}".
-                let separator = utf16!(",");
-                let result = once(utf16!("{"))
-                    .chain(Itertools::intersperse(
-                        partial.iter().map(Vec::as_slice),
-                        separator,
-                    ))
-                    .chain(once(utf16!("}")))
-                    .flatten()
-                    .copied()
-                    .collect::<Vec<_>>();
-                js_string!(&result[..])
-            // b. Else,
-            } else {
-                // i. Let separator be the string-concatenation of the code unit 0x002C (COMMA),
-                //    the code unit 0x000A (LINE FEED), and state.[[Indent]].
-                let mut separator = utf16!(",\n").to_vec();
-                separator.extend(state.indent.iter());
-                // ii. Let properties be the String value formed by concatenating all the element Strings of partial
-                //     with each adjacent pair of Strings separated with separator.
-                //     The separator String is not inserted either before the first String or after the last String.
-                // iii. Let final be the string-concatenation of "{", the code
-                //      unit 0x000A (LINE FEED), state.[[Indent]], properties,
-                //      the code unit 0x000A (LINE FEED), stepback, and "}".
-                let result = [utf16!("{\n"), &state.indent.to_vec()[..]]
-                    .into_iter()
-                    .chain(Itertools::intersperse(
-                        partial.iter().map(Vec::as_slice),
-                        &separator,
-                    ))
-                    .chain([utf16!("\n"), &stepback.to_vec()[..], utf16!("}")])
-                    .flatten()
-                    .copied()
-                    .collect::<Vec<_>>();
-                js_string!(&result[..])
-            }
-        };
+        //     a. If state.[[Gap]] is the empty String, then
+        //         ii. Let final be the string-concatenation of "{", properties, and "}".
+        //     b. Else,
+        //         iii. Let final be the string-concatenation of "{", the code
+        //              unit 0x000A (LINE FEED), state.[[Indent]], properties,
+        //              the code unit 0x000A (LINE FEED), stepback, and "}".
+        if !partial_is_empty && !state.gap.is_empty() {
+            state.output.push(u16::from(b'\n'));
+            state.output.extend_from_slice(&state.indent[..stepback]);
+        }
+        state.output.push(u16::from(b'}'));
 
         // 11. Remove the last element of state.[[Stack]].
         state.stack.pop();
 
         // 12. Set state.[[Indent]] to stepback.
-        state.indent = stepback;
+        state.indent.truncate(stepback);
 
         // 13. Return final.
-        Ok(r#final)
+        Ok(())
     }
 
     /// `25.5.2.5 SerializeJSONArray ( state, value )`
@@ -722,7 +872,7 @@ impl Json {
         state: &mut StateRecord,
         value: &JsObject,
         context: &mut Context,
-    ) -> JsResult<JsString> {
+    ) -> JsResult<()> {
         // 1. If state.[[Stack]] contains value, throw a TypeError exception because the structure is cyclical.
         if state.stack.contains(value) {
             return Err(JsNativeError::typ()
@@ -734,13 +884,15 @@ impl Json {
         state.stack.push(value.clone());
 
         // 3. Let stepback be state.[[Indent]].
-        let stepback = state.indent.clone();
+        let stepback = state.indent.len();
 
         // 4. Set state.[[Indent]] to the string-concatenation of state.[[Indent]] and state.[[Gap]].
-        state.indent = js_string!(&state.indent, &state.gap);
+        state.indent.extend_from_slice(&state.gap);
 
         // 5. Let partial be a new empty List.
-        let mut partial = Vec::new();
+        // NOTE: Instead of collecting the elements in partial and joining them afterwards, each
+        // element is written directly to the output.
+        state.output.push(u16::from(b'['));
 
         // 6. Let len be ? LengthOfArrayLike(value).
         let len = value.length_of_array_like(context)?;
@@ -750,17 +902,22 @@ impl Json {
 
         // 8. Repeat, while index < len,
         while index < len {
-            // a. Let strP be ? SerializeJSONProperty(state, ! ToString(𝔽(index)), value).
-            let str_p = Self::serialize_json_property(state, index.into(), value, context)?;
+            // Separate the element from the previous one as specified in steps 10.a.i and 10.b.i-ii.
+            if index > 0 {
+                state.output.push(u16::from(b','));
+            }
+            if !state.gap.is_empty() {
+                state.output.push(u16::from(b'\n'));
+                state.output.extend_from_slice(&state.indent);
+            }
 
+            // a. Let strP be ? SerializeJSONProperty(state, ! ToString(𝔽(index)), value).
             // b. If strP is undefined, then
-            if let Some(str_p) = str_p {
-                // i. Append strP to partial.
-                partial.push(Cow::Owned(str_p.iter().collect::<_>()));
+            //     i. Append "null" to partial.
             // c. Else,
-            } else {
-                // i. Append "null" to partial.
-                partial.push(Cow::Borrowed(utf16!("null")));
+            //     i. Append strP to partial.
+            if !Self::serialize_json_property(state, index.into(), value, context)? {
+                state.output.extend_from_slice(utf16!("null"));
             }
 
             // d. Set index to index + 1.
@@ -768,67 +925,38 @@ impl Json {
         }
 
         // 9. If partial is empty, then
-        let r#final = if partial.is_empty() {
-            // a. Let final be "[]".
-            js_string!("[]")
+        //     a. Let final be "[]".
         // 10. Else,
-        } else {
-            // a. If state.[[Gap]] is the empty String, then
-            if state.gap.is_empty() {
-                // i. Let properties be the String value formed by concatenating all the element Strings of partial
-                //    with each adjacent pair of Strings separated with the code unit 0x002C (COMMA).
-                //    A comma is not inserted either before the first String or after the last String.
-                // ii. Let final be the string-concatenation of "[", properties, and "]".
-                let separator = utf16!(",");
-                let result = once(utf16!("["))
-                    .chain(Itertools::intersperse(
-                        partial.iter().map(Cow::as_ref),
-                        separator,
-                    ))
-                    .chain(once(utf16!("]")))
-                    .flatten()
-                    .copied()
-                    .collect::<Vec<_>>();
-                js_string!(&result[..])
-            // b. Else,
-            } else {
-                // i. Let separator be the string-concatenation of the code unit 0x002C (COMMA),
-                //    the code unit 0x000A (LINE FEED), and state.[[Indent]].
-                let mut separator = utf16!(",\n").to_vec();
-                separator.extend(state.indent.iter());
-                // ii. Let properties be the String value formed by concatenating all the element Strings of partial
-                //     with each adjacent pair of Strings separated with separator.
-                //     The separator String is not inserted either before the first String or after the last String.
-                // iii. Let final be the string-concatenation of "[", the code unit 0x000A (LINE FEED), state.[[Indent]], properties, the code unit 0x000A (LINE FEED), stepback, and "]".
-                let result = [utf16!("[\n"), &state.indent.to_vec()[..]]
-                    .into_iter()
-                    .chain(Itertools::intersperse(
-                        partial.iter().map(Cow::as_ref),
-                        &separator,
-                    ))
-                    .chain([utf16!("\n"), &stepback.to_vec()[..], utf16!("]")])
-                    .flatten()
-                    .copied()
-                    .collect::<Vec<_>>();
-                js_string!(&result[..])
-            }
-        };
+        //     a. If state.[[Gap]] is the empty String, then
+        //         ii. Let final be the string-concatenation of "[", properties, and "]".
+        //     b. Else,
+        //         iii. Let final be the string-concatenation of "[", the code unit 0x000A (LINE FEED), state.[[Indent]], properties, the code unit 0x000A (LINE FEED), stepback, and "]".
+        if len > 0 && !state.gap.is_empty() {
+            state.output.push(u16::from(b'\n'));
+            state.output.extend_from_slice(&state.indent[..stepback]);
+        }
+        state.output.push(u16::from(b']'));
 
         // 11. Remove the last element of state.[[Stack]].
         state.stack.pop();
 
         // 12. Set state.[[Indent]] to stepback.
-        state.indent = stepback;
+        state.indent.truncate(stepback);
 
         // 13. Return final.
-        Ok(r#final)
+        Ok(())
     }
 }
 
+/// The state of a `JSON.stringify` call.
+///
+/// The output is written to a single UTF-16 buffer, which avoids creating an intermediate string
+/// for every serialized value.
 struct StateRecord {
     replacer_function: Option<JsObject>,
     stack: Vec<JsObject>,
-    indent: JsString,
-    gap: JsString,
+    indent: Vec<u16>,
+    gap: Vec<u16>,
     property_list: Option<Vec<JsString>>,
+    output: Vec<u16>,
 }
@@ -0,0 +1,214 @@
+//! `JSON Parse Record`s, which give the reviver of `JSON.parse` access to the source text of
+//! primitive values.
+//!
+//! More information:
+//!  - [JSON.parse source text access proposal][proposal]
+//!
+//! [proposal]: https://tc39.es/proposal-json-parse-with-source/
+
+use rustc_hash::FxHashMap;
+
+use crate::{value::decode_json_string, Context, JsResult, JsString, JsValue};
+
+/// A value of a JSON text, referencing its source text.
+#[derive(Debug)]
+enum Node<'a> {
+    Primitive(&'a str),
+    Array(Vec<Node<'a>>),
+    /// The keys are the quoted source text of the property names.
+    Object(Vec<(&'a str, Node<'a>)>),
+}
+
+/// A minimal scanner over a JSON text that was already validated, used to recover the source
+/// text of each value.
+struct Scanner<'a> {
+    text: &'a str,
+    pos: usize,
+}
+
+impl<'a> Scanner<'a> {
+    fn peek(&self) -> Option<u8> {
+        self.text.as_bytes().get(self.pos).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(self.peek(), Some(b' ' | b'\t' | b'\n' | b'\r')) {
+            self.pos += 1;
+        }
+    }
+
+    /// Consumes `byte` if it is the next non-whitespace byte.
+    fn eat(&mut self, byte: u8) -> bool {
+        self.skip_whitespace();
+        if self.peek() == Some(byte) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn string(&mut self) -> &'a str {
+        let start = self.pos;
+        self.pos += 1;
+        while let Some(byte) = self.peek() {
+            self.pos += 1;
+            match byte {
+                b'\\' => self.pos += 1,
+                b'"' => break,
+                _ => {}
+            }
+        }
+        &self.text[start..self.pos]
+    }
+
+    fn value(&mut self) -> Node<'a> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some(b'[') => {
+                self.pos += 1;
+                let mut elements = Vec::new();
+                if !self.eat(b']') {
+                    loop {
+                        elements.push(self.value());
+                        if !self.eat(b',') {
+                            self.eat(b']');
+                            break;
+                        }
+                    }
+                }
+                Node::Array(elements)
+            }
+            Some(b'{') => {
+                self.pos += 1;
+                let mut entries = Vec::new();
+                if !self.eat(b'}') {
+                    loop {
+                        self.skip_whitespace();
+                        let key = self.string();
+                        self.eat(b':');
+                        entries.push((key, self.value()));
+                        if !self.eat(b',') {
+                            self.eat(b'}');
+                            break;
+                        }
+                    }
+                }
+                Node::Object(entries)
+            }
+            Some(b'"') => Node::Primitive(self.string()),
+            _ => {
+                let start = self.pos;
+                while let Some(byte) = self.peek() {
+                    if matches!(byte, b',' | b']' | b'}' | b' ' | b'\t' | b'\n' | b'\r') {
+                        break;
+                    }
+                    self.pos += 1;
+                }
+                Node::Primitive(&self.text[start..self.pos])
+            }
+        }
+    }
+}
+
+/// A `JSON Parse Record`.
+///
+/// More information:
+///  - [JSON.parse source text access proposal][proposal]
+///
+/// [proposal]: https://tc39.es/proposal-json-parse-with-source/#sec-json-parse-record
+#[derive(Debug)]
+pub(super) struct JsonParseRecord {
+    /// `[[Value]]`
+    pub(super) value: JsValue,
+
+    kind: JsonParseRecordKind,
+}
+
+#[derive(Debug)]
+enum JsonParseRecordKind {
+    /// The source text matched by the `[[ParseNode]]` of a primitive value.
+    Primitive(JsString),
+
+    /// `[[Elements]]`
+    Array(Vec<JsonParseRecord>),
+
+    /// `[[Entries]]`, keyed by property name.
+    Object(FxHashMap<JsString, JsonParseRecord>),
+}
+
+impl JsonParseRecord {
+    /// `CreateJSONParseRecord ( parseNode, key, val )`
+    ///
+    /// Creates the parse record of `value`, which must be the result of evaluating the valid JSON
+    /// text `text`.
+    ///
+    /// More information:
+    ///  - [JSON.parse source text access proposal][proposal]
+    ///
+    /// [proposal]: https://tc39.es/proposal-json-parse-with-source/#sec-createjsonparserecord
+    pub(super) fn new(text: &str, value: JsValue, context: &mut Context) -> JsResult<Self> {
+        let node = Scanner { text, pos: 0 }.value();
+        Self::from_node(node, value, context)
+    }
+
+    fn from_node(node: Node<'_>, value: JsValue, context: &mut Context) -> JsResult<Self> {
+        let kind = match (node, value.as_object().cloned()) {
+            (Node::Array(elements), Some(array)) => {
+                let mut records = Vec::with_capacity(elements.len());
+                for (index, element) in elements.into_iter().enumerate() {
+                    let element_value = array.get(index, context)?;
+                    records.push(Self::from_node(element, element_value, context)?);
+                }
+                JsonParseRecordKind::Array(records)
+            }
+            (Node::Object(entries), Some(object)) => {
+                let mut records = FxHashMap::default();
+                records.reserve(entries.len());
+                for (key, entry) in entries {
+                    let key = key
+                        .strip_prefix('"')
+                        .and_then(|key| key.strip_suffix('"'))
+                        .and_then(|key| decode_json_string(key.as_bytes()));
+                    let Some(key) = key else {
+                        continue;
+                    };
+                    let entry_value = object.get(key.clone(), context)?;
+
+                    // Only the last definition of a duplicated key produces the property value.
+                    records.insert(key, Self::from_node(entry, entry_value, context)?);
+                }
+                JsonParseRecordKind::Object(records)
+            }
+            (Node::Primitive(source), _) => JsonParseRecordKind::Primitive(JsString::from(source)),
+            // The structure of the value always matches the JSON text it was evaluated from.
+            (Node::Array(_) | Node::Object(_), None) => JsonParseRecordKind::Array(Vec::new()),
+        };
+
+        Ok(Self { value, kind })
+    }
+
+    /// Gets the record of the element at `index`, if this is the record of an array.
+    pub(super) fn element(&self, index: usize) -> Option<&Self> {
+        match &self.kind {
+            JsonParseRecordKind::Array(elements) => elements.get(index),
+            _ => None,
+        }
+    }
+
+    /// Gets the record of the entry with key `key`, if this is the record of an object.
+    pub(super) fn entry(&self, key: &JsString) -> Option<&Self> {
+        match &self.kind {
+            JsonParseRecordKind::Object(entries) => entries.get(key),
+            _ => None,
+        }
+    }
+
+    /// Gets the source text of this record, if this is the record of a primitive value.
+    pub(super) fn source(&self) -> Option<&JsString> {
+        match &self.kind {
+            JsonParseRecordKind::Primitive(source) => Some(source),
+            _ => None,
+        }
+    }
+}
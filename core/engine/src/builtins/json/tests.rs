@@ -240,6 +240,32 @@ fn json_stringify_pretty_print_with_string_object() {
     )]);
 }
 
+#[test]
+fn json_stringify_pretty_print_skips_undefined_members() {
+    run_test_actions([TestAction::assert_eq(
+        r#"JSON.stringify({ a: undefined, b: [undefined, {}, []], c: { d: () => {} } }, null, 2)"#,
+        js_string!("{\n  \"b\": [\n    null,\n    {},\n    []\n  ],\n  \"c\": {}\n}"),
+    )]);
+}
+
+#[test]
+fn json_stringify_escapes_strings() {
+    run_test_actions([
+        TestAction::assert_eq(
+            r#"JSON.stringify("\u{1F600}\uD800a\uDC00\u0001\n\"")"#,
+            js_string!(r#""😀\ud800a\udc00\u0001\n\"""#),
+        ),
+        TestAction::assert_eq(
+            "JSON.stringify([-0, 1e21, 0.1, -5, NaN, 2147483648])",
+            js_string!("[0,1e+21,0.1,-5,null,2147483648]"),
+        ),
+        TestAction::assert_eq(
+            r#"JSON.stringify({ "\u00e9": "\u00ff" })"#,
+            js_string!("{\"\u{e9}\":\"\u{ff}\"}"),
+        ),
+    ]);
+}
+
 #[test]
 fn json_parse_array_with_reviver() {
     run_test_actions([
@@ -315,3 +341,90 @@ fn json_parse_with_no_args_throws_syntax_error() {
         "expected value at line 1 column 1",
     )]);
 }
+
+#[cfg(feature = "experimental")]
+#[test]
+fn json_parse_reviver_source_text() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+                const sources = {};
+                const parsed = JSON.parse(
+                    '{"big": 12345678901234567890, "list": [1.50, "a\\u0062"], "nested": {"x": null}}',
+                    (key, value, context) => {
+                        sources[key] = context.source;
+                        return key === "big" ? BigInt(context.source) : value;
+                    },
+                );
+            "#}),
+        TestAction::assert("parsed.big === 12345678901234567890n"),
+        TestAction::assert_eq("sources[0]", js_str!("1.50")),
+        TestAction::assert_eq("sources[1]", js_str!(r#""a\u0062""#)),
+        TestAction::assert_eq("sources.x", js_str!("null")),
+        TestAction::assert("sources.list === undefined && sources.nested === undefined"),
+        TestAction::assert(indoc! {r#"
+                let replaced;
+                JSON.parse('[1, 2]', function (key, value, context) {
+                    if (key === "0") {
+                        this[1] = 3;
+                    }
+                    if (key === "1") {
+                        replaced = context.source;
+                    }
+                    return value;
+                });
+                replaced === undefined
+            "#}),
+        TestAction::assert_eq(
+            indoc! {r#"
+                let duplicate;
+                JSON.parse('{"a": 1, "\\u0061": 2.0}', (key, value, context) => {
+                    if (key === "a") {
+                        duplicate = context.source;
+                    }
+                    return value;
+                });
+                duplicate
+            "#},
+            js_str!("2.0"),
+        ),
+    ]);
+}
+
+#[cfg(feature = "experimental")]
+#[test]
+fn json_raw_json() {
+    run_test_actions([
+        TestAction::assert_eq(
+            "JSON.stringify({ n: JSON.rawJSON('12345678901234567890') })",
+            js_string!(r#"{"n":12345678901234567890}"#),
+        ),
+        TestAction::assert("JSON.isRawJSON(JSON.rawJSON('null'))"),
+        TestAction::assert("!JSON.isRawJSON({ rawJSON: 'null' })"),
+        TestAction::assert("Object.isFrozen(JSON.rawJSON('1'))"),
+        TestAction::assert("Object.getPrototypeOf(JSON.rawJSON('1')) === null"),
+        TestAction::assert_eq(
+            "JSON.stringify([JSON.rawJSON('1e400'), JSON.rawJSON('\"\\\\uD800\"')])",
+            js_string!(r#"[1e400,"\uD800"]"#),
+        ),
+        TestAction::assert_native_error(
+            "JSON.rawJSON(' 1')",
+            JsNativeErrorKind::Syntax,
+            "JSON.rawJSON text cannot start or end with whitespace",
+        ),
+        TestAction::assert_native_error(
+            "JSON.rawJSON('[1]')",
+            JsNativeErrorKind::Syntax,
+            "JSON.rawJSON text cannot be an object or an array",
+        ),
+    ]);
+}
+
+#[test]
+fn json_parse_duplicate_proto() {
+    run_test_actions([
+        TestAction::run(r#"var obj = JSON.parse('{"__proto__": 1, "__proto__": [2]}');"#),
+        TestAction::assert("Object.hasOwn(obj, '__proto__')"),
+        TestAction::assert("Array.isArray(obj.__proto__)"),
+        TestAction::assert("Object.getPrototypeOf(obj) === Object.prototype"),
+    ]);
+}
@@ -129,15 +129,12 @@ impl Math {
     /// [spec]: https://tc39.es/ecma262/#sec-math.acos
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/acos
     pub(crate) fn acos(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
-        Ok(args
-            .get_or_undefined(0)
-            // 1. Let n be ? ToNumber(x).
-            .to_number(context)?
-            // 2. If n is NaN, n > 1𝔽, or n < -1𝔽, return NaN.
-            // 3. If n is 1𝔽, return +0𝔽.
-            // 4. Return an implementation-approximated value representing the result of the inverse cosine of ℝ(n).
-            .acos()
-            .into())
+        // 1. Let n be ? ToNumber(x).
+        let n = args.get_or_undefined(0).to_number(context)?;
+        // 2. If n is NaN, n > 1𝔽, or n < -1𝔽, return NaN.
+        // 3. If n is 1𝔽, return +0𝔽.
+        // 4. Return an implementation-approximated value representing the result of the inverse cosine of ℝ(n).
+        Ok(approximate(context, n, f64::acos, libm::acos).into())
     }
 
     /// Get the hyperbolic arccos of a number.
@@ -149,16 +146,13 @@ impl Math {
     /// [spec]: https://tc39.es/ecma262/#sec-math.acosh
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/acosh
     pub(crate) fn acosh(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
-        Ok(args
-            .get_or_undefined(0)
-            // 1. Let n be ? ToNumber(x).
-            .to_number(context)?
-            // 4. If n < 1𝔽, return NaN.
-            // 2. If n is NaN or n is +∞𝔽, return n.
-            // 3. If n is 1𝔽, return +0𝔽.
-            // 5. Return an implementation-approximated value representing the result of the inverse hyperbolic cosine of ℝ(n).
-            .acosh()
-            .into())
+        // 1. Let n be ? ToNumber(x).
+        let n = args.get_or_undefined(0).to_number(context)?;
+        // 4. If n < 1𝔽, return NaN.
+        // 2. If n is NaN or n is +∞𝔽, return n.
+        // 3. If n is 1𝔽, return +0𝔽.
+        // 5. Return an implementation-approximated value representing the result of the inverse hyperbolic cosine of ℝ(n).
+        Ok(approximate(context, n, f64::acosh, libm::acosh).into())
     }
 
     /// Get the arcsine of a number.
@@ -170,15 +164,12 @@ impl Math {
     /// [spec]: https://tc39.es/ecma262/#sec-math.asin
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/asin
     pub(crate) fn asin(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
-        Ok(args
-            .get_or_undefined(0)
-            // 1. Let n be ? ToNumber(x).
-            .to_number(context)?
-            // 2. If n is NaN, n is +0𝔽, or n is -0𝔽, return n.
-            // 3. If n > 1𝔽 or n < -1𝔽, return NaN.
-            // 4. Return an implementation-approximated value representing the result of the inverse sine of ℝ(n).
-            .asin()
-            .into())
+        // 1. Let n be ? ToNumber(x).
+        let n = args.get_or_undefined(0).to_number(context)?;
+        // 2. If n is NaN, n is +0𝔽, or n is -0𝔽, return n.
+        // 3. If n > 1𝔽 or n < -1𝔽, return NaN.
+        // 4. Return an implementation-approximated value representing the result of the inverse sine of ℝ(n).
+        Ok(approximate(context, n, f64::asin, libm::asin).into())
     }
 
     /// Get the hyperbolic arcsine of a number.
@@ -190,14 +181,11 @@ impl Math {
     /// [spec]: https://tc39.es/ecma262/#sec-math.asinh
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/asinh
     pub(crate) fn asinh(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
-        Ok(args
-            .get_or_undefined(0)
-            // 1. Let n be ? ToNumber(x).
-            .to_number(context)?
-            // 2. If n is NaN, n is +0𝔽, n is -0𝔽, n is +∞𝔽, or n is -∞𝔽, return n.
-            // 3. Return an implementation-approximated value representing the result of the inverse hyperbolic sine of ℝ(n).
-            .asinh()
-            .into())
+        // 1. Let n be ? ToNumber(x).
+        let n = args.get_or_undefined(0).to_number(context)?;
+        // 2. If n is NaN, n is +0𝔽, n is -0𝔽, n is +∞𝔽, or n is -∞𝔽, return n.
+        // 3. Return an implementation-approximated value representing the result of the inverse hyperbolic sine of ℝ(n).
+        Ok(approximate(context, n, f64::asinh, libm::asinh).into())
     }
 
     /// Get the arctangent of a number.
@@ -209,16 +197,13 @@ impl Math {
     /// [spec]: https://tc39.es/ecma262/#sec-math.atan
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/atan
     pub(crate) fn atan(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
-        Ok(args
-            .get_or_undefined(0)
-            // 1. Let n be ? ToNumber(x).
-            .to_number(context)?
-            // 2. If n is NaN, n is +0𝔽, or n is -0𝔽, return n.
-            // 3. If n is +∞𝔽, return an implementation-approximated value representing π / 2.
-            // 4. If n is -∞𝔽, return an implementation-approximated value representing -π / 2.
-            // 5. Return an implementation-approximated value representing the result of the inverse tangent of ℝ(n).
-            .atan()
-            .into())
+        // 1. Let n be ? ToNumber(x).
+        let n = args.get_or_undefined(0).to_number(context)?;
+        // 2. If n is NaN, n is +0𝔽, or n is -0𝔽, return n.
+        // 3. If n is +∞𝔽, return an implementation-approximated value representing π / 2.
+        // 4. If n is -∞𝔽, return an implementation-approximated value representing -π / 2.
+        // 5. Return an implementation-approximated value representing the result of the inverse tangent of ℝ(n).
+        Ok(approximate(context, n, f64::atan, libm::atan).into())
     }
 
     /// Get the hyperbolic arctangent of a number.
@@ -230,17 +215,14 @@ impl Math {
     /// [spec]: https://tc39.es/ecma262/#sec-math.atanh
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/atanh
     pub(crate) fn atanh(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
-        Ok(args
-            .get_or_undefined(0)
-            // 1. Let n be ? ToNumber(x).
-            .to_number(context)?
-            // 2. If n is NaN, n is +0𝔽, or n is -0𝔽, return n.
-            // 3. If n > 1𝔽 or n < -1𝔽, return NaN.
-            // 4. If n is 1𝔽, return +∞𝔽.
-            // 5. If n is -1𝔽, return -∞𝔽.
-            // 6. Return an implementation-approximated value representing the result of the inverse hyperbolic tangent of ℝ(n).
-            .atanh()
-            .into())
+        // 1. Let n be ? ToNumber(x).
+        let n = args.get_or_undefined(0).to_number(context)?;
+        // 2. If n is NaN, n is +0𝔽, or n is -0𝔽, return n.
+        // 3. If n > 1𝔽 or n < -1𝔽, return NaN.
+        // 4. If n is 1𝔽, return +∞𝔽.
+        // 5. If n is -1𝔽, return -∞𝔽.
+        // 6. Return an implementation-approximated value representing the result of the inverse hyperbolic tangent of ℝ(n).
+        Ok(approximate(context, n, f64::atanh, libm::atanh).into())
     }
 
     /// Get the four quadrant arctangent of the quotient y / x.
@@ -283,7 +265,12 @@ impl Math {
         // c. If nx is +0𝔽 or nx is -0𝔽, return an implementation-approximated value representing -π / 2.
         // 11. Assert: nx is finite and is neither +0𝔽 nor -0𝔽.
         // 12. Return an implementation-approximated value representing the result of the inverse tangent of the quotient ℝ(ny) / ℝ(nx).
-        Ok(y.atan2(x).into())
+        Ok(if context.deterministic_math() {
+            libm::atan2(y, x)
+        } else {
+            y.atan2(x)
+        }
+        .into())
     }
 
     /// Get the cubic root of a number.
@@ -295,14 +282,11 @@ impl Math {
     /// [spec]: https://tc39.es/ecma262/#sec-math.cbrt
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/cbrt
     pub(crate) fn cbrt(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
-        Ok(args
-            .get_or_undefined(0)
-            // 1. Let n be ? ToNumber(x).
-            .to_number(context)?
-            // 2. If n is NaN, n is +0𝔽, n is -0𝔽, n is +∞𝔽, or n is -∞𝔽, return n.
-            // 3. Return an implementation-approximated value representing the result of the cube root of ℝ(n).
-            .cbrt()
-            .into())
+        // 1. Let n be ? ToNumber(x).
+        let n = args.get_or_undefined(0).to_number(context)?;
+        // 2. If n is NaN, n is +0𝔽, n is -0𝔽, n is +∞𝔽, or n is -∞𝔽, return n.
+        // 3. Return an implementation-approximated value representing the result of the cube root of ℝ(n).
+        Ok(approximate(context, n, f64::cbrt, libm::cbrt).into())
     }
 
     /// Get lowest integer above a number.
@@ -354,15 +338,12 @@ impl Math {
     /// [spec]: https://tc39.es/ecma262/#sec-math.cos
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/cos
     pub(crate) fn cos(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
-        Ok(args
-            .get_or_undefined(0)
-            // 1. Let n be ? ToNumber(x).
-            .to_number(context)?
-            // 2. If n is NaN, n is +∞𝔽, or n is -∞𝔽, return NaN.
-            // 3. If n is +0𝔽 or n is -0𝔽, return 1𝔽.
-            // 4. Return an implementation-approximated value representing the result of the cosine of ℝ(n).
-            .cos()
-            .into())
+        // 1. Let n be ? ToNumber(x).
+        let n = args.get_or_undefined(0).to_number(context)?;
+        // 2. If n is NaN, n is +∞𝔽, or n is -∞𝔽, return NaN.
+        // 3. If n is +0𝔽 or n is -0𝔽, return 1𝔽.
+        // 4. Return an implementation-approximated value representing the result of the cosine of ℝ(n).
+        Ok(approximate(context, n, f64::cos, libm::cos).into())
     }
 
     /// Get the hyperbolic cosine of a number.
@@ -374,16 +355,13 @@ impl Math {
     /// [spec]: https://tc39.es/ecma262/#sec-math.cosh
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/cosh
     pub(crate) fn cosh(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
-        Ok(args
-            .get_or_undefined(0)
-            // 1. Let n be ? ToNumber(x).
-            .to_number(context)?
-            // 2. If n is NaN, return NaN.
-            // 3. If n is +∞𝔽 or n is -∞𝔽, return +∞𝔽.
-            // 4. If n is +0𝔽 or n is -0𝔽, return 1𝔽.
-            // 5. Return an implementation-approximated value representing the result of the hyperbolic cosine of ℝ(n).
-            .cosh()
-            .into())
+        // 1. Let n be ? ToNumber(x).
+        let n = args.get_or_undefined(0).to_number(context)?;
+        // 2. If n is NaN, return NaN.
+        // 3. If n is +∞𝔽 or n is -∞𝔽, return +∞𝔽.
+        // 4. If n is +0𝔽 or n is -0𝔽, return 1𝔽.
+        // 5. Return an implementation-approximated value representing the result of the hyperbolic cosine of ℝ(n).
+        Ok(approximate(context, n, f64::cosh, libm::cosh).into())
     }
 
     /// Get the power to raise the natural logarithm to get the number.
@@ -395,16 +373,13 @@ impl Math {
     /// [spec]: https://tc39.es/ecma262/#sec-math.exp
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/exp
     pub(crate) fn exp(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
-        Ok(args
-            .get_or_undefined(0)
-            // 1. Let n be ? ToNumber(x).
-            .to_number(context)?
-            // 2. If n is NaN or n is +∞𝔽, return n.
-            // 3. If n is +0𝔽 or n is -0𝔽, return 1𝔽.
-            // 4. If n is -∞𝔽, return +0𝔽.
-            // 5. Return an implementation-approximated value representing the result of the exponential function of ℝ(n).
-            .exp()
-            .into())
+        // 1. Let n be ? ToNumber(x).
+        let n = args.get_or_undefined(0).to_number(context)?;
+        // 2. If n is NaN or n is +∞𝔽, return n.
+        // 3. If n is +0𝔽 or n is -0𝔽, return 1𝔽.
+        // 4. If n is -∞𝔽, return +0𝔽.
+        // 5. Return an implementation-approximated value representing the result of the exponential function of ℝ(n).
+        Ok(approximate(context, n, f64::exp, libm::exp).into())
     }
 
     /// The `Math.expm1()` function returns e^x - 1, where x is the argument, and e the base of
@@ -418,15 +393,12 @@ impl Math {
     /// [spec]: https://tc39.es/ecma262/#sec-math.expm1
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/expm1
     pub(crate) fn expm1(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
-        Ok(args
-            .get_or_undefined(0)
-            // 1. Let n be ? ToNumber(x).
-            .to_number(context)?
-            // 2. If n is NaN, n is +0𝔽, n is -0𝔽, or n is +∞𝔽, return n.
-            // 3. If n is -∞𝔽, return -1𝔽.
-            // 4. Return an implementation-approximated value representing the result of subtracting 1 from the exponential function of ℝ(n).
-            .exp_m1()
-            .into())
+        // 1. Let n be ? ToNumber(x).
+        let n = args.get_or_undefined(0).to_number(context)?;
+        // 2. If n is NaN, n is +0𝔽, n is -0𝔽, or n is +∞𝔽, return n.
+        // 3. If n is -∞𝔽, return -1𝔽.
+        // 4. Return an implementation-approximated value representing the result of subtracting 1 from the exponential function of ℝ(n).
+        Ok(approximate(context, n, f64::exp_m1, libm::expm1).into())
     }
 
     /// Get the highest integer below a number.
@@ -483,25 +455,21 @@ impl Math {
     /// [spec]: https://tc39.es/ecma262/#sec-math.hypot
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/hypot
     pub(crate) fn hypot(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // Fast path for arguments that don't need to be coerced, e.g. when spreading a typed array.
+        if args.iter().all(JsValue::is_number) {
+            return Ok(hypot_of(args.iter().filter_map(JsValue::as_number)).into());
+        }
+
         // 1. Let coerced be a new empty List.
         // 2. For each element arg of args, do
         // a. Let n be ? ToNumber(arg).
         // b. Append n to coerced.
-        // 3. For each element number of coerced, do
-        // 5. For each element number of coerced, do
-        let mut result = 0f64;
-        for arg in args {
-            let num = arg.to_number(context)?;
-            // a. If number is +∞𝔽 or number is -∞𝔽, return +∞𝔽.
-            // 4. Let onlyZero be true.
-            // a. If number is NaN, return NaN.
-            // b. If number is neither +0𝔽 nor -0𝔽, set onlyZero to false.
-            // 6. If onlyZero is true, return +0𝔽.
-            // 7. Return an implementation-approximated value representing the square root of the sum of squares of the mathematical values of the elements of coerced.
-            result = result.hypot(num);
-        }
+        let coerced = args
+            .iter()
+            .map(|arg| arg.to_number(context))
+            .collect::<JsResult<Vec<_>>>()?;
 
-        Ok(result.into())
+        Ok(hypot_of(coerced.into_iter()).into())
     }
 
     /// Get the result of the C-like 32-bit multiplication of the two parameters.
@@ -533,17 +501,14 @@ impl Math {
     /// [spec]: https://tc39.es/ecma262/#sec-math.log
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/log
     pub(crate) fn log(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
-        Ok(args
-            .get_or_undefined(0)
-            // 1. Let n be ? ToNumber(x).
-            .to_number(context)?
-            // 2. If n is NaN or n is +∞𝔽, return n.
-            // 3. If n is 1𝔽, return +0𝔽.
-            // 4. If n is +0𝔽 or n is -0𝔽, return -∞𝔽.
-            // 5. If n < +0𝔽, return NaN.
-            // 6. Return an implementation-approximated value representing the result of the natural logarithm of ℝ(n).
-            .ln()
-            .into())
+        // 1. Let n be ? ToNumber(x).
+        let n = args.get_or_undefined(0).to_number(context)?;
+        // 2. If n is NaN or n is +∞𝔽, return n.
+        // 3. If n is 1𝔽, return +0𝔽.
+        // 4. If n is +0𝔽 or n is -0𝔽, return -∞𝔽.
+        // 5. If n < +0𝔽, return NaN.
+        // 6. Return an implementation-approximated value representing the result of the natural logarithm of ℝ(n).
+        Ok(approximate(context, n, f64::ln, libm::log).into())
     }
 
     /// Get approximation to the natural logarithm of 1 + x.
@@ -555,16 +520,13 @@ impl Math {
     /// [spec]: https://tc39.es/ecma262/#sec-math.log1p
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/log1p
     pub(crate) fn log1p(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
-        Ok(args
-            .get_or_undefined(0)
-            // 1. Let n be ? ToNumber(x).
-            .to_number(context)?
-            // 2. If n is NaN, n is +0𝔽, n is -0𝔽, or n is +∞𝔽, return n.
-            // 3. If n is -1𝔽, return -∞𝔽.
-            // 4. If n < -1𝔽, return NaN.
-            // 5. Return an implementation-approximated value representing the result of the natural logarithm of 1 + ℝ(n).
-            .ln_1p()
-            .into())
+        // 1. Let n be ? ToNumber(x).
+        let n = args.get_or_undefined(0).to_number(context)?;
+        // 2. If n is NaN, n is +0𝔽, n is -0𝔽, or n is +∞𝔽, return n.
+        // 3. If n is -1𝔽, return -∞𝔽.
+        // 4. If n < -1𝔽, return NaN.
+        // 5. Return an implementation-approximated value representing the result of the natural logarithm of 1 + ℝ(n).
+        Ok(approximate(context, n, f64::ln_1p, libm::log1p).into())
     }
 
     /// Get the base 10 logarithm of the number.
@@ -576,17 +538,14 @@ impl Math {
     /// [spec]: https://tc39.es/ecma262/#sec-math.log10
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/log10
     pub(crate) fn log10(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
-        Ok(args
-            .get_or_undefined(0)
-            // 1. Let n be ? ToNumber(x).
-            .to_number(context)?
-            // 2. If n is NaN or n is +∞𝔽, return n.
-            // 3. If n is 1𝔽, return +0𝔽.
-            // 4. If n is +0𝔽 or n is -0𝔽, return -∞𝔽.
-            // 5. If n < +0𝔽, return NaN.
-            // 6. Return an implementation-approximated value representing the result of the base 10 logarithm of ℝ(n).
-            .log10()
-            .into())
+        // 1. Let n be ? ToNumber(x).
+        let n = args.get_or_undefined(0).to_number(context)?;
+        // 2. If n is NaN or n is +∞𝔽, return n.
+        // 3. If n is 1𝔽, return +0𝔽.
+        // 4. If n is +0𝔽 or n is -0𝔽, return -∞𝔽.
+        // 5. If n < +0𝔽, return NaN.
+        // 6. Return an implementation-approximated value representing the result of the base 10 logarithm of ℝ(n).
+        Ok(approximate(context, n, f64::log10, libm::log10).into())
     }
 
     /// Get the base 2 logarithm of the number.
@@ -598,17 +557,14 @@ impl Math {
     /// [spec]: https://tc39.es/ecma262/#sec-math.log2
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/log2
     pub(crate) fn log2(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
-        Ok(args
-            .get_or_undefined(0)
-            // 1. Let n be ? ToNumber(x).
-            .to_number(context)?
-            // 2. If n is NaN or n is +∞𝔽, return n.
-            // 3. If n is 1𝔽, return +0𝔽.
-            // 4. If n is +0𝔽 or n is -0𝔽, return -∞𝔽.
-            // 5. If n < +0𝔽, return NaN.
-            // 6. Return an implementation-approximated value representing the result of the base 2 logarithm of ℝ(n).
-            .log2()
-            .into())
+        // 1. Let n be ? ToNumber(x).
+        let n = args.get_or_undefined(0).to_number(context)?;
+        // 2. If n is NaN or n is +∞𝔽, return n.
+        // 3. If n is 1𝔽, return +0𝔽.
+        // 4. If n is +0𝔽 or n is -0𝔽, return -∞𝔽.
+        // 5. If n < +0𝔽, return NaN.
+        // 6. Return an implementation-approximated value representing the result of the base 2 logarithm of ℝ(n).
+        Ok(approximate(context, n, f64::log2, libm::log2).into())
     }
 
     /// Get the maximum of several numbers.
@@ -620,6 +576,15 @@ impl Math {
     /// [spec]: https://tc39.es/ecma262/#sec-math.max
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/max
     pub(crate) fn max(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // Fast path for arguments that don't need to be coerced, e.g. when spreading a typed array.
+        if args.iter().all(JsValue::is_number) {
+            return Ok(args
+                .iter()
+                .filter_map(JsValue::as_number)
+                .fold(f64::NEG_INFINITY, max_of)
+                .into());
+        }
+
         // 1. Let coerced be a new empty List.
         // 2. For each element arg of args, do
         // b. Append n to coerced.
@@ -631,19 +596,7 @@ impl Math {
             // a. Let n be ? ToNumber(arg).
             let num = arg.to_number(context)?;
 
-            highest = if highest.is_nan() {
-                continue;
-            } else if num.is_nan() {
-                // a. If number is NaN, return NaN.
-                f64::NAN
-            } else {
-                match (highest, num) {
-                    // b. When x and y are +0𝔽 -0𝔽, return +0𝔽.
-                    (x, y) if x == 0f64 && y == 0f64 && x.signum() != y.signum() => 0f64,
-                    // c. Otherwise, return the maximum value.
-                    (x, y) => x.max(y),
-                }
-            };
+            highest = max_of(highest, num);
         }
         // 5. Return highest.
         Ok(highest.into())
@@ -658,6 +611,15 @@ impl Math {
     /// [spec]: https://tc39.es/ecma262/#sec-math.min
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/min
     pub(crate) fn min(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // Fast path for arguments that don't need to be coerced, e.g. when spreading a typed array.
+        if args.iter().all(JsValue::is_number) {
+            return Ok(args
+                .iter()
+                .filter_map(JsValue::as_number)
+                .fold(f64::INFINITY, min_of)
+                .into());
+        }
+
         // 1. Let coerced be a new empty List.
         // 2. For each element arg of args, do
         // b. Append n to coerced.
@@ -669,19 +631,7 @@ impl Math {
             // a. Let n be ? ToNumber(arg).
             let num = arg.to_number(context)?;
 
-            lowest = if lowest.is_nan() {
-                continue;
-            } else if num.is_nan() {
-                // a. If number is NaN, return NaN.
-                f64::NAN
-            } else {
-                match (lowest, num) {
-                    // b. When x and y are +0𝔽 -0𝔽, return -0𝔽.
-                    (x, y) if x == 0f64 && y == 0f64 && x.signum() != y.signum() => -0f64,
-                    // c. Otherwise, return the minimum value.
-                    (x, y) => x.min(y),
-                }
-            };
+            lowest = min_of(lowest, num);
         }
         // 5. Return lowest.
         Ok(lowest.into())
@@ -695,7 +645,6 @@ impl Math {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-math.pow
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/pow
-    #[allow(clippy::float_cmp)]
     pub(crate) fn pow(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
         // 1. Set base to ? ToNumber(base).
         let x = args.get_or_undefined(0).to_number(context)?;
@@ -704,14 +653,21 @@ impl Math {
         let y = args.get_or_undefined(1).to_number(context)?;
 
         // 3. Return Number::exponentiate(base, exponent).
+        Ok(Self::exponentiate(x, y, context).into())
+    }
 
-        // https://github.com/rust-lang/rust/issues/60468
+    /// Abstract operation [`Number::exponentiate ( base, exponent )`][spec], shared by
+    /// `Math.pow` and the `**` operator.
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-numeric-types-number-exponentiate
+    #[allow(clippy::float_cmp)]
+    pub(crate) fn exponentiate(base: f64, exponent: f64, context: &Context) -> f64 {
+        // `powf` follows the C `pow` function, which differs from the specification when the
+        // base is `1` or `-1` (https://github.com/rust-lang/rust/issues/60468).
 
-        // https://tc39.es/ecma262/multipage/ecmascript-data-types-and-values.html#sec-numeric-types-number-exponentiate
-        // 6.1.6.1.3 Number::exponentiate ( base, exponent )
-        //  1. If exponent is NaN, return NaN.
-        if y.is_nan() {
-            return Ok(f64::NAN.into());
+        // 1. If exponent is NaN, return NaN.
+        if exponent.is_nan() {
+            return f64::NAN;
         }
 
         // 9. If exponent is +∞𝔽, then
@@ -722,11 +678,15 @@ impl Math {
         //   a. If abs(ℝ(base)) > 1, return +0𝔽.
         //   b. If abs(ℝ(base)) = 1, return NaN.
         //   c. If abs(ℝ(base)) < 1, return +∞𝔽.
-        if f64::abs(x) == 1f64 && y.is_infinite() {
-            return Ok(f64::NAN.into());
+        if base.abs() == 1.0 && exponent.is_infinite() {
+            return f64::NAN;
         }
 
-        Ok(x.powf(y).into())
+        if context.deterministic_math() {
+            libm::pow(base, exponent)
+        } else {
+            base.powf(exponent)
+        }
     }
 
     /// Generate a random floating-point number between `0` and `1`.
@@ -800,15 +760,12 @@ impl Math {
     /// [spec]: https://tc39.es/ecma262/#sec-math.sin
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/sin
     pub(crate) fn sin(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
-        Ok(args
-            .get_or_undefined(0)
-            // 1. Let n be ? ToNumber(x).
-            .to_number(context)?
-            // 2. If n is NaN, n is +0𝔽, or n is -0𝔽, return n.
-            // 3. If n is +∞𝔽 or n is -∞𝔽, return NaN.
-            // 4. Return an implementation-approximated value representing the result of the sine of ℝ(n).
-            .sin()
-            .into())
+        // 1. Let n be ? ToNumber(x).
+        let n = args.get_or_undefined(0).to_number(context)?;
+        // 2. If n is NaN, n is +0𝔽, or n is -0𝔽, return n.
+        // 3. If n is +∞𝔽 or n is -∞𝔽, return NaN.
+        // 4. Return an implementation-approximated value representing the result of the sine of ℝ(n).
+        Ok(approximate(context, n, f64::sin, libm::sin).into())
     }
 
     /// Get the hyperbolic sine of a number.
@@ -820,14 +777,11 @@ impl Math {
     /// [spec]: https://tc39.es/ecma262/#sec-math.sinh
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/sinh
     pub(crate) fn sinh(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
-        Ok(args
-            .get_or_undefined(0)
-            // 1. Let n be ? ToNumber(x).
-            .to_number(context)?
-            // 2. If n is NaN, n is +0𝔽, n is -0𝔽, n is +∞𝔽, or n is -∞𝔽, return n.
-            // 3. Return an implementation-approximated value representing the result of the hyperbolic sine of ℝ(n).
-            .sinh()
-            .into())
+        // 1. Let n be ? ToNumber(x).
+        let n = args.get_or_undefined(0).to_number(context)?;
+        // 2. If n is NaN, n is +0𝔽, n is -0𝔽, n is +∞𝔽, or n is -∞𝔽, return n.
+        // 3. Return an implementation-approximated value representing the result of the hyperbolic sine of ℝ(n).
+        Ok(approximate(context, n, f64::sinh, libm::sinh).into())
     }
 
     /// Get the square root of a number.
@@ -859,15 +813,12 @@ impl Math {
     /// [spec]: https://tc39.es/ecma262/#sec-math.tan
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/tan
     pub(crate) fn tan(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
-        Ok(args
-            .get_or_undefined(0)
-            // 1. Let n be ? ToNumber(x).
-            .to_number(context)?
-            // 2. If n is NaN, n is +0𝔽, or n is -0𝔽, return n.
-            // 3. If n is +∞𝔽, or n is -∞𝔽, return NaN.
-            // 4. Return an implementation-approximated value representing the result of the tangent of ℝ(n).
-            .tan()
-            .into())
+        // 1. Let n be ? ToNumber(x).
+        let n = args.get_or_undefined(0).to_number(context)?;
+        // 2. If n is NaN, n is +0𝔽, or n is -0𝔽, return n.
+        // 3. If n is +∞𝔽, or n is -∞𝔽, return NaN.
+        // 4. Return an implementation-approximated value representing the result of the tangent of ℝ(n).
+        Ok(approximate(context, n, f64::tan, libm::tan).into())
     }
 
     /// Get the hyperbolic tangent of a number.
@@ -879,16 +830,13 @@ impl Math {
     /// [spec]: https://tc39.es/ecma262/#sec-math.tanh
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/tanh
     pub(crate) fn tanh(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
-        Ok(args
-            .get_or_undefined(0)
-            // 1. Let n be ? ToNumber(x).
-            .to_number(context)?
-            // 2. If n is NaN, n is +0𝔽, or n is -0𝔽, return n.
-            // 3. If n is +∞𝔽, return 1𝔽.
-            // 4. If n is -∞𝔽, return -1𝔽.
-            // 5. Return an implementation-approximated value representing the result of the hyperbolic tangent of ℝ(n).
-            .tanh()
-            .into())
+        // 1. Let n be ? ToNumber(x).
+        let n = args.get_or_undefined(0).to_number(context)?;
+        // 2. If n is NaN, n is +0𝔽, or n is -0𝔽, return n.
+        // 3. If n is +∞𝔽, return 1𝔽.
+        // 4. If n is -∞𝔽, return -1𝔽.
+        // 5. Return an implementation-approximated value representing the result of the hyperbolic tangent of ℝ(n).
+        Ok(approximate(context, n, f64::tanh, libm::tanh).into())
     }
 
     /// Get the integer part of a number.
@@ -912,3 +860,95 @@ impl Math {
             .into())
     }
 }
+
+/// Computes an implementation-approximated function with the software implementation of the
+/// [`libm`] crate if the context uses deterministic math, or with the platform's implementation
+/// otherwise.
+///
+/// See [`ContextBuilder::deterministic_math`](crate::context::ContextBuilder::deterministic_math).
+fn approximate(
+    context: &Context,
+    n: f64,
+    platform: fn(f64) -> f64,
+    deterministic: fn(f64) -> f64,
+) -> f64 {
+    if context.deterministic_math() {
+        deterministic(n)
+    } else {
+        platform(n)
+    }
+}
+
+/// Computes a step of `Math.max`, returning the maximum of `highest` and `number`.
+#[allow(clippy::float_cmp)]
+fn max_of(highest: f64, number: f64) -> f64 {
+    // a. If number is NaN, return NaN.
+    // b. If number is +0𝔽 and highest is -0𝔽, set highest to +0𝔽.
+    // c. If number > highest, set highest to number.
+    if highest.is_nan() || number.is_nan() {
+        f64::NAN
+    } else if number > highest || (number == highest && highest.is_sign_negative()) {
+        number
+    } else {
+        highest
+    }
+}
+
+/// Computes a step of `Math.min`, returning the minimum of `lowest` and `number`.
+#[allow(clippy::float_cmp)]
+fn min_of(lowest: f64, number: f64) -> f64 {
+    // a. If number is NaN, return NaN.
+    // b. If number is -0𝔽 and lowest is +0𝔽, set lowest to -0𝔽.
+    // c. If number < lowest, set lowest to number.
+    if lowest.is_nan() || number.is_nan() {
+        f64::NAN
+    } else if number < lowest || (number == lowest && number.is_sign_negative()) {
+        number
+    } else {
+        lowest
+    }
+}
+
+/// Computes the result of `Math.hypot` for the coerced `numbers`.
+///
+/// This only uses basic arithmetic and `sqrt`, which are correctly rounded, so the result is the
+/// same on all platforms.
+#[allow(clippy::float_cmp)]
+fn hypot_of(numbers: impl Iterator<Item = f64> + Clone) -> f64 {
+    // 3. For each element number of coerced, do
+    //     a. If number is +∞𝔽 or number is -∞𝔽, return +∞𝔽.
+    // 4. Let onlyZero be true.
+    // 5. For each element number of coerced, do
+    //     a. If number is NaN, return NaN.
+    //     b. If number is neither +0𝔽 nor -0𝔽, set onlyZero to false.
+    // 6. If onlyZero is true, return +0𝔽.
+    let (mut largest, mut sum, mut is_nan) = (0f64, 0f64, false);
+    for number in numbers.clone() {
+        if number.is_infinite() {
+            return f64::INFINITY;
+        }
+        is_nan |= number.is_nan();
+        largest = largest.max(number.abs());
+        sum += number * number;
+    }
+    if is_nan {
+        return f64::NAN;
+    }
+    if largest == 0.0 {
+        return 0.0;
+    }
+
+    // 7. Return an implementation-approximated value representing the square root of the sum of
+    //    squares of the mathematical values of the elements of coerced.
+    if sum.is_finite() && sum >= f64::MIN_POSITIVE {
+        return sum.sqrt();
+    }
+
+    // The sum of squares overflowed or lost its precision in the subnormal range, so the
+    // numbers are scaled by the largest one before they are squared.
+    let sum = numbers.fold(0f64, |sum, number| {
+        let scaled = number / largest;
+        sum + scaled * scaled
+    });
+    largest * sum.sqrt()
+}
@@ -1,4 +1,4 @@
-use crate::{run_test_actions, TestAction};
+use crate::{run_test_actions, run_test_actions_with, Context, TestAction};
 
 #[test]
 fn abs() {
@@ -170,6 +170,18 @@ fn hypot() {
         TestAction::assert_eq("Math.hypot(4, [5], 6)", 8.774_964_387_392_123),
         TestAction::assert_eq("Math.hypot(3, -Infinity)", f64::INFINITY),
         TestAction::assert_eq("Math.hypot(12)", 12.0),
+        TestAction::assert_eq("Math.hypot(NaN, -Infinity)", f64::INFINITY),
+        TestAction::assert_eq("Math.hypot(1, NaN)", f64::NAN),
+        TestAction::assert("Object.is(Math.hypot(-0, -0), 0)"),
+        TestAction::assert_eq(
+            "Math.hypot(3 * 2 ** 600, 4 * 2 ** 600)",
+            5.0 * 2f64.powi(600),
+        ),
+        TestAction::assert_eq(
+            "Math.hypot(3 * 2 ** -600, 4 * 2 ** -600)",
+            5.0 * 2f64.powi(-600),
+        ),
+        TestAction::assert_eq("Math.hypot(...new Float64Array([3, 4, 12]))", 13.0),
     ]);
 }
 
@@ -231,6 +243,12 @@ fn max() {
         TestAction::assert_eq("Math.max(10, 20)", 20.0),
         TestAction::assert_eq("Math.max(-10, -20)", -10.0),
         TestAction::assert_eq("Math.max(-10, 20)", 20.0),
+        TestAction::assert_eq("Math.max()", f64::NEG_INFINITY),
+        TestAction::assert_eq("Math.max(1, NaN, 2)", f64::NAN),
+        TestAction::assert_eq("Math.max(1, '3', 2)", 3.0),
+        TestAction::assert("Object.is(Math.max(-0, 0), 0)"),
+        TestAction::assert("Object.is(Math.max(0, -0), 0)"),
+        TestAction::assert_eq("Math.max(...new Int8Array([3, -7, 12, 5]))", 12.0),
     ]);
 }
 
@@ -240,6 +258,12 @@ fn min() {
         TestAction::assert_eq("Math.min(10, 20)", 10.0),
         TestAction::assert_eq("Math.min(-10, -20)", -20.0),
         TestAction::assert_eq("Math.min(-10, 20)", -10.0),
+        TestAction::assert_eq("Math.min()", f64::INFINITY),
+        TestAction::assert_eq("Math.min(1, NaN, 2)", f64::NAN),
+        TestAction::assert_eq("Math.min(1, '-3', 2)", -3.0),
+        TestAction::assert("Object.is(Math.min(-0, 0), -0)"),
+        TestAction::assert("Object.is(Math.min(0, -0), -0)"),
+        TestAction::assert_eq("Math.min(...new Float32Array([3, -7.5, 12]))", -7.5),
     ]);
 }
 
@@ -322,3 +346,37 @@ fn trunc() {
         TestAction::assert_eq("Math.trunc(0.123)", 0.0),
     ]);
 }
+
+#[test]
+fn exponentiation_operator() {
+    run_test_actions([
+        TestAction::assert_eq("2 ** 10", 1_024),
+        TestAction::assert_eq("2 ** -1", 0.5),
+        TestAction::assert_eq("1.5 ** 2", 2.25),
+        TestAction::assert_eq("1 ** NaN", f64::NAN),
+        TestAction::assert_eq("(-1) ** Infinity", f64::NAN),
+        TestAction::assert_eq("1.0000001 ** Infinity", f64::INFINITY),
+    ]);
+}
+
+#[test]
+fn deterministic_math() {
+    let context = &mut Context::builder().deterministic_math(true).build().unwrap();
+    assert!(context.deterministic_math());
+    run_test_actions_with(
+        [
+            TestAction::assert_eq("Math.sin(1)", libm::sin(1.0)),
+            TestAction::assert_eq("Math.cos(1e10)", libm::cos(1e10)),
+            TestAction::assert_eq("Math.tan(0.5)", libm::tan(0.5)),
+            TestAction::assert_eq("Math.exp(10.5)", libm::exp(10.5)),
+            TestAction::assert_eq("Math.log(3)", libm::log(3.0)),
+            TestAction::assert_eq("Math.atan2(1, 3)", libm::atan2(1.0, 3.0)),
+            TestAction::assert_eq("Math.pow(1.1, 2.5)", libm::pow(1.1, 2.5)),
+            TestAction::assert_eq("1.1 ** 2.5", libm::pow(1.1, 2.5)),
+            TestAction::assert_eq("Math.pow(1, NaN)", f64::NAN),
+            TestAction::assert_eq("Math.pow(-1, Infinity)", f64::NAN),
+            TestAction::assert_eq("Math.sqrt(2)", std::f64::consts::SQRT_2),
+        ],
+        context,
+    );
+}
@@ -21,6 +21,7 @@ pub mod map;
 pub mod math;
 pub mod number;
 pub mod object;
+pub mod options;
 pub mod promise;
 pub mod proxy;
 pub mod reflect;
@@ -45,10 +46,6 @@ pub mod escape;
 #[cfg(feature = "intl")]
 pub mod intl;
 
-// TODO: remove `cfg` when `Temporal` gets to stage 4.
-#[cfg(any(feature = "intl", feature = "temporal"))]
-pub(crate) mod options;
-
 #[cfg(feature = "temporal")]
 pub mod temporal;
 
@@ -403,12 +400,20 @@ pub(crate) fn set_default_global_bindings(context: &mut Context) -> JsResult<()>
     }
 
     #[cfg(feature = "intl")]
-    global_binding::<intl::Intl>(context)?;
+    {
+        global_binding::<intl::Intl>(context)?;
+
+        if context.string_grapheme_methods() {
+            String::define_grapheme_methods(context)?;
+        }
+    }
 
     #[cfg(feature = "temporal")]
     {
         global_binding::<temporal::Temporal>(context)?;
     }
 
+    Symbol::define_well_known_symbols(context)?;
+
     Ok(())
 }
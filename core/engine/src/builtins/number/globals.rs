@@ -3,12 +3,11 @@ use crate::{
     context::intrinsics::Intrinsics,
     object::JsObject,
     realm::Realm,
-    string::StaticJsStrings,
+    string::{JsStrVariant, StaticJsStrings},
     Context, JsArgs, JsResult, JsStr, JsString, JsValue,
 };
 
 use boa_macros::js_str;
-use cow_utils::CowUtils;
 
 /// Builtin javascript 'isFinite(number)' function.
 ///
@@ -122,6 +121,14 @@ fn from_js_str_radix(src: JsStr<'_>, radix: u8) -> Option<f64> {
         }
     }
 
+    // Long decimal strings are parsed as floats, which rounds them correctly instead of
+    // accumulating the rounding errors of each digit.
+    if radix == 10 && !can_not_overflow(radix, src.len()) {
+        if let JsStrVariant::Latin1(digits) = src.variant() {
+            return fast_float2::parse(digits).ok();
+        }
+    }
+
     let src = src
         .iter()
         .map(|x| u8::try_from(x).expect("should be ascii string"));
@@ -227,8 +234,10 @@ pub(crate) fn parse_int(_: &JsValue, args: &[JsValue], context: &mut Context) ->
 
     // 11. If S contains a code unit that is not a radix-R digit, let end be the index within S of the
     //     first such code unit; otherwise, let end be the length of S.
-    let end = char::decode_utf16(s.iter())
-        .position(|code| !code.is_ok_and(|c| c.is_digit(u32::from(r))))
+    //     All the radix-R digits are ASCII, so the code units don't need to be decoded.
+    let end = s
+        .iter()
+        .position(|code| !u8::try_from(code).is_ok_and(|c| char::from(c).is_digit(u32::from(r))))
         .unwrap_or(s.len());
 
     // 12. Let Z be the substring of S from 0 to end.
@@ -301,40 +310,51 @@ pub(crate) fn parse_float(
     args: &[JsValue],
     context: &mut Context,
 ) -> JsResult<JsValue> {
-    if let Some(val) = args.first() {
-        // TODO: parse float with optimal utf16 algorithm
-        let input_string = val.to_string(context)?.to_std_string_escaped();
-        let s = input_string.trim_start_matches(is_trimmable_whitespace);
-        let s_prefix = s.chars().take(4).collect::<String>();
-        let s_prefix_lower = s_prefix.cow_to_ascii_lowercase();
-        // TODO: write our own lexer to match syntax StrDecimalLiteral
-        if s.starts_with("Infinity") || s.starts_with("+Infinity") {
-            Ok(JsValue::new(f64::INFINITY))
-        } else if s.starts_with("-Infinity") {
-            Ok(JsValue::new(f64::NEG_INFINITY))
-        } else if s_prefix_lower.starts_with("inf")
-            || s_prefix_lower.starts_with("+inf")
-            || s_prefix_lower.starts_with("-inf")
-        {
-            // Prevent fast_float from parsing "inf", "+inf" as Infinity and "-inf" as -Infinity
-            Ok(JsValue::nan())
-        } else {
-            Ok(fast_float2::parse_partial::<f64, _>(s).map_or_else(
-                |_| JsValue::nan(),
-                |(f, len)| {
-                    if len > 0 {
-                        JsValue::new(f)
-                    } else {
-                        JsValue::nan()
-                    }
-                },
-            ))
-        }
-    } else {
+    let Some(val) = args.first() else {
         // Not enough arguments to parseFloat.
-        Ok(JsValue::nan())
+        return Ok(JsValue::nan());
+    };
+
+    let input_string = val.to_string(context)?;
+    let number = match input_string.as_str().variant() {
+        // ASCII strings are valid UTF-8, so they can be parsed without copying them.
+        JsStrVariant::Latin1(bytes) if bytes.is_ascii() => {
+            parse_float_str(std::str::from_utf8(bytes).expect("ASCII strings are valid UTF-8"))
+        }
+        // TODO: parse float with optimal utf16 algorithm
+        _ => parse_float_str(&input_string.to_std_string_escaped()),
+    };
+    Ok(JsValue::new(number))
+}
+
+/// Parses the longest prefix of `s` that is a `StrDecimalLiteral`, after trimming its leading
+/// whitespace.
+fn parse_float_str(s: &str) -> f64 {
+    let s = s.trim_start_matches(is_trimmable_whitespace);
+    // TODO: write our own lexer to match syntax StrDecimalLiteral
+    if s.starts_with("Infinity") || s.starts_with("+Infinity") {
+        return f64::INFINITY;
+    }
+    if s.starts_with("-Infinity") {
+        return f64::NEG_INFINITY;
+    }
+
+    // Prevent fast_float from parsing "inf", "+inf" as Infinity and "-inf" as -Infinity
+    let unsigned = s.strip_prefix(['+', '-']).unwrap_or(s);
+    if unsigned
+        .as_bytes()
+        .get(..3)
+        .is_some_and(|prefix| prefix.eq_ignore_ascii_case(b"inf"))
+    {
+        return f64::NAN;
+    }
+
+    match fast_float2::parse_partial::<f64, _>(s) {
+        Ok((f, len)) if len > 0 => f,
+        _ => f64::NAN,
     }
 }
+
 pub(crate) struct ParseFloat;
 
 impl IntrinsicObject for ParseFloat {
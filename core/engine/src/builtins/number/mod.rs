@@ -294,23 +294,46 @@ impl Number {
     ///
     /// The `toLocaleString()` method returns a string with a language-sensitive representation of this number.
     ///
-    /// Note that while this technically conforms to the Ecma standard, it does no actual
-    /// internationalization logic.
+    /// If the `intl` feature is enabled, this formats the number with `Intl.NumberFormat`.
+    /// Otherwise, this returns the same string as `toString`.
     ///
     /// More information:
     ///  - [ECMAScript reference][spec]
+    ///  - [ECMA-402 reference][spec-402]
     ///  - [MDN documentation][mdn]
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-number.prototype.tolocalestring
+    /// [spec-402]: https://tc39.es/ecma402/#sup-number.prototype.tolocalestring
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number/toLocaleString
     #[allow(clippy::wrong_self_convention)]
     pub(crate) fn to_locale_string(
         this: &JsValue,
-        _: &[JsValue],
-        _: &mut Context,
+        args: &[JsValue],
+        context: &mut Context,
     ) -> JsResult<JsValue> {
+        // 1. Let x be ? ThisNumberValue(this value).
         let this_num = Self::this_number_value(this)?;
-        Ok(JsValue::new(js_string!(this_num)))
+
+        #[cfg(feature = "intl")]
+        {
+            use crate::builtins::intl::number_format::format_number_to_locale_string;
+
+            // 2. Let numberFormat be ? Construct(%Intl.NumberFormat%, « locales, options »).
+            // 3. Return FormatNumeric(numberFormat, ! ToIntlMathematicalValue(x)).
+            format_number_to_locale_string(
+                this_num,
+                args.get_or_undefined(0),
+                args.get_or_undefined(1),
+                context,
+            )
+            .map(Into::into)
+        }
+
+        #[cfg(not(feature = "intl"))]
+        {
+            let _ = (args, context);
+            Ok(JsValue::new(js_string!(this_num)))
+        }
     }
 
     /// `flt_str_to_exp` - used in `to_precision`
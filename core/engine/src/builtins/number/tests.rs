@@ -1,4 +1,6 @@
 use boa_macros::js_str;
+#[cfg(feature = "intl")]
+use indoc::indoc;
 
 use crate::{
     builtins::Number, run_test_actions, value::AbstractRelation, JsNativeErrorKind, TestAction,
@@ -167,16 +169,47 @@ fn issue_2609() {
 
 #[test]
 fn to_locale_string() {
-    // TODO: We don't actually do any locale checking here
-    // To honor the spec we should print numbers according to user locale.
     run_test_actions([
         TestAction::assert_eq("Number().toLocaleString()", js_str!("0")),
         TestAction::assert_eq("Number(5).toLocaleString()", js_str!("5")),
-        TestAction::assert_eq("Number('345600').toLocaleString()", js_str!("345600")),
+        TestAction::assert_eq(
+            "Number('345600').toLocaleString('en')",
+            if cfg!(feature = "intl") {
+                js_str!("345,600")
+            } else {
+                js_str!("345600")
+            },
+        ),
         TestAction::assert_eq("Number(-25).toLocaleString()", js_str!("-25")),
     ]);
 }
 
+#[cfg(feature = "intl")]
+#[test]
+fn to_locale_string_intl() {
+    run_test_actions([
+        TestAction::assert_eq(
+            "(1234567.891).toLocaleString('en-US')",
+            js_str!("1,234,567.891"),
+        ),
+        TestAction::assert_eq("(1234.5).toLocaleString('de')", js_str!("1.234,5")),
+        TestAction::assert_eq(
+            "(0.256).toLocaleString('en', { maximumFractionDigits: 1 })",
+            js_str!("0.3"),
+        ),
+        TestAction::assert_eq("NaN.toLocaleString('en')", js_str!("NaN")),
+        TestAction::assert_eq("(-Infinity).toLocaleString('en')", js_str!("-∞")),
+        TestAction::assert(indoc! {r#"
+            try {
+                (1).toLocaleString('en', { maximumFractionDigits: 101 });
+                false
+            } catch (e) {
+                e instanceof RangeError
+            }
+        "#}),
+    ]);
+}
+
 #[test]
 fn to_precision() {
     const ERROR: &str = "precision must be an integer at least 1 and no greater than 100";
@@ -487,6 +520,19 @@ fn parse_int_varying_radix() {
     run_test_actions(tests);
 }
 
+#[test]
+fn parse_int_long_decimal() {
+    run_test_actions([
+        TestAction::assert_eq(
+            "parseInt('123456789012345678901')",
+            123_456_789_012_345_680_000.0,
+        ),
+        TestAction::assert_eq("parseInt('9007199254740993')", 9_007_199_254_740_992.0),
+        TestAction::assert_eq("parseInt('-90071992547409930')", -90_071_992_547_409_930.0),
+        TestAction::assert_eq("parseInt('1e21')", 1),
+    ]);
+}
+
 #[test]
 fn parse_float() {
     run_test_actions([
@@ -501,6 +547,11 @@ fn parse_float() {
         TestAction::assert_eq("parseFloat()", f64::NAN),
         // Shows that the parseFloat function ignores extra arguments.
         TestAction::assert_eq("parseFloat('100.5', 10)", 100.5),
+        TestAction::assert_eq("parseFloat('  \\u00A0 3.25abc')", 3.25),
+        TestAction::assert_eq("parseFloat('-Infinityx')", f64::NEG_INFINITY),
+        TestAction::assert_eq("parseFloat('+inf')", f64::NAN),
+        TestAction::assert_eq("parseFloat('INFINITY')", f64::NAN),
+        TestAction::assert_eq("parseFloat('.5e-3x')", 0.0005),
     ]);
 }
 
@@ -996,14 +996,11 @@ impl OrdinaryObject {
                 // 3.a.iii. For each element nextKey of keys, do
                 for key in keys {
                     // 3.a.iii.1. Let desc be ? from.[[GetOwnProperty]](nextKey).
-
-                    if let Some(desc) =
-                        from.__get_own_property__(&key, &mut InternalMethodContext::new(context))?
-                    {
+                    if let Some(desc) = from.get_own_property_fast(&key, context)? {
                         // 3.a.iii.2. If desc is not undefined and desc.[[Enumerable]] is true, then
                         if desc.expect_enumerable() {
                             // 3.a.iii.2.a. Let propValue be ? Get(from, nextKey).
-                            let property = from.get(key.clone(), context)?;
+                            let property = from.get_own_value_fast(key.clone(), &desc, context)?;
                             // 3.a.iii.2.b. Perform ? Set(to, nextKey, propValue, true).
                             to.set(key, property, true, context)?;
                         }
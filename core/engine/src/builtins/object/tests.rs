@@ -426,3 +426,216 @@ fn object_from_entries() {
         TestAction::assert_eq("map[5]", 4),
     ]);
 }
+
+#[test]
+fn own_property_keys_order() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+                let s1 = Symbol("s1");
+                let s2 = Symbol("s2");
+                function keys(o) {
+                    return Reflect.ownKeys(o).map(String).join();
+                }
+            "#}),
+        // Integer keys ascending, then strings in insertion order, then symbols in insertion order.
+        TestAction::assert_eq(
+            r#"keys({ b: 1, 2: 1, [s2]: 1, a: 1, [s1]: 1, 1: 1, 4294967295: 1, "-1": 1 })"#,
+            js_str!("1,2,b,a,4294967295,-1,Symbol(s2),Symbol(s1)"),
+        ),
+        // Deleting, re-adding and reconfiguring properties.
+        TestAction::assert_eq(
+            indoc! {r#"
+                var o = { a: 1, b: 2, c: 3 };
+                delete o.b;
+                o.b = 4;
+                Object.defineProperty(o, "a", { get() {} });
+                Object.defineProperty(o, "c", { value: 5 });
+                keys(o)
+            "#},
+            js_str!("a,c,b"),
+        ),
+        TestAction::assert_eq(
+            "var arr = []; arr[5] = 1; arr.x = 1; arr[1] = 1; keys(arr)",
+            js_str!("1,5,length,x"),
+        ),
+        // Sparse index storage.
+        TestAction::assert_eq(
+            indoc! {r#"
+                var sparse = { x: 1 };
+                sparse[4000000000] = 1;
+                sparse[70000] = 1;
+                sparse[3] = 1;
+                sparse[123456] = 1;
+                keys(sparse)
+            "#},
+            js_str!("3,70000,123456,4000000000,x"),
+        ),
+        TestAction::assert_eq(
+            r#"var sparseStr = new String("ab"); sparseStr[90000] = 1; sparseStr[7] = 1; keys(sparseStr)"#,
+            js_str!("0,1,7,90000,length"),
+        ),
+        TestAction::assert_eq(
+            r#"var str = new String("ab"); str[5] = 1; str.x = 1; str[3] = 1; keys(str)"#,
+            js_str!("0,1,3,5,length,x"),
+        ),
+        TestAction::assert_eq(
+            "var ta = new Uint8Array(2); ta[s1] = 1; ta.x = 1; keys(ta)",
+            js_str!("0,1,x,Symbol(s1)"),
+        ),
+        TestAction::assert_eq(
+            "keys(new Proxy({ b: 1, [s1]: 1, 1: 1 }, {}))",
+            js_str!("1,b,Symbol(s1)"),
+        ),
+        TestAction::assert_eq(
+            r#"keys(new Proxy({ a: 1, 1: 1 }, { ownKeys() { return ["a", "1"]; } }))"#,
+            js_str!("a,1"),
+        ),
+        // Enumeration follows the same order.
+        TestAction::assert_eq(
+            indoc! {r#"
+                var enumerated = [];
+                for (var key in { b: 1, 1: 1, a: 1, 0: 1 }) {
+                    enumerated.push(key);
+                }
+                enumerated.join()
+            "#},
+            js_str!("0,1,b,a"),
+        ),
+        TestAction::assert_eq(
+            "Object.keys({ b: 1, 1: 1, [s1]: 1, a: 1 }).join()",
+            js_str!("1,b,a"),
+        ),
+    ]);
+}
+
+#[test]
+fn object_integrity_levels() {
+    run_test_actions([
+        TestAction::assert_eq(
+            "var o = Object.freeze({ a: 1, get b() { return 2; } }); Object.isFrozen(o)",
+            true,
+        ),
+        TestAction::assert_eq("Object.isSealed(o)", true),
+        TestAction::assert_eq("o.a = 3; o.a", 1),
+        TestAction::assert_eq("o.b", 2),
+        TestAction::assert_eq("var s = Object.seal({ a: 1 }); Object.isSealed(s)", true),
+        TestAction::assert_eq("Object.isFrozen(s)", false),
+        TestAction::assert_eq("s.a = 2; s.a", 2),
+        // An object made frozen without `Object.freeze`.
+        TestAction::assert_eq(
+            indoc! {r#"
+                var d = { a: 1 };
+                Object.defineProperty(d, "b", { value: 2 });
+                Object.preventExtensions(d);
+                Object.isFrozen(d)
+            "#},
+            false,
+        ),
+        TestAction::assert_eq(
+            indoc! {r#"
+                Object.defineProperty(d, "a", { writable: false, configurable: false });
+                Object.isFrozen(d)
+            "#},
+            true,
+        ),
+        // Accessors are frozen when they aren't configurable.
+        TestAction::assert_eq(
+            indoc! {r#"
+                var g = Object.preventExtensions(Object.defineProperty({}, "x", { get() {} }));
+                Object.isFrozen(g)
+            "#},
+            true,
+        ),
+        // Elements are checked separately from the named properties.
+        TestAction::assert_eq("Object.isFrozen(Object.preventExtensions([1, 2]))", false),
+        TestAction::assert_eq(
+            indoc! {r#"
+                var empty = Object.defineProperty([], "length", { writable: false });
+                Object.isFrozen(Object.preventExtensions(empty))
+            "#},
+            true,
+        ),
+        TestAction::assert_eq(
+            "var arr = Object.freeze([1, 2]); Object.isFrozen(arr)",
+            true,
+        ),
+        TestAction::assert_eq("arr[0] = 3; arr.length = 0; arr.join()", js_str!("1,2")),
+        TestAction::assert_eq("Object.isSealed(Object.seal([1, 2]))", true),
+        TestAction::assert_eq("Object.isFrozen(Object.seal([1, 2]))", false),
+        // Deleting the last configurable property of a non-extensible object seals it.
+        TestAction::assert_eq(
+            indoc! {r#"
+                var e = Object.defineProperty({ a: 1 }, "b", { value: 2, writable: true });
+                Object.preventExtensions(e);
+                delete e.a;
+                Object.isSealed(e) && !Object.isFrozen(e)
+            "#},
+            true,
+        ),
+        TestAction::assert_eq("Object.isFrozen(Object.freeze(new String('ab')))", true),
+        TestAction::assert_eq(
+            "Object.isFrozen(Object.preventExtensions(new Uint8Array(1)))",
+            false,
+        ),
+    ]);
+}
+
+#[test]
+fn assign_to_frozen_object() {
+    run_test_actions([
+        TestAction::run("var o = Object.freeze({ a: 1 });"),
+        TestAction::assert_native_error(
+            "'use strict'; o.a = 2;",
+            JsNativeErrorKind::Type,
+            "cannot set non-writable property: a",
+        ),
+        TestAction::assert_eq("o.a = 2; o.a", 1),
+        TestAction::assert_native_error(
+            "'use strict'; o.b = 2;",
+            JsNativeErrorKind::Type,
+            "cannot set non-writable property: b",
+        ),
+        TestAction::assert_eq("o.b = 2; o.b", JsValue::undefined()),
+    ]);
+}
+
+#[test]
+fn copy_properties_observes_getters() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var source = {
+                a: 1,
+                get b() { delete this.c; this.d = 4; return 2; },
+                c: 3,
+                [Symbol.for("e")]: 5,
+            };
+        "#}),
+        TestAction::assert_eq("Object.keys(source).join()", js_str!("a,b,c")),
+        TestAction::assert_eq("Object.values(source).join()", js_str!("1,2")),
+        TestAction::assert_eq(
+            "source.c = 3; Object.entries(source).join(';')",
+            js_str!("a,1;b,2;d,4"),
+        ),
+        TestAction::assert_eq(
+            indoc! {r#"
+                source.c = 3;
+                delete source.d;
+                var target = Object.assign({}, source);
+                Object.getOwnPropertyNames(target).join() + ":" + target[Symbol.for("e")]
+            "#},
+            js_str!("a,b:5"),
+        ),
+        TestAction::assert_eq(
+            indoc! {r#"
+                var log = [];
+                var proxy = new Proxy({ x: 1, y: 2 }, {
+                    get(target, key) { log.push(key); return target[key] * 10; },
+                });
+                Object.values(proxy).join() + ":" + log.join()
+            "#},
+            js_str!("10,20:x,y"),
+        ),
+        TestAction::assert_eq("Object.values([1, , 3]).join()", js_str!("1,3")),
+        TestAction::assert_eq("Object.entries('ab').join(';')", js_str!("0,a;1,b")),
+    ]);
+}
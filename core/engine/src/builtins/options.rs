@@ -1,12 +1,58 @@
 //! Utilities to parse, validate and get options in builtins.
+//!
+//! These implement the option bag operations of [ECMA-402][spec], and can be used by native
+//! functions of embedders to parse their own options objects with the same semantics as the
+//! builtins.
+//!
+//! An enumeration of string values can implement [`OptionType`] using its derive macro, which
+//! matches each unit variant with its name in lower camel case, unless renamed with
+//! `#[boa(rename = "...")]`:
+//!
+//! ```
+//! use boa_engine::{
+//!     builtins::options::{get_option, get_options_object, OptionType},
+//!     js_string, Context, JsResult, Source,
+//! };
+//!
+//! #[derive(Debug, PartialEq, Eq, OptionType)]
+//! enum Style {
+//!     Long,
+//!     Short,
+//!     #[boa(rename = "very short")]
+//!     VeryShort,
+//! }
+//!
+//! # fn main() -> JsResult<()> {
+//! let context = &mut Context::default();
+//! let options = context.eval(Source::from_bytes("({ style: 'very short' })"))?;
+//! let options = get_options_object(&options)?;
+//!
+//! let style = get_option::<Style>(&options, js_string!("style"), context)?;
+//! assert_eq!(style, Some(Style::VeryShort));
+//!
+//! let fallback = get_option::<bool>(&options, js_string!("fallback"), context)?;
+//! assert_eq!(fallback, None);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [spec]: https://tc39.es/ecma402/#sec-abstract-operations
 
 use std::{fmt, str::FromStr};
 
 use crate::value::JsVariant;
-use crate::{object::JsObject, Context, JsNativeError, JsResult, JsString, JsValue};
+use crate::{
+    builtins::OrdinaryObject, object::JsObject, Context, JsNativeError, JsResult, JsString, JsValue,
+};
+
+/// Derives [`OptionType`] for an enum of unit variants.
+///
+/// Each variant is parsed from its name in lower camel case, or from the name given by the
+/// `#[boa(rename = "...")]` attribute. Any other string throws a `RangeError`.
+pub use boa_macros::OptionType;
 
 /// A type used as an option parameter for [`get_option`].
-pub(crate) trait OptionType: Sized {
+pub trait OptionType: Sized {
     /// Parses a [`JsValue`] into an instance of `Self`.
     ///
     /// Roughly equivalent to the algorithm steps of [9.12.13.3-7][spec], but allows for parsing
@@ -19,7 +65,7 @@ pub(crate) trait OptionType: Sized {
 /// A type that implements [`OptionType`] by parsing a string.
 ///
 /// This automatically implements `OptionType` for a type if the type implements `FromStr`.
-pub(crate) trait ParsableOptionType: FromStr {}
+pub trait ParsableOptionType: FromStr {}
 
 impl<T: ParsableOptionType> OptionType for T
 where
@@ -49,13 +95,17 @@ where
 /// it wants to parse from a [`str`] or convert directly from a boolean or number.
 ///
 /// [spec]: https://tc39.es/ecma402/#sec-getoption
-pub(crate) fn get_option<T: OptionType>(
+pub fn get_option<T: OptionType>(
     options: &JsObject,
     property: JsString,
     context: &mut Context,
 ) -> JsResult<Option<T>> {
     // 1. Let value be ? Get(options, property).
-    let value = options.get(property, context)?;
+    // Most option bags are plain objects, whose properties can be read without the full `Get`.
+    let value = match options.get_fast(property.clone()) {
+        Some(value) => value,
+        None => options.get(property, context)?,
+    };
 
     // 2. If value is undefined, then
     if value.is_undefined() {
@@ -74,7 +124,7 @@ pub(crate) fn get_option<T: OptionType>(
 /// default empty `JsObject`. It throws a `TypeError` if `options` is not undefined and not a `JsObject`.
 ///
 /// [spec]: https://tc39.es/ecma402/#sec-getoptionsobject
-pub(crate) fn get_options_object(options: &JsValue) -> JsResult<JsObject> {
+pub fn get_options_object(options: &JsValue) -> JsResult<JsObject> {
     match options.variant() {
         // If options is undefined, then
         JsVariant::Undefined => {
@@ -93,6 +143,29 @@ pub(crate) fn get_options_object(options: &JsValue) -> JsResult<JsObject> {
     }
 }
 
+/// Abstract operation [`CoerceOptionsToObject ( options )`][spec]
+///
+/// Coerces `options` into a [`JsObject`] suitable for use with [`get_option`], defaulting to an
+/// empty `JsObject`.
+/// Because it coerces non-null primitive values into objects, its use is discouraged for new
+/// functionality in favour of [`get_options_object`].
+///
+/// [spec]: https://tc39.es/ecma402/#sec-coerceoptionstoobject
+pub fn coerce_options_to_object(options: &JsValue, context: &mut Context) -> JsResult<JsObject> {
+    // If options is undefined, then
+    if options.is_undefined() {
+        // a. Return OrdinaryObjectCreate(null).
+        return Ok(JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            None,
+            OrdinaryObject,
+        ));
+    }
+
+    // 2. Return ? ToObject(options).
+    options.to_object(context)
+}
+
 // Common options used in several builtins
 
 impl OptionType for bool {
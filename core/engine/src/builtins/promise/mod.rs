@@ -2111,7 +2111,7 @@ impl Promise {
                     // 4. Let alreadyResolved be F.[[AlreadyResolved]].
                     // 5. If alreadyResolved.[[Value]] is true, return undefined.
                     // 6. Set alreadyResolved.[[Value]] to true.
-                    let Some(promise) = captures.take() else {
+                    let Some(promise) = boa_gc::take_traced(captures) else {
                         return Ok(JsValue::undefined());
                     };
 
@@ -2210,7 +2210,7 @@ impl Promise {
                     // 4. Let alreadyResolved be F.[[AlreadyResolved]].
                     // 5. If alreadyResolved.[[Value]] is true, return undefined.
                     // 6. Set alreadyResolved.[[Value]] to true.
-                    let Some(promise) = captures.take() else {
+                    let Some(promise) = boa_gc::take_traced(captures) else {
                         return Ok(JsValue::undefined());
                     };
 
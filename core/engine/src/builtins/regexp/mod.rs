@@ -27,7 +27,7 @@ use boa_macros::{js_str, utf16};
 use boa_parser::lexer::regex::RegExpFlags;
 use boa_profiler::Profiler;
 use regress::{Flags, Range, Regex};
-use std::str::FromStr;
+use std::{cell::RefCell, rc::Rc, str::FromStr};
 
 use super::{BuiltInBuilder, BuiltInConstructor, IntrinsicObject};
 
@@ -46,6 +46,14 @@ pub struct RegExp {
     flags: RegExpFlags,
     original_source: JsString,
     original_flags: JsString,
+
+    /// The Latin1 input of the loop of `@@match`, `@@replace` or `@@split` executing this regex,
+    /// and its UTF-16 code units.
+    ///
+    /// The matcher cannot run on Latin1 strings directly, and these loops execute the regex many
+    /// times on the same input, so the conversion of the input is reused instead of being made on
+    /// every match. It is cleared when the loop ends, see [`RegExp::cache_latin1_input`].
+    latin1_input: Rc<RefCell<Option<(JsString, Rc<[u16]>)>>>,
 }
 
 impl IntrinsicObject for RegExp {
@@ -360,9 +368,41 @@ impl RegExp {
             flags,
             original_source: p,
             original_flags: f,
+            latin1_input: Rc::default(),
         })
     }
 
+    /// Converts the Latin1 `input` to UTF-16 code units, reusing the cached conversion if it was
+    /// made for the same string.
+    fn latin1_input_to_ucs2(&self, input: &JsString) -> Rc<[u16]> {
+        if let Some((cached, units)) = &*self.latin1_input.borrow() {
+            // Comparing the buffers is enough, since the cached string keeps its buffer alive.
+            if let (JsStrVariant::Latin1(cached), JsStrVariant::Latin1(input)) =
+                (cached.as_str().variant(), input.as_str().variant())
+            {
+                if std::ptr::eq(cached, input) {
+                    return units.clone();
+                }
+            }
+        }
+
+        input.to_vec().into()
+    }
+
+    /// Caches the UTF-16 code units of the Latin1 `input` for the executions of `rx` until the
+    /// returned guard is dropped.
+    ///
+    /// Returns `None` if `rx` is not a `RegExp` object or `input` is not a Latin1 string.
+    fn cache_latin1_input(rx: &JsObject, input: &JsString) -> Option<Latin1InputCache> {
+        if !matches!(input.as_str().variant(), JsStrVariant::Latin1(_)) {
+            return None;
+        }
+
+        let rx = rx.downcast_ref::<Self>()?;
+        *rx.latin1_input.borrow_mut() = Some((input.clone(), input.to_vec().into()));
+        Some(Latin1InputCache(rx.latin1_input.clone()))
+    }
+
     /// `RegExpInitialize ( obj, pattern, flags )`
     ///
     /// If prototype is `None`, initializes the prototype to `%RegExp%.prototype`.
@@ -918,9 +958,7 @@ impl RegExp {
         let length = input.len() as u64;
 
         // 2. Let lastIndex be ℝ(? ToLength(? Get(R, "lastIndex"))).
-        let mut last_index = this
-            .get(js_string!("lastIndex"), context)?
-            .to_length(context)?;
+        let mut last_index = get_last_index(this, context)?.to_length(context)?;
 
         // 3. Let flags be R.[[OriginalFlags]].
         let flags = &rx.original_flags;
@@ -957,7 +995,7 @@ impl RegExp {
             // i. If global is true or sticky is true, then
             if global || sticky {
                 // 1. Perform ? Set(R, "lastIndex", +0𝔽, true).
-                this.set(js_string!("lastIndex"), 0, true, context)?;
+                set_last_index(this, 0, context)?;
             }
 
             // ii. Return null.
@@ -966,10 +1004,15 @@ impl RegExp {
 
         // 13.b. Let inputIndex be the index into input of the character that was obtained from element lastIndex of S.
         // 13.c. Let r be matcher(input, inputIndex).
+        //
+        // TODO: Matching can take exponential time for patterns with catastrophic backtracking,
+        //       which should be bounded by a budget in `RuntimeLimits` and throw a `RangeError`.
+        //       This needs regress to support interrupting a match, e.g. with a step callback,
+        //       since it runs the whole backtracking search in a single call.
         let r: Option<regress::Match> = match (full_unicode, input.as_str().variant()) {
             (true | false, JsStrVariant::Latin1(_)) => {
                 // TODO: Currently regress does not support latin1 encoding.
-                let input = input.to_vec();
+                let input = rx.latin1_input_to_ucs2(input);
 
                 // NOTE: We can use the faster ucs2 variant since there will never be two byte unicode.
                 matcher.find_from_ucs2(&input, last_index as usize).next()
@@ -990,7 +1033,7 @@ impl RegExp {
             //       13.a.i. If global is true or sticky is true, then
             if global || sticky {
                 // 1. Perform ? Set(R, "lastIndex", +0𝔽, true).
-                this.set(js_string!("lastIndex"), 0, true, context)?;
+                set_last_index(this, 0, context)?;
             }
 
             // MOVE: ii. Set lastIndex to AdvanceStringIndex(S, lastIndex, fullUnicode).
@@ -1009,7 +1052,7 @@ impl RegExp {
         // NOTE: regress currently doesn't support the sticky flag so we have to emulate it.
         if sticky && match_value.start() != last_index as usize {
             // 1. Perform ? Set(R, "lastIndex", +0𝔽, true).
-            this.set(js_string!("lastIndex"), 0, true, context)?;
+            set_last_index(this, 0, context)?;
 
             // 2. Return null.
             return Ok(None);
@@ -1027,7 +1070,7 @@ impl RegExp {
         // 16. If global is true or sticky is true, then
         if global || sticky {
             // a. Perform ? Set(R, "lastIndex", 𝔽(e), true).
-            this.set(js_string!("lastIndex"), e, true, context)?;
+            set_last_index(this, e, context)?;
         }
 
         // 17. Let n be the number of elements in r's captures List.
@@ -1236,7 +1279,7 @@ impl RegExp {
         let full_unicode = flags.contains(b'u') || flags.contains(b'v');
 
         // b. Perform ? Set(rx, "lastIndex", +0𝔽, true).
-        rx.set(js_string!("lastIndex"), 0, true, context)?;
+        set_last_index(rx, 0, context)?;
 
         // c. Let A be ! ArrayCreate(0).
         let a = Array::array_create(0, None, context).expect("this ArrayCreate call must not fail");
@@ -1244,6 +1287,8 @@ impl RegExp {
         // d. Let n be 0.
         let mut n = 0;
 
+        let _latin1_input = Self::cache_latin1_input(rx, &arg_str);
+
         // e. Repeat,
         loop {
             // i. Let result be ? RegExpExec(rx, S).
@@ -1262,20 +1307,13 @@ impl RegExp {
                 // 3. If matchStr is the empty String, then
                 if match_str.is_empty() {
                     // a. Let thisIndex be ℝ(? ToLength(? Get(rx, "lastIndex"))).
-                    let this_index = rx
-                        .get(js_string!("lastIndex"), context)?
-                        .to_length(context)?;
+                    let this_index = get_last_index(rx, context)?.to_length(context)?;
 
                     // b. Let nextIndex be AdvanceStringIndex(S, thisIndex, fullUnicode).
                     let next_index = advance_string_index(&arg_str, this_index, full_unicode);
 
                     // c. Perform ? Set(rx, "lastIndex", 𝔽(nextIndex), true).
-                    rx.set(
-                        js_string!("lastIndex"),
-                        JsValue::new(next_index),
-                        true,
-                        context,
-                    )?;
+                    set_last_index(rx, next_index, context)?;
                 }
 
                 // 4. Set n to n + 1.
@@ -1453,7 +1491,7 @@ impl RegExp {
             let full_unicode = flags.contains(b'u');
 
             // b. Perform ? Set(rx, "lastIndex", +0𝔽, true).
-            rx.set(js_string!("lastIndex"), 0, true, context)?;
+            set_last_index(rx, 0, context)?;
 
             full_unicode
         } else {
@@ -1463,6 +1501,8 @@ impl RegExp {
         // 10. Let results be a new empty List.
         let mut results = Vec::new();
 
+        let latin1_input = Self::cache_latin1_input(rx, &s);
+
         // SKIPPED: 11. Let done be false.
         //
         // NOTE(HalidOdat): We don't keep track of `done`, we just break when done is true.
@@ -1495,28 +1535,25 @@ impl RegExp {
             //    2. If matchStr is the empty String, then
             if match_str.is_empty() {
                 // a. Let thisIndex be ℝ(? ToLength(? Get(rx, "lastIndex"))).
-                let this_index = rx
-                    .get(js_string!("lastIndex"), context)?
-                    .to_length(context)?;
+                let this_index = get_last_index(rx, context)?.to_length(context)?;
 
                 // b. Let nextIndex be AdvanceStringIndex(S, thisIndex, fullUnicode).
                 let next_index = advance_string_index(&s, this_index, full_unicode);
 
                 // c. Perform ? Set(rx, "lastIndex", 𝔽(nextIndex), true).
-                rx.set(
-                    js_string!("lastIndex"),
-                    JsValue::new(next_index),
-                    true,
-                    context,
-                )?;
+                set_last_index(rx, next_index, context)?;
             }
         }
 
+        drop(latin1_input);
+
         // 16. If nextSourcePosition ≥ lengthS, return accumulatedResult.
         // 17. Return the string-concatenation of accumulatedResult and the substring of S from nextSourcePosition.
 
         // 13. Let accumulatedResult be the empty String.
-        let mut accumulated_result = vec![];
+        // NOTE: The result is built as a list of replaced ranges of `S`, which are concatenated
+        //       with a single allocation at the end.
+        let mut replacements = Vec::with_capacity(results.len());
 
         // 14. Let nextSourcePosition be 0.
         let mut next_source_position = 0;
@@ -1622,25 +1659,26 @@ impl RegExp {
                 //    In such cases, the corresponding substitution is ignored.
 
                 // ii. Set accumulatedResult to the string-concatenation of accumulatedResult, the substring of S from nextSourcePosition to position, and replacement.
-                accumulated_result.extend(s.get_expect(next_source_position..position).iter());
-                accumulated_result.extend(replacement.iter());
+                replacements.push((next_source_position..position, replacement));
 
                 // iii. Set nextSourcePosition to position + matchLength.
                 next_source_position = position + match_length;
             }
         }
 
-        // 16. If nextSourcePosition ≥ lengthS, return accumulatedResult.
-        if next_source_position >= length_s {
-            return Ok(js_string!(&accumulated_result[..]).into());
+        let mut segments = Vec::with_capacity(replacements.len() * 2 + 1);
+        for (preserved, replacement) in &replacements {
+            segments.push(s.get_expect(preserved.clone()));
+            segments.push(replacement.as_str());
         }
 
+        // 16. If nextSourcePosition ≥ lengthS, return accumulatedResult.
         // 17. Return the string-concatenation of accumulatedResult and the substring of S from nextSourcePosition.
-        Ok(js_string!(
-            &JsString::from(&accumulated_result[..]),
-            s.get_expect(next_source_position..)
-        )
-        .into())
+        if next_source_position < length_s {
+            segments.push(s.get_expect(next_source_position..));
+        }
+
+        Ok(JsString::concat_array(&segments).into())
     }
 
     /// `RegExp.prototype[ @@search ]( string )`
@@ -1792,10 +1830,12 @@ impl RegExp {
         let mut p = 0;
         let mut q = p;
 
+        let _latin1_input = Self::cache_latin1_input(&splitter, &arg_str);
+
         // 19. Repeat, while q < size,
         while q < size {
             // a. Perform ? Set(splitter, "lastIndex", 𝔽(q), true).
-            splitter.set(js_string!("lastIndex"), JsValue::new(q), true, context)?;
+            set_last_index(&splitter, q, context)?;
 
             // b. Let z be ? RegExpExec(splitter, S).
             let result = Self::abstract_exec(&splitter, arg_str.clone(), context)?;
@@ -1804,9 +1844,7 @@ impl RegExp {
             // d. Else,
             if let Some(result) = result {
                 // i. Let e be ℝ(? ToLength(? Get(splitter, "lastIndex"))).
-                let mut e = splitter
-                    .get(js_string!("lastIndex"), context)?
-                    .to_length(context)?;
+                let mut e = get_last_index(&splitter, context)?.to_length(context)?;
 
                 // ii. Set e to min(e, size).
                 e = std::cmp::min(e, size);
@@ -1935,6 +1973,57 @@ impl RegExp {
     }
 }
 
+/// Clears the Latin1 input cached by [`RegExp::cache_latin1_input`] when dropped, so that the
+/// regex doesn't keep the input alive.
+struct Latin1InputCache(Rc<RefCell<Option<(JsString, Rc<[u16]>)>>>);
+
+impl Drop for Latin1InputCache {
+    fn drop(&mut self) {
+        self.0.borrow_mut().take();
+    }
+}
+
+/// Returns `true` if the regex object `rx` still has the shape it was created with, whose only
+/// own property is the writable `lastIndex` data property, stored in the first slot.
+fn has_initial_shape(rx: &JsObject, context: &Context) -> bool {
+    rx.borrow().properties().shape.to_addr_usize()
+        == context
+            .intrinsics()
+            .templates()
+            .regexp()
+            .shape()
+            .to_addr_usize()
+}
+
+/// Gets the `lastIndex` property of the regex object `rx`.
+///
+/// The property is read directly from the storage of regex objects with their initial shape,
+/// where `[[Get]]` would return it as is, instead of being looked up on every match.
+fn get_last_index(rx: &JsObject, context: &mut Context) -> JsResult<JsValue> {
+    if has_initial_shape(rx, context) {
+        return Ok(rx.borrow().properties().storage[0].clone());
+    }
+
+    rx.get(js_string!("lastIndex"), context)
+}
+
+/// Sets the `lastIndex` property of the regex object `rx`, throwing if it cannot be set.
+///
+/// The property is written directly to the storage of regex objects with their initial shape,
+/// since it is known to be writable there.
+fn set_last_index<V>(rx: &JsObject, value: V, context: &mut Context) -> JsResult<()>
+where
+    V: Into<JsValue>,
+{
+    if has_initial_shape(rx, context) {
+        rx.borrow_mut().properties_mut().storage[0] = value.into();
+        return Ok(());
+    }
+
+    rx.set(js_string!("lastIndex"), value, true, context)?;
+    Ok(())
+}
+
 /// `22.2.5.2.3 AdvanceStringIndex ( S, index, unicode )`
 ///
 /// More information:
@@ -1,6 +1,7 @@
+use super::RegExp;
 use crate::{
     js_string, native_function::NativeFunctionObject, run_test_actions, JsNativeErrorKind,
-    JsObject, JsValue, TestAction,
+    JsObject, JsValue, Source, TestAction,
 };
 use boa_macros::js_str;
 use indoc::indoc;
@@ -225,3 +226,73 @@ fn regular_expression_construction_independant_of_global_reg_exp() {
         TestAction::run(regex),
     ]);
 }
+
+#[test]
+fn global_replace_reuses_latin1_input() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var re = /a(b)?/g;
+            var first = "xabyaz".replace(re, (m, b, i) => `[${m}${b === undefined ? "" : "!"}${i}]`);
+            var second = "aa".replace(re, "λ");
+            var third = "xab".replace(re, "$1$&");
+        "#}),
+        TestAction::assert_eq("first", js_str!("x[ab!1]y[a4]z")),
+        TestAction::assert_eq("second", js_str!("\u{3bb}\u{3bb}")),
+        TestAction::assert_eq("third", js_str!("xbab")),
+        TestAction::assert_eq(
+            "[...'a1a2'.matchAll(/a(\\d)/g)].map(m => m[1]).join()",
+            js_str!("1,2"),
+        ),
+        TestAction::assert_eq(
+            "'abcabc'.replaceAll('b', '\u{3bb}')",
+            js_str!("a\u{3bb}ca\u{3bb}c"),
+        ),
+    ]);
+}
+#[test]
+fn latin1_input_is_not_kept_alive() {
+    run_test_actions([TestAction::inspect_context(|ctx| {
+        for code in [
+            "var re = /a/g; 'xaxa'.replace(re, 'b'); re",
+            "var re = /a/g; 'xaxa'.match(re); re",
+            "var re = /a/; re.exec('xaxa'); re",
+        ] {
+            let regexp = ctx.eval(Source::from_bytes(code)).unwrap();
+            let regexp = regexp
+                .as_object()
+                .unwrap()
+                .downcast_ref::<RegExp>()
+                .unwrap();
+            assert!(regexp.latin1_input.borrow().is_none());
+        }
+    })]);
+}
+
+#[test]
+fn last_index_of_modified_regexps() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var frozen = Object.freeze(/a/g);
+            var threw = false;
+            try {
+                "aa".replace(frozen, "b");
+            } catch (e) {
+                threw = e instanceof TypeError;
+            }
+
+            var calls = 0;
+            var sticky = /a/y;
+            sticky.lastIndex = { valueOf() { calls++; return 1; } };
+            var matched = sticky.exec("ba");
+
+            var extended = /a/g;
+            extended.other = 1;
+            var replaced = "aXa".replace(extended, "b");
+        "#}),
+        TestAction::assert("threw"),
+        TestAction::assert("matched.index === 1 && sticky.lastIndex === 2 && calls === 1"),
+        TestAction::assert_eq("replaced", js_str!("bXb")),
+        TestAction::assert_eq("extended.lastIndex", 0),
+        TestAction::assert_eq("'a,b,,c'.split(/,/).join('|')", js_str!("a|b||c")),
+    ]);
+}
@@ -0,0 +1,115 @@
+//! Non-standard grapheme methods of `String.prototype`.
+//!
+//! See [`ContextBuilder::string_grapheme_methods`].
+//!
+//! [`ContextBuilder::string_grapheme_methods`]: crate::context::ContextBuilder::string_grapheme_methods
+
+use std::ops::Range;
+
+use crate::{
+    js_string,
+    native_function::NativeFunction,
+    object::FunctionObjectBuilder,
+    property::PropertyDescriptor,
+    string::{JsStr, JsStrVariant},
+    value::IntegerOrInfinity,
+    Context, JsArgs, JsResult, JsString, JsValue,
+};
+
+use super::String;
+
+impl String {
+    /// Defines the grapheme methods on the `String.prototype` of the current realm.
+    pub(crate) fn define_grapheme_methods(context: &mut Context) -> JsResult<()> {
+        let prototype = context.intrinsics().constructors().string().prototype();
+
+        let methods: [(JsString, usize, NativeFunction); 2] = [
+            (
+                js_string!("graphemeAt"),
+                1,
+                NativeFunction::from_fn_ptr(Self::grapheme_at),
+            ),
+            (
+                js_string!("graphemeLength"),
+                0,
+                NativeFunction::from_fn_ptr(Self::grapheme_length),
+            ),
+        ];
+
+        for (name, length, body) in methods {
+            let function = FunctionObjectBuilder::new(context.realm(), body)
+                .name(name.clone())
+                .length(length)
+                .constructor(false)
+                .build();
+
+            prototype.define_property_or_throw(
+                name,
+                PropertyDescriptor::builder()
+                    .value(function)
+                    .writable(true)
+                    .enumerable(false)
+                    .configurable(true),
+                context,
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// `String.prototype.graphemeAt( index )`
+    ///
+    /// Returns the grapheme cluster of the string at `index`, counting from the end of the string
+    /// if `index` is negative, or `undefined` if there is no grapheme cluster at `index`.
+    ///
+    /// This is a non-standard method, only defined if enabled with
+    /// [`ContextBuilder::string_grapheme_methods`].
+    ///
+    /// [`ContextBuilder::string_grapheme_methods`]: crate::context::ContextBuilder::string_grapheme_methods
+    fn grapheme_at(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let this = this.require_object_coercible()?;
+        let s = this.to_string(context)?;
+
+        let graphemes = graphemes(s.as_str(), context)?;
+        let len = graphemes.len() as i64;
+
+        let relative_index = args.get_or_undefined(0).to_integer_or_infinity(context)?;
+        let k = match relative_index {
+            IntegerOrInfinity::Integer(i) if i >= 0 && i < len => i as usize,
+            IntegerOrInfinity::Integer(i) if i < 0 && (-i) <= len => (len + i) as usize,
+            _ => return Ok(JsValue::undefined()),
+        };
+
+        Ok(js_string!(s.get_expect(graphemes[k].clone())).into())
+    }
+
+    /// `String.prototype.graphemeLength( )`
+    ///
+    /// Returns the number of grapheme clusters of the string.
+    ///
+    /// This is a non-standard method, only defined if enabled with
+    /// [`ContextBuilder::string_grapheme_methods`].
+    ///
+    /// [`ContextBuilder::string_grapheme_methods`]: crate::context::ContextBuilder::string_grapheme_methods
+    fn grapheme_length(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let this = this.require_object_coercible()?;
+        let s = this.to_string(context)?;
+
+        Ok(graphemes(s.as_str(), context)?.len().into())
+    }
+}
+
+/// Gets the ranges of the grapheme clusters of `s`, using the segmentation data of `Intl`.
+fn graphemes(s: JsStr<'_>, context: &Context) -> JsResult<Vec<Range<usize>>> {
+    let segmenter = context.intl_provider().grapheme_segmenter()?.as_borrowed();
+    let boundaries: Vec<usize> = match s.variant() {
+        JsStrVariant::Latin1(s) => segmenter.segment_latin1(s).collect(),
+        JsStrVariant::Utf16(s) => segmenter.segment_utf16(s).collect(),
+    };
+
+    Ok(boundaries
+        .windows(2)
+        .map(|bounds| bounds[0]..bounds[1])
+        .filter(|range| !range.is_empty())
+        .collect())
+}
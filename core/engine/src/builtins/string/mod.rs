@@ -17,7 +17,7 @@ use crate::{
     object::{internal_methods::get_prototype_from_constructor, JsObject},
     property::{Attribute, PropertyDescriptor},
     realm::Realm,
-    string::{CodePoint, StaticJsStrings},
+    string::{CodePoint, NormalizationForm, Normalizers, StaticJsStrings},
     symbol::JsSymbol,
     value::IntegerOrInfinity,
     Context, JsArgs, JsResult, JsString, JsValue,
@@ -26,6 +26,7 @@ use boa_macros::utf16;
 
 use boa_profiler::Profiler;
 use cow_utils::CowUtils;
+#[cfg(feature = "intl")]
 use icu_normalizer::{ComposingNormalizer, DecomposingNormalizer};
 use std::{
     borrow::Cow,
@@ -34,6 +35,8 @@ use std::{
 
 use super::{BuiltInBuilder, BuiltInConstructor, IntrinsicObject};
 
+#[cfg(feature = "intl")]
+mod graphemes;
 mod string_iterator;
 pub(crate) use string_iterator::StringIterator;
 
@@ -41,6 +44,7 @@ pub(crate) use string_iterator::StringIterator;
 pub use crate::{js_str, JsStr};
 
 /// The set of normalizers required for the `String.prototype.normalize` function.
+#[cfg(feature = "intl")]
 #[derive(Debug)]
 pub(crate) struct StringNormalizers {
     pub(crate) nfc: ComposingNormalizer,
@@ -49,6 +53,19 @@ pub(crate) struct StringNormalizers {
     pub(crate) nfkd: DecomposingNormalizer,
 }
 
+#[cfg(feature = "intl")]
+impl StringNormalizers {
+    /// Borrows the normalizers, to normalize [`JsString`]s with them.
+    pub(crate) fn as_borrowed(&self) -> Normalizers<'_> {
+        Normalizers {
+            nfc: self.nfc.as_borrowed(),
+            nfd: self.nfd.as_borrowed(),
+            nfkc: self.nfkc.as_borrowed(),
+            nfkd: self.nfkd.as_borrowed(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests;
 
@@ -1202,13 +1219,12 @@ impl String {
         let mut end_of_last_match = 0;
 
         // 13. Let result be the empty String.
-        let mut result = Vec::with_capacity(string.len());
+        // NOTE: The result is built as a list of segments, which are concatenated with a single
+        //       allocation at the end.
+        let mut replacements = Vec::with_capacity(match_positions.len());
 
         // 14. For each element p of matchPositions, do
         for p in match_positions {
-            // a. Let preserved be the substring of string from endOfLastMatch to p.
-            let preserved = string.get_expect(end_of_last_match..p);
-
             // c. Else,
             let replacement = match replace {
                 // b. If functionalReplace is true, then
@@ -1241,9 +1257,17 @@ impl String {
                 .expect("GetSubstitution should never fail here."),
             };
 
+            replacements.push((p, replacement));
+        }
+
+        let mut segments = Vec::with_capacity(replacements.len() * 2 + 1);
+        for (p, replacement) in &replacements {
+            // a. Let preserved be the substring of string from endOfLastMatch to p.
+            let preserved = string.get_expect(end_of_last_match..*p);
+
             // d. Set result to the string-concatenation of result, preserved, and replacement.
-            result.extend(preserved.iter());
-            result.extend(replacement.iter());
+            segments.push(preserved);
+            segments.push(replacement.as_str());
 
             // e. Set endOfLastMatch to p + searchLength.
             end_of_last_match = p + search_length;
@@ -1252,11 +1276,11 @@ impl String {
         // 15. If endOfLastMatch < the length of string, then
         if end_of_last_match < string.len() {
             // a. Set result to the string-concatenation of result and the substring of string from endOfLastMatch.
-            result.extend(string.get_expect(end_of_last_match..).iter());
+            segments.push(string.get_expect(end_of_last_match..));
         }
 
         // 16. Return result.
-        Ok(js_string!(&result[..]).into())
+        Ok(JsString::concat_array(&segments).into())
     }
 
     /// `String.prototype.indexOf( searchValue[, fromIndex] )`
@@ -1323,12 +1347,7 @@ impl String {
         let s = o.to_string(context)?;
 
         // 3. Return IsStringWellFormedUnicode(S).
-        for code_point in s.code_points() {
-            if let CodePoint::UnpairedSurrogate(_) = code_point {
-                return Ok(false.into());
-            }
-        }
-        Ok(true.into())
+        Ok(s.is_well_formed().into())
     }
 
     /// `String.prototype.lastIndexOf( searchValue[, fromIndex] )`
@@ -1852,16 +1871,8 @@ impl String {
         // c. Else,
         // i. Set result to the string-concatenation of result and UTF16EncodeCodePoint(cp.[[CodePoint]]).
         // d. Set k to k + cp.[[CodeUnitCount]].
-        let result = s
-            .code_points()
-            .map(|code_point| match code_point {
-                CodePoint::UnpairedSurrogate(_) => '\u{FFFD}',
-                CodePoint::Unicode(char) => char,
-            })
-            .collect::<std::string::String>();
-
         // 7. Return result.
-        Ok(js_string!(result).into())
+        Ok(s.to_well_formed().into())
     }
 
     /// `String.prototype.substring( indexStart[, indexEnd] )`
@@ -2128,15 +2139,6 @@ impl String {
         args: &[JsValue],
         context: &mut Context,
     ) -> JsResult<JsValue> {
-        /// Represents the type of normalization applied to a [`JsString`]
-        #[derive(Clone, Copy)]
-        pub(crate) enum Normalization {
-            Nfc,
-            Nfd,
-            Nfkc,
-            Nfkd,
-        }
-
         // 1. Let O be ? RequireObjectCoercible(this value).
         let this = this.require_object_coercible()?;
 
@@ -2149,53 +2151,26 @@ impl String {
         let first = args.get_or_undefined(0);
         // 3. If form is undefined, let f be "NFC".
         let normalization = if first.is_undefined() {
-            Normalization::Nfc
+            NormalizationForm::Nfc
         } else {
             // 4. Else, let f be ? ToString(form).
-            match first.to_string(context)? {
-                ntype if &ntype == "NFC" => Normalization::Nfc,
-                ntype if &ntype == "NFD" => Normalization::Nfd,
-                ntype if &ntype == "NFKC" => Normalization::Nfkc,
-                ntype if &ntype == "NFKD" => Normalization::Nfkd,
-                // 5. If f is not one of "NFC", "NFD", "NFKC", or "NFKD", throw a RangeError exception.
-                _ => {
-                    return Err(JsNativeError::range()
-                        .with_message(
-                            "The normalization form should be one of NFC, NFD, NFKC, NFKD.",
-                        )
-                        .into());
-                }
-            }
+            let f = first.to_string(context)?;
+            // 5. If f is not one of "NFC", "NFD", "NFKC", or "NFKD", throw a RangeError exception.
+            NormalizationForm::from_name(f.as_str()).ok_or_else(|| {
+                JsNativeError::range()
+                    .with_message("The normalization form should be one of NFC, NFD, NFKC, NFKD.")
+            })?
         };
 
-        let normalizers = {
-            #[cfg(not(feature = "intl"))]
-            {
-                const NORMALIZERS: StringNormalizers = StringNormalizers {
-                    nfc: ComposingNormalizer::new_nfc().static_to_owned(),
-                    nfkc: ComposingNormalizer::new_nfkc().static_to_owned(),
-                    nfd: DecomposingNormalizer::new_nfd().static_to_owned(),
-                    nfkd: DecomposingNormalizer::new_nfkd().static_to_owned(),
-                };
-                &NORMALIZERS
-            }
-            #[cfg(feature = "intl")]
-            {
-                context.intl_provider().string_normalizers()?
-            }
-        };
-
-        let s = s.iter().collect::<Vec<_>>();
+        #[cfg(not(feature = "intl"))]
+        let normalizers = Normalizers::new();
+        #[cfg(feature = "intl")]
+        let normalizers = context.intl_provider().string_normalizers()?.as_borrowed();
 
-        let result = match normalization {
-            Normalization::Nfc => normalizers.nfc.as_borrowed().normalize_utf16(&s),
-            Normalization::Nfd => normalizers.nfd.as_borrowed().normalize_utf16(&s),
-            Normalization::Nfkc => normalizers.nfkc.as_borrowed().normalize_utf16(&s),
-            Normalization::Nfkd => normalizers.nfkd.as_borrowed().normalize_utf16(&s),
-        };
+        let result = s.normalize_with(normalization, &normalizers);
 
         // 7. Return ns.
-        Ok(js_string!(&result[..]).into())
+        Ok(result.into())
     }
 
     /// `String.prototype.search( regexp )`
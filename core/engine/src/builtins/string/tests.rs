@@ -916,3 +916,63 @@ fn from_code_point() {
         ),
     ]);
 }
+
+#[test]
+fn well_formed() {
+    run_test_actions([
+        TestAction::assert("'abc'.isWellFormed()"),
+        TestAction::assert("'caf\\u00e9 \\ud83d\\ude00'.isWellFormed()"),
+        TestAction::assert("!'a\\ud800b'.isWellFormed()"),
+        TestAction::assert("!'\\ude00\\ud83d'.isWellFormed()"),
+        TestAction::assert("!'abc\\ud83d'.isWellFormed()"),
+        TestAction::assert_eq("'abc'.toWellFormed()", js_str!("abc")),
+        TestAction::assert_eq("'\\ud83d\\ude00'.toWellFormed()", js_str!("\u{1F600}")),
+        TestAction::assert_eq(
+            "'a\\ud800b\\ude00\\ud83d'.toWellFormed()",
+            js_str!("a\u{FFFD}b\u{FFFD}\u{FFFD}"),
+        ),
+        TestAction::run("const s = 'x\\ud800'.repeat(1000);"),
+        TestAction::assert("!s.isWellFormed() && !s.isWellFormed()"),
+        TestAction::assert("s.toWellFormed().isWellFormed()"),
+        TestAction::assert_native_error(
+            "String.prototype.isWellFormed.call(undefined)",
+            JsNativeErrorKind::Type,
+            "cannot convert 'null' or 'undefined' to object",
+        ),
+    ]);
+}
+
+#[cfg(feature = "intl_bundled")]
+#[test]
+fn grapheme_methods() {
+    use crate::{run_test_actions_with, Context};
+
+    let context = &mut Context::builder()
+        .string_grapheme_methods(true)
+        .build()
+        .unwrap();
+    run_test_actions_with(
+        [
+            TestAction::assert_eq("'e\\u0301👍🏽🇫🇷'.graphemeLength()", 3),
+            TestAction::assert_eq("''.graphemeLength()", 0),
+            TestAction::assert_eq("'abc'.graphemeLength()", 3),
+            TestAction::assert_eq("'e\\u0301👍🏽🇫🇷'.graphemeAt(0)", js_str!("e\u{301}")),
+            TestAction::assert_eq("'e\\u0301👍🏽🇫🇷'.graphemeAt(1)", js_str!("👍🏽")),
+            TestAction::assert_eq("'e\\u0301👍🏽🇫🇷'.graphemeAt(-1)", js_str!("🇫🇷")),
+            TestAction::assert_eq("'e\\u0301👍🏽🇫🇷'.graphemeAt(3)", JsValue::undefined()),
+            TestAction::assert_eq("'abc'.graphemeAt(-4)", JsValue::undefined()),
+            TestAction::assert_eq("String.prototype.graphemeAt.length", 1),
+            TestAction::assert_native_error(
+                "String.prototype.graphemeLength.call(null)",
+                JsNativeErrorKind::Type,
+                "cannot convert 'null' or 'undefined' to object",
+            ),
+        ],
+        context,
+    );
+
+    run_test_actions([TestAction::assert_eq(
+        "typeof String.prototype.graphemeAt",
+        js_str!("undefined"),
+    )]);
+}
@@ -18,7 +18,10 @@
 #[cfg(test)]
 mod tests;
 
-use std::{hash::BuildHasherDefault, sync::LazyLock};
+use std::{
+    hash::BuildHasherDefault,
+    sync::{Arc, LazyLock},
+};
 
 use crate::{
     builtins::BuiltInObject,
@@ -26,21 +29,21 @@ use crate::{
     error::JsNativeError,
     js_string,
     object::JsObject,
-    property::Attribute,
+    property::{Attribute, PropertyDescriptor},
     realm::Realm,
     string::StaticJsStrings,
     symbol::JsSymbol,
     value::JsValue,
     Context, JsArgs, JsResult, JsString,
 };
+use boa_macros::js_str;
 use boa_profiler::Profiler;
-use dashmap::DashMap;
+use dashmap::{mapref::entry::Entry, DashMap};
 use rustc_hash::FxHasher;
 
 use super::{BuiltInBuilder, BuiltInConstructor, IntrinsicObject};
 
-static GLOBAL_SYMBOL_REGISTRY: LazyLock<GlobalSymbolRegistry> =
-    LazyLock::new(GlobalSymbolRegistry::new);
+static GLOBAL_SYMBOL_REGISTRY: LazyLock<SymbolRegistry> = LazyLock::new(SymbolRegistry::new);
 
 type FxDashMap<K, V> = DashMap<K, V, BuildHasherDefault<FxHasher>>;
 
@@ -50,43 +53,140 @@ type FxDashMap<K, V> = DashMap<K, V, BuildHasherDefault<FxHasher>>;
 // advanced users to utilize it. On the other hand, almost every JS programmer uses `JsString`s, and
 // the first option would impact performance for all `JsString`s in general. For those reasons, we
 // opted for the second option, but we should try to optimize this in the future.
-struct GlobalSymbolRegistry {
+#[derive(Debug, Default)]
+struct SymbolRegistryInner {
     keys: FxDashMap<Box<[u16]>, JsSymbol>,
     symbols: FxDashMap<JsSymbol, Box<[u16]>>,
+    well_known: FxDashMap<Box<[u16]>, JsSymbol>,
 }
 
-impl GlobalSymbolRegistry {
-    fn new() -> Self {
-        Self {
-            keys: FxDashMap::default(),
-            symbols: FxDashMap::default(),
-        }
+/// The [`GlobalSymbolRegistry`][spec] used by `Symbol.for` and `Symbol.keyFor`, together with
+/// the host-defined well-known symbols.
+///
+/// Every [`Context`] gets its own registry by default. Contexts built with the same registry
+/// (see [`ContextBuilder::symbol_registry`][crate::context::ContextBuilder::symbol_registry])
+/// behave as a single agent cluster: `Symbol.for("x")` returns the same symbol in all of them,
+/// even if they run on different threads. [`SymbolRegistry::global`] returns a process-wide
+/// registry for this purpose.
+///
+/// [spec]: https://tc39.es/ecma262/#sec-symbol.for
+#[derive(Debug, Clone, Default)]
+pub struct SymbolRegistry {
+    inner: Arc<SymbolRegistryInner>,
+}
+
+impl SymbolRegistry {
+    /// Creates a new, empty symbol registry.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
     }
 
-    fn get_or_create_symbol(&self, key: &JsString) -> JsResult<JsSymbol> {
-        let slice = key.iter().collect::<Vec<_>>();
-        if let Some(symbol) = self.keys.get(&slice[..]) {
+    /// Gets the process-wide symbol registry.
+    #[must_use]
+    pub fn global() -> Self {
+        GLOBAL_SYMBOL_REGISTRY.clone()
+    }
+
+    /// Returns `true` if both registries are the same registry.
+    #[must_use]
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.inner, &other.inner)
+    }
+
+    /// Gets the symbol registered with `key`, creating it if it doesn't exist.
+    ///
+    /// This is equivalent to calling `Symbol.for(key)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `RangeError` if the maximum number of symbols has been reached.
+    pub fn get_or_create_symbol(&self, key: &JsString) -> JsResult<JsSymbol> {
+        let slice = key.iter().collect::<Box<[u16]>>();
+        if let Some(symbol) = self.inner.keys.get(&slice) {
             return Ok(symbol.clone());
         }
 
-        let symbol = JsSymbol::new(Some(key.clone())).ok_or_else(|| {
-            JsNativeError::range()
-                .with_message("reached the maximum number of symbols that can be created")
-        })?;
-        self.keys
-            .insert(slice.clone().into_boxed_slice(), symbol.clone());
-        self.symbols
-            .insert(symbol.clone(), slice.into_boxed_slice());
-        Ok(symbol)
+        // The key stays locked until the symbol is in both maps, so concurrent calls with the same
+        // key get the same symbol, and `get_key` finds every symbol returned by this.
+        match self.inner.keys.entry(slice.clone()) {
+            Entry::Occupied(entry) => Ok(entry.get().clone()),
+            Entry::Vacant(entry) => {
+                let symbol = new_symbol(key.clone())?;
+                self.inner.symbols.insert(symbol.clone(), slice);
+                entry.insert(symbol.clone());
+                Ok(symbol)
+            }
+        }
     }
 
-    fn get_key(&self, sym: &JsSymbol) -> Option<JsString> {
-        if let Some(key) = self.symbols.get(sym) {
+    /// Gets the key of `sym` if it was created by this registry.
+    ///
+    /// This is equivalent to calling `Symbol.keyFor(sym)`.
+    #[must_use]
+    pub fn get_key(&self, sym: &JsSymbol) -> Option<JsString> {
+        if let Some(key) = self.inner.symbols.get(sym) {
             return Some(js_string!(&**key));
         }
 
         None
     }
+
+    /// Gets the host-defined well-known symbol named `name`, if it was registered with
+    /// [`Context::register_well_known_symbol`].
+    #[must_use]
+    pub fn well_known_symbol(&self, name: &JsString) -> Option<JsSymbol> {
+        let slice = name.iter().collect::<Vec<_>>();
+        self.inner
+            .well_known
+            .get(&slice[..])
+            .map(|symbol| symbol.clone())
+    }
+
+    /// Gets all the host-defined well-known symbols, along with their names.
+    pub(crate) fn well_known_symbols(&self) -> Vec<(JsString, JsSymbol)> {
+        self.inner
+            .well_known
+            .iter()
+            .map(|entry| (js_string!(&**entry.key()), entry.value().clone()))
+            .collect()
+    }
+
+    /// Gets the host-defined well-known symbol named `name`, calling `define` with it.
+    ///
+    /// If no symbol is registered with that name, a new symbol is only registered if `define`
+    /// succeeds, and the name stays locked until then, so concurrent calls with the same name get
+    /// the same symbol.
+    pub(crate) fn get_or_insert_well_known_symbol(
+        &self,
+        name: &JsString,
+        define: impl FnOnce(&JsSymbol) -> JsResult<()>,
+    ) -> JsResult<JsSymbol> {
+        let slice = name.iter().collect::<Box<[u16]>>();
+        match self.inner.well_known.entry(slice) {
+            Entry::Occupied(entry) => {
+                let symbol = entry.get().clone();
+                drop(entry);
+                define(&symbol)?;
+                Ok(symbol)
+            }
+            Entry::Vacant(entry) => {
+                let symbol = new_symbol(js_string!(js_str!("Symbol."), name))?;
+                define(&symbol)?;
+                entry.insert(symbol.clone());
+                Ok(symbol)
+            }
+        }
+    }
+}
+
+/// Creates a new unique symbol with the provided description.
+fn new_symbol(description: JsString) -> JsResult<JsSymbol> {
+    JsSymbol::new(Some(description)).ok_or_else(|| {
+        JsNativeError::range()
+            .with_message("reached the maximum number of symbols that can be created")
+            .into()
+    })
 }
 
 /// The internal representation of a `Symbol` object.
@@ -329,7 +429,8 @@ impl Symbol {
         // 4. Let newSymbol be a new unique Symbol value whose [[Description]] value is stringKey.
         // 5. Append the Record { [[Key]]: stringKey, [[Symbol]]: newSymbol } to the GlobalSymbolRegistry List.
         // 6. Return newSymbol.
-        GLOBAL_SYMBOL_REGISTRY
+        context
+            .symbol_registry()
             .get_or_create_symbol(&string_key)
             .map(JsValue::from)
     }
@@ -343,7 +444,11 @@ impl Symbol {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-symbol.prototype.keyfor
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Symbol/keyFor
-    pub(crate) fn key_for(_: &JsValue, args: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+    pub(crate) fn key_for(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
         // 1. If Type(sym) is not Symbol, throw a TypeError exception.
         let sym = args.get_or_undefined(0).as_symbol().ok_or_else(|| {
             JsNativeError::typ().with_message("Symbol.keyFor: sym is not a symbol")
@@ -354,12 +459,42 @@ impl Symbol {
         // 3. Assert: GlobalSymbolRegistry does not currently contain an entry for sym.
         // 4. Return undefined.
 
-        Ok(GLOBAL_SYMBOL_REGISTRY
+        Ok(context
+            .symbol_registry()
             .get_key(&sym)
             .map(JsValue::from)
             .unwrap_or_default())
     }
 
+    /// Defines the host-defined well-known symbol `symbol` as the `name` property of the `Symbol`
+    /// constructor of the current realm.
+    pub(crate) fn define_well_known_symbol(
+        name: JsString,
+        symbol: JsSymbol,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let constructor = context.intrinsics().constructors().symbol().constructor();
+        constructor.define_property_or_throw(
+            name,
+            PropertyDescriptor::builder()
+                .value(symbol)
+                .writable(false)
+                .enumerable(false)
+                .configurable(false),
+            context,
+        )?;
+        Ok(())
+    }
+
+    /// Defines all the host-defined well-known symbols of the symbol registry of the context on
+    /// the `Symbol` constructor of the current realm.
+    pub(crate) fn define_well_known_symbols(context: &mut Context) -> JsResult<()> {
+        for (name, symbol) in context.symbol_registry().well_known_symbols() {
+            Self::define_well_known_symbol(name, symbol, context)?;
+        }
+        Ok(())
+    }
+
     /// `Symbol.prototype [ @@toPrimitive ]`
     ///
     /// This function is called by ECMAScript language operators to convert a Symbol object to a primitive value.
@@ -1,4 +1,7 @@
-use crate::{run_test_actions, JsValue, TestAction};
+use crate::{
+    js_string, run_test_actions, run_test_actions_with, symbol::SymbolRegistry, Context,
+    JsNativeErrorKind, JsValue, Source, TestAction,
+};
 use boa_macros::js_str;
 use indoc::indoc;
 
@@ -32,3 +35,139 @@ fn symbol_access() {
         TestAction::assert_eq("x['Symbol(Hello)']", JsValue::undefined()),
     ]);
 }
+
+#[test]
+fn symbol_registry_is_per_context_by_default() {
+    let context1 = &mut Context::default();
+    let context2 = &mut Context::default();
+
+    let sym1 = context1
+        .eval(Source::from_bytes("Symbol.for('shared')"))
+        .unwrap();
+    let sym2 = context2
+        .eval(Source::from_bytes("Symbol.for('shared')"))
+        .unwrap();
+
+    assert_ne!(sym1, sym2);
+    assert!(!context1
+        .symbol_registry()
+        .ptr_eq(context2.symbol_registry()));
+}
+
+#[test]
+fn shared_symbol_registry() {
+    let registry = SymbolRegistry::new();
+    let context1 = &mut Context::builder()
+        .symbol_registry(registry.clone())
+        .build()
+        .unwrap();
+    let context2 = &mut Context::builder()
+        .symbol_registry(registry.clone())
+        .build()
+        .unwrap();
+
+    let sym1 = context1
+        .eval(Source::from_bytes("Symbol.for('shared')"))
+        .unwrap();
+    let sym2 = context2
+        .eval(Source::from_bytes("Symbol.for('shared')"))
+        .unwrap();
+    assert_eq!(sym1, sym2);
+
+    context2
+        .global_object()
+        .set(js_string!("sym"), sym1, false, context2)
+        .unwrap();
+    run_test_actions_with(
+        [TestAction::assert_eq(
+            "Symbol.keyFor(sym)",
+            js_str!("shared"),
+        )],
+        context2,
+    );
+}
+
+#[test]
+fn register_well_known_symbol() {
+    let registry = SymbolRegistry::new();
+    let context = &mut Context::builder()
+        .symbol_registry(registry.clone())
+        .build()
+        .unwrap();
+
+    let inspect = context
+        .register_well_known_symbol(js_string!("inspect"))
+        .unwrap();
+    assert_eq!(inspect.description(), Some(js_string!("Symbol.inspect")));
+    assert_eq!(
+        context
+            .register_well_known_symbol(js_string!("inspect"))
+            .unwrap(),
+        inspect
+    );
+
+    context
+        .global_object()
+        .set(js_string!("inspect"), inspect.clone(), false, context)
+        .unwrap();
+    run_test_actions_with(
+        [
+            TestAction::assert("Symbol.inspect === inspect"),
+            TestAction::assert(indoc! {r#"
+                    var desc = Object.getOwnPropertyDescriptor(Symbol, "inspect");
+                    !desc.writable && !desc.enumerable && !desc.configurable
+                "#}),
+        ],
+        context,
+    );
+
+    let err = context
+        .register_well_known_symbol(js_string!("iterator"))
+        .unwrap_err();
+    assert_eq!(
+        err.as_native().map(|err| &err.kind),
+        Some(&JsNativeErrorKind::Type)
+    );
+
+    let realm = context.create_realm().unwrap();
+    let old_realm = context.enter_realm(realm);
+    let value = context.eval(Source::from_bytes("Symbol.inspect")).unwrap();
+    context.enter_realm(old_realm);
+    assert_eq!(value, inspect.clone().into());
+
+    let other = &mut Context::builder()
+        .symbol_registry(registry)
+        .build()
+        .unwrap();
+    let value = other.eval(Source::from_bytes("Symbol.inspect")).unwrap();
+    assert_eq!(value, inspect.into());
+}
+
+#[test]
+fn shared_registry_across_threads() {
+    let registry = SymbolRegistry::new();
+    let threads = (0..8)
+        .map(|_| {
+            let registry = registry.clone();
+            std::thread::spawn(move || {
+                let context = &mut Context::builder()
+                    .symbol_registry(registry.clone())
+                    .build()
+                    .unwrap();
+                let symbol = registry.get_or_create_symbol(&js_string!("x")).unwrap();
+                assert_eq!(registry.get_key(&symbol), Some(js_string!("x")));
+
+                let inspect = context
+                    .register_well_known_symbol(js_string!("inspect"))
+                    .unwrap();
+                (symbol, inspect)
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let symbols = threads
+        .into_iter()
+        .map(|thread| thread.join().unwrap())
+        .collect::<Vec<_>>();
+    assert!(symbols.windows(2).all(|pair| pair[0] == pair[1]));
+}
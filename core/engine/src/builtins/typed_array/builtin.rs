@@ -7,7 +7,8 @@ use boa_macros::utf16;
 use num_traits::Zero;
 
 use super::{
-    object::typed_array_set_element, ContentType, TypedArray, TypedArrayKind, TypedArrayMarker,
+    object::typed_array_set_element, ContentType, TypedArray, TypedArrayElement, TypedArrayKind,
+    TypedArrayMarker,
 };
 use crate::{builtins::array_buffer::utils::memmove_naive, value::JsVariant};
 use crate::{
@@ -1622,20 +1623,17 @@ impl BuiltinTypedArray {
 
         // 5. Let k be 0.
         // 6. Repeat, while k < length,
-        let ta = ta.upcast();
-        for k in 0..len {
-            // a. Let from be ! ToString(𝔽(length - k - 1)).
-            // b. Let Pk be ! ToString(𝔽(k)).
-            // c. Let fromValue be ! Get(O, from).
-            let value = ta
-                .get(len - k - 1, context)
-                .expect("cannot fail per the spec");
-            // d. Perform ! Set(A, Pk, fromValue, true).
-            new_array
-                .set(k, value, true, context)
-                .expect("cannot fail per the spec");
-            // e. Set k to k + 1.
-        }
+        //     a. Let from be ! ToString(𝔽(length - k - 1)).
+        //     b. Let Pk be ! ToString(𝔽(k)).
+        //     c. Let fromValue be ! Get(O, from).
+        //     d. Perform ! Set(A, Pk, fromValue, true).
+        //     e. Set k to k + 1.
+        //
+        // NOTE: None of these steps can run user code, so the elements are copied directly
+        //       between the backing buffers.
+        let mut elements = read_typed_array_elements(&ta.borrow().data, buf_len, len);
+        elements.reverse();
+        write_new_typed_array_elements(&new_array, &elements);
 
         // 7. Return A.
         Ok(new_array.into())
@@ -2257,6 +2255,16 @@ impl BuiltinTypedArray {
         // 4. Let len be TypedArrayLength(taRecord).
         let len = ta.borrow().data.array_length(buf_len);
 
+        // Without a comparator, sorting can't run user code, so the elements are sorted
+        // directly on the backing buffer.
+        if compare_fn.is_none() {
+            let ta = ta.borrow();
+            let mut elements = read_typed_array_elements(&ta.data, buf_len, len);
+            elements.sort_by(compare_typed_array_element_values);
+            write_typed_array_elements(&ta.data, buf_len, &elements);
+            return Ok(this.clone());
+        }
+
         // 5. NOTE: The following closure performs a numeric comparison rather than the string comparison used in 23.1.3.30.
         // 6. Let SortCompare be a new Abstract Closure with parameters (x, y) that captures comparefn and performs the following steps when called:
         let sort_compare =
@@ -2312,6 +2320,15 @@ impl BuiltinTypedArray {
         // 5. Let A be ? TypedArrayCreateSameType(O, « 𝔽(len) »).
         let new_array = Self::from_kind_and_length(ta.borrow().data.kind(), len, context)?;
 
+        // Without a comparator, sorting can't run user code, so the elements are sorted
+        // directly on the backing buffers.
+        if compare_fn.is_none() {
+            let mut elements = read_typed_array_elements(&ta.borrow().data, buf_len, len);
+            elements.sort_by(compare_typed_array_element_values);
+            write_new_typed_array_elements(&new_array, &elements);
+            return Ok(new_array.into());
+        }
+
         // 6. NOTE: The following closure performs a numeric comparison rather than the string comparison used in 23.1.3.34.
         // 7. Let SortCompare be a new Abstract Closure with parameters (x, y) that captures comparefn and performs the following steps when called:
         let sort_compare =
@@ -3217,6 +3234,102 @@ fn compare_typed_array_elements(
     }
 }
 
+/// Compares two elements of the same kind, in the same way as `CompareTypedArrayElements`
+/// without a `comparefn`.
+fn compare_typed_array_element_values(
+    x: &TypedArrayElement,
+    y: &TypedArrayElement,
+) -> cmp::Ordering {
+    fn compare_floats(x: f64, y: f64) -> cmp::Ordering {
+        match (x.is_nan(), y.is_nan()) {
+            // 3. If x and y are both NaN, return +0𝔽.
+            (true, true) => cmp::Ordering::Equal,
+            // 4. If x is NaN, return 1𝔽.
+            (true, false) => cmp::Ordering::Greater,
+            // 5. If y is NaN, return -1𝔽.
+            (false, true) => cmp::Ordering::Less,
+            // 6-10. `total_cmp` also orders -0𝔽 before +0𝔽.
+            (false, false) => x.total_cmp(&y),
+        }
+    }
+
+    match (*x, *y) {
+        (TypedArrayElement::Int8(x), TypedArrayElement::Int8(y)) => x.cmp(&y),
+        (TypedArrayElement::Uint8(x), TypedArrayElement::Uint8(y)) => x.cmp(&y),
+        (TypedArrayElement::Uint8Clamped(x), TypedArrayElement::Uint8Clamped(y)) => x.cmp(&y),
+        (TypedArrayElement::Int16(x), TypedArrayElement::Int16(y)) => x.cmp(&y),
+        (TypedArrayElement::Uint16(x), TypedArrayElement::Uint16(y)) => x.cmp(&y),
+        (TypedArrayElement::Int32(x), TypedArrayElement::Int32(y)) => x.cmp(&y),
+        (TypedArrayElement::Uint32(x), TypedArrayElement::Uint32(y)) => x.cmp(&y),
+        (TypedArrayElement::BigInt64(x), TypedArrayElement::BigInt64(y)) => x.cmp(&y),
+        (TypedArrayElement::BigUint64(x), TypedArrayElement::BigUint64(y)) => x.cmp(&y),
+        (TypedArrayElement::Float32(x), TypedArrayElement::Float32(y)) => {
+            compare_floats(f64::from(x), f64::from(y))
+        }
+        (TypedArrayElement::Float64(x), TypedArrayElement::Float64(y)) => compare_floats(x, y),
+        _ => unreachable!("elements of a typed array must have the same kind"),
+    }
+}
+
+/// Reads the first `len` elements of a typed array directly from its backing buffer.
+///
+/// The typed array must not be out of bounds for a buffer with a byte length of `buf_len`.
+fn read_typed_array_elements(ta: &TypedArray, buf_len: usize, len: u64) -> Vec<TypedArrayElement> {
+    let kind = ta.kind();
+    let element_size = kind.element_size() as usize;
+    let byte_offset = ta.byte_offset() as usize;
+
+    let buffer = ta.viewed_array_buffer().as_buffer();
+    let buffer = buffer
+        .bytes_with_len(buf_len)
+        .expect("typed array must not be out of bounds");
+
+    (0..len as usize)
+        .map(|k| {
+            // SAFETY: The typed array is not out of bounds, so all of its elements are inside
+            // the buffer and aligned to their size.
+            unsafe {
+                buffer
+                    .subslice(byte_offset + k * element_size..)
+                    .get_value(kind, Ordering::Relaxed)
+            }
+        })
+        .collect()
+}
+
+/// Writes `elements` directly to the start of the backing buffer of a typed array.
+///
+/// The typed array must not be out of bounds for a buffer with a byte length of `buf_len`, and
+/// must have at least as many elements as `elements`, all of its kind.
+fn write_typed_array_elements(ta: &TypedArray, buf_len: usize, elements: &[TypedArrayElement]) {
+    let element_size = ta.kind().element_size() as usize;
+    let byte_offset = ta.byte_offset() as usize;
+
+    let mut buffer = ta.viewed_array_buffer().as_buffer_mut();
+    let mut buffer = buffer
+        .bytes_with_len(buf_len)
+        .expect("typed array must not be out of bounds");
+
+    for (k, element) in elements.iter().enumerate() {
+        // SAFETY: The typed array is not out of bounds and has enough elements, so all of them
+        // are inside the buffer and aligned to their size.
+        unsafe {
+            buffer
+                .subslice_mut(byte_offset + k * element_size..)
+                .set_value(*element, Ordering::Relaxed);
+        }
+    }
+}
+
+/// Writes `elements` to a typed array that was just created with a length of `elements.len()`.
+fn write_new_typed_array_elements(new_array: &JsObject, elements: &[TypedArrayElement]) {
+    let new_array = new_array
+        .downcast_ref::<TypedArray>()
+        .expect("must be a typed array");
+    let buf_len = elements.len() * new_array.kind().element_size() as usize;
+    write_typed_array_elements(&new_array, buf_len, elements);
+}
+
 /// Abstract operation `IsValidIntegerIndex ( O, index )`.
 ///
 /// Returns `true` if the index is valid, or `false` otherwise.
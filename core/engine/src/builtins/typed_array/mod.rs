@@ -32,6 +32,9 @@ mod builtin;
 mod element;
 mod object;
 
+#[cfg(test)]
+mod tests;
+
 pub(crate) use builtin::{is_valid_integer_index, BuiltinTypedArray};
 pub(crate) use element::{Atomic, ClampedU8, Element};
 pub use object::TypedArray;
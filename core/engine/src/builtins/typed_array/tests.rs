@@ -0,0 +1,59 @@
+use crate::{run_test_actions, TestAction};
+use indoc::indoc;
+
+#[test]
+fn sort_without_comparator() {
+    run_test_actions([
+        TestAction::assert_eq(
+            "new Int16Array([3, -1, 200, 0, -300]).sort().join()",
+            "-300,-1,0,3,200",
+        ),
+        TestAction::assert_eq(
+            "new Uint8ClampedArray([255, 3, 0, 128]).sort().join()",
+            "0,3,128,255",
+        ),
+        TestAction::assert_eq(
+            "new BigInt64Array([5n, -2n, 0n, -9007199254740993n]).sort().join()",
+            "-9007199254740993,-2,0,5",
+        ),
+        TestAction::assert(indoc! {r#"
+            var sorted = new Float64Array([NaN, 1, -0, Infinity, 0, -Infinity, NaN, -1]).sort();
+            var expected = [-Infinity, -1, -0, 0, 1, Infinity, NaN, NaN];
+            sorted.length === expected.length &&
+                expected.every((value, i) => Object.is(sorted[i], value))
+        "#}),
+        TestAction::assert(indoc! {r#"
+            var ta = new Float32Array([2.5, -0.5, 1]);
+            ta.sort() === ta && ta.join() === "-0.5,1,2.5"
+        "#}),
+    ]);
+}
+
+#[test]
+fn sort_subarray_in_place() {
+    run_test_actions([TestAction::assert_eq(
+        indoc! {r#"
+            var ta = new Uint32Array([9, 8, 7, 6, 5, 4]);
+            ta.subarray(1, 5).sort();
+            ta.join()
+        "#},
+        "9,5,6,7,8,4",
+    )]);
+}
+
+#[test]
+fn to_sorted_and_to_reversed() {
+    run_test_actions([
+        TestAction::run("var ta = new Int8Array([3, -1, 2]);"),
+        TestAction::assert_eq("ta.toSorted().join()", "-1,2,3"),
+        TestAction::assert_eq("ta.toSorted((a, b) => b - a).join()", "3,2,-1"),
+        TestAction::assert_eq("ta.toReversed().join()", "2,-1,3"),
+        TestAction::assert_eq("ta.join()", "3,-1,2"),
+        TestAction::assert("ta.toSorted() instanceof Int8Array"),
+        TestAction::assert_eq(
+            "new BigUint64Array([2n, 18446744073709551615n, 0n]).toSorted().join()",
+            "0,2,18446744073709551615",
+        ),
+        TestAction::assert_eq("new Float64Array(0).toSorted().length", 0),
+    ]);
+}
@@ -160,10 +160,7 @@ mod tests {
                 "#},
                 |v, _| v.is_object(),
             ),
-            TestAction::inspect_context(|context| {
-                context.clear_kept_objects();
-                boa_gc::force_collect();
-            }),
+            TestAction::collect_garbage(),
             TestAction::assert_eq("ptr.deref()", JsValue::undefined()),
         ]);
     }
@@ -0,0 +1,87 @@
+use crate::{run_test_actions, TestAction};
+use indoc::indoc;
+
+#[test]
+fn value_does_not_keep_key_alive() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+                var map = new WeakMap();
+                var keyRef, valueRef;
+                {
+                    let key = {};
+                    let value = { key };
+                    map.set(key, value);
+                    keyRef = new WeakRef(key);
+                    valueRef = new WeakRef(value);
+                }
+            "#}),
+        TestAction::collect_garbage(),
+        TestAction::assert("keyRef.deref() === undefined"),
+        TestAction::assert("valueRef.deref() === undefined"),
+    ]);
+}
+
+#[test]
+fn cycle_through_values_of_two_maps() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+                var first = new WeakMap();
+                var second = new WeakMap();
+                var refs;
+                {
+                    let a = {};
+                    let b = {};
+                    first.set(a, b);
+                    second.set(b, a);
+                    refs = [new WeakRef(a), new WeakRef(b)];
+                }
+            "#}),
+        TestAction::collect_garbage(),
+        TestAction::assert("refs.every(ref => ref.deref() === undefined)"),
+    ]);
+}
+
+#[test]
+fn chained_entries_are_kept_alive_by_the_first_key() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+                var map = new WeakMap();
+                var root = {};
+                var refs;
+                {
+                    let a = {};
+                    let b = {};
+                    let c = {};
+                    map.set(b, c);
+                    map.set(a, b);
+                    map.set(root, a);
+                    refs = [new WeakRef(a), new WeakRef(b), new WeakRef(c)];
+                }
+            "#}),
+        TestAction::collect_garbage(),
+        TestAction::assert("map.get(map.get(map.get(root))) === refs[2].deref()"),
+        TestAction::assert("refs.every(ref => ref.deref() !== undefined)"),
+        TestAction::run("root = undefined"),
+        TestAction::collect_garbage(),
+        TestAction::assert("refs.every(ref => ref.deref() === undefined)"),
+    ]);
+}
+
+#[test]
+fn live_key_keeps_value_alive() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+                var map = new WeakMap();
+                var key = {};
+                var valueRef;
+                {
+                    let value = { name: "value" };
+                    map.set(key, value);
+                    valueRef = new WeakRef(value);
+                }
+            "#}),
+        TestAction::collect_garbage(),
+        TestAction::assert(r#"map.get(key).name === "value""#),
+        TestAction::assert("valueRef.deref() === map.get(key)"),
+    ]);
+}
@@ -21,6 +21,9 @@ use crate::{
 use boa_gc::{Finalize, Trace};
 use boa_profiler::Profiler;
 
+#[cfg(test)]
+mod tests;
+
 use super::iterable::IteratorHint;
 
 type NativeWeakSet = boa_gc::WeakMap<ErasedVTableObject, ()>;
@@ -0,0 +1,22 @@
+use crate::{run_test_actions, TestAction};
+use indoc::indoc;
+
+#[test]
+fn values_are_held_weakly() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+                var set = new WeakSet();
+                var live = {};
+                var deadRef;
+                set.add(live);
+                {
+                    let dead = { live };
+                    set.add(dead);
+                    deadRef = new WeakRef(dead);
+                }
+            "#}),
+        TestAction::collect_garbage(),
+        TestAction::assert("deadRef.deref() === undefined"),
+        TestAction::assert("set.has(live)"),
+    ]);
+}
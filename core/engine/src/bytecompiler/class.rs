@@ -202,6 +202,7 @@ impl ByteCompiler<'_> {
             .emit_push_private_environment(class_register.variable(), name_indices);
 
         let mut static_elements = Vec::new();
+        let mut auto_accessors = 0;
 
         if let Some(scope) = class.name_scope {
             let binding = scope.get_identifier_reference(class_name.clone());
@@ -401,6 +402,20 @@ impl ByteCompiler<'_> {
                             self.compile_expr(expr, &name);
                         }
                     }
+                    let storage_index = if field.is_accessor() {
+                        let storage_index = self.auto_accessor_storage_index(&mut auto_accessors);
+                        self.bytecode
+                            .emit_to_property_key(name.variable(), name.variable());
+                        self.bytecode.emit_define_class_auto_accessor_by_value(
+                            class_register.variable(),
+                            proto_register.variable(),
+                            storage_index.into(),
+                            name.variable(),
+                        );
+                        Some(storage_index)
+                    } else {
+                        None
+                    };
                     let mut field_compiler = ByteCompiler::new(
                         js_string!(),
                         true,
@@ -440,17 +455,35 @@ impl ByteCompiler<'_> {
 
                     let dst = self.register_allocator.alloc();
                     self.emit_get_function(&dst, index);
-                    self.bytecode.emit_push_class_field(
-                        class_register.variable(),
-                        name.variable(),
-                        dst.variable(),
-                        is_anonymous_function.into(),
-                    );
+                    if let Some(storage_index) = storage_index {
+                        self.bytecode.emit_push_class_field_private(
+                            class_register.variable(),
+                            dst.variable(),
+                            storage_index.into(),
+                        );
+                    } else {
+                        self.bytecode.emit_push_class_field(
+                            class_register.variable(),
+                            name.variable(),
+                            dst.variable(),
+                            is_anonymous_function.into(),
+                        );
+                    }
                     self.register_allocator.dealloc(name);
                     self.register_allocator.dealloc(dst);
                 }
                 ClassElement::PrivateFieldDefinition(field) => {
-                    let name_index = self.get_or_insert_private_name(*field.name());
+                    let mut name_index = self.get_or_insert_private_name(*field.name());
+                    if field.is_accessor() {
+                        let storage_index = self.auto_accessor_storage_index(&mut auto_accessors);
+                        self.bytecode.emit_define_class_private_auto_accessor(
+                            class_register.variable(),
+                            storage_index.into(),
+                            name_index.into(),
+                            false.into(),
+                        );
+                        name_index = storage_index;
+                    }
                     let mut field_compiler = ByteCompiler::new(
                         class_name.clone(),
                         true,
@@ -492,7 +525,7 @@ impl ByteCompiler<'_> {
                     self.register_allocator.dealloc(dst);
                 }
                 ClassElement::StaticFieldDefinition(field) => {
-                    let name_index = match field.name() {
+                    let mut name_index = match field.name() {
                         PropertyName::Literal(name) => {
                             StaticFieldName::Index(self.get_or_insert_name(name.sym()))
                         }
@@ -502,6 +535,32 @@ impl ByteCompiler<'_> {
                             StaticFieldName::Register(name_register)
                         }
                     };
+                    if field.is_accessor() {
+                        let storage_index = self.auto_accessor_storage_index(&mut auto_accessors);
+                        match name_index {
+                            StaticFieldName::Index(name) => {
+                                self.bytecode.emit_define_class_auto_accessor_by_name(
+                                    class_register.variable(),
+                                    class_register.variable(),
+                                    storage_index.into(),
+                                    name.into(),
+                                );
+                            }
+                            StaticFieldName::Register(key) => {
+                                self.bytecode
+                                    .emit_to_property_key(key.variable(), key.variable());
+                                self.bytecode.emit_define_class_auto_accessor_by_value(
+                                    class_register.variable(),
+                                    class_register.variable(),
+                                    storage_index.into(),
+                                    key.variable(),
+                                );
+                                self.register_allocator.dealloc(key);
+                            }
+                            StaticFieldName::PrivateName(_) => unreachable!(),
+                        }
+                        name_index = StaticFieldName::PrivateName(storage_index);
+                    }
                     let mut field_compiler = ByteCompiler::new(
                         class_name.clone(),
                         true,
@@ -544,7 +603,17 @@ impl ByteCompiler<'_> {
                     });
                 }
                 ClassElement::PrivateStaticFieldDefinition(field) => {
-                    let name_index = self.get_or_insert_private_name(*field.name());
+                    let mut name_index = self.get_or_insert_private_name(*field.name());
+                    if field.is_accessor() {
+                        let storage_index = self.auto_accessor_storage_index(&mut auto_accessors);
+                        self.bytecode.emit_define_class_private_auto_accessor(
+                            class_register.variable(),
+                            storage_index.into(),
+                            name_index.into(),
+                            true.into(),
+                        );
+                        name_index = storage_index;
+                    }
                     let mut field_compiler = ByteCompiler::new(
                         class_name.clone(),
                         true,
@@ -662,7 +731,7 @@ impl ByteCompiler<'_> {
                             );
                         }
                         StaticFieldName::Index(name) => {
-                            self.bytecode.emit_define_own_property_by_name(
+                            self.bytecode.emit_define_class_static_field_by_name(
                                 class_register.variable(),
                                 value.variable(),
                                 name.into(),
@@ -678,7 +747,7 @@ impl ByteCompiler<'_> {
                                     0u32.into(),
                                 );
                             }
-                            self.bytecode.emit_define_own_property_by_value(
+                            self.bytecode.emit_define_class_static_field_by_value(
                                 value.variable(),
                                 key.variable(),
                                 class_register.variable(),
@@ -709,4 +778,15 @@ impl ByteCompiler<'_> {
         // NOTE: Reset strict mode to before class declaration/expression evalutation.
         self.code_block_flags.set(CodeBlockFlags::STRICT, strict);
     }
+
+    /// Returns the constant index of the private name that stores the value of the next
+    /// auto-accessor of a class.
+    ///
+    /// The description of the private name contains a space, so it cannot clash with any
+    /// private name written in the source.
+    fn auto_accessor_storage_index(&mut self, auto_accessors: &mut u32) -> u32 {
+        let index = self.get_or_insert_string(format!("accessor storage {auto_accessors}").into());
+        *auto_accessors += 1;
+        index
+    }
 }
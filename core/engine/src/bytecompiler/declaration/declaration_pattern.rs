@@ -6,6 +6,7 @@ use boa_ast::{
     pattern::{ArrayPatternElement, ObjectPatternElement, Pattern},
     property::PropertyName,
 };
+use boa_interner::Sym;
 use thin_vec::ThinVec;
 
 impl ByteCompiler<'_> {
@@ -46,16 +47,12 @@ impl ByteCompiler<'_> {
                                         object,
                                         ident.sym(),
                                     );
-                                    let key = self.register_allocator.alloc();
-                                    self.emit_push_literal(
-                                        Literal::String(
-                                            self.interner()
-                                                .resolve_expect(ident.sym())
-                                                .into_common(false),
-                                        ),
-                                        &key,
-                                    );
-                                    excluded_keys_registers.push(key);
+                                    if rest_exits {
+                                        self.push_excluded_key(
+                                            ident.sym(),
+                                            &mut excluded_keys_registers,
+                                        );
+                                    }
                                 }
                                 PropertyName::Computed(node) => {
                                     let key = self.register_allocator.alloc();
@@ -136,16 +133,12 @@ impl ByteCompiler<'_> {
                             let key = self.register_allocator.alloc();
                             match &name {
                                 PropertyName::Literal(ident) => {
-                                    let key = self.register_allocator.alloc();
-                                    self.emit_push_literal(
-                                        Literal::String(
-                                            self.interner()
-                                                .resolve_expect(ident.sym())
-                                                .into_common(false),
-                                        ),
-                                        &key,
-                                    );
-                                    excluded_keys_registers.push(key);
+                                    if rest_exits {
+                                        self.push_excluded_key(
+                                            ident.sym(),
+                                            &mut excluded_keys_registers,
+                                        );
+                                    }
                                 }
                                 PropertyName::Computed(node) => {
                                     self.compile_expr(node, &key);
@@ -215,17 +208,33 @@ impl ByteCompiler<'_> {
                                         object,
                                         ident.sym(),
                                     );
+                                    if rest_exits {
+                                        self.push_excluded_key(
+                                            ident.sym(),
+                                            &mut excluded_keys_registers,
+                                        );
+                                    }
                                 }
                                 PropertyName::Computed(node) => {
                                     let key = self.register_allocator.alloc();
                                     self.compile_expr(node, &key);
-                                    self.bytecode.emit_get_property_by_value(
-                                        dst.variable(),
-                                        key.variable(),
-                                        object.variable(),
-                                        object.variable(),
-                                    );
-                                    self.register_allocator.dealloc(key);
+                                    if rest_exits {
+                                        self.bytecode.emit_get_property_by_value_push(
+                                            dst.variable(),
+                                            key.variable(),
+                                            object.variable(),
+                                            object.variable(),
+                                        );
+                                        excluded_keys_registers.push(key);
+                                    } else {
+                                        self.bytecode.emit_get_property_by_value(
+                                            dst.variable(),
+                                            key.variable(),
+                                            object.variable(),
+                                            object.variable(),
+                                        );
+                                        self.register_allocator.dealloc(key);
+                                    }
                                 }
                             }
 
@@ -282,6 +291,17 @@ impl ByteCompiler<'_> {
         }
     }
 
+    /// Pushes the string key `sym` into a new register, to be excluded from an object rest
+    /// property.
+    fn push_excluded_key(&mut self, sym: Sym, excluded_keys_registers: &mut Vec<Register>) {
+        let key = self.register_allocator.alloc();
+        self.emit_push_literal(
+            Literal::String(self.interner().resolve_expect(sym).into_common(false)),
+            &key,
+        );
+        excluded_keys_registers.push(key);
+    }
+
     fn compile_array_pattern_element(&mut self, element: &ArrayPatternElement, def: BindingOpcode) {
         use ArrayPatternElement::{
             Elision, Pattern, PatternRest, PropertyAccess, PropertyAccessRest, SingleName,
@@ -291,22 +311,22 @@ impl ByteCompiler<'_> {
         match element {
             // ArrayBindingPattern : [ Elision ]
             Elision => {
+                // An elision only advances the iterator, so `next` must not be called once the
+                // iterator is done, and the value is never read.
+                let done = self.register_allocator.alloc();
+                self.bytecode.emit_iterator_done(done.variable());
+                let skip = self.jump_if_true(&done);
+                self.register_allocator.dealloc(done);
                 self.bytecode.emit_iterator_next();
+                self.patch_jump(skip);
             }
             // SingleNameBinding : BindingIdentifier Initializer[opt]
             SingleName {
                 ident,
                 default_init,
             } => {
-                self.bytecode.emit_iterator_next();
                 let value = self.register_allocator.alloc();
-                self.bytecode.emit_iterator_done(value.variable());
-                let done = self.jump_if_true(&value);
-                self.bytecode.emit_iterator_value(value.variable());
-                let skip_push = self.jump();
-                self.patch_jump(done);
-                self.bytecode.emit_push_undefined(value.variable());
-                self.patch_jump(skip_push);
+                self.bytecode.emit_iterator_step_value(value.variable());
 
                 if let Some(init) = default_init {
                     let skip = self.emit_jump_if_not_undefined(&value);
@@ -323,14 +343,7 @@ impl ByteCompiler<'_> {
             } => {
                 let value = self.register_allocator.alloc();
                 self.access_set(Access::Property { access }, |compiler| {
-                    compiler.bytecode.emit_iterator_next();
-                    compiler.bytecode.emit_iterator_done(value.variable());
-                    let done = compiler.jump_if_true(&value);
-                    compiler.bytecode.emit_iterator_value(value.variable());
-                    let skip_push = compiler.jump();
-                    compiler.patch_jump(done);
-                    compiler.bytecode.emit_push_undefined(value.variable());
-                    compiler.patch_jump(skip_push);
+                    compiler.bytecode.emit_iterator_step_value(value.variable());
 
                     if let Some(init) = default_init {
                         let skip = compiler.emit_jump_if_not_undefined(&value);
@@ -347,15 +360,8 @@ impl ByteCompiler<'_> {
                 pattern,
                 default_init,
             } => {
-                self.bytecode.emit_iterator_next();
                 let value = self.register_allocator.alloc();
-                self.bytecode.emit_iterator_done(value.variable());
-                let done = self.jump_if_true(&value);
-                self.bytecode.emit_iterator_value(value.variable());
-                let skip_push = self.jump();
-                self.patch_jump(done);
-                self.bytecode.emit_push_undefined(value.variable());
-                self.patch_jump(skip_push);
+                self.bytecode.emit_iterator_step_value(value.variable());
 
                 if let Some(init) = default_init {
                     let skip = self.emit_jump_if_not_undefined(&value);
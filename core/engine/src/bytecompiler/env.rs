@@ -41,12 +41,25 @@ impl ByteCompiler<'_> {
         if let Some(mut scope) = scope {
             std::mem::swap(&mut self.lexical_scope, &mut scope);
             if !scope.all_bindings_local() {
+                self.forget_initialized_bindings(&scope);
                 self.current_open_environments_count -= 1;
                 self.bytecode.emit_pop_environment();
             }
         }
     }
 
+    /// Forgets that the bindings of `scope`, and of its inner scopes, are initialized.
+    ///
+    /// This must be called when the code compiled next can be reached without going through the
+    /// initializations of these bindings, like when leaving `scope`.
+    pub(crate) fn forget_initialized_bindings(&mut self, scope: &Scope) {
+        // The global scope doesn't have an environment on the stack, so the environment of a
+        // scope is at the index below its own.
+        let environment = scope.scope_index().saturating_sub(1);
+        self.initialized_bindings
+            .retain(|(index, _)| *index < environment);
+    }
+
     /// Pops the top scope.
     pub(crate) fn pop_scope(&mut self) {
         self.current_open_environments_count -= 1;
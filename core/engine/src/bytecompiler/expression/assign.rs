@@ -5,7 +5,7 @@ use crate::{
 use boa_ast::{
     expression::{
         access::{PropertyAccess, PropertyAccessField},
-        operator::{assign::AssignOp, Assign},
+        operator::{assign::AssignOp, binary::ArithmeticOp, Assign},
     },
     scope::BindingLocatorError,
     Expression,
@@ -57,6 +57,18 @@ impl ByteCompiler<'_> {
                     compiler.compile_expr(expr, dst);
                     Some(Label { index: next })
                 } else {
+                    let arithmetic_op = match op {
+                        AssignOp::Add => Some(ArithmeticOp::Add),
+                        AssignOp::Sub => Some(ArithmeticOp::Sub),
+                        AssignOp::Mul => Some(ArithmeticOp::Mul),
+                        _ => None,
+                    };
+                    if arithmetic_op
+                        .is_some_and(|op| compiler.compile_int32_arithmetic(op, expr, dst))
+                    {
+                        return None;
+                    }
+
                     let rhs = compiler.register_allocator.alloc();
                     compiler.compile_expr(expr, &rhs);
                     match op {
@@ -1,8 +1,11 @@
 use crate::bytecompiler::{ByteCompiler, Label, Register};
 use boa_ast::{
-    expression::operator::{
-        binary::{ArithmeticOp, BinaryOp, BitwiseOp, LogicalOp, RelationalOp},
-        Binary, BinaryInPrivate,
+    expression::{
+        literal::LiteralKind,
+        operator::{
+            binary::{ArithmeticOp, BinaryOp, BitwiseOp, LogicalOp, RelationalOp},
+            Binary, BinaryInPrivate,
+        },
     },
     Expression,
 };
@@ -38,6 +41,10 @@ impl ByteCompiler<'_> {
     }
 
     fn compile_binary_arithmetic(&mut self, op: ArithmeticOp, expr: &Expression, dst: &Register) {
+        if self.compile_int32_arithmetic(op, expr, dst) {
+            return;
+        }
+
         let rhs = self.register_allocator.alloc();
         self.compile_expr(expr, &rhs);
         let bytecode = &mut self.bytecode;
@@ -52,6 +59,34 @@ impl ByteCompiler<'_> {
         self.register_allocator.dealloc(rhs);
     }
 
+    /// Compiles the arithmetic `op` on `dst` and an integer literal `expr` to an opcode
+    /// specialized for `i32` operands.
+    ///
+    /// Returns `false` without emitting anything if `expr` is not an integer literal, or if `op`
+    /// has no specialized opcode.
+    pub(crate) fn compile_int32_arithmetic(
+        &mut self,
+        op: ArithmeticOp,
+        expr: &Expression,
+        dst: &Register,
+    ) -> bool {
+        let Expression::Literal(literal) = expr else {
+            return false;
+        };
+        let LiteralKind::Int(rhs) = *literal.kind() else {
+            return false;
+        };
+
+        let bytecode = &mut self.bytecode;
+        match op {
+            ArithmeticOp::Add => bytecode.emit_add_int32(dst.variable(), dst.variable(), rhs),
+            ArithmeticOp::Sub => bytecode.emit_sub_int32(dst.variable(), dst.variable(), rhs),
+            ArithmeticOp::Mul => bytecode.emit_mul_int32(dst.variable(), dst.variable(), rhs),
+            ArithmeticOp::Div | ArithmeticOp::Exp | ArithmeticOp::Mod => return false,
+        }
+        true
+    }
+
     fn compile_binary_bitwise(&mut self, op: BitwiseOp, expr: &Expression, dst: &Register) {
         let rhs = self.register_allocator.alloc();
         self.compile_expr(expr, &rhs);
@@ -57,8 +57,11 @@ impl ByteCompiler<'_> {
             let value = self.register_allocator.alloc();
             match element {
                 TemplateElement::String(s) => {
+                    let cooked = s
+                        .cooked()
+                        .expect("template literals must not contain invalid escapes");
                     self.emit_push_literal(
-                        Literal::String(s.to_js_string(self.interner())),
+                        Literal::String(cooked.to_js_string(self.interner())),
                         &value,
                     );
                 }
@@ -108,9 +111,8 @@ impl ByteCompiler<'_> {
                 for element in literal.as_ref() {
                     if let Some(element) = element {
                         self.compile_expr(element, &value);
-                        if let Expression::Spread(_) = element {
-                            self.bytecode.emit_get_iterator(value.variable());
-                            self.bytecode.emit_push_iterator_to_array(dst.variable());
+                        if let Expression::Spread(spread) = element {
+                            self.push_spread_to_array(spread, &value, dst);
                         } else {
                             self.bytecode
                                 .emit_push_value_to_array(value.variable(), dst.variable());
@@ -305,14 +307,14 @@ impl ByteCompiler<'_> {
                 self.register_allocator.dealloc(function);
 
                 let site = template.identifier();
-                let count = template.cookeds().len() as u32;
+                let count = template.strings().len() as u32;
                 let jump_label = self.template_lookup(dst, site);
 
                 let mut part_registers = Vec::with_capacity(count as usize * 2);
 
-                for (cooked, raw) in template.cookeds().iter().zip(template.raws()) {
+                for string in template.strings() {
                     let value = self.register_allocator.alloc();
-                    if let Some(cooked) = cooked {
+                    if let Some(cooked) = string.cooked() {
                         self.emit_push_literal(
                             Literal::String(cooked.to_js_string(self.interner())),
                             &value,
@@ -323,7 +325,7 @@ impl ByteCompiler<'_> {
                     part_registers.push(value);
                     let value = self.register_allocator.alloc();
                     self.emit_push_literal(
-                        Literal::String(raw.to_js_string(self.interner())),
+                        Literal::String(string.raw().to_js_string(self.interner())),
                         &value,
                     );
                     part_registers.push(value);
@@ -351,6 +353,7 @@ impl ByteCompiler<'_> {
 
                 self.bytecode
                     .emit_call((template.exprs().len() as u32 + 1).into());
+                self.record_callee_text(template.tag());
                 self.pop_into_register(dst);
             }
             Expression::ClassExpression(class) => {
@@ -379,9 +382,8 @@ impl ByteCompiler<'_> {
 
                     for arg in super_call.arguments() {
                         self.compile_expr(arg, &value);
-                        if let Expression::Spread(_) = arg {
-                            self.bytecode.emit_get_iterator(value.variable());
-                            self.bytecode.emit_push_iterator_to_array(array.variable());
+                        if let Expression::Spread(spread) = arg {
+                            self.push_spread_to_array(spread, &value, &array);
                         } else {
                             self.bytecode
                                 .emit_push_value_to_array(value.variable(), array.variable());
@@ -24,7 +24,7 @@ impl ByteCompiler<'_> {
                     );
                     self.register_allocator.dealloc(value);
                 }
-                PropertyDefinition::Property(name, expr) => match name {
+                PropertyDefinition::Property(name, expr, _) => match name {
                     PropertyName::Literal(name) => {
                         let value = self.register_allocator.alloc();
                         self.compile_expr(expr, &value);
@@ -107,7 +107,7 @@ impl ByteCompiler<'_> {
                         }
                     }
                 }
-                PropertyDefinition::SpreadObject(expr) => {
+                PropertyDefinition::SpreadObject(expr, _) => {
                     let source = self.register_allocator.alloc();
                     self.compile_expr(expr, &source);
                     self.bytecode.emit_copy_data_properties(
@@ -117,7 +117,7 @@ impl ByteCompiler<'_> {
                     );
                     self.register_allocator.dealloc(source);
                 }
-                PropertyDefinition::CoverInitializedName(_, _) => {
+                PropertyDefinition::CoverInitializedName(_, _, _) => {
                     unreachable!("invalid assignment pattern in object literal")
                 }
             }
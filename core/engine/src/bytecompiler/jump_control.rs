@@ -4,6 +4,13 @@
 //! statements][iteration spec], [switch statements][switch spec], [try statements][try spec],
 //! and [labelled statements][labelled spec].
 //!
+//! The local control flow of a function is modeled as a graph: every [`JumpControlInfo`] is a node
+//! that abrupt completions can target or cross, and every `break`, `continue` and `return` is a
+//! [`JumpRecord`] edge, whose path through the enclosing nodes is computed once by
+//! [`ByteCompiler::jump_record_actions()`]. Edges that reach a node with the same kind and the same
+//! remaining path are merged, so they share the bytecode that is emitted for the rest of the path,
+//! including a single entry in the jump table of a `finally` block.
+//!
 //! [iteration spec]: https://tc39.es/ecma262/#sec-iteration-statements
 //! [switch spec]: https://tc39.es/ecma262/#sec-switch-statement
 //! [try spec]: https://tc39.es/ecma262/#sec-try-statement
@@ -19,7 +26,7 @@ use boa_interner::Sym;
 use thin_vec::thin_vec;
 
 /// An actions to be performed for the local control flow.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub(crate) enum JumpRecordAction {
     /// Places a [`crate::vm::opcode::Opcode::Jump`], transfers to a specified [`JumpControlInfo`] to be handled when it gets poped.
     Transfer {
@@ -57,9 +64,10 @@ pub(crate) enum JumpRecordAction {
     /// Both `continue` and `break` must go through the finally, but the `continue` goes to the beginning of the loop,
     /// and the `break` goes to the end of the loop, this is solved by having a jump table (See [`crate::vm::opcode::Opcode::JumpTable`])
     /// at the end of finally (It is constructed in [`ByteCompiler::pop_try_with_finally_control_info()`]).
+    ///
+    /// It is always followed by the [`JumpRecordAction::Transfer`] to the try statement, the jump table index
+    /// is resolved when the action is performed, so that merged [`JumpRecord`]s share an entry.
     HandleFinally {
-        /// Jump table index.
-        index: u32,
         /// Register for the flag that indicated if the finally block needs to re throw.
         finally_throw: u32,
     },
@@ -74,10 +82,13 @@ pub(crate) enum JumpRecordKind {
 }
 
 /// This represents a local control flow handling. See [`JumpRecordKind`] for types.
+///
+/// A record is an edge of the local control flow graph, it can have multiple `labels` when
+/// equivalent records have been merged.
 #[derive(Debug, Clone)]
 pub(crate) struct JumpRecord {
     kind: JumpRecordKind,
-    label: Label,
+    labels: Vec<Label>,
     actions: Vec<JumpRecordAction>,
 }
 
@@ -85,7 +96,7 @@ impl JumpRecord {
     pub(crate) const fn new(kind: JumpRecordKind, actions: Vec<JumpRecordAction>) -> Self {
         Self {
             kind,
-            label: ByteCompiler::DUMMY_LABEL,
+            labels: Vec::new(),
             actions,
         }
     }
@@ -95,8 +106,8 @@ impl JumpRecord {
         while let Some(action) = self.actions.pop() {
             match action {
                 JumpRecordAction::Transfer { index } => {
-                    self.label = compiler.jump();
-                    compiler.jump_info[index as usize].jumps.push(self);
+                    self.labels = vec![compiler.jump()];
+                    compiler.jump_info[index as usize].push_jump_record(self);
 
                     // Don't continue actions, let the delegate jump control info handle it!
                     return;
@@ -106,10 +117,15 @@ impl JumpRecord {
                         compiler.bytecode.emit_pop_environment();
                     }
                 }
-                JumpRecordAction::HandleFinally {
-                    index: value,
-                    finally_throw,
-                } => {
+                JumpRecordAction::HandleFinally { finally_throw } => {
+                    let Some(JumpRecordAction::Transfer { index: info }) = self.actions.last()
+                    else {
+                        unreachable!("handling a finally block must be followed by a transfer");
+                    };
+                    let remaining = &self.actions[..self.actions.len() - 1];
+                    let value =
+                        compiler.jump_info[*info as usize].jump_record_index(self.kind, remaining);
+
                     // Note: +1 because 0 is reserved for default entry in jump table (for fallthrough).
                     let index = value as i32 + 1;
                     let value = compiler.register_allocator.alloc();
@@ -126,8 +142,16 @@ impl JumpRecord {
 
         // If there are no actions left, finalize the jump record.
         match self.kind {
-            JumpRecordKind::Break => compiler.patch_jump(self.label),
-            JumpRecordKind::Continue => compiler.patch_jump_with_target(self.label, start_address),
+            JumpRecordKind::Break => {
+                for label in self.labels {
+                    compiler.patch_jump(label);
+                }
+            }
+            JumpRecordKind::Continue => {
+                for label in self.labels {
+                    compiler.patch_jump_with_target(label, start_address);
+                }
+            }
             JumpRecordKind::Return {
                 return_value_on_stack,
             } => {
@@ -317,6 +341,26 @@ impl JumpControlInfo {
     pub(crate) fn set_start_address(&mut self, start_address: u32) {
         self.start_address = start_address;
     }
+
+    /// Returns the index that a [`JumpRecord`] with the given kind and remaining actions has in
+    /// the `jumps` of this `JumpControlInfo`, once it is pushed.
+    fn jump_record_index(&self, kind: JumpRecordKind, actions: &[JumpRecordAction]) -> usize {
+        self.jumps
+            .iter()
+            .position(|record| record.kind == kind && record.actions == actions)
+            .unwrap_or(self.jumps.len())
+    }
+
+    /// Pushes a [`JumpRecord`] that has been transferred to this `JumpControlInfo`, merging it
+    /// with an equivalent record if there is one.
+    fn push_jump_record(&mut self, mut record: JumpRecord) {
+        let index = self.jump_record_index(record.kind, &record.actions);
+        if let Some(existing) = self.jumps.get_mut(index) {
+            existing.labels.append(&mut record.labels);
+        } else {
+            self.jumps.push(record);
+        }
+    }
 }
 
 // `JumpControlInfo` related methods that are implemented on `ByteCompiler`.
@@ -554,7 +598,7 @@ impl ByteCompiler<'_> {
             return;
         }
 
-        for JumpRecord { label, .. } in &info.jumps {
+        for label in info.jumps.iter().flat_map(|record| &record.labels) {
             self.patch_jump_with_target(*label, finally_start);
         }
 
@@ -566,10 +610,8 @@ impl ByteCompiler<'_> {
 
         let mut patch_jumps = Vec::with_capacity(info.jumps.len());
         // Handle breaks/continue/returns in a finally block
-        for i in 0..info.jumps.len() {
+        for jump_record in info.jumps {
             patch_jumps.push(self.next_opcode_location());
-
-            let jump_record = info.jumps[i].clone();
             jump_record.perform_actions(Self::DUMMY_ADDRESS, self);
         }
 
@@ -579,6 +621,52 @@ impl ByteCompiler<'_> {
             .patch_jump_table(jump_table_index, (default, &patch_jumps));
     }
 
+    /// Computes the path of a `break`, `continue` or `return` through the enclosing
+    /// [`JumpControlInfo`]s, up to its target.
+    ///
+    /// A `return` has no target in the function, so its path crosses all of them.
+    pub(crate) fn jump_record_actions(
+        &self,
+        kind: JumpRecordKind,
+        label: Option<Sym>,
+    ) -> Vec<JumpRecordAction> {
+        let mut actions = Vec::default();
+        for (i, info) in self.jump_info.iter().enumerate().rev() {
+            let count = self.jump_info_open_environment_count(i);
+            if count != 0 {
+                actions.push(JumpRecordAction::PopEnvironments { count });
+            }
+
+            if !info.in_finally() {
+                if let Some(finally_throw) = info.finally_throw {
+                    actions.push(JumpRecordAction::HandleFinally { finally_throw });
+                    actions.push(JumpRecordAction::Transfer { index: i as u32 });
+                }
+            }
+
+            let is_target = match (kind, label) {
+                (JumpRecordKind::Return { .. }, _) => false,
+                (_, Some(label)) => info.label() == Some(label),
+                (JumpRecordKind::Break, None) => info.is_loop() || info.is_switch(),
+                (JumpRecordKind::Continue, None) => info.is_loop(),
+            };
+            if is_target {
+                actions.push(JumpRecordAction::Transfer { index: i as u32 });
+                break;
+            }
+
+            // Iterator loops that are exited before reaching the target must be closed.
+            if info.iterator_loop() {
+                actions.push(JumpRecordAction::CloseIterator {
+                    r#async: info.for_await_of_loop(),
+                });
+            }
+        }
+
+        actions.reverse();
+        actions
+    }
+
     pub(crate) fn jump_info_open_environment_count(&self, index: usize) -> u32 {
         let current = &self.jump_info[index];
         if let Some(next) = self.jump_info.get(index + 1) {
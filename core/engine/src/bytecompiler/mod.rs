@@ -19,7 +19,8 @@ use crate::{
     js_string,
     vm::{
         opcode::{BindingOpcode, ByteCodeEmitter},
-        CallFrame, CodeBlock, CodeBlockFlags, Constant, GeneratorResumeKind, Handler, InlineCache,
+        CallFrame, CodeBlock, CodeBlockCounter, CodeBlockFlags, Constant, GeneratorResumeKind,
+        Handler, InlineCache, SourcePositionTable,
     },
     JsBigInt, JsStr, JsString, SourceText, SpannedSourceText,
 };
@@ -29,7 +30,7 @@ use boa_ast::{
         access::{PropertyAccess, PropertyAccessField},
         literal::ObjectMethodDefinition,
         operator::{assign::AssignTarget, update::UpdateTarget},
-        Call, Identifier, New, Optional, OptionalOperationKind,
+        Call, Identifier, New, Optional, OptionalOperationKind, Spread,
     },
     function::{
         ArrowFunction, AsyncArrowFunction, AsyncFunctionDeclaration, AsyncFunctionExpression,
@@ -40,13 +41,16 @@ use boa_ast::{
     operations::returns_value,
     pattern::Pattern,
     property::MethodDefinitionKind,
-    scope::{BindingLocator, BindingLocatorError, FunctionScopes, IdentifierReference, Scope},
-    Declaration, Expression, LinearSpan, Statement, StatementList, StatementListItem,
+    scope::{
+        BindingLocator, BindingLocatorError, BindingLocatorScope, FunctionScopes,
+        IdentifierReference, Scope,
+    },
+    Declaration, Expression, LinearSpan, Position, Statement, StatementList, StatementListItem,
 };
 use boa_gc::Gc;
-use boa_interner::{Interner, Sym};
+use boa_interner::{Interner, Sym, ToInternedString};
 use boa_macros::js_str;
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 use thin_vec::ThinVec;
 
 pub(crate) use declarations::{
@@ -447,9 +451,16 @@ pub struct ByteCompiler<'ctx> {
     code_block_flags: CodeBlockFlags,
     handlers: ThinVec<Handler>,
     pub(crate) ic: Vec<InlineCache>,
+    callee_texts: ThinVec<(u32, JsString)>,
+    source_positions: SourcePositionTable,
+    source_position: Option<Position>,
     literals_map: FxHashMap<Literal, u32>,
     names_map: FxHashMap<Sym, u32>,
     bindings_map: FxHashMap<BindingLocator, u32>,
+
+    /// The environments and slots of the lexical bindings that are initialized on every path to
+    /// the code being compiled, whose reads don't need a temporal dead zone check.
+    initialized_bindings: FxHashSet<(u32, u32)>,
     jump_info: Vec<JumpControlInfo>,
 
     /// Used to handle exception throws that escape the async function types.
@@ -548,10 +559,14 @@ impl<'ctx> ByteCompiler<'ctx> {
             code_block_flags,
             handlers: ThinVec::default(),
             ic: Vec::default(),
+            callee_texts: ThinVec::default(),
+            source_positions: SourcePositionTable::default(),
+            source_position: None,
 
             literals_map: FxHashMap::default(),
             names_map: FxHashMap::default(),
             bindings_map: FxHashMap::default(),
+            initialized_bindings: FxHashSet::default(),
             jump_info: Vec::new(),
             async_handler: None,
             json_parse,
@@ -753,12 +768,78 @@ impl<'ctx> ByteCompiler<'ctx> {
         self.bytecode.emit_push_from_register(src.variable());
     }
 
+    /// Pushes the values produced by the spread element in `value` to `array`.
+    ///
+    /// The target of a spread element that is an array literal cannot be reached from anywhere
+    /// else, so the VM is allowed to move its elements instead of copying them.
+    fn push_spread_to_array(&mut self, spread: &Spread, value: &Register, array: &Register) {
+        let is_array_literal = matches!(spread.target().flatten(), Expression::ArrayLiteral(_));
+        self.bytecode.emit_push_spread_to_array(
+            value.variable(),
+            array.variable(),
+            is_array_literal.into(),
+        );
+    }
+
+    /// Returns the environment and the slot of the binding at `index`, if it cannot be shadowed
+    /// at runtime and can be accessed without a name lookup.
+    fn binding_slot(&self, index: u32) -> Option<(u32, u32)> {
+        if self.in_with {
+            return None;
+        }
+        let locator = &self.bindings[index as usize];
+        let BindingLocatorScope::Stack(environment) = locator.scope() else {
+            return None;
+        };
+        self.lexical_scope
+            .is_binding_static(locator)
+            .then_some((environment, locator.binding_index()))
+    }
+
     pub(crate) fn emit_binding_access(
         &mut self,
         opcode: BindingAccessOpcode,
         binding: &BindingKind,
         value: &Register,
     ) {
+        if let BindingKind::Stack(index) = binding {
+            if let Some((environment, slot)) = self.binding_slot(*index) {
+                match opcode {
+                    BindingAccessOpcode::GetName
+                    | BindingAccessOpcode::GetNameOrUndefined
+                    | BindingAccessOpcode::GetNameAndLocator => {
+                        if self.initialized_bindings.contains(&(environment, slot)) {
+                            self.bytecode.emit_get_binding_unchecked(
+                                value.variable(),
+                                environment.into(),
+                                slot.into(),
+                            );
+                        } else {
+                            self.bytecode.emit_get_binding(
+                                value.variable(),
+                                environment.into(),
+                                slot.into(),
+                            );
+                        }
+                        return;
+                    }
+                    BindingAccessOpcode::SetName | BindingAccessOpcode::SetNameByLocator => {
+                        self.bytecode.emit_set_binding(
+                            value.variable(),
+                            environment.into(),
+                            slot.into(),
+                        );
+                        return;
+                    }
+                    BindingAccessOpcode::GetLocator => return,
+                    BindingAccessOpcode::DefVar
+                    | BindingAccessOpcode::DefInitVar
+                    | BindingAccessOpcode::PutLexicalValue
+                    | BindingAccessOpcode::DeleteName => {}
+                }
+            }
+        }
+
         match binding {
             BindingKind::Global(index) => match opcode {
                 BindingAccessOpcode::SetNameByLocator => {
@@ -801,9 +882,18 @@ impl<'ctx> ByteCompiler<'ctx> {
                 }
                 BindingAccessOpcode::GetLocator => self.bytecode.emit_get_locator((*index).into()),
                 BindingAccessOpcode::DefVar => self.bytecode.emit_def_var((*index).into()),
-                BindingAccessOpcode::PutLexicalValue => self
-                    .bytecode
-                    .emit_put_lexical_value(value.variable(), (*index).into()),
+                BindingAccessOpcode::PutLexicalValue => {
+                    self.bytecode
+                        .emit_put_lexical_value(value.variable(), (*index).into());
+
+                    // The code compiled after the initialization of a binding, until the end of
+                    // its scope, can only be reached through it.
+                    let locator = &self.bindings[*index as usize];
+                    if let BindingLocatorScope::Stack(environment) = locator.scope() {
+                        self.initialized_bindings
+                            .insert((environment, locator.binding_index()));
+                    }
+                }
                 BindingAccessOpcode::DefInitVar => self
                     .bytecode
                     .emit_def_init_var(value.variable(), (*index).into()),
@@ -975,6 +1065,15 @@ impl<'ctx> ByteCompiler<'ctx> {
         Label { index }
     }
 
+    /// Emit a jump that short-circuits an optional chain, leaving `undefined` in `value` when it
+    /// is taken.
+    fn jump_if_null_or_undefined_set_undefined(&mut self, value: &Register) -> Label {
+        let index = self.next_opcode_location();
+        self.bytecode
+            .emit_jump_if_null_or_undefined_set_undefined(Self::DUMMY_ADDRESS, value.variable());
+        Label { index }
+    }
+
     pub(crate) fn emit_jump_if_not_undefined(&mut self, value: &Register) -> Label {
         let index = self.next_opcode_location();
         self.bytecode
@@ -1288,7 +1387,27 @@ impl<'ctx> ByteCompiler<'ctx> {
     /// Compile an [`Expression`].
     #[inline]
     pub(crate) fn compile_expr(&mut self, expr: &Expression, dst: &'_ Register) {
+        let previous = self.set_source_position(expr.span().start());
         self.compile_expr_impl(expr, dst);
+        if let Some(previous) = previous {
+            self.set_source_position(previous);
+        }
+    }
+
+    /// Forgets the current source position, so that the instructions emitted by the next statement
+    /// are not mapped to an expression of the previous one.
+    ///
+    /// The table still maps them to the last position until an expression is compiled, since
+    /// statements do not have positions of their own.
+    fn clear_source_position(&mut self) {
+        self.source_position = None;
+    }
+
+    /// Maps the instructions emitted from now on to `position`, returning the previous position.
+    fn set_source_position(&mut self, position: Position) -> Option<Position> {
+        let start = self.next_opcode_location();
+        self.source_positions.push(start, position);
+        self.source_position.replace(position)
     }
 
     /// Compile a property access expression, prepending `this` to the property value in the stack.
@@ -1378,20 +1497,42 @@ impl<'ctx> ByteCompiler<'ctx> {
         this: &Register,
         value: &Register,
     ) {
-        let mut jumps = Vec::with_capacity(optional.chain().len());
+        let mut exits = Vec::with_capacity(optional.chain().len());
+
+        self.compile_optional_chain(optional, this, value, &mut exits);
 
+        // All the short-circuiting checks of the chain jump straight to the end of the chain,
+        // since they already leave `undefined` as its value.
+        for label in exits {
+            self.patch_jump(label);
+        }
+    }
+
+    /// Compile the operations of an optional chain, collecting the jumps that short-circuit it
+    /// into `exits`.
+    ///
+    /// The exits of an inner optional chain (`(a?.b)?.c`) are folded into the exits of the outer
+    /// chain, since an inner chain that short-circuits to `undefined` always short-circuits the
+    /// outer chain too.
+    fn compile_optional_chain(
+        &mut self,
+        optional: &Optional,
+        this: &Register,
+        value: &Register,
+        exits: &mut Vec<Label>,
+    ) {
         match optional.target().flatten() {
             Expression::PropertyAccess(access) => {
                 self.compile_access_preserve_this(access, this, value);
             }
-            Expression::Optional(opt) => self.compile_optional_preserve_this(opt, this, value),
+            Expression::Optional(opt) => self.compile_optional_chain(opt, this, value, exits),
             expr => {
                 self.bytecode.emit_push_undefined(this.variable());
                 self.compile_expr(expr, value);
             }
         }
 
-        jumps.push(self.jump_if_null_or_undefined(value));
+        exits.push(self.jump_if_null_or_undefined_set_undefined(value));
 
         let (first, rest) = optional
             .chain()
@@ -1403,19 +1544,10 @@ impl<'ctx> ByteCompiler<'ctx> {
 
         for item in rest {
             if item.shorted() {
-                jumps.push(self.jump_if_null_or_undefined(value));
+                exits.push(self.jump_if_null_or_undefined_set_undefined(value));
             }
             self.compile_optional_item_kind(item.kind(), this, value);
         }
-
-        let skip_undef = self.jump();
-
-        for label in jumps {
-            self.patch_jump(label);
-            self.bytecode.emit_push_undefined(value.variable());
-        }
-
-        self.patch_jump(skip_undef);
     }
 
     /// Compile a single operation in an optional chain.
@@ -1484,9 +1616,8 @@ impl<'ctx> ByteCompiler<'ctx> {
 
                     for arg in args {
                         self.compile_expr(arg, &value);
-                        if let Expression::Spread(_) = arg {
-                            self.bytecode.emit_get_iterator(value.variable());
-                            self.bytecode.emit_push_iterator_to_array(array.variable());
+                        if let Expression::Spread(spread) = arg {
+                            self.push_spread_to_array(spread, &value, &array);
                         } else {
                             self.bytecode
                                 .emit_push_value_to_array(value.variable(), array.variable());
@@ -1622,6 +1753,7 @@ impl<'ctx> ByteCompiler<'ctx> {
 
     /// Compile a [`StatementListItem`].
     fn compile_stmt_list_item(&mut self, item: &StatementListItem, use_expr: bool, block: bool) {
+        self.clear_source_position();
         match item {
             StatementListItem::Statement(stmt) => {
                 self.compile_stmt(stmt, use_expr, false);
@@ -1882,16 +2014,20 @@ impl<'ctx> ByteCompiler<'ctx> {
                         kind = CallKind::CallEval;
                     }
 
-                    if self.in_with {
+                    let with_binding = if self.in_with {
                         let name = self.resolve_identifier_expect(*ident);
                         let binding = self.lexical_scope.get_identifier_reference(name);
-                        let index = self.get_binding(&binding);
-                        let index = match index {
-                            BindingKind::Global(index) | BindingKind::Stack(index) => index,
-                            BindingKind::Local(_) => {
-                                unreachable!("with binding cannot be local")
-                            }
-                        };
+                        match self.get_binding(&binding) {
+                            BindingKind::Global(index) | BindingKind::Stack(index) => Some(index),
+                            // Local bindings are declared inside of the `with` statement, so its
+                            // object cannot provide them.
+                            BindingKind::Local(_) => None,
+                        }
+                    } else {
+                        None
+                    };
+
+                    if let Some(index) = with_binding {
                         let value = self.register_allocator.alloc();
                         self.bytecode
                             .emit_this_for_object_environment_name(value.variable(), index.into());
@@ -1940,9 +2076,8 @@ impl<'ctx> ByteCompiler<'ctx> {
 
             for arg in call.args() {
                 self.compile_expr(arg, &value);
-                if let Expression::Spread(_) = arg {
-                    self.bytecode.emit_get_iterator(value.variable());
-                    self.bytecode.emit_push_iterator_to_array(array.variable());
+                if let Expression::Spread(spread) = arg {
+                    self.push_spread_to_array(spread, &value, &array);
                 } else {
                     self.bytecode
                         .emit_push_value_to_array(value.variable(), array.variable());
@@ -1981,9 +2116,28 @@ impl<'ctx> ByteCompiler<'ctx> {
             CallKind::New if contains_spread => self.bytecode.emit_new_spread(),
             CallKind::New => self.bytecode.emit_new((call.args().len() as u32).into()),
         }
+        self.record_callee_text(call.function());
         self.pop_into_register(dst);
     }
 
+    /// Records the source text of `callee` for the call instruction that was just emitted, so that
+    /// a `TypeError` thrown because it is not callable can name the expression.
+    ///
+    /// Only references are recorded, which keeps the text short and recognizable.
+    fn record_callee_text(&mut self, callee: &Expression) {
+        let callee = callee.flatten();
+        if !matches!(
+            callee,
+            Expression::Identifier(_) | Expression::PropertyAccess(_) | Expression::This(_)
+        ) {
+            return;
+        }
+
+        let text = callee.to_interned_string(self.interner());
+        let pc = self.next_opcode_location();
+        self.callee_texts.push((pc, text.into()));
+    }
+
     /// Finish compiling code with the [`ByteCompiler`] and return the generated [`CodeBlock`].
     #[inline]
     #[must_use]
@@ -2005,6 +2159,7 @@ impl<'ctx> ByteCompiler<'ctx> {
         let register_count = self.register_allocator.finish();
 
         CodeBlock {
+            counter: CodeBlockCounter::new(self.constants.len()),
             name: self.function_name,
             length: self.length,
             register_count,
@@ -2017,6 +2172,8 @@ impl<'ctx> ByteCompiler<'ctx> {
             handlers: self.handlers,
             flags: Cell::new(self.code_block_flags),
             ic: self.ic.into_boxed_slice(),
+            callee_texts: self.callee_texts,
+            source_positions: self.source_positions,
             source_text_spanned: self.spanned_source_text,
         }
     }
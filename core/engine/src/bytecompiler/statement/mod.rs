@@ -1,4 +1,4 @@
-use super::jump_control::{JumpRecord, JumpRecordAction, JumpRecordKind};
+use super::jump_control::{JumpRecord, JumpRecordKind};
 use crate::bytecompiler::ByteCompiler;
 use boa_ast::Statement;
 
@@ -15,6 +15,7 @@ mod with;
 impl ByteCompiler<'_> {
     /// Compiles a [`Statement`] `boa_ast` node.
     pub fn compile_stmt(&mut self, node: &Statement, use_expr: bool, root_statement: bool) {
+        self.clear_source_position();
         match node {
             Statement::Var(var) => self.compile_var_decl(var),
             Statement::If(node) => self.compile_if(node, use_expr),
@@ -103,41 +104,11 @@ impl ByteCompiler<'_> {
     }
 
     pub(crate) fn r#return(&mut self, return_value_on_stack: bool) {
-        let actions = self.return_jump_record_actions();
+        let kind = JumpRecordKind::Return {
+            return_value_on_stack,
+        };
+        let actions = self.jump_record_actions(kind, None);
 
-        JumpRecord::new(
-            JumpRecordKind::Return {
-                return_value_on_stack,
-            },
-            actions,
-        )
-        .perform_actions(Self::DUMMY_ADDRESS, self);
-    }
-
-    fn return_jump_record_actions(&self) -> Vec<JumpRecordAction> {
-        let mut actions = Vec::default();
-        for (i, info) in self.jump_info.iter().enumerate().rev() {
-            let count = self.jump_info_open_environment_count(i);
-            actions.push(JumpRecordAction::PopEnvironments { count });
-
-            if !info.in_finally() {
-                if let Some(finally_throw) = info.finally_throw {
-                    actions.push(JumpRecordAction::HandleFinally {
-                        index: info.jumps.len() as u32,
-                        finally_throw,
-                    });
-                    actions.push(JumpRecordAction::Transfer { index: i as u32 });
-                }
-            }
-
-            if info.iterator_loop() {
-                actions.push(JumpRecordAction::CloseIterator {
-                    r#async: info.for_await_of_loop(),
-                });
-            }
-        }
-
-        actions.reverse();
-        actions
+        JumpRecord::new(kind, actions).perform_actions(Self::DUMMY_ADDRESS, self);
     }
 }
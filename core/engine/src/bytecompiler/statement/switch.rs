@@ -1,5 +1,19 @@
-use crate::bytecompiler::ByteCompiler;
-use boa_ast::statement::Switch;
+use crate::{
+    bytecompiler::{ByteCompiler, ToJsString},
+    vm::{opcode::SwitchTable, Constant},
+    JsString,
+};
+use boa_ast::{expression::literal::LiteralKind, statement::Switch, Expression};
+use rustc_hash::FxHashMap;
+
+/// The minimum number of cases of a [`Switch`] compiled to a jump table.
+const MIN_TABLE_CASES: usize = 4;
+
+/// The value of a literal case of a [`Switch`].
+enum CaseValue {
+    String(JsString),
+    Number(f64),
+}
 
 impl ByteCompiler<'_> {
     /// Compile a [`Switch`] `boa_ast` node
@@ -13,37 +27,63 @@ impl ByteCompiler<'_> {
         let start_address = self.next_opcode_location();
         self.push_switch_control_info(None, start_address, use_expr);
 
+        // If all the cases are string or number literals, the matching case is found with a
+        // single lookup in a jump table, instead of comparing the value with each case.
+        let case_values = self.literal_case_values(switch);
+        let table_index = case_values.is_some().then(|| {
+            let index = self.constants.len() as u32;
+            // The table is built once the addresses of the case bodies are known.
+            self.constants
+                .push(Constant::SwitchTable(Box::new(SwitchTable::Dense {
+                    min: 0,
+                    addresses: Box::default(),
+                })));
+            self.bytecode
+                .emit_case_table(value.variable(), index.into());
+            index
+        });
+
         let mut labels = Vec::with_capacity(switch.cases().len());
 
-        let condition = self.register_allocator.alloc();
+        if table_index.is_none() {
+            let condition = self.register_allocator.alloc();
 
-        for case in switch.cases() {
-            // If it does not have a condition it is the default case.
-            let label = if let Some(cond) = case.condition() {
-                self.compile_expr(cond, &condition);
-                self.case(&value, &condition)
-            } else {
-                Self::DUMMY_LABEL
-            };
+            for case in switch.cases() {
+                // If it does not have a condition it is the default case.
+                let label = if let Some(cond) = case.condition() {
+                    self.compile_expr(cond, &condition);
+                    self.case(&value, &condition)
+                } else {
+                    Self::DUMMY_LABEL
+                };
 
-            labels.push(label);
+                labels.push(label);
+            }
+
+            self.register_allocator.dealloc(condition);
         }
 
-        self.register_allocator.dealloc(condition);
         self.register_allocator.dealloc(value);
 
         let default_label = self.jump();
         let mut default_label_set = false;
+        let mut addresses = Vec::with_capacity(switch.cases().len());
+
+        for (i, case) in switch.cases().iter().enumerate() {
+            // The body of a case can be reached by jumping over the declarations of the previous
+            // cases.
+            if let Some(scope) = switch.scope() {
+                self.forget_initialized_bindings(scope);
+            }
 
-        for (label, case) in labels.into_iter().zip(switch.cases()) {
             // Check if it's the default case.
-            let label = if label == Self::DUMMY_LABEL {
+            if case.condition().is_none() {
                 default_label_set = true;
-                default_label
-            } else {
-                label
-            };
-            self.patch_jump(label);
+                self.patch_jump(default_label);
+            } else if let Some(label) = labels.get(i) {
+                self.patch_jump(*label);
+            }
+            addresses.push(self.next_opcode_location());
 
             self.compile_statement_list(case.body(), use_expr, true);
         }
@@ -52,7 +92,101 @@ impl ByteCompiler<'_> {
             self.patch_jump(default_label);
         }
 
+        if let (Some(index), Some(values)) = (table_index, case_values) {
+            let table = switch_table(values, &addresses, default_label.index);
+            self.constants[index as usize] = Constant::SwitchTable(Box::new(table));
+        }
+
         self.pop_switch_control_info();
         self.pop_declarative_scope(outer_scope);
     }
+
+    /// Returns the values of the cases of `switch`, with `None` for the default case.
+    ///
+    /// Returns `None` if a case is not a string or number literal, or if there are too few cases
+    /// for a jump table to be worth it.
+    fn literal_case_values(&self, switch: &Switch) -> Option<Vec<Option<CaseValue>>> {
+        let mut count = 0;
+        let values = switch
+            .cases()
+            .iter()
+            .map(|case| {
+                let Some(condition) = case.condition() else {
+                    return Some(None);
+                };
+                let Expression::Literal(literal) = condition else {
+                    return None;
+                };
+                count += 1;
+                match literal.kind() {
+                    LiteralKind::String(value) => {
+                        Some(Some(CaseValue::String(value.to_js_string(self.interner()))))
+                    }
+                    LiteralKind::Int(value) => Some(Some(CaseValue::Number(f64::from(*value)))),
+                    LiteralKind::Num(value) => Some(Some(CaseValue::Number(*value))),
+                    _ => None,
+                }
+            })
+            .collect::<Option<Vec<_>>>()?;
+
+        (count >= MIN_TABLE_CASES).then_some(values)
+    }
+}
+
+/// Builds the jump table of the cases with `values`, whose bodies start at `addresses`.
+///
+/// If several cases have the same value, the first one is selected, like with sequential
+/// comparisons. Values without a case map to `fallback`.
+fn switch_table(values: Vec<Option<CaseValue>>, addresses: &[u32], fallback: u32) -> SwitchTable {
+    let mut strings = FxHashMap::default();
+    let mut numbers = FxHashMap::default();
+
+    for (value, address) in values.into_iter().zip(addresses.iter().copied()) {
+        match value {
+            Some(CaseValue::String(value)) => {
+                strings.entry(value).or_insert(address);
+            }
+            Some(CaseValue::Number(value)) => {
+                if let Some(key) = SwitchTable::number_key(value) {
+                    numbers.entry(key).or_insert(address);
+                }
+            }
+            None => {}
+        }
+    }
+
+    if strings.is_empty() {
+        if let Some(table) = dense_switch_table(&numbers, fallback) {
+            return table;
+        }
+    }
+
+    SwitchTable::Hashed { strings, numbers }
+}
+
+/// Builds a [`SwitchTable::Dense`] if the cases are integers, and at least half of the integers
+/// between the smallest and the largest case are cases.
+fn dense_switch_table(numbers: &FxHashMap<u64, u32>, fallback: u32) -> Option<SwitchTable> {
+    let integers = numbers
+        .iter()
+        .map(|(key, address)| {
+            let number = f64::from_bits(*key);
+            let integer = number as i32;
+            (f64::from(integer) == number).then_some((integer, *address))
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    let min = integers.iter().map(|(integer, _)| *integer).min()?;
+    let max = integers.iter().map(|(integer, _)| *integer).max()?;
+    let len = max.abs_diff(min) as usize + 1;
+    if len > integers.len() * 2 {
+        return None;
+    }
+
+    let mut addresses = vec![fallback; len].into_boxed_slice();
+    for (integer, address) in integers {
+        addresses[integer.abs_diff(min) as usize] = address;
+    }
+
+    Some(SwitchTable::Dense { min, addresses })
 }
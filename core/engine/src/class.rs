@@ -107,9 +107,12 @@ use crate::{
     context::intrinsics::StandardConstructor,
     error::JsNativeError,
     native_function::NativeFunction,
-    object::{ConstructorBuilder, FunctionBinding, JsFunction, JsObject, NativeObject, PROTOTYPE},
+    object::{
+        ConstructorBuilder, FunctionBinding, JsFunction, JsObject, JsPrototype, NativeObject,
+        PROTOTYPE,
+    },
     property::{Attribute, PropertyDescriptor, PropertyKey},
-    Context, JsResult, JsValue,
+    Context, JsArgs, JsResult, JsString, JsSymbol, JsValue,
 };
 
 /// Native class.
@@ -254,6 +257,11 @@ impl<'ctx> ClassBuilder<'ctx> {
         );
         builder.name(T::NAME);
         builder.length(T::LENGTH);
+        builder.static_method(
+            NativeFunction::from_fn_ptr(has_instance::<T>),
+            JsSymbol::has_instance(),
+            1,
+        );
         Self { builder }
     }
 
@@ -370,9 +378,62 @@ impl<'ctx> ClassBuilder<'ctx> {
         self
     }
 
+    /// Specify the parent prototype which instances of the class inherit from, through the
+    /// `prototype` object of the class.
+    ///
+    /// Default is `Object.prototype`. This can be used to make instances of the class inherit the
+    /// methods of another registered class, which can be looked up with
+    /// [`Context::class_prototype`].
+    pub fn inherit<O: Into<JsPrototype>>(&mut self, prototype: O) -> &mut Self {
+        self.builder.inherit(prototype);
+        self
+    }
+
+    /// Sets the `Symbol.toStringTag` property of the class prototype.
+    ///
+    /// This customizes the tag returned by `Object.prototype.toString` for instances of the class,
+    /// like `[object Animal]`, which would be `[object Object]` otherwise. Like for built-in
+    /// objects, the property is only configurable.
+    pub fn to_string_tag<T: Into<JsString>>(&mut self, tag: T) -> &mut Self {
+        self.builder.property(
+            JsSymbol::to_string_tag(),
+            tag.into(),
+            Attribute::CONFIGURABLE,
+        );
+        self
+    }
+
     /// Return the current context.
     #[inline]
     pub fn context(&mut self) -> &mut Context {
         self.builder.context()
     }
 }
+
+/// The `[Symbol.hasInstance]` static method of the native class `T`.
+///
+/// Instances of `T` are branded by their native data, which makes `instanceof` work for instances
+/// created in other realms. Other values fall back to checking their prototype chain, like
+/// `Function.prototype[Symbol.hasInstance]`.
+fn has_instance<T: Class>(
+    this: &JsValue,
+    args: &[JsValue],
+    context: &mut Context,
+) -> JsResult<JsValue> {
+    let value = args.get_or_undefined(0);
+
+    if let (Some(constructor), Some(object)) = (this.as_object(), value.as_object()) {
+        // Subclasses inherit this method, but their instances must still be checked through the
+        // prototype chain, so only the class constructors of `T` can check the brand.
+        if object.is_native::<T>()
+            && constructor
+                .get_function_realm(context)?
+                .get_class::<T>()
+                .is_some_and(|class| class.constructor() == *constructor)
+        {
+            return Ok(true.into());
+        }
+    }
+
+    Ok(JsValue::ordinary_has_instance(this, value, context)?.into())
+}
@@ -0,0 +1,78 @@
+//! Runtime extensions, which install optional global bindings into a [`Context`].
+
+use crate::{Context, JsResult, JsString};
+
+/// A set of optional global bindings (e.g. `console`, timers or `URL`) that can be installed
+/// into a [`Context`] with [`Context::register_extension`].
+///
+/// Registered extensions can be listed with [`Context::extensions`] and removed individually
+/// with [`Context::unregister_extension`].
+///
+/// # Example
+///
+/// ```
+/// use boa_engine::{
+///     context::Extension, js_string, property::Attribute, Context, JsResult, JsString,
+///     JsValue, Source,
+/// };
+///
+/// struct Version;
+///
+/// impl Extension for Version {
+///     fn name(&self) -> &'static str {
+///         "version"
+///     }
+///
+///     fn globals(&self) -> Vec<JsString> {
+///         vec![js_string!("VERSION")]
+///     }
+///
+///     fn install(&self, context: &mut Context) -> JsResult<()> {
+///         context.register_global_property(js_string!("VERSION"), 1, Attribute::CONFIGURABLE)
+///     }
+/// }
+///
+/// let context = &mut Context::default();
+/// context.register_extension(Version).unwrap();
+/// assert!(context.has_extension("version"));
+/// assert_eq!(
+///     context.eval(Source::from_bytes("typeof VERSION")).unwrap(),
+///     JsValue::from(js_string!("number"))
+/// );
+///
+/// assert!(context.unregister_extension("version").unwrap());
+/// assert_eq!(
+///     context.eval(Source::from_bytes("typeof VERSION")).unwrap(),
+///     JsValue::from(js_string!("undefined"))
+/// );
+/// ```
+pub trait Extension {
+    /// The unique name of the extension.
+    fn name(&self) -> &'static str;
+
+    /// The names of the global properties defined by [`Extension::install`].
+    fn globals(&self) -> Vec<JsString>;
+
+    /// Defines the global bindings of the extension.
+    ///
+    /// # Errors
+    ///
+    /// Any error thrown while defining the global bindings.
+    fn install(&self, context: &mut Context) -> JsResult<()>;
+
+    /// Removes the global bindings of the extension.
+    ///
+    /// By default this deletes every property returned by [`Extension::globals`] from the global
+    /// object.
+    ///
+    /// # Errors
+    ///
+    /// Any error thrown while deleting the global bindings.
+    fn uninstall(&self, context: &mut Context) -> JsResult<()> {
+        let global = context.global_object();
+        for name in self.globals() {
+            global.delete_property_or_throw(name, context)?;
+        }
+        Ok(())
+    }
+}
@@ -162,6 +162,9 @@ pub trait HostHooks {
     ///
     /// Equivalent to the step 7 of [`InitializeHostDefinedRealm ( )`][ihdr].
     ///
+    /// Hosts that need to provide global bindings backed by their own state can return a global
+    /// object created with [`JsObject::with_global_interceptor`].
+    ///
     /// [ihdr]: https://tc39.es/ecma262/#sec-initializehostdefinedrealm
     fn create_global_object(&self, intrinsics: &Intrinsics) -> JsObject {
         JsObject::with_object_proto(intrinsics)
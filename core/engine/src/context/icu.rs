@@ -2,9 +2,11 @@ use std::{cell::OnceCell, fmt::Debug};
 
 use boa_profiler::Profiler;
 use icu_casemap::CaseMapper;
-use icu_locale::{LocaleCanonicalizer, LocaleExpander};
+use icu_collator::provider::CollationMetadataV1;
+use icu_locale::{LanguageIdentifier, LocaleCanonicalizer, LocaleExpander, LocaleFallbacker};
 use icu_normalizer::{ComposingNormalizer, DecomposingNormalizer};
 use icu_provider::prelude::*;
+use icu_segmenter::GraphemeClusterSegmenter;
 use serde::Deserialize;
 use thiserror::Error;
 use yoke::Yokeable;
@@ -32,13 +34,24 @@ impl From<IcuError> for JsError {
     }
 }
 
+/// The collation types that can be selected with the `co` Unicode extension keyword, sorted in
+/// code unit order.
+///
+/// `standard` and `search` are omitted, since ECMA-402 doesn't allow selecting them.
+const COLLATION_TYPES: [&str; 17] = [
+    "big5han", "compat", "dict", "direct", "ducet", "emoji", "eor", "gb2312", "phonebk",
+    "phonetic", "pinyin", "reformed", "searchjl", "stroke", "trad", "unihan", "zhuyin",
+];
+
 /// Custom [`DataProvider`] for `Intl` that caches some utilities.
 pub(crate) struct IntlProvider {
     inner_provider: Box<dyn DynamicDryDataProvider<BufferMarker>>,
     locale_canonicalizer: OnceCell<LocaleCanonicalizer>,
     locale_expander: OnceCell<LocaleExpander>,
+    locale_fallbacker: OnceCell<LocaleFallbacker>,
     string_normalizers: OnceCell<StringNormalizers>,
     case_mapper: OnceCell<CaseMapper>,
+    grapheme_segmenter: OnceCell<GraphemeClusterSegmenter>,
 }
 
 impl<M> DataProvider<M> for IntlProvider
@@ -70,8 +83,10 @@ impl Debug for IntlProvider {
         f.debug_struct("Icu")
             .field("locale_canonicalizer", &self.locale_canonicalizer)
             .field("locale_expander", &self.locale_expander)
+            .field("locale_fallbacker", &self.locale_fallbacker)
             .field("string_normalizers", &self.string_normalizers)
             .field("string_normalizercase_mapper", &self.case_mapper)
+            .field("grapheme_segmenter", &self.grapheme_segmenter)
             .finish_non_exhaustive()
     }
 }
@@ -89,8 +104,10 @@ impl IntlProvider {
         Self {
             locale_canonicalizer: OnceCell::new(),
             locale_expander: OnceCell::new(),
+            locale_fallbacker: OnceCell::new(),
             string_normalizers: OnceCell::new(),
             case_mapper: OnceCell::new(),
+            grapheme_segmenter: OnceCell::new(),
             inner_provider: Box::new(provider),
         }
     }
@@ -118,6 +135,16 @@ impl IntlProvider {
         Ok(self.locale_expander.get_or_init(|| le))
     }
 
+    /// Gets the [`LocaleFallbacker`] tool.
+    pub(crate) fn locale_fallbacker(&self) -> Result<&LocaleFallbacker, IcuError> {
+        if let Some(lf) = self.locale_fallbacker.get() {
+            return Ok(lf);
+        }
+
+        let lf = LocaleFallbacker::try_new_with_buffer_provider(&self.inner_provider)?;
+        Ok(self.locale_fallbacker.get_or_init(|| lf))
+    }
+
     /// Gets the [`StringNormalizers`] tools.
     pub(crate) fn string_normalizers(&self) -> Result<&StringNormalizers, IcuError> {
         if let Some(sn) = self.string_normalizers.get() {
@@ -142,6 +169,54 @@ impl IntlProvider {
         Ok(self.case_mapper.get_or_init(|| cm))
     }
 
+    /// Gets the [`GraphemeClusterSegmenter`] tool.
+    pub(crate) fn grapheme_segmenter(&self) -> Result<&GraphemeClusterSegmenter, IcuError> {
+        if let Some(gs) = self.grapheme_segmenter.get() {
+            return Ok(gs);
+        }
+        let gs = GraphemeClusterSegmenter::try_new_with_buffer_provider(&self.inner_provider)?;
+
+        Ok(self.grapheme_segmenter.get_or_init(|| gs))
+    }
+
+    /// Checks if the provider has data of the marker `M` with `attributes` for `language`,
+    /// without falling back to the data of a parent locale.
+    pub(crate) fn has_data<M: DataMarker>(
+        &self,
+        language: LanguageIdentifier,
+        attributes: &DataMarkerAttributes,
+    ) -> bool
+    where
+        Self: DryDataProvider<M>,
+    {
+        let locale = DataLocale::from(language);
+        let req = DataRequest {
+            id: DataIdentifierBorrowed::for_marker_attributes_and_locale(attributes, &locale),
+            metadata: {
+                let mut metadata = DataRequestMetadata::default();
+                metadata.silent = true;
+                metadata
+            },
+        };
+
+        DryDataProvider::<M>::dry_load(self, req)
+            .is_ok_and(|md| md.locale.is_none_or(|loc| loc == locale))
+    }
+
+    /// Gets the collation types that have tailored data for `language`, sorted in code unit
+    /// order.
+    pub(crate) fn collations(&self, language: &LanguageIdentifier) -> Vec<&'static str> {
+        COLLATION_TYPES
+            .into_iter()
+            .filter(|co| {
+                self.has_data::<CollationMetadataV1>(
+                    language.clone(),
+                    DataMarkerAttributes::from_str_or_panic(co),
+                )
+            })
+            .collect()
+    }
+
     /// Gets the inner provider.
     pub(crate) fn erased_provider(&self) -> &dyn DynamicDryDataProvider<BufferMarker> {
         &self.inner_provider
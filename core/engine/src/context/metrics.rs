@@ -0,0 +1,79 @@
+//! Metrics of the evaluations of a [`Context`].
+
+use std::time::Duration;
+
+use boa_parser::source::ReadChar;
+
+use crate::{script::Script, sys::time::Instant, JsResult, JsValue, Source};
+
+use super::Context;
+
+/// The metrics of an evaluation, returned by [`Context::eval_with_metrics`].
+///
+/// Hosts that run untrusted or user provided scripts can use the metrics to find out why a script
+/// is slow, or to bill the scripts by the work they do.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct EvalMetrics {
+    /// The wall time of the evaluation, including the parsing and the compilation of the source.
+    pub wall_time: Duration,
+
+    /// The number of bytecode instructions executed by the virtual machine.
+    pub instructions: u64,
+
+    /// The number of values allocated on the garbage collected heap of the current thread.
+    pub allocations: usize,
+
+    /// The number of garbage collections of the current thread, minor and major, that ran
+    /// during the evaluation.
+    pub gc_runs: usize,
+}
+
+impl Context {
+    /// Evaluates the given source like [`Context::eval`], and measures the [`EvalMetrics`] of
+    /// the evaluation.
+    ///
+    /// The metrics are returned even if the evaluation fails. Like [`Context::eval`], this won't
+    /// run any scheduled promise jobs, so the work done by the jobs is not measured.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use boa_engine::{Context, Source};
+    ///
+    /// let context = &mut Context::default();
+    ///
+    /// let (result, metrics) = context.eval_with_metrics(Source::from_bytes(
+    ///     "let points = []; for (let i = 0; i < 10; i++) { points.push({ x: i }); } points.length",
+    /// ));
+    ///
+    /// assert_eq!(result.unwrap().as_number(), Some(10.0));
+    /// assert!(metrics.instructions > 10);
+    /// assert!(metrics.allocations >= 10);
+    /// ```
+    pub fn eval_with_metrics<R: ReadChar>(
+        &mut self,
+        src: Source<'_, R>,
+    ) -> (JsResult<JsValue>, EvalMetrics) {
+        let gc_before = boa_gc::statistics();
+        let instructions_before = self.vm.executed_instructions;
+        let start = Instant::now();
+
+        let result = Script::parse(src, None, self).and_then(|script| script.evaluate(self));
+
+        let wall_time = start.elapsed();
+        let gc_after = boa_gc::statistics();
+        let metrics = EvalMetrics {
+            wall_time,
+            instructions: self
+                .vm
+                .executed_instructions
+                .wrapping_sub(instructions_before),
+            allocations: gc_after.allocations - gc_before.allocations,
+            gc_runs: (gc_after.minor.count + gc_after.major.count)
+                - (gc_before.minor.count + gc_before.major.count),
+        };
+
+        (result, metrics)
+    }
+}
@@ -4,6 +4,7 @@ use std::cell::RefCell;
 use std::{cell::Cell, path::Path, rc::Rc};
 
 use boa_ast::StatementList;
+use boa_gc::GcTuning;
 use boa_interner::Interner;
 use boa_parser::source::ReadChar;
 use boa_profiler::Profiler;
@@ -11,24 +12,30 @@ pub use hooks::{DefaultHooks, HostHooks};
 #[cfg(feature = "intl")]
 pub use icu::IcuError;
 use intrinsics::Intrinsics;
+use rustc_hash::FxHashSet;
 #[cfg(feature = "temporal")]
 use temporal_rs::tzdb::FsTzdbProvider;
 
 use crate::job::Job;
 use crate::vm::RuntimeLimits;
 use crate::{
-    builtins,
+    builtins::{
+        self,
+        symbol::{Symbol, SymbolRegistry},
+    },
     class::{Class, ClassBuilder},
+    interop::IntoJsFunctionCopied,
     job::{JobExecutor, SimpleJobExecutor},
     js_string,
-    module::{IdleModuleLoader, ModuleLoader, SimpleModuleLoader},
+    module::{IdleModuleLoader, ImportMap, ModuleLoader, SimpleModuleLoader},
     native_function::NativeFunction,
-    object::{shape::RootShape, FunctionObjectBuilder, JsObject},
+    object::{shape::RootShape, FunctionObjectBuilder, JsObject, PropertyWatcher},
     optimizer::{Optimizer, OptimizerOptions, OptimizerStatistics},
     property::{Attribute, PropertyDescriptor, PropertyKey},
     realm::Realm,
     script::Script,
-    vm::{ActiveRunnable, CallFrame, Vm},
+    symbol::JsSymbol,
+    vm::{ActiveRunnable, CallFrame, StackTrace, Vm},
     HostDefined, JsNativeError, JsResult, JsString, JsValue, NativeObject, Source,
 };
 
@@ -38,10 +45,18 @@ pub mod time;
 use crate::context::time::StdClock;
 pub use time::Clock;
 
+mod extension;
+pub use extension::Extension;
+
 mod hooks;
 #[cfg(feature = "intl")]
 pub(crate) mod icu;
 pub mod intrinsics;
+mod metrics;
+pub use metrics::EvalMetrics;
+mod pure;
+mod statistics;
+pub use statistics::ContextStatistics;
 
 thread_local! {
     static CANNOT_BLOCK_COUNTER: Cell<u64> = const { Cell::new(0) };
@@ -108,6 +123,29 @@ pub struct Context {
 
     can_block: bool,
 
+    /// Disables `with` statements and direct calls to `eval`.
+    deny_dynamic_scopes: bool,
+
+    /// Disables the lookups of `constructor[Symbol.species]` in the built-ins.
+    disable_species_lookups: bool,
+
+    /// Defines public class fields with `Set` instead of `CreateDataPropertyOrThrow`.
+    class_fields_use_set: bool,
+
+    /// Defines the non-standard `lineNumber` and `columnNumber` properties on error objects.
+    error_positions: bool,
+
+    /// Defines the non-standard `stack` property on error objects.
+    error_stack_traces: bool,
+
+    /// Computes the `Math` functions with software implementations that give the same results
+    /// on all platforms.
+    deterministic_math: bool,
+
+    /// Defines the non-standard grapheme methods of `String.prototype`.
+    #[cfg(feature = "intl")]
+    string_grapheme_methods: bool,
+
     #[cfg(feature = "temporal")]
     tz_provider: FsTzdbProvider,
 
@@ -123,6 +161,18 @@ pub struct Context {
 
     module_loader: Rc<dyn ModuleLoader>,
 
+    /// Remaps the specifiers of the imports before they are passed to the module loader.
+    pub(crate) import_map: Option<ImportMap>,
+
+    property_watcher: Option<Rc<dyn PropertyWatcher>>,
+
+    /// Native functions that can be called in a pure evaluation, in addition to the built-in ones.
+    pure_functions: FxHashSet<JsObject>,
+
+    symbol_registry: SymbolRegistry,
+
+    extensions: Vec<Box<dyn Extension>>,
+
     optimizer_options: OptimizerOptions,
     root_shape: RootShape,
 
@@ -145,6 +195,15 @@ impl std::fmt::Debug for Context {
             .field("hooks", &"HostHooks")
             .field("clock", &"Clock")
             .field("module_loader", &"ModuleLoader")
+            .field("import_map", &self.import_map)
+            .field(
+                "property_watcher",
+                &self.property_watcher.as_ref().map(|_| "PropertyWatcher"),
+            )
+            .field(
+                "extensions",
+                &self.extensions.iter().map(|e| e.name()).collect::<Vec<_>>(),
+            )
             .field("optimizer_options", &self.optimizer_options);
 
         #[cfg(feature = "intl")]
@@ -209,6 +268,70 @@ impl Context {
         result
     }
 
+    /// Evaluates the given source, which must be a single expression, without allowing it to have
+    /// any side effects.
+    ///
+    /// This is useful to compute values from untrusted or user provided expressions, e.g. the
+    /// expressions of a configuration file or the previews of an editor.
+    ///
+    /// Evaluation stops with an uncatchable error as soon as the expression, or any function it
+    /// calls, tries to:
+    /// - assign to or delete a variable or a property,
+    /// - call a native function that is not known to be free of side effects,
+    /// - `await` a value, import a module or directly call `eval`.
+    ///
+    /// The allowed native functions are the built-ins that only compute values and create new
+    /// objects, e.g. the functions of `Math` (except `Math.random`), `JSON` and the methods of
+    /// `String.prototype`. More functions can be allowed with [`Context::allow_in_pure_eval`].
+    ///
+    /// Creating objects and functions is allowed, as well as declaring variables inside of the
+    /// functions called by the expression.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `SyntaxError` if the source is not a single expression, and an uncatchable error
+    /// if the expression tries to have a side effect.
+    ///
+    /// # Examples
+    /// ```
+    /// # use boa_engine::{Context, Source};
+    /// let mut context = Context::default();
+    ///
+    /// let source = Source::from_bytes("Math.max(...[1, 2, 3].map((x) => x * 2))");
+    /// let value = context.eval_pure(source).unwrap();
+    ///
+    /// assert_eq!(value.as_number(), Some(6.0));
+    ///
+    /// assert!(context.eval_pure(Source::from_bytes("globalThis.x = 1")).is_err());
+    /// assert!(context.eval_pure(Source::from_bytes("Math.random()")).is_err());
+    /// ```
+    pub fn eval_pure<R: ReadChar>(&mut self, src: Source<'_, R>) -> JsResult<JsValue> {
+        let script = Script::parse(src, None, self)?;
+        if !script.is_single_expression() {
+            return Err(JsNativeError::syntax()
+                .with_message("a pure evaluation must consist of a single expression")
+                .into());
+        }
+
+        let mut functions = pure::builtin_pure_functions(self)?;
+        functions.extend(self.pure_functions.iter().cloned());
+
+        let previous = self.vm.pure_evaluation.replace(functions);
+        let result = script.evaluate(self);
+        self.vm.pure_evaluation = previous;
+
+        result
+    }
+
+    /// Allows calling the native `function` in the expressions evaluated with
+    /// [`Context::eval_pure`].
+    ///
+    /// The function must not have any side effects that are observable by the code running in
+    /// this context.
+    pub fn allow_in_pure_eval(&mut self, function: JsObject) {
+        self.pure_functions.insert(function);
+    }
+
     /// Applies optimizations to the [`StatementList`] inplace.
     pub fn optimize_statement_list(
         &mut self,
@@ -336,6 +459,93 @@ impl Context {
         Ok(())
     }
 
+    /// Register a global native function with typed arguments that is not a constructor.
+    ///
+    /// This is the same as [`Context::register_global_builtin_callable`], but the body is created
+    /// with [`NativeFunction::from_typed`] and the `length` of the function is derived from its
+    /// arguments, up to the first optional one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use boa_engine::{Context, js_string, Source, JsValue};
+    /// let context = &mut Context::default();
+    /// context
+    ///     .register_global_typed_callable(js_string!("add"), |a: i32, b: Option<i32>| {
+    ///         a + b.unwrap_or(0)
+    ///     })
+    ///     .unwrap();
+    ///
+    /// let result = context
+    ///     .eval(Source::from_bytes("[add(1, 2), add.length]"))
+    ///     .unwrap();
+    /// # let result = result.as_object().unwrap().clone();
+    /// # assert_eq!(result.get(0, context).unwrap(), JsValue::new(3));
+    /// # assert_eq!(result.get(1, context).unwrap(), JsValue::new(1));
+    /// ```
+    pub fn register_global_typed_callable<F, Args, Ret>(
+        &mut self,
+        name: JsString,
+        function: F,
+    ) -> JsResult<()>
+    where
+        F: IntoJsFunctionCopied<Args, Ret>,
+    {
+        self.register_global_builtin_callable(name, F::LENGTH, NativeFunction::from_typed(function))
+    }
+
+    /// Installs the global bindings of `extension` and records it in the list of registered
+    /// extensions.
+    ///
+    /// See [`Extension`] for more information.
+    ///
+    /// # Errors
+    ///
+    /// - An extension with the same name is already registered.
+    /// - [`Extension::install`] throws.
+    pub fn register_extension<E: Extension + 'static>(&mut self, extension: E) -> JsResult<()> {
+        if self.has_extension(extension.name()) {
+            return Err(JsNativeError::typ()
+                .with_message(format!(
+                    "cannot register the extension `{}` twice",
+                    extension.name()
+                ))
+                .into());
+        }
+
+        extension.install(self)?;
+        self.extensions.push(Box::new(extension));
+        Ok(())
+    }
+
+    /// Removes the global bindings of the registered extension named `name`.
+    ///
+    /// Returns `false` if no extension with that name is registered.
+    ///
+    /// # Errors
+    ///
+    /// Any error thrown by [`Extension::uninstall`]. The extension is removed from the list of
+    /// registered extensions regardless.
+    pub fn unregister_extension(&mut self, name: &str) -> JsResult<bool> {
+        let Some(index) = self.extensions.iter().position(|e| e.name() == name) else {
+            return Ok(false);
+        };
+        let extension = self.extensions.remove(index);
+        extension.uninstall(self)?;
+        Ok(true)
+    }
+
+    /// Checks if an extension named `name` is registered.
+    #[must_use]
+    pub fn has_extension(&self, name: &str) -> bool {
+        self.extensions.iter().any(|e| e.name() == name)
+    }
+
+    /// Returns an iterator over the registered extensions, in registration order.
+    pub fn extensions(&self) -> impl Iterator<Item = &dyn Extension> {
+        self.extensions.iter().map(AsRef::as_ref)
+    }
+
     /// Registers a global class `C` in the currently active realm.
     ///
     /// Errors if the class has already been registered.
@@ -417,6 +627,56 @@ impl Context {
         self.realm().get_class::<C>()
     }
 
+    /// Gets the prototype of the global class `C` if the currently active realm has that class
+    /// registered.
+    ///
+    /// This is the object that instances of `C` created by the class constructor or by
+    /// [`Class::from_data`] inherit from, which is useful to make other native objects or
+    /// classes behave like instances of `C`.
+    #[must_use]
+    pub fn class_prototype<C: Class>(&self) -> Option<JsObject> {
+        self.get_global_class::<C>().map(|class| class.prototype())
+    }
+
+    /// Registers a host-defined well-known symbol, exposing it as `Symbol[name]`.
+    ///
+    /// The symbol is stored in the [`SymbolRegistry`] of the context, so native code can look it
+    /// up with [`SymbolRegistry::well_known_symbol`] to implement custom protocols, the same way
+    /// built-in protocols use symbols like [`JsSymbol::iterator`]. It is defined on the `Symbol`
+    /// constructor of the current realm and of every realm created afterwards, and is shared by
+    /// all contexts using the same registry. Registering the same name again returns the
+    /// existing symbol.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TypeError` if `Symbol[name]` is already defined as a non-configurable property,
+    /// like the built-in well-known symbols.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use boa_engine::{js_string, Context, JsValue, Source};
+    /// let mut context = Context::default();
+    ///
+    /// let symbol = context
+    ///     .register_well_known_symbol(js_string!("inspect"))
+    ///     .unwrap();
+    /// let value = context
+    ///     .eval(Source::from_bytes("Symbol.inspect"))
+    ///     .unwrap();
+    ///
+    /// assert_eq!(value, JsValue::from(symbol));
+    /// ```
+    pub fn register_well_known_symbol(&mut self, name: JsString) -> JsResult<JsSymbol> {
+        // The property is defined before registering a new symbol, to avoid registering a symbol
+        // that can never be exposed.
+        self.symbol_registry
+            .clone()
+            .get_or_insert_well_known_symbol(&name, |symbol| {
+                Symbol::define_well_known_symbol(name.clone(), symbol.clone(), self)
+            })
+    }
+
     /// Gets the string interner.
     #[inline]
     #[must_use]
@@ -592,6 +852,32 @@ impl Context {
         self.module_loader.clone()
     }
 
+    /// Gets the [`SymbolRegistry`] used by `Symbol.for` and `Symbol.keyFor`.
+    #[inline]
+    #[must_use]
+    pub const fn symbol_registry(&self) -> &SymbolRegistry {
+        &self.symbol_registry
+    }
+
+    /// Gets the [`PropertyWatcher`] notified of property changes on watched objects.
+    #[inline]
+    #[must_use]
+    pub fn property_watcher(&self) -> Option<Rc<dyn PropertyWatcher>> {
+        self.property_watcher.clone()
+    }
+
+    /// Sets the [`PropertyWatcher`] notified of property changes on watched objects, returning
+    /// the previous one.
+    ///
+    /// See [`JsObject::watch`] for more information.
+    #[inline]
+    pub fn set_property_watcher(
+        &mut self,
+        watcher: Option<Rc<dyn PropertyWatcher>>,
+    ) -> Option<Rc<dyn PropertyWatcher>> {
+        std::mem::replace(&mut self.property_watcher, watcher)
+    }
+
     /// Get the [`RuntimeLimits`].
     #[inline]
     #[must_use]
@@ -611,6 +897,25 @@ impl Context {
         &mut self.vm.runtime_limits
     }
 
+    /// Returns the parameters of the garbage collector of the current thread.
+    ///
+    /// See [`ContextBuilder::gc_tuning`].
+    #[inline]
+    #[must_use]
+    #[allow(clippy::unused_self)]
+    pub fn gc_tuning(&self) -> GcTuning {
+        boa_gc::tuning()
+    }
+
+    /// Sets the parameters of the garbage collector of the current thread.
+    ///
+    /// See [`ContextBuilder::gc_tuning`].
+    #[inline]
+    #[allow(clippy::unused_self)]
+    pub fn set_gc_tuning(&mut self, gc_tuning: GcTuning) {
+        boa_gc::set_tuning(gc_tuning);
+    }
+
     /// Returns `true` if this context can be suspended by an `Atomics.wait` call.
     #[inline]
     #[must_use]
@@ -618,6 +923,81 @@ impl Context {
         self.can_block
     }
 
+    /// Returns `true` if `with` statements and direct calls to `eval` are disabled for this
+    /// context.
+    ///
+    /// See [`ContextBuilder::deny_dynamic_scopes`].
+    #[inline]
+    #[must_use]
+    pub const fn dynamic_scopes_denied(&self) -> bool {
+        self.deny_dynamic_scopes
+    }
+
+    /// Returns `true` if the built-ins always create objects with their default constructor,
+    /// ignoring `Symbol.species`.
+    ///
+    /// See [`ContextBuilder::disable_species_lookups`].
+    #[inline]
+    #[must_use]
+    pub const fn species_lookups_disabled(&self) -> bool {
+        self.disable_species_lookups
+    }
+
+    /// Returns `true` if public class fields are assigned with `Set` instead of being defined on
+    /// the object.
+    ///
+    /// See [`ContextBuilder::class_fields_use_set`].
+    #[inline]
+    #[must_use]
+    pub const fn class_fields_use_set(&self) -> bool {
+        self.class_fields_use_set
+    }
+
+    /// Returns `true` if error objects get the non-standard `lineNumber` and `columnNumber`
+    /// properties.
+    ///
+    /// See [`ContextBuilder::error_positions`].
+    #[inline]
+    #[must_use]
+    pub const fn error_positions(&self) -> bool {
+        self.error_positions
+    }
+
+    /// Returns `true` if error objects get the non-standard `stack` property.
+    ///
+    /// See [`ContextBuilder::error_stack_traces`].
+    #[inline]
+    #[must_use]
+    pub const fn error_stack_traces(&self) -> bool {
+        self.error_stack_traces
+    }
+
+    /// Returns `true` if the `Math` functions and the `**` operator give the same results on all
+    /// platforms.
+    ///
+    /// See [`ContextBuilder::deterministic_math`].
+    #[inline]
+    #[must_use]
+    pub const fn deterministic_math(&self) -> bool {
+        self.deterministic_math
+    }
+
+    /// Returns `true` if `String.prototype` gets the non-standard grapheme methods.
+    ///
+    /// See [`ContextBuilder::string_grapheme_methods`].
+    #[cfg(feature = "intl")]
+    #[inline]
+    #[must_use]
+    pub const fn string_grapheme_methods(&self) -> bool {
+        self.string_grapheme_methods
+    }
+
+    /// Captures the stack trace of the running code, if enabled with
+    /// [`ContextBuilder::error_stack_traces`].
+    pub(crate) fn capture_stack_trace(&self) -> Option<StackTrace> {
+        self.error_stack_traces.then(|| self.vm.stack_trace())
+    }
+
     /// Insert a type into the context-specific [`HostDefined`] field.
     #[inline]
     pub fn insert_data<T: NativeObject>(&mut self, value: T) -> Option<Box<T>> {
@@ -643,6 +1023,13 @@ impl Context {
     pub fn get_data<T: NativeObject>(&self) -> Option<&T> {
         self.data.get::<T>()
     }
+
+    /// Get a mutable reference to type T from the context-specific [`HostDefined`], if it exists.
+    #[inline]
+    #[must_use]
+    pub fn get_data_mut<T: NativeObject>(&mut self) -> Option<&mut T> {
+        self.data.get_mut::<T>()
+    }
 }
 
 // ==== Private API ====
@@ -917,7 +1304,18 @@ pub struct ContextBuilder {
     clock: Option<Rc<dyn Clock>>,
     job_executor: Option<Rc<dyn JobExecutor>>,
     module_loader: Option<Rc<dyn ModuleLoader>>,
+    property_watcher: Option<Rc<dyn PropertyWatcher>>,
+    symbol_registry: Option<SymbolRegistry>,
     can_block: bool,
+    deny_dynamic_scopes: bool,
+    disable_species_lookups: bool,
+    class_fields_use_set: bool,
+    error_positions: bool,
+    error_stack_traces: bool,
+    deterministic_math: bool,
+    gc_tuning: Option<GcTuning>,
+    #[cfg(feature = "intl")]
+    string_grapheme_methods: bool,
     #[cfg(feature = "intl")]
     icu: Option<icu::IntlProvider>,
     #[cfg(feature = "fuzz")]
@@ -934,6 +1332,8 @@ impl std::fmt::Debug for ContextBuilder {
         struct Clock;
         #[derive(Clone, Copy, Debug)]
         struct ModuleLoader;
+        #[derive(Clone, Copy, Debug)]
+        struct PropertyWatcher;
 
         let mut out = f.debug_struct("ContextBuilder");
 
@@ -948,10 +1348,23 @@ impl std::fmt::Debug for ContextBuilder {
                 "module_loader",
                 &self.module_loader.as_ref().map(|_| ModuleLoader),
             )
-            .field("can_block", &self.can_block);
+            .field(
+                "property_watcher",
+                &self.property_watcher.as_ref().map(|_| PropertyWatcher),
+            )
+            .field("symbol_registry", &self.symbol_registry)
+            .field("can_block", &self.can_block)
+            .field("deny_dynamic_scopes", &self.deny_dynamic_scopes)
+            .field("disable_species_lookups", &self.disable_species_lookups)
+            .field("class_fields_use_set", &self.class_fields_use_set)
+            .field("error_positions", &self.error_positions)
+            .field("error_stack_traces", &self.error_stack_traces)
+            .field("deterministic_math", &self.deterministic_math)
+            .field("gc_tuning", &self.gc_tuning);
 
         #[cfg(feature = "intl")]
-        out.field("icu", &self.icu);
+        out.field("string_grapheme_methods", &self.string_grapheme_methods)
+            .field("icu", &self.icu);
 
         #[cfg(feature = "fuzz")]
         out.field("instructions_remaining", &self.instructions_remaining);
@@ -1043,6 +1456,26 @@ impl ContextBuilder {
         self
     }
 
+    /// Initializes the [`PropertyWatcher`] for the context.
+    ///
+    /// See [`JsObject::watch`] for more information.
+    #[must_use]
+    pub fn property_watcher<W: PropertyWatcher + 'static>(mut self, watcher: Rc<W>) -> Self {
+        self.property_watcher = Some(watcher);
+        self
+    }
+
+    /// Initializes the [`SymbolRegistry`] for the context.
+    ///
+    /// By default, every context gets its own registry. Contexts sharing a registry, like the
+    /// process-wide [`SymbolRegistry::global`], get the same symbol from `Symbol.for` for the
+    /// same key, and share their host-defined well-known symbols.
+    #[must_use]
+    pub fn symbol_registry(mut self, registry: SymbolRegistry) -> Self {
+        self.symbol_registry = Some(registry);
+        self
+    }
+
     /// [`AgentCanSuspend ( )`][spec] aka `[[CanBlock]]`
     ///
     /// Defines if this context can be suspended by calls to the [`Atomics.wait`][wait] function.
@@ -1061,6 +1494,122 @@ impl ContextBuilder {
         self
     }
 
+    /// Disables `with` statements and direct calls to `eval`.
+    ///
+    /// Both can add bindings to, or remove bindings from, the scopes of the running code at
+    /// runtime. Hosts that run untrusted code can use this to reject them altogether: executing
+    /// a `with` statement throws a `TypeError`, and a direct call to `eval` throws an
+    /// `EvalError`. Indirect calls to `eval` can be restricted with
+    /// [`HostHooks::ensure_can_compile_strings`].
+    #[must_use]
+    pub const fn deny_dynamic_scopes(mut self, deny: bool) -> Self {
+        self.deny_dynamic_scopes = deny;
+        self
+    }
+
+    /// Disables the lookups of `Symbol.species` in the built-ins.
+    ///
+    /// Methods like `Array.prototype.map`, `Promise.prototype.then` or
+    /// `TypedArray.prototype.slice` create their result with the constructor found at
+    /// `this.constructor[Symbol.species]`, which lets subclasses return instances of themselves.
+    /// This runs user code in the middle of the built-ins, which is a common source of bugs
+    /// and exploits, and makes these methods slower. When disabled, the built-ins don't read the
+    /// `constructor` property at all, and always use the intrinsic constructor of the realm, as if
+    /// `Symbol.species` was undefined. `Symbol.species` itself and the species getters of the
+    /// constructors are still defined.
+    #[must_use]
+    pub const fn disable_species_lookups(mut self, disable: bool) -> Self {
+        self.disable_species_lookups = disable;
+        self
+    }
+
+    /// Assigns public class fields with `Set` instead of defining them on the object.
+    ///
+    /// By the specification, public fields are defined with `CreateDataPropertyOrThrow`, so they
+    /// shadow accessors inherited from a base class, and throw on non-extensible objects. Some
+    /// transpiled code expects the legacy behaviour where a field initializer is a plain
+    /// assignment, which calls inherited setters instead. This only affects public fields;
+    /// private fields are always defined.
+    #[must_use]
+    pub const fn class_fields_use_set(mut self, use_set: bool) -> Self {
+        self.class_fields_use_set = use_set;
+        self
+    }
+
+    /// Defines the non-standard `lineNumber` and `columnNumber` properties on error objects.
+    ///
+    /// Some engines expose the position where an error was created as own properties of the
+    /// error object, and scripts written for them may rely on it. When enabled, errors created
+    /// with an error constructor get the position of the constructor call, and errors thrown by
+    /// the engine get the position of the expression that threw them. Errors created in a script
+    /// or module that has a path also get its path as their `fileName` property. The position of
+    /// an error is always available to the host through [`JsError::position`].
+    ///
+    /// [`JsError::position`]: crate::JsError::position
+    #[must_use]
+    pub const fn error_positions(mut self, error_positions: bool) -> Self {
+        self.error_positions = error_positions;
+        self
+    }
+
+    /// Defines the non-standard `stack` property on error objects.
+    ///
+    /// The `stack` property of an error is a string with the name and message of the error,
+    /// followed by a line for each function that was running when the error was created. Calls
+    /// to async functions are followed across `await`s: the functions that called an async
+    /// function before it was first suspended are listed after it, prefixed with `async`, even
+    /// if the error is created after the function was resumed from a promise job. At most 10
+    /// functions are listed, and the string is only built when `stack` is first read.
+    ///
+    /// This is disabled by default, since it requires capturing the stack on every error and on
+    /// the first `await` of every async function call.
+    #[must_use]
+    pub const fn error_stack_traces(mut self, error_stack_traces: bool) -> Self {
+        self.error_stack_traces = error_stack_traces;
+        self
+    }
+
+    /// Computes the `Math` functions and the `**` operator with software implementations that
+    /// give the same results on all platforms.
+    ///
+    /// The specification lets implementations approximate transcendental functions like
+    /// `Math.sin` or `Math.pow`, and by default they use the functions of the platform's math
+    /// library, whose results can differ in the last bits between operating systems and
+    /// architectures. When enabled, these functions are computed with a port of the musl math
+    /// library instead, which is slower but gives identical results everywhere. This is useful
+    /// when the results of a script must be reproduced exactly on another machine, e.g. to replay
+    /// a recorded session or to run a simulation in lockstep.
+    #[must_use]
+    pub const fn deterministic_math(mut self, deterministic_math: bool) -> Self {
+        self.deterministic_math = deterministic_math;
+        self
+    }
+
+    /// Sets the parameters of the garbage collector, like its incremental marking.
+    ///
+    /// The garbage collector is shared by all the contexts of a thread, so this also applies to
+    /// the other contexts of the current thread. See [`GcTuning`] for the available parameters.
+    #[must_use]
+    pub const fn gc_tuning(mut self, gc_tuning: GcTuning) -> Self {
+        self.gc_tuning = Some(gc_tuning);
+        self
+    }
+
+    /// Defines the non-standard `graphemeAt` and `graphemeLength` methods of `String.prototype`.
+    ///
+    /// These methods work with the grapheme clusters of a string, the characters as perceived by
+    /// the user, instead of its UTF-16 code units. `"👍🏽".graphemeLength()` is `1`, while its
+    /// `length` is `4`. They use the same segmentation data as `Intl.Segmenter`, and are meant for
+    /// embedders that need to handle user visible text, like text editors.
+    ///
+    /// This function is only available if the `intl` feature is enabled.
+    #[cfg(feature = "intl")]
+    #[must_use]
+    pub const fn string_grapheme_methods(mut self, string_grapheme_methods: bool) -> Self {
+        self.string_grapheme_methods = string_grapheme_methods;
+        self
+    }
+
     /// Specifies the number of instructions remaining to the [`Context`].
     ///
     /// This function is only available if the `fuzz` feature is enabled.
@@ -1089,6 +1638,10 @@ impl ContextBuilder {
             CANNOT_BLOCK_COUNTER.set(CANNOT_BLOCK_COUNTER.get() + 1);
         }
 
+        if let Some(gc_tuning) = self.gc_tuning {
+            boa_gc::set_tuning(gc_tuning);
+        }
+
         let root_shape = RootShape::default();
 
         let host_hooks = self.host_hooks.unwrap_or(Rc::new(DefaultHooks));
@@ -1136,10 +1689,23 @@ impl ContextBuilder {
             clock,
             job_executor,
             module_loader,
+            import_map: None,
+            property_watcher: self.property_watcher,
+            pure_functions: FxHashSet::default(),
+            symbol_registry: self.symbol_registry.unwrap_or_default(),
+            extensions: Vec::new(),
             optimizer_options: OptimizerOptions::OPTIMIZE_ALL,
             root_shape,
             parser_identifier: 0,
             can_block: self.can_block,
+            deny_dynamic_scopes: self.deny_dynamic_scopes,
+            disable_species_lookups: self.disable_species_lookups,
+            class_fields_use_set: self.class_fields_use_set,
+            error_positions: self.error_positions,
+            error_stack_traces: self.error_stack_traces,
+            deterministic_math: self.deterministic_math,
+            #[cfg(feature = "intl")]
+            string_grapheme_methods: self.string_grapheme_methods,
             data: HostDefined::default(),
         };
 
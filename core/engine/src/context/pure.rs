@@ -0,0 +1,131 @@
+//! The built-in functions that can be called in a pure evaluation.
+//!
+//! See [`Context::eval_pure`].
+
+use rustc_hash::FxHashSet;
+
+use crate::{property::PropertyKey, Context, JsObject, JsResult, JsValue};
+
+/// The functions of the `Array` constructor that schedule jobs.
+const ARRAY_ASYNC: &[&str] = &["fromAsync"];
+
+/// The methods of `Function.prototype` that call a function chosen by the caller, and the
+/// `Function` constructor, which compiles code.
+const FUNCTION_PROTOTYPE_EXCLUDED: &[&str] = &["apply", "call", "constructor"];
+
+/// The methods of `Array.prototype` that mutate the array they are called on.
+const ARRAY_PROTOTYPE_MUTATORS: &[&str] = &[
+    "copyWithin",
+    "fill",
+    "pop",
+    "push",
+    "reverse",
+    "shift",
+    "sort",
+    "splice",
+    "unshift",
+];
+
+/// The functions of the `Object` constructor that mutate their arguments.
+const OBJECT_MUTATORS: &[&str] = &[
+    "assign",
+    "defineProperties",
+    "defineProperty",
+    "freeze",
+    "preventExtensions",
+    "seal",
+    "setPrototypeOf",
+];
+
+/// The legacy accessor methods of `Object.prototype`, which define properties.
+const OBJECT_PROTOTYPE_MUTATORS: &[&str] = &["__defineGetter__", "__defineSetter__"];
+
+/// Returns the built-in functions of the current realm that don't have side effects.
+///
+/// These are the functions that only compute a value from their arguments and create new
+/// objects, e.g. the functions of `Math` except `Math.random`, or the methods of
+/// `String.prototype`. Functions that are not deterministic, mutate their arguments or the global
+/// state, or schedule jobs are left out, as well as the `next` methods of iterators, since they
+/// advance iterators that other code can share.
+///
+/// Some of the remaining functions, like `Array.from` or `Array.prototype.map`, create their
+/// result with a constructor chosen by the caller. They check the constructor with
+/// [`Context::check_pure_construct`], since it could return an object that already exists.
+pub(super) fn builtin_pure_functions(context: &mut Context) -> JsResult<FxHashSet<JsObject>> {
+    let realm = context.realm().clone();
+    let intrinsics = realm.intrinsics();
+    let constructors = intrinsics.constructors();
+    let objects = intrinsics.objects();
+
+    let mut functions: FxHashSet<JsObject> = [
+        objects.is_finite(),
+        objects.is_nan(),
+        objects.parse_float(),
+        objects.parse_int(),
+    ]
+    .into_iter()
+    .map(JsObject::from)
+    .collect();
+
+    let groups: [(JsObject, &[&str]); 16] = [
+        (objects.math(), &["random"]),
+        (objects.json(), &[]),
+        (constructors.object().constructor(), OBJECT_MUTATORS),
+        (constructors.object().prototype(), OBJECT_PROTOTYPE_MUTATORS),
+        (
+            constructors.function().prototype(),
+            FUNCTION_PROTOTYPE_EXCLUDED,
+        ),
+        (constructors.array().constructor(), ARRAY_ASYNC),
+        (constructors.array().prototype(), ARRAY_PROTOTYPE_MUTATORS),
+        (constructors.string().constructor(), &[]),
+        (constructors.string().prototype(), &[]),
+        (constructors.number().constructor(), &[]),
+        (constructors.number().prototype(), &[]),
+        (constructors.boolean().constructor(), &[]),
+        (constructors.boolean().prototype(), &[]),
+        (constructors.bigint().constructor(), &[]),
+        (constructors.bigint().prototype(), &[]),
+        (constructors.symbol().prototype(), &[]),
+    ];
+
+    for (object, excluded) in groups {
+        if object.is_callable() {
+            functions.insert(object.clone());
+        }
+        insert_own_functions(&mut functions, &object, excluded, context)?;
+    }
+
+    Ok(functions)
+}
+
+/// Inserts the functions and getters of the own properties of `object` into `functions`,
+/// excluding the functions of the properties named in `excluded`.
+///
+/// Setters are never inserted.
+fn insert_own_functions(
+    functions: &mut FxHashSet<JsObject>,
+    object: &JsObject,
+    excluded: &[&str],
+    context: &mut Context,
+) -> JsResult<()> {
+    for key in object.own_property_keys(context)? {
+        let Some(property) = object.__get_own_property__(&key, &mut context.into())? else {
+            continue;
+        };
+
+        if let Some(getter) = property.get().and_then(JsValue::as_object) {
+            functions.insert(getter.clone());
+        }
+
+        if matches!(&key, PropertyKey::String(name) if excluded.iter().any(|ex| name == ex)) {
+            continue;
+        }
+
+        if let Some(function) = property.value().and_then(JsValue::as_callable) {
+            functions.insert(function.clone());
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,66 @@
+//! Statistics of the memory used by a [`Context`].
+
+use crate::vm::CodeBlockCounter;
+
+use super::Context;
+
+/// A snapshot of counters of the data structures of a [`Context`], returned by
+/// [`Context::statistics`].
+///
+/// Long-running hosts can compare the statistics taken over time to monitor the memory used by
+/// the engine, and detect scripts that leak functions or objects with ever-changing shapes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ContextStatistics {
+    /// The number of strings in the interner of the context.
+    ///
+    /// The interner only grows, since the strings it holds are never freed.
+    pub interned_strings: usize,
+
+    /// The number of live shared object shapes of the context.
+    pub shared_shapes: usize,
+
+    /// The number of live compiled code blocks of the current thread.
+    ///
+    /// Each script, module and function has its own code block. Code blocks are counted
+    /// for all the contexts of the thread.
+    pub code_blocks: usize,
+
+    /// The total number of constants in the constant pools of the live code blocks of the
+    /// current thread.
+    pub constants: usize,
+}
+
+impl Context {
+    /// Takes a snapshot of the [`ContextStatistics`] of the context.
+    ///
+    /// The statistics count the data structures that haven't been garbage collected yet, so
+    /// calling [`boa_gc::force_collect`] beforehand gives more accurate results.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use boa_engine::{Context, Source};
+    ///
+    /// let context = &mut Context::default();
+    /// let before = context.statistics();
+    ///
+    /// context
+    ///     .eval(Source::from_bytes("function f() { return { a: 1, b: 2 }; } f()"))
+    ///     .unwrap();
+    ///
+    /// let after = context.statistics();
+    /// assert!(after.code_blocks > before.code_blocks);
+    /// assert!(after.shared_shapes >= before.shared_shapes);
+    /// ```
+    #[must_use]
+    pub fn statistics(&self) -> ContextStatistics {
+        let (code_blocks, constants) = CodeBlockCounter::live();
+        ContextStatistics {
+            interned_strings: self.interner.len(),
+            shared_shapes: self.root_shape.shape().transition_tree_size(),
+            code_blocks,
+            constants,
+        }
+    }
+}
@@ -1,5 +1,9 @@
 //! Clock related types and functions.
 
+use std::sync::OnceLock;
+
+use crate::sys::time::Instant;
+
 /// A monotonic instant in time, in the Boa engine.
 ///
 /// This type is guaranteed to be monotonic, i.e. if two instants
@@ -129,9 +133,23 @@ impl std::ops::Sub for JsInstant {
 }
 
 /// Implement a clock that can be used to measure time.
+///
+/// The clock of a context is used by `Date.now()`, `Temporal.Now` and the scheduling of timeout
+/// jobs, so hosts can virtualize the time seen by scripts with a custom clock, e.g. a
+/// [`FixedClock`] in tests.
 pub trait Clock {
-    /// Returns the current time.
+    /// Returns the current time of the wall clock.
     fn now(&self) -> JsInstant;
+
+    /// Returns the current time of a monotonic clock, which never goes backwards, even if the
+    /// wall clock is adjusted.
+    ///
+    /// This is used to measure elapsed time, e.g. to run timeout jobs when they are due. The
+    /// default implementation returns [`Clock::now`], which is enough for clocks that can only
+    /// be moved forward.
+    fn monotonic_now(&self) -> JsInstant {
+        self.now()
+    }
 }
 
 /// A clock that uses the standard system clock.
@@ -147,6 +165,14 @@ impl Clock for StdClock {
 
         JsInstant::new_unchecked(duration)
     }
+
+    fn monotonic_now(&self) -> JsInstant {
+        // The monotonic clock starts at the time of the wall clock when it is first used.
+        static ORIGIN: OnceLock<(Instant, JsInstant)> = OnceLock::new();
+
+        let (instant, origin) = *ORIGIN.get_or_init(|| (Instant::now(), self.now()));
+        origin + JsDuration::from(instant.elapsed())
+    }
 }
 
 /// A clock that uses a fixed time, useful for testing. The internal time is in milliseconds.
@@ -195,9 +221,13 @@ fn basic() {
     let diff = later - earlier;
     assert_eq!(diff.as_millis(), 2000);
 
+    let monotonic = StdClock.monotonic_now();
+    assert!(StdClock.monotonic_now() >= monotonic);
+
     let fixed = FixedClock::from_millis(0);
     let now2 = fixed.now();
     assert_eq!(now2.millis_since_epoch(), 0);
+    assert_eq!(fixed.monotonic_now(), now2);
     assert!(now2 < now);
 
     fixed.forward(1000);
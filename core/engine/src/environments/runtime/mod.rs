@@ -425,6 +425,42 @@ impl Context {
         Ok(())
     }
 
+    /// Checks that the binding at `slot` in the environment at `environment`, that the compiler
+    /// resolved statically, is the binding that a runtime name lookup would find.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the binding could be shadowed at runtime, which is a bug in the compiler.
+    #[cfg(debug_assertions)]
+    pub(crate) fn verify_static_binding(&mut self, environment: u32, slot: u32) {
+        let env = self.vm.environments.current_declarative_ref();
+        assert!(
+            env.is_some_and(|env| !env.with()),
+            "binding accessed by slot inside of a `with` statement, this is a compiler bug"
+        );
+        if !env.is_some_and(|env| env.poisoned()) {
+            return;
+        }
+
+        let mut locator = self
+            .vm
+            .frame()
+            .code_block()
+            .slot_binding(environment, slot)
+            .expect("binding accessed by slot must have a locator")
+            .clone();
+        // Name lookups only have side effects through object environments, which are ruled out
+        // above.
+        let _ = self.find_runtime_binding(&mut locator);
+        assert!(
+            matches!(locator.scope(), BindingLocatorScope::Stack(index) if index == environment)
+                && locator.binding_index() == slot,
+            "binding `{}` accessed by slot was resolved by name to a different binding, this is a \
+             compiler bug",
+            locator.name().to_std_string_escaped()
+        );
+    }
+
     /// Finds the object environment that contains the binding and returns the `this` value of the object environment.
     pub(crate) fn this_from_object_environment_binding(
         &mut self,
@@ -523,6 +559,13 @@ impl Context {
                 Environment::Object(obj) => {
                     let key = locator.name().clone();
                     let obj = obj.clone();
+
+                    // The property may have been removed after the binding was resolved, in which
+                    // case non-strict code gets `undefined`.
+                    if !obj.has_property(key.clone(), self)? {
+                        let strict = self.vm.frame().code_block.strict();
+                        return Ok((!strict).then(JsValue::undefined));
+                    }
                     obj.get(key, self).map(Some)
                 }
             },
@@ -6,10 +6,12 @@ use crate::{
     object::JsObject,
     property::PropertyDescriptor,
     realm::Realm,
+    vm::StackTrace,
     Context, JsString, JsValue,
 };
+use boa_ast::Position;
 use boa_gc::{custom_trace, Finalize, Trace};
-use std::{borrow::Cow, error, fmt};
+use std::{borrow::Cow, error, fmt, io, path::Path, rc::Rc};
 use thiserror::Error;
 
 /// Create an error object from a value or string literal. Optionally the
@@ -187,12 +189,34 @@ macro_rules! js_error {
 /// let kind = &native_error.as_native().unwrap().kind;
 /// assert!(matches!(kind, JsNativeErrorKind::Type));
 /// ```
-#[derive(Debug, Clone, PartialEq, Eq, Trace, Finalize)]
+#[derive(Debug, Clone, Trace, Finalize)]
 #[boa_gc(unsafe_no_drop)]
 pub struct JsError {
     inner: Repr,
+
+    /// Position in the source code where the error was thrown.
+    #[unsafe_ignore_trace]
+    position: Option<Position>,
+
+    /// Path of the script or module where the error was thrown.
+    #[unsafe_ignore_trace]
+    path: Option<Rc<Path>>,
+
+    /// Stack trace of the code that threw the error, for native errors thrown by the engine.
+    // SAFETY: Nothing in `StackTrace` requires tracing, so this is safe.
+    #[unsafe_ignore_trace]
+    stack_trace: Option<StackTrace>,
+}
+
+// The position, path and stack trace are not compared, since it is not part of the thrown value.
+impl PartialEq for JsError {
+    fn eq(&self, other: &Self) -> bool {
+        self.inner == other.inner
+    }
 }
 
+impl Eq for JsError {}
+
 /// Internal representation of a [`JsError`].
 ///
 /// `JsError` is represented by an opaque enum because it restricts
@@ -281,6 +305,9 @@ impl JsError {
     pub const fn from_native(err: JsNativeError) -> Self {
         Self {
             inner: Repr::Native(err),
+            position: None,
+            path: None,
+            stack_trace: None,
         }
     }
 
@@ -321,6 +348,9 @@ impl JsError {
     pub const fn from_opaque(value: JsValue) -> Self {
         Self {
             inner: Repr::Opaque(value),
+            position: None,
+            path: None,
+            stack_trace: None,
         }
     }
 
@@ -341,7 +371,12 @@ impl JsError {
     /// ```
     pub fn to_opaque(&self, context: &mut Context) -> JsValue {
         match &self.inner {
-            Repr::Native(e) => e.to_opaque(context).into(),
+            Repr::Native(e) => {
+                let o = e.to_opaque(context);
+                Error::install_error_position(&o, self.position, self.path.clone(), context);
+                Error::install_error_stack(&o, self.stack_trace.as_ref(), context);
+                o.into()
+            }
             Repr::Opaque(v) => v.clone(),
         }
     }
@@ -616,6 +651,170 @@ impl JsError {
         self
     }
 
+    /// Classifies this error by the kind of value that was thrown.
+    ///
+    /// Native errors and error objects are classified by their error type, which lets callers
+    /// branch on the type of an error without inspecting its message. Instances of classes
+    /// extending a built-in error are classified as the built-in error they extend.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use boa_engine::{Context, JsErrorKind, Source};
+    /// let mut context = Context::default();
+    ///
+    /// let error = context
+    ///     .eval(Source::from_bytes("new Array(-1)"))
+    ///     .unwrap_err();
+    /// assert_eq!(error.kind(), JsErrorKind::Range);
+    ///
+    /// let error = context
+    ///     .eval(Source::from_bytes("throw { code: 42 }"))
+    ///     .unwrap_err();
+    /// assert!(matches!(error.kind(), JsErrorKind::Custom(_)));
+    /// ```
+    #[must_use]
+    pub fn kind(&self) -> JsErrorKind {
+        match &self.inner {
+            Repr::Native(e) => match e.kind {
+                JsNativeErrorKind::Aggregate(_) => JsErrorKind::Aggregate,
+                JsNativeErrorKind::Error => JsErrorKind::Error,
+                JsNativeErrorKind::Eval => JsErrorKind::Eval,
+                JsNativeErrorKind::Range => JsErrorKind::Range,
+                JsNativeErrorKind::Reference => JsErrorKind::Reference,
+                JsNativeErrorKind::Syntax => JsErrorKind::Syntax,
+                JsNativeErrorKind::Type => JsErrorKind::Type,
+                JsNativeErrorKind::Uri => JsErrorKind::Uri,
+                JsNativeErrorKind::RuntimeLimit => JsErrorKind::RuntimeLimit,
+                #[cfg(feature = "fuzz")]
+                JsNativeErrorKind::NoInstructionsRemain => JsErrorKind::RuntimeLimit,
+            },
+            Repr::Opaque(value) => {
+                let Some(object) = value.as_object() else {
+                    return JsErrorKind::Value(value.clone());
+                };
+                let Some(error) = object.downcast_ref::<Error>().map(|error| *error) else {
+                    return JsErrorKind::Custom(object.clone());
+                };
+                match error {
+                    Error::Aggregate => JsErrorKind::Aggregate,
+                    Error::Error => JsErrorKind::Error,
+                    Error::Eval => JsErrorKind::Eval,
+                    Error::Type => JsErrorKind::Type,
+                    Error::Range => JsErrorKind::Range,
+                    Error::Reference => JsErrorKind::Reference,
+                    Error::Syntax => JsErrorKind::Syntax,
+                    Error::Uri => JsErrorKind::Uri,
+                }
+            }
+        }
+    }
+
+    /// Converts this error into a [`std::io::Error`], with an [`io::ErrorKind`] matching the
+    /// [`kind`][JsError::kind] of the error.
+    ///
+    /// This is useful to report script errors from APIs that return I/O errors. The error is
+    /// [erased][JsError::into_erased] first, so it is lossy in the same way.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use boa_engine::{Context, JsNativeError, JsError};
+    /// # use std::io;
+    /// let context = &mut Context::default();
+    /// let error: JsError = JsNativeError::syntax().with_message("unexpected token").into();
+    ///
+    /// let io_error = error.into_io_error(context);
+    /// assert_eq!(io_error.kind(), io::ErrorKind::InvalidData);
+    /// assert_eq!(io_error.to_string(), "SyntaxError: unexpected token");
+    /// ```
+    #[must_use]
+    pub fn into_io_error(self, context: &mut Context) -> io::Error {
+        let kind = self.kind().io_error_kind();
+        io::Error::new(kind, self.into_erased(context))
+    }
+
+    /// Gets the position in the source code where this error was thrown, if it was thrown while
+    /// executing JavaScript code.
+    ///
+    /// For errors thrown by a `throw` statement this is the position of the thrown expression,
+    /// and for errors thrown by the engine this is the position of the expression that was being
+    /// evaluated, like the callee of a call that is not a function. For syntax errors returned
+    /// when parsing a [`Script`] or [`Module`], this is the position of the invalid code.
+    ///
+    /// [`Script`]: crate::Script
+    /// [`Module`]: crate::Module
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use boa_engine::{Context, Source};
+    /// let mut context = Context::default();
+    /// let error = context
+    ///     .eval(Source::from_bytes("let a = 1;\nnull.foo;"))
+    ///     .unwrap_err();
+    ///
+    /// let position = error.position().unwrap();
+    /// assert_eq!(position.line_number(), 2);
+    /// assert_eq!(position.column_number(), 1);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn position(&self) -> Option<Position> {
+        self.position
+    }
+
+    /// Gets the path of the script or module where this error was thrown, if it was thrown while
+    /// executing JavaScript code from a file.
+    ///
+    /// For syntax errors returned when parsing a [`Script`] or [`Module`], this is the path of the
+    /// parsed source, which can be a module imported by the code being run.
+    ///
+    /// [`Script`]: crate::Script
+    /// [`Module`]: crate::Module
+    #[inline]
+    #[must_use]
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    /// Sets the position and the path of the source where the error was thrown.
+    ///
+    /// This is a no-op if the position of the error is already set, since errors keep the
+    /// position where they were first thrown while propagating.
+    pub(crate) fn inject_position(
+        mut self,
+        position: Option<Position>,
+        path: Option<Rc<Path>>,
+    ) -> Self {
+        if self.position.is_none() {
+            self.position = position;
+            self.path = path;
+        }
+        self
+    }
+
+    /// Creates a syntax error from an error returned when parsing the source at `path`, keeping
+    /// the position of the error.
+    pub(crate) fn from_parse_error(err: boa_parser::Error, path: Option<Rc<Path>>) -> Self {
+        let position = err.position();
+        Self::from(err).inject_position(position, path)
+    }
+
+    /// Captures the stack trace of the code that threw a native error, if enabled with
+    /// [`ContextBuilder::error_stack_traces`].
+    ///
+    /// Like the position, the stack trace is kept from where the error was first thrown. Opaque
+    /// errors are not modified, since their values were created with their own stack trace.
+    ///
+    /// [`ContextBuilder::error_stack_traces`]: crate::context::ContextBuilder::error_stack_traces
+    pub(crate) fn inject_stack_trace(mut self, context: &Context) -> Self {
+        if self.stack_trace.is_none() && matches!(self.inner, Repr::Native(_)) {
+            self.stack_trace = context.capture_stack_trace();
+        }
+        self
+    }
+
     /// Is the [`JsError`] catchable in JavaScript.
     #[inline]
     pub(crate) fn is_catchable(&self) -> bool {
@@ -633,6 +832,9 @@ impl From<JsNativeError> for JsError {
     fn from(error: JsNativeError) -> Self {
         Self {
             inner: Repr::Native(error),
+            position: None,
+            path: None,
+            stack_trace: None,
         }
     }
 }
@@ -646,6 +848,65 @@ impl fmt::Display for JsError {
     }
 }
 
+/// The classification of a [`JsError`], as returned by [`JsError::kind`].
+///
+/// Unlike [`JsNativeErrorKind`], this can also represent errors that are arbitrary thrown values,
+/// and can be obtained without a [`Context`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum JsErrorKind {
+    /// An `AggregateError`.
+    Aggregate,
+    /// An `Error`, or an instance of a class extending it.
+    Error,
+    /// An `EvalError`.
+    Eval,
+    /// A `RangeError`.
+    Range,
+    /// A `ReferenceError`.
+    Reference,
+    /// A `SyntaxError`.
+    Syntax,
+    /// A `TypeError`.
+    Type,
+    /// A `URIError`.
+    Uri,
+    /// A runtime limit was exceeded. This error cannot be caught by scripts.
+    RuntimeLimit,
+    /// A thrown object that is not an error object.
+    Custom(JsObject),
+    /// A thrown primitive value.
+    Value(JsValue),
+}
+
+impl JsErrorKind {
+    /// Returns `true` if the error is one of the built-in error types.
+    #[must_use]
+    pub const fn is_builtin_error(&self) -> bool {
+        !matches!(self, Self::RuntimeLimit | Self::Custom(_) | Self::Value(_))
+    }
+
+    /// Gets the [`io::ErrorKind`] that best describes this kind of error.
+    ///
+    /// Syntax errors are mapped to [`io::ErrorKind::InvalidData`], type, range and URI errors to
+    /// [`io::ErrorKind::InvalidInput`], reference errors to [`io::ErrorKind::NotFound`], and
+    /// any other error to [`io::ErrorKind::Other`].
+    #[must_use]
+    pub const fn io_error_kind(&self) -> io::ErrorKind {
+        match self {
+            Self::Syntax => io::ErrorKind::InvalidData,
+            Self::Type | Self::Range | Self::Uri => io::ErrorKind::InvalidInput,
+            Self::Reference => io::ErrorKind::NotFound,
+            Self::Aggregate
+            | Self::Error
+            | Self::Eval
+            | Self::RuntimeLimit
+            | Self::Custom(_)
+            | Self::Value(_) => io::ErrorKind::Other,
+        }
+    }
+}
+
 /// Native representation of an ideal `Error` object from Javascript.
 ///
 /// This representation is more space efficient than its [`JsObject`] equivalent,
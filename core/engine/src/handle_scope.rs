@@ -0,0 +1,259 @@
+//! Scopes of rooted handles, used by native code that creates many temporary values.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::JsValue;
+
+/// The identifier of the next [`HandleScope`].
+static NEXT_SCOPE_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// A scope that keeps temporary values alive, and releases them all at once.
+///
+/// Native functions that create many temporary values usually have to keep a clone of every
+/// one of them around while they call back into the [`Context`][crate::Context], which is easy
+/// to get wrong when the values are moved between collections or closures. A `HandleScope`
+/// owns these values instead, and hands out [`Handle`]s, which are small `Copy` indices that
+/// don't borrow the scope or the context.
+///
+/// All the values of a scope are released when it is dropped, and the values rooted after a
+/// [`Checkpoint`] can be released early with [`HandleScope::release`], which makes loops that
+/// allocate a few values per iteration keep a constant number of roots. Using a handle after
+/// its value was released panics, even if another value was rooted in its place.
+///
+/// Each value of the scope is rooted like any other value held by native code, so the scope
+/// doesn't reduce the work of the garbage collector to find the roots, which depends on the
+/// number of allocations rather than on the number of roots.
+///
+/// # Examples
+///
+/// ```
+/// # use boa_engine::{Context, HandleScope, JsObject};
+/// let context = &mut Context::default();
+/// let mut scope = HandleScope::new();
+///
+/// let first = scope.root(JsObject::with_object_proto(context.intrinsics()));
+/// for _ in 0..100 {
+///     let checkpoint = scope.checkpoint();
+///     let temporary = scope.root(JsObject::with_object_proto(context.intrinsics()));
+///     assert!(scope.get(temporary).is_object());
+///     scope.release(checkpoint);
+/// }
+///
+/// assert_eq!(scope.len(), 1);
+/// assert!(scope.get(first).is_object());
+/// ```
+#[derive(Debug)]
+pub struct HandleScope {
+    id: usize,
+    values: Vec<JsValue>,
+    /// The generation of each value, which is the number of values rooted before it.
+    generations: Vec<u64>,
+    next_generation: u64,
+}
+
+/// A handle to a value rooted in a [`HandleScope`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Handle {
+    scope: usize,
+    index: usize,
+    generation: u64,
+}
+
+/// The number of values of a [`HandleScope`] at some point, to release the values rooted after
+/// it with [`HandleScope::release`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Checkpoint {
+    scope: usize,
+    len: usize,
+}
+
+impl Default for HandleScope {
+    fn default() -> Self {
+        Self::with_capacity(0)
+    }
+}
+
+impl HandleScope {
+    /// Creates a new, empty [`HandleScope`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new, empty [`HandleScope`] with room for `capacity` values.
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            id: NEXT_SCOPE_ID.fetch_add(1, Ordering::Relaxed),
+            values: Vec::with_capacity(capacity),
+            generations: Vec::with_capacity(capacity),
+            next_generation: 0,
+        }
+    }
+
+    /// Roots `value` in the scope, keeping it alive until it is released.
+    pub fn root<V: Into<JsValue>>(&mut self, value: V) -> Handle {
+        let handle = Handle {
+            scope: self.id,
+            index: self.values.len(),
+            generation: self.next_generation,
+        };
+        self.next_generation += 1;
+        self.values.push(value.into());
+        self.generations.push(handle.generation);
+        handle
+    }
+
+    /// Gets the value of `handle`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `handle` was released, or if it comes from another scope.
+    #[must_use]
+    #[track_caller]
+    pub fn get(&self, handle: Handle) -> &JsValue {
+        assert_eq!(
+            handle.scope, self.id,
+            "the handle must be used with the scope that created it"
+        );
+        match self.generations.get(handle.index) {
+            Some(&generation) if generation == handle.generation => &self.values[handle.index],
+            _ => panic!("the handle must not be used after it was released"),
+        }
+    }
+
+    /// Returns the number of values rooted in the scope.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns `true` if there are no values rooted in the scope.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Returns a checkpoint of the current values of the scope.
+    #[must_use]
+    pub fn checkpoint(&self) -> Checkpoint {
+        Checkpoint {
+            scope: self.id,
+            len: self.values.len(),
+        }
+    }
+
+    /// Releases all the values rooted after `checkpoint`.
+    ///
+    /// The handles to these values must not be used afterwards.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `checkpoint` comes from another scope.
+    #[track_caller]
+    pub fn release(&mut self, checkpoint: Checkpoint) {
+        assert_eq!(
+            checkpoint.scope, self.id,
+            "the checkpoint must be used with the scope that created it"
+        );
+        self.values.truncate(checkpoint.len);
+        self.generations.truncate(checkpoint.len);
+    }
+
+    /// Releases all the values rooted after `checkpoint`, except the value of `handle`, which is
+    /// rooted again and returned with a new handle.
+    ///
+    /// This is useful to keep the result of a computation that created many temporary values.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `handle` was released, or if `handle` or `checkpoint` come from another scope.
+    #[track_caller]
+    pub fn release_except(&mut self, checkpoint: Checkpoint, handle: Handle) -> Handle {
+        let value = self.get(handle).clone();
+        self.release(checkpoint);
+        self.root(value)
+    }
+
+    /// Releases all the values of the scope.
+    pub fn clear(&mut self) {
+        self.values.clear();
+        self.generations.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use boa_gc::WeakGc;
+
+    use super::HandleScope;
+    use crate::{Context, JsObject};
+
+    #[test]
+    fn rooted_values_survive_collections() {
+        let context = &mut Context::default();
+        let mut scope = HandleScope::new();
+
+        let object = JsObject::with_object_proto(context.intrinsics());
+        let weak = WeakGc::new(object.inner());
+        let handle = scope.root(object);
+
+        boa_gc::force_collect();
+        assert!(weak.upgrade().is_some());
+        assert!(scope.get(handle).is_object());
+
+        drop(scope);
+        boa_gc::force_collect();
+        assert!(weak.upgrade().is_none());
+    }
+
+    #[test]
+    fn release_drops_values_after_checkpoint() {
+        let context = &mut Context::default();
+        let mut scope = HandleScope::new();
+
+        let kept = scope.root(JsObject::with_object_proto(context.intrinsics()));
+        let checkpoint = scope.checkpoint();
+
+        let temporary = JsObject::with_object_proto(context.intrinsics());
+        let temporary_weak = WeakGc::new(temporary.inner());
+        scope.root(temporary);
+        let result = JsObject::with_object_proto(context.intrinsics());
+        let result_weak = WeakGc::new(result.inner());
+        let result = scope.root(result);
+
+        let result = scope.release_except(checkpoint, result);
+        boa_gc::force_collect();
+
+        assert_eq!(scope.len(), 2);
+        assert!(scope.get(kept).is_object());
+        assert!(scope.get(result).is_object());
+        assert!(temporary_weak.upgrade().is_none());
+        assert!(result_weak.upgrade().is_some());
+    }
+
+    #[test]
+    #[should_panic(expected = "the handle must not be used after it was released")]
+    fn released_handle_panics_after_slot_reuse() {
+        let mut scope = HandleScope::new();
+
+        let checkpoint = scope.checkpoint();
+        let released = scope.root(1);
+        scope.release(checkpoint);
+        scope.root(2);
+
+        let _ = scope.get(released);
+    }
+
+    #[test]
+    #[should_panic(expected = "the handle must be used with the scope that created it")]
+    fn handle_from_another_scope_panics() {
+        let mut scope = HandleScope::new();
+        let mut other = HandleScope::new();
+
+        scope.root(1);
+        let handle = other.root(2);
+
+        let _ = scope.get(handle);
+    }
+}
@@ -8,6 +8,16 @@ use std::ops::Deref;
 /// convert arguments from JS to Rust types. It allows support
 /// for optional arguments or rest arguments.
 pub trait TryFromJsArgument<'a>: Sized {
+    /// The number of JS arguments this Rust argument stands for, used to compute the `length`
+    /// of typed functions. Arguments captured from the `this` value or from the context, and
+    /// rest arguments, don't count.
+    const LENGTH: usize = 0;
+
+    /// Whether this argument can be omitted. Arguments from the first optional one onwards
+    /// don't count towards the `length` of typed functions, like parameters with a default
+    /// value in JS.
+    const OPTIONAL: bool = false;
+
     /// Try to convert a JS argument into a Rust value, returning the
     /// value and the rest of the arguments to be parsed.
     ///
@@ -21,6 +31,9 @@ pub trait TryFromJsArgument<'a>: Sized {
 }
 
 impl<'a, T: TryFromJs> TryFromJsArgument<'a> for T {
+    const LENGTH: usize = 1;
+    const OPTIONAL: bool = T::OPTIONAL;
+
     fn try_from_js_argument(
         _: &'a JsValue,
         rest: &'a [JsValue],
@@ -39,6 +52,8 @@ impl<'a, T: TryFromJs> TryFromJsArgument<'a> for T {
 pub struct Ignore;
 
 impl<'a> TryFromJsArgument<'a> for Ignore {
+    const LENGTH: usize = 1;
+
     fn try_from_js_argument(
         _this: &'a JsValue,
         rest: &'a [JsValue],
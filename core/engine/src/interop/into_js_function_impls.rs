@@ -3,7 +3,7 @@
 use super::private::IntoJsFunctionSealed;
 use super::{IntoJsFunctionCopied, UnsafeIntoJsFunction};
 use crate::interop::{JsRest, TryFromJsArgument};
-use crate::{js_string, Context, JsError, NativeFunction, TryIntoJsResult};
+use crate::{js_string, Context, JsError, JsResult, JsValue, NativeFunction, TryIntoJsResult};
 use std::cell::RefCell;
 
 /// A token to represent the context argument in the function signature.
@@ -11,6 +11,42 @@ use std::cell::RefCell;
 #[derive(Debug, Copy, Clone)]
 pub struct ContextArgToken;
 
+/// Converts the next argument of a typed function, adding the position of the argument
+/// to the message of conversion errors.
+fn next_argument<'a, T: TryFromJsArgument<'a>>(
+    this: &'a JsValue,
+    args: &'a [JsValue],
+    rest: &'a [JsValue],
+    context: &mut Context,
+) -> JsResult<(T, &'a [JsValue])> {
+    T::try_from_js_argument(this, rest, context).map_err(|err| {
+        let Some(native) = err.as_native().filter(|_| T::LENGTH > 0) else {
+            return err;
+        };
+        let position = args.len() - rest.len() + 1;
+        native
+            .clone()
+            .with_message(format!("argument {position}: {}", native.message()))
+            .into()
+    })
+}
+
+/// Computes the `length` of a typed function from the [`TryFromJsArgument::LENGTH`] and
+/// [`TryFromJsArgument::OPTIONAL`] of its arguments, stopping at the first optional argument.
+const fn arguments_length(arguments: &[(usize, bool)]) -> usize {
+    let mut length = 0;
+    let mut i = 0;
+    while i < arguments.len() {
+        let (argument_length, optional) = arguments[i];
+        if optional {
+            break;
+        }
+        length += argument_length;
+        i += 1;
+    }
+    length
+}
+
 macro_rules! impl_into_js_function {
     ($($id: ident: $t: ident),*) => {
         impl<$($t,)* R, T> IntoJsFunctionSealed<($($t,)*), R> for T
@@ -54,7 +90,7 @@ macro_rules! impl_into_js_function {
                     NativeFunction::from_closure(move |this, args, ctx| {
                         let rest = args;
                         $(
-                            let ($id, rest) = $t::try_from_js_argument(this, rest, ctx)?;
+                            let ($id, rest) = next_argument::<$t>(this, args, rest, ctx)?;
                         )*
                         match s.try_borrow_mut() {
                             Ok(mut r) => r( $($id,)* ).try_into_js_result(ctx),
@@ -80,7 +116,7 @@ macro_rules! impl_into_js_function {
                     NativeFunction::from_closure(move |this, args, ctx| {
                         let rest = args;
                         $(
-                            let ($id, rest) = $t::try_from_js_argument(this, rest, ctx)?;
+                            let ($id, rest) = next_argument::<$t>(this, args, rest, ctx)?;
                         )*
                         match s.try_borrow_mut() {
                             Ok(mut r) => r( $($id,)* rest.into() ).try_into_js_result(ctx),
@@ -106,7 +142,7 @@ macro_rules! impl_into_js_function {
                     NativeFunction::from_closure(move |this, args, ctx| {
                         let rest = args;
                         $(
-                            let ($id, rest) = $t::try_from_js_argument(this, rest, ctx)?;
+                            let ($id, rest) = next_argument::<$t>(this, args, rest, ctx)?;
                         )*
                         let r = s.borrow_mut()( $($id,)* ctx);
                         r.try_into_js_result(ctx)
@@ -128,7 +164,7 @@ macro_rules! impl_into_js_function {
                     NativeFunction::from_closure(move |this, args, ctx| {
                         let rest = args;
                         $(
-                            let ($id, rest) = $t::try_from_js_argument(this, rest, ctx)?;
+                            let ($id, rest) = next_argument::<$t>(this, args, rest, ctx)?;
                         )*
                         let r = s.borrow_mut()( $($id,)* rest.into(), ctx);
                         r.try_into_js_result(ctx)
@@ -144,13 +180,18 @@ macro_rules! impl_into_js_function {
             R: TryIntoJsResult,
             T: Fn($($t,)*) -> R + 'static + Copy,
         {
+            const LENGTH: usize = arguments_length(&[$((
+                <$t as TryFromJsArgument<'static>>::LENGTH,
+                <$t as TryFromJsArgument<'static>>::OPTIONAL,
+            )),*]);
+
             #[allow(unused_variables)]
-            fn into_js_function_copied(self, _context: &mut Context) -> NativeFunction {
+            fn into_native_function(self) -> NativeFunction {
                 let s = self;
                 NativeFunction::from_copy_closure(move |this, args, ctx| {
                     let rest = args;
                     $(
-                        let ($id, rest) = $t::try_from_js_argument(this, rest, ctx)?;
+                        let ($id, rest) = next_argument::<$t>(this, args, rest, ctx)?;
                     )*
                     let r = s( $($id,)* );
                     r.try_into_js_result(ctx)
@@ -164,13 +205,18 @@ macro_rules! impl_into_js_function {
             R: TryIntoJsResult,
             T: Fn($($t,)* JsRest<'_>) -> R + 'static + Copy,
         {
+            const LENGTH: usize = arguments_length(&[$((
+                <$t as TryFromJsArgument<'static>>::LENGTH,
+                <$t as TryFromJsArgument<'static>>::OPTIONAL,
+            )),*]);
+
             #[allow(unused_variables)]
-            fn into_js_function_copied(self, _context: &mut Context) -> NativeFunction {
+            fn into_native_function(self) -> NativeFunction {
                 let s = self;
                 NativeFunction::from_copy_closure(move |this, args, ctx| {
                     let rest = args;
                     $(
-                        let ($id, rest) = $t::try_from_js_argument(this, rest, ctx)?;
+                        let ($id, rest) = next_argument::<$t>(this, args, rest, ctx)?;
                     )*
                     let r = s( $($id,)* rest.into() );
                     r.try_into_js_result(ctx)
@@ -184,13 +230,18 @@ macro_rules! impl_into_js_function {
             R: TryIntoJsResult,
             T: Fn($($t,)* &mut Context) -> R + 'static + Copy,
         {
+            const LENGTH: usize = arguments_length(&[$((
+                <$t as TryFromJsArgument<'static>>::LENGTH,
+                <$t as TryFromJsArgument<'static>>::OPTIONAL,
+            )),*]);
+
             #[allow(unused_variables)]
-            fn into_js_function_copied(self, _context: &mut Context) -> NativeFunction {
+            fn into_native_function(self) -> NativeFunction {
                 let s = self;
                 NativeFunction::from_copy_closure(move |this, args, ctx| {
                     let rest = args;
                     $(
-                        let ($id, rest) = $t::try_from_js_argument(this, rest, ctx)?;
+                        let ($id, rest) = next_argument::<$t>(this, args, rest, ctx)?;
                     )*
                     let r = s( $($id,)* ctx);
                     r.try_into_js_result(ctx)
@@ -204,13 +255,18 @@ macro_rules! impl_into_js_function {
             R: TryIntoJsResult,
             T: Fn($($t,)* JsRest<'_>, &mut Context) -> R + 'static + Copy,
         {
+            const LENGTH: usize = arguments_length(&[$((
+                <$t as TryFromJsArgument<'static>>::LENGTH,
+                <$t as TryFromJsArgument<'static>>::OPTIONAL,
+            )),*]);
+
             #[allow(unused_variables)]
-            fn into_js_function_copied(self, _context: &mut Context) -> NativeFunction {
+            fn into_native_function(self) -> NativeFunction {
                 let s = self;
                 NativeFunction::from_copy_closure(move |this, args, ctx| {
                     let rest = args;
                     $(
-                        let ($id, rest) = $t::try_from_js_argument(this, rest, ctx)?;
+                        let ($id, rest) = next_argument::<$t>(this, args, rest, ctx)?;
                     )*
                     let r = s( $($id,)* rest.into(), ctx);
                     r.try_into_js_result(ctx)
@@ -81,8 +81,19 @@ pub trait UnsafeIntoJsFunction<Args, Ret>: private::IntoJsFunctionSealed<Args, R
 /// assert_eq!(result, JsValue::new(3));
 /// ```
 pub trait IntoJsFunctionCopied<Args, Ret>: private::IntoJsFunctionSealed<Args, Ret> + Copy {
+    /// The number of JS arguments expected by the function, which should be used as its
+    /// `length` property.
+    const LENGTH: usize;
+
+    /// Converts the type into a JS function.
+    fn into_native_function(self) -> NativeFunction;
+
     /// Converts the type into a JS function.
-    fn into_js_function_copied(self, context: &mut Context) -> NativeFunction;
+    ///
+    /// This is the same as [`IntoJsFunctionCopied::into_native_function`].
+    fn into_js_function_copied(self, _context: &mut Context) -> NativeFunction {
+        self.into_native_function()
+    }
 }
 
 mod into_js_arguments;
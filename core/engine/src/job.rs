@@ -554,14 +554,14 @@ impl JobExecutor for SimpleJobExecutor {
             Job::PromiseJob(p) => self.promise_jobs.borrow_mut().push_back(p),
             Job::AsyncJob(a) => self.async_jobs.borrow_mut().push_back(a),
             Job::TimeoutJob(t) => {
-                let now = context.clock().now();
+                let now = context.clock().monotonic_now();
                 self.timeout_jobs.borrow_mut().insert(now + t.timeout(), t);
             }
         }
     }
 
     fn run_jobs(&self, context: &mut Context) -> JsResult<()> {
-        let now = context.clock().now();
+        let now = context.clock().monotonic_now();
 
         {
             let mut timeouts_borrow = self.timeout_jobs.borrow_mut();
@@ -89,6 +89,7 @@ pub mod class;
 pub mod context;
 pub mod environments;
 pub mod error;
+pub mod handle_scope;
 pub mod interop;
 pub mod job;
 pub mod module;
@@ -119,7 +120,8 @@ pub mod prelude {
     pub use crate::{
         bigint::JsBigInt,
         context::Context,
-        error::{JsError, JsNativeError, JsNativeErrorKind},
+        error::{JsError, JsErrorKind, JsNativeError, JsNativeErrorKind},
+        handle_scope::HandleScope,
         host_defined::HostDefined,
         interop::{IntoJsFunctionCopied, UnsafeIntoJsFunction},
         module::{IntoJsModule, Module},
@@ -201,6 +203,7 @@ enum Inner {
     InspectContext {
         op: fn(&mut Context),
     },
+    CollectGarbage,
     Assert {
         source: Cow<'static, str>,
     },
@@ -247,6 +250,14 @@ impl TestAction {
         Self(Inner::InspectContext { op })
     }
 
+    /// Runs a full garbage collection, after releasing the objects kept alive by `WeakRef`s.
+    ///
+    /// Useful to check which objects are collected, since the collection doesn't depend on how
+    /// much memory was allocated by the previous actions.
+    const fn collect_garbage() -> Self {
+        Self(Inner::CollectGarbage)
+    }
+
     /// Asserts that evaluating `source` returns the `true` value.
     fn assert(source: impl Into<Cow<'static, str>>) -> Self {
         Self(Inner::Assert {
@@ -365,6 +376,10 @@ fn run_test_actions_with(actions: impl IntoIterator<Item = TestAction>, context:
             Inner::InspectContext { op } => {
                 op(context);
             }
+            Inner::CollectGarbage => {
+                context.clear_kept_objects();
+                boa_gc::force_collect();
+            }
             Inner::Assert { source } => {
                 let val = match forward_val(context, &source) {
                     Err(e) => panic!("{}\nUncaught {e}", fmt_test(&source, i)),
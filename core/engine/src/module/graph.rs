@@ -0,0 +1,363 @@
+//! Inspection of the dependency graph of a module.
+//!
+//! See [`Module::graph`].
+
+use std::{collections::VecDeque, fmt, fmt::Write, path::Path};
+
+use rustc_hash::FxHashMap;
+
+use crate::JsString;
+
+use super::{Module, ModuleKind};
+
+/// The state of a module in its lifecycle, as seen in a [`ModuleGraph`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ModuleState {
+    /// The module is not linked yet.
+    Unlinked,
+    /// The module is being linked.
+    Linking,
+    /// The module is linked, and waiting to be evaluated.
+    Linked,
+    /// The module is being evaluated.
+    Evaluating,
+    /// The module is waiting for its asynchronous dependencies, or evaluating its top-level
+    /// `await`s.
+    EvaluatingAsync,
+    /// The module was evaluated successfully.
+    Evaluated,
+    /// The evaluation of the module threw an error.
+    Errored,
+}
+
+impl fmt::Display for ModuleState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Unlinked => "unlinked",
+            Self::Linking => "linking",
+            Self::Linked => "linked",
+            Self::Evaluating => "evaluating",
+            Self::EvaluatingAsync => "evaluating-async",
+            Self::Evaluated => "evaluated",
+            Self::Errored => "errored",
+        })
+    }
+}
+
+/// An import of a module in a [`ModuleGraph`].
+#[derive(Debug, Clone)]
+pub struct ModuleDependency {
+    specifier: JsString,
+    index: Option<usize>,
+}
+
+impl ModuleDependency {
+    /// Gets the specifier of the import, as written in the source of the importing module.
+    #[must_use]
+    pub const fn specifier(&self) -> &JsString {
+        &self.specifier
+    }
+
+    /// Gets the index of the imported module in [`ModuleGraph::nodes`], or `None` if it wasn't
+    /// loaded, which happens when its load failed or is still pending.
+    #[must_use]
+    pub const fn index(&self) -> Option<usize> {
+        self.index
+    }
+}
+
+/// A module of a [`ModuleGraph`].
+#[derive(Debug, Clone)]
+pub struct ModuleGraphNode {
+    module: Module,
+    state: ModuleState,
+    dependencies: Vec<ModuleDependency>,
+}
+
+impl ModuleGraphNode {
+    /// Gets the module.
+    #[must_use]
+    pub const fn module(&self) -> &Module {
+        &self.module
+    }
+
+    /// Gets the path the module was loaded from, if it has one.
+    #[must_use]
+    pub fn path(&self) -> Option<&Path> {
+        self.module.path()
+    }
+
+    /// Gets the state of the module when the graph was built.
+    #[must_use]
+    pub const fn state(&self) -> ModuleState {
+        self.state
+    }
+
+    /// Gets the imports of the module, in the order they appear in its source.
+    #[must_use]
+    pub fn dependencies(&self) -> &[ModuleDependency] {
+        &self.dependencies
+    }
+
+    /// Gets a name for the module in the output of the graph.
+    fn name(&self) -> String {
+        self.path().map_or_else(
+            || String::from("<anonymous>"),
+            |path| path.display().to_string(),
+        )
+    }
+}
+
+/// A snapshot of the dependency graph of a module, returned by [`Module::graph`].
+///
+/// The [`Display`](fmt::Display) implementation prints the graph as a list of modules with their
+/// imports, and [`ModuleGraph::to_dot`] exports it to the DOT format of Graphviz.
+#[derive(Debug, Clone)]
+pub struct ModuleGraph {
+    nodes: Vec<ModuleGraphNode>,
+    cycles: Vec<Vec<usize>>,
+}
+
+impl ModuleGraph {
+    /// Gets the modules of the graph, in breadth-first order from the root module, which is the
+    /// first one.
+    #[must_use]
+    pub fn nodes(&self) -> &[ModuleGraphNode] {
+        &self.nodes
+    }
+
+    /// Gets the root module of the graph.
+    #[must_use]
+    pub fn root(&self) -> &ModuleGraphNode {
+        &self.nodes[0]
+    }
+
+    /// Gets the groups of modules that depend on each other through circular imports, as indices
+    /// in [`ModuleGraph::nodes`].
+    ///
+    /// Each group is a strongly connected component of the graph, and the modules of a group are
+    /// linked and evaluated together.
+    #[must_use]
+    pub fn cycles(&self) -> &[Vec<usize>] {
+        &self.cycles
+    }
+
+    /// Exports the graph to the DOT format of [Graphviz][graphviz].
+    ///
+    /// The modules of a cycle are grouped in a cluster.
+    ///
+    /// [graphviz]: https://graphviz.org/doc/info/lang.html
+    #[must_use]
+    pub fn to_dot(&self) -> String {
+        fn escape(label: &str) -> String {
+            label.replace('\\', "\\\\").replace('"', "\\\"")
+        }
+
+        let mut dot = String::from("digraph {\n");
+        for (index, node) in self.nodes.iter().enumerate() {
+            let _ = writeln!(
+                dot,
+                "    {index} [label=\"{} ({})\"];",
+                escape(&node.name()),
+                node.state
+            );
+        }
+        for (index, cycle) in self.cycles.iter().enumerate() {
+            let _ = writeln!(dot, "    subgraph cluster_{index} {{");
+            let _ = writeln!(dot, "        style=dashed;");
+            for node in cycle {
+                let _ = writeln!(dot, "        {node};");
+            }
+            let _ = writeln!(dot, "    }}");
+        }
+        for (index, node) in self.nodes.iter().enumerate() {
+            for dependency in &node.dependencies {
+                let specifier = escape(&dependency.specifier.to_std_string_escaped());
+                match dependency.index {
+                    Some(target) => {
+                        let _ = writeln!(dot, "    {index} -> {target} [label=\"{specifier}\"];");
+                    }
+                    None => {
+                        let _ = writeln!(
+                            dot,
+                            "    \"{specifier}\" [shape=box, style=dashed];\n    \
+                             {index} -> \"{specifier}\" [style=dashed];"
+                        );
+                    }
+                }
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+impl fmt::Display for ModuleGraph {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, node) in self.nodes.iter().enumerate() {
+            writeln!(f, "[{index}] {} ({})", node.name(), node.state)?;
+            for dependency in &node.dependencies {
+                let specifier = dependency.specifier.to_std_string_escaped();
+                match dependency.index {
+                    Some(target) => writeln!(f, "    {specifier:?} -> [{target}]")?,
+                    None => writeln!(f, "    {specifier:?} -> (not loaded)")?,
+                }
+            }
+        }
+        for cycle in &self.cycles {
+            let cycle = cycle
+                .iter()
+                .map(|index| format!("[{index}]"))
+                .collect::<Vec<_>>()
+                .join(", ");
+            writeln!(f, "cycle: {cycle}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Module {
+    /// Builds a snapshot of the dependency graph of the module, with the specifiers, paths and
+    /// states of the modules it imports directly or indirectly, and the groups of modules
+    /// importing each other.
+    ///
+    /// The graph only contains the modules that were loaded, so this is most useful after the
+    /// module is loaded or linked, to debug the resolution of its imports and its circular
+    /// dependencies.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use boa_engine::{module::ModuleState, Context, Module, Source};
+    ///
+    /// let context = &mut Context::default();
+    /// let module = Module::parse(Source::from_bytes("export const a = 1;"), None, context).unwrap();
+    /// module.load_link_evaluate(context);
+    /// context.run_jobs().unwrap();
+    ///
+    /// let graph = module.graph();
+    /// assert_eq!(graph.nodes().len(), 1);
+    /// assert_eq!(graph.root().state(), ModuleState::Evaluated);
+    /// ```
+    #[must_use]
+    pub fn graph(&self) -> ModuleGraph {
+        let mut indices = FxHashMap::default();
+        let mut modules = Vec::new();
+        let mut queue = VecDeque::from([self.clone()]);
+        indices.insert(self.clone(), 0);
+
+        while let Some(module) = queue.pop_front() {
+            let dependencies = match module.kind() {
+                ModuleKind::SourceText(src) => src.dependencies(),
+                ModuleKind::Synthetic(_) => Vec::new(),
+            };
+            for (_, dependency) in &dependencies {
+                let Some(dependency) = dependency else {
+                    continue;
+                };
+                if !indices.contains_key(dependency) {
+                    indices.insert(dependency.clone(), indices.len());
+                    queue.push_back(dependency.clone());
+                }
+            }
+            modules.push((module, dependencies));
+        }
+
+        let nodes: Vec<_> = modules
+            .into_iter()
+            .map(|(module, dependencies)| ModuleGraphNode {
+                state: match module.kind() {
+                    ModuleKind::SourceText(src) => src.graph_state(),
+                    ModuleKind::Synthetic(synth) => synth.graph_state(),
+                },
+                dependencies: dependencies
+                    .into_iter()
+                    .map(|(specifier, module)| ModuleDependency {
+                        specifier,
+                        index: module.map(|module| indices[&module]),
+                    })
+                    .collect(),
+                module,
+            })
+            .collect();
+
+        let cycles = strongly_connected_components(&nodes)
+            .into_iter()
+            .filter(|component| {
+                component.len() > 1
+                    || nodes[component[0]]
+                        .dependencies
+                        .iter()
+                        .any(|dependency| dependency.index == Some(component[0]))
+            })
+            .collect();
+
+        ModuleGraph { nodes, cycles }
+    }
+}
+
+/// Computes the strongly connected components of the graph with Kosaraju's algorithm, using
+/// explicit stacks to support deep graphs.
+///
+/// The indices of each component are sorted, and the components are sorted by their first index.
+fn strongly_connected_components(nodes: &[ModuleGraphNode]) -> Vec<Vec<usize>> {
+    let edges = |index: usize| {
+        nodes[index]
+            .dependencies
+            .iter()
+            .filter_map(ModuleDependency::index)
+    };
+
+    // Order the nodes by the time their depth-first traversal finishes.
+    let mut visited = vec![false; nodes.len()];
+    let mut finished = Vec::with_capacity(nodes.len());
+    for start in 0..nodes.len() {
+        if visited[start] {
+            continue;
+        }
+        visited[start] = true;
+        let mut stack = vec![(start, edges(start))];
+        while let Some((node, successors)) = stack.last_mut() {
+            if let Some(next) = successors.find(|&next| !visited[next]) {
+                visited[next] = true;
+                stack.push((next, edges(next)));
+            } else {
+                finished.push(*node);
+                stack.pop();
+            }
+        }
+    }
+
+    let mut reversed = vec![Vec::new(); nodes.len()];
+    for index in 0..nodes.len() {
+        for next in edges(index) {
+            reversed[next].push(index);
+        }
+    }
+
+    // Collect the nodes reachable in the reversed graph, in reverse finishing order.
+    let mut component_of = vec![None; nodes.len()];
+    let mut components = Vec::new();
+    for &start in finished.iter().rev() {
+        if component_of[start].is_some() {
+            continue;
+        }
+        let mut component = Vec::new();
+        let mut stack = vec![start];
+        component_of[start] = Some(components.len());
+        while let Some(node) = stack.pop() {
+            component.push(node);
+            for &previous in &reversed[node] {
+                if component_of[previous].is_none() {
+                    component_of[previous] = Some(components.len());
+                    stack.push(previous);
+                }
+            }
+        }
+        component.sort_unstable();
+        components.push(component);
+    }
+
+    components.sort_unstable_by_key(|component| component[0]);
+    components
+}
@@ -0,0 +1,334 @@
+//! Support for [import maps][spec], which remap the specifiers of imports before they reach the
+//! [`ModuleLoader`].
+//!
+//! See [`Context::set_import_map`].
+//!
+//! [spec]: https://html.spec.whatwg.org/multipage/webappapis.html#import-maps
+
+use std::path::{Component, Path, PathBuf};
+
+use serde_json::{Map, Value};
+
+use crate::{Context, JsNativeError, JsResult, JsString, Module};
+
+use super::{ModuleLoader, Referrer};
+
+/// A map from specifier keys to their addresses, sorted by descending key so that longer
+/// prefixes are matched first.
+///
+/// `None` addresses block the imports matching their key.
+type SpecifierMap = Vec<(String, Option<String>)>;
+
+/// An [import map][spec], remapping bare specifiers and specifier prefixes.
+///
+/// Unlike browsers, which resolve specifiers into URLs, the entries of an import map are
+/// filesystem paths:
+///  - Keys and addresses starting with `/`, `./` or `../` are paths, resolved against the base
+///    path the map is parsed with.
+///  - Relative specifiers are resolved against the directory of the importing module before
+///    they are matched against the keys.
+///  - Other addresses are passed to the [`ModuleLoader`] as they are written, which allows
+///    remapping a bare specifier to another one.
+///
+/// Keys ending with `/` match all the specifiers starting with them, and the rest of the
+/// specifier is appended to their address. The entries of the `"scopes"` whose key is a prefix
+/// of the path of the importing module take precedence over the top-level `"imports"`.
+///
+/// [spec]: https://html.spec.whatwg.org/multipage/webappapis.html#import-maps
+#[derive(Debug, Clone, Default)]
+pub struct ImportMap {
+    imports: SpecifierMap,
+    scopes: Vec<(String, SpecifierMap)>,
+}
+
+impl ImportMap {
+    /// Parses the JSON text of an import map, resolving its relative paths against `base`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `SyntaxError` if `json` is not valid JSON, and a `TypeError` if it is not a
+    /// valid import map.
+    pub fn parse(json: &str, base: &Path) -> JsResult<Self> {
+        let value: Value = serde_json::from_str(json).map_err(|e| {
+            JsNativeError::syntax().with_message(format!("invalid import map: {e}"))
+        })?;
+        let Value::Object(map) = value else {
+            return Err(JsNativeError::typ()
+                .with_message("import map must be a JSON object")
+                .into());
+        };
+
+        let imports = match map.get("imports") {
+            None => SpecifierMap::new(),
+            Some(Value::Object(imports)) => parse_specifier_map(imports, base),
+            Some(_) => {
+                return Err(JsNativeError::typ()
+                    .with_message("the `imports` of an import map must be a JSON object")
+                    .into())
+            }
+        };
+
+        let mut scopes = match map.get("scopes") {
+            None => Vec::new(),
+            Some(Value::Object(scopes)) => scopes
+                .iter()
+                .map(|(scope, imports)| match imports {
+                    Value::Object(imports) => Ok((
+                        resolve_path(base, scope),
+                        parse_specifier_map(imports, base),
+                    )),
+                    _ => Err(JsNativeError::typ()
+                        .with_message(format!(
+                            "the scope `{scope}` of an import map must be a JSON object"
+                        ))
+                        .into()),
+                })
+                .collect::<JsResult<Vec<_>>>()?,
+            Some(_) => {
+                return Err(JsNativeError::typ()
+                    .with_message("the `scopes` of an import map must be a JSON object")
+                    .into())
+            }
+        };
+        scopes.sort_by(|(a, _), (b, _)| b.cmp(a));
+
+        Ok(Self { imports, scopes })
+    }
+
+    /// Remaps `specifier`, imported by the module or script at `referrer`, returning `None` if
+    /// no entry of the map matches it.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TypeError` if the matching entry blocks the specifier.
+    pub fn resolve(&self, specifier: &str, referrer: Option<&Path>) -> JsResult<Option<String>> {
+        let normalized = match referrer.and_then(Path::parent) {
+            Some(dir) if is_path(specifier) => resolve_path(dir, specifier),
+            _ => specifier.to_owned(),
+        };
+
+        if let Some(referrer) = referrer {
+            let referrer = referrer.to_string_lossy();
+            for (scope, imports) in &self.scopes {
+                if !matches_key(scope, &referrer) {
+                    continue;
+                }
+                if let Some(address) = resolve_in(imports, specifier, &normalized)? {
+                    return Ok(Some(address));
+                }
+            }
+        }
+
+        resolve_in(&self.imports, specifier, &normalized)
+    }
+}
+
+/// Parses the entries of the `"imports"` map or of a scope of an import map.
+fn parse_specifier_map(map: &Map<String, Value>, base: &Path) -> SpecifierMap {
+    let mut entries: SpecifierMap = map
+        .iter()
+        .filter(|(key, _)| !key.is_empty())
+        .map(|(key, address)| {
+            let key = if is_path(key) {
+                resolve_path(base, key)
+            } else {
+                key.clone()
+            };
+            let address = match address {
+                Value::String(address) if is_path(address) => Some(resolve_path(base, address)),
+                Value::String(address) => Some(address.clone()),
+                _ => None,
+            };
+            // The address of a prefix must be a prefix as well.
+            let address = address.filter(|address| !key.ends_with('/') || address.ends_with('/'));
+            (key, address)
+        })
+        .collect();
+    entries.sort_by(|(a, _), (b, _)| b.cmp(a));
+    entries
+}
+
+/// Finds the entry of `map` matching the `normalized` specifier, and returns its address.
+fn resolve_in(map: &SpecifierMap, specifier: &str, normalized: &str) -> JsResult<Option<String>> {
+    let Some((key, address)) = map.iter().find(|(key, _)| matches_key(key, normalized)) else {
+        return Ok(None);
+    };
+    let Some(address) = address else {
+        return Err(JsNativeError::typ()
+            .with_message(format!("module `{specifier}` is blocked by the import map"))
+            .into());
+    };
+
+    Ok(Some(format!("{address}{}", &normalized[key.len()..])))
+}
+
+/// Checks if `key` is `value`, or a prefix of `value` ending with `/`.
+fn matches_key(key: &str, value: &str) -> bool {
+    key == value || (key.ends_with('/') && value.starts_with(key))
+}
+
+/// Checks if `specifier` is an absolute or relative path.
+fn is_path(specifier: &str) -> bool {
+    specifier.starts_with('/') || specifier.starts_with("./") || specifier.starts_with("../")
+}
+
+/// Resolves the `path` of an import map against `base`, removing its `.` and `..` components
+/// and keeping its trailing `/`.
+fn resolve_path(base: &Path, path: &str) -> String {
+    if !is_path(path) {
+        return path.to_owned();
+    }
+
+    let mut resolved = PathBuf::new();
+    for component in base.join(path).components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                resolved.pop();
+            }
+            component => resolved.push(component),
+        }
+    }
+
+    let mut resolved = resolved.to_string_lossy().into_owned();
+    if path.ends_with('/') && !resolved.ends_with('/') {
+        resolved.push('/');
+    }
+    resolved
+}
+
+impl Context {
+    /// Sets the [`ImportMap`] of the context from its JSON text, replacing the previous one.
+    ///
+    /// The import map remaps the specifiers of all the static and dynamic imports before they
+    /// are passed to the [`ModuleLoader`]. Its relative paths are resolved against the current
+    /// working directory; use [`Context::set_parsed_import_map`] with [`ImportMap::parse`] to
+    /// resolve them against another path.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `json` is not a valid import map, leaving the previous import map in
+    /// place.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use boa_engine::Context;
+    ///
+    /// let mut context = Context::default();
+    /// context
+    ///     .set_import_map(r#"{ "imports": { "lodash": "/vendor/lodash-es/lodash.js" } }"#)
+    ///     .unwrap();
+    ///
+    /// let import_map = context.import_map().unwrap();
+    /// assert_eq!(
+    ///     import_map.resolve("lodash", None).unwrap().as_deref(),
+    ///     Some("/vendor/lodash-es/lodash.js")
+    /// );
+    /// ```
+    pub fn set_import_map(&mut self, json: &str) -> JsResult<()> {
+        let base = std::env::current_dir().unwrap_or_default();
+        self.set_parsed_import_map(Some(ImportMap::parse(json, &base)?));
+        Ok(())
+    }
+
+    /// Sets the [`ImportMap`] of the context, or removes it if `import_map` is `None`.
+    #[inline]
+    pub fn set_parsed_import_map(&mut self, import_map: Option<ImportMap>) {
+        self.import_map = import_map;
+    }
+
+    /// Gets the [`ImportMap`] of the context, if it has one.
+    #[inline]
+    #[must_use]
+    pub const fn import_map(&self) -> Option<&ImportMap> {
+        self.import_map.as_ref()
+    }
+
+    /// Abstract operation [`HostLoadImportedModule`][spec], remapping `specifier` with the
+    /// import map of the context before passing it to the module loader.
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-HostLoadImportedModule
+    pub(crate) fn load_imported_module(
+        &mut self,
+        referrer: Referrer,
+        specifier: JsString,
+        finish_load: Box<dyn FnOnce(JsResult<Module>, &mut Self)>,
+    ) {
+        let specifier = match &self.import_map {
+            Some(import_map) => {
+                match import_map.resolve(&specifier.to_std_string_escaped(), referrer.path()) {
+                    Ok(Some(address)) => JsString::from(address),
+                    Ok(None) => specifier,
+                    Err(err) => return finish_load(Err(err), self),
+                }
+            }
+            None => specifier,
+        };
+
+        self.module_loader()
+            .load_imported_module(referrer, specifier, finish_load, self);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::ImportMap;
+
+    #[cfg(target_family = "unix")]
+    #[test]
+    fn resolve() {
+        let import_map = ImportMap::parse(
+            r#"{
+                "imports": {
+                    "lodash": "./vendor/lodash.js",
+                    "lodash/": "./vendor/lodash/",
+                    "moment": "/vendor/moment.js",
+                    "alias": "other",
+                    "./src/old.js": "./src/new.js",
+                    "blocked": null,
+                    "invalid/": "./vendor/invalid.js"
+                },
+                "scopes": {
+                    "./legacy/": { "lodash": "./vendor/lodash-v3.js" }
+                }
+            }"#,
+            Path::new("/app"),
+        )
+        .unwrap();
+
+        let resolve = |specifier, referrer: &str| {
+            import_map
+                .resolve(specifier, Some(Path::new(referrer)))
+                .map_err(|_| ())
+        };
+
+        assert_eq!(
+            resolve("lodash", "/app/main.js"),
+            Ok(Some("/app/vendor/lodash.js".into()))
+        );
+        assert_eq!(
+            resolve("lodash/fp/map.js", "/app/main.js"),
+            Ok(Some("/app/vendor/lodash/fp/map.js".into()))
+        );
+        assert_eq!(
+            resolve("moment", "/app/main.js"),
+            Ok(Some("/vendor/moment.js".into()))
+        );
+        assert_eq!(resolve("alias", "/app/main.js"), Ok(Some("other".into())));
+        assert_eq!(
+            resolve("../src/old.js", "/app/lib/main.js"),
+            Ok(Some("/app/src/new.js".into()))
+        );
+        assert_eq!(
+            resolve("lodash", "/app/legacy/main.js"),
+            Ok(Some("/app/vendor/lodash-v3.js".into()))
+        );
+        assert_eq!(resolve("./other.js", "/app/main.js"), Ok(None));
+        assert_eq!(resolve("unknown", "/app/main.js"), Ok(None));
+        assert_eq!(resolve("blocked", "/app/main.js"), Err(()));
+        assert_eq!(resolve("invalid/x.js", "/app/main.js"), Err(()));
+    }
+}
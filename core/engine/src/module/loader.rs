@@ -12,7 +12,7 @@ use crate::{
     JsResult, JsString,
 };
 
-use super::Module;
+use super::{Module, ModuleMiddleware, ModuleMiddlewares, ModuleRequest};
 
 /// Resolves paths from the referrer and the specifier, normalize the paths and ensure the path
 /// is within a base. If the base is empty, that last verification will be skipped.
@@ -247,6 +247,7 @@ impl ModuleLoader for IdleModuleLoader {
 pub struct SimpleModuleLoader {
     root: PathBuf,
     module_map: GcRefCell<FxHashMap<PathBuf, Module>>,
+    middlewares: ModuleMiddlewares,
 }
 
 impl SimpleModuleLoader {
@@ -267,9 +268,18 @@ impl SimpleModuleLoader {
         Ok(Self {
             root: absolute,
             module_map: GcRefCell::default(),
+            middlewares: ModuleMiddlewares::new(),
         })
     }
 
+    /// Adds a middleware that processes the sources of the modules loaded from the filesystem,
+    /// after the middlewares that were already added.
+    #[must_use]
+    pub fn with_middleware<M: ModuleMiddleware + 'static>(mut self, middleware: M) -> Self {
+        self.middlewares.push(middleware);
+        self
+    }
+
     /// Inserts a new module onto the module map.
     #[inline]
     pub fn insert(&self, path: PathBuf, module: Module) {
@@ -299,11 +309,14 @@ impl ModuleLoader for SimpleModuleLoader {
                 return Ok(module);
             }
 
-            let source = Source::from_filepath(&path).map_err(|err| {
+            let source = std::fs::read(&path).map_err(|err| {
                 JsNativeError::typ()
                     .with_message(format!("could not open file `{short_path}`"))
                     .with_cause(JsError::from_opaque(js_string!(err.to_string()).into()))
             })?;
+            let request = ModuleRequest::new(&referrer, &specifier, Some(&path));
+            let source = self.middlewares.apply(&request, source, context)?;
+            let source = Source::from_reader(source.as_slice(), Some(&path));
             let module = Module::parse(source, None, context).map_err(|err| {
                 JsNativeError::syntax()
                     .with_message(format!("could not parse module `{short_path}`"))
@@ -0,0 +1,218 @@
+//! Middlewares of the module loading pipeline.
+//!
+//! A [`ModuleMiddleware`] processes the source of a module after it is fetched and before it is
+//! parsed, which allows hosts to transform modules, e.g. to strip type annotations or to
+//! instrument them for coverage, and to verify their integrity before running them.
+
+use std::{fmt, path::Path, rc::Rc};
+
+use rustc_hash::FxHashSet;
+
+use crate::{Context, JsNativeError, JsResult, JsString};
+
+use super::Referrer;
+
+/// A load request of a module, as seen by a [`ModuleMiddleware`].
+#[derive(Debug, Clone, Copy)]
+pub struct ModuleRequest<'a> {
+    referrer: &'a Referrer,
+    specifier: &'a JsString,
+    path: Option<&'a Path>,
+}
+
+impl<'a> ModuleRequest<'a> {
+    /// Creates a new `ModuleRequest` for the module imported as `specifier` by `referrer`, which
+    /// the module loader resolved to `path`.
+    #[must_use]
+    pub const fn new(
+        referrer: &'a Referrer,
+        specifier: &'a JsString,
+        path: Option<&'a Path>,
+    ) -> Self {
+        Self {
+            referrer,
+            specifier,
+            path,
+        }
+    }
+
+    /// Gets the referrer that imports the module.
+    #[must_use]
+    pub const fn referrer(&self) -> &'a Referrer {
+        self.referrer
+    }
+
+    /// Gets the specifier the module is imported with.
+    #[must_use]
+    pub const fn specifier(&self) -> &'a JsString {
+        self.specifier
+    }
+
+    /// Gets the path the module loader resolved the specifier to, if it has one.
+    #[must_use]
+    pub const fn path(&self) -> Option<&'a Path> {
+        self.path
+    }
+}
+
+/// A step of the module loading pipeline, applied to the source of a module before it is parsed.
+///
+/// This is implemented for closures with the same signature as [`ModuleMiddleware::process`].
+pub trait ModuleMiddleware {
+    /// Processes the `source` of the module loaded for `request`, returning the source that is
+    /// passed to the next middleware, or parsed if this is the last one.
+    ///
+    /// # Errors
+    ///
+    /// Returning an error aborts the load of the module, rejecting it with the error.
+    fn process(
+        &self,
+        request: &ModuleRequest<'_>,
+        source: Vec<u8>,
+        context: &mut Context,
+    ) -> JsResult<Vec<u8>>;
+}
+
+impl<F> ModuleMiddleware for F
+where
+    F: Fn(&ModuleRequest<'_>, Vec<u8>, &mut Context) -> JsResult<Vec<u8>>,
+{
+    fn process(
+        &self,
+        request: &ModuleRequest<'_>,
+        source: Vec<u8>,
+        context: &mut Context,
+    ) -> JsResult<Vec<u8>> {
+        self(request, source, context)
+    }
+}
+
+/// The list of [`ModuleMiddleware`]s of a module loader, applied in the order they were added.
+///
+/// Module loaders should apply their middlewares with [`ModuleMiddlewares::apply`] to the source
+/// of every module they fetch.
+#[derive(Default, Clone)]
+pub struct ModuleMiddlewares {
+    middlewares: Vec<Rc<dyn ModuleMiddleware>>,
+}
+
+impl fmt::Debug for ModuleMiddlewares {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ModuleMiddlewares")
+            .field("len", &self.middlewares.len())
+            .finish()
+    }
+}
+
+impl ModuleMiddlewares {
+    /// Creates an empty list of middlewares.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `middleware` at the end of the list.
+    pub fn push<M: ModuleMiddleware + 'static>(&mut self, middleware: M) {
+        self.middlewares.push(Rc::new(middleware));
+    }
+
+    /// Returns `true` if the list has no middlewares.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.middlewares.is_empty()
+    }
+
+    /// Applies the middlewares to the `source` of the module loaded for `request`, in order.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the first middleware that fails.
+    pub fn apply(
+        &self,
+        request: &ModuleRequest<'_>,
+        source: Vec<u8>,
+        context: &mut Context,
+    ) -> JsResult<Vec<u8>> {
+        self.middlewares
+            .iter()
+            .try_fold(source, |source, middleware| {
+                middleware.process(request, source, context)
+            })
+    }
+}
+
+/// A [`ModuleMiddleware`] that only allows loading modules whose source has an allowed hash.
+///
+/// The engine doesn't ship a hash function, so the host provides the one used to compute the
+/// digests of the sources, which are then looked up in the allowlist.
+///
+/// # Example
+///
+/// ```
+/// use boa_engine::module::{IntegrityCheck, SimpleModuleLoader};
+///
+/// // A real host would use a cryptographic hash function, like SHA-256.
+/// fn hash(source: &[u8]) -> Vec<u8> {
+///     source.iter().fold(0u8, |acc, b| acc ^ b).to_le_bytes().to_vec()
+/// }
+///
+/// let integrity = IntegrityCheck::new(hash).allow(hash(b"export const a = 1;"));
+/// let loader = SimpleModuleLoader::new(".").unwrap().with_middleware(integrity);
+/// ```
+pub struct IntegrityCheck<H> {
+    hasher: H,
+    allowlist: FxHashSet<Vec<u8>>,
+}
+
+impl<H> fmt::Debug for IntegrityCheck<H> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("IntegrityCheck")
+            .field("allowlist", &self.allowlist)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<H> IntegrityCheck<H>
+where
+    H: Fn(&[u8]) -> Vec<u8>,
+{
+    /// Creates a new `IntegrityCheck` with an empty allowlist, computing the digests of the
+    /// sources with `hasher`.
+    #[must_use]
+    pub fn new(hasher: H) -> Self {
+        Self {
+            hasher,
+            allowlist: FxHashSet::default(),
+        }
+    }
+
+    /// Adds `digest` to the allowlist.
+    #[must_use]
+    pub fn allow<D: Into<Vec<u8>>>(mut self, digest: D) -> Self {
+        self.allowlist.insert(digest.into());
+        self
+    }
+}
+
+impl<H> ModuleMiddleware for IntegrityCheck<H>
+where
+    H: Fn(&[u8]) -> Vec<u8>,
+{
+    fn process(
+        &self,
+        request: &ModuleRequest<'_>,
+        source: Vec<u8>,
+        _context: &mut Context,
+    ) -> JsResult<Vec<u8>> {
+        if self.allowlist.contains(&(self.hasher)(&source)) {
+            return Ok(source);
+        }
+
+        Err(JsNativeError::typ()
+            .with_message(format!(
+                "module `{}` failed its integrity check",
+                request.specifier().to_std_string_escaped()
+            ))
+            .into())
+    }
+}
@@ -3,6 +3,8 @@
 //! This module contains the [`Module`] type, which represents an [**Abstract Module Record**][module],
 //! a [`ModuleLoader`] trait for custom module loader implementations, and [`SimpleModuleLoader`],
 //! the default `ModuleLoader` for [`Context`] which can be used for most simple usecases.
+//! Module loaders can process the sources of the modules they fetch with [`ModuleMiddleware`]s.
+//! The specifiers of the imports can be remapped before they reach the loader with an [`ImportMap`].
 //!
 //! Every module roughly follows the same lifecycle:
 //! - Parse using [`Module::parse`].
@@ -37,7 +39,10 @@ use boa_interner::Interner;
 use boa_parser::source::ReadChar;
 use boa_parser::{Parser, Source};
 use boa_profiler::Profiler;
+pub use graph::{ModuleDependency, ModuleGraph, ModuleGraphNode, ModuleState};
+pub use import_map::ImportMap;
 pub use loader::*;
+pub use middleware::{IntegrityCheck, ModuleMiddleware, ModuleMiddlewares, ModuleRequest};
 pub use namespace::ModuleNamespace;
 use source::SourceTextModule;
 pub use synthetic::{SyntheticModule, SyntheticModuleInitializer};
@@ -53,7 +58,10 @@ use crate::{
     Context, HostDefined, JsError, JsNativeError, JsResult, JsString, JsValue, NativeFunction,
 };
 
+mod graph;
+mod import_map;
 mod loader;
+mod middleware;
 mod namespace;
 mod source;
 mod synthetic;
@@ -80,9 +88,12 @@ impl std::fmt::Debug for Module {
 struct ModuleRepr {
     realm: Realm,
     namespace: GcRefCell<Option<JsObject>>,
+    deferred_namespace: GcRefCell<Option<JsObject>>,
+    source: GcRefCell<Option<JsObject>>,
     kind: ModuleKind,
     host_defined: HostDefined,
-    path: Option<PathBuf>,
+    #[unsafe_ignore_trace]
+    path: Option<Rc<Path>>,
 }
 
 /// The kind of a [`Module`].
@@ -163,13 +174,14 @@ impl Module {
         context: &mut Context,
     ) -> JsResult<Self> {
         let _timer = Profiler::global().start_event("Module parsing", "Main");
-        let path = src.path().map(Path::to_path_buf);
+        let path = src.path().map(Rc::from);
         let realm = realm.unwrap_or_else(|| context.realm().clone());
 
         let mut parser = Parser::new(src);
         parser.set_identifier(context.next_parser_identifier());
-        let (module, source) =
-            parser.parse_module_with_source(realm.scope(), context.interner_mut())?;
+        let (module, source) = parser
+            .parse_module_with_source(realm.scope(), context.interner_mut())
+            .map_err(|err| JsError::from_parse_error(err, path.clone()))?;
 
         let source_text = SourceText::new(source);
         let src = SourceTextModule::new(module, context.interner(), source_text);
@@ -178,6 +190,8 @@ impl Module {
             inner: Gc::new(ModuleRepr {
                 realm,
                 namespace: GcRefCell::default(),
+                deferred_namespace: GcRefCell::default(),
+                source: GcRefCell::default(),
                 kind: ModuleKind::SourceText(Box::new(src)),
                 host_defined: HostDefined::default(),
                 path,
@@ -207,9 +221,11 @@ impl Module {
             inner: Gc::new(ModuleRepr {
                 realm,
                 namespace: GcRefCell::default(),
+                deferred_namespace: GcRefCell::default(),
+                source: GcRefCell::default(),
                 kind: ModuleKind::Synthetic(Box::new(synth)),
                 host_defined: HostDefined::default(),
-                path,
+                path: path.map(Rc::from),
             }),
         }
     }
@@ -265,6 +281,32 @@ impl Module {
         &self.inner.host_defined
     }
 
+    /// Sets the object that source phase imports of this `Module` evaluate to
+    /// (`import source name from "module-name"`).
+    ///
+    /// Module loaders can use this to expose a representation of the module source that is
+    /// independent from its evaluation, like a compiled `WebAssembly.Module`. Modules without a
+    /// source object throw a `SyntaxError` when they are imported in the source phase.
+    ///
+    /// This is part of the [source phase imports proposal][proposal].
+    ///
+    /// [proposal]: https://github.com/tc39/proposal-source-phase-imports
+    #[inline]
+    pub fn set_source_object(&self, source: JsObject) {
+        *self.inner.source.borrow_mut() = Some(source);
+    }
+
+    /// Abstract method [`GetModuleSource ( )`][spec].
+    ///
+    /// [spec]: https://tc39.es/proposal-source-phase-imports/#table-abstract-methods-of-module-records
+    pub(crate) fn get_module_source(&self) -> JsResult<JsObject> {
+        self.inner.source.borrow().clone().ok_or_else(|| {
+            JsNativeError::syntax()
+                .with_message("module does not provide a source phase representation")
+                .into()
+        })
+    }
+
     /// Gets the kind of this `Module`.
     pub(crate) fn kind(&self) -> &ModuleKind {
         &self.inner.kind
@@ -504,6 +546,59 @@ impl Module {
         }
     }
 
+    /// Abstract operation [`ReadyForSyncExecution ( module [ , seen ] )`][spec].
+    ///
+    /// Checks if this module and its dependencies can be evaluated synchronously.
+    ///
+    /// [spec]: https://tc39.es/proposal-defer-import-eval/#sec-ReadyForSyncExecution
+    fn ready_for_sync_execution(&self, seen: &mut FxHashSet<Module>) -> bool {
+        match self.kind() {
+            ModuleKind::SourceText(src) => src.ready_for_sync_execution(self, seen),
+            // 1. If module is not a Cyclic Module Record, return true.
+            ModuleKind::Synthetic(_) => true,
+        }
+    }
+
+    /// Abstract operation [`EvaluateModuleSync ( module )`][spec].
+    ///
+    /// Evaluates this module synchronously, throwing a `TypeError` if the module or its
+    /// dependencies cannot be evaluated synchronously.
+    ///
+    /// [spec]: https://tc39.es/proposal-defer-import-eval/#sec-EvaluateModuleSync
+    pub(crate) fn evaluate_sync(&self, context: &mut Context) -> JsResult<()> {
+        // 1. Assert: module is not a Cyclic Module Record or module.[[Status]] is not new or unlinked.
+        // 2. If module is a Cyclic Module Record and module.[[Status]] is evaluated, then
+        //     a. Return ? module.[[EvaluationError]] or unused.
+        if let ModuleKind::SourceText(src) = self.kind() {
+            if let Some(result) = src.evaluation_result() {
+                return result;
+            }
+        }
+
+        // 3. If ReadyForSyncExecution(module) is false, throw a TypeError exception.
+        if !self.ready_for_sync_execution(&mut FxHashSet::default()) {
+            return Err(JsNativeError::typ()
+                .with_message(
+                    "cannot synchronously evaluate a module that is being evaluated or \
+                     uses top-level await",
+                )
+                .into());
+        }
+
+        // 4. Let promise be ! module.Evaluate().
+        // 5. Assert: promise.[[PromiseState]] is either fulfilled or rejected.
+        match self.evaluate(context).state() {
+            PromiseState::Pending => {
+                unreachable!("5. Assert: promise.[[PromiseState]] is either fulfilled or rejected.")
+            }
+            // 7. Return unused.
+            PromiseState::Fulfilled(_) => Ok(()),
+            // 6. If promise.[[PromiseState]] is rejected, then
+            //     a. Return ThrowCompletion(promise.[[PromiseResult]]).
+            PromiseState::Rejected(err) => Err(JsError::from_opaque(err)),
+        }
+    }
+
     /// Loads, links and evaluates this module, returning a promise that will resolve after the module
     /// finishes its lifecycle.
     ///
@@ -581,12 +676,36 @@ impl Module {
     /// [spec]: https://tc39.es/ecma262/#sec-getmodulenamespace
     /// [ns]: https://tc39.es/ecma262/#sec-module-namespace-exotic-objects
     pub fn namespace(&self, context: &mut Context) -> JsObject {
+        self.get_namespace(false, context)
+    }
+
+    /// Gets the deferred [**Module Namespace Object**][ns] of this module, which evaluates the
+    /// module on the first access to its exports.
+    ///
+    /// This is part of the [deferred module evaluation proposal][proposal].
+    ///
+    /// [ns]: https://tc39.es/proposal-defer-import-eval/#sec-module-namespace-exotic-objects
+    /// [proposal]: https://github.com/tc39/proposal-defer-import-eval
+    pub(crate) fn deferred_namespace(&self, context: &mut Context) -> JsObject {
+        self.get_namespace(true, context)
+    }
+
+    /// Abstract operation [`GetModuleNamespace ( module, phase )`][spec].
+    ///
+    /// [spec]: https://tc39.es/proposal-defer-import-eval/#sec-getmodulenamespace
+    fn get_namespace(&self, deferred: bool, context: &mut Context) -> JsObject {
         // 1. Assert: If module is a Cyclic Module Record, then module.[[Status]] is not new or unlinked.
-        // 2. Let namespace be module.[[Namespace]].
+        // 2. If phase is defer, let namespace be module.[[DeferredNamespace]], otherwise let
+        //    namespace be module.[[Namespace]].
+        let namespace = if deferred {
+            &self.inner.deferred_namespace
+        } else {
+            &self.inner.namespace
+        };
+
         // 3. If namespace is empty, then
         // 4. Return namespace.
-        self.inner
-            .namespace
+        namespace
             .borrow_mut()
             .get_or_insert_with(|| {
                 // a. Let exportedNames be module.GetExportedNames().
@@ -610,8 +729,8 @@ impl Module {
                     })
                     .collect();
 
-                //     d. Set namespace to ModuleNamespaceCreate(module, unambiguousNames).
-                ModuleNamespace::create(self.clone(), unambiguous_names, context)
+                //     d. Set namespace to ModuleNamespaceCreate(module, unambiguousNames, phase).
+                ModuleNamespace::create(self.clone(), unambiguous_names, deferred, context)
             })
             .clone()
     }
@@ -650,6 +769,10 @@ impl Module {
     pub fn path(&self) -> Option<&Path> {
         self.inner.path.as_deref()
     }
+
+    pub(crate) fn shared_path(&self) -> Option<&Rc<Path>> {
+        self.inner.path.as_ref()
+    }
 }
 
 impl PartialEq for Module {
@@ -15,6 +15,7 @@ use crate::object::{JsData, JsPrototype};
 use crate::property::{PropertyDescriptor, PropertyKey};
 use crate::{js_string, object::JsObject, Context, JsResult, JsString, JsValue};
 use crate::{JsNativeError, Module};
+use boa_macros::js_str;
 
 use super::BindingName;
 
@@ -26,6 +27,7 @@ pub struct ModuleNamespace {
     module: Module,
     #[unsafe_ignore_trace]
     exports: IndexSet<JsString, BuildHasherDefault<FxHasher>>,
+    deferred: bool,
 }
 
 impl JsData for ModuleNamespace {
@@ -51,10 +53,15 @@ impl JsData for ModuleNamespace {
 }
 
 impl ModuleNamespace {
-    /// Abstract operation [`ModuleNamespaceCreate ( module, exports )`][spec].
+    /// Abstract operation [`ModuleNamespaceCreate ( module, exports, phase )`][spec].
     ///
-    /// [spec]: https://tc39.es/ecma262/#sec-modulenamespacecreate
-    pub(crate) fn create(module: Module, names: Vec<JsString>, context: &mut Context) -> JsObject {
+    /// [spec]: https://tc39.es/proposal-defer-import-eval/#sec-modulenamespacecreate
+    pub(crate) fn create(
+        module: Module,
+        names: Vec<JsString>,
+        deferred: bool,
+        context: &mut Context,
+    ) -> JsObject {
         // 1. Assert: module.[[Namespace]] is empty.
         // ignored since this is ensured by `Module::namespace`.
 
@@ -67,12 +74,21 @@ impl ModuleNamespace {
         // 4. Set M's essential internal methods to the definitions specified in 10.4.6.
         // 5. Set M.[[Module]] to module.
         // 7. Set M.[[Exports]] to sortedExports.
-        // 8. Create own properties of M corresponding to the definitions in 28.3.
-        let namespace = context
-            .intrinsics()
-            .templates()
-            .namespace()
-            .create(Self { module, exports }, vec![js_string!("Module").into()]);
+        // 8. Set M.[[Deferred]] to be true if phase is defer, or false otherwise.
+        // 9. Create own properties of M corresponding to the definitions in 28.3.
+        let to_string_tag = if deferred {
+            js_string!("Deferred Module")
+        } else {
+            js_string!("Module")
+        };
+        let namespace = context.intrinsics().templates().namespace().create(
+            Self {
+                module,
+                exports,
+                deferred,
+            },
+            vec![to_string_tag.into()],
+        );
 
         // 9. Set module.[[Namespace]] to M.
         // Ignored because this is done by `Module::namespace`
@@ -90,6 +106,59 @@ impl ModuleNamespace {
     pub(crate) const fn module(&self) -> &Module {
         &self.module
     }
+
+    /// Returns `true` if this is a deferred Module Namespace object.
+    pub(crate) const fn is_deferred(&self) -> bool {
+        self.deferred
+    }
+}
+
+/// Abstract operation [`IsSymbolLikeNamespaceKey ( P, O )`][spec].
+///
+/// Returns the export name for `key`, or `None` if `key` must be handled as an ordinary property
+/// of the namespace.
+///
+/// [spec]: https://tc39.es/proposal-defer-import-eval/#sec-IsSymbolLikeNamespaceKey
+fn namespace_export_key(obj: &JsObject, key: &PropertyKey) -> Option<JsString> {
+    let key = match key {
+        // 1. If P is a Symbol, return true.
+        PropertyKey::Symbol(_) => return None,
+        PropertyKey::Index(idx) => js_string!(format!("{}", idx.get())),
+        PropertyKey::String(s) => s.clone(),
+    };
+
+    let deferred = obj
+        .downcast_ref::<ModuleNamespace>()
+        .expect("internal method can only be called on module namespace objects")
+        .is_deferred();
+
+    // 2. If ns.[[Deferred]] is true and P is "then", return true.
+    // 3. Return false.
+    (!deferred || key != js_str!("then")).then_some(key)
+}
+
+/// Abstract operation [`EnsureDeferredNamespaceEvaluation ( O )`][spec].
+///
+/// Evaluates the module of a deferred namespace, if it wasn't evaluated yet.
+///
+/// [spec]: https://tc39.es/proposal-defer-import-eval/#sec-EnsureDeferredNamespaceEvaluation
+fn ensure_deferred_namespace_evaluation(obj: &JsObject, context: &mut Context) -> JsResult<()> {
+    let module = {
+        let obj = obj
+            .downcast_ref::<ModuleNamespace>()
+            .expect("internal method can only be called on module namespace objects");
+
+        // 1. If O.[[Deferred]] is false, return unused.
+        if !obj.is_deferred() {
+            return Ok(());
+        }
+
+        // 2. Let m be O.[[Module]].
+        obj.module().clone()
+    };
+
+    // 3. Perform ? EvaluateModuleSync(m).
+    module.evaluate_sync(context)
 }
 
 /// [`[[GetPrototypeOf]] ( )`][spec].
@@ -145,13 +214,13 @@ fn module_namespace_exotic_get_own_property(
     key: &PropertyKey,
     context: &mut InternalMethodContext<'_>,
 ) -> JsResult<Option<PropertyDescriptor>> {
-    // 1. If P is a Symbol, return OrdinaryGetOwnProperty(O, P).
-    let key = match key {
-        PropertyKey::Symbol(_) => return ordinary_get_own_property(obj, key, context),
-        PropertyKey::Index(idx) => js_string!(format!("{}", idx.get())),
-        PropertyKey::String(s) => s.clone(),
+    // 1. If IsSymbolLikeNamespaceKey(P, O) is true, return OrdinaryGetOwnProperty(O, P).
+    let Some(key) = namespace_export_key(obj, key) else {
+        return ordinary_get_own_property(obj, key, context);
     };
 
+    ensure_deferred_namespace_evaluation(obj, context)?;
+
     {
         let obj = obj
             .downcast_ref::<ModuleNamespace>()
@@ -188,8 +257,8 @@ fn module_namespace_exotic_define_own_property(
     desc: PropertyDescriptor,
     context: &mut InternalMethodContext<'_>,
 ) -> JsResult<bool> {
-    // 1. If P is a Symbol, return ! OrdinaryDefineOwnProperty(O, P, Desc).
-    if let PropertyKey::Symbol(_) = key {
+    // 1. If IsSymbolLikeNamespaceKey(P, O) is true, return ! OrdinaryDefineOwnProperty(O, P, Desc).
+    if namespace_export_key(obj, key).is_none() {
         return ordinary_define_own_property(obj, key, desc, context);
     }
 
@@ -224,13 +293,13 @@ fn module_namespace_exotic_has_property(
     key: &PropertyKey,
     context: &mut InternalMethodContext<'_>,
 ) -> JsResult<bool> {
-    // 1. If P is a Symbol, return ! OrdinaryHasProperty(O, P).
-    let key = match key {
-        PropertyKey::Symbol(_) => return ordinary_has_property(obj, key, context),
-        PropertyKey::Index(idx) => js_string!(format!("{}", idx.get())),
-        PropertyKey::String(s) => s.clone(),
+    // 1. If IsSymbolLikeNamespaceKey(P, O) is true, return ! OrdinaryHasProperty(O, P).
+    let Some(key) = namespace_export_key(obj, key) else {
+        return ordinary_has_property(obj, key, context);
     };
 
+    ensure_deferred_namespace_evaluation(obj, context)?;
+
     let obj = obj
         .downcast_ref::<ModuleNamespace>()
         .expect("internal method can only be called on module namespace objects");
@@ -259,14 +328,14 @@ fn module_namespace_exotic_try_get(
     receiver: JsValue,
     context: &mut InternalMethodContext<'_>,
 ) -> JsResult<Option<JsValue>> {
-    // 1. If P is a Symbol, then
+    // 1. If IsSymbolLikeNamespaceKey(P, O) is true, then
     //     a. Return ! OrdinaryGet(O, P, Receiver).
-    let key = match key {
-        PropertyKey::Symbol(_) => return ordinary_try_get(obj, key, receiver, context),
-        PropertyKey::Index(idx) => js_string!(format!("{}", idx.get())),
-        PropertyKey::String(s) => s.clone(),
+    let Some(key) = namespace_export_key(obj, key) else {
+        return ordinary_try_get(obj, key, receiver, context);
     };
 
+    ensure_deferred_namespace_evaluation(obj, context)?;
+
     let obj = obj
         .downcast_ref::<ModuleNamespace>()
         .expect("internal method can only be called on module namespace objects");
@@ -340,14 +409,14 @@ fn module_namespace_exotic_get(
     receiver: JsValue,
     context: &mut InternalMethodContext<'_>,
 ) -> JsResult<JsValue> {
-    // 1. If P is a Symbol, then
+    // 1. If IsSymbolLikeNamespaceKey(P, O) is true, then
     //     a. Return ! OrdinaryGet(O, P, Receiver).
-    let key = match key {
-        PropertyKey::Symbol(_) => return ordinary_get(obj, key, receiver, context),
-        PropertyKey::Index(idx) => js_string!(format!("{}", idx.get())),
-        PropertyKey::String(s) => s.clone(),
+    let Some(key) = namespace_export_key(obj, key) else {
+        return ordinary_get(obj, key, receiver, context);
     };
 
+    ensure_deferred_namespace_evaluation(obj, context)?;
+
     let obj = obj
         .downcast_ref::<ModuleNamespace>()
         .expect("internal method can only be called on module namespace objects");
@@ -434,14 +503,14 @@ fn module_namespace_exotic_delete(
     key: &PropertyKey,
     context: &mut InternalMethodContext<'_>,
 ) -> JsResult<bool> {
-    // 1. If P is a Symbol, then
+    // 1. If IsSymbolLikeNamespaceKey(P, O) is true, then
     //     a. Return ! OrdinaryDelete(O, P).
-    let key = match key {
-        PropertyKey::Symbol(_) => return ordinary_delete(obj, key, context),
-        PropertyKey::Index(idx) => js_string!(format!("{}", idx.get())),
-        PropertyKey::String(s) => s.clone(),
+    let Some(key) = namespace_export_key(obj, key) else {
+        return ordinary_delete(obj, key, context);
     };
 
+    ensure_deferred_namespace_evaluation(obj, context)?;
+
     let obj = obj
         .downcast_ref::<ModuleNamespace>()
         .expect("internal method can only be called on module namespace objects");
@@ -461,6 +530,9 @@ fn module_namespace_exotic_own_property_keys(
     obj: &JsObject,
     context: &mut Context,
 ) -> JsResult<Vec<PropertyKey>> {
+    // 1. Let exports be ? GetModuleExportsList(O).
+    ensure_deferred_namespace_evaluation(obj, context)?;
+
     // 2. Let symbolKeys be OrdinaryOwnPropertyKeys(O).
     let symbol_keys = ordinary_own_property_keys(obj, context)?;
 
@@ -468,7 +540,6 @@ fn module_namespace_exotic_own_property_keys(
         .downcast_ref::<ModuleNamespace>()
         .expect("internal method can only be called on module namespace objects");
 
-    // 1. Let exports be O.[[Exports]].
     let exports = obj.exports();
 
     // 3. Return the list-concatenation of exports and symbolKeys.
@@ -34,8 +34,8 @@ use crate::{
 };
 
 use super::{
-    BindingName, GraphLoadingState, Module, Referrer, ResolveExportError, ResolvedBinding,
-    SourceText,
+    BindingName, GraphLoadingState, Module, ModuleState, Referrer, ResolveExportError,
+    ResolvedBinding, SourceText,
 };
 
 /// Information for the [**Depth-first search**] algorithm used in the
@@ -233,6 +233,8 @@ impl std::fmt::Debug for SourceTextModule {
 struct ModuleCode {
     has_tla: bool,
     requested_modules: IndexSet<JsString, BuildHasherDefault<FxHasher>>,
+    linking_requests: IndexSet<JsString, BuildHasherDefault<FxHasher>>,
+    evaluation_requests: IndexSet<JsString, BuildHasherDefault<FxHasher>>,
     source: boa_ast::Module,
     source_text: SourceText,
     import_entries: Vec<ImportEntry>,
@@ -255,6 +257,21 @@ impl SourceTextModule {
             .iter()
             .map(|name| name.to_js_string(interner))
             .collect();
+        // NOTE: Modules that are only imported in the source phase are loaded, but never linked
+        //       nor evaluated, and modules that are only imported with deferred imports are
+        //       linked, but evaluated on the first access to their namespace.
+        let linking_requests = code
+            .items()
+            .linking_requests()
+            .iter()
+            .map(|name| name.to_js_string(interner))
+            .collect();
+        let evaluation_requests = code
+            .items()
+            .evaluation_requests()
+            .iter()
+            .map(|name| name.to_js_string(interner))
+            .collect();
         // 4. Let importEntries be ImportEntries of body.
         let import_entries = code.items().import_entries();
 
@@ -340,6 +357,8 @@ impl SourceTextModule {
                 source: code,
                 source_text,
                 requested_modules,
+                linking_requests,
+                evaluation_requests,
                 has_tla,
                 import_entries,
                 local_export_entries,
@@ -386,7 +405,7 @@ impl SourceTextModule {
                     let name_specifier = required.clone();
                     let src = module_self.clone();
                     let state = state.clone();
-                    context.module_loader().load_imported_module(
+                    context.load_imported_module(
                         Referrer::Module(module_self.clone()),
                         name_specifier,
                         Box::new(move |completion, context| {
@@ -448,7 +467,6 @@ impl SourceTextModule {
 
                             // 4. Return unused.
                         }),
-                        context,
                     );
                 }
                 // iii. If state.[[IsLoading]] is false, return unused.
@@ -739,7 +757,7 @@ impl SourceTextModule {
 
         // 9. For each String required of module.[[RequestedModules]], do
 
-        for required in &self.code.requested_modules {
+        for required in &self.code.linking_requests {
             // a. Let requiredModule be GetImportedModule(module, required).
             let required_module = self.loaded_modules.borrow()[required].clone();
 
@@ -856,6 +874,79 @@ impl SourceTextModule {
         Ok(index)
     }
 
+    /// Abstract operation [`ReadyForSyncExecution ( module [ , seen ] )`][spec].
+    ///
+    /// [spec]: https://tc39.es/proposal-defer-import-eval/#sec-ReadyForSyncExecution
+    pub(super) fn ready_for_sync_execution(
+        &self,
+        module_self: &Module,
+        seen: &mut FxHashSet<Module>,
+    ) -> bool {
+        // 2. If seen contains module, return true.
+        // 3. Append module to seen.
+        if !seen.insert(module_self.clone()) {
+            return true;
+        }
+
+        match &*self.status.borrow() {
+            // 4. If module.[[Status]] is evaluated, return true.
+            ModuleStatus::Evaluated { .. } => return true,
+            // 5. If module.[[Status]] is either evaluating or evaluating-async, return false.
+            ModuleStatus::Evaluating { .. } | ModuleStatus::EvaluatingAsync { .. } => return false,
+            // 6. Assert: module.[[Status]] is linked.
+            ModuleStatus::Linked { .. } => {}
+            _ => unreachable!("6. Assert: module.[[Status]] is linked."),
+        }
+
+        // 7. If module.[[HasTLA]] is true, return false.
+        if self.code.has_tla {
+            return false;
+        }
+
+        // 8. For each ModuleRequest Record required of module.[[RequestedModules]], do
+        self.code.linking_requests.iter().all(|required| {
+            // a. Let requiredModule be GetImportedModule(module, required).
+            let required_module = self.loaded_modules.borrow()[required].clone();
+            // b. If ReadyForSyncExecution(requiredModule, seen) is false, then
+            //     i. Return false.
+            required_module.ready_for_sync_execution(seen)
+        })
+        // 9. Return true.
+    }
+
+    /// Gets the result of the evaluation of this module, or `None` if the module has not been
+    /// evaluated yet.
+    pub(super) fn evaluation_result(&self) -> Option<JsResult<()>> {
+        match &*self.status.borrow() {
+            ModuleStatus::Evaluated { error, .. } => Some(error.clone().map_or(Ok(()), Err)),
+            _ => None,
+        }
+    }
+
+    /// Gets the state of this module for a [`ModuleGraph`](super::ModuleGraph).
+    pub(super) fn graph_state(&self) -> ModuleState {
+        match &*self.status.borrow() {
+            ModuleStatus::Unlinked => ModuleState::Unlinked,
+            ModuleStatus::Linking { .. } => ModuleState::Linking,
+            ModuleStatus::PreLinked { .. } | ModuleStatus::Linked { .. } => ModuleState::Linked,
+            ModuleStatus::Evaluating { .. } => ModuleState::Evaluating,
+            ModuleStatus::EvaluatingAsync { .. } => ModuleState::EvaluatingAsync,
+            ModuleStatus::Evaluated { error: None, .. } => ModuleState::Evaluated,
+            ModuleStatus::Evaluated { error: Some(_), .. } => ModuleState::Errored,
+        }
+    }
+
+    /// Gets the specifiers requested by this module, in order, with the modules they were
+    /// loaded as, if they were loaded.
+    pub(super) fn dependencies(&self) -> Vec<(JsString, Option<Module>)> {
+        let loaded_modules = self.loaded_modules.borrow();
+        self.code
+            .requested_modules
+            .iter()
+            .map(|specifier| (specifier.clone(), loaded_modules.get(specifier).cloned()))
+            .collect()
+    }
+
     /// Concrete method [`Evaluate ( )`][spec].
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-moduleevaluation
@@ -1068,9 +1159,20 @@ impl SourceTextModule {
         stack.push(module_self.clone());
 
         // 11. For each String required of module.[[RequestedModules]], do
-        for required in &self.code.requested_modules {
+        for required in &self.code.linking_requests {
             // a. Let requiredModule be GetImportedModule(module, required).
             let required_module = self.loaded_modules.borrow()[required].clone();
+
+            // NOTE: Modules that are only imported with deferred imports are evaluated on the
+            //       first access to their namespace, which requires them to be evaluated
+            //       synchronously. Modules that cannot be evaluated synchronously, because they
+            //       or their dependencies use top-level await or are already being evaluated,
+            //       are evaluated eagerly instead.
+            if !self.code.evaluation_requests.contains(required)
+                && required_module.ready_for_sync_execution(&mut FxHashSet::default())
+            {
+                continue;
+            }
             // b. Set index to ? InnerModuleEvaluation(requiredModule, stack, index).
             index = required_module.inner_evaluate(stack, index, context)?;
 
@@ -1387,6 +1489,14 @@ impl SourceTextModule {
                 locator: BindingLocator,
                 export_locator: ResolvedBinding,
             },
+            Source {
+                locator: BindingLocator,
+                source: JsObject,
+            },
+            DeferredNamespace {
+                locator: BindingLocator,
+                module: Module,
+            },
         }
 
         {
@@ -1453,59 +1563,92 @@ impl SourceTextModule {
                 let module_request = entry.module_request().to_js_string(compiler.interner());
                 let imported_module = self.loaded_modules.borrow()[&module_request].clone();
 
-                if let ImportName::Name(name) = entry.import_name() {
-                    let name = name.to_js_string(compiler.interner());
-                    // c. Else,
-                    //    i. Let resolution be importedModule.ResolveExport(in.[[ImportName]]).
-                    let resolution = imported_module
-                        .resolve_export(name.clone(), &mut HashSet::default(), compiler.interner())
-                        // ii. If resolution is either null or ambiguous, throw a SyntaxError exception.
-                        .map_err(|err| match err {
-                            ResolveExportError::NotFound => JsNativeError::syntax().with_message(
-                                format!("could not find export `{}`", name.to_std_string_escaped()),
-                            ),
-                            ResolveExportError::Ambiguous => {
-                                JsNativeError::syntax().with_message(format!(
-                                    "could not resolve ambiguous export `{}`",
-                                    name.to_std_string_escaped()
-                                ))
-                            }
-                        })?;
-
-                    // 2. Perform ! env.CreateImmutableBinding(in.[[LocalName]], true).
-                    // 3. Perform ! env.InitializeBinding(in.[[LocalName]], namespace).
-                    let local_name = entry.local_name().to_js_string(compiler.interner());
-                    let locator = env.get_binding(&local_name).expect("binding must exist");
-
-                    if let BindingName::Name(_) = resolution.binding_name {
-                        // 1. Perform env.CreateImportBinding(in.[[LocalName]], resolution.[[Module]],
-                        //    resolution.[[BindingName]]).
-                        //    deferred to initialization below
-                        imports.push(ImportBinding::Single {
+                match entry.import_name() {
+                    ImportName::Name(name) => {
+                        let name = name.to_js_string(compiler.interner());
+                        // c. Else,
+                        //    i. Let resolution be importedModule.ResolveExport(in.[[ImportName]]).
+                        let resolution = imported_module
+                            .resolve_export(
+                                name.clone(),
+                                &mut HashSet::default(),
+                                compiler.interner(),
+                            )
+                            // ii. If resolution is either null or ambiguous, throw a SyntaxError exception.
+                            .map_err(|err| match err {
+                                ResolveExportError::NotFound => JsNativeError::syntax()
+                                    .with_message(format!(
+                                        "could not find export `{}`",
+                                        name.to_std_string_escaped()
+                                    )),
+                                ResolveExportError::Ambiguous => JsNativeError::syntax()
+                                    .with_message(format!(
+                                        "could not resolve ambiguous export `{}`",
+                                        name.to_std_string_escaped()
+                                    )),
+                            })?;
+
+                        // 2. Perform ! env.CreateImmutableBinding(in.[[LocalName]], true).
+                        // 3. Perform ! env.InitializeBinding(in.[[LocalName]], namespace).
+                        let local_name = entry.local_name().to_js_string(compiler.interner());
+                        let locator = env.get_binding(&local_name).expect("binding must exist");
+
+                        if let BindingName::Name(_) = resolution.binding_name {
+                            // 1. Perform env.CreateImportBinding(in.[[LocalName]], resolution.[[Module]],
+                            //    resolution.[[BindingName]]).
+                            //    deferred to initialization below
+                            imports.push(ImportBinding::Single {
+                                locator,
+                                export_locator: resolution,
+                            });
+                        } else {
+                            // 1. Let namespace be GetModuleNamespace(resolution.[[Module]]).
+                            // deferred to initialization below
+                            imports.push(ImportBinding::Namespace {
+                                locator,
+                                module: resolution.module,
+                            });
+                        }
+                    }
+                    ImportName::Source => {
+                        // c. Else if in.[[ImportName]] is source, then
+                        //    i. Let moduleSource be ? importedModule.GetModuleSource().
+                        let source = imported_module.get_module_source()?;
+
+                        //    ii. Perform ! env.CreateImmutableBinding(in.[[LocalName]], true).
+                        //    iii. Perform ! env.InitializeBinding(in.[[LocalName]], moduleSource).
+                        let name = entry.local_name().to_js_string(compiler.interner());
+                        let locator = env.get_binding(&name).expect("binding must exist");
+                        imports.push(ImportBinding::Source { locator, source });
+                    }
+                    ImportName::DeferredNamespace => {
+                        // b. Else if in.[[ImportName]] is namespace-object and in.[[Phase]] is defer, then
+                        //    ii. Perform ! env.CreateImmutableBinding(in.[[LocalName]], true).
+                        //    iii. Perform ! env.InitializeBinding(in.[[LocalName]], namespace).
+                        let name = entry.local_name().to_js_string(compiler.interner());
+                        let locator = env.get_binding(&name).expect("binding must exist");
+
+                        //    i. Let namespace be GetModuleNamespace(importedModule, defer).
+                        //       deferred to initialization below
+                        imports.push(ImportBinding::DeferredNamespace {
                             locator,
-                            export_locator: resolution,
+                            module: imported_module.clone(),
                         });
-                    } else {
-                        // 1. Let namespace be GetModuleNamespace(resolution.[[Module]]).
-                        // deferred to initialization below
+                    }
+                    ImportName::Namespace => {
+                        // b. If in.[[ImportName]] is namespace-object, then
+                        //    ii. Perform ! env.CreateImmutableBinding(in.[[LocalName]], true).
+                        //    iii. Perform ! env.InitializeBinding(in.[[LocalName]], namespace).
+                        let name = entry.local_name().to_js_string(compiler.interner());
+                        let locator = env.get_binding(&name).expect("binding must exist");
+
+                        //    i. Let namespace be GetModuleNamespace(importedModule).
+                        //       deferred to initialization below
                         imports.push(ImportBinding::Namespace {
                             locator,
-                            module: resolution.module,
+                            module: imported_module.clone(),
                         });
                     }
-                } else {
-                    // b. If in.[[ImportName]] is namespace-object, then
-                    //    ii. Perform ! env.CreateImmutableBinding(in.[[LocalName]], true).
-                    //    iii. Perform ! env.InitializeBinding(in.[[LocalName]], namespace).
-                    let name = entry.local_name().to_js_string(compiler.interner());
-                    let locator = env.get_binding(&name).expect("binding must exist");
-
-                    //    i. Let namespace be GetModuleNamespace(importedModule).
-                    //       deferred to initialization below
-                    imports.push(ImportBinding::Namespace {
-                        locator,
-                        module: imported_module.clone(),
-                    });
                 }
             }
 
@@ -1632,6 +1775,22 @@ impl SourceTextModule {
         // deferred initialization of import bindings
         for import in imports {
             match import {
+                ImportBinding::DeferredNamespace { locator, module } => {
+                    // i. Let namespace be GetModuleNamespace(importedModule, defer).
+                    let namespace = module.deferred_namespace(context);
+                    context.vm.environments.put_lexical_value(
+                        locator.scope(),
+                        locator.binding_index(),
+                        namespace.into(),
+                    );
+                }
+                ImportBinding::Source { locator, source } => {
+                    context.vm.environments.put_lexical_value(
+                        locator.scope(),
+                        locator.binding_index(),
+                        source.into(),
+                    );
+                }
                 ImportBinding::Namespace { locator, module } => {
                     // i. Let namespace be GetModuleNamespace(importedModule).
                     let namespace = module.namespace(context);
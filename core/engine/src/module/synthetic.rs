@@ -2,9 +2,9 @@ use boa_ast::scope::Scope;
 use boa_gc::{Finalize, Gc, GcRefCell, Trace};
 use rustc_hash::FxHashSet;
 
-use super::{BindingName, ResolveExportError, ResolvedBinding};
+use super::{BindingName, ModuleState, ResolveExportError, ResolvedBinding};
 use crate::{
-    builtins::promise::ResolvingFunctions,
+    builtins::promise::{PromiseState, ResolvingFunctions},
     bytecompiler::ByteCompiler,
     class::{Class, ClassBuilder},
     environments::{DeclarativeEnvironment, EnvironmentStack},
@@ -253,6 +253,18 @@ impl SyntheticModule {
         }
     }
 
+    /// Gets the state of this module for a [`ModuleGraph`](super::ModuleGraph).
+    pub(super) fn graph_state(&self) -> ModuleState {
+        match &*self.state.borrow() {
+            ModuleStatus::Unlinked => ModuleState::Unlinked,
+            ModuleStatus::Linked { .. } => ModuleState::Linked,
+            ModuleStatus::Evaluated { promise, .. } => match promise.state() {
+                PromiseState::Rejected(_) => ModuleState::Errored,
+                _ => ModuleState::Evaluated,
+            },
+        }
+    }
+
     /// Concrete method [`LoadRequestedModules ( )`][spec].
     ///
     /// [spec]: https://tc39.es/proposal-json-modules/#sec-smr-LoadRequestedModules
@@ -20,7 +20,7 @@ use crate::{
         FunctionObjectBuilder, JsData, JsFunction, JsPromise,
     },
     realm::Realm,
-    Context, JsNativeError, JsObject, JsResult, JsValue,
+    Context, IntoJsFunctionCopied, JsNativeError, JsObject, JsResult, JsValue,
 };
 
 #[cfg(feature = "experimental")]
@@ -327,6 +327,48 @@ impl NativeFunction {
         unsafe { Self::from_closure_with_captures(closure, captures) }
     }
 
+    /// Creates a `NativeFunction` from a `Copy` Rust function with typed arguments and return
+    /// value.
+    ///
+    /// Arguments are converted with [`TryFromJsArgument`], and the return value with
+    /// [`TryIntoJsResult`]. Conversion errors thrown by a positional argument include the position
+    /// of the argument in their message. The `length` of the function can be obtained from
+    /// [`IntoJsFunctionCopied::LENGTH`], or the function can be registered directly with
+    /// [`Context::register_global_typed_callable`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use boa_engine::{Context, JsResult, JsString, JsValue, NativeFunction, js_string};
+    /// # let context = &mut Context::default();
+    /// let repeat = NativeFunction::from_typed(|s: String, n: Option<f64>| -> JsResult<JsString> {
+    ///     Ok(JsString::from(s.repeat(n.unwrap_or(1.0) as usize)))
+    /// });
+    ///
+    /// let result = repeat
+    ///     .call(
+    ///         &JsValue::undefined(),
+    ///         &[js_string!("ab").into(), JsValue::new(2)],
+    ///         context,
+    ///     )
+    ///     .unwrap();
+    /// assert_eq!(result, JsValue::from(js_string!("abab")));
+    ///
+    /// let error = repeat
+    ///     .call(&JsValue::undefined(), &[JsValue::new(1)], context)
+    ///     .unwrap_err();
+    /// assert!(error.to_string().contains("argument 1"));
+    /// ```
+    ///
+    /// [`TryFromJsArgument`]: crate::interop::TryFromJsArgument
+    /// [`TryIntoJsResult`]: crate::TryIntoJsResult
+    pub fn from_typed<F, Args, Ret>(function: F) -> Self
+    where
+        F: IntoJsFunctionCopied<Args, Ret>,
+    {
+        function.into_native_function()
+    }
+
     /// Creates a new `NativeFunction` from a closure.
     ///
     /// # Safety
@@ -410,16 +452,17 @@ pub(crate) fn native_function_call(
     argument_count: usize,
     context: &mut Context,
 ) -> JsResult<CallValue> {
-    let args = context
-        .vm
-        .stack
-        .calling_convention_pop_arguments(argument_count);
+    let args = context.vm.take_arguments(argument_count);
     let _func = context.vm.stack.pop();
     let this = context.vm.stack.pop();
 
+    // The function can only suspend the script if it is the callee of a call instruction.
+    let suspendable = context.vm.suspension.call.take() == Some(context.vm.stack.len());
+
     // We technically don't need this since native functions don't push any new frames to the
     // vm, but we'll eventually have to combine the native stack with the vm stack.
     context.check_runtime_limits()?;
+    context.check_pure_call(obj)?;
     let this_function_object = obj.clone();
 
     let NativeFunctionObject {
@@ -435,6 +478,7 @@ pub(crate) fn native_function_call(
 
     context.swap_realm(&mut realm);
     context.vm.native_active_function = Some(this_function_object);
+    let outer_suspendable = std::mem::replace(&mut context.vm.suspension.native, suspendable);
 
     let result = if constructor.is_some() {
         function.call(&JsValue::undefined(), &args, context)
@@ -443,8 +487,13 @@ pub(crate) fn native_function_call(
     }
     .map_err(|err| err.inject_realm(context.realm().clone()));
 
+    context.vm.suspension.native = outer_suspendable;
+    if suspendable && result.is_err() {
+        context.vm.suspension.requested = false;
+    }
     context.vm.native_active_function = None;
     context.swap_realm(&mut realm);
+    context.vm.recycle_arguments(args);
 
     context.vm.stack.push(result?);
 
@@ -465,6 +514,7 @@ fn native_function_construct(
     // We technically don't need this since native functions don't push any new frames to the
     // vm, but we'll eventually have to combine the native stack with the vm stack.
     context.check_runtime_limits()?;
+    context.check_pure_call(obj)?;
     let this_function_object = obj.clone();
 
     let NativeFunctionObject {
@@ -482,10 +532,7 @@ fn native_function_construct(
     context.vm.native_active_function = Some(this_function_object);
 
     let new_target = context.vm.stack.pop();
-    let args = context
-        .vm
-        .stack
-        .calling_convention_pop_arguments(argument_count);
+    let args = context.vm.take_arguments(argument_count);
     let _func = context.vm.stack.pop();
     let _this = context.vm.stack.pop();
 
@@ -516,6 +563,7 @@ fn native_function_construct(
 
     context.vm.native_active_function = None;
     context.swap_realm(&mut realm);
+    context.vm.recycle_arguments(args);
 
     context.vm.stack.push(result?);
 
@@ -222,6 +222,68 @@ impl JsArrayBuffer {
             })
     }
 
+    /// Detaches this `JsArrayBuffer` and moves its data into a new `ArrayBuffer` created in
+    /// `context`, without copying it.
+    ///
+    /// This allows moving buffers between isolated contexts. The maximum byte length of a
+    /// resizable buffer is preserved.
+    ///
+    /// # Errors
+    ///
+    /// Throws a `TypeError` if the buffer is already detached, or if it has a detach key other
+    /// than `undefined`.
+    ///
+    /// # Note
+    ///
+    /// `JsArrayBuffer`s cannot be sent to other threads. To move the data across threads, use
+    /// [`JsArrayBuffer::detach`] and [`JsArrayBuffer::from_byte_block`] instead.
+    ///
+    /// ```
+    /// # use boa_engine::{
+    /// # object::builtins::JsArrayBuffer,
+    /// # Context, JsResult,
+    /// # };
+    /// # fn main() -> JsResult<()> {
+    /// let source = &mut Context::default();
+    /// let target = &mut Context::default();
+    ///
+    /// let data_block: Vec<u8> = (0..5).collect();
+    /// let array_buffer = JsArrayBuffer::from_byte_block(data_block, source)?;
+    ///
+    /// // Move the buffer to the other context.
+    /// let transferred = array_buffer.transfer_to(target)?;
+    ///
+    /// assert_eq!(
+    ///     transferred.data().as_deref(),
+    ///     Some((0..5).collect::<Vec<u8>>().as_slice())
+    /// );
+    ///
+    /// // The original buffer is now detached.
+    /// assert!(array_buffer.data().is_none());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn transfer_to(&self, context: &mut Context) -> JsResult<Self> {
+        // Create the new buffer first, so that the data is not lost if its creation fails.
+        let transferred = Self::from_byte_block(Vec::new(), context)?;
+
+        let max_byte_len = self.inner.borrow().data.max_byte_len();
+        let block = self.detach(&JsValue::undefined())?;
+
+        {
+            let mut buffer = transferred.inner.borrow_mut();
+            *buffer
+                .data
+                .vec_mut()
+                .expect("newly created buffer cannot be detached") = block;
+            if let Some(max_byte_len) = max_byte_len {
+                buffer.data.set_max_byte_length(max_byte_len);
+            }
+        }
+
+        Ok(transferred)
+    }
+
     /// Get an immutable reference to the [`JsArrayBuffer`]'s data.
     ///
     /// Returns `None` if detached.
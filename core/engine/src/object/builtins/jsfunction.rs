@@ -1,9 +1,12 @@
 //! A Rust API wrapper for Boa's `Function` Builtin ECMAScript Object
 use crate::{
-    builtins::function::ConstructorKind, native_function::NativeFunctionObject, object::JsObject,
-    value::TryFromJs, Context, JsNativeError, JsResult, JsValue, NativeFunction, TryIntoJsResult,
+    builtins::function::ConstructorKind,
+    native_function::NativeFunctionObject,
+    object::{ErasedVTableObject, JsObject},
+    value::TryFromJs,
+    Context, JsNativeError, JsResult, JsValue, NativeFunction, TryIntoJsResult,
 };
-use boa_gc::{Finalize, Trace};
+use boa_gc::{Finalize, Trace, WeakGc};
 use std::marker::PhantomData;
 use std::ops::Deref;
 
@@ -157,6 +160,71 @@ impl JsFunction {
             _ret: PhantomData,
         }
     }
+
+    /// Creates a [`WeakJsFunction`] pointing to this function, which doesn't keep it alive.
+    #[inline]
+    #[must_use]
+    pub fn downgrade(&self) -> WeakJsFunction {
+        WeakJsFunction {
+            inner: WeakGc::new(self.inner.inner()),
+        }
+    }
+}
+
+/// A weak reference to a [`JsFunction`].
+///
+/// Unlike [`JsFunction`], this doesn't keep the function alive across garbage collections, which
+/// makes it suitable for hosts that store callbacks registered by scripts (e.g. event listeners)
+/// without leaking them after the script, or its realm, drops every reference to them.
+///
+/// # Example
+///
+/// ```
+/// use boa_engine::{object::builtins::JsFunction, Context, JsValue, Source};
+///
+/// let context = &mut Context::default();
+///
+/// let function = context
+///     .eval(Source::from_bytes("(x) => x * 2"))
+///     .unwrap()
+///     .as_function()
+///     .unwrap();
+/// let weak = function.downgrade();
+///
+/// let function = weak.upgrade().expect("the function is still alive");
+/// assert_eq!(
+///     function.call(&JsValue::undefined(), &[21.into()], context),
+///     Ok(42.into())
+/// );
+/// ```
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct WeakJsFunction {
+    inner: WeakGc<ErasedVTableObject>,
+}
+
+impl WeakJsFunction {
+    /// Returns the referenced [`JsFunction`], or `None` if it was already garbage collected.
+    #[inline]
+    #[must_use]
+    pub fn upgrade(&self) -> Option<JsFunction> {
+        self.inner
+            .upgrade()
+            .map(|inner| JsFunction::from_object_unchecked(JsObject::from(inner)))
+    }
+
+    /// Returns `true` if the referenced function hasn't been garbage collected yet.
+    #[inline]
+    #[must_use]
+    pub fn is_alive(&self) -> bool {
+        self.inner.is_upgradable()
+    }
+}
+
+impl From<&JsFunction> for WeakJsFunction {
+    #[inline]
+    fn from(function: &JsFunction) -> Self {
+        function.downgrade()
+    }
 }
 
 impl From<JsFunction> for JsObject {
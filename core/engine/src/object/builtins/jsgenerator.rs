@@ -1,12 +1,55 @@
 //! A Rust API wrapper for Boa's `Generator` Builtin ECMAScript Object
 use crate::{
-    builtins::generator::Generator, object::JsObject, value::TryFromJs, Context, JsNativeError,
-    JsResult, JsValue,
+    builtins::{
+        generator::{Generator, GeneratorState},
+        iterable::IteratorResult,
+    },
+    object::JsObject,
+    value::TryFromJs,
+    Context, JsError, JsNativeError, JsResult, JsValue,
 };
 
 use boa_gc::{Finalize, Trace};
 use std::ops::Deref;
 
+/// The outcome of resuming a [`JsGenerator`] from Rust.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub enum GeneratorStep {
+    /// The generator yielded a value, and can be resumed again.
+    Yield(JsValue),
+
+    /// The generator finished, returning a value.
+    Return(JsValue),
+}
+
+impl GeneratorStep {
+    /// Gets the yielded or returned value.
+    #[inline]
+    #[must_use]
+    pub const fn value(&self) -> &JsValue {
+        match self {
+            Self::Yield(value) | Self::Return(value) => value,
+        }
+    }
+
+    /// Returns `true` if the generator finished.
+    #[inline]
+    #[must_use]
+    pub const fn is_return(&self) -> bool {
+        matches!(self, Self::Return(_))
+    }
+
+    fn from_iter_result(result: JsValue, context: &mut Context) -> JsResult<Self> {
+        let result = IteratorResult::from_value(result)?;
+        let value = result.value(context)?;
+        if result.complete(context)? {
+            Ok(Self::Return(value))
+        } else {
+            Ok(Self::Yield(value))
+        }
+    }
+}
+
 /// `JsGenerator` provides a wrapper for Boa's implementation of the ECMAScript `Generator` builtin object
 #[derive(Debug, Clone, Trace, Finalize)]
 pub struct JsGenerator {
@@ -56,6 +99,68 @@ impl JsGenerator {
     {
         Generator::throw(&self.inner.clone().into(), &[value.into()], context)
     }
+
+    /// Resumes the generator with `value` as the result of the suspended `yield` expression.
+    ///
+    /// This is equivalent to [`JsGenerator::next`], but returns the step of the generator
+    /// instead of an iterator result object.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error thrown by the generator body, which also finishes the generator.
+    pub fn resume<T>(&self, value: T, context: &mut Context) -> JsResult<GeneratorStep>
+    where
+        T: Into<JsValue>,
+    {
+        let result =
+            Generator::generator_resume(&self.inner.clone().into(), value.into(), context)?;
+        GeneratorStep::from_iter_result(result, context)
+    }
+
+    /// Resumes the generator by throwing `error` from the suspended `yield` expression.
+    ///
+    /// This is equivalent to [`JsGenerator::throw`], but returns the step of the generator
+    /// instead of an iterator result object.
+    ///
+    /// # Errors
+    ///
+    /// Returns `error` if the generator does not catch it, or any other error thrown by the
+    /// generator body.
+    pub fn resume_throw(&self, error: JsError, context: &mut Context) -> JsResult<GeneratorStep> {
+        let result =
+            Generator::generator_resume_abrupt(&self.inner.clone().into(), Err(error), context)?;
+        GeneratorStep::from_iter_result(result, context)
+    }
+
+    /// Resumes the generator by returning `value` from the suspended `yield` expression,
+    /// running any pending `finally` blocks.
+    ///
+    /// This is equivalent to [`JsGenerator::r#return`], but returns the step of the generator
+    /// instead of an iterator result object.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error thrown by the `finally` blocks of the generator body.
+    pub fn resume_return<T>(&self, value: T, context: &mut Context) -> JsResult<GeneratorStep>
+    where
+        T: Into<JsValue>,
+    {
+        let result = Generator::generator_resume_abrupt(
+            &self.inner.clone().into(),
+            Ok(value.into()),
+            context,
+        )?;
+        GeneratorStep::from_iter_result(result, context)
+    }
+
+    /// Returns `true` if the generator has finished, and won't run again when resumed.
+    #[inline]
+    #[must_use]
+    pub fn is_completed(&self) -> bool {
+        self.inner
+            .downcast_ref::<Generator>()
+            .is_some_and(|generator| matches!(generator.state, GeneratorState::Completed))
+    }
 }
 
 impl From<JsGenerator> for JsObject {
@@ -92,3 +197,107 @@ impl TryFromJs for JsGenerator {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use boa_parser::Source;
+
+    use super::{GeneratorStep, JsGenerator};
+    use crate::{js_string, Context, JsError, JsObject, JsValue};
+
+    fn generator(source: &str, context: &mut Context) -> JsGenerator {
+        let value = context
+            .eval(Source::from_bytes(source))
+            .expect("generator source should evaluate");
+        JsGenerator::from_object(value.as_object().expect("should be an object").clone())
+            .expect("should be a generator")
+    }
+
+    fn assert_yield(step: &GeneratorStep, value: impl Into<JsValue>) {
+        assert!(!step.is_return(), "expected a yield, got {step:?}");
+        assert!(step.value().strict_equals(&value.into()));
+    }
+
+    fn assert_return(step: &GeneratorStep, value: impl Into<JsValue>) {
+        assert!(step.is_return(), "expected a return, got {step:?}");
+        assert!(step.value().strict_equals(&value.into()));
+    }
+
+    #[test]
+    fn resume_passes_values_to_yield() {
+        let context = &mut Context::default();
+        let generator = generator(
+            "(function* () { let x = yield 1; let y = yield x + 1; return y * 2; })()",
+            context,
+        );
+
+        assert_yield(&generator.resume(JsValue::undefined(), context).unwrap(), 1);
+        assert_yield(&generator.resume(5, context).unwrap(), 6);
+        assert!(!generator.is_completed());
+        assert_return(&generator.resume(10, context).unwrap(), 20);
+        assert!(generator.is_completed());
+        assert_return(
+            &generator.resume(JsValue::undefined(), context).unwrap(),
+            JsValue::undefined(),
+        );
+    }
+
+    #[test]
+    fn resume_throw_injects_errors() {
+        let context = &mut Context::default();
+        let source = r#"(function* () {
+            try {
+                yield 1;
+            } catch (e) {
+                yield "caught " + e;
+            }
+        })()"#;
+
+        let caught = generator(source, context);
+        caught.resume(JsValue::undefined(), context).unwrap();
+        let step = caught
+            .resume_throw(JsError::from_opaque(js_string!("boom").into()), context)
+            .unwrap();
+        assert_yield(&step, js_string!("caught boom"));
+        assert_return(
+            &caught.resume(JsValue::undefined(), context).unwrap(),
+            JsValue::undefined(),
+        );
+
+        let uncaught = generator("(function* () { yield 1; })()", context);
+        uncaught.resume(JsValue::undefined(), context).unwrap();
+        let error = uncaught
+            .resume_throw(JsError::from_opaque(js_string!("boom").into()), context)
+            .unwrap_err();
+        assert!(error
+            .as_opaque()
+            .is_some_and(|value| value.strict_equals(&js_string!("boom").into())));
+        assert!(uncaught.is_completed());
+    }
+
+    #[test]
+    fn resume_return_runs_finally_blocks() {
+        let context = &mut Context::default();
+        let source = r#"(function* () {
+            try {
+                yield 1;
+            } finally {
+                yield 2;
+            }
+        })()"#;
+
+        let generator = generator(source, context);
+        assert_yield(&generator.resume(JsValue::undefined(), context).unwrap(), 1);
+        assert_yield(&generator.resume_return(3, context).unwrap(), 2);
+        assert!(!generator.is_completed());
+        assert_return(&generator.resume(JsValue::undefined(), context).unwrap(), 3);
+        assert!(generator.is_completed());
+    }
+
+    #[test]
+    fn from_object_rejects_other_objects() {
+        let context = &mut Context::default();
+        let object = JsObject::with_object_proto(context.intrinsics());
+        assert!(JsGenerator::from_object(object).is_err());
+    }
+}
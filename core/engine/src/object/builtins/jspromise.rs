@@ -1184,7 +1184,7 @@ impl JsPromise {
                     // c. Push asyncContext onto the execution context stack; asyncContext is now the running execution context.
                     // d. Resume the suspended evaluation of asyncContext using NormalCompletion(value) as the result of the operation that suspended it.
                     let continuation = &captures.0;
-                    let mut gen = captures.1.take().expect("should only run once");
+                    let mut gen = boa_gc::take_traced(&captures.1).expect("should only run once");
 
                     // NOTE: We need to get the object before resuming, since it could clear the stack.
                     let async_generator = gen.async_generator_object();
@@ -1241,7 +1241,7 @@ impl JsPromise {
                     // e. Assert: When we reach this step, asyncContext has already been removed from the execution context stack and prevContext is the currently running execution context.
                     // f. Return undefined.
                     let continuation = &captures.0;
-                    let mut gen = captures.1.take().expect("should only run once");
+                    let mut gen = boa_gc::take_traced(&captures.1).expect("should only run once");
 
                     // NOTE: We need to get the object before resuming, since it could clear the stack.
                     let async_generator = gen.async_generator_object();
@@ -0,0 +1,275 @@
+//! Interception of the property accesses of a global object.
+//!
+//! This is a host-side alternative to using a `Proxy` as the global object: the internal methods
+//! of an intercepted global object ask its [`GlobalInterceptor`] for a binding before looking at
+//! its own properties, which allows embedders to expose live bindings to host state both as
+//! properties of `globalThis` and as global variables.
+
+use std::rc::Rc;
+
+use boa_gc::{Finalize, Trace};
+
+use super::{
+    internal_methods::{
+        ordinary_define_own_property, ordinary_delete, ordinary_get_own_property,
+        ordinary_own_property_keys, InternalMethodContext, InternalObjectMethods,
+        ORDINARY_INTERNAL_METHODS,
+    },
+    shape::slot::SlotAttributes,
+    JsData, JsObject,
+};
+use crate::{
+    context::intrinsics::Intrinsics,
+    property::{PropertyDescriptor, PropertyKey},
+    Context, JsResult, JsValue,
+};
+
+/// A host hook that provides the bindings of an intercepted global object.
+///
+/// Intercepted global objects are created with [`JsObject::with_global_interceptor`], usually
+/// from [`HostHooks::create_global_object`][crate::context::HostHooks::create_global_object].
+///
+/// The bindings of the interceptor take precedence over the own properties of the global object,
+/// and behave like enumerable, non-configurable data properties. They are looked up on every
+/// access, so they always reflect the current state of the host.
+///
+/// # Example
+///
+/// ```
+/// use boa_engine::{
+///     context::{intrinsics::Intrinsics, HostHooks},
+///     js_string,
+///     object::GlobalInterceptor,
+///     property::PropertyKey,
+///     Context, JsObject, JsResult, JsValue, Source,
+/// };
+/// use std::{cell::Cell, rc::Rc};
+///
+/// #[derive(Default)]
+/// struct Counter(Cell<i32>);
+///
+/// impl GlobalInterceptor for Counter {
+///     fn get(&self, key: &PropertyKey, _context: &mut Context) -> JsResult<Option<JsValue>> {
+///         let count = PropertyKey::from(js_string!("count"));
+///         Ok((*key == count).then(|| self.0.get().into()))
+///     }
+///
+///     fn set(&self, _key: &PropertyKey, value: JsValue, context: &mut Context) -> JsResult<bool> {
+///         self.0.set(value.to_i32(context)?);
+///         Ok(true)
+///     }
+/// }
+///
+/// struct Hooks(Rc<Counter>);
+///
+/// impl HostHooks for Hooks {
+///     fn create_global_object(&self, intrinsics: &Intrinsics) -> JsObject {
+///         JsObject::with_global_interceptor(intrinsics, self.0.clone())
+///     }
+/// }
+///
+/// let counter = Rc::new(Counter::default());
+/// let context = &mut Context::builder()
+///     .host_hooks(Rc::new(Hooks(counter.clone())))
+///     .build()
+///     .unwrap();
+///
+/// counter.0.set(41);
+/// let result = context
+///     .eval(Source::from_bytes("count += 1; globalThis.count"))
+///     .unwrap();
+///
+/// assert_eq!(result, JsValue::new(42));
+/// assert_eq!(counter.0.get(), 42);
+/// ```
+pub trait GlobalInterceptor {
+    /// Gets the value of the binding `key`, or `None` if the host has no such binding, in which
+    /// case the own properties of the global object are used instead.
+    fn get(&self, key: &PropertyKey, context: &mut Context) -> JsResult<Option<JsValue>>;
+
+    /// Sets the value of the binding `key`, which the host has a value for.
+    ///
+    /// Returns `false` if the binding is read-only, which is the default.
+    fn set(&self, key: &PropertyKey, value: JsValue, context: &mut Context) -> JsResult<bool> {
+        let _ = (key, value, context);
+        Ok(false)
+    }
+
+    /// Gets the keys of the bindings of the host, used when enumerating the global object.
+    ///
+    /// The keys are ordered with the keys of the own properties of the global object like any
+    /// other property keys: array indices first, then strings, then symbols.
+    ///
+    /// By default, the bindings of the host are not enumerated.
+    fn keys(&self, context: &mut Context) -> JsResult<Vec<PropertyKey>> {
+        let _ = context;
+        Ok(Vec::new())
+    }
+}
+
+/// The data of a global object created with [`JsObject::with_global_interceptor`].
+#[derive(Trace, Finalize)]
+struct InterceptedGlobal {
+    // SAFETY: Like `HostHooks`, interceptors are owned by the host and must not hold
+    // garbage collected values.
+    #[unsafe_ignore_trace]
+    interceptor: Rc<dyn GlobalInterceptor>,
+}
+
+impl std::fmt::Debug for InterceptedGlobal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("InterceptedGlobal").finish_non_exhaustive()
+    }
+}
+
+impl JsData for InterceptedGlobal {
+    fn internal_methods(&self) -> &'static InternalObjectMethods {
+        static METHODS: InternalObjectMethods = InternalObjectMethods {
+            __get_own_property__: intercepted_global_get_own_property,
+            __define_own_property__: intercepted_global_define_own_property,
+            __delete__: intercepted_global_delete,
+            __own_property_keys__: intercepted_global_own_property_keys,
+            ..ORDINARY_INTERNAL_METHODS
+        };
+
+        &METHODS
+    }
+}
+
+impl JsObject {
+    /// Creates a global object whose bindings are provided by `interceptor`, falling back to its
+    /// own properties for the bindings the host doesn't have.
+    ///
+    /// Property accesses on an intercepted global object never use the inline caches of the VM,
+    /// since the bindings of the host can change at any time.
+    #[must_use]
+    pub fn with_global_interceptor(
+        intrinsics: &Intrinsics,
+        interceptor: Rc<dyn GlobalInterceptor>,
+    ) -> Self {
+        Self::from_proto_and_data(
+            intrinsics.constructors().object().prototype(),
+            InterceptedGlobal { interceptor },
+        )
+    }
+}
+
+/// Gets the [`GlobalInterceptor`] of an intercepted global object.
+fn interceptor_of(obj: &JsObject) -> Rc<dyn GlobalInterceptor> {
+    obj.downcast_ref::<InterceptedGlobal>()
+        .expect("the object must be an intercepted global object")
+        .interceptor
+        .clone()
+}
+
+/// `[[GetOwnProperty]]` of an intercepted global object.
+///
+/// The bindings of the host are reported as data properties.
+fn intercepted_global_get_own_property(
+    obj: &JsObject,
+    key: &PropertyKey,
+    context: &mut InternalMethodContext<'_>,
+) -> JsResult<Option<PropertyDescriptor>> {
+    let binding = interceptor_of(obj).get(key, context)?;
+    let desc = match binding {
+        Some(value) => Some(
+            PropertyDescriptor::builder()
+                .value(value)
+                .writable(true)
+                .enumerable(true)
+                .configurable(false)
+                .build(),
+        ),
+        None => ordinary_get_own_property(obj, key, context)?,
+    };
+
+    // Accesses must always reach the interceptor, since the bindings of the host can change.
+    context.slot().attributes |= SlotAttributes::NOT_CACHABLE;
+    Ok(desc)
+}
+
+/// `[[DefineOwnProperty]]` of an intercepted global object.
+///
+/// Defining a binding of the host sets its value, and fails if the descriptor would change the
+/// attributes of the binding.
+fn intercepted_global_define_own_property(
+    obj: &JsObject,
+    key: &PropertyKey,
+    desc: PropertyDescriptor,
+    context: &mut InternalMethodContext<'_>,
+) -> JsResult<bool> {
+    let interceptor = interceptor_of(obj);
+    let defined = if interceptor.get(key, context)?.is_some() {
+        if desc.is_accessor_descriptor()
+            || desc.configurable() == Some(true)
+            || desc.enumerable() == Some(false)
+            || desc.writable() == Some(false)
+        {
+            false
+        } else if let Some(value) = desc.value() {
+            interceptor.set(key, value.clone(), context)?
+        } else {
+            true
+        }
+    } else {
+        ordinary_define_own_property(obj, key, desc, context)?
+    };
+
+    context.slot().attributes |= SlotAttributes::NOT_CACHABLE;
+    Ok(defined)
+}
+
+/// `[[Delete]]` of an intercepted global object.
+///
+/// The bindings of the host cannot be deleted.
+fn intercepted_global_delete(
+    obj: &JsObject,
+    key: &PropertyKey,
+    context: &mut InternalMethodContext<'_>,
+) -> JsResult<bool> {
+    if interceptor_of(obj).get(key, context)?.is_some() {
+        return Ok(false);
+    }
+
+    ordinary_delete(obj, key, context)
+}
+
+/// `[[OwnPropertyKeys]]` of an intercepted global object.
+///
+/// The keys of the bindings of the host are merged with the keys of the own properties, keeping
+/// the order of `OrdinaryOwnPropertyKeys`: array indices in ascending numeric order, then strings
+/// and then symbols, each host key coming after the own keys of the same kind.
+fn intercepted_global_own_property_keys(
+    obj: &JsObject,
+    context: &mut Context,
+) -> JsResult<Vec<PropertyKey>> {
+    let mut keys = ordinary_own_property_keys(obj, context)?;
+    let own_keys = keys.len();
+    for key in interceptor_of(obj).keys(context)? {
+        if !keys.contains(&key) {
+            keys.push(key);
+        }
+    }
+    if keys.len() == own_keys {
+        return Ok(keys);
+    }
+
+    let mut indices = Vec::new();
+    let mut strings = Vec::new();
+    let mut symbols = Vec::new();
+    for key in keys {
+        match key {
+            PropertyKey::Index(index) => indices.push(index),
+            PropertyKey::String(_) => strings.push(key),
+            PropertyKey::Symbol(_) => symbols.push(key),
+        }
+    }
+    indices.sort_unstable_by_key(|index| index.get());
+
+    Ok(indices
+        .into_iter()
+        .map(PropertyKey::Index)
+        .chain(strings)
+        .chain(symbols)
+        .collect())
+}
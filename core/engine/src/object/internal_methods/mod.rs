@@ -9,7 +9,7 @@ use std::ops::{Deref, DerefMut};
 
 use super::{
     shape::slot::{Slot, SlotAttributes},
-    JsPrototype, PROTOTYPE,
+    JsPrototype, PropertyChange, PROTOTYPE,
 };
 use crate::{
     context::intrinsics::{StandardConstructor, StandardConstructors},
@@ -159,7 +159,21 @@ impl JsObject {
         context: &mut InternalMethodContext<'_>,
     ) -> JsResult<bool> {
         let _timer = Profiler::global().start_event("Object::__define_own_property__", "object");
-        (self.vtable().__define_own_property__)(self, key, desc, context)
+        if !self.is_watched() {
+            return (self.vtable().__define_own_property__)(self, key, desc, context);
+        }
+
+        let existed = self.borrow().properties.contains_key(key);
+        let defined = (self.vtable().__define_own_property__)(self, key, desc, context)?;
+        if defined {
+            let change = if existed {
+                PropertyChange::Changed
+            } else {
+                PropertyChange::Added
+            };
+            self.notify_property_change(key, change, context)?;
+        }
+        Ok(defined)
     }
 
     /// Internal method `[[hasProperty]]`.
@@ -250,7 +264,16 @@ impl JsObject {
         context: &mut InternalMethodContext<'_>,
     ) -> JsResult<bool> {
         let _timer = Profiler::global().start_event("Object::__delete__", "object");
-        (self.vtable().__delete__)(self, key, context)
+        if !self.is_watched() {
+            return (self.vtable().__delete__)(self, key, context);
+        }
+
+        let existed = self.borrow().properties.contains_key(key);
+        let deleted = (self.vtable().__delete__)(self, key, context)?;
+        if deleted && existed {
+            self.notify_property_change(key, PropertyChange::Deleted, context)?;
+        }
+        Ok(deleted)
     }
 
     /// Internal method `[[OwnPropertyKeys]]`
@@ -614,7 +637,6 @@ pub(crate) fn ordinary_has_property(
         // 4. Let parent be ? O.[[GetPrototypeOf]]().
         let parent = obj.__get_prototype_of__(context)?;
 
-        context.slot().set_not_cachable_if_already_prototype();
         context.slot().attributes |= SlotAttributes::PROTOTYPE;
 
         parent
@@ -645,7 +667,6 @@ pub(crate) fn ordinary_get(
         None => {
             // a. Let parent be ? O.[[GetPrototypeOf]]().
             if let Some(parent) = obj.__get_prototype_of__(context)? {
-                context.slot().set_not_cachable_if_already_prototype();
                 context.slot().attributes |= SlotAttributes::PROTOTYPE;
 
                 // c. Return ? parent.[[Get]](P, Receiver).
@@ -699,7 +720,6 @@ pub(crate) fn ordinary_try_get(
         None => {
             // a. Let parent be ? O.[[GetPrototypeOf]]().
             if let Some(parent) = obj.__get_prototype_of__(context)? {
-                context.slot().set_not_cachable_if_already_prototype();
                 context.slot().attributes |= SlotAttributes::PROTOTYPE;
 
                 // c. Return ? parent.[[Get]](P, Receiver).
@@ -751,6 +771,25 @@ pub(crate) fn ordinary_set(
     // OrdinarySetWithOwnDescriptor ( O, P, V, Receiver, ownDesc )
     // https://tc39.es/ecma262/multipage/ordinary-and-exotic-objects-behaviours.html#sec-ordinarysetwithowndescriptor
 
+    // NOTE: This is an optimization, writes to the data properties of a frozen object are
+    //       rejected without building their descriptors.
+    if !matches!(key, PropertyKey::Index(_)) && obj.has_ordinary_own_properties() {
+        let object = obj.borrow();
+        let shape = &object.properties().shape;
+        if shape.is_frozen() {
+            if let Some(slot) = shape.lookup(&key) {
+                if !slot.attributes.is_accessor_descriptor() {
+                    let out_slot = context.slot();
+                    out_slot.index = slot.index;
+                    out_slot.attributes = (out_slot.attributes & SlotAttributes::INLINE_CACHE_BITS)
+                        | slot.attributes
+                        | SlotAttributes::FOUND;
+                    return Ok(false);
+                }
+            }
+        }
+    }
+
     // 1. Assert: IsPropertyKey(P) is true.
     let own_desc = if let Some(desc) = obj.__get_own_property__(&key, context)? {
         desc
@@ -759,7 +798,6 @@ pub(crate) fn ordinary_set(
     // a. Let parent be ? O.[[GetPrototypeOf]]().
     // b. If parent is not null, then
     else if let Some(parent) = obj.__get_prototype_of__(context)? {
-        context.slot().set_not_cachable_if_already_prototype();
         context.slot().attributes |= SlotAttributes::PROTOTYPE;
 
         // i. Return ? parent.[[Set]](P, V, Receiver).
@@ -890,24 +928,15 @@ pub(crate) fn ordinary_own_property_keys(
 ) -> JsResult<Vec<PropertyKey>> {
     let _timer = Profiler::global().start_event("Object::ordinary_own_property_keys", "object");
     // 1. Let keys be a new empty List.
-    let mut keys = Vec::new();
-
-    let ordered_indexes = {
-        let mut indexes: Vec<_> = obj.borrow().properties.index_property_keys().collect();
-        indexes.sort_unstable();
-        indexes
-    };
-
     // 2. For each own property key P of O such that P is an array index, in ascending numeric index order, do
     // a. Add P as the last element of keys.
-    keys.extend(ordered_indexes.into_iter().map(Into::into));
-
+    //
     // 3. For each own property key P of O such that Type(P) is String and P is not an array index, in ascending chronological order of property creation, do
     //     a. Add P as the last element of keys.
     //
     // 4. For each own property key P of O such that Type(P) is Symbol, in ascending chronological order of property creation, do
     //     a. Add P as the last element of keys.
-    keys.extend(obj.borrow().properties.shape.keys());
+    let keys = obj.borrow().properties.ordered_keys();
 
     // 5. Return keys.
     Ok(keys)
@@ -107,14 +107,7 @@ pub(crate) fn string_exotic_own_property_keys(
     // 6. For each own property key P of O such that P is an array index
     // and ! ToIntegerOrInfinity(P) ≥ len, in ascending numeric index order, do
     //      a. Add P as the last element of keys.
-    let mut remaining_indices: Vec<_> = obj
-        .properties
-        .index_property_keys()
-        .filter(|idx| (*idx as usize) >= len)
-        .collect();
-    remaining_indices.sort_unstable();
-    keys.extend(remaining_indices.into_iter().map(Into::into));
-
+    //
     // 7. For each own property key P of O such that Type(P) is String and P is not
     // an array index, in ascending chronological order of property creation, do
     //      a. Add P as the last element of keys.
@@ -122,7 +115,11 @@ pub(crate) fn string_exotic_own_property_keys(
     // 8. For each own property key P of O such that Type(P) is Symbol, in ascending
     // chronological order of property creation, do
     //      a. Add P as the last element of keys.
-    keys.extend(obj.properties.shape.keys());
+    keys.extend(
+        obj.properties.ordered_keys().into_iter().filter(
+            |key| !matches!(key, PropertyKey::Index(index) if (index.get() as usize) < len),
+        ),
+    );
 
     // 9. Return keys.
     Ok(keys)
@@ -17,6 +17,7 @@ use crate::{
         array_buffer::{ArrayBuffer, BufferObject, SharedArrayBuffer},
         object::OrdinaryObject,
     },
+    class::Class,
     context::intrinsics::Intrinsics,
     error::JsNativeError,
     js_string,
@@ -140,6 +141,7 @@ impl JsObject {
                 data,
                 properties: PropertyMap::from_prototype_unique_shape(prototype.into()),
                 extensible: true,
+                watched: false,
                 private_elements: ThinVec::new(),
             }),
             vtable: internal_methods,
@@ -171,6 +173,7 @@ impl JsObject {
                     prototype.into(),
                 ),
                 extensible: true,
+                watched: false,
                 private_elements: ThinVec::new(),
             }),
             vtable: internal_methods,
@@ -267,6 +270,22 @@ impl JsObject {
         self.borrow().is::<T>()
     }
 
+    /// Checks if this object is an instance of the native class `T`.
+    ///
+    /// Unlike `instanceof`, this only checks the native data of the object, so it works for
+    /// instances created in any realm where `T` is registered, and isn't affected by changes to the
+    /// prototype chain of the object.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the object is currently mutably borrowed.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn is_native<T: Class>(&self) -> bool {
+        self.is::<T>()
+    }
+
     /// Checks if it's an ordinary object.
     ///
     /// # Panics
@@ -586,6 +605,7 @@ impl<T: NativeObject + ?Sized> JsObject<T> {
                     prototype.into(),
                 ),
                 extensible: true,
+                watched: false,
                 private_elements: ThinVec::new(),
             }),
             vtable: internal_methods,
@@ -608,6 +628,7 @@ impl<T: NativeObject + ?Sized> JsObject<T> {
                 data,
                 properties: PropertyMap::from_prototype_unique_shape(prototype.into()),
                 extensible: true,
+                watched: false,
                 private_elements: ThinVec::new(),
             }),
             vtable: internal_methods,
@@ -776,6 +797,36 @@ impl<T: NativeObject + ?Sized> JsObject<T> {
         self.inner.vtable.__construct__ != ORDINARY_INTERNAL_METHODS.__construct__
     }
 
+    /// Checks if the own properties of the object are the ones stored in its [`PropertyMap`],
+    /// which is the case if its `[[GetOwnProperty]]`, `[[OwnPropertyKeys]]` and `[[IsExtensible]]`
+    /// internal methods are the ordinary ones.
+    #[inline]
+    #[expect(
+        unpredictable_function_pointer_comparisons,
+        reason = "can only use `ptr::fn_addr_eq` on rustc 1.85"
+    )]
+    pub(crate) fn has_ordinary_own_properties(&self) -> bool {
+        let vtable = self.inner.vtable;
+        vtable.__get_own_property__ == ORDINARY_INTERNAL_METHODS.__get_own_property__
+            && vtable.__own_property_keys__ == ORDINARY_INTERNAL_METHODS.__own_property_keys__
+            && vtable.__is_extensible__ == ORDINARY_INTERNAL_METHODS.__is_extensible__
+    }
+
+    /// Checks if the properties of the object are looked up in its [`PropertyMap`] and then in
+    /// its prototype, which is the case if its `[[GetOwnProperty]]`, `[[GetPrototypeOf]]` and
+    /// `[[Get]]` internal methods are the ordinary ones.
+    #[inline]
+    #[expect(
+        unpredictable_function_pointer_comparisons,
+        reason = "can only use `ptr::fn_addr_eq` on rustc 1.85"
+    )]
+    pub(crate) fn has_ordinary_property_lookups(&self) -> bool {
+        let vtable = self.inner.vtable;
+        vtable.__get_own_property__ == ORDINARY_INTERNAL_METHODS.__get_own_property__
+            && vtable.__get_prototype_of__ == ORDINARY_INTERNAL_METHODS.__get_prototype_of__
+            && vtable.__get__ == ORDINARY_INTERNAL_METHODS.__get__
+    }
+
     pub(crate) fn vtable(&self) -> &'static InternalObjectMethods {
         self.inner.vtable
     }
@@ -3,7 +3,7 @@
 //! For the builtin object wrappers, please see [`object::builtins`][builtins] for implementors.
 
 pub use jsobject::{RecursionLimiter, Ref, RefMut};
-pub use operations::IntegrityLevel;
+pub use operations::{AccessorHandling, IntegrityLevel};
 pub use property_map::*;
 use thin_vec::ThinVec;
 
@@ -39,15 +39,19 @@ pub(crate) mod internal_methods;
 
 pub mod builtins;
 mod datatypes;
+mod interceptor;
 mod jsobject;
 mod operations;
 mod property_map;
 pub mod shape;
+mod watch;
 
 pub(crate) use builtins::*;
 
 pub use datatypes::JsData;
+pub use interceptor::GlobalInterceptor;
 pub use jsobject::*;
+pub use watch::{PropertyChange, PropertyWatcher};
 
 /// Const `constructor`, usually set on prototypes as a key to point to their respective constructor object.
 pub const CONSTRUCTOR: JsString = js_string!("constructor");
@@ -177,6 +181,8 @@ pub struct Object<T: ?Sized> {
     pub(crate) properties: PropertyMap,
     /// Whether it can have new properties added to it.
     pub(crate) extensible: bool,
+    /// Whether changes to its properties are reported to the context's [`PropertyWatcher`].
+    pub(crate) watched: bool,
     /// The `[[PrivateElements]]` internal slot.
     private_elements: ThinVec<(PrivateName, PrivateElement)>,
     /// The inner object data
@@ -188,6 +194,7 @@ impl<T: Default> Default for Object<T> {
         Self {
             properties: PropertyMap::default(),
             extensible: true,
+            watched: false,
             private_elements: ThinVec::new(),
             data: T::default(),
         }
@@ -268,6 +275,7 @@ impl<T: ?Sized> Object<T> {
         let prototype = prototype.into();
         if self.extensible {
             self.properties.shape = self.properties.shape.change_prototype_transition(prototype);
+            self.properties.invalidate_inline_caches();
             true
         } else {
             // If target is non-extensible, [[SetPrototypeOf]] must return false
@@ -746,12 +754,14 @@ impl<'ctx> ConstructorBuilder<'ctx> {
                 data: OrdinaryObject,
                 properties: PropertyMap::default(),
                 extensible: true,
+                watched: false,
                 private_elements: ThinVec::new(),
             },
             prototype: Object {
                 data: OrdinaryObject,
                 properties: PropertyMap::default(),
                 extensible: true,
+                watched: false,
                 private_elements: ThinVec::new(),
             },
             length: 0,
@@ -1011,6 +1021,7 @@ impl<'ctx> ConstructorBuilder<'ctx> {
             let mut constructor = Object {
                 properties: self.constructor_object.properties,
                 extensible: self.constructor_object.extensible,
+                watched: false,
                 private_elements: self.constructor_object.private_elements,
                 data: NativeFunctionObject {
                     f: self.function,
@@ -51,6 +51,19 @@ impl IntegrityLevel {
     }
 }
 
+/// How [`JsObject::own_entries`] handles the accessor properties of an object.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum AccessorHandling {
+    /// Leave the accessor properties out of the entries, without calling their getters.
+    #[default]
+    Skip,
+
+    /// Call the getters of the accessor properties to get their values.
+    ///
+    /// Accessor properties without a getter have an `undefined` value.
+    Invoke,
+}
+
 impl JsObject {
     /// Check if object is extensible.
     ///
@@ -365,8 +378,102 @@ impl JsObject {
         Ok(desc.is_some())
     }
 
+    /// Checks if the object has an own property, without running any internal method.
+    ///
+    /// This is a cheaper alternative to [`JsObject::has_own_property`] for host code that probes
+    /// objects frequently. It returns `None` if the answer depends on the internal methods of an
+    /// exotic object, like a proxy, a string or a typed array, in which case the caller has to
+    /// fall back to [`JsObject::has_own_property`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the object is currently mutably borrowed.
+    #[must_use]
+    pub fn has_own_fast<K>(&self, key: K) -> Option<bool>
+    where
+        K: Into<PropertyKey>,
+    {
+        if !self.has_ordinary_property_lookups() {
+            return None;
+        }
+        Some(self.borrow().properties().get(&key.into()).is_some())
+    }
+
+    /// Gets the value of a property without running any user code or internal method.
+    ///
+    /// This is a cheaper alternative to [`JsObject::get`] for host code that reads many
+    /// properties, e.g. the options of an option bag. Like [`JsObject::get`], it looks up the
+    /// property in the prototype chain, and returns `undefined` for missing properties.
+    ///
+    /// It returns `None` if the lookup reaches an exotic object, like a proxy, a string or a
+    /// typed array, or an accessor property with a getter, since getting the value would need to
+    /// run their internal methods or the getter. The caller then has to fall back to
+    /// [`JsObject::get`], which gives the same result as this method whenever it returns a value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an object of the prototype chain is currently mutably borrowed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use boa_engine::{js_string, Context, JsValue, Source};
+    /// let context = &mut Context::default();
+    /// let options = context
+    ///     .eval(Source::from_bytes("({ depth: 2, get lazy() { return 1; } })"))
+    ///     .unwrap();
+    /// let options = options.as_object().unwrap();
+    ///
+    /// assert_eq!(options.get_fast(js_string!("depth")), Some(JsValue::new(2)));
+    /// assert_eq!(options.get_fast(js_string!("missing")), Some(JsValue::undefined()));
+    /// assert_eq!(options.get_fast(js_string!("lazy")), None);
+    /// assert_eq!(options.has_own_fast(js_string!("lazy")), Some(true));
+    /// ```
+    #[must_use]
+    pub fn get_fast<K>(&self, key: K) -> Option<JsValue>
+    where
+        K: Into<PropertyKey>,
+    {
+        let key = key.into();
+        let mut current = self.clone();
+        loop {
+            if !current.has_ordinary_property_lookups() {
+                return None;
+            }
+
+            let object = current.borrow();
+            if let Some(desc) = object.properties().get(&key) {
+                if desc.is_accessor_descriptor() {
+                    return desc
+                        .get()
+                        .is_none_or(JsValue::is_undefined)
+                        .then(JsValue::undefined);
+                }
+                return Some(desc.value().cloned().unwrap_or_default());
+            }
+
+            let Some(prototype) = object.prototype() else {
+                return Some(JsValue::undefined());
+            };
+            drop(object);
+            current = prototype;
+        }
+    }
+
     /// Get all the keys of the properties of this object.
     ///
+    /// For ordinary objects, as well as arrays, strings, typed arrays and arguments objects, the
+    /// keys are ordered as follows:
+    ///
+    /// 1. Array index keys, in ascending numeric order.
+    /// 2. String keys, in the order they were created.
+    /// 3. Symbol keys, in the order they were created.
+    ///
+    /// Global objects with a [`GlobalInterceptor`][super::GlobalInterceptor] follow the same order,
+    /// including the keys of the host. Module namespace objects list their exports in code unit
+    /// order before their symbol keys, and proxies with an `ownKeys` trap return the keys in the
+    /// order of the trap result.
+    ///
     /// More information:
     ///  - [ECMAScript reference][spec]
     ///
@@ -375,6 +482,70 @@ impl JsObject {
         self.__own_property_keys__(context)
     }
 
+    /// Takes a snapshot of the keys and values of the enumerable own properties of this object.
+    ///
+    /// The entries are the properties that are copied by the object spread syntax, including
+    /// the properties with symbol keys, in the order of [`JsObject::own_property_keys`].
+    ///
+    /// Unlike getting the keys and then the value of each key, the values of data properties are
+    /// read from their property descriptors, so no getter is called unless `accessors` is
+    /// [`AccessorHandling::Invoke`]. Proxies still run their `ownKeys` and
+    /// `getOwnPropertyDescriptor` traps. A property that is deleted by a getter before it is
+    /// reached is left out of the entries.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use boa_engine::{js_string, object::AccessorHandling, Context, JsValue, Source};
+    /// let context = &mut Context::default();
+    /// let object = context
+    ///     .eval(Source::from_bytes("({ b: 1, a: 2, get c() { return 3; } })"))
+    ///     .unwrap();
+    /// let object = object.as_object().unwrap();
+    ///
+    /// let entries = object.own_entries(AccessorHandling::Skip, context).unwrap();
+    /// assert_eq!(entries.len(), 2);
+    /// assert_eq!(entries[0], (js_string!("b").into(), JsValue::new(1)));
+    ///
+    /// let entries = object.own_entries(AccessorHandling::Invoke, context).unwrap();
+    /// assert_eq!(entries[2], (js_string!("c").into(), JsValue::new(3)));
+    /// ```
+    pub fn own_entries(
+        &self,
+        accessors: AccessorHandling,
+        context: &mut Context,
+    ) -> JsResult<Vec<(PropertyKey, JsValue)>> {
+        let keys = self.__own_property_keys__(&mut InternalMethodContext::new(context))?;
+        let mut entries = Vec::with_capacity(keys.len());
+
+        for key in keys {
+            let Some(desc) =
+                self.__get_own_property__(&key, &mut InternalMethodContext::new(context))?
+            else {
+                continue;
+            };
+            if !desc.expect_enumerable() {
+                continue;
+            }
+
+            let value = if desc.is_accessor_descriptor() {
+                match accessors {
+                    AccessorHandling::Skip => continue,
+                    AccessorHandling::Invoke => match desc.get().and_then(JsValue::as_callable) {
+                        Some(getter) => getter.call(&self.clone().into(), &[], context)?,
+                        None => JsValue::undefined(),
+                    },
+                }
+            } else {
+                desc.value().cloned().unwrap_or_default()
+            };
+
+            entries.push((key, value));
+        }
+
+        Ok(entries)
+    }
+
     /// `Call ( F, V [ , argumentsList ] )`
     ///
     /// # Panics
@@ -497,6 +668,14 @@ impl JsObject {
             return Ok(false);
         }
 
+        // NOTE: This is an optimization, freezing or sealing an object that already has the
+        //       integrity level doesn't change any of its properties.
+        if self.has_ordinary_own_properties()
+            && self.borrow().properties().has_integrity_level(level)
+        {
+            return Ok(true);
+        }
+
         // 5. Let keys be ? O.[[OwnPropertyKeys]]().
         let keys = self.__own_property_keys__(&mut InternalMethodContext::new(context))?;
 
@@ -560,6 +739,13 @@ impl JsObject {
         // 1. Assert: Type(O) is Object.
         // 2. Assert: level is either sealed or frozen.
 
+        // NOTE: This is an optimization, the shape of an ordinary object tracks if it has
+        //       configurable or writable properties, so only its sparse elements need to be checked.
+        if self.has_ordinary_own_properties() {
+            let object = self.borrow();
+            return Ok(!object.extensible && object.properties().has_integrity_level(level));
+        }
+
         // 3. Let extensible be ? IsExtensible(O).
         let extensible = self.is_extensible(context)?;
 
@@ -641,6 +827,11 @@ impl JsObject {
     {
         // 1. Assert: Type(O) is Object.
 
+        // Hosts can disable species lookups, which behaves as if `constructor` was undefined.
+        if context.species_lookups_disabled() {
+            return Ok(default_constructor(context.intrinsics().constructors()).constructor());
+        }
+
         // 2. Let C be ? Get(O, "constructor").
         let c = self.get(CONSTRUCTOR, context)?;
 
@@ -688,7 +879,7 @@ impl JsObject {
         // 2. Let ownKeys be ? O.[[OwnPropertyKeys]]().
         let own_keys = self.__own_property_keys__(&mut InternalMethodContext::new(context))?;
         // 3. Let properties be a new empty List.
-        let mut properties = vec![];
+        let mut properties = Vec::with_capacity(own_keys.len());
 
         // 4. For each element key of ownKeys, do
         for key in own_keys {
@@ -701,8 +892,7 @@ impl JsObject {
 
             if let Some(key_str) = key_str {
                 // i. Let desc be ? O.[[GetOwnProperty]](key).
-                let desc =
-                    self.__get_own_property__(&key, &mut InternalMethodContext::new(context))?;
+                let desc = self.get_own_property_fast(&key, context)?;
                 // ii. If desc is not undefined and desc.[[Enumerable]] is true, then
                 if let Some(desc) = desc {
                     if desc.expect_enumerable() {
@@ -713,19 +903,19 @@ impl JsObject {
                             // a. Let value be ? Get(O, key).
                             // b. If kind is value, append value to properties.
                             PropertyNameKind::Value => {
-                                properties.push(self.get(key.clone(), context)?);
+                                properties.push(self.get_own_value_fast(key, &desc, context)?);
                             }
                             // c. Else,
                             // i. Assert: kind is key+value.
                             // ii. Let entry be ! CreateArrayFromList(« key, value »).
                             // iii. Append entry to properties.
-                            PropertyNameKind::KeyAndValue => properties.push(
-                                Array::create_array_from_list(
-                                    [key_str.into(), self.get(key.clone(), context)?],
-                                    context,
-                                )
-                                .into(),
-                            ),
+                            PropertyNameKind::KeyAndValue => {
+                                let value = self.get_own_value_fast(key, &desc, context)?;
+                                properties.push(
+                                    Array::create_array_from_list([key_str.into(), value], context)
+                                        .into(),
+                                );
+                            }
                         }
                     }
                 }
@@ -736,6 +926,39 @@ impl JsObject {
         Ok(properties)
     }
 
+    /// `[[GetOwnProperty]]`, which reads the property directly from the property map of an
+    /// object with ordinary own properties.
+    ///
+    /// This is used by the operations that copy the own properties of an object, to skip the
+    /// dispatch of the internal method for the objects that can't run any user code.
+    pub(crate) fn get_own_property_fast(
+        &self,
+        key: &PropertyKey,
+        context: &mut Context,
+    ) -> JsResult<Option<PropertyDescriptor>> {
+        if self.has_ordinary_own_properties() {
+            return Ok(self.borrow().properties().get(key));
+        }
+        self.__get_own_property__(key, &mut InternalMethodContext::new(context))
+    }
+
+    /// `Get ( O, P )` of an own property, whose descriptor `desc` was just returned by
+    /// [`JsObject::get_own_property_fast`].
+    ///
+    /// The value of a data property of an ordinary object is taken from `desc`, since getting it
+    /// can't run any user code. Otherwise this is a full `Get`.
+    pub(crate) fn get_own_value_fast(
+        &self,
+        key: PropertyKey,
+        desc: &PropertyDescriptor,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        if desc.is_data_descriptor() && self.has_ordinary_property_lookups() {
+            return Ok(desc.value().cloned().unwrap_or_default());
+        }
+        self.get(key, context)
+    }
+
     /// Abstract operation `GetMethod ( V, P )`
     ///
     /// Retrieves the value of a specific property, when the value of the property is expected to be a function.
@@ -1110,7 +1333,7 @@ impl JsObject {
 
                 // a. Assert: IsPropertyKey(fieldName) is true.
                 // b. Perform ? CreateDataPropertyOrThrow(receiver, fieldName, initValue).
-                self.create_data_property_or_throw(field_name.clone(), init_value, context)?;
+                self.define_public_field(field_name.clone(), init_value, context)?;
             }
         }
 
@@ -1118,6 +1341,27 @@ impl JsObject {
         Ok(())
     }
 
+    /// Defines a public class field on an object.
+    ///
+    /// This uses `CreateDataPropertyOrThrow`, as required by the specification, unless the
+    /// context was built with [`ContextBuilder::class_fields_use_set`], in which case the field
+    /// is assigned with `Set`, like in code transpiled with legacy class field semantics.
+    ///
+    /// [`ContextBuilder::class_fields_use_set`]: crate::context::ContextBuilder::class_fields_use_set
+    pub(crate) fn define_public_field(
+        &self,
+        key: PropertyKey,
+        value: JsValue,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        if context.class_fields_use_set() {
+            self.set(key, value, true, context)?;
+        } else {
+            self.create_data_property_or_throw(key, value, context)?;
+        }
+        Ok(())
+    }
+
     /// Abstract operation `InitializeInstanceElements ( O, constructor )`
     ///
     /// Add private methods and fields from a class constructor to an object.
@@ -5,9 +5,10 @@ use super::{
         slot::{Slot, SlotAttributes},
         ChangeTransitionAction, RootShape, Shape, UniqueShape,
     },
-    JsPrototype, ObjectStorage, PropertyDescriptor, PropertyKey,
+    IntegrityLevel, JsPrototype, ObjectStorage, PropertyDescriptor, PropertyKey,
 };
 use crate::value::JsVariant;
+use crate::vm::ValidityCell;
 use crate::{property::PropertyDescriptorBuilder, JsValue};
 use boa_gc::{custom_trace, Finalize, Trace};
 use indexmap::IndexMap;
@@ -370,6 +371,11 @@ pub struct PropertyMap {
 
     pub(crate) shape: Shape,
     pub(crate) storage: ObjectStorage,
+
+    /// The cell invalidating the inline caches of the properties looked up through this map,
+    /// created when one of them is cached.
+    #[unsafe_ignore_trace]
+    validity_cell: Option<ValidityCell>,
 }
 
 impl PropertyMap {
@@ -381,6 +387,7 @@ impl PropertyMap {
             indexed_properties,
             shape,
             storage: Vec::default(),
+            validity_cell: None,
         }
     }
 
@@ -392,6 +399,7 @@ impl PropertyMap {
             indexed_properties: IndexedProperties::default(),
             shape: UniqueShape::new(prototype, PropertyTableInner::default()).into(),
             storage: Vec::default(),
+            validity_cell: None,
         }
     }
 
@@ -407,6 +415,21 @@ impl PropertyMap {
             indexed_properties: IndexedProperties::default(),
             shape: shape.into(),
             storage: Vec::default(),
+            validity_cell: None,
+        }
+    }
+
+    /// Gets the [`ValidityCell`] of the layout of the map, creating it if it doesn't exist.
+    pub(crate) fn validity_cell(&mut self) -> &ValidityCell {
+        self.validity_cell.get_or_insert_with(ValidityCell::default)
+    }
+
+    /// Invalidates the inline caches that depend on the layout of the map.
+    ///
+    /// This must be called every time the shape of the map changes.
+    pub(crate) fn invalidate_inline_caches(&self) {
+        if let Some(cell) = &self.validity_cell {
+            cell.invalidate();
         }
     }
 
@@ -496,6 +519,7 @@ impl PropertyMap {
                 };
                 let transition = self.shape.change_attributes_transition(key);
                 self.shape = transition.shape;
+                self.invalidate_inline_caches();
                 match transition.action {
                     ChangeTransitionAction::Nothing => {}
                     ChangeTransitionAction::Remove => {
@@ -537,6 +561,7 @@ impl PropertyMap {
             attributes,
         };
         self.shape = self.shape.insert_property_transition(transition_key);
+        self.invalidate_inline_caches();
 
         // Make Sure that if we are inserting, it has the correct slot index.
         debug_assert_eq!(
@@ -587,12 +612,40 @@ impl PropertyMap {
             self.storage.remove(slot.index as usize);
 
             self.shape = self.shape.remove_property_transition(key);
+            self.invalidate_inline_caches();
             return true;
         }
 
         false
     }
 
+    /// Checks if none of the properties of the map are configurable, and, for the frozen level,
+    /// if none of its data properties are writable.
+    ///
+    /// The named properties are checked by their [`Shape`] without visiting them, so this only
+    /// visits the indexed properties if they are stored sparsely.
+    pub(crate) fn has_integrity_level(&self, level: IntegrityLevel) -> bool {
+        let named = if level.is_frozen() {
+            self.shape.is_frozen()
+        } else {
+            self.shape.is_sealed()
+        };
+        if !named {
+            return false;
+        }
+
+        match &self.indexed_properties {
+            // Dense elements are always writable and configurable.
+            IndexedProperties::DenseI32(properties) => properties.is_empty(),
+            IndexedProperties::DenseF64(properties) => properties.is_empty(),
+            IndexedProperties::DenseElement(properties) => properties.is_empty(),
+            IndexedProperties::Sparse(properties) => properties.values().all(|desc| {
+                desc.configurable() != Some(true)
+                    && (!level.is_frozen() || desc.writable() != Some(true))
+            }),
+        }
+    }
+
     /// Overrides all the indexed properties, setting it to dense storage.
     pub(crate) fn override_indexed_properties(&mut self, properties: ThinVec<JsValue>) {
         self.indexed_properties = IndexedProperties::DenseElement(properties);
@@ -726,6 +779,25 @@ impl PropertyMap {
         self.indexed_properties.keys()
     }
 
+    /// Returns the keys of all the properties in the order of [`OrdinaryOwnPropertyKeys`]: array
+    /// indices in ascending numeric order, then strings and then symbols in the order they were
+    /// created.
+    ///
+    /// [`OrdinaryOwnPropertyKeys`]: https://tc39.es/ecma262/#sec-ordinaryownpropertykeys
+    #[must_use]
+    pub(crate) fn ordered_keys(&self) -> Vec<PropertyKey> {
+        let mut indices: Vec<_> = self.index_property_keys().collect();
+
+        // The dense storages already visit their keys in ascending order.
+        if matches!(self.indexed_properties, IndexedProperties::Sparse(_)) {
+            indices.sort_unstable();
+        }
+
+        let mut keys: Vec<PropertyKey> = indices.into_iter().map(Into::into).collect();
+        keys.extend(self.shape.keys());
+        keys
+    }
+
     /// An iterator visiting all index values in arbitrary order. The iterator element type is `&'a Property`.
     ///
     /// This iterator does not recurse down the prototype chain.
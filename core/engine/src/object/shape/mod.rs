@@ -175,6 +175,18 @@ impl Shape {
         }
     }
 
+    /// Returns a new [`UniqueShape`] with the same properties and prototype as this [`Shape`].
+    ///
+    /// Unlike transitions, this always creates a new shape, even if `self` is already unique.
+    pub(crate) fn to_new_unique(&self) -> Self {
+        match &self.inner {
+            Inner::Shared(shape) => shape.to_unique().into(),
+            Inner::Unique(shape) => {
+                UniqueShape::new(shape.prototype(), shape.property_table().borrow().clone()).into()
+            }
+        }
+    }
+
     /// Get the [`JsPrototype`] of the [`Shape`].
     #[must_use]
     pub fn prototype(&self) -> JsPrototype {
@@ -184,6 +196,33 @@ impl Shape {
         }
     }
 
+    /// Returns `true` if none of the properties of the shape are configurable.
+    ///
+    /// This is tracked by the shape, so it doesn't need to visit the properties.
+    #[inline]
+    pub(crate) fn is_sealed(&self) -> bool {
+        match &self.inner {
+            Inner::Shared(shape) => !shape.has_configurable_property(),
+            Inner::Unique(shape) => !shape.has_configurable_property(),
+        }
+    }
+
+    /// Returns `true` if none of the properties of the shape are configurable, and none of its
+    /// data properties are writable.
+    ///
+    /// This is tracked by the shape, so it doesn't need to visit the properties.
+    #[inline]
+    pub(crate) fn is_frozen(&self) -> bool {
+        match &self.inner {
+            Inner::Shared(shape) => {
+                !shape.has_configurable_property() && !shape.has_writable_property()
+            }
+            Inner::Unique(shape) => {
+                !shape.has_configurable_property() && !shape.has_writable_property()
+            }
+        }
+    }
+
     /// Lookup a property in the shape
     #[inline]
     pub(crate) fn lookup(&self, key: &PropertyKey) -> Option<Slot> {
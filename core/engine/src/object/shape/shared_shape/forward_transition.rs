@@ -91,6 +91,17 @@ impl ForwardTransition {
         transitions.map.get(key).cloned()
     }
 
+    /// Gets the shapes of the transitions that haven't been garbage collected.
+    pub(super) fn live_transitions(&self) -> Vec<Gc<SharedShapeInner>> {
+        let this = self.inner.borrow();
+        let properties = this.properties.iter().flat_map(|t| t.map.values());
+        let prototypes = this.prototypes.iter().flat_map(|t| t.map.values());
+        properties
+            .chain(prototypes)
+            .filter_map(WeakGc::upgrade)
+            .collect()
+    }
+
     /// Prunes the [`WeakGc`]s that have been garbage collected.
     pub(super) fn prune_property_transitions(&self) {
         let mut this = self.inner.borrow_mut();
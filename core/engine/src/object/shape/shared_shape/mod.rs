@@ -9,6 +9,7 @@ use std::{collections::hash_map::RandomState, hash::Hash};
 use bitflags::bitflags;
 use boa_gc::{empty_trace, Finalize, Gc, Trace, WeakGc};
 use indexmap::IndexMap;
+use rustc_hash::FxHashSet;
 
 use crate::{object::JsPrototype, property::PropertyKey, JsObject};
 
@@ -46,6 +47,15 @@ bitflags! {
     pub struct ShapeFlags: u8 {
         /// Represents the transition type of a [`SharedShape`].
         const TRANSITION_TYPE = 0b0000_0011;
+
+        /// Set if the shape has a configurable property.
+        const HAS_CONFIGURABLE = 0b0000_0100;
+
+        /// Set if the shape has a writable data property.
+        const HAS_WRITABLE = 0b0000_1000;
+
+        /// The flags describing the attributes of all the properties of the shape.
+        const INTEGRITY = Self::HAS_CONFIGURABLE.bits() | Self::HAS_WRITABLE.bits();
     }
 }
 
@@ -70,6 +80,17 @@ impl ShapeFlags {
             | Self::from_bits_retain(PROTOTYPE_TRANSITION_TYPE)
     }
 
+    /// Returns the integrity flags of a property with the given attributes.
+    fn integrity_of(attributes: SlotAttributes) -> Self {
+        let mut flags = Self::empty();
+        flags.set(
+            Self::HAS_CONFIGURABLE,
+            attributes.contains(SlotAttributes::CONFIGURABLE),
+        );
+        flags.set(Self::HAS_WRITABLE, attributes.is_writable_data());
+        flags
+    }
+
     const fn is_insert_transition_type(self) -> bool {
         self.intersection(Self::TRANSITION_TYPE).bits() == INSERT_PROPERTY_TRANSITION_TYPE
     }
@@ -163,6 +184,14 @@ impl SharedShape {
     fn forward_transitions(&self) -> &ForwardTransition {
         &self.inner.forward_transitions
     }
+    /// Returns `true` if the shape has a configurable property.
+    pub(crate) fn has_configurable_property(&self) -> bool {
+        self.flags().contains(ShapeFlags::HAS_CONFIGURABLE)
+    }
+    /// Returns `true` if the shape has a writable data property.
+    pub(crate) fn has_writable_property(&self) -> bool {
+        self.flags().contains(ShapeFlags::HAS_WRITABLE)
+    }
     /// Check if the shape has the given prototype.
     #[must_use]
     pub fn has_prototype(&self, prototype: &JsObject) -> bool {
@@ -176,6 +205,19 @@ impl SharedShape {
         }
     }
 
+    /// Counts the live shapes reachable from this shape through its transitions, including
+    /// itself.
+    pub(crate) fn transition_tree_size(&self) -> usize {
+        let mut visited = FxHashSet::default();
+        let mut stack = vec![self.inner.clone()];
+        while let Some(inner) = stack.pop() {
+            if visited.insert(std::ptr::from_ref::<Inner>(&inner)) {
+                stack.extend(inner.forward_transitions.live_transitions());
+            }
+        }
+        visited.len()
+    }
+
     /// Create a root [`SharedShape`].
     #[must_use]
     pub(crate) fn root() -> Self {
@@ -240,7 +282,8 @@ impl SharedShape {
             property_count: self.property_count() + 1,
             previous: Some(self.clone()),
             transition_count: self.transition_count() + 1,
-            flags: ShapeFlags::insert_property_transition_from(self.flags()),
+            flags: ShapeFlags::insert_property_transition_from(self.flags())
+                | ShapeFlags::integrity_of(key.attributes),
         };
         let new_shape = Self::new(new_inner_shape);
 
@@ -283,6 +326,17 @@ impl SharedShape {
         if slot.attributes.width_match(key.attributes) {
             let property_table = self.property_table().deep_clone_all();
             property_table.set_attributes_at_index(&key.property_key, key.attributes);
+
+            // The changed property may have been the only configurable or writable one.
+            let integrity = property_table
+                .inner()
+                .borrow()
+                .keys
+                .iter()
+                .take(self.property_count() as usize)
+                .fold(ShapeFlags::empty(), |flags, (_, slot)| {
+                    flags | ShapeFlags::integrity_of(slot.attributes)
+                });
             let inner_shape = Inner {
                 forward_transitions: ForwardTransition::default(),
                 prototype: self.prototype(),
@@ -290,7 +344,9 @@ impl SharedShape {
                 property_count: self.property_count(),
                 previous: Some(self.clone()),
                 transition_count: self.transition_count() + 1,
-                flags: ShapeFlags::configure_property_transition_from(self.flags()),
+                flags: ShapeFlags::configure_property_transition_from(self.flags())
+                    .difference(ShapeFlags::INTEGRITY)
+                    | integrity,
             };
             let shape = Self::new(inner_shape);
 
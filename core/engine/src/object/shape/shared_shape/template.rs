@@ -112,6 +112,7 @@ impl ObjectTemplate {
         let mut object = Object {
             data,
             extensible: true,
+            watched: false,
             properties: PropertyMap::new(self.shape.clone().into(), IndexedProperties::default()),
             private_elements: ThinVec::new(),
         };
@@ -135,6 +136,7 @@ impl ObjectTemplate {
         let mut object = Object {
             data,
             extensible: true,
+            watched: false,
             properties: PropertyMap::new(self.shape.clone().into(), indexed_properties),
             private_elements: ThinVec::new(),
         };
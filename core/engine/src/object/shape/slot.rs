@@ -30,6 +30,11 @@ impl SlotAttributes {
         self.contains(Self::SET)
     }
 
+    /// Checks if the slot is a writable data property.
+    pub(crate) const fn is_writable_data(self) -> bool {
+        !self.is_accessor_descriptor() && self.contains(Self::WRITABLE)
+    }
+
     /// Check if slot type width matches, this can only happens,
     /// if they are both accessors, or both data properties.
     pub(crate) const fn width_match(self, other: Self) -> bool {
@@ -104,17 +109,4 @@ impl Slot {
             attributes: new_attributes,
         }
     }
-
-    pub(crate) fn set_not_cachable_if_already_prototype(&mut self) {
-        // NOTE(HalidOdat): This is a bit hack to avoid conditional branches.
-        //
-        // Equivalent to:
-        // if slot.attributes.contains(SlotAttributes::PROTOTYPE) {
-        //     slot.attributes |= SlotAttributes::NOT_CACHABLE;
-        // }
-        //
-        self.attributes |= SlotAttributes::from_bits_retain(
-            (self.attributes.bits() & SlotAttributes::PROTOTYPE.bits()) << 2,
-        );
-    }
 }
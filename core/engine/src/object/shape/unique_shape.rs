@@ -1,14 +1,50 @@
-use std::{cell::RefCell, fmt::Debug};
+use std::{
+    cell::{Cell, RefCell},
+    fmt::Debug,
+};
 
 use boa_gc::{Finalize, Gc, GcRefCell, Trace, WeakGc};
 
 use crate::property::PropertyKey;
 
 use super::{
-    property_table::PropertyTableInner, shared_shape::TransitionKey, ChangeTransition,
-    ChangeTransitionAction, JsPrototype, Shape, Slot,
+    property_table::PropertyTableInner,
+    shared_shape::TransitionKey,
+    slot::{Slot, SlotAttributes},
+    ChangeTransition, ChangeTransitionAction, JsPrototype, Shape,
 };
 
+/// The number of properties of a [`UniqueShape`] that prevent it from being sealed or frozen.
+#[derive(Default, Debug, Clone, Copy)]
+struct MutablePropertyCount {
+    /// The number of configurable properties.
+    configurable: u32,
+
+    /// The number of writable data properties.
+    writable: u32,
+}
+
+impl MutablePropertyCount {
+    /// Counts the mutable properties of the property table.
+    fn of(property_table: &PropertyTableInner) -> Self {
+        let mut count = Self::default();
+        for (_, slot) in &property_table.keys {
+            count.add(slot.attributes);
+        }
+        count
+    }
+
+    fn add(&mut self, attributes: SlotAttributes) {
+        self.configurable += u32::from(attributes.contains(SlotAttributes::CONFIGURABLE));
+        self.writable += u32::from(attributes.is_writable_data());
+    }
+
+    fn remove(&mut self, attributes: SlotAttributes) {
+        self.configurable -= u32::from(attributes.contains(SlotAttributes::CONFIGURABLE));
+        self.writable -= u32::from(attributes.is_writable_data());
+    }
+}
+
 /// The internal representation of [`UniqueShape`].
 #[derive(Default, Debug, Trace, Finalize)]
 struct Inner {
@@ -20,6 +56,12 @@ struct Inner {
 
     /// The prototype of the shape.
     prototype: GcRefCell<JsPrototype>,
+
+    /// The number of mutable properties in the property table, kept in sync with it.
+    //
+    // SAFETY: This is safe because nothing in this field needs tracing.
+    #[unsafe_ignore_trace]
+    mutable_count: Cell<MutablePropertyCount>,
 }
 
 /// Represents a [`Shape`] that is not shared with any other object.
@@ -36,10 +78,21 @@ pub(crate) struct UniqueShape {
 impl UniqueShape {
     /// Create a new [`UniqueShape`].
     pub(crate) fn new(prototype: JsPrototype, property_table: PropertyTableInner) -> Self {
+        let mutable_count = MutablePropertyCount::of(&property_table);
+        Self::with_mutable_count(prototype, property_table, mutable_count)
+    }
+
+    /// Create a new [`UniqueShape`] whose mutable properties were already counted.
+    fn with_mutable_count(
+        prototype: JsPrototype,
+        property_table: PropertyTableInner,
+        mutable_count: MutablePropertyCount,
+    ) -> Self {
         Self {
             inner: Gc::new(Inner {
                 property_table: RefCell::new(property_table),
                 prototype: GcRefCell::new(prototype),
+                mutable_count: Cell::new(mutable_count),
             }),
         }
     }
@@ -49,6 +102,9 @@ impl UniqueShape {
         property_table: PropertyTableInner,
         prototype: JsPrototype,
     ) {
+        self.inner
+            .mutable_count
+            .set(MutablePropertyCount::of(&property_table));
         *self.inner.property_table.borrow_mut() = property_table;
         *self.inner.prototype.borrow_mut() = prototype;
     }
@@ -58,6 +114,23 @@ impl UniqueShape {
         self.inner.prototype.borrow().clone()
     }
 
+    /// Returns `true` if the shape has a configurable property.
+    pub(crate) fn has_configurable_property(&self) -> bool {
+        self.inner.mutable_count.get().configurable != 0
+    }
+
+    /// Returns `true` if the shape has a writable data property.
+    pub(crate) fn has_writable_property(&self) -> bool {
+        self.inner.mutable_count.get().writable != 0
+    }
+
+    /// Updates the count of mutable properties.
+    fn update_mutable_count(&self, f: impl FnOnce(&mut MutablePropertyCount)) {
+        let mut count = self.inner.mutable_count.get();
+        f(&mut count);
+        self.inner.mutable_count.set(count);
+    }
+
     /// Get the property table of the [`UniqueShape`].
     pub(crate) fn property_table(&self) -> &RefCell<PropertyTableInner> {
         &self.inner.property_table
@@ -67,6 +140,7 @@ impl UniqueShape {
     pub(crate) fn insert_property_transition(&self, key: TransitionKey) -> Self {
         let mut property_table = self.property_table().borrow_mut();
         property_table.insert(key.property_key, key.attributes);
+        self.update_mutable_count(|count| count.add(key.attributes));
         self.clone()
     }
 
@@ -75,9 +149,10 @@ impl UniqueShape {
     /// This will cause the current shape to be invalidated, and a new [`UniqueShape`] will be returned.
     pub(crate) fn remove_property_transition(&self, key: &PropertyKey) -> Self {
         let mut property_table = self.property_table().borrow_mut();
-        let Some((index, _attributes)) = property_table.map.remove(key) else {
+        let Some((index, slot)) = property_table.map.remove(key) else {
             return self.clone();
         };
+        self.update_mutable_count(|count| count.remove(slot.attributes));
 
         let index = index as usize;
 
@@ -113,7 +188,7 @@ impl UniqueShape {
         }
 
         let prototype = self.inner.prototype.borrow_mut().take();
-        Self::new(prototype, property_table)
+        Self::with_mutable_count(prototype, property_table, self.inner.mutable_count.get())
     }
 
     /// Does a property lookup on the [`UniqueShape`] returning the [`Slot`] where it's
@@ -143,6 +218,11 @@ impl UniqueShape {
 
         let index = *index as usize;
 
+        self.update_mutable_count(|count| {
+            count.remove(slot.attributes);
+            count.add(key.attributes);
+        });
+
         // If property does not change type, there is no need to shift.
         if slot.attributes.width_match(key.attributes) {
             slot.attributes = key.attributes;
@@ -208,7 +288,8 @@ impl UniqueShape {
         }
 
         let prototype = self.inner.prototype.borrow_mut().take();
-        let shape = Self::new(prototype, property_table);
+        let shape =
+            Self::with_mutable_count(prototype, property_table, self.inner.mutable_count.get());
 
         ChangeTransition {
             shape: shape.into(),
@@ -225,7 +306,7 @@ impl UniqueShape {
         // We need to create a new unique shape,
         // to invalidate any pointers to this shape i.e inline caches.
         let property_table = std::mem::take(&mut *property_table);
-        Self::new(prototype, property_table)
+        Self::with_mutable_count(prototype, property_table, self.inner.mutable_count.get())
     }
 
     /// Gets all keys first strings then symbols in creation order.
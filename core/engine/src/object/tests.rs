@@ -1,4 +1,4 @@
-use crate::{run_test_actions, JsNativeErrorKind, TestAction};
+use crate::{run_test_actions, run_test_actions_with, JsNativeErrorKind, TestAction};
 use indoc::indoc;
 
 #[test]
@@ -38,3 +38,286 @@ fn object_properties_return_order() {
         ),
     ]);
 }
+
+#[test]
+fn watched_object_reports_changes_through_inline_caches() {
+    use crate::{
+        js_string,
+        object::{PropertyChange, PropertyWatcher},
+        property::{Attribute, PropertyKey},
+        Context, JsObject, JsResult, Source,
+    };
+    use std::{cell::RefCell, rc::Rc};
+
+    #[derive(Default)]
+    struct Log(RefCell<Vec<(PropertyKey, PropertyChange)>>);
+
+    impl PropertyWatcher for Log {
+        fn property_changed(
+            &self,
+            _object: &JsObject,
+            key: &PropertyKey,
+            change: PropertyChange,
+            _context: &mut Context,
+        ) -> JsResult<()> {
+            self.0.borrow_mut().push((key.clone(), change));
+            Ok(())
+        }
+    }
+
+    let log = Rc::new(Log::default());
+    let context = &mut Context::builder()
+        .property_watcher(log.clone())
+        .build()
+        .unwrap();
+
+    let array = context.eval(Source::from_bytes("[0]")).unwrap();
+    let array = array.as_object().unwrap().clone();
+    array.watch();
+    let object = JsObject::with_object_proto(context.intrinsics());
+    object.watch();
+
+    context
+        .register_global_property(js_string!("state"), object, Attribute::all())
+        .unwrap();
+    context
+        .register_global_property(js_string!("list"), array, Attribute::all())
+        .unwrap();
+
+    // The loop warms up the inline cache of the property store.
+    context
+        .eval(Source::from_bytes(
+            "for (let i = 0; i < 3; i++) { state.x = i; list[0] = i; }",
+        ))
+        .unwrap();
+
+    let x: PropertyKey = js_string!("x").into();
+    let zero: PropertyKey = 0u32.into();
+    assert_eq!(
+        *log.0.borrow(),
+        [
+            (x.clone(), PropertyChange::Added),
+            (zero.clone(), PropertyChange::Changed),
+            (x.clone(), PropertyChange::Changed),
+            (zero.clone(), PropertyChange::Changed),
+            (x, PropertyChange::Changed),
+            (zero, PropertyChange::Changed),
+        ]
+    );
+}
+
+#[test]
+fn global_interceptor_bindings_stay_live() {
+    use crate::{
+        context::{intrinsics::Intrinsics, HostHooks},
+        js_string,
+        object::GlobalInterceptor,
+        property::PropertyKey,
+        Context, JsObject, JsResult, JsValue, Source,
+    };
+    use std::{cell::Cell, rc::Rc};
+
+    #[derive(Default)]
+    struct State {
+        count: Cell<i32>,
+    }
+
+    impl GlobalInterceptor for State {
+        fn get(&self, key: &PropertyKey, _context: &mut Context) -> JsResult<Option<JsValue>> {
+            Ok(if *key == PropertyKey::from(js_string!("count")) {
+                Some(self.count.get().into())
+            } else if *key == PropertyKey::from(js_string!("version")) {
+                Some(js_string!("1.0").into())
+            } else {
+                None
+            })
+        }
+
+        fn set(&self, key: &PropertyKey, value: JsValue, context: &mut Context) -> JsResult<bool> {
+            if *key != PropertyKey::from(js_string!("count")) {
+                return Ok(false);
+            }
+            self.count.set(value.to_i32(context)?);
+            Ok(true)
+        }
+
+        fn keys(&self, _context: &mut Context) -> JsResult<Vec<PropertyKey>> {
+            Ok(vec![
+                js_string!("count").into(),
+                js_string!("version").into(),
+            ])
+        }
+    }
+
+    struct Hooks(Rc<State>);
+
+    impl HostHooks for Hooks {
+        fn create_global_object(&self, intrinsics: &Intrinsics) -> JsObject {
+            JsObject::with_global_interceptor(intrinsics, self.0.clone())
+        }
+    }
+
+    let state = Rc::new(State::default());
+    let context = &mut Context::builder()
+        .host_hooks(Rc::new(Hooks(state.clone())))
+        .build()
+        .unwrap();
+
+    // The loop warms up the inline caches of the global lookups, which must not bypass the
+    // interceptor.
+    context
+        .eval(Source::from_bytes(indoc! {r#"
+            function read() { return count; }
+            var seen = [];
+            for (let i = 0; i < 3; i++) { seen.push(read()); globalThis.count++; }
+        "#}))
+        .unwrap();
+    assert_eq!(state.count.get(), 3);
+
+    state.count.set(10);
+    run_test_actions_with(
+        [
+            TestAction::assert_eq("seen.join()", js_string!("0,1,2")),
+            TestAction::assert_eq("read()", 10),
+            TestAction::assert_eq("count = 20; count", 20),
+            TestAction::assert("Object.keys(globalThis).includes('version')"),
+            TestAction::assert(indoc! {r#"
+                globalThis[Symbol.for("host")] = 1;
+                const ownKeys = Reflect.ownKeys(globalThis);
+                ownKeys.indexOf("version") < ownKeys.indexOf(Symbol.for("host"))
+            "#}),
+            TestAction::assert("!delete globalThis.count"),
+            TestAction::assert_eq("version = '2.0'; version", js_string!("1.0")),
+            TestAction::assert_native_error(
+                "'use strict'; version = '2.0';",
+                JsNativeErrorKind::Type,
+                "cannot set non-writable property: version",
+            ),
+            // Bindings the host doesn't have are ordinary properties of the global object.
+            TestAction::assert_eq("var other = 1; globalThis.other", 1),
+            TestAction::assert_eq("typeof Array", js_string!("function")),
+        ],
+        context,
+    );
+    assert_eq!(state.count.get(), 20);
+}
+
+#[test]
+fn weak_js_function_does_not_keep_function_alive() {
+    use crate::{native_function::NativeFunction, object::FunctionObjectBuilder, Context, JsValue};
+
+    let context = &mut Context::default();
+
+    let function = FunctionObjectBuilder::new(
+        context.realm(),
+        NativeFunction::from_fn_ptr(|_, _, _| Ok(JsValue::undefined())),
+    )
+    .build();
+    let weak = function.downgrade();
+
+    boa_gc::force_collect();
+    assert!(weak.is_alive());
+    assert!(weak.upgrade().is_some());
+
+    drop(function);
+    boa_gc::force_collect();
+    assert!(!weak.is_alive());
+    assert!(weak.upgrade().is_none());
+}
+
+#[test]
+fn own_entries_snapshot() {
+    use crate::{
+        js_string, object::AccessorHandling, property::PropertyKey, Context, JsValue, Source,
+    };
+
+    let context = &mut Context::default();
+    let object = context
+        .eval(Source::from_bytes(indoc! {r#"
+            var calls = 0;
+            var symbol = Symbol("s");
+            var object = {
+                b: "b",
+                get a() { calls++; delete this.c; return "a"; },
+                c: "c",
+                [symbol]: "symbol",
+                1: "one",
+            };
+            Object.defineProperty(object, "hidden", { value: "hidden", enumerable: false });
+            object
+        "#}))
+        .unwrap();
+    let object = object.as_object().unwrap();
+
+    let keys = |entries: &[(PropertyKey, JsValue)]| {
+        entries
+            .iter()
+            .map(|(key, _)| key.to_string())
+            .collect::<Vec<_>>()
+    };
+
+    let entries = object.own_entries(AccessorHandling::Skip, context).unwrap();
+    assert_eq!(keys(&entries), ["1", "b", "c", "Symbol(s)"]);
+    assert_eq!(entries[2].1, JsValue::from(js_string!("c")));
+    assert_eq!(
+        context.eval(Source::from_bytes("calls")).unwrap(),
+        JsValue::new(0)
+    );
+
+    let entries = object
+        .own_entries(AccessorHandling::Invoke, context)
+        .unwrap();
+    assert_eq!(keys(&entries), ["1", "b", "a", "Symbol(s)"]);
+    assert_eq!(entries[2].1, JsValue::from(js_string!("a")));
+    assert_eq!(
+        context.eval(Source::from_bytes("calls")).unwrap(),
+        JsValue::new(1)
+    );
+}
+
+#[test]
+fn fast_property_lookups() {
+    use crate::{js_string, Context, JsValue, Source};
+
+    let context = &mut Context::default();
+    let mut eval = |source: &str| {
+        context
+            .eval(Source::from_bytes(source))
+            .unwrap()
+            .as_object()
+            .unwrap()
+            .clone()
+    };
+
+    let proto =
+        eval("var proto = { inherited: 1, get getter() { return 2; }, set setter(v) {} }; proto");
+    let object = eval("var object = Object.create(proto); object.own = 3; object[0] = 4; object");
+    let proxy = eval("new Proxy(object, {})");
+    let string = eval("new String('abc')");
+    let child_of_proxy = eval("Object.create(new Proxy({}, {}))");
+
+    assert_eq!(object.get_fast(js_string!("own")), Some(JsValue::new(3)));
+    assert_eq!(object.get_fast(0), Some(JsValue::new(4)));
+    assert_eq!(
+        object.get_fast(js_string!("inherited")),
+        Some(JsValue::new(1))
+    );
+    assert_eq!(
+        object.get_fast(js_string!("setter")),
+        Some(JsValue::undefined())
+    );
+    assert_eq!(
+        object.get_fast(js_string!("missing")),
+        Some(JsValue::undefined())
+    );
+    assert_eq!(object.get_fast(js_string!("getter")), None);
+    assert_eq!(proxy.get_fast(js_string!("own")), None);
+    assert_eq!(string.get_fast(0), None);
+    assert_eq!(child_of_proxy.get_fast(js_string!("missing")), None);
+
+    assert_eq!(object.has_own_fast(js_string!("own")), Some(true));
+    assert_eq!(object.has_own_fast(js_string!("inherited")), Some(false));
+    assert_eq!(proto.has_own_fast(js_string!("getter")), Some(true));
+    assert_eq!(proxy.has_own_fast(js_string!("own")), None);
+    assert_eq!(string.has_own_fast(0), None);
+}
@@ -0,0 +1,145 @@
+//! Notifications for changes to the properties of watched objects.
+//!
+//! This is a host-side alternative to wrapping objects in a `Proxy`: the internal methods of a
+//! watched object report every successful property addition, modification or deletion to the
+//! [`PropertyWatcher`] of the [`Context`], which allows embedders to keep host state in sync
+//! with script objects.
+
+use super::{shape::slot::SlotAttributes, JsObject};
+use crate::{
+    object::internal_methods::InternalMethodContext, property::PropertyKey, Context, JsResult,
+};
+
+/// The kind of change made to a property of a watched object.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PropertyChange {
+    /// A new own property was defined.
+    Added,
+
+    /// The value or the attributes of an existing own property were redefined.
+    Changed,
+
+    /// An own property was deleted.
+    Deleted,
+}
+
+/// A host hook that gets notified of property changes on watched objects.
+///
+/// Objects are watched by calling [`JsObject::watch`], and the watcher is installed with
+/// [`ContextBuilder::property_watcher`][crate::context::ContextBuilder::property_watcher] or
+/// [`Context::set_property_watcher`].
+///
+/// # Example
+///
+/// ```
+/// use boa_engine::{
+///     object::{PropertyChange, PropertyWatcher},
+///     property::PropertyKey,
+///     Context, JsObject, JsResult, Source,
+/// };
+/// use std::{cell::RefCell, rc::Rc};
+///
+/// #[derive(Default)]
+/// struct Log(RefCell<Vec<(String, PropertyChange)>>);
+///
+/// impl PropertyWatcher for Log {
+///     fn property_changed(
+///         &self,
+///         _object: &JsObject,
+///         key: &PropertyKey,
+///         change: PropertyChange,
+///         _context: &mut Context,
+///     ) -> JsResult<()> {
+///         self.0.borrow_mut().push((key.to_string(), change));
+///         Ok(())
+///     }
+/// }
+///
+/// let log = Rc::new(Log::default());
+/// let context = &mut Context::builder()
+///     .property_watcher(log.clone())
+///     .build()
+///     .unwrap();
+///
+/// let object = JsObject::with_object_proto(context.intrinsics());
+/// object.watch();
+/// context
+///     .register_global_property(
+///         boa_engine::js_string!("state"),
+///         object,
+///         boa_engine::property::Attribute::all(),
+///     )
+///     .unwrap();
+///
+/// context
+///     .eval(Source::from_bytes("state.a = 1; state.a = 2; delete state.a;"))
+///     .unwrap();
+///
+/// assert_eq!(
+///     *log.0.borrow(),
+///     [
+///         ("a".to_owned(), PropertyChange::Added),
+///         ("a".to_owned(), PropertyChange::Changed),
+///         ("a".to_owned(), PropertyChange::Deleted),
+///     ]
+/// );
+/// ```
+pub trait PropertyWatcher {
+    /// Called after the property `key` of the watched `object` was successfully changed.
+    ///
+    /// Returning an error throws it as the result of the operation that changed the property.
+    /// Note that the change itself is not reverted.
+    fn property_changed(
+        &self,
+        object: &JsObject,
+        key: &PropertyKey,
+        change: PropertyChange,
+        context: &mut Context,
+    ) -> JsResult<()>;
+}
+
+impl JsObject {
+    /// Starts reporting the property changes of this object to the [`PropertyWatcher`] of the
+    /// context.
+    ///
+    /// Watching an object disables the inline caches and the fast paths of the VM for it, since
+    /// every change needs to go through its internal methods.
+    pub fn watch(&self) {
+        let mut object = self.borrow_mut();
+        object.watched = true;
+
+        // Inline caches are keyed by shape, so a watched object needs a shape that no existing
+        // cache entry could match, otherwise property writes could bypass its internal methods.
+        let shape = object.properties.shape.to_new_unique();
+        object.properties.shape = shape;
+        object.properties.invalidate_inline_caches();
+    }
+
+    /// Stops reporting the property changes of this object.
+    pub fn unwatch(&self) {
+        self.borrow_mut().watched = false;
+    }
+
+    /// Returns `true` if the property changes of this object are reported to the
+    /// [`PropertyWatcher`] of the context.
+    #[must_use]
+    pub fn is_watched(&self) -> bool {
+        self.borrow().watched
+    }
+
+    /// Reports a property change of this watched object to the [`PropertyWatcher`] of the context,
+    /// and marks the current property access as not cachable.
+    pub(crate) fn notify_property_change(
+        &self,
+        key: &PropertyKey,
+        change: PropertyChange,
+        context: &mut InternalMethodContext<'_>,
+    ) -> JsResult<()> {
+        context.slot().attributes |= SlotAttributes::NOT_CACHABLE;
+
+        let Some(watcher) = context.property_watcher() else {
+            return Ok(());
+        };
+        watcher.property_changed(self, key, change, context)
+    }
+}
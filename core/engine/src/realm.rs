@@ -19,8 +19,15 @@ use crate::{
     },
     environments::DeclarativeEnvironment,
     module::Module,
-    object::shape::RootShape,
-    HostDefined, JsNativeError, JsObject, JsResult, JsString,
+    native_function::NativeFunction,
+    object::{
+        internal_methods::InternalMethodContext, shape::RootShape, FunctionObjectBuilder,
+        NativeObject,
+    },
+    property::PropertyDescriptor,
+    string::StaticJsStrings,
+    vm::GlobalPropertyCache,
+    Context, HostDefined, JsNativeError, JsObject, JsResult, JsString, JsValue,
 };
 use boa_gc::{Finalize, Gc, GcRef, GcRefCell, GcRefMut, Trace};
 use boa_profiler::Profiler;
@@ -71,9 +78,33 @@ struct Inner {
     loaded_modules: GcRefCell<FxHashMap<JsString, Module>>,
     host_classes: GcRefCell<FxHashMap<TypeId, StandardConstructor>>,
 
+    /// The intrinsic functions replaced with [`Realm::override_intrinsic`], by path.
+    overridden_intrinsics: GcRefCell<FxHashMap<JsString, OverriddenIntrinsic>>,
+
+    /// The cache of the properties of the global object, shared by the global name loads.
+    // Safety: Nothing in `GlobalPropertyCache` needs tracing.
+    #[unsafe_ignore_trace]
+    global_property_cache: GlobalPropertyCache,
+
+    /// The `Intl.NumberFormat` used by `Number.prototype.toLocaleString` when called without
+    /// locales and options.
+    #[cfg(feature = "intl")]
+    default_number_format: GcRefCell<Option<JsObject<crate::builtins::intl::NumberFormat>>>,
+
     host_defined: GcRefCell<HostDefined>,
 }
 
+/// An intrinsic function replaced with [`Realm::override_intrinsic`].
+#[derive(Trace, Finalize)]
+struct OverriddenIntrinsic {
+    /// The object with the property of the function.
+    holder: JsObject,
+    key: JsString,
+
+    /// The value of the property before it was first overridden.
+    original: JsValue,
+}
+
 impl Realm {
     /// Create a new [`Realm`].
     #[inline]
@@ -101,6 +132,10 @@ impl Realm {
                 template_map: GcRefCell::default(),
                 loaded_modules: GcRefCell::default(),
                 host_classes: GcRefCell::default(),
+                overridden_intrinsics: GcRefCell::default(),
+                global_property_cache: GlobalPropertyCache::default(),
+                #[cfg(feature = "intl")]
+                default_number_format: GcRefCell::default(),
                 host_defined: GcRefCell::default(),
             }),
         };
@@ -145,6 +180,64 @@ impl Realm {
         self.inner.host_defined.borrow_mut()
     }
 
+    /// Insert a type into the [`HostDefined`] field of the [`Realm`].
+    ///
+    /// Unlike the data stored in the [`Context`][crate::Context], which is kept alive for as long
+    /// as the context exists, the data of a realm is traced through the realm, so any garbage
+    /// collected values it holds are collected together with it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`HostDefined`] field is borrowed.
+    #[inline]
+    pub fn insert_data<T: NativeObject>(&self, value: T) -> Option<Box<T>> {
+        self.host_defined_mut().insert(value)
+    }
+
+    /// Check if the [`HostDefined`] field of the [`Realm`] has type T.
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`HostDefined`] field is mutably borrowed.
+    #[inline]
+    #[must_use]
+    pub fn has_data<T: NativeObject>(&self) -> bool {
+        self.host_defined().has::<T>()
+    }
+
+    /// Remove type T from the [`HostDefined`] field of the [`Realm`], if it exists.
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`HostDefined`] field is borrowed.
+    #[inline]
+    pub fn remove_data<T: NativeObject>(&self) -> Option<Box<T>> {
+        self.host_defined_mut().remove::<T>()
+    }
+
+    /// Get type T from the [`HostDefined`] field of the [`Realm`], if it exists.
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`HostDefined`] field is mutably borrowed.
+    #[inline]
+    #[must_use]
+    pub fn get_data<T: NativeObject>(&self) -> Option<GcRef<'_, T>> {
+        GcRef::try_map(self.host_defined(), HostDefined::get::<T>)
+    }
+
+    /// Get a mutable reference to type T from the [`HostDefined`] field of the [`Realm`],
+    /// if it exists.
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`HostDefined`] field is borrowed.
+    #[inline]
+    #[must_use]
+    pub fn get_data_mut<T: NativeObject>(&self) -> Option<GcRefMut<'_, HostDefined, T>> {
+        GcRefMut::try_map(self.host_defined_mut(), HostDefined::get_mut::<T>)
+    }
+
     /// Checks if this `Realm` has the class `C` registered into its class map.
     #[must_use]
     pub fn has_class<C: Class>(&self) -> bool {
@@ -164,6 +257,132 @@ impl Realm {
             .cloned()
     }
 
+    /// Replaces the intrinsic function at `path` with a native `function`, until it is restored
+    /// with [`Realm::restore_intrinsic`].
+    ///
+    /// `path` is the dot-separated path of the function from the global object of the realm,
+    /// e.g. `"Math.random"`, `"Date.now"` or `"Array.prototype.sort"`. The replacement gets the
+    /// name of the last segment of the path and the `length` of the replaced function, and the
+    /// property keeps its attributes, so the shapes and inline caches of the objects stay valid.
+    ///
+    /// This is meant for test harnesses that need to stub randomness or time. Only the code that
+    /// gets the function through its property sees the replacement, and built-ins that use the
+    /// function internally keep using the original. The current time used by `new Date()` can be
+    /// stubbed with a [`Clock`][crate::context::Clock] instead.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TypeError` if `path` is not the path of a function in data properties, or if
+    /// the property of the function can't be redefined.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use boa_engine::{Context, JsValue, NativeFunction, Source};
+    /// let context = &mut Context::default();
+    /// let realm = context.realm().clone();
+    ///
+    /// realm
+    ///     .override_intrinsic(
+    ///         "Math.random",
+    ///         NativeFunction::from_fn_ptr(|_, _, _| Ok(JsValue::new(0.5))),
+    ///         context,
+    ///     )
+    ///     .unwrap();
+    /// let value = context.eval(Source::from_bytes("Math.random()")).unwrap();
+    /// assert_eq!(value, JsValue::new(0.5));
+    ///
+    /// assert!(realm.restore_intrinsic("Math.random", context).unwrap());
+    /// let value = context.eval(Source::from_bytes("Math.random()")).unwrap();
+    /// assert_ne!(value, JsValue::new(0.5));
+    /// ```
+    pub fn override_intrinsic(
+        &self,
+        path: &str,
+        function: NativeFunction,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let not_a_function =
+            || JsNativeError::typ().with_message(format!("`{path}` is not an intrinsic function"));
+
+        let (parents, name) = path.rsplit_once('.').unwrap_or(("", path));
+        let mut holder = self.global_object().clone();
+        for segment in parents.split('.').filter(|segment| !segment.is_empty()) {
+            holder = own_data_value(&holder, segment, context)?
+                .and_then(|value| value.as_object().cloned())
+                .ok_or_else(not_a_function)?;
+        }
+        let current = own_data_value(&holder, name, context)?
+            .and_then(|value| value.as_callable().cloned())
+            .ok_or_else(not_a_function)?;
+
+        let length = current
+            .get(StaticJsStrings::LENGTH, context)?
+            .to_length(context)?;
+        let key = JsString::from(name);
+        let replacement = FunctionObjectBuilder::new(self, function)
+            .name(key.clone())
+            .length(usize::try_from(length).unwrap_or_default())
+            .build();
+        holder.define_property_or_throw(
+            key.clone(),
+            PropertyDescriptor::builder().value(replacement),
+            context,
+        )?;
+
+        self.inner
+            .overridden_intrinsics
+            .borrow_mut()
+            .entry(JsString::from(path))
+            .or_insert(OverriddenIntrinsic {
+                holder,
+                key,
+                original: current.into(),
+            });
+        Ok(())
+    }
+
+    /// Restores the intrinsic function at `path` that was replaced with
+    /// [`Realm::override_intrinsic`].
+    ///
+    /// Returns `false` if the function at `path` is not overridden.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TypeError` if the property of the function can't be redefined anymore, e.g.
+    /// because it was frozen after it was overridden.
+    pub fn restore_intrinsic(&self, path: &str, context: &mut Context) -> JsResult<bool> {
+        let Some(intrinsic) = self
+            .inner
+            .overridden_intrinsics
+            .borrow_mut()
+            .remove(&JsString::from(path))
+        else {
+            return Ok(false);
+        };
+
+        intrinsic.restore(context)?;
+        Ok(true)
+    }
+
+    /// Restores all the intrinsic functions that were replaced with
+    /// [`Realm::override_intrinsic`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TypeError` if the property of a function can't be redefined anymore. The other
+    /// functions are still restored.
+    pub fn restore_intrinsics(&self, context: &mut Context) -> JsResult<()> {
+        let intrinsics = std::mem::take(&mut *self.inner.overridden_intrinsics.borrow_mut());
+
+        let mut result = Ok(());
+        for intrinsic in intrinsics.into_values() {
+            let restored = intrinsic.restore(context);
+            result = result.and(restored);
+        }
+        result
+    }
+
     pub(crate) fn environment(&self) -> &Gc<DeclarativeEnvironment> {
         &self.inner.environment
     }
@@ -182,6 +401,10 @@ impl Realm {
         &self.inner.global_this
     }
 
+    pub(crate) fn global_property_cache(&self) -> &GlobalPropertyCache {
+        &self.inner.global_property_cache
+    }
+
     pub(crate) fn loaded_modules(&self) -> &GcRefCell<FxHashMap<JsString, Module>> {
         &self.inner.loaded_modules
     }
@@ -210,6 +433,20 @@ impl Realm {
         self.inner.template_map.borrow().get(&site).cloned()
     }
 
+    /// Gets the default `Intl.NumberFormat` of this realm, creating it with `init` on first use.
+    #[cfg(feature = "intl")]
+    pub(crate) fn default_number_format(
+        &self,
+        init: impl FnOnce() -> JsResult<JsObject<crate::builtins::intl::NumberFormat>>,
+    ) -> JsResult<JsObject<crate::builtins::intl::NumberFormat>> {
+        if let Some(number_format) = self.inner.default_number_format.borrow().clone() {
+            return Ok(number_format);
+        }
+        let number_format = init()?;
+        *self.inner.default_number_format.borrow_mut() = Some(number_format.clone());
+        Ok(number_format)
+    }
+
     pub(crate) fn register_class<C: Class>(&self, spec: StandardConstructor) {
         self.inner
             .host_classes
@@ -229,3 +466,28 @@ impl Realm {
         ptr.cast()
     }
 }
+
+impl OverriddenIntrinsic {
+    /// Sets the property of the function back to its original value.
+    fn restore(self, context: &mut Context) -> JsResult<()> {
+        self.holder.define_property_or_throw(
+            self.key,
+            PropertyDescriptor::builder().value(self.original),
+            context,
+        )?;
+        Ok(())
+    }
+}
+
+/// Gets the value of the own data property `name` of `object`, without calling any getter.
+fn own_data_value(
+    object: &JsObject,
+    name: &str,
+    context: &mut Context,
+) -> JsResult<Option<JsValue>> {
+    let desc = object.__get_own_property__(
+        &JsString::from(name).into(),
+        &mut InternalMethodContext::new(context),
+    )?;
+    Ok(desc.and_then(|desc| desc.value().cloned()))
+}
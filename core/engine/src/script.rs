@@ -8,10 +8,11 @@
 //! [spec]: https://tc39.es/ecma262/#sec-scripts
 //! [script]: https://tc39.es/ecma262/#sec-script-records
 
-use std::path::{Path, PathBuf};
+use std::{path::Path, rc::Rc};
 
 use rustc_hash::FxHashMap;
 
+use boa_ast::{Statement, StatementListItem};
 use boa_gc::{Finalize, Gc, GcRefCell, Trace};
 use boa_parser::{source::ReadChar, Parser, Source};
 use boa_profiler::Profiler;
@@ -21,8 +22,10 @@ use crate::{
     js_string,
     realm::Realm,
     spanned_source_text::SourceText,
-    vm::{ActiveRunnable, CallFrame, CallFrameFlags, CodeBlock},
-    Context, HostDefined, JsResult, JsString, JsValue, Module, SpannedSourceText,
+    vm::{
+        run_suspendable, ActiveRunnable, CallFrame, CallFrameFlags, CodeBlock, SuspendableResult,
+    },
+    Context, HostDefined, JsError, JsResult, JsString, JsValue, Module, SpannedSourceText,
 };
 
 /// ECMAScript's [**Script Record**][spec].
@@ -52,7 +55,8 @@ struct Inner {
     codeblock: GcRefCell<Option<Gc<CodeBlock>>>,
     loaded_modules: GcRefCell<FxHashMap<JsString, Module>>,
     host_defined: HostDefined,
-    path: Option<PathBuf>,
+    #[unsafe_ignore_trace]
+    path: Option<Rc<Path>>,
 }
 
 impl Script {
@@ -86,14 +90,16 @@ impl Script {
         context: &mut Context,
     ) -> JsResult<Self> {
         let _timer = Profiler::global().start_event("Script parsing", "Main");
-        let path = src.path().map(Path::to_path_buf);
+        let path = src.path().map(Rc::from);
         let mut parser = Parser::new(src);
         parser.set_identifier(context.next_parser_identifier());
         if context.is_strict() {
             parser.set_strict();
         }
         let scope = context.realm().scope().clone();
-        let (mut code, source) = parser.parse_script_with_source(&scope, context.interner_mut())?;
+        let (mut code, source) = parser
+            .parse_script_with_source(&scope, context.interner_mut())
+            .map_err(|err| JsError::from_parse_error(err, path.clone()))?;
         if !context.optimizer_options().is_empty() {
             context.optimize_statement_list(code.statements_mut());
         }
@@ -218,6 +224,56 @@ impl Script {
         record.consume()
     }
 
+    /// Evaluates this script and returns its result, allowing the native functions that it calls
+    /// to suspend it with [`Context::suspend`].
+    ///
+    /// If the script is suspended, this returns a [`Continuation`] that resumes it, for example
+    /// once a blocking operation of the host has finished.
+    ///
+    /// # Examples
+    /// ```
+    /// # use boa_engine::{
+    /// #     js_string, vm::SuspendableResult, Context, JsValue, NativeFunction, Script, Source,
+    /// # };
+    /// let mut context = Context::default();
+    /// context
+    ///     .register_global_callable(
+    ///         js_string!("read"),
+    ///         0,
+    ///         NativeFunction::from_fn_ptr(|_, _, context| {
+    ///             context.suspend()?;
+    ///             Ok(JsValue::undefined())
+    ///         }),
+    ///     )
+    ///     .unwrap();
+    ///
+    /// let source = Source::from_bytes("read() + read()");
+    /// let script = Script::parse(source, None, &mut context).unwrap();
+    ///
+    /// let mut result = script.evaluate_suspendable(&mut context).unwrap();
+    /// let mut value = 0;
+    /// while let SuspendableResult::Suspended(continuation) = result {
+    ///     value += 1;
+    ///     result = continuation.resume(value.into(), &mut context).unwrap();
+    /// }
+    ///
+    /// assert!(matches!(result, SuspendableResult::Complete(value) if value == JsValue::from(3)));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns the error thrown by the script if it is not caught.
+    ///
+    /// [`Continuation`]: crate::vm::Continuation
+    pub fn evaluate_suspendable(&self, context: &mut Context) -> JsResult<SuspendableResult> {
+        let _timer = Profiler::global().start_event("Suspendable Execution", "Main");
+
+        self.prepare_run(context)?;
+        context.vm.frame_mut().flags |= CallFrameFlags::SUSPENDABLE;
+
+        run_suspendable(context)
+    }
+
     fn prepare_run(&self, context: &mut Context) -> JsResult<()> {
         let codeblock = self.codeblock(context)?;
 
@@ -242,10 +298,23 @@ impl Script {
         Ok(())
     }
 
+    /// Returns `true` if this script consists of a single expression statement.
+    pub(crate) fn is_single_expression(&self) -> bool {
+        matches!(
+            self.inner.source.statements().statements(),
+            [StatementListItem::Statement(statement)]
+                if matches!(**statement, Statement::Expression(_))
+        )
+    }
+
     pub(super) fn path(&self) -> Option<&Path> {
         self.inner.path.as_deref()
     }
 
+    pub(crate) fn shared_path(&self) -> Option<&Rc<Path>> {
+        self.inner.path.as_ref()
+    }
+
     pub(super) fn get_source(&self) -> SourceText {
         self.inner.source_text.clone()
     }
@@ -21,6 +21,8 @@
     clippy::missing_safety_doc
 )]
 
+pub use crate::builtins::symbol::SymbolRegistry;
+
 use crate::{
     js_string,
     string::{JsString, StaticJsStrings},
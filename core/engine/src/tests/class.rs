@@ -1,4 +1,4 @@
-use crate::{run_test_actions, TestAction};
+use crate::{run_test_actions, run_test_actions_with, Context, JsNativeErrorKind, TestAction};
 use boa_macros::js_str;
 use indoc::indoc;
 
@@ -72,3 +72,254 @@ fn class_can_access_super_from_static_initializer() {
         TestAction::assert_eq("c.field", js_str!("super field")),
     ]);
 }
+
+#[test]
+fn class_private_in_relational_chain() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            class C {
+                #x;
+                static check(o) {
+                    return #x in o in { true: 1 };
+                }
+                static compare(o) {
+                    return #x in o < 1;
+                }
+            }
+        "#}),
+        TestAction::assert("C.check(new C())"),
+        TestAction::assert("!C.check({})"),
+        TestAction::assert("!C.compare(new C())"),
+        TestAction::assert("C.compare({})"),
+    ]);
+}
+
+#[test]
+fn class_private_name_early_errors() {
+    run_test_actions([
+        TestAction::assert_native_error(
+            "class C { m(o) { return #y in o; } }",
+            JsNativeErrorKind::Syntax,
+            "undeclared private name `#y` at line 1, col 25",
+        ),
+        TestAction::assert_native_error(
+            "class C { #x; m() { return this.#x + this?.#z; } }",
+            JsNativeErrorKind::Syntax,
+            "undeclared private name `#z` at line 1, col 44",
+        ),
+        TestAction::assert_native_error(
+            "class C { get #x() {} static set #x(v) {} }",
+            JsNativeErrorKind::Syntax,
+            "private identifier has already been declared at line 1, col 34",
+        ),
+        TestAction::assert_native_error(
+            "class C { static { await 1; } }",
+            JsNativeErrorKind::Syntax,
+            "invalid await usage at line 1, col 20",
+        ),
+    ]);
+}
+
+#[test]
+fn class_private_accessor_inheritance() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            class A {
+                get #x() { return 1; }
+                set #y(v) {}
+                static get #s() { return 2; }
+                static has(o) { return #x in o; }
+                static getX(o) { return o.#x; }
+                static setX(o) { o.#x = 2; }
+                static getY(o) { return o.#y; }
+                static getS(o) { return o.#s; }
+            }
+            class B extends A {}
+        "#}),
+        TestAction::assert("A.has(new B())"),
+        TestAction::assert("!A.has(B)"),
+        TestAction::assert_eq("A.getX(new B())", 1),
+        TestAction::assert_eq("A.getS(A)", 2),
+        TestAction::assert_native_error(
+            "A.setX(new B())",
+            JsNativeErrorKind::Type,
+            "private property was defined without a setter",
+        ),
+        TestAction::assert_native_error(
+            "A.getY(new B())",
+            JsNativeErrorKind::Type,
+            "private property was defined without a getter",
+        ),
+        TestAction::assert_native_error(
+            "A.getS(B)",
+            JsNativeErrorKind::Type,
+            "Private element does not exist on object",
+        ),
+    ]);
+}
+
+const CLASS_FIELD_SEMANTICS_SETUP: &str = indoc! {r#"
+    var log = [];
+    class A {
+        set x(v) { log.push("x=" + v); }
+        static set y(v) { log.push("y=" + v); }
+        static set z(v) { log.push("z=" + v); }
+    }
+    class B extends A {
+        x = 1;
+        static y = 2;
+        static ["z"] = 3;
+    }
+    var b = new B();
+"#};
+
+#[test]
+fn class_fields_use_define_semantics() {
+    run_test_actions([
+        TestAction::run(CLASS_FIELD_SEMANTICS_SETUP),
+        TestAction::assert_eq("log.length", 0),
+        TestAction::assert_eq("Object.getOwnPropertyDescriptor(b, 'x').value", 1),
+        TestAction::assert_eq("Object.getOwnPropertyDescriptor(B, 'y').value", 2),
+        TestAction::assert_eq("Object.getOwnPropertyDescriptor(B, 'z').value", 3),
+    ]);
+}
+
+#[test]
+fn class_fields_use_set_semantics() {
+    let context = &mut Context::builder()
+        .class_fields_use_set(true)
+        .build()
+        .unwrap();
+    run_test_actions_with(
+        [
+            TestAction::run(CLASS_FIELD_SEMANTICS_SETUP),
+            TestAction::assert_eq("log.join()", js_str!("y=2,z=3,x=1")),
+            TestAction::assert("!Object.hasOwn(b, 'x')"),
+            TestAction::assert("!Object.hasOwn(B, 'y')"),
+            TestAction::assert("!Object.hasOwn(B, 'z')"),
+            TestAction::run(indoc! {r#"
+                class C {
+                    #p = 1;
+                    q = 2;
+                    get p() { return this.#p; }
+                }
+                var c = new C();
+            "#}),
+            TestAction::assert_eq("c.p", 1),
+            TestAction::assert_eq("c.q", 2),
+        ],
+        context,
+    );
+}
+
+#[test]
+fn class_auto_accessors() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var key = "c";
+            class C {
+                accessor a = 1;
+                static accessor b = 2;
+                accessor [key] = 3;
+                accessor #d = 4;
+                static accessor #e = 5;
+                getD() { return this.#d; }
+                setD(v) { this.#d = v; }
+                static getE() { return C.#e; }
+            }
+            var c = new C();
+        "#}),
+        TestAction::assert_eq("c.a", 1),
+        TestAction::assert_eq("C.b", 2),
+        TestAction::assert_eq("c.c", 3),
+        TestAction::assert_eq("c.getD()", 4),
+        TestAction::assert_eq("C.getE()", 5),
+        TestAction::assert("!Object.hasOwn(c, 'a')"),
+        TestAction::run("c.a = 10; c.setD(40);"),
+        TestAction::assert_eq("c.a", 10),
+        TestAction::assert_eq("c.getD()", 40),
+        TestAction::assert_eq("new C().a", 1),
+        TestAction::run("var desc = Object.getOwnPropertyDescriptor(C.prototype, 'a');"),
+        TestAction::assert("!desc.enumerable && desc.configurable"),
+        TestAction::assert_eq("desc.get.name", js_str!("get a")),
+        TestAction::assert_eq("desc.set.name", js_str!("set a")),
+        TestAction::assert_native_error(
+            "desc.get.call({})",
+            JsNativeErrorKind::Type,
+            "Private element does not exist on object",
+        ),
+    ]);
+}
+
+#[test]
+fn class_accessor_as_field_name() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            class C {
+                accessor = 1;
+                static accessor;
+                accessor() { return 2; }
+            }
+            class D {
+                accessor
+                x = 3;
+            }
+            var d = new D();
+        "#}),
+        TestAction::assert_eq("new C().accessor", 1),
+        TestAction::assert("Object.hasOwn(C, 'accessor')"),
+        TestAction::assert_eq("C.prototype.accessor()", 2),
+        TestAction::assert("Object.hasOwn(d, 'accessor')"),
+        TestAction::assert_eq("d.x", 3),
+    ]);
+}
+
+#[test]
+fn class_field_initializer_captures_outer_bindings() {
+    run_test_actions([TestAction::assert_eq(
+        indoc! {r#"
+            function f() {
+                let a = 1;
+                class C {
+                    x = a;
+                    static y = a + 1;
+                    #z = a + 2;
+                    get z() { return this.#z; }
+                }
+                const c = new C();
+                return [c.x, C.y, c.z].join();
+            }
+            f();
+        "#},
+        js_str!("1,2,3"),
+    )]);
+}
+
+#[test]
+fn class_name_binding() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            class A { m() { return 1; } }
+            class B { m() { return B; } }
+            class C { static self = C; }
+            class D { m() { return eval("D"); } }
+            const E = class F { m() { return F; } };
+            const G = class H { [eval("'key'")]() { return 2; } };
+        "#}),
+        TestAction::assert_eq("new A().m()", 1),
+        TestAction::assert("new B().m() === B"),
+        TestAction::assert("C.self === C"),
+        TestAction::assert("new D().m() === D"),
+        TestAction::assert("new E().m() === E"),
+        TestAction::assert_eq("new G().key()", 2),
+        TestAction::assert(indoc! {r#"
+            (() => {
+                try {
+                    class I extends I {}
+                } catch (e) {
+                    return e instanceof ReferenceError;
+                }
+            })()
+        "#}),
+    ]);
+}
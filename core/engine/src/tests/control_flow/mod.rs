@@ -429,6 +429,96 @@ fn bigger_switch_example() {
     ]);
 }
 
+#[test]
+fn dense_integer_switch_table() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+                function f(a) {
+                    switch (a) {
+                        case 1: return "one";
+                        case 2: return "two";
+                        default: return "default";
+                        case 4: return "four";
+                        case 2: return "duplicate";
+                        case 0: return "zero";
+                    }
+                }
+            "#}),
+        TestAction::assert_eq("f(0)", js_str!("zero")),
+        TestAction::assert_eq("f(-0)", js_str!("zero")),
+        TestAction::assert_eq("f(1.0)", js_str!("one")),
+        TestAction::assert_eq("f(2)", js_str!("two")),
+        TestAction::assert_eq("f(3)", js_str!("default")),
+        TestAction::assert_eq("f(4)", js_str!("four")),
+        TestAction::assert_eq("f(1.5)", js_str!("default")),
+        TestAction::assert_eq("f(-1)", js_str!("default")),
+        TestAction::assert_eq("f(NaN)", js_str!("default")),
+        TestAction::assert_eq("f(Infinity)", js_str!("default")),
+        TestAction::assert_eq("f('1')", js_str!("default")),
+        TestAction::assert_eq("f(1n)", js_str!("default")),
+        TestAction::assert_eq("f(new Number(1))", js_str!("default")),
+    ]);
+}
+
+#[test]
+fn sparse_switch_table() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+                function f(a) {
+                    let result = "";
+                    switch (a) {
+                        case "a":
+                            result += "a";
+                        case 1000:
+                            result += "1000";
+                            break;
+                        case 0.5:
+                            result += "0.5";
+                        case "":
+                            result += "empty";
+                            break;
+                        case "a":
+                            result += "duplicate";
+                    }
+                    return result;
+                }
+            "#}),
+        TestAction::assert_eq("f('a')", js_str!("a1000")),
+        TestAction::assert_eq("f(1000)", js_str!("1000")),
+        TestAction::assert_eq("f('1000')", js_str!("")),
+        TestAction::assert_eq("f(0.5)", js_str!("0.5empty")),
+        TestAction::assert_eq("f('')", js_str!("empty")),
+        TestAction::assert_eq("f(0)", js_str!("")),
+        TestAction::assert_eq("f(undefined)", js_str!("")),
+    ]);
+}
+
+#[test]
+fn switch_table_not_used_with_non_literal_case() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+                let evaluated = [];
+                function c(value) {
+                    evaluated.push(value);
+                    return value;
+                }
+                function f(a) {
+                    switch (a) {
+                        case 1: return "one";
+                        case 2: return "two";
+                        case c(3): return "three";
+                        case 4: return "four";
+                        case c(5): return "five";
+                    }
+                }
+            "#}),
+        TestAction::assert_eq("f(4)", js_str!("four")),
+        TestAction::assert_eq("evaluated.join()", js_str!("3")),
+        TestAction::assert_eq("f(5)", js_str!("five")),
+        TestAction::assert_eq("evaluated.join()", js_str!("3,3,5")),
+    ]);
+}
+
 #[test]
 fn break_labelled_if_statement() {
     run_test_actions([TestAction::assert_eq(
@@ -462,3 +552,187 @@ fn break_labelled_try_statement() {
         js_str!("foo"),
     )]);
 }
+
+#[test]
+fn return_overrides_finally() {
+    run_test_actions([
+        TestAction::assert_eq(
+            indoc! {r#"
+                (function() {
+                    try {
+                        return "try";
+                    } finally {
+                        return "finally";
+                    }
+                })()
+            "#},
+            js_str!("finally"),
+        ),
+        TestAction::assert_eq(
+            indoc! {r#"
+                (function() {
+                    for (;;) {
+                        try {
+                            return "try";
+                        } finally {
+                            break;
+                        }
+                    }
+                    return "after loop";
+                })()
+            "#},
+            js_str!("after loop"),
+        ),
+    ]);
+}
+
+#[test]
+fn continue_across_finally() {
+    run_test_actions([TestAction::assert_eq(
+        indoc! {r#"
+            var log = [];
+            for (var i = 0; i < 3; i++) {
+                try {
+                    if (i === 1) {
+                        continue;
+                    }
+                    log.push(i);
+                } finally {
+                    log.push("f" + i);
+                }
+            }
+            log.join()
+        "#},
+        js_str!("0,f0,f1,2,f2"),
+    )]);
+}
+
+#[test]
+fn nested_labels_across_finally() {
+    run_test_actions([TestAction::assert_eq(
+        indoc! {r#"
+            var log = [];
+            a: {
+                b: for (var i = 0; i < 2; i++) {
+                    try {
+                        try {
+                            if (i === 1) {
+                                break a;
+                            }
+                            continue b;
+                        } finally {
+                            log.push("inner" + i);
+                        }
+                    } finally {
+                        log.push("outer" + i);
+                    }
+                }
+                log.push("unreachable");
+            }
+            log.join()
+        "#},
+        js_str!("inner0,outer0,inner1,outer1"),
+    )]);
+}
+
+#[test]
+fn labelled_break_closes_inner_iterators() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var closed = 0;
+            var iterable = {
+                [Symbol.iterator]() {
+                    return {
+                        next() { return { done: false, value: 1 }; },
+                        return() { closed++; return {}; },
+                    };
+                },
+            };
+        "#}),
+        TestAction::assert_eq(
+            indoc! {r#"
+                outer: while (true) {
+                    for (var x of iterable) {
+                        break outer;
+                    }
+                }
+                closed
+            "#},
+            1,
+        ),
+        TestAction::assert_eq(
+            indoc! {r#"
+                closed = 0;
+                outer: for (var a of iterable) {
+                    for (var b of iterable) {
+                        try {
+                            break outer;
+                        } finally {
+                            closed += 10;
+                        }
+                    }
+                }
+                closed
+            "#},
+            12,
+        ),
+    ]);
+}
+
+#[test]
+fn merged_jumps_across_finally() {
+    run_test_actions([
+        TestAction::assert_eq(
+            indoc! {r#"
+                var log = [];
+                for (var i = 0; i < 6; i++) {
+                    try {
+                        if (i % 3 === 0) {
+                            continue;
+                        }
+                        if (i === 4) {
+                            break;
+                        }
+                        if (i % 3 === 1) {
+                            continue;
+                        }
+                        log.push(i);
+                        if (i === 5) {
+                            break;
+                        }
+                    } finally {
+                        log.push("f" + i);
+                    }
+                }
+                log.join()
+            "#},
+            js_str!("f0,f1,2,f2,f3,f4"),
+        ),
+        TestAction::assert_eq(
+            indoc! {r#"
+                (function(n) {
+                    var log = [];
+                    try {
+                        try {
+                            if (n === 0) {
+                                return "zero";
+                            }
+                            if (n === 1) {
+                                return "one";
+                            }
+                        } finally {
+                            log.push("inner");
+                        }
+                        return "other";
+                    } finally {
+                        log.push("outer");
+                        if (log.join() !== "inner,outer") {
+                            return "unexpected " + log.join();
+                        }
+                    }
+                })(1)
+            "#},
+            js_str!("one"),
+        ),
+    ]);
+}
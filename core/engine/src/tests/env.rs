@@ -1,7 +1,10 @@
 use boa_macros::js_str;
 use indoc::indoc;
 
-use crate::{run_test_actions, JsNativeErrorKind, TestAction};
+use crate::{
+    context::ContextBuilder, run_test_actions, run_test_actions_with, JsNativeErrorKind, JsValue,
+    TestAction,
+};
 
 #[test]
 // https://github.com/boa-dev/boa/issues/2317
@@ -47,3 +50,327 @@ fn with_env_not_panic() {
         "k is not defined",
     )]);
 }
+
+#[test]
+fn with_delete_binding() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var x = "outer";
+            var o = { x: "inner" };
+            var deleted;
+            with (o) {
+                deleted = delete x;
+            }
+        "#}),
+        TestAction::assert("deleted"),
+        TestAction::assert("!('x' in o)"),
+        TestAction::assert_eq("x", js_str!("outer")),
+        TestAction::assert_eq(
+            indoc! {r#"
+                var p = { y: "inner", [Symbol.unscopables]: { y: true } };
+                var y = "outer";
+                with (p) {
+                    y;
+                }
+            "#},
+            js_str!("outer"),
+        ),
+    ]);
+}
+
+#[test]
+fn with_binding_removed_after_lookup() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var log = [];
+            var target = { x: 1 };
+            var p = new Proxy(target, {
+                has(t, k) { log.push("has:" + String(k)); return k in t; },
+                get(t, k) { log.push("get:" + String(k)); return t[k]; },
+            });
+            with (p) {
+                x;
+            }
+        "#}),
+        TestAction::assert_eq(
+            "log.join()",
+            js_str!("has:x,get:Symbol(Symbol.unscopables),has:x,get:x"),
+        ),
+        TestAction::run(indoc! {r#"
+            function makeScope() {
+                return {
+                    y: 1,
+                    get [Symbol.unscopables]() {
+                        delete this.y;
+                        return undefined;
+                    },
+                };
+            }
+        "#}),
+        TestAction::assert_eq(
+            indoc! {r#"
+                with (makeScope()) {
+                    (function() { return y; })();
+                }
+            "#},
+            JsValue::undefined(),
+        ),
+        TestAction::assert_native_error(
+            indoc! {r#"
+                with (makeScope()) {
+                    (function() { "use strict"; return y; })();
+                }
+            "#},
+            JsNativeErrorKind::Reference,
+            "y is not defined",
+        ),
+    ]);
+}
+
+#[test]
+fn with_inner_bindings() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var o = { x: "object", f() { return this === o; } };
+        "#}),
+        TestAction::assert_eq(
+            indoc! {r#"
+                with (o) {
+                    let x = "block";
+                    x = x + "!";
+                    x;
+                }
+            "#},
+            js_str!("block!"),
+        ),
+        TestAction::assert_eq("o.x", js_str!("object")),
+        TestAction::assert_eq(
+            indoc! {r#"
+                with (o) {
+                    (function() {
+                        const x = "function";
+                        return x;
+                    })();
+                }
+            "#},
+            js_str!("function"),
+        ),
+        TestAction::assert(indoc! {r#"
+            (function() {
+                with (o) {
+                    let f = function() { "use strict"; return this; };
+                    return f() === undefined;
+                }
+            })()
+        "#}),
+        TestAction::assert(indoc! {r#"
+            (function() {
+                with (o) {
+                    let result = f();
+                    return result;
+                }
+            })()
+        "#}),
+        TestAction::assert_eq(
+            indoc! {r#"
+                (function() {
+                    let x = "outer";
+                    with (o) {
+                        return x;
+                    }
+                })()
+            "#},
+            js_str!("object"),
+        ),
+    ]);
+}
+
+#[test]
+fn with_direct_eval() {
+    run_test_actions([
+        TestAction::assert_eq(
+            indoc! {r#"
+                var o = { a: 1 };
+                with (o) {
+                    eval("a = 2");
+                }
+                o.a
+            "#},
+            2,
+        ),
+        TestAction::assert_eq(
+            indoc! {r#"
+                var p = { b: 0 };
+                with (p) {
+                    eval("var b = 5");
+                }
+                p.b
+            "#},
+            5,
+        ),
+        TestAction::assert_eq(
+            indoc! {r#"
+                (function() {
+                    with ({}) {
+                        eval("var c = 3");
+                    }
+                    return c;
+                })()
+            "#},
+            3,
+        ),
+    ]);
+}
+
+#[test]
+fn closure_binding_slot_access() {
+    run_test_actions([
+        TestAction::assert_eq(
+            indoc! {r#"
+                (function() {
+                    let count = 0;
+                    var total = 10;
+                    const add = (n) => { count += 1; total = total + n; };
+                    add(1);
+                    add(2);
+                    return count * 100 + total;
+                })()
+            "#},
+            213,
+        ),
+        TestAction::assert_native_error(
+            indoc! {r#"
+                (function() {
+                    const read = () => x;
+                    read();
+                    let x = 1;
+                })()
+            "#},
+            JsNativeErrorKind::Reference,
+            "x is not defined",
+        ),
+        TestAction::assert_native_error(
+            indoc! {r#"
+                (function() {
+                    const write = () => { y = 2; };
+                    write();
+                    let y = 1;
+                })()
+            "#},
+            JsNativeErrorKind::Reference,
+            "cannot assign to uninitialized binding `y`",
+        ),
+    ]);
+}
+
+#[test]
+fn initialized_binding_reads() {
+    run_test_actions([
+        TestAction::assert_eq(
+            indoc! {r#"
+                (function() {
+                    let sum = 0;
+                    const fns = [];
+                    for (let i = 0; i < 3; i++) {
+                        let doubled = i * 2;
+                        fns.push(() => doubled);
+                        sum += doubled;
+                    }
+                    return sum + fns[2]();
+                })()
+            "#},
+            10,
+        ),
+        TestAction::assert_native_error(
+            indoc! {r#"
+                (function() {
+                    {
+                        let a = 1;
+                        const f = () => a;
+                        a;
+                    }
+                    {
+                        const f = () => a;
+                        a;
+                        let a = 2;
+                    }
+                })()
+            "#},
+            JsNativeErrorKind::Reference,
+            "a is not defined",
+        ),
+        TestAction::assert_native_error(
+            indoc! {r#"
+                (function(value) {
+                    switch (value) {
+                        case 0:
+                            let b = 1;
+                        case 1:
+                            const f = () => b;
+                            return b;
+                    }
+                })(1)
+            "#},
+            JsNativeErrorKind::Reference,
+            "b is not defined",
+        ),
+    ]);
+}
+
+#[test]
+fn binding_shadowed_by_direct_eval() {
+    run_test_actions([
+        TestAction::assert_eq(
+            indoc! {r#"
+                var a = "outer";
+                (function() {
+                    let b = "block";
+                    return (function() {
+                        eval("var a = 'eval'");
+                        const read = () => a + b;
+                        return read();
+                    })();
+                })()
+            "#},
+            js_str!("evalblock"),
+        ),
+        TestAction::assert_eq(
+            indoc! {r#"
+                (function() {
+                    let c = "outer";
+                    return (function() {
+                        with ({ c: "with" }) {
+                            return (() => c)();
+                        }
+                    })();
+                })()
+            "#},
+            js_str!("with"),
+        ),
+    ]);
+}
+
+#[test]
+fn deny_dynamic_scopes() {
+    let context = &mut ContextBuilder::new()
+        .deny_dynamic_scopes(true)
+        .build()
+        .unwrap();
+
+    run_test_actions_with(
+        [
+            TestAction::assert_native_error(
+                "with ({}) {}",
+                JsNativeErrorKind::Type,
+                "with statements are disabled in this context",
+            ),
+            TestAction::assert_native_error(
+                "eval('1 + 1')",
+                JsNativeErrorKind::Eval,
+                "direct eval is disabled in this context",
+            ),
+            TestAction::assert_eq("(0, eval)('1 + 1')", 2),
+        ],
+        context,
+    );
+}
@@ -1,4 +1,4 @@
-use crate::{run_test_actions, JsNativeErrorKind, JsValue, TestAction};
+use crate::{js_string, run_test_actions, JsNativeErrorKind, JsString, JsValue, TestAction};
 use boa_macros::js_str;
 use indoc::indoc;
 
@@ -126,13 +126,50 @@ fn not_a_function() {
                 let a = {};
                 let b = true;
             "#}),
-        TestAction::assert_native_error("a()", JsNativeErrorKind::Type, "not a callable function"),
+        TestAction::assert_native_error("a()", JsNativeErrorKind::Type, "a is not a function"),
+        TestAction::assert_native_error("a.a()", JsNativeErrorKind::Type, "a.a is not a function"),
+        TestAction::assert_native_error("b()", JsNativeErrorKind::Type, "b is not a function"),
+    ]);
+}
+
+#[test]
+fn not_a_function_names_callee_expression() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+                let obj = { foo: { bar: 1 }, list: [0] };
+                let key = "bar";
+                function tag() {}
+            "#}),
+        TestAction::assert_native_error(
+            "obj.foo.bar()",
+            JsNativeErrorKind::Type,
+            "obj.foo.bar is not a function",
+        ),
+        TestAction::assert_native_error(
+            "obj.foo[key](1, 2)",
+            JsNativeErrorKind::Type,
+            "obj.foo[key] is not a function",
+        ),
+        TestAction::assert_native_error(
+            "obj.list[0](...obj.list)",
+            JsNativeErrorKind::Type,
+            "obj.list[0] is not a function",
+        ),
         TestAction::assert_native_error(
-            "a.a()",
+            "obj.foo`template`",
+            JsNativeErrorKind::Type,
+            "obj.foo is not a function",
+        ),
+        TestAction::assert_native_error(
+            "new obj.foo()",
+            JsNativeErrorKind::Type,
+            "obj.foo is not a constructor",
+        ),
+        TestAction::assert_native_error(
+            "tag()()",
             JsNativeErrorKind::Type,
             "not a callable function",
         ),
-        TestAction::assert_native_error("b()", JsNativeErrorKind::Type, "not a callable function"),
     ]);
 }
 
@@ -161,3 +198,141 @@ fn duplicate_function_name() {
         12,
     )]);
 }
+
+#[test]
+fn call_function_named_async() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var async = (...args) => args.length;
+            var arrow = async (a, { b } = {}, ...c) => a;
+        "#}),
+        TestAction::assert_eq("async(1, 2, 3)", 3),
+        TestAction::assert_eq("async(`${1}`, /a/) + async()", 2),
+        TestAction::assert("arrow(1) instanceof Promise"),
+        TestAction::assert(indoc! {r#"
+            (() => {
+                try {
+                    eval("async(1)\n=> 1");
+                } catch (e) {
+                    return e instanceof SyntaxError;
+                }
+            })()
+        "#}),
+    ]);
+}
+
+#[test]
+fn nested_async_arrow_function_heads() {
+    // The arguments of every `async (...)` are parsed once, whether they are the arguments of a
+    // call or the parameters of an async arrow function.
+    let depth = 32;
+    let calls = format!("{}0{}", "async(".repeat(depth), ")".repeat(depth));
+    let arrows = format!(
+        "{}() => 0{}",
+        "async (a = ".repeat(depth),
+        ") => a()".repeat(depth)
+    );
+
+    run_test_actions([
+        TestAction::run("var async = (x) => x;"),
+        TestAction::assert_eq(calls, 0),
+        TestAction::assert(format!("({arrows})() instanceof Promise")),
+    ]);
+}
+
+#[test]
+fn async_arrow_function_cover_head_early_errors() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            function isSyntaxError(source) {
+                try {
+                    eval(source);
+                } catch (e) {
+                    return e instanceof SyntaxError;
+                }
+                return false;
+            }
+        "#}),
+        TestAction::assert("isSyntaxError('async(...a,) => 1')"),
+        TestAction::assert("isSyntaxError('async(...a, b) => 1')"),
+        TestAction::assert("isSyntaxError('async(...a = 1) => 1')"),
+        TestAction::assert("isSyntaxError('async(a, a) => 1')"),
+        TestAction::assert("isSyntaxError('async((a)) => 1')"),
+        TestAction::assert("isSyntaxError('async(await) => 1')"),
+        TestAction::assert("isSyntaxError('async(a = await) => 1')"),
+        TestAction::assert("isSyntaxError('async function f() { async(a = await 1) => 1; }')"),
+        TestAction::assert("isSyntaxError('function* g() { async(a = yield) => 1; }')"),
+        TestAction::assert("!isSyntaxError('async(a, ...[b]) => 1')"),
+        TestAction::assert("!isSyntaxError('async(a,) => 1')"),
+    ]);
+}
+
+#[test]
+fn arrow_function_cover_grammar() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var a = 12, b = 3, c = 2;
+            var f = (x = /a\/b/g, { y } = { y: `${a / b}` }, [z] = [(a) / c], ...rest) =>
+                [x.source, y, z, rest.length];
+
+            function isSyntaxError(source) {
+                try {
+                    eval(source);
+                } catch (e) {
+                    return e instanceof SyntaxError;
+                }
+                return false;
+            }
+        "#}),
+        TestAction::assert_eq("(a / b) / c", 2),
+        TestAction::assert_eq("(a, b) / c", 1.5),
+        TestAction::assert_eq("(/a/g).flags + (`${a}`) + (a)", js_str!("g1212")),
+        TestAction::assert_eq("f().join()", js_str!("a\\/b,4,6,0")),
+        TestAction::assert_eq("((a = 1, [b, c] = [2, 3]) => a + b + c)()", 6),
+        TestAction::assert_eq("((a) => (b) => a / b)(6)(3)", 2),
+        TestAction::assert("isSyntaxError('(a, 1) => a')"),
+        TestAction::assert("isSyntaxError('(a)\\n=> a')"),
+        TestAction::assert("isSyntaxError('(a, b,)')"),
+        TestAction::assert("isSyntaxError('(...a)')"),
+        TestAction::assert("isSyntaxError('()')"),
+        TestAction::assert("isSyntaxError('function* g() { (a = yield) => a; }')"),
+    ]);
+}
+
+#[test]
+fn nested_arrow_function_parameters() {
+    // Every level is parsed once, so deeply nested parameter lists parse in linear time.
+    let depth = 32;
+    let source = format!(
+        "{}() => 0{}",
+        "(a = ".repeat(depth),
+        ") => a()".repeat(depth)
+    );
+
+    run_test_actions([TestAction::assert_eq(format!("({source})()"), 0)]);
+}
+
+#[test]
+fn typed_function_length_stops_at_optional_arguments() {
+    run_test_actions([
+        TestAction::inspect_context(|context| {
+            context
+                .register_global_typed_callable(
+                    js_string!("scaled"),
+                    |s: String, n: Option<f64>| JsString::from(format!("{s}{}", n.unwrap_or(1.0))),
+                )
+                .unwrap();
+            context
+                .register_global_typed_callable(
+                    js_string!("padded"),
+                    |s: String, n: Option<f64>, fill: String| {
+                        JsString::from(format!("{s}{}{fill}", n.unwrap_or(0.0)))
+                    },
+                )
+                .unwrap();
+        }),
+        TestAction::assert_eq("scaled.length", 1),
+        TestAction::assert_eq("padded.length", 1),
+        TestAction::assert_eq("scaled('x')", js_str!("x1")),
+    ]);
+}
@@ -217,3 +217,136 @@ fn mixed_iterators_close_in_continue() {
         "#}),
     ]);
 }
+
+#[test]
+fn array_destructuring_fast_path_is_unobservable() {
+    run_test_actions([
+        TestAction::run_harness(),
+        TestAction::run(indoc! {r#"
+            const array = [1, 2, 3];
+            const [a, b, ...rest] = array;
+
+            const proto = Object.getPrototypeOf([][Symbol.iterator]());
+            const next = proto.next;
+
+            const results = [];
+            function* delegate() {
+                results.push(yield* [4, 5]);
+            }
+            const delegated = [...delegate()];
+
+            const calls = [];
+            proto.next = function () {
+                calls.push("next");
+                return next.call(this);
+            };
+            const [c] = array;
+            proto.next = next;
+
+            const accessed = [];
+            const sparse = [10];
+            Object.defineProperty(sparse, 1, {
+                get() {
+                    accessed.push(1);
+                    return 20;
+                },
+            });
+            const [d, e, f] = sparse;
+        "#}),
+        TestAction::assert("arrayEquals([a, b, rest], [1, 2, [3]])"),
+        TestAction::assert("arrayEquals(delegated, [4, 5])"),
+        TestAction::assert("arrayEquals(results, [undefined])"),
+        TestAction::assert("c === 1 && arrayEquals(calls, ['next'])"),
+        TestAction::assert("arrayEquals([d, e, f], [10, 20, undefined])"),
+        TestAction::assert("arrayEquals(accessed, [1])"),
+    ]);
+}
+
+#[test]
+fn array_destructuring_does_not_step_finished_iterators() {
+    run_test_actions([
+        TestAction::run_harness(),
+        TestAction::run(indoc! {r#"
+            const log = [];
+            function iterable(length) {
+                let i = 0;
+                return {
+                    [Symbol.iterator]() {
+                        return this;
+                    },
+                    next() {
+                        log.push("next");
+                        return {
+                            get done() {
+                                log.push("done");
+                                return i >= length;
+                            },
+                            get value() {
+                                log.push("value");
+                                return i++;
+                            },
+                        };
+                    },
+                };
+            }
+
+            function init(name) {
+                log.push("init " + name);
+                return name;
+            }
+
+            const [a = init("a"), , b = init("b"), c = init("c"), ...rest] = iterable(1);
+            const first = log.splice(0);
+
+            const [d = init("d"), e = init("e")] = [null, undefined];
+            const second = log.splice(0);
+        "#}),
+        TestAction::assert("arrayEquals([a, b, c, rest], [0, 'b', 'c', []])"),
+        TestAction::assert(
+            "arrayEquals(first, ['next', 'done', 'value', 'next', 'done', 'init b', 'init c'])",
+        ),
+        TestAction::assert("d === null && e === 'e'"),
+        TestAction::assert("arrayEquals(second, ['init e'])"),
+    ]);
+}
+
+#[test]
+fn array_iterator_creation_fast_path_is_unobservable() {
+    run_test_actions([
+        TestAction::run_harness(),
+        TestAction::run(indoc! {r#"
+            const values = Array.prototype[Symbol.iterator];
+
+            const iterated = [];
+            Array.prototype[Symbol.iterator] = function () {
+                iterated.push(this.length);
+                return values.call(this);
+            };
+            const [a, b] = [1, 2];
+            Array.prototype[Symbol.iterator] = values;
+
+            function args() {
+                const [x, y] = arguments;
+                return [x, y];
+            }
+
+            const inherited = [1, , 3];
+            Array.prototype[1] = 2;
+            const [c, d, e] = inherited;
+            delete Array.prototype[1];
+
+            const grown = [1, 2];
+            grown.length = 3;
+            const [f, g, h] = grown;
+
+            const iterator = [][Symbol.iterator]();
+        "#}),
+        TestAction::assert("arrayEquals([a, b], [1, 2]) && arrayEquals(iterated, [2])"),
+        TestAction::assert("arrayEquals(args(3, 4), [3, 4])"),
+        TestAction::assert("arrayEquals([c, d, e], [1, 2, 3])"),
+        TestAction::assert("arrayEquals([f, g, h], [1, 2, undefined])"),
+        TestAction::assert(
+            "Object.getPrototypeOf(iterator) === Object.getPrototypeOf([].values())",
+        ),
+    ]);
+}
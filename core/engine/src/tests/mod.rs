@@ -11,10 +11,11 @@ mod function;
 mod iterators;
 mod operators;
 mod promise;
+mod pure;
 mod spread;
 mod to_string;
 
-use crate::{run_test_actions, JsNativeErrorKind, JsValue, TestAction};
+use crate::{run_test_actions, run_test_actions_with, JsNativeErrorKind, JsValue, TestAction};
 
 #[test]
 fn length_correct_value_on_string_literal() {
@@ -394,7 +395,7 @@ fn strict_mode_octal() {
             var n = 023;
         "#},
         JsNativeErrorKind::Syntax,
-        "implicit octal literals are not allowed in strict mode at line 2, col 9",
+        "implicit octal literals are not allowed in strict mode at line 2, col 9 (help: use `0o23` instead)",
     )]);
 }
 
@@ -478,6 +479,21 @@ fn tagged_template() {
     ]);
 }
 
+#[test]
+fn tagged_template_object_caching() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+                function tag(t) { return t; }
+                function site() { return tag`a${1}b`; }
+                function otherSite() { return tag`a${1}b`; }
+            "#}),
+        TestAction::assert("site() === site()"),
+        TestAction::assert("site() !== otherSite()"),
+        TestAction::assert("Object.isFrozen(site()) && Object.isFrozen(site().raw)"),
+        TestAction::assert("eval('tag`x`') !== eval('tag`x`')"),
+    ]);
+}
+
 #[test]
 fn template_literal() {
     run_test_actions([TestAction::assert_eq(
@@ -506,3 +522,386 @@ fn null_bool_in_object_pattern() {
         TestAction::assert_eq("c", 100),
     ]);
 }
+
+#[test]
+fn object_pattern_getter_order_and_rest() {
+    run_test_actions([
+        TestAction::run_harness(),
+        TestAction::run(indoc! {r#"
+            const log = [];
+            const obj = {
+                get a() {
+                    log.push("get a");
+                    return undefined;
+                },
+                get b() {
+                    log.push("get b");
+                    return null;
+                },
+                get c() {
+                    log.push("get c");
+                    return { d: 1 };
+                },
+                e: 2,
+                f: 3,
+            };
+
+            function init(name) {
+                log.push("init " + name);
+                return name;
+            }
+
+            const key = "e";
+            const { a = init("a"), b = init("b"), c: { d }, [key]: e, ...rest } = obj;
+        "#}),
+        TestAction::assert("a === 'a' && b === null && d === 1 && e === 2"),
+        TestAction::assert("arrayEquals(log, ['get a', 'init a', 'get b', 'get c'])"),
+        TestAction::assert("arrayEquals(Object.keys(rest), ['f'])"),
+    ]);
+}
+
+#[test]
+fn embedder_data_on_context_and_realm() {
+    use crate::object::JsObject;
+    use boa_gc::{Finalize, Trace};
+    use boa_macros::JsData;
+
+    #[derive(Debug, Trace, Finalize, JsData)]
+    struct Counter(u32);
+
+    #[derive(Debug, Trace, Finalize, JsData)]
+    struct Cache(JsObject);
+
+    run_test_actions([TestAction::inspect_context(|ctx| {
+        assert!(ctx.insert_data(Counter(0)).is_none());
+        ctx.get_data_mut::<Counter>().expect("data was inserted").0 += 1;
+        assert_eq!(ctx.get_data::<Counter>().map(|c| c.0), Some(1));
+
+        let realm = ctx.realm().clone();
+        let object = JsObject::with_object_proto(ctx.intrinsics());
+        object
+            .set(js_str!("x"), 1, false, ctx)
+            .expect("setting a property of an ordinary object cannot fail");
+        assert!(realm.insert_data(Cache(object)).is_none());
+        drop(realm);
+
+        // The object is only reachable through the realm data, which must keep it alive.
+        boa_gc::force_collect();
+
+        let realm = ctx.realm().clone();
+        assert!(realm.has_data::<Cache>());
+        let object = realm
+            .get_data::<Cache>()
+            .expect("data was inserted")
+            .0
+            .clone();
+        assert_eq!(
+            object
+                .get(js_str!("x"), ctx)
+                .expect("getting a property of an ordinary object cannot fail"),
+            JsValue::new(1)
+        );
+        assert!(realm.remove_data::<Cache>().is_some());
+        assert!(realm.get_data_mut::<Cache>().is_none());
+    })]);
+}
+
+#[test]
+fn native_class_prototype_and_to_string_tag() {
+    use crate::{
+        class::{Class, ClassBuilder},
+        js_string,
+        property::Attribute,
+        Context, JsResult,
+    };
+    use boa_gc::{Finalize, Trace};
+    use boa_macros::JsData;
+
+    #[derive(Debug, Trace, Finalize, JsData)]
+    struct Animal;
+
+    impl Class for Animal {
+        const NAME: &'static str = "Animal";
+
+        fn init(class: &mut ClassBuilder<'_>) -> JsResult<()> {
+            class.property(js_string!("legs"), 4, Attribute::all());
+            class.to_string_tag(js_string!("Animal"));
+            Ok(())
+        }
+
+        fn data_constructor(
+            _new_target: &JsValue,
+            _args: &[JsValue],
+            _context: &mut Context,
+        ) -> JsResult<Self> {
+            Ok(Self)
+        }
+    }
+
+    #[derive(Debug, Trace, Finalize, JsData)]
+    struct Bird;
+
+    impl Class for Bird {
+        const NAME: &'static str = "Bird";
+
+        fn init(class: &mut ClassBuilder<'_>) -> JsResult<()> {
+            let animal = class
+                .context()
+                .class_prototype::<Animal>()
+                .expect("`Animal` must be registered before `Bird`");
+            class.inherit(animal);
+            class.property(js_string!("legs"), 2, Attribute::all());
+            Ok(())
+        }
+
+        fn data_constructor(
+            _new_target: &JsValue,
+            _args: &[JsValue],
+            _context: &mut Context,
+        ) -> JsResult<Self> {
+            Ok(Self)
+        }
+    }
+
+    run_test_actions([
+        TestAction::inspect_context(|ctx| {
+            assert!(ctx.class_prototype::<Animal>().is_none());
+            ctx.register_global_class::<Animal>().unwrap();
+            ctx.register_global_class::<Bird>().unwrap();
+            assert_eq!(
+                ctx.class_prototype::<Animal>(),
+                ctx.get_global_class::<Animal>().map(|c| c.prototype())
+            );
+        }),
+        TestAction::assert_eq(
+            "Object.prototype.toString.call(new Animal())",
+            js_str!("[object Animal]"),
+        ),
+        TestAction::assert_eq(
+            "Object.prototype.toString.call(new Bird())",
+            js_str!("[object Animal]"),
+        ),
+        TestAction::assert("new Bird() instanceof Animal"),
+        TestAction::assert_eq("new Bird().legs", 2),
+        TestAction::assert_eq(
+            "Object.getOwnPropertyDescriptor(Animal.prototype, Symbol.toStringTag).writable",
+            false,
+        ),
+    ]);
+}
+
+#[test]
+fn native_class_cross_realm_instanceof() {
+    use crate::{
+        class::{Class, ClassBuilder},
+        js_string,
+        property::Attribute,
+        Context, JsResult, Source,
+    };
+    use boa_gc::{Finalize, Trace};
+    use boa_macros::JsData;
+
+    #[derive(Debug, Trace, Finalize, JsData)]
+    struct Host;
+
+    impl Class for Host {
+        const NAME: &'static str = "Host";
+
+        fn init(_class: &mut ClassBuilder<'_>) -> JsResult<()> {
+            Ok(())
+        }
+
+        fn data_constructor(
+            _new_target: &JsValue,
+            _args: &[JsValue],
+            _context: &mut Context,
+        ) -> JsResult<Self> {
+            Ok(Self)
+        }
+    }
+
+    run_test_actions([
+        TestAction::inspect_context(|ctx| {
+            ctx.register_global_class::<Host>().unwrap();
+
+            let realm = ctx.create_realm().unwrap();
+            let old_realm = ctx.enter_realm(realm);
+            ctx.register_global_class::<Host>().unwrap();
+            let other = ctx.eval(Source::from_bytes("new Host()")).unwrap();
+            ctx.enter_realm(old_realm);
+
+            assert!(other.as_object().is_some_and(|o| o.is_native::<Host>()));
+            ctx.register_global_property(js_string!("other"), other, Attribute::all())
+                .unwrap();
+        }),
+        TestAction::run(indoc! {r#"
+            class Sub extends Host {}
+        "#}),
+        TestAction::assert("Object.getPrototypeOf(other) !== Host.prototype"),
+        TestAction::assert("other instanceof Host"),
+        TestAction::assert("!(other instanceof Sub)"),
+        TestAction::assert("!(new Host() instanceof Sub)"),
+        TestAction::assert("new Sub() instanceof Sub"),
+        TestAction::assert("new Sub() instanceof Host"),
+        TestAction::assert("Object.create(Host.prototype) instanceof Host"),
+        TestAction::assert("!({} instanceof Host)"),
+    ]);
+}
+
+#[test]
+fn context_statistics() {
+    let context = &mut crate::Context::default();
+    let before = context.statistics();
+
+    context
+        .eval(crate::Source::from_bytes(indoc! {r#"
+            var objects = [];
+            for (let i = 0; i < 10; i++) {
+                objects.push({ ["property" + i]: i });
+            }
+            function kept() { return "constant"; }
+        "#}))
+        .unwrap();
+
+    let after = context.statistics();
+    assert!(after.interned_strings > before.interned_strings);
+    assert!(after.shared_shapes >= before.shared_shapes + 10);
+    assert!(after.code_blocks > before.code_blocks);
+    assert!(after.constants > before.constants);
+}
+
+#[test]
+fn eval_with_metrics() {
+    let context = &mut crate::Context::default();
+
+    let (result, cheap) = context.eval_with_metrics(crate::Source::from_bytes("1 + 1"));
+    assert_eq!(result.unwrap().as_number(), Some(2.0));
+
+    let (result, expensive) = context.eval_with_metrics(crate::Source::from_bytes(indoc! {r#"
+        let objects = [];
+        for (let i = 0; i < 100; i++) {
+            objects.push({ value: i });
+        }
+        objects.length
+    "#}));
+    assert_eq!(result.unwrap().as_number(), Some(100.0));
+    assert!(expensive.instructions > cheap.instructions);
+    assert!(expensive.allocations >= 100);
+
+    let (result, metrics) = context.eval_with_metrics(crate::Source::from_bytes("throw 1"));
+    assert!(result.is_err());
+    assert!(metrics.instructions > 0);
+}
+
+#[test]
+fn override_intrinsics() {
+    use crate::NativeFunction;
+
+    let context = &mut crate::Context::default();
+    let realm = context.realm().clone();
+
+    context
+        .eval(crate::Source::from_bytes(indoc! {r#"
+            function sample() { return [Math.random(), Date.now()]; }
+            for (let i = 0; i < 10; i++) { sample(); }
+        "#}))
+        .unwrap();
+
+    realm
+        .override_intrinsic(
+            "Math.random",
+            NativeFunction::from_fn_ptr(|_, _, _| Ok(JsValue::new(0.25))),
+            context,
+        )
+        .unwrap();
+    realm
+        .override_intrinsic(
+            "Date.now",
+            NativeFunction::from_fn_ptr(|_, _, _| Ok(JsValue::new(1000))),
+            context,
+        )
+        .unwrap();
+
+    run_test_actions_with(
+        [
+            TestAction::assert("sample()[0] === 0.25"),
+            TestAction::assert("sample()[1] === 1000"),
+            TestAction::assert_eq("Math.random.name", js_str!("random")),
+            TestAction::assert_eq("Date.now.length", 0),
+            TestAction::assert("!Object.getOwnPropertyDescriptor(Math, 'random').enumerable"),
+        ],
+        context,
+    );
+
+    assert!(realm
+        .override_intrinsic(
+            "Math.PI",
+            NativeFunction::from_fn_ptr(|_, _, _| Ok(JsValue::undefined())),
+            context,
+        )
+        .is_err());
+    assert!(realm.restore_intrinsic("Math.random", context).unwrap());
+    assert!(!realm.restore_intrinsic("Math.random", context).unwrap());
+    realm.restore_intrinsics(context).unwrap();
+
+    run_test_actions_with(
+        [
+            TestAction::assert("sample()[0] !== 0.25"),
+            TestAction::assert("sample()[1] !== 1000"),
+        ],
+        context,
+    );
+}
+
+#[test]
+fn disable_species_lookups() {
+    const SUBCLASSES: &str = indoc! {r#"
+        class MyArray extends Array {}
+        class MyPromise extends Promise {}
+        class MyBuffer extends ArrayBuffer {}
+        class MyUint8Array extends Uint8Array {}
+        class MyRegExp extends RegExp {}
+    "#};
+
+    run_test_actions([
+        TestAction::run(SUBCLASSES),
+        TestAction::assert("MyArray.from([1, 2]).map(x => x) instanceof MyArray"),
+        TestAction::assert("MyPromise.resolve(1).then() instanceof MyPromise"),
+        TestAction::assert("new MyBuffer(4).slice(1) instanceof MyBuffer"),
+        TestAction::assert("new MyUint8Array(4).subarray(1) instanceof MyUint8Array"),
+    ]);
+
+    let context = &mut crate::Context::builder()
+        .disable_species_lookups(true)
+        .build()
+        .unwrap();
+    run_test_actions_with(
+        [
+            TestAction::run(SUBCLASSES),
+            TestAction::assert("!(MyArray.from([1, 2]).map(x => x) instanceof MyArray)"),
+            TestAction::assert("Array.isArray(MyArray.from([1, 2]).filter(x => x))"),
+            TestAction::assert("!(MyPromise.resolve(1).then() instanceof MyPromise)"),
+            TestAction::assert("MyPromise.resolve(1).then() instanceof Promise"),
+            TestAction::assert("!(new MyBuffer(4).slice(1) instanceof MyBuffer)"),
+            TestAction::assert("!(new MyUint8Array(4).subarray(1) instanceof MyUint8Array)"),
+            TestAction::assert("new MyUint8Array(4).slice(1) instanceof Uint8Array"),
+            TestAction::assert_eq("new MyRegExp('b').exec('abc').index", 1),
+            TestAction::assert_eq(
+                indoc! {r#"
+                    var reads = 0;
+                    var ta = new Uint8Array([1, 2, 3]);
+                    Object.defineProperty(ta, "constructor", {
+                        get() { reads++; return Uint8Array; },
+                    });
+                    ta.slice();
+                    ta.map(x => x);
+                    ta.filter(x => x);
+                    reads
+                "#},
+                0,
+            ),
+            TestAction::assert_eq("typeof Array[Symbol.species]", js_str!("function")),
+        ],
+        context,
+    );
+}
@@ -638,6 +638,36 @@ fn ops_at_the_end() {
     run_test_actions(actions);
 }
 
+#[test]
+fn arithmetic_with_integer_literal() {
+    run_test_actions([
+        TestAction::assert_eq(
+            indoc! {r#"
+                let sum = 0;
+                for (let i = 0; i < 10; i += 1) {
+                    sum = sum + i * 3 - 1;
+                }
+                sum
+            "#},
+            125,
+        ),
+        TestAction::assert_eq("2147483647 + 1", 2_147_483_648.0),
+        TestAction::assert_eq("-2147483648 - 1", -2_147_483_649.0),
+        TestAction::assert_eq("65536 * 65536", 4_294_967_296.0),
+        TestAction::assert("Object.is(-3 * 0, -0)"),
+        TestAction::assert("let z = -3; z *= 0; Object.is(z, -0)"),
+        TestAction::assert("Object.is(-0 - 0, -0)"),
+        TestAction::assert_eq("1.5 + 1", 2.5),
+        TestAction::assert_eq("'a' + 1", js_str!("a1")),
+        TestAction::assert_eq("({ valueOf() { return 4; } }) * 2", 8),
+        TestAction::assert_native_error(
+            "1n + 1",
+            JsNativeErrorKind::Type,
+            "cannot mix BigInt and other types, use explicit conversions",
+        ),
+    ]);
+}
+
 #[test]
 fn regex_slash_eq() {
     run_test_actions([
@@ -647,6 +677,31 @@ fn regex_slash_eq() {
     ]);
 }
 
+#[test]
+fn optional_chain_short_circuit() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+                var obj = { a: { b: { c: { d() { return this === obj.a.b.c; } } } } };
+                var calls = 0;
+                function count() { calls++; return 'c'; }
+            "#}),
+        TestAction::assert("obj?.a?.b?.c?.d()"),
+        TestAction::assert_eq("obj?.a?.x?.c?.d()", JsValue::undefined()),
+        TestAction::assert_eq("null?.a.b.c", JsValue::undefined()),
+        TestAction::assert_eq("obj.a?.b.x?.[count()]?.d()", JsValue::undefined()),
+        TestAction::assert_eq("calls", 0),
+        TestAction::assert("obj.n = null; obj?.n?.x === undefined"),
+        TestAction::assert("(obj.n?.x)?.() === undefined"),
+        TestAction::assert_eq("(obj?.x?.b)?.c?.d", JsValue::undefined()),
+        TestAction::assert("(obj?.a?.b?.c)?.d()"),
+        TestAction::assert_native_error(
+            "(obj?.a?.b)?.x.d",
+            JsNativeErrorKind::Type,
+            "cannot convert 'null' or 'undefined' to object",
+        ),
+    ]);
+}
+
 mod in_operator {
     use super::*;
 
@@ -1,6 +1,7 @@
+use boa_gc::GcTuning;
 use indoc::indoc;
 
-use crate::{run_test_actions, TestAction};
+use crate::{context::ContextBuilder, js_string, run_test_actions, Source, TestAction};
 
 #[test]
 #[allow(clippy::redundant_closure_for_method_calls)]
@@ -38,3 +39,49 @@ fn issue_2658() {
         TestAction::assert_eq("result2.value", 5),
     ]);
 }
+
+#[test]
+fn async_code_with_incremental_marking() {
+    let mut tuning = GcTuning::default().with_incremental_marking(true);
+    tuning.mark_step = 8;
+    let context = &mut ContextBuilder::new()
+        .gc_tuning(tuning)
+        .build()
+        .expect("the context should be built");
+
+    // Every `await` moves the suspended frame out of the heap and back while the heap is marked.
+    context
+        .eval(Source::from_bytes(indoc! {r#"
+            var log = [];
+            async function* numbers(n) {
+                for (let i = 0; i < n; i++) {
+                    await null;
+                    yield { value: i, garbage: new Array(64).fill(i) };
+                }
+            }
+            async function sum(n) {
+                let total = 0;
+                for await (const { value } of numbers(n)) {
+                    const pair = await Promise.all([value, Promise.resolve(value)]);
+                    total += pair[0] + pair[1];
+                }
+                return total;
+            }
+            async function main() {
+                const totals = await Promise.all([sum(2000), sum(2000)]);
+                const values = await Array.fromAsync(numbers(500), async (x) => x.value);
+                log.push(totals.join(), values.length, values[499]);
+            }
+            main().catch((e) => log.push(String(e)));
+        "#}))
+        .expect("the script should not throw");
+    context.run_jobs().expect("the jobs should not throw");
+
+    assert!(boa_gc::statistics().incremental.count > 0);
+    assert_eq!(
+        context
+            .eval(Source::from_bytes("log.join()"))
+            .expect("the log should be joined"),
+        js_string!("3998000,3998000,500,499").into()
+    );
+}
@@ -0,0 +1,246 @@
+use boa_macros::js_str;
+use indoc::indoc;
+
+use crate::{
+    js_string, native_function::NativeFunction, Context, JsArgs, JsNativeErrorKind, JsResult,
+    JsValue, Source,
+};
+
+fn eval_pure(context: &mut Context, source: &str) -> JsResult<JsValue> {
+    context.eval_pure(Source::from_bytes(source))
+}
+
+#[track_caller]
+fn assert_side_effect(context: &mut Context, source: &str) {
+    let error = eval_pure(context, source).expect_err("the expression should trap");
+    let error = error
+        .as_native()
+        .expect("the error should be a native error");
+    assert!(error.is_runtime_limit(), "unexpected error: {error}");
+}
+
+#[test]
+fn pure_expressions() {
+    let context = &mut Context::default();
+    context
+        .eval(Source::from_bytes(
+            "var config = { port: 8080, hosts: ['a', 'b'] };",
+        ))
+        .unwrap();
+
+    assert_eq!(eval_pure(context, "1 + 2 * 3").unwrap(), JsValue::new(7));
+    assert_eq!(
+        eval_pure(context, "config.hosts.map((h) => h.toUpperCase()).join()").unwrap(),
+        js_str!("A,B").into()
+    );
+    assert_eq!(
+        eval_pure(context, "Math.max(config.port, ...[1, 2, 3])").unwrap(),
+        JsValue::new(8080)
+    );
+    assert_eq!(
+        eval_pure(
+            context,
+            "JSON.stringify({ ...config, port: `${config.port + 1}` })"
+        )
+        .unwrap(),
+        js_str!(r#"{"port":"8081","hosts":["a","b"]}"#).into()
+    );
+    assert_eq!(
+        eval_pure(
+            context,
+            indoc! {r#"
+                (() => {
+                    const sum = [1, 2, 3].reduce((a, b) => a + b, 0);
+                    const total = { sum, get double() { return this.sum * 2; } };
+                    return total.double;
+                })()
+            "#}
+        )
+        .unwrap(),
+        JsValue::new(12)
+    );
+}
+
+#[test]
+fn pure_stores() {
+    let context = &mut Context::default();
+    context
+        .eval(Source::from_bytes(indoc! {r#"
+            var count = 0;
+            var object = { a: 1 };
+            function increment() { return ++count; }
+        "#}))
+        .unwrap();
+
+    assert_side_effect(context, "count = 1");
+    assert_side_effect(context, "count++");
+    assert_side_effect(context, "object.a = 2");
+    assert_side_effect(context, "object['a'] += 2");
+    assert_side_effect(context, "delete object.a");
+    assert_side_effect(context, "increment()");
+    assert_side_effect(context, "[1].map(() => increment())");
+    assert_side_effect(
+        context,
+        "(() => { try { object.a = 2; } catch { return 'caught'; } })()",
+    );
+
+    assert_eq!(
+        context
+            .eval(Source::from_bytes("[count, object.a]"))
+            .unwrap()
+            .to_string(context)
+            .unwrap(),
+        js_str!("0,1")
+    );
+
+    // Side effects are allowed again after the pure evaluation.
+    assert_eq!(
+        context.eval(Source::from_bytes("increment()")).unwrap(),
+        JsValue::new(1)
+    );
+}
+
+#[test]
+fn pure_outer_let_assignments() {
+    let context = &mut Context::default();
+    context
+        .eval(Source::from_bytes(indoc! {r#"
+            let value = 1;
+            const counter = (() => {
+                let count = 0;
+                return { increment: () => ++count, reset: () => { count = 0; }, get: () => count };
+            })();
+        "#}))
+        .unwrap();
+
+    assert_side_effect(context, "value = 2");
+    assert_side_effect(context, "counter.increment()");
+    assert_side_effect(context, "counter.reset()");
+
+    assert_eq!(
+        eval_pure(context, "counter.get() + value").unwrap(),
+        JsValue::new(1)
+    );
+}
+
+#[test]
+fn pure_native_calls() {
+    let context = &mut Context::default();
+    context
+        .register_global_callable(
+            js_string!("double"),
+            1,
+            NativeFunction::from_fn_ptr(|_, args, context| {
+                Ok(JsValue::new(
+                    args.get_or_undefined(0).to_number(context)? * 2.0,
+                ))
+            }),
+        )
+        .unwrap();
+
+    assert_side_effect(context, "Math.random()");
+    assert_side_effect(context, "[3, 1, 2].sort()");
+    assert_side_effect(context, "Object.defineProperty({}, 'a', { value: 1 })");
+    assert_side_effect(context, "double(2)");
+
+    let double = context
+        .global_object()
+        .get(js_string!("double"), context)
+        .unwrap();
+    context.allow_in_pure_eval(double.as_object().unwrap().clone());
+
+    assert_eq!(eval_pure(context, "double(2)").unwrap(), JsValue::new(4));
+    assert_eq!(
+        eval_pure(context, "[3, 1, 2].toSorted().map(double).join()").unwrap(),
+        js_str!("2,4,6").into()
+    );
+}
+
+#[test]
+fn pure_user_constructors() {
+    let context = &mut Context::default();
+    context
+        .eval(Source::from_bytes(indoc! {r#"
+            var cfg = { port: 8080 };
+            var species = [1, 2];
+            species.constructor = { [Symbol.species]: function () { return cfg; } };
+            class List extends Array {}
+        "#}))
+        .unwrap();
+
+    assert_side_effect(
+        context,
+        "Array.from.call(function(){ return globalThis.cfg }, [1])",
+    );
+    assert_side_effect(
+        context,
+        "Array.from.call(function(){ return cfg }, { length: 1 })",
+    );
+    assert_side_effect(context, "Array.of.call(function(){ return cfg }, 1)");
+    assert_side_effect(context, "species.map((x) => x)");
+    assert_side_effect(context, "species.concat([3])");
+    assert_side_effect(context, "List.from([1])");
+    assert_side_effect(context, "Array.fromAsync([1])");
+    assert_side_effect(
+        context,
+        "Array.prototype.push.call.call(Array.prototype.push, cfg, 1)",
+    );
+
+    assert_eq!(
+        context
+            .eval(Source::from_bytes("JSON.stringify(cfg)"))
+            .unwrap(),
+        js_str!(r#"{"port":8080}"#).into()
+    );
+
+    // The intrinsic `Array` constructor always creates new arrays.
+    assert_eq!(
+        eval_pure(
+            context,
+            "Array.from([1, 2], (x) => x * 2).concat(Array.of(3)).join()"
+        )
+        .unwrap(),
+        js_str!("2,4,3").into()
+    );
+}
+
+#[test]
+fn pure_shared_iterators() {
+    let context = &mut Context::default();
+    context
+        .eval(Source::from_bytes(
+            "var iterator = [1, 2, 3].values(); var letters = 'ab'[Symbol.iterator]();",
+        ))
+        .unwrap();
+
+    assert_side_effect(context, "iterator.next()");
+    assert_side_effect(context, "[...iterator]");
+    assert_side_effect(context, "letters.next()");
+    assert_side_effect(context, "[...'ab']");
+
+    // Arrays are iterated with new iterators, which other code cannot reach.
+    assert_eq!(
+        eval_pure(context, "[...[1, 2, 3]].join()").unwrap(),
+        js_str!("1,2,3").into()
+    );
+    assert_eq!(
+        context
+            .eval(Source::from_bytes("[...iterator].join()"))
+            .unwrap(),
+        js_str!("1,2,3").into()
+    );
+}
+
+#[test]
+fn pure_single_expression() {
+    let context = &mut Context::default();
+
+    for source in ["var x = 1;", "1; 2;", "if (true) 1;", ""] {
+        let error = eval_pure(context, source).unwrap_err();
+        assert_eq!(
+            error.as_native().map(|error| &error.kind),
+            Some(&JsNativeErrorKind::Syntax),
+            "{source}"
+        );
+    }
+}
@@ -0,0 +1,485 @@
+//! This module implements the incremental conversion of JSON texts into [`JsValue`]s.
+
+use std::io::{ErrorKind, Read};
+
+use super::JsValue;
+use crate::{
+    builtins::Array, error::JsNativeError, object::JsObject, property::PropertyDescriptor, Context,
+    JsResult, JsString,
+};
+
+/// The size of the chunks read by [`JsValue::from_json_reader`].
+const READ_CHUNK_SIZE: usize = 8 * 1024;
+
+impl JsValue {
+    /// Parses a JSON text from `reader` into a `JsValue`.
+    ///
+    /// Unlike `JSON.parse`, the source text is never materialized as a whole. It is read in
+    /// chunks and the objects and arrays are constructed while the text is read, which keeps the
+    /// peak memory usage low when parsing very large documents.
+    ///
+    /// See [`JsonStreamParser`] to push the chunks of the text manually instead.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `SyntaxError` if the text is not a valid JSON text, and an `Error` if reading
+    /// from `reader` fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use boa_engine::{js_string, Context, JsValue};
+    ///
+    /// let data = br#"{ "name": "John Doe", "phones": ["+44 1234567", "+44 2345678"] }"#;
+    ///
+    /// let mut context = Context::default();
+    /// let value = JsValue::from_json_reader(&data[..], &mut context).unwrap();
+    ///
+    /// let object = value.as_object().unwrap();
+    /// assert_eq!(
+    ///     object.get(js_string!("name"), &mut context).unwrap(),
+    ///     JsValue::from(js_string!("John Doe"))
+    /// );
+    /// ```
+    pub fn from_json_reader<R: Read>(mut reader: R, context: &mut Context) -> JsResult<Self> {
+        let mut parser = JsonStreamParser::new();
+        let mut buffer = vec![0; READ_CHUNK_SIZE];
+        loop {
+            let read = match reader.read(&mut buffer) {
+                Ok(0) => break,
+                Ok(read) => read,
+                Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+                Err(e) => {
+                    return Err(JsNativeError::error()
+                        .with_message(format!("could not read JSON input: {e}"))
+                        .into());
+                }
+            };
+            parser.push(&buffer[..read], context)?;
+        }
+        parser.finish()
+    }
+}
+
+/// An incremental JSON parser, which builds a [`JsValue`] from chunks of a JSON text.
+///
+/// The chunks can be split at any byte, including in the middle of a string, a number or a
+/// multi-byte UTF-8 sequence.
+///
+/// # Example
+///
+/// ```
+/// use boa_engine::{js_string, value::JsonStreamParser, Context, JsValue};
+///
+/// let mut context = Context::default();
+/// let mut parser = JsonStreamParser::new();
+/// parser.push(br#"{ "total": 12"#, &mut context).unwrap();
+/// parser.push(br#".5, "items": [tr"#, &mut context).unwrap();
+/// parser.push(br#"ue, null] }"#, &mut context).unwrap();
+/// let value = parser.finish().unwrap();
+///
+/// let object = value.as_object().unwrap();
+/// assert_eq!(
+///     object.get(js_string!("total"), &mut context).unwrap(),
+///     JsValue::from(12.5)
+/// );
+/// ```
+#[derive(Debug, Default)]
+pub struct JsonStreamParser {
+    /// The arrays and objects that are currently being parsed.
+    stack: Vec<Frame>,
+    /// What is allowed next in the JSON text.
+    expect: Expect,
+    /// The bytes of the string, number or literal that is currently being parsed.
+    token: Vec<u8>,
+    /// The kind of the token that is currently being parsed, if any.
+    token_kind: Option<TokenKind>,
+    /// The number of bytes consumed so far, for error messages.
+    position: usize,
+    /// The parsed value, once the JSON text is complete.
+    result: Option<JsValue>,
+}
+
+#[derive(Debug)]
+enum Frame {
+    Array(Vec<JsValue>),
+    Object {
+        object: JsObject,
+        key: Option<JsString>,
+    },
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum Expect {
+    /// A value, at the start of the text or after a `:` or a `,` in an array.
+    #[default]
+    Value,
+    /// A value or `]`, after a `[`.
+    ValueOrEnd,
+    /// A key, after a `,` in an object.
+    Key,
+    /// A key or `}`, after a `{`.
+    KeyOrEnd,
+    /// A `:`, after a key.
+    Colon,
+    /// A `,`, `]` or `}`, after a value in an array or object.
+    CommaOrEnd,
+    /// Only whitespace, after the complete JSON text.
+    Done,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TokenKind {
+    /// A string, where `escaped` is `true` if the last byte was a backslash.
+    String { escaped: bool },
+    /// A number or one of the `true`, `false` or `null` literals.
+    Literal,
+}
+
+impl JsonStreamParser {
+    /// Creates a new, empty `JsonStreamParser`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parses the next chunk of the JSON text.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `SyntaxError` if the chunk cannot continue a valid JSON text.
+    pub fn push(&mut self, chunk: &[u8], context: &mut Context) -> JsResult<()> {
+        let mut index = 0;
+        while index < chunk.len() {
+            match self.token_kind {
+                Some(TokenKind::String { mut escaped }) => {
+                    let rest = &chunk[index..];
+                    let end = rest.iter().position(|&byte| {
+                        let end = !escaped && byte == b'"';
+                        escaped = !escaped && byte == b'\\';
+                        end
+                    });
+                    let Some(end) = end else {
+                        self.token.extend_from_slice(rest);
+                        self.token_kind = Some(TokenKind::String { escaped });
+                        self.position += rest.len();
+                        break;
+                    };
+                    self.token.extend_from_slice(&rest[..end]);
+                    self.token_kind = None;
+                    index += end + 1;
+                    self.position += end + 1;
+                    let string = decode_json_string(&self.token).ok_or_else(|| {
+                        self.error(format_args!(
+                            "invalid string ending at position {}",
+                            self.position
+                        ))
+                    })?;
+                    self.token.clear();
+                    self.complete_string(string);
+                    continue;
+                }
+                Some(TokenKind::Literal) => {
+                    let byte = chunk[index];
+                    if byte.is_ascii_alphanumeric() || matches!(byte, b'+' | b'-' | b'.') {
+                        self.token.push(byte);
+                        index += 1;
+                        self.position += 1;
+                        continue;
+                    }
+                    self.complete_literal()?;
+                }
+                None => {}
+            }
+
+            let byte = chunk[index];
+            index += 1;
+            self.position += 1;
+            self.structural(byte, context)?;
+        }
+        Ok(())
+    }
+
+    /// Finishes parsing, returning the parsed value.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `SyntaxError` if the pushed chunks do not form a complete JSON text.
+    pub fn finish(mut self) -> JsResult<JsValue> {
+        if self.token_kind == Some(TokenKind::Literal) {
+            self.complete_literal()?;
+        }
+        self.result
+            .take()
+            .ok_or_else(|| self.error(format_args!("unexpected end of JSON input")))
+    }
+
+    /// Handles a byte outside of a string or literal token.
+    fn structural(&mut self, byte: u8, context: &mut Context) -> JsResult<()> {
+        match (byte, self.expect) {
+            (b' ' | b'\t' | b'\n' | b'\r', _) => {}
+            (b'"', Expect::Value | Expect::ValueOrEnd | Expect::Key | Expect::KeyOrEnd) => {
+                self.token_kind = Some(TokenKind::String { escaped: false });
+            }
+            (b'[', Expect::Value | Expect::ValueOrEnd) => {
+                self.stack.push(Frame::Array(Vec::new()));
+                self.expect = Expect::ValueOrEnd;
+            }
+            (b'{', Expect::Value | Expect::ValueOrEnd) => {
+                self.stack.push(Frame::Object {
+                    object: JsObject::with_object_proto(context.intrinsics()),
+                    key: None,
+                });
+                self.expect = Expect::KeyOrEnd;
+            }
+            (b'-' | b'0'..=b'9' | b't' | b'f' | b'n', Expect::Value | Expect::ValueOrEnd) => {
+                self.token.push(byte);
+                self.token_kind = Some(TokenKind::Literal);
+            }
+            (b':', Expect::Colon) => self.expect = Expect::Value,
+            (b',', Expect::CommaOrEnd) => {
+                self.expect = match self.stack.last() {
+                    Some(Frame::Object { .. }) => Expect::Key,
+                    _ => Expect::Value,
+                };
+            }
+            (b']', Expect::ValueOrEnd | Expect::CommaOrEnd)
+                if matches!(self.stack.last(), Some(Frame::Array(_))) =>
+            {
+                let Some(Frame::Array(elements)) = self.stack.pop() else {
+                    unreachable!("the last frame must be an array");
+                };
+                let array = Array::create_array_from_list(elements, context);
+                self.complete_value(array.into());
+            }
+            (b'}', Expect::KeyOrEnd | Expect::CommaOrEnd)
+                if matches!(self.stack.last(), Some(Frame::Object { .. })) =>
+            {
+                let Some(Frame::Object { object, .. }) = self.stack.pop() else {
+                    unreachable!("the last frame must be an object");
+                };
+                self.complete_value(object.into());
+            }
+            _ => {
+                return Err(self.error(format_args!(
+                    "unexpected character `{}` at position {}",
+                    byte.escape_ascii(),
+                    self.position
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Completes a string token, which is either a key or a value.
+    fn complete_string(&mut self, string: JsString) {
+        if let (Expect::Key | Expect::KeyOrEnd, Some(Frame::Object { key, .. })) =
+            (self.expect, self.stack.last_mut())
+        {
+            *key = Some(string);
+            self.expect = Expect::Colon;
+        } else {
+            self.complete_value(string.into());
+        }
+    }
+
+    /// Completes a number, `true`, `false` or `null` token.
+    fn complete_literal(&mut self) -> JsResult<()> {
+        self.token_kind = None;
+        let value = match self.token.as_slice() {
+            b"true" => JsValue::new(true),
+            b"false" => JsValue::new(false),
+            b"null" => JsValue::null(),
+            number => parse_number(number).ok_or_else(|| {
+                self.error(format_args!(
+                    "invalid literal `{}` ending at position {}",
+                    number.escape_ascii(),
+                    self.position
+                ))
+            })?,
+        };
+        self.token.clear();
+        self.complete_value(value);
+        Ok(())
+    }
+
+    /// Adds a complete value to the array or object that is being parsed, or sets it as the
+    /// result if it is the outermost value.
+    fn complete_value(&mut self, value: JsValue) {
+        match self.stack.last_mut() {
+            None => {
+                self.result = Some(value);
+                self.expect = Expect::Done;
+            }
+            Some(Frame::Array(elements)) => {
+                elements.push(value);
+                self.expect = Expect::CommaOrEnd;
+            }
+            Some(Frame::Object { object, key }) => {
+                let key = key.take().expect("a value in an object must follow a key");
+                let property = PropertyDescriptor::builder()
+                    .value(value)
+                    .writable(true)
+                    .enumerable(true)
+                    .configurable(true);
+                object.borrow_mut().insert(key, property);
+                self.expect = Expect::CommaOrEnd;
+            }
+        }
+    }
+
+    /// Creates a `SyntaxError` with the given message.
+    fn error(&self, message: std::fmt::Arguments<'_>) -> crate::JsError {
+        JsNativeError::syntax()
+            .with_message(format!("invalid JSON: {message}"))
+            .into()
+    }
+}
+
+/// Decodes the contents of a JSON string, without the quotes, into a [`JsString`].
+///
+/// Returns `None` if the contents are not valid UTF-8, contain an invalid escape sequence, or
+/// contain an unescaped control character.
+pub(crate) fn decode_json_string(bytes: &[u8]) -> Option<JsString> {
+    let text = std::str::from_utf8(bytes).ok()?;
+    if !text.contains(|c: char| c == '\\' || c < ' ') {
+        return Some(JsString::from(text));
+    }
+
+    let mut units = Vec::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                let unit = match chars.next()? {
+                    '"' => 0x22,
+                    '\\' => 0x5C,
+                    '/' => 0x2F,
+                    'b' => 0x08,
+                    'f' => 0x0C,
+                    'n' => 0x0A,
+                    'r' => 0x0D,
+                    't' => 0x09,
+                    'u' => {
+                        let hex = chars.as_str().get(..4)?;
+                        if !hex.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+                            return None;
+                        }
+                        chars = chars.as_str()[4..].chars();
+                        u16::from_str_radix(hex, 16).ok()?
+                    }
+                    _ => return None,
+                };
+                units.push(unit);
+            }
+            '\0'..='\u{1F}' => return None,
+            c => units.extend_from_slice(c.encode_utf16(&mut [0; 2])),
+        }
+    }
+    Some(JsString::from(&units[..]))
+}
+
+/// Parses a JSON number, returning `None` if `bytes` is not a valid JSON number.
+fn parse_number(bytes: &[u8]) -> Option<JsValue> {
+    // -? (0 | [1-9][0-9]*) (. [0-9]+)? ([eE] [+-]? [0-9]+)?
+    let digits = |bytes: &[u8]| bytes.iter().take_while(|b| b.is_ascii_digit()).count();
+
+    let mut rest = bytes.strip_prefix(b"-").unwrap_or(bytes);
+    let integer_digits = digits(rest);
+    if integer_digits == 0 || (integer_digits > 1 && rest[0] == b'0') {
+        return None;
+    }
+    rest = &rest[integer_digits..];
+    let is_integer = rest.is_empty();
+
+    if let Some(fraction) = rest.strip_prefix(b".") {
+        let fraction_digits = digits(fraction);
+        if fraction_digits == 0 {
+            return None;
+        }
+        rest = &fraction[fraction_digits..];
+    }
+    if let Some(exponent) = rest.strip_prefix(b"e").or_else(|| rest.strip_prefix(b"E")) {
+        let exponent = exponent
+            .strip_prefix(b"+")
+            .or_else(|| exponent.strip_prefix(b"-"))
+            .unwrap_or(exponent);
+        let exponent_digits = digits(exponent);
+        if exponent_digits == 0 {
+            return None;
+        }
+        rest = &exponent[exponent_digits..];
+    }
+    if !rest.is_empty() {
+        return None;
+    }
+
+    // The grammar above only accepts ASCII bytes.
+    let text = std::str::from_utf8(bytes).ok()?;
+    if is_integer && text != "-0" {
+        if let Ok(integer) = text.parse::<i32>() {
+            return Some(JsValue::new(integer));
+        }
+    }
+    text.parse::<f64>().ok().map(JsValue::new)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::{Context, JsNativeErrorKind, JsValue};
+
+    use super::JsonStreamParser;
+
+    #[test]
+    fn json_stream_chunk_boundaries() {
+        let data = r#" { "name": "Jöhn \"Döe\" 🦀", "age": -43.5e1, "minor": false,
+            "extra": { "address": null, "empty": [], "nested": [[1, {}], "a\\b"] },
+            "age": 2147483648 } "#;
+        let expected = json!({
+            "name": "Jöhn \"Döe\" 🦀",
+            "age": 2_147_483_648.0,
+            "minor": false,
+            "extra": { "address": null, "empty": [], "nested": [[1, {}], "a\\b"] },
+        });
+
+        let context = &mut Context::default();
+        for chunk_size in [1, 2, 3, 7, data.len()] {
+            let mut parser = JsonStreamParser::new();
+            for chunk in data.as_bytes().chunks(chunk_size) {
+                parser.push(chunk, context).unwrap();
+            }
+            let value = parser.finish().unwrap();
+            assert_eq!(value.to_json(context).unwrap(), Some(expected.clone()));
+        }
+
+        let value = JsValue::from_json_reader(data.as_bytes(), context).unwrap();
+        assert_eq!(value.to_json(context).unwrap(), Some(expected));
+    }
+
+    #[test]
+    fn json_stream_invalid_input() {
+        let context = &mut Context::default();
+        for data in [
+            "",
+            "[1,]",
+            r#"{"a" 1}"#,
+            r#"{"a": 1,}"#,
+            "[01]",
+            "[1.]",
+            "tru",
+            "1 2",
+            r#""\x""#,
+            "\"a\nb\"",
+            "[1",
+        ] {
+            let error = JsValue::from_json_reader(data.as_bytes(), context)
+                .expect_err(data)
+                .as_native()
+                .cloned()
+                .expect(data);
+            assert!(matches!(error.kind, JsNativeErrorKind::Syntax), "{data}");
+        }
+    }
+}
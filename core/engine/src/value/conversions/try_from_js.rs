@@ -10,6 +10,12 @@ mod tuples;
 
 /// This trait adds a fallible and efficient conversions from a [`JsValue`] to Rust types.
 pub trait TryFromJs: Sized {
+    /// Whether `undefined` stands for a missing value, making this an optional argument of typed
+    /// functions. See [`TryFromJsArgument::OPTIONAL`].
+    ///
+    /// [`TryFromJsArgument::OPTIONAL`]: crate::interop::TryFromJsArgument::OPTIONAL
+    const OPTIONAL: bool = false;
+
     /// This function tries to convert a JavaScript value into `Self`.
     fn try_from_js(value: &JsValue, context: &mut Context) -> JsResult<Self>;
 }
@@ -75,6 +81,8 @@ impl<T> TryFromJs for Option<T>
 where
     T: TryFromJs,
 {
+    const OPTIONAL: bool = true;
+
     fn try_from_js(value: &JsValue, context: &mut Context) -> JsResult<Self> {
         // TODO: remove NULL -> None conversion.
         if value.is_null_or_undefined() {
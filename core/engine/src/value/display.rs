@@ -70,8 +70,7 @@ macro_rules! print_obj_value {
         }
     };
     (props of $obj:expr, $display_fn:ident, $indent:expr, $encounters:expr, $print_internals:expr) => {
-        {let mut keys: Vec<_> = $obj.borrow().properties().index_property_keys().map(crate::property::PropertyKey::from).collect();
-        keys.extend($obj.borrow().properties().shape.keys());
+        {let keys = $obj.borrow().properties().ordered_keys();
         let mut result = Vec::default();
         for key in keys {
             let val = $obj.borrow().properties().get(&key).expect("There should be a value");
@@ -21,12 +21,12 @@ use boa_profiler::Profiler;
 #[doc(inline)]
 pub use conversions::convert::Convert;
 
-pub(crate) use self::conversions::IntoOrUndefined;
+pub(crate) use self::conversions::{json_stream::decode_json_string, IntoOrUndefined};
 #[doc(inline)]
 pub use self::{
-    conversions::try_from_js::TryFromJs, conversions::try_into_js::TryIntoJs,
-    display::ValueDisplay, integer::IntegerOrInfinity, operations::*, r#type::Type,
-    variant::JsVariant,
+    conversions::json_stream::JsonStreamParser, conversions::try_from_js::TryFromJs,
+    conversions::try_into_js::TryIntoJs, display::ValueDisplay, integer::IntegerOrInfinity,
+    operations::*, r#type::Type, variant::JsVariant,
 };
 use crate::builtins::RegExp;
 use crate::object::{ErasedObject, JsFunction, JsPromise, JsRegExp};
@@ -80,6 +80,10 @@ static TWO_E_63: LazyLock<BigInt> = LazyLock::new(|| {
 #[derive(Finalize, Debug, Clone, Trace)]
 pub struct JsValue(inner::InnerValue);
 
+// Values are NaN-boxed, so that the register file of the VM and the storage of objects hold them
+// in a single word, with small integers stored inline.
+static_assertions::const_assert_eq!(size_of::<JsValue>(), size_of::<u64>());
+
 impl JsValue {
     /// Create a new [`JsValue`] from an inner value.
     #[inline]
@@ -1,7 +1,7 @@
 use crate::{
     builtins::{
         number::{f64_to_int32, f64_to_uint32},
-        Number,
+        Math, Number,
     },
     error::JsNativeError,
     js_string,
@@ -81,8 +81,10 @@ impl JsValue {
     pub fn mul(&self, other: &Self, context: &mut Context) -> JsResult<Self> {
         Ok(match (self.variant(), other.variant()) {
             // Fast path:
+            // A zero product may be `-0`, which is not an `i32`.
             (JsVariant::Integer32(x), JsVariant::Integer32(y)) => x
                 .checked_mul(y)
+                .filter(|product| *product != 0)
                 .map_or_else(|| Self::new(f64::from(x) * f64::from(y)), Self::new),
             (JsVariant::Float64(x), JsVariant::Float64(y)) => Self::new(x * y),
             (JsVariant::Integer32(x), JsVariant::Float64(y)) => Self::new(f64::from(x) * y),
@@ -198,40 +200,31 @@ impl JsValue {
     }
 
     /// Perform the binary `**` operator on the value and return the result.
-    // NOTE: There are some cases in the spec where we have to compare floats
-    #[allow(clippy::float_cmp)]
     pub fn pow(&self, other: &Self, context: &mut Context) -> JsResult<Self> {
         Ok(match (self.variant(), other.variant()) {
             // Fast path:
             (JsVariant::Integer32(x), JsVariant::Integer32(y)) => u32::try_from(y)
                 .ok()
                 .and_then(|y| x.checked_pow(y))
-                .map_or_else(|| Self::new(f64::from(x).powi(y)), Self::new),
+                .map_or_else(
+                    || Self::new(Math::exponentiate(f64::from(x), f64::from(y), context)),
+                    Self::new,
+                ),
             (JsVariant::Float64(x), JsVariant::Float64(y)) => {
-                if x.abs() == 1.0 && y.is_infinite() {
-                    Self::nan()
-                } else {
-                    Self::new(x.powf(y))
-                }
+                Self::new(Math::exponentiate(x, y, context))
             }
             (JsVariant::Integer32(x), JsVariant::Float64(y)) => {
-                if x.wrapping_abs() == 1 && y.is_infinite() {
-                    Self::nan()
-                } else {
-                    Self::new(f64::from(x).powf(y))
-                }
+                Self::new(Math::exponentiate(f64::from(x), y, context))
+            }
+            (JsVariant::Float64(x), JsVariant::Integer32(y)) => {
+                Self::new(Math::exponentiate(x, f64::from(y), context))
             }
-            (JsVariant::Float64(x), JsVariant::Integer32(y)) => Self::new(x.powi(y)),
             (JsVariant::BigInt(a), JsVariant::BigInt(b)) => Self::new(JsBigInt::pow(a, b)?),
 
             // Slow path:
             (_, _) => match (self.to_numeric(context)?, other.to_numeric(context)?) {
                 (Numeric::Number(a), Numeric::Number(b)) => {
-                    if a.abs() == 1.0 && b.is_infinite() {
-                        Self::nan()
-                    } else {
-                        Self::new(a.powf(b))
-                    }
+                    Self::new(Math::exponentiate(a, b, context))
                 }
                 (Numeric::BigInt(ref a), Numeric::BigInt(ref b)) => Self::new(JsBigInt::pow(a, b)?),
                 (_, _) => {
@@ -6,11 +6,12 @@ use crate::{
     builtins::iterable::IteratorRecord, environments::EnvironmentStack, realm::Realm,
     vm::CodeBlock, JsValue,
 };
-use boa_ast::scope::BindingLocator;
+use boa_ast::{scope::BindingLocator, Position};
 use boa_gc::{Finalize, Gc, Trace};
+use std::{path::Path, rc::Rc};
 use thin_vec::ThinVec;
 
-use super::ActiveRunnable;
+use super::{ActiveRunnable, StackTrace};
 
 bitflags::bitflags! {
     /// Flags associated with a [`CallFrame`].
@@ -28,6 +29,10 @@ bitflags::bitflags! {
 
         /// If the `this` value has been cached.
         const THIS_VALUE_CACHED = 0b0000_1000;
+
+        /// Can native functions called from this [`CallFrame`] suspend it, with
+        /// [`crate::Context::suspend()`].
+        const SUSPENDABLE = 0b0001_0000;
     }
 }
 
@@ -68,6 +73,15 @@ pub struct CallFrame {
     // SAFETY: Nothing in `CallFrameFlags` requires tracing, so this is safe.
     #[unsafe_ignore_trace]
     pub(crate) flags: CallFrameFlags,
+
+    /// The calls that awaited this async function, captured when it was first suspended.
+    ///
+    /// This is only captured if enabled with [`ContextBuilder::error_stack_traces`].
+    ///
+    /// [`ContextBuilder::error_stack_traces`]: crate::context::ContextBuilder::error_stack_traces
+    // SAFETY: Nothing in `StackTrace` requires tracing, so this is safe.
+    #[unsafe_ignore_trace]
+    pub(crate) async_trace: Option<StackTrace>,
 }
 
 /// ---- `CallFrame` public API ----
@@ -78,6 +92,18 @@ impl CallFrame {
     pub const fn code_block(&self) -> &Gc<CodeBlock> {
         &self.code_block
     }
+
+    /// Returns the position in the source code of the instruction being run by this call frame.
+    pub(crate) fn source_position(&self) -> Option<Position> {
+        // Note: -1 because the pc is incremented after fetching the opcode.
+        let pc = self.pc.checked_sub(1)?;
+        self.code_block.source_position(pc)
+    }
+
+    /// Returns the path of the script or module of this call frame, if it has one.
+    pub(crate) fn source_path(&self) -> Option<&Rc<Path>> {
+        self.active_runnable.as_ref().and_then(ActiveRunnable::path)
+    }
 }
 
 /// ---- `CallFrame` creation methods ----
@@ -110,6 +136,7 @@ impl CallFrame {
             environments,
             realm,
             flags: CallFrameFlags::empty(),
+            async_trace: None,
         }
     }
 
@@ -198,6 +225,11 @@ impl CallFrame {
     pub(crate) fn has_this_value_cached(&self) -> bool {
         self.flags.contains(CallFrameFlags::THIS_VALUE_CACHED)
     }
+
+    /// Does this have the [`CallFrameFlags::SUSPENDABLE`] flag.
+    pub(crate) fn suspendable(&self) -> bool {
+        self.flags.contains(CallFrameFlags::SUSPENDABLE)
+    }
 }
 
 /// ---- `CallFrame` stack methods ----
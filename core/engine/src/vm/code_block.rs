@@ -11,15 +11,18 @@ use crate::{
     Context, JsBigInt, JsString, JsValue, SpannedSourceText,
 };
 use bitflags::bitflags;
-use boa_ast::scope::{BindingLocator, Scope};
+use boa_ast::{
+    scope::{BindingLocator, BindingLocatorScope, Scope},
+    Position,
+};
 use boa_gc::{empty_trace, Finalize, Gc, Trace};
 use boa_profiler::Profiler;
 use std::{cell::Cell, fmt::Display, fmt::Write as _};
 use thin_vec::ThinVec;
 
 use super::{
-    opcode::{ByteCode, Instruction, InstructionIterator},
-    InlineCache,
+    opcode::{ByteCode, Instruction, InstructionIterator, SwitchTable},
+    InlineCache, SourcePositionTable,
 };
 
 bitflags! {
@@ -105,6 +108,10 @@ pub(crate) enum Constant {
     /// Declarative or function scope.
     // Safety: Nothing in `Scope` needs tracing, so this is safe.
     Scope(#[unsafe_ignore_trace] Scope),
+
+    /// The jump table of a `switch` statement.
+    // Safety: Nothing in `SwitchTable` needs tracing, so this is safe.
+    SwitchTable(#[unsafe_ignore_trace] Box<SwitchTable>),
 }
 
 /// The internal representation of a JavaScript function.
@@ -152,8 +159,65 @@ pub struct CodeBlock {
     /// inline caching
     pub(crate) ic: Box<[InlineCache]>,
 
+    /// Source text of callee expressions, keyed by the address following their call instruction.
+    #[unsafe_ignore_trace]
+    pub(crate) callee_texts: ThinVec<(u32, JsString)>,
+
+    /// Source positions of the instructions.
+    #[unsafe_ignore_trace]
+    pub(crate) source_positions: SourcePositionTable,
+
     /// source text of the code block
     pub(crate) source_text_spanned: SpannedSourceText,
+
+    /// Counts the code block in the statistics of the thread while it is alive.
+    #[unsafe_ignore_trace]
+    pub(crate) counter: CodeBlockCounter,
+}
+
+thread_local! {
+    /// The number of live [`CodeBlock`]s of the current thread, and the total number of constants
+    /// in their constant pools.
+    static LIVE_CODE_BLOCKS: Cell<(usize, usize)> = const { Cell::new((0, 0)) };
+}
+
+/// Counts a [`CodeBlock`] in the live code blocks of the current thread, until it is dropped.
+#[derive(Debug)]
+pub(crate) struct CodeBlockCounter {
+    constants: usize,
+}
+
+impl CodeBlockCounter {
+    /// Counts a new code block with `constants` constants.
+    pub(crate) fn new(constants: usize) -> Self {
+        LIVE_CODE_BLOCKS.with(|live| {
+            let (code_blocks, total_constants) = live.get();
+            live.set((code_blocks + 1, total_constants + constants));
+        });
+        Self { constants }
+    }
+
+    /// Gets the number of live code blocks of the current thread, and the total number of
+    /// constants in their constant pools.
+    pub(crate) fn live() -> (usize, usize) {
+        LIVE_CODE_BLOCKS.with(Cell::get)
+    }
+}
+
+impl Clone for CodeBlockCounter {
+    fn clone(&self) -> Self {
+        Self::new(self.constants)
+    }
+}
+
+impl Drop for CodeBlockCounter {
+    fn drop(&mut self) {
+        // The counters may already be destroyed if the thread is exiting.
+        let _ = LIVE_CODE_BLOCKS.try_with(|live| {
+            let (code_blocks, total_constants) = live.get();
+            live.set((code_blocks - 1, total_constants - self.constants));
+        });
+    }
 }
 
 /// ---- `CodeBlock` public API ----
@@ -176,7 +240,10 @@ impl CodeBlock {
             parameter_length: 0,
             handlers: ThinVec::default(),
             ic: Box::default(),
+            callee_texts: ThinVec::default(),
+            source_positions: SourcePositionTable::default(),
             source_text_spanned: SpannedSourceText::new_empty(),
+            counter: CodeBlockCounter::new(0),
         }
     }
 
@@ -277,6 +344,21 @@ impl CodeBlock {
             .find(|(_, handler)| handler.contains(pc))
     }
 
+    /// Find the source text of the callee of the call instruction ending at `pc`, if it was
+    /// recorded.
+    pub(crate) fn find_callee_text(&self, pc: u32) -> Option<&JsString> {
+        self.callee_texts
+            .binary_search_by_key(&pc, |(end, _)| *end)
+            .ok()
+            .map(|index| &self.callee_texts[index].1)
+    }
+
+    /// Find the source position of the instruction at `pc`, if it is known.
+    #[inline]
+    pub(crate) fn source_position(&self, pc: u32) -> Option<Position> {
+        self.source_positions.find(pc)
+    }
+
     /// Get the [`JsString`] constant from the [`CodeBlock`].
     ///
     /// # Panics
@@ -291,6 +373,17 @@ impl CodeBlock {
         panic!("expected string constant at index {index}")
     }
 
+    /// Get the [`BindingLocator`] of the binding at `slot` in the environment at `environment`,
+    /// which is accessed by environment slot in this [`CodeBlock`].
+    ///
+    /// This is only needed on cold paths, like building the message of an error.
+    pub(crate) fn slot_binding(&self, environment: u32, slot: u32) -> Option<&BindingLocator> {
+        self.bindings.iter().find(|locator| {
+            matches!(locator.scope(), BindingLocatorScope::Stack(index) if index == environment)
+                && locator.binding_index() == slot
+        })
+    }
+
     /// Get the function ([`Gc<CodeBlock>`]) constant from the [`CodeBlock`].
     ///
     /// # Panics
@@ -409,6 +502,11 @@ impl CodeBlock {
             Instruction::PushInt32 { value, dst } => {
                 format!("value:{value}, dst:{dst}")
             }
+            Instruction::AddInt32 { dst, lhs, rhs }
+            | Instruction::SubInt32 { dst, lhs, rhs }
+            | Instruction::MulInt32 { dst, lhs, rhs } => {
+                format!("dst:{dst}, lhs:{lhs}, rhs:{rhs}")
+            }
             Instruction::PushFloat { value, dst } => {
                 format!("value:{value}, dst:{dst}")
             }
@@ -439,6 +537,7 @@ impl CodeBlock {
             | Instruction::JumpIfFalse { address, value }
             | Instruction::JumpIfNotUndefined { address, value }
             | Instruction::JumpIfNullOrUndefined { address, value }
+            | Instruction::JumpIfNullOrUndefinedSetUndefined { address, value }
             | Instruction::LogicalAnd { address, value }
             | Instruction::LogicalOr { address, value }
             | Instruction::Coalesce { address, value } => {
@@ -451,6 +550,7 @@ impl CodeBlock {
             } => {
                 format!("value:{value}, condition:{condition}, address:{address}")
             }
+            Instruction::CaseTable { value, table } => format!("value:{value}, table:{table}"),
             Instruction::CallEval {
                 argument_count,
                 scope_index,
@@ -480,6 +580,25 @@ impl CodeBlock {
             | Instruction::DeleteName { dst, binding_index } => {
                 format!("dst:{dst}, binding_index:{binding_index}")
             }
+            Instruction::GetBinding {
+                dst,
+                environment,
+                slot,
+            }
+            | Instruction::GetBindingUnchecked {
+                dst,
+                environment,
+                slot,
+            } => {
+                format!("dst:{dst}, environment:{environment}, slot:{slot}")
+            }
+            Instruction::SetBinding {
+                src,
+                environment,
+                slot,
+            } => {
+                format!("src:{src}, environment:{environment}, slot:{slot}")
+            }
             Instruction::GetNameGlobal {
                 dst,
                 binding_index,
@@ -564,6 +683,11 @@ impl CodeBlock {
                 value,
                 name_index,
             }
+            | Instruction::DefineClassStaticFieldByName {
+                object,
+                value,
+                name_index,
+            }
             | Instruction::DefineClassStaticGetterByName {
                 object,
                 value,
@@ -667,6 +791,7 @@ impl CodeBlock {
             Instruction::DefineOwnPropertyByValue { value, key, object }
             | Instruction::DefineClassStaticMethodByValue { value, key, object }
             | Instruction::DefineClassMethodByValue { value, key, object }
+            | Instruction::DefineClassStaticFieldByValue { value, key, object }
             | Instruction::SetPropertyGetterByValue { value, key, object }
             | Instruction::DefineClassStaticGetterByValue { value, key, object }
             | Instruction::DefineClassGetterByValue { value, key, object }
@@ -704,10 +829,16 @@ impl CodeBlock {
             Instruction::PushValueToArray { value, array } => {
                 format!("value:{value}, array:{array}")
             }
-            Instruction::PushElisionToArray { array }
-            | Instruction::PushIteratorToArray { array } => {
+            Instruction::PushElisionToArray { array } => {
                 format!("array:{array}")
             }
+            Instruction::PushSpreadToArray {
+                value,
+                array,
+                is_array_literal,
+            } => {
+                format!("value:{value}, array:{array}, is_array_literal:{is_array_literal}")
+            }
             Instruction::TypeOf { value }
             | Instruction::LogicalNot { value }
             | Instruction::Pos { value }
@@ -728,6 +859,34 @@ impl CodeBlock {
                     "object:{object}, value:{value}, name_index:{name_index}, is_anonymous_function:{is_anonymous_function}"
                 )
             }
+            Instruction::DefineClassAutoAccessorByName {
+                class,
+                object,
+                storage_index,
+                name_index,
+            } => {
+                format!(
+                    "class:{class}, object:{object}, storage_index:{storage_index}, name_index:{name_index}"
+                )
+            }
+            Instruction::DefineClassAutoAccessorByValue {
+                class,
+                object,
+                storage_index,
+                key,
+            } => {
+                format!("class:{class}, object:{object}, storage_index:{storage_index}, key:{key}")
+            }
+            Instruction::DefineClassPrivateAutoAccessor {
+                class,
+                storage_index,
+                name_index,
+                is_static,
+            } => {
+                format!(
+                    "class:{class}, storage_index:{storage_index}, name_index:{name_index}, is_static:{is_static}"
+                )
+            }
             Instruction::MaybeException {
                 has_exception,
                 exception,
@@ -750,6 +909,7 @@ impl CodeBlock {
             }
             Instruction::IteratorDone { dst }
             | Instruction::IteratorValue { dst }
+            | Instruction::IteratorStepValue { dst }
             | Instruction::IteratorResult { dst }
             | Instruction::IteratorToArray { dst }
             | Instruction::IteratorStackEmpty { dst }
@@ -877,20 +1037,7 @@ impl CodeBlock {
             | Instruction::Reserved47
             | Instruction::Reserved48
             | Instruction::Reserved49
-            | Instruction::Reserved50
-            | Instruction::Reserved51
-            | Instruction::Reserved52
-            | Instruction::Reserved53
-            | Instruction::Reserved54
-            | Instruction::Reserved55
-            | Instruction::Reserved56
-            | Instruction::Reserved57
-            | Instruction::Reserved58
-            | Instruction::Reserved59
-            | Instruction::Reserved60
-            | Instruction::Reserved61
-            | Instruction::Reserved62
-            | Instruction::Reserved63 => unreachable!("Reserved opcodes are unreachable"),
+            | Instruction::Reserved50 => unreachable!("Reserved opcodes are unreachable"),
         }
     }
 }
@@ -963,6 +1110,18 @@ impl Display for CodeBlock {
                             v.num_bindings()
                         )?;
                     }
+                    Constant::SwitchTable(v) => match &**v {
+                        SwitchTable::Dense { min, addresses } => writeln!(
+                            f,
+                            "[SWITCH TABLE] dense, min: {min}, cases: {}",
+                            addresses.len()
+                        )?,
+                        SwitchTable::Hashed { strings, numbers } => writeln!(
+                            f,
+                            "[SWITCH TABLE] hashed, cases: {}",
+                            strings.len() + numbers.len()
+                        )?,
+                    },
                 }
             }
         }
@@ -0,0 +1,176 @@
+//! Suspending the execution of a script from a native function.
+//!
+//! A native function called from a script evaluated with [`Script::evaluate_suspendable`] can
+//! suspend the script with [`Context::suspend`]. The frames of the script are then moved into a
+//! [`Continuation`], which resumes the script later with the value that the call evaluates to.
+//!
+//! [`Script::evaluate_suspendable`]: crate::Script::evaluate_suspendable
+
+use super::{CallFrame, CallFrameFlags, CompletionRecord, Stack};
+use crate::{Context, JsError, JsNativeError, JsResult, JsValue};
+use boa_gc::{Finalize, Trace};
+use std::ops::ControlFlow;
+
+/// The suspension state of the virtual machine.
+#[derive(Debug, Default)]
+pub(crate) struct Suspension {
+    /// The stack index of the callee of the `Call` instruction being executed, used to check that
+    /// a native function is called directly from the script.
+    pub(crate) call: Option<usize>,
+
+    /// If the native function being called can suspend the script.
+    pub(crate) native: bool,
+
+    /// If the native function being called requested to suspend the script.
+    pub(crate) requested: bool,
+}
+
+/// The result of evaluating a script that native functions can suspend.
+#[derive(Debug)]
+pub enum SuspendableResult {
+    /// The script ran to completion and returned this value.
+    Complete(JsValue),
+
+    /// The script was suspended by a native function.
+    Suspended(Continuation),
+}
+
+/// The suspended execution of a script, from the call of the native function that suspended it
+/// with [`Context::suspend`] up to the script itself.
+///
+/// Dropping the continuation abandons the execution of the script without running its `finally`
+/// blocks.
+#[derive(Debug, Trace, Finalize)]
+pub struct Continuation {
+    frames: Vec<CallFrame>,
+    stack: Stack,
+    return_value: JsValue,
+    pending_exception: Option<JsError>,
+    frame_pointer: u32,
+}
+
+impl Continuation {
+    /// Moves the frames of the suspended script out of the virtual machine.
+    fn capture(context: &mut Context) -> Self {
+        let mut frames = Vec::new();
+        loop {
+            let frame = context.vm.pop_frame().expect("the script frame must exist");
+            let exit_early = frame.exit_early();
+            frames.push(frame);
+            if exit_early {
+                break;
+            }
+        }
+        frames.reverse();
+
+        let script = &frames[0];
+        let frame_pointer = script.frame_pointer() as u32;
+        let stack = context.vm.stack.split_off_frame(script);
+
+        Self {
+            frames,
+            stack,
+            return_value: context.vm.take_return_value(),
+            pending_exception: context.vm.pending_exception.take(),
+            frame_pointer,
+        }
+    }
+
+    /// Resumes the script, making the call that suspended it return `value`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error thrown by the script if it is not caught.
+    pub fn resume(self, value: JsValue, context: &mut Context) -> JsResult<SuspendableResult> {
+        self.restore(context);
+        context.vm.stack.pop();
+        context.vm.stack.push(value);
+        run_suspendable(context)
+    }
+
+    /// Resumes the script, making the call that suspended it throw `error`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error thrown by the script if it is not caught.
+    pub fn throw(self, error: JsError, context: &mut Context) -> JsResult<SuspendableResult> {
+        self.restore(context);
+        context.vm.stack.pop();
+        match context.handle_error(error) {
+            ControlFlow::Continue(()) => run_suspendable(context),
+            ControlFlow::Break(record) => complete(record, context),
+        }
+    }
+
+    /// Moves the frames of the suspended script back into the virtual machine.
+    fn restore(self, context: &mut Context) {
+        let frame_pointer = context.vm.stack.stack.len() as u32;
+        context.vm.stack.stack.extend(self.stack.stack);
+
+        for mut frame in self.frames {
+            let rp = frame.rp - self.frame_pointer + frame_pointer;
+            let flags = frame.flags;
+
+            // NOTE: The registers of the frames were moved with the stack.
+            frame.flags |= CallFrameFlags::REGISTERS_ALREADY_PUSHED;
+            context.vm.push_frame(frame);
+
+            let frame = context.vm.frame_mut();
+            frame.rp = rp;
+            frame.flags = flags;
+        }
+
+        context.vm.set_return_value(self.return_value);
+        context.vm.pending_exception = self.pending_exception;
+    }
+}
+
+/// Runs the script of the current frame, which was pushed to be suspendable.
+pub(crate) fn run_suspendable(context: &mut Context) -> JsResult<SuspendableResult> {
+    let record = context.run();
+    complete(record, context)
+}
+
+/// Finishes the execution of a suspendable script, capturing its frames if it was suspended.
+fn complete(record: CompletionRecord, context: &mut Context) -> JsResult<SuspendableResult> {
+    if std::mem::take(&mut context.vm.suspension.requested) {
+        return Ok(SuspendableResult::Suspended(Continuation::capture(context)));
+    }
+
+    context.vm.pop_frame();
+    context.clear_kept_objects();
+
+    record.consume().map(SuspendableResult::Complete)
+}
+
+impl Context {
+    /// Suspends the script that called the current native function once the function returns.
+    ///
+    /// The script must be evaluated with [`Script::evaluate_suspendable`], which then returns a
+    /// [`Continuation`] to resume it. The value returned by the native function is ignored, the call
+    /// evaluates to the value given to [`Continuation::resume`] instead.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TypeError` if the native function was not called directly by the script or by a
+    /// function that it called, e.g. if it was called by another native function, as a getter or
+    /// from a generator.
+    ///
+    /// [`Script::evaluate_suspendable`]: crate::Script::evaluate_suspendable
+    pub fn suspend(&mut self) -> JsResult<()> {
+        let suspendable = self.vm.suspension.native
+            && std::iter::once(&self.vm.frame)
+                .chain(self.vm.frames.iter().rev())
+                .find(|frame| frame.exit_early())
+                .is_some_and(CallFrame::suspendable);
+
+        if !suspendable {
+            return Err(JsNativeError::typ()
+                .with_message("the caller of this native function cannot be suspended")
+                .into());
+        }
+
+        self.vm.suspension.requested = true;
+        Ok(())
+    }
+}
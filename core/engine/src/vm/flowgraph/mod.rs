@@ -14,7 +14,7 @@ pub use graph::*;
 pub use node::*;
 
 use super::{
-    opcode::{Instruction, InstructionIterator},
+    opcode::{Instruction, InstructionIterator, SwitchTable},
     Constant,
 };
 
@@ -50,6 +50,9 @@ impl CodeBlock {
                 | Instruction::Sub { .. }
                 | Instruction::Div { .. }
                 | Instruction::Mul { .. }
+                | Instruction::AddInt32 { .. }
+                | Instruction::SubInt32 { .. }
+                | Instruction::MulInt32 { .. }
                 | Instruction::Mod { .. }
                 | Instruction::Pow { .. }
                 | Instruction::ShiftRight { .. }
@@ -123,7 +126,8 @@ impl CodeBlock {
                 Instruction::JumpIfFalse { address, .. }
                 | Instruction::JumpIfTrue { address, .. }
                 | Instruction::JumpIfNotUndefined { address, .. }
-                | Instruction::JumpIfNullOrUndefined { address, .. } => {
+                | Instruction::JumpIfNullOrUndefined { address, .. }
+                | Instruction::JumpIfNullOrUndefinedSetUndefined { address, .. } => {
                     graph.add_node(previous_pc, NodeShape::Diamond, label.into(), Color::None);
                     graph.add_edge(
                         previous_pc,
@@ -174,6 +178,34 @@ impl CodeBlock {
                         EdgeStyle::Line,
                     );
                 }
+                Instruction::CaseTable { table, .. } => {
+                    graph.add_node(previous_pc, NodeShape::None, label.into(), Color::None);
+                    graph.add_edge(
+                        previous_pc,
+                        pc,
+                        Some("NO MATCH".into()),
+                        Color::Red,
+                        EdgeStyle::Line,
+                    );
+                    let addresses: Vec<u32> = match &self.constants[usize::from(table)] {
+                        Constant::SwitchTable(table) => match &**table {
+                            SwitchTable::Dense { addresses, .. } => addresses.to_vec(),
+                            SwitchTable::Hashed { strings, numbers } => {
+                                strings.values().chain(numbers.values()).copied().collect()
+                            }
+                        },
+                        _ => unreachable!("constant should be a switch table"),
+                    };
+                    for address in addresses {
+                        graph.add_edge(
+                            previous_pc,
+                            address as usize,
+                            Some("MATCH".into()),
+                            Color::Green,
+                            EdgeStyle::Line,
+                        );
+                    }
+                }
                 Instruction::GeneratorDelegateNext {
                     return_method_undefined,
                     throw_method_undefined,
@@ -276,6 +308,9 @@ impl CodeBlock {
                 | Instruction::GetNameAndLocator { .. }
                 | Instruction::GetNameOrUndefined { .. }
                 | Instruction::SetName { .. }
+                | Instruction::GetBinding { .. }
+                | Instruction::GetBindingUnchecked { .. }
+                | Instruction::SetBinding { .. }
                 | Instruction::DeleteName { .. } => {
                     graph.add_node(previous_pc, NodeShape::None, label.into(), Color::None);
                     graph.add_edge(previous_pc, pc, None, Color::None, EdgeStyle::Line);
@@ -287,6 +322,7 @@ impl CodeBlock {
                 | Instruction::DefineOwnPropertyByName { .. }
                 | Instruction::DefineClassStaticMethodByName { .. }
                 | Instruction::DefineClassMethodByName { .. }
+                | Instruction::DefineClassStaticFieldByName { .. }
                 | Instruction::SetPropertyGetterByName { .. }
                 | Instruction::DefineClassStaticGetterByName { .. }
                 | Instruction::DefineClassGetterByName { .. }
@@ -383,6 +419,7 @@ impl CodeBlock {
                 | Instruction::DefineOwnPropertyByValue { .. }
                 | Instruction::DefineClassStaticMethodByValue { .. }
                 | Instruction::DefineClassMethodByValue { .. }
+                | Instruction::DefineClassStaticFieldByValue { .. }
                 | Instruction::SetPropertyGetterByValue { .. }
                 | Instruction::DefineClassStaticGetterByValue { .. }
                 | Instruction::DefineClassGetterByValue { .. }
@@ -402,6 +439,7 @@ impl CodeBlock {
                 | Instruction::IteratorNext
                 | Instruction::IteratorFinishAsyncNext { .. }
                 | Instruction::IteratorValue { .. }
+                | Instruction::IteratorStepValue { .. }
                 | Instruction::IteratorResult { .. }
                 | Instruction::IteratorDone { .. }
                 | Instruction::IteratorToArray { .. }
@@ -411,7 +449,7 @@ impl CodeBlock {
                 | Instruction::RestParameterInit { .. }
                 | Instruction::PushValueToArray { .. }
                 | Instruction::PushElisionToArray { .. }
-                | Instruction::PushIteratorToArray { .. }
+                | Instruction::PushSpreadToArray { .. }
                 | Instruction::PushNewArray { .. }
                 | Instruction::GeneratorYield { .. }
                 | Instruction::AsyncGeneratorYield { .. }
@@ -420,6 +458,9 @@ impl CodeBlock {
                 | Instruction::CompletePromiseCapability
                 | Instruction::GeneratorNext { .. }
                 | Instruction::PushClassField { .. }
+                | Instruction::DefineClassAutoAccessorByName { .. }
+                | Instruction::DefineClassAutoAccessorByValue { .. }
+                | Instruction::DefineClassPrivateAutoAccessor { .. }
                 | Instruction::SuperCallDerived
                 | Instruction::Await { .. }
                 | Instruction::NewTarget { .. }
@@ -498,20 +539,7 @@ impl CodeBlock {
                 | Instruction::Reserved47
                 | Instruction::Reserved48
                 | Instruction::Reserved49
-                | Instruction::Reserved50
-                | Instruction::Reserved51
-                | Instruction::Reserved52
-                | Instruction::Reserved53
-                | Instruction::Reserved54
-                | Instruction::Reserved55
-                | Instruction::Reserved56
-                | Instruction::Reserved57
-                | Instruction::Reserved58
-                | Instruction::Reserved59
-                | Instruction::Reserved60
-                | Instruction::Reserved61
-                | Instruction::Reserved62
-                | Instruction::Reserved63 => unreachable!("Reserved opcodes are unreachable"),
+                | Instruction::Reserved50 => unreachable!("Reserved opcodes are unreachable"),
             }
         }
 
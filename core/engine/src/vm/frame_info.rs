@@ -0,0 +1,153 @@
+//! Introspection of the call frames of the running code, for embedders.
+//!
+//! See [`Context::frames`].
+
+use std::{fmt, path::Path, rc::Rc};
+
+use boa_ast::Position;
+
+use super::{CallFrame, Stack};
+use crate::{Context, JsObject, JsString, JsValue};
+
+/// A function call of the running code, as seen by the host.
+///
+/// This is returned by [`Context::frames`], and gives access to the function, receiver and
+/// arguments of a call, and the position in the source code where it is currently running.
+#[derive(Clone, Copy)]
+pub struct FrameInfo<'a> {
+    frame: &'a CallFrame,
+
+    /// The stack holding the registers of the frame, if it is the one of the running code.
+    ///
+    /// The frames older than a resumed generator or async function keep their registers in a
+    /// stack that was swapped out when resuming it.
+    stack: Option<&'a Stack>,
+}
+
+impl<'a> FrameInfo<'a> {
+    /// Returns the [`CallFrame`] of the call.
+    #[inline]
+    #[must_use]
+    pub const fn call_frame(&self) -> &'a CallFrame {
+        self.frame
+    }
+
+    /// Returns the name of the called function, which is `<main>` for scripts and modules, and
+    /// empty for anonymous functions.
+    #[inline]
+    #[must_use]
+    pub fn function_name(&self) -> &'a JsString {
+        self.frame.code_block().name()
+    }
+
+    /// Returns the path of the script or module of the called function, if it has one.
+    #[inline]
+    #[must_use]
+    pub fn path(&self) -> Option<&'a Path> {
+        self.frame.source_path().map(Rc::as_ref)
+    }
+
+    /// Returns the position in the source code of the instruction the call is running.
+    ///
+    /// For the frames of the callers, this is the position of the call to the next frame.
+    #[inline]
+    #[must_use]
+    pub fn position(&self) -> Option<Position> {
+        self.frame.source_position()
+    }
+
+    /// Returns the number of arguments of the call.
+    ///
+    /// The arguments collected by a rest parameter are not counted once the parameter is
+    /// initialized.
+    #[inline]
+    #[must_use]
+    pub fn argument_count(&self) -> usize {
+        self.frame.argument_count as usize
+    }
+
+    /// Returns the called function, or `None` for scripts and modules.
+    ///
+    /// Returns `None` as well if the registers of the frame are not available, which is the case
+    /// for the callers of a resumed generator or async function.
+    #[must_use]
+    pub fn function(&self) -> Option<JsObject> {
+        self.stack?
+            .stack
+            .get(self.frame.function_index())?
+            .as_object()
+            .cloned()
+    }
+
+    /// Returns the `this` value the function was called with.
+    ///
+    /// This is the value passed by the caller, before it is replaced by the global object or
+    /// converted to an object for non-strict functions.
+    ///
+    /// Returns `None` if the registers of the frame are not available, which is the case for the
+    /// callers of a resumed generator or async function.
+    #[must_use]
+    pub fn this(&self) -> Option<JsValue> {
+        self.stack?.stack.get(self.frame.this_index()).cloned()
+    }
+
+    /// Returns the arguments of the call.
+    ///
+    /// Returns `None` if the registers of the frame are not available, which is the case for the
+    /// callers of a resumed generator or async function.
+    #[must_use]
+    pub fn arguments(&self) -> Option<&'a [JsValue]> {
+        self.stack?.stack.get(self.frame.arguments_range())
+    }
+}
+
+impl fmt::Debug for FrameInfo<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FrameInfo")
+            .field("function_name", self.function_name())
+            .field("path", &self.path())
+            .field("position", &self.position())
+            .finish_non_exhaustive()
+    }
+}
+
+impl Context {
+    /// Returns the function calls of the running code, from the innermost to the outermost call.
+    ///
+    /// Calls to native functions don't have a frame, so when called from a native function this
+    /// starts with the JavaScript function that called it. This allows hosts to log the calls
+    /// that reached them, check permissions based on the caller, or improve their error reports.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use boa_engine::{js_string, Context, JsValue, NativeFunction, Source};
+    ///
+    /// let context = &mut Context::default();
+    /// context
+    ///     .register_global_callable(
+    ///         js_string!("caller"),
+    ///         0,
+    ///         NativeFunction::from_fn_ptr(|_, _, context| {
+    ///             let caller = context.frames().next().expect("must have a caller");
+    ///             Ok(caller.function_name().clone().into())
+    ///         }),
+    ///     )
+    ///     .unwrap();
+    ///
+    /// let result = context
+    ///     .eval(Source::from_bytes("function outer() { return caller(); } outer()"))
+    ///     .unwrap();
+    /// assert_eq!(result, JsValue::from(js_string!("outer")));
+    /// ```
+    pub fn frames(&self) -> impl Iterator<Item = FrameInfo<'_>> {
+        let mut stack = Some(&self.vm.stack);
+        self.stack_trace().map(move |frame| {
+            let info = FrameInfo { frame, stack };
+            if frame.registers_already_pushed() {
+                stack = None;
+            }
+            info
+        })
+    }
+}
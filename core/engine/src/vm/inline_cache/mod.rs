@@ -1,16 +1,121 @@
-use std::cell::Cell;
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+};
 
-use boa_gc::GcRefCell;
+use boa_gc::{GcRefCell, WeakGc};
 use boa_macros::{Finalize, Trace};
+use rustc_hash::FxHashMap;
 
 use crate::{
-    object::shape::{slot::Slot, Shape, WeakShape},
-    JsString,
+    object::{
+        shape::{
+            slot::{Slot, SlotAttributes},
+            Shape, WeakShape,
+        },
+        ErasedVTableObject,
+    },
+    property::PropertyKey,
+    JsObject, JsString,
 };
 
 #[cfg(test)]
 mod tests;
 
+/// A counter of the changes to the layout of an object, shared with the inline caches of the
+/// properties that are looked up through it.
+///
+/// The inline cache of a property found in a prototype is keyed by the shape of the receiver,
+/// which doesn't change when a property is added to or removed from one of its prototypes. So the
+/// cache also keeps the count of the validity cell of each object visited by the lookup, and
+/// is only used while none of them changed.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ValidityCell(Rc<Cell<u64>>);
+
+impl ValidityCell {
+    /// Invalidates the inline caches that depend on the layout of the object of this cell.
+    pub(crate) fn invalidate(&self) {
+        self.0.set(self.0.get().wrapping_add(1));
+    }
+
+    fn count(&self) -> u64 {
+        self.0.get()
+    }
+}
+
+/// The prototype holding a cached property, and the objects its lookup depends on.
+#[derive(Clone, Debug, Trace, Finalize)]
+struct PrototypeLookup {
+    /// The prototype that has the property.
+    ///
+    /// A weak pointer is enough, since the holder is kept alive by the prototype chain of the
+    /// receiver while the lookup is valid.
+    holder: WeakGc<ErasedVTableObject>,
+
+    /// The validity cells of the objects visited by the lookup, with their count when the
+    /// property was cached.
+    #[unsafe_ignore_trace]
+    cells: Box<[(ValidityCell, u64)]>,
+}
+
+impl PrototypeLookup {
+    /// Finds the prototype of `receiver` that has the property `key` at `slot`.
+    ///
+    /// Returns `None` if the property is not in the shape of any of the prototypes, which happens
+    /// if the lookup went through an exotic object.
+    fn new(receiver: &JsObject, key: &PropertyKey, slot: Slot) -> Option<Self> {
+        let mut cells = Vec::new();
+
+        let mut object = receiver.borrow_mut();
+        // Adding a property to a unique shape doesn't change it, so it doesn't invalidate the
+        // caches keyed by it.
+        if object.shape().is_unique() {
+            let cell = object.properties_mut().validity_cell();
+            cells.push((cell.clone(), cell.count()));
+        }
+        let mut current = object.prototype();
+        drop(object);
+
+        while let Some(prototype) = current {
+            let mut object = prototype.borrow_mut();
+            let cell = object.properties_mut().validity_cell();
+            cells.push((cell.clone(), cell.count()));
+
+            if let Some(found) = object.shape().lookup(key) {
+                if found.index != slot.index
+                    || found.attributes
+                        != slot
+                            .attributes
+                            .difference(SlotAttributes::INLINE_CACHE_BITS)
+                {
+                    return None;
+                }
+                drop(object);
+                return Some(Self {
+                    holder: WeakGc::new(prototype.inner()),
+                    cells: cells.into_boxed_slice(),
+                });
+            }
+
+            current = object.prototype();
+        }
+
+        None
+    }
+
+    /// Returns the holder of the property if none of the objects of the lookup changed.
+    fn holder(&self) -> Option<JsObject> {
+        if self
+            .cells
+            .iter()
+            .any(|(cell, count)| cell.count() != *count)
+        {
+            return None;
+        }
+        self.holder.upgrade().map(JsObject::from)
+    }
+}
+
 /// An inline cache entry for a property access.
 #[derive(Clone, Debug, Trace, Finalize)]
 pub(crate) struct InlineCache {
@@ -23,6 +128,9 @@ pub(crate) struct InlineCache {
     /// The [`Slot`] of the property.
     #[unsafe_ignore_trace]
     pub(crate) slot: Cell<Slot>,
+
+    /// The lookup of the property, if it was found in a prototype.
+    prototype: GcRefCell<Option<PrototypeLookup>>,
 }
 
 impl InlineCache {
@@ -31,31 +139,110 @@ impl InlineCache {
             name,
             shape: GcRefCell::new(WeakShape::None),
             slot: Cell::new(Slot::new()),
+            prototype: GcRefCell::new(None),
         }
     }
 
-    pub(crate) fn set(&self, shape: &Shape, slot: Slot) {
-        *self.shape.borrow_mut() = shape.into();
+    /// Caches the property found at `slot` by a lookup on `object`.
+    pub(crate) fn set(&self, object: &JsObject, slot: Slot) {
+        let prototype = if slot.attributes.contains(SlotAttributes::PROTOTYPE) {
+            let Some(lookup) = PrototypeLookup::new(object, &self.name.clone().into(), slot) else {
+                return;
+            };
+            Some(lookup)
+        } else {
+            None
+        };
+
+        *self.shape.borrow_mut() = object.borrow().shape().into();
         self.slot.set(slot);
+        *self.prototype.borrow_mut() = prototype;
     }
 
     pub(crate) fn slot(&self) -> Slot {
         self.slot.get()
     }
 
-    /// Returns true, if the [`InlineCache`]'s shape matches with the given shape.
+    /// Returns the cached [`Slot`] if the [`InlineCache`]'s shape matches with the given shape,
+    /// along with the prototype holding the property if it's not an own property.
     ///
     /// Otherwise we reset the internal weak reference to [`WeakShape::None`],
     /// so it can be deallocated by the GC.
-    pub(crate) fn match_or_reset(&self, shape: &Shape) -> Option<(Shape, Slot)> {
+    pub(crate) fn match_or_reset(&self, shape: &Shape) -> Option<(Slot, Option<JsObject>)> {
         let mut old = self.shape.borrow_mut();
 
         let old_upgraded = old.upgrade();
         if old_upgraded.as_ref().map_or(0, Shape::to_addr_usize) == shape.to_addr_usize() {
-            return old_upgraded.map(|shape| (shape, self.slot()));
+            let slot = self.slot();
+            if !slot.attributes.contains(SlotAttributes::PROTOTYPE) {
+                return Some((slot, None));
+            }
+
+            let holder = self
+                .prototype
+                .borrow()
+                .as_ref()
+                .and_then(PrototypeLookup::holder);
+            if holder.is_some() {
+                return Some((slot, holder));
+            }
         }
 
         *old = WeakShape::None;
+        *self.prototype.borrow_mut() = None;
         None
     }
 }
+
+/// A cache of the own properties of a global object, shared by all the global name loads of a
+/// realm.
+///
+/// The inline cache of a global name load only helps once the load was executed, so this cache
+/// makes the first execution of each load, and the loads of newly compiled code, skip the full
+/// property lookup. The entries are bound to the count of the [`ValidityCell`] of the global
+/// object, and are cleared as soon as its layout changes.
+#[derive(Debug, Default)]
+pub(crate) struct GlobalPropertyCache {
+    inner: RefCell<GlobalPropertyCacheInner>,
+}
+
+#[derive(Debug, Default)]
+struct GlobalPropertyCacheInner {
+    /// The validity cell of the global object, with its count when the entries were cached.
+    generation: Option<(ValidityCell, u64)>,
+
+    /// The slots of the cached properties.
+    entries: FxHashMap<JsString, Slot>,
+}
+
+impl GlobalPropertyCache {
+    /// Gets the [`Slot`] of the own property `name` of the global object, if it's cached.
+    pub(crate) fn get(&self, name: &JsString) -> Option<Slot> {
+        let mut inner = self.inner.borrow_mut();
+        let (cell, count) = inner.generation.as_ref()?;
+        if cell.count() != *count {
+            inner.generation = None;
+            inner.entries.clear();
+            return None;
+        }
+        inner.entries.get(name).copied()
+    }
+
+    /// Caches the [`Slot`] of the own property `name` of `global`.
+    pub(crate) fn insert(&self, global: &JsObject, name: JsString, slot: Slot) {
+        debug_assert!(!slot.attributes.contains(SlotAttributes::PROTOTYPE));
+
+        let mut global = global.borrow_mut();
+        let cell = global.properties_mut().validity_cell();
+        let mut inner = self.inner.borrow_mut();
+        if inner
+            .generation
+            .as_ref()
+            .is_none_or(|(_, count)| cell.count() != *count)
+        {
+            inner.generation = Some((cell.clone(), cell.count()));
+            inner.entries.clear();
+        }
+        inner.entries.insert(name, slot);
+    }
+}
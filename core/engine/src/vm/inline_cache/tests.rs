@@ -1,5 +1,6 @@
 use boa_gc::Gc;
 use boa_parser::Source;
+use indoc::indoc;
 
 use crate::{
     builtins::{function::OrdinaryFunction, OrdinaryObject},
@@ -10,8 +11,9 @@ use crate::{
         ObjectInitializer,
     },
     property::{Attribute, PropertyDescriptor, PropertyKey},
+    run_test_actions,
     vm::CodeBlock,
-    Context, JsObject, JsResult, JsValue,
+    Context, JsNativeErrorKind, JsObject, JsResult, JsValue, TestAction,
 };
 
 #[test]
@@ -365,3 +367,140 @@ fn get_property_by_name_set_inline_cache_on_property_load() -> JsResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn get_property_by_name_set_inline_cache_on_prototype_chain_load() -> JsResult<()> {
+    let context = &mut Context::default();
+    let function = context.eval(Source::from_bytes("(function (o) { return o.test; })"))?;
+    let (function, code) = get_codeblock(&function).unwrap();
+
+    let prototype = ObjectInitializer::new(context)
+        .property(js_string!("test"), 0, Attribute::all())
+        .build();
+    let middle = JsObject::with_object_proto(context.intrinsics());
+    middle.set_prototype(Some(prototype.clone()));
+    let o = JsObject::with_object_proto(context.intrinsics());
+    o.set_prototype(Some(middle.clone()));
+    let o_shape = o.borrow().shape().clone();
+
+    function.call(&JsValue::undefined(), &[o.clone().into()], context)?;
+
+    assert_eq!(code.ic[0].shape.borrow().clone(), WeakShape::from(&o_shape));
+    assert!(code.ic[0].slot().in_prototype());
+
+    // Shadowing the property in the middle of the chain doesn't change the shape of `o`, but it
+    // must invalidate the cache.
+    middle.set(js_string!("test"), 1, true, context)?;
+    let result = function.call(&JsValue::undefined(), &[o.clone().into()], context)?;
+
+    assert_eq!(result, JsValue::new(1));
+    assert_eq!(code.ic[0].shape.borrow().clone(), WeakShape::from(&o_shape));
+
+    Ok(())
+}
+
+#[test]
+fn prototype_chain_mutations_invalidate_inline_caches() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            function get(o) { return o.y; }
+            function set(o, value) { o.y = value; }
+
+            var p = { x: 1, y: 2 };
+            var o = Object.create(p);
+            get(o);
+        "#}),
+        // Removing a property moves the storage of the next ones.
+        TestAction::assert_eq("delete p.x; get(o)", 2),
+        TestAction::assert_eq(
+            r#"Object.defineProperty(p, "y", { get() { return 3; } }); get(o)"#,
+            3,
+        ),
+        TestAction::assert_eq(
+            "var mid = Object.create(o); var deep = Object.create(mid); get(deep)",
+            3,
+        ),
+        TestAction::assert_eq("Object.setPrototypeOf(mid, { y: 4 }); get(deep)", 4),
+        TestAction::assert_eq(
+            r#"Object.defineProperty(mid, "y", { value: 5 }); get(deep)"#,
+            5,
+        ),
+        // Inherited setters are cached too.
+        TestAction::run(indoc! {r#"
+            var stored;
+            var q = { set y(value) { stored = value; } };
+            var r = Object.create(Object.create(q));
+            set(r, 1);
+            set(r, 2);
+        "#}),
+        TestAction::assert_eq("stored", 2),
+        TestAction::assert_eq(
+            indoc! {r#"
+                Object.defineProperty(q, "y", { set(value) { stored = -value; } });
+                set(r, 3);
+                stored
+            "#},
+            -3,
+        ),
+        // Adding a property to an object with a unique shape doesn't change its shape.
+        TestAction::run("Object.prototype.y = 6;"),
+        TestAction::assert_eq("get(Math)", 6),
+        TestAction::assert_eq("Math.y = 7; get(Math)", 7),
+        TestAction::run("delete Math.y; delete Object.prototype.y;"),
+    ]);
+}
+
+#[test]
+fn global_property_cache() -> JsResult<()> {
+    let context = &mut Context::default();
+    context.eval(Source::from_bytes(indoc! {r#"
+        var counter = 1;
+        function getCounter() { return counter; }
+        getCounter();
+    "#}))?;
+
+    let name = js_string!("counter");
+    let slot = context.realm().global_property_cache().get(&name);
+    assert_eq!(
+        slot.map(|slot| slot.index),
+        context
+            .global_object()
+            .borrow()
+            .shape()
+            .lookup(&name.clone().into())
+            .map(|slot| slot.index),
+    );
+    assert!(
+        slot.is_some(),
+        "global loads should fill the cache of the realm"
+    );
+
+    // Changing the layout of the global object clears the cache.
+    context.eval(Source::from_bytes("var other = 2;"))?;
+    assert!(context.realm().global_property_cache().get(&name).is_none());
+
+    Ok(())
+}
+
+#[test]
+fn global_property_cache_invalidation() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            globalThis.a = 1;
+            globalThis.b = 2;
+            function getA() { return a; }
+            function getB() { return b; }
+            getA();
+            getB();
+        "#}),
+        // A load that was never executed uses the cache.
+        TestAction::assert_eq("(function () { return b; })()", 2),
+        TestAction::assert_eq("delete globalThis.a; getB()", 2),
+        TestAction::assert_eq(
+            r#"Object.defineProperty(globalThis, "b", { get() { return 3; } }); getB()"#,
+            3,
+        ),
+        TestAction::assert_eq("(function () { return b; })()", 3),
+        TestAction::assert_native_error("getA()", JsNativeErrorKind::Reference, "a is not defined"),
+    ]);
+}
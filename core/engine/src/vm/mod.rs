@@ -12,9 +12,11 @@ use crate::{
     script::Script,
     Context, JsError, JsNativeError, JsObject, JsResult, JsString, JsValue, Module,
 };
+use boa_ast::Position;
 use boa_gc::{custom_trace, Finalize, Gc, Trace};
 use boa_profiler::Profiler;
-use std::{future::Future, ops::ControlFlow, pin::Pin, task};
+use rustc_hash::FxHashSet;
+use std::{future::Future, ops::ControlFlow, path::Path, pin::Pin, rc::Rc, task};
 
 #[cfg(feature = "trace")]
 use crate::sys::time::Instant;
@@ -28,23 +30,33 @@ pub(crate) use opcode::{Instruction, InstructionIterator, Opcode};
 pub(crate) use {
     call_frame::CallFrameFlags,
     code_block::{
-        create_function_object, create_function_object_fast, CodeBlockFlags, Constant, Handler,
+        create_function_object, create_function_object_fast, CodeBlockCounter, CodeBlockFlags,
+        Constant, Handler,
     },
     completion_record::CompletionRecord,
-    inline_cache::InlineCache,
+    continuation::{run_suspendable, Suspension},
+    inline_cache::{GlobalPropertyCache, InlineCache, ValidityCell},
+    source_positions::SourcePositionTable,
+    stack_trace::StackTrace,
 };
 
 pub use runtime_limits::RuntimeLimits;
 pub use {
     call_frame::{CallFrame, GeneratorResumeKind},
     code_block::CodeBlock,
+    continuation::{Continuation, SuspendableResult},
+    frame_info::FrameInfo,
 };
 
 mod call_frame;
 mod code_block;
 mod completion_record;
+mod continuation;
+mod frame_info;
 mod inline_cache;
 mod runtime_limits;
+mod source_positions;
+mod stack_trace;
 
 pub(crate) mod opcode;
 
@@ -88,6 +100,22 @@ pub struct Vm {
     /// because we don't push a frame for them.
     pub(crate) native_active_function: Option<JsObject>,
 
+    /// The native functions that can be called while evaluating an expression with
+    /// [`Context::eval_pure`], or [`None`] if no pure evaluation is running.
+    pub(crate) pure_evaluation: Option<FxHashSet<JsObject>>,
+
+    /// The number of instructions executed by the virtual machine.
+    pub(crate) executed_instructions: u64,
+
+    /// Empty buffers for the arguments of native calls, reused across calls to avoid allocating
+    /// a new buffer on every call.
+    ///
+    /// Only native calls need them, since the arguments of other calls stay on the [`Stack`].
+    argument_buffers: Vec<Vec<JsValue>>,
+
+    /// The state of the suspension of a script by a native function, see [`Context::suspend`].
+    pub(crate) suspension: Suspension,
+
     /// realm holds both the global object and the environment
     pub(crate) realm: Realm,
 
@@ -100,6 +128,11 @@ pub struct Vm {
 /// The stack is persistent across frames.
 /// It's addressing is relative to the frame pointer.
 ///
+/// The registers of a call are pushed right after its arguments, which the callee reads in place
+/// from the stack of the caller. The stack only allocates when a call goes deeper than any
+/// previous one, since truncating it keeps its capacity. The environments of a call are not part
+/// of the stack, they are still cloned from the function on every call.
+///
 /// The stack stores the following elements:
 /// - The function prologue
 ///   - The `this` value of the function
@@ -161,6 +194,11 @@ impl Stack {
         }
     }
 
+    /// Returns the number of values on the stack.
+    pub(crate) fn len(&self) -> usize {
+        self.stack.len()
+    }
+
     /// Truncate the stack to the given frame.
     pub(crate) fn truncate_to_frame(&mut self, frame: &CallFrame) {
         self.stack.truncate(frame.frame_pointer());
@@ -408,6 +446,16 @@ unsafe impl Trace for ActiveRunnable {
     });
 }
 
+impl ActiveRunnable {
+    /// Returns the path of the script or module, if it has one.
+    pub(crate) fn path(&self) -> Option<&Rc<Path>> {
+        match self {
+            Self::Script(script) => script.shared_path(),
+            Self::Module(module) => module.shared_path(),
+        }
+    }
+}
+
 impl Vm {
     /// Creates a new virtual machine.
     pub(crate) fn new(realm: Realm) -> Self {
@@ -426,6 +474,10 @@ impl Vm {
             pending_exception: None,
             runtime_limits: RuntimeLimits::default(),
             native_active_function: None,
+            pure_evaluation: None,
+            executed_instructions: 0,
+            argument_buffers: Vec::new(),
+            suspension: Suspension::default(),
             realm,
             #[cfg(feature = "trace")]
             trace: false,
@@ -457,6 +509,41 @@ impl Vm {
         &mut self.frame
     }
 
+    /// Gets the source position of the instruction being executed in the current frame, if it is
+    /// known.
+    pub(crate) fn source_position(&self) -> Option<Position> {
+        self.frame.source_position()
+    }
+
+    /// Returns the path of the script or module of the current frame, if it has one.
+    pub(crate) fn source_path(&self) -> Option<Rc<Path>> {
+        self.frame.source_path().cloned()
+    }
+
+    /// Pops the last `argument_count` values of the stack into a reused buffer, according to
+    /// the calling convention.
+    ///
+    /// The buffer should be given back with [`Vm::recycle_arguments`] once the call returns.
+    pub(crate) fn take_arguments(&mut self, argument_count: usize) -> Vec<JsValue> {
+        let index = self.stack.stack.len() - argument_count;
+        let mut buffer = self.argument_buffers.pop().unwrap_or_default();
+        buffer.extend(self.stack.stack.drain(index..));
+        buffer
+    }
+
+    /// Keeps the buffer of the arguments of a call to reuse it in later calls.
+    ///
+    /// Only a few buffers are kept, for nested native calls, and large buffers are freed.
+    pub(crate) fn recycle_arguments(&mut self, mut buffer: Vec<JsValue>) {
+        const MAX_BUFFERS: usize = 16;
+        const MAX_BUFFER_CAPACITY: usize = 64;
+
+        if self.argument_buffers.len() < MAX_BUFFERS && buffer.capacity() <= MAX_BUFFER_CAPACITY {
+            buffer.clear();
+            self.argument_buffers.push(buffer);
+        }
+    }
+
     pub(crate) fn push_frame(&mut self, mut frame: CallFrame) {
         let current_stack_length = self.stack.stack.len();
         frame.set_register_pointer(current_stack_length as u32);
@@ -657,6 +744,16 @@ impl Context {
             self.instructions_remaining -= 1;
         }
 
+        self.vm.executed_instructions = self.vm.executed_instructions.wrapping_add(1);
+
+        if self.vm.pure_evaluation.is_some() && opcode.has_side_effects() {
+            return self.handle_error(
+                JsNativeError::runtime_limit()
+                    .with_message("side effects are not allowed in a pure evaluation")
+                    .into(),
+            );
+        }
+
         #[cfg(feature = "trace")]
         if self.vm.trace || self.vm.frame().code_block.traceable() {
             self.trace_execute_instruction(f, opcode)
@@ -693,6 +790,10 @@ impl Context {
             return ControlFlow::Break(CompletionRecord::Throw(err));
         }
 
+        let err = err
+            .inject_position(self.vm.source_position(), self.vm.source_path())
+            .inject_stack_trace(self);
+
         // Note: -1 because we increment after fetching the opcode.
         let pc = self.vm.frame().pc.saturating_sub(1);
         if self.vm.handle_exception_at(pc) {
@@ -861,6 +962,35 @@ impl Context {
 
         Ok(())
     }
+
+    /// Checks if the native `function` can be called, which is only restricted while evaluating
+    /// an expression with [`Context::eval_pure`].
+    pub(crate) fn check_pure_call(&self, function: &JsObject) -> JsResult<()> {
+        match &self.vm.pure_evaluation {
+            Some(allowed) if !allowed.contains(function) => Err(JsNativeError::runtime_limit()
+                .with_message(
+                    "calling a function with side effects is not allowed in a pure evaluation",
+                )
+                .into()),
+            _ => Ok(()),
+        }
+    }
+
+    /// Checks if a built-in can create an object with `constructor` and write to it, which is only
+    /// restricted while evaluating an expression with [`Context::eval_pure`].
+    ///
+    /// Only the allowed constructors are known to create new objects. Other constructors can
+    /// return an object that already exists, which the built-in would then mutate.
+    pub(crate) fn check_pure_construct(&self, constructor: &JsObject) -> JsResult<()> {
+        match &self.vm.pure_evaluation {
+            Some(allowed) if !allowed.contains(constructor) => Err(JsNativeError::runtime_limit()
+                .with_message(
+                    "creating objects with this constructor is not allowed in a pure evaluation",
+                )
+                .into()),
+            _ => Ok(()),
+        }
+    }
 }
 
 /// Yields once to the executor.
@@ -48,6 +48,12 @@ impl Await {
             .map(JsValue::from)
             .unwrap_or_default();
 
+        // The callers of the function are gone once it is resumed, so they are captured
+        // to be included in the stack traces of the errors created after resuming.
+        if context.error_stack_traces() && context.vm.frame.async_trace.is_none() {
+            context.vm.frame.async_trace = Some(context.vm.async_stack_trace());
+        }
+
         let gen = GeneratorContext::from_current(context, None);
 
         let captures = Gc::new(Cell::new(Some(gen)));
@@ -62,7 +68,7 @@ impl Await {
                     // b. Suspend prevContext.
                     // c. Push asyncContext onto the execution context stack; asyncContext is now the running execution context.
                     // d. Resume the suspended evaluation of asyncContext using NormalCompletion(value) as the result of the operation that suspended it.
-                    let mut gen = captures.take().expect("should only run once");
+                    let mut gen = boa_gc::take_traced(captures).expect("should only run once");
 
                     // NOTE: We need to get the object before resuming, since it could clear the stack.
                     let async_generator = gen.async_generator_object();
@@ -103,7 +109,7 @@ impl Await {
                     // d. Resume the suspended evaluation of asyncContext using ThrowCompletion(reason) as the result of the operation that suspended it.
                     // e. Assert: When we reach this step, asyncContext has already been removed from the execution context stack and prevContext is the currently running execution context.
                     // f. Return undefined.
-                    let mut gen = captures.take().expect("should only run once");
+                    let mut gen = boa_gc::take_traced(captures).expect("should only run once");
 
                     // NOTE: We need to get the object before resuming, since it could clear the stack.
                     let async_generator = gen.async_generator_object();
@@ -1,6 +1,6 @@
 use crate::{
     vm::opcode::{Operation, VaryingOperand},
-    Context, JsResult,
+    Context, JsResult, JsValue, JsVariant,
 };
 
 macro_rules! implement_bin_ops {
@@ -34,6 +34,47 @@ macro_rules! implement_bin_ops {
     };
 }
 
+macro_rules! implement_int32_bin_ops {
+    ($name:ident, $op:ident, $fast:expr, $doc_string:literal) => {
+        #[doc= concat!("`", stringify!($name), "` implements the OpCode Operation for `Opcode::", stringify!($name), "`\n")]
+        #[doc= "\n"]
+        #[doc="Operation:\n"]
+        #[doc= concat!(" - ", $doc_string)]
+        #[derive(Debug, Clone, Copy)]
+        pub(crate) struct $name;
+
+        impl $name {
+            #[inline(always)]
+            pub(crate) fn operation(
+                (dst, lhs, rhs): (VaryingOperand, VaryingOperand, i32),
+                context: &mut Context,
+            ) -> JsResult<()> {
+                let lhs = context.vm.get_register(lhs.into());
+                let fast: fn(i32, i32) -> Option<i32> = $fast;
+                let result = match lhs.variant() {
+                    JsVariant::Integer32(lhs) => fast(lhs, rhs),
+                    _ => None,
+                };
+                let value = if let Some(result) = result {
+                    JsValue::new(result)
+                } else {
+                    // Fall back to the generic operator for any other operand, or if the result
+                    // is not an `i32`.
+                    lhs.clone().$op(&JsValue::new(rhs), context)?
+                };
+                context.vm.set_register(dst.into(), value);
+                Ok(())
+            }
+        }
+
+        impl Operation for $name {
+            const NAME: &'static str = stringify!($name);
+            const INSTRUCTION: &'static str = stringify!("INST - " + $name);
+            const COST: u8 = 1;
+        }
+    };
+}
+
 implement_bin_ops!(Add, add, "Binary `+` operator.");
 implement_bin_ops!(Sub, sub, "Binary `-` operator.");
 implement_bin_ops!(Mul, mul, "Binary `*` operator.");
@@ -52,3 +93,22 @@ implement_bin_ops!(GreaterThanOrEq, ge, "Binary `>=` operator.");
 implement_bin_ops!(LessThan, lt, "Binary `<` operator.");
 implement_bin_ops!(LessThanOrEq, le, "Binary `<=` operator.");
 implement_bin_ops!(InstanceOf, instance_of, "Binary `instanceof` operator.");
+implement_int32_bin_ops!(
+    AddInt32,
+    add,
+    i32::checked_add,
+    "Binary `+` operator with an `i32` right operand."
+);
+implement_int32_bin_ops!(
+    SubInt32,
+    sub,
+    i32::checked_sub,
+    "Binary `-` operator with an `i32` right operand."
+);
+// A zero product may be `-0`, which is not an `i32`.
+implement_int32_bin_ops!(
+    MulInt32,
+    mul,
+    |lhs, rhs| lhs.checked_mul(rhs).filter(|value| *value != 0),
+    "Binary `*` operator with an `i32` right operand."
+);
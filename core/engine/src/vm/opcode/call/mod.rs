@@ -3,10 +3,58 @@ use crate::{
     builtins::{promise::PromiseCapability, Promise},
     error::JsNativeError,
     module::{ModuleKind, Referrer},
-    object::FunctionObjectBuilder,
-    vm::opcode::Operation,
+    object::{internal_methods::CallValue, FunctionObjectBuilder},
+    vm::{opcode::Operation, CallFrame, CompletionRecord},
     Context, JsObject, JsResult, JsValue, NativeFunction,
 };
+use std::ops::ControlFlow;
+
+/// Creates the error thrown when the callee of the current call instruction is not callable,
+/// naming the callee expression if its source text was recorded.
+pub(super) fn not_callable_error(context: &Context) -> JsNativeError {
+    let frame = context.vm.frame();
+    match frame.code_block().find_callee_text(frame.pc) {
+        Some(text) => JsNativeError::typ().with_message(format!(
+            "{} is not a function",
+            text.to_std_string_escaped()
+        )),
+        None => JsNativeError::typ().with_message("not a callable function"),
+    }
+}
+
+/// Creates the error thrown when the callee of the current `new` instruction is not a
+/// constructor, naming the callee expression if its source text was recorded.
+pub(super) fn not_constructor_error(context: &Context) -> JsNativeError {
+    let frame = context.vm.frame();
+    match frame.code_block().find_callee_text(frame.pc) {
+        Some(text) => JsNativeError::typ().with_message(format!(
+            "{} is not a constructor",
+            text.to_std_string_escaped()
+        )),
+        None => JsNativeError::typ().with_message("not a constructor"),
+    }
+}
+
+/// Resolves the call of a call instruction, stopping the execution if the called function is a
+/// native function that suspended the script with [`Context::suspend`].
+fn resolve_suspendable_call(
+    call: CallValue,
+    argument_count: usize,
+    context: &mut Context,
+) -> ControlFlow<CompletionRecord> {
+    context.vm.suspension.call =
+        Some(context.vm.stack.len() - argument_count - CallFrame::FUNCTION_PROLOGUE as usize);
+    let result = call.resolve(context);
+    context.vm.suspension.call = None;
+
+    if let Err(err) = result {
+        return context.handle_error(err);
+    }
+    if context.vm.suspension.requested {
+        return ControlFlow::Break(CompletionRecord::Normal(JsValue::undefined()));
+    }
+    ControlFlow::Continue(())
+}
 
 /// `CallEval` implements the Opcode Operation for `Opcode::CallEval`
 ///
@@ -26,10 +74,8 @@ impl CallEval {
             .stack
             .calling_convention_get_function(argument_count.into());
 
-        let Some(object) = func.as_object() else {
-            return Err(JsNativeError::typ()
-                .with_message("not a callable function")
-                .into());
+        let Some(object) = func.as_object().filter(|object| object.is_callable()) else {
+            return Err(not_callable_error(context).into());
         };
 
         // Taken from `13.3.6.1 Runtime Semantics: Evaluation`
@@ -110,10 +156,12 @@ impl CallEvalSpread {
 
         let func = context.vm.stack.calling_convention_get_function(0);
 
-        let Some(object) = func.as_object().cloned() else {
-            return Err(JsNativeError::typ()
-                .with_message("not a callable function")
-                .into());
+        let Some(object) = func
+            .as_object()
+            .filter(|object| object.is_callable())
+            .cloned()
+        else {
+            return Err(not_callable_error(context).into());
         };
         // Taken from `13.3.6.1 Runtime Semantics: Evaluation`
         //            `CallExpression : CoverCallExpressionAndAsyncArrowHead`
@@ -177,21 +225,22 @@ pub(crate) struct Call;
 
 impl Call {
     #[inline(always)]
-    pub(super) fn operation(argument_count: VaryingOperand, context: &mut Context) -> JsResult<()> {
+    pub(super) fn operation(
+        argument_count: VaryingOperand,
+        context: &mut Context,
+    ) -> ControlFlow<CompletionRecord> {
         let func = context
             .vm
             .stack
             .calling_convention_get_function(argument_count.into());
 
-        let Some(object) = func.as_object() else {
-            return Err(JsNativeError::typ()
-                .with_message("not a callable function")
-                .into());
+        let Some(object) = func.as_object().filter(|object| object.is_callable()) else {
+            let error = not_callable_error(context);
+            return context.handle_error(error.into());
         };
 
-        object.__call__(argument_count.into()).resolve(context)?;
-
-        Ok(())
+        let call = object.__call__(argument_count.into());
+        resolve_suspendable_call(call, argument_count.into(), context)
     }
 }
 
@@ -206,7 +255,7 @@ pub(crate) struct CallSpread;
 
 impl CallSpread {
     #[inline(always)]
-    pub(super) fn operation((): (), context: &mut Context) -> JsResult<()> {
+    pub(super) fn operation((): (), context: &mut Context) -> ControlFlow<CompletionRecord> {
         // Get the arguments that are stored as an array object on the stack.
         let arguments_array = context.vm.stack.pop();
         let arguments_array_object = arguments_array
@@ -229,14 +278,13 @@ impl CallSpread {
             .stack
             .calling_convention_get_function(argument_count);
 
-        let Some(object) = func.as_object() else {
-            return Err(JsNativeError::typ()
-                .with_message("not a callable function")
-                .into());
+        let Some(object) = func.as_object().filter(|object| object.is_callable()) else {
+            let error = not_callable_error(context);
+            return context.handle_error(error.into());
         };
 
-        object.__call__(argument_count).resolve(context)?;
-        Ok(())
+        let call = object.__call__(argument_count);
+        resolve_suspendable_call(call, argument_count, context)
     }
 }
 
@@ -286,7 +334,7 @@ impl ImportCall {
                 cap.reject().call(&JsValue::undefined(), &[err], context)?;
             }
             // 8. Perform HostLoadImportedModule(referrer, specifierString, empty, promiseCapability).
-            Ok(specifier) => context.module_loader().load_imported_module(
+            Ok(specifier) => context.load_imported_module(
                 referrer.clone(),
                 specifier.clone(),
                 Box::new(move |completion, context| {
@@ -447,7 +495,6 @@ impl ImportCall {
 
                     // 9. Return unused.
                 }),
-                context,
             ),
         }
 
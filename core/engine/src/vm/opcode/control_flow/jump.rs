@@ -1,6 +1,6 @@
 use crate::{
     vm::opcode::{Operation, VaryingOperand},
-    Context,
+    Context, JsValue,
 };
 use thin_vec::ThinVec;
 
@@ -116,6 +116,30 @@ impl Operation for JumpIfNullOrUndefined {
     const COST: u8 = 1;
 }
 
+/// `JumpIfNullOrUndefinedSetUndefined` implements the Opcode Operation for `Opcode::JumpIfNullOrUndefinedSetUndefined`
+///
+/// Operation:
+///  - Conditional jump to address, setting the value to undefined.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct JumpIfNullOrUndefinedSetUndefined;
+
+impl JumpIfNullOrUndefinedSetUndefined {
+    #[inline(always)]
+    pub(crate) fn operation((address, value): (u32, VaryingOperand), context: &mut Context) {
+        let register = value.into();
+        if context.vm.get_register(register).is_null_or_undefined() {
+            context.vm.set_register(register, JsValue::undefined());
+            context.vm.frame_mut().pc = address;
+        }
+    }
+}
+
+impl Operation for JumpIfNullOrUndefinedSetUndefined {
+    const NAME: &'static str = "JumpIfNullOrUndefinedSetUndefined";
+    const INSTRUCTION: &'static str = "INST - JumpIfNullOrUndefinedSetUndefined";
+    const COST: u8 = 1;
+}
+
 /// `JumpTable` implements the Opcode Operation for `Opcode::JumpTable`
 ///
 /// Operation:
@@ -0,0 +1,229 @@
+use boa_macros::js_str;
+
+use crate::{
+    builtins::function::{set_function_name, OrdinaryFunction},
+    js_string,
+    native_function::NativeFunction,
+    object::{FunctionObjectBuilder, JsObject, PrivateElement, PrivateName},
+    property::{PropertyDescriptor, PropertyKey},
+    vm::opcode::{Operation, VaryingOperand},
+    Context, JsArgs, JsNativeError, JsResult, JsValue,
+};
+
+/// Creates the getter and setter of an auto-accessor named `key`, which read and write the
+/// private `storage` of the accessor.
+///
+/// More information:
+///  - [Decorators proposal][spec]
+///
+/// [spec]: https://github.com/tc39/proposal-decorators
+fn make_auto_accessor(
+    storage: PrivateName,
+    key: &PropertyKey,
+    context: &mut Context,
+) -> (JsObject, JsObject) {
+    // MakeAutoAccessorGetter ( homeObject, name, privateStorageName )
+    let getter = FunctionObjectBuilder::new(
+        context.realm(),
+        NativeFunction::from_copy_closure_with_captures(
+            |this, _, storage, context| {
+                // 1. Let o be the this value.
+                // 2. If o is not an Object, throw a TypeError exception.
+                let object = this.as_object().ok_or_else(|| {
+                    JsNativeError::typ()
+                        .with_message("auto-accessor getter called on a non-object value")
+                })?;
+
+                // 3. Return ? PrivateGet(o, privateStorageName).
+                object.private_get(storage, context)
+            },
+            storage.clone(),
+        ),
+    )
+    .name(js_string!())
+    .length(0)
+    .build();
+
+    // MakeAutoAccessorSetter ( homeObject, name, privateStorageName )
+    let setter = FunctionObjectBuilder::new(
+        context.realm(),
+        NativeFunction::from_copy_closure_with_captures(
+            |this, args, storage, context| {
+                // 1. Let o be the this value.
+                // 2. If o is not an Object, throw a TypeError exception.
+                let object = this.as_object().ok_or_else(|| {
+                    JsNativeError::typ()
+                        .with_message("auto-accessor setter called on a non-object value")
+                })?;
+
+                // 3. Perform ? PrivateSet(o, privateStorageName, value).
+                object.private_set(storage, args.get_or_undefined(0).clone(), context)?;
+
+                // 4. Return undefined.
+                Ok(JsValue::undefined())
+            },
+            storage,
+        ),
+    )
+    .name(js_string!())
+    .length(1)
+    .build();
+
+    set_function_name(&getter, key, Some(js_str!("get")), context);
+    set_function_name(&setter, key, Some(js_str!("set")), context);
+
+    (getter.into(), setter.into())
+}
+
+/// Creates an auto-accessor named `key` and defines it on `object`.
+fn define_auto_accessor(
+    class: &JsValue,
+    object: &JsValue,
+    storage_index: VaryingOperand,
+    key: PropertyKey,
+    context: &mut Context,
+) -> JsResult<()> {
+    let class = class.as_object().expect("class must be object");
+    let object = object.as_object().expect("home object must be object");
+    let storage = context
+        .vm
+        .frame()
+        .code_block()
+        .constant_string(storage_index.into());
+    let storage = class.private_name(storage);
+
+    let (getter, setter) = make_auto_accessor(storage, &key, context);
+
+    object.define_property_or_throw(
+        key,
+        PropertyDescriptor::builder()
+            .get(getter)
+            .set(setter)
+            .enumerable(false)
+            .configurable(true)
+            .build(),
+        context,
+    )?;
+    Ok(())
+}
+
+/// `DefineClassAutoAccessorByName` implements the Opcode Operation for `Opcode::DefineClassAutoAccessorByName`
+///
+/// Operation:
+///  - Defines a class auto-accessor by name.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct DefineClassAutoAccessorByName;
+
+impl DefineClassAutoAccessorByName {
+    #[inline(always)]
+    pub(crate) fn operation(
+        (class, object, storage_index, index): (
+            VaryingOperand,
+            VaryingOperand,
+            VaryingOperand,
+            VaryingOperand,
+        ),
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let class = context.vm.get_register(class.into()).clone();
+        let object = context.vm.get_register(object.into()).clone();
+        let key = context
+            .vm
+            .frame()
+            .code_block()
+            .constant_string(index.into())
+            .into();
+        define_auto_accessor(&class, &object, storage_index, key, context)
+    }
+}
+
+impl Operation for DefineClassAutoAccessorByName {
+    const NAME: &'static str = "DefineClassAutoAccessorByName";
+    const INSTRUCTION: &'static str = "INST - DefineClassAutoAccessorByName";
+    const COST: u8 = 6;
+}
+
+/// `DefineClassAutoAccessorByValue` implements the Opcode Operation for `Opcode::DefineClassAutoAccessorByValue`
+///
+/// Operation:
+///  - Defines a class auto-accessor by value.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct DefineClassAutoAccessorByValue;
+
+impl DefineClassAutoAccessorByValue {
+    #[inline(always)]
+    pub(crate) fn operation(
+        (class, object, storage_index, key): (
+            VaryingOperand,
+            VaryingOperand,
+            VaryingOperand,
+            VaryingOperand,
+        ),
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let class = context.vm.get_register(class.into()).clone();
+        let object = context.vm.get_register(object.into()).clone();
+        let key = context.vm.get_register(key.into()).clone();
+        let key = key
+            .to_property_key(context)
+            .expect("property key must already be valid");
+        define_auto_accessor(&class, &object, storage_index, key, context)
+    }
+}
+
+impl Operation for DefineClassAutoAccessorByValue {
+    const NAME: &'static str = "DefineClassAutoAccessorByValue";
+    const INSTRUCTION: &'static str = "INST - DefineClassAutoAccessorByValue";
+    const COST: u8 = 6;
+}
+
+/// `DefineClassPrivateAutoAccessor` implements the Opcode Operation for `Opcode::DefineClassPrivateAutoAccessor`
+///
+/// Operation:
+///  - Defines a private class auto-accessor.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct DefineClassPrivateAutoAccessor;
+
+impl DefineClassPrivateAutoAccessor {
+    #[inline(always)]
+    pub(crate) fn operation(
+        (class, storage_index, index, is_static): (
+            VaryingOperand,
+            VaryingOperand,
+            VaryingOperand,
+            VaryingOperand,
+        ),
+        context: &mut Context,
+    ) {
+        let class = context.vm.get_register(class.into()).clone();
+        let class = class.as_object().expect("class must be object");
+        let code_block = context.vm.frame().code_block();
+        let storage = code_block.constant_string(storage_index.into());
+        let name = code_block.constant_string(index.into());
+        let is_static = u32::from(is_static) != 0;
+
+        let key = js_string!(js_str!("#"), &name).into();
+        let (getter, setter) = make_auto_accessor(class.private_name(storage), &key, context);
+        let accessor = PrivateElement::Accessor {
+            getter: Some(getter),
+            setter: Some(setter),
+        };
+
+        if is_static {
+            class
+                .borrow_mut()
+                .append_private_element(class.private_name(name), accessor);
+        } else {
+            class
+                .downcast_mut::<OrdinaryFunction>()
+                .expect("class must be function object")
+                .push_private_method(class.private_name(name), accessor);
+        }
+    }
+}
+
+impl Operation for DefineClassPrivateAutoAccessor {
+    const NAME: &'static str = "DefineClassPrivateAutoAccessor";
+    const INSTRUCTION: &'static str = "INST - DefineClassPrivateAutoAccessor";
+    const COST: u8 = 6;
+}
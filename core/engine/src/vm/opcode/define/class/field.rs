@@ -0,0 +1,64 @@
+use crate::{
+    vm::opcode::{Operation, VaryingOperand},
+    Context, JsResult,
+};
+
+/// `DefineClassStaticFieldByName` implements the Opcode Operation for `Opcode::DefineClassStaticFieldByName`
+///
+/// Operation:
+///  - Defines a static public class field by name.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct DefineClassStaticFieldByName;
+
+impl DefineClassStaticFieldByName {
+    #[inline(always)]
+    pub(crate) fn operation(
+        (class, value, index): (VaryingOperand, VaryingOperand, VaryingOperand),
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let class = context.vm.get_register(class.into()).clone();
+        let value = context.vm.get_register(value.into()).clone();
+        let class = class.as_object().expect("class must be object");
+        let key = context
+            .vm
+            .frame()
+            .code_block()
+            .constant_string(index.into())
+            .into();
+        class.define_public_field(key, value, context)
+    }
+}
+
+impl Operation for DefineClassStaticFieldByName {
+    const NAME: &'static str = "DefineClassStaticFieldByName";
+    const INSTRUCTION: &'static str = "INST - DefineClassStaticFieldByName";
+    const COST: u8 = 4;
+}
+
+/// `DefineClassStaticFieldByValue` implements the Opcode Operation for `Opcode::DefineClassStaticFieldByValue`
+///
+/// Operation:
+///  - Defines a static public class field by value.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct DefineClassStaticFieldByValue;
+
+impl DefineClassStaticFieldByValue {
+    #[inline(always)]
+    pub(crate) fn operation(
+        (value, key, class): (VaryingOperand, VaryingOperand, VaryingOperand),
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let value = context.vm.get_register(value.into()).clone();
+        let key = context.vm.get_register(key.into()).clone();
+        let class = context.vm.get_register(class.into()).clone();
+        let class = class.as_object().expect("class must be object");
+        let key = key.to_property_key(context)?;
+        class.define_public_field(key, value, context)
+    }
+}
+
+impl Operation for DefineClassStaticFieldByValue {
+    const NAME: &'static str = "DefineClassStaticFieldByValue";
+    const INSTRUCTION: &'static str = "INST - DefineClassStaticFieldByValue";
+    const COST: u8 = 4;
+}
@@ -1,7 +1,11 @@
+pub(crate) mod auto_accessor;
+pub(crate) mod field;
 pub(crate) mod getter;
 pub(crate) mod method;
 pub(crate) mod setter;
 
+pub(crate) use auto_accessor::*;
+pub(crate) use field::*;
 pub(crate) use getter::*;
 pub(crate) use method::*;
 pub(crate) use setter::*;
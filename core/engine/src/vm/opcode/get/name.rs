@@ -37,6 +37,91 @@ impl Operation for GetName {
     const COST: u8 = 4;
 }
 
+/// `GetBinding` implements the Opcode Operation for `Opcode::GetBinding`
+///
+/// Operation:
+///  - Get the value of the binding at `slot` in the declarative environment at `environment`.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct GetBinding;
+
+impl GetBinding {
+    #[inline(always)]
+    pub(crate) fn operation(
+        (value, environment, slot): (VaryingOperand, VaryingOperand, VaryingOperand),
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let (environment, slot) = (u32::from(environment), u32::from(slot));
+
+        #[cfg(debug_assertions)]
+        context.verify_static_binding(environment, slot);
+
+        let result = context
+            .environment_expect(environment)
+            .as_declarative()
+            .expect("statically resolved bindings must be in a declarative environment")
+            .get(slot)
+            .ok_or_else(|| {
+                let name = context
+                    .vm
+                    .frame()
+                    .code_block()
+                    .slot_binding(environment, slot)
+                    .map(|locator| locator.name().to_std_string_escaped())
+                    .unwrap_or_default();
+                JsNativeError::reference().with_message(format!("{name} is not defined"))
+            })?;
+        context.vm.set_register(value.into(), result);
+        Ok(())
+    }
+}
+
+impl Operation for GetBinding {
+    const NAME: &'static str = "GetBinding";
+    const INSTRUCTION: &'static str = "INST - GetBinding";
+    const COST: u8 = 2;
+}
+
+/// `GetBindingUnchecked` implements the Opcode Operation for `Opcode::GetBindingUnchecked`
+///
+/// Operation:
+///  - Get the value of the binding at `slot` in the declarative environment at `environment`,
+///    without checking that it is initialized.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct GetBindingUnchecked;
+
+impl GetBindingUnchecked {
+    #[inline(always)]
+    pub(crate) fn operation(
+        (value, environment, slot): (VaryingOperand, VaryingOperand, VaryingOperand),
+        context: &mut Context,
+    ) {
+        let (environment, slot) = (u32::from(environment), u32::from(slot));
+
+        #[cfg(debug_assertions)]
+        context.verify_static_binding(environment, slot);
+
+        let result = context
+            .environment_expect(environment)
+            .as_declarative()
+            .expect("statically resolved bindings must be in a declarative environment")
+            .get(slot);
+        debug_assert!(
+            result.is_some(),
+            "binding read without a temporal dead zone check is not initialized, this is a \
+             compiler bug"
+        );
+        context
+            .vm
+            .set_register(value.into(), result.unwrap_or_default());
+    }
+}
+
+impl Operation for GetBindingUnchecked {
+    const NAME: &'static str = "GetBindingUnchecked";
+    const INSTRUCTION: &'static str = "INST - GetBindingUnchecked";
+    const COST: u8 = 1;
+}
+
 /// `GetNameGlobal` implements the Opcode Operation for `Opcode::GetNameGlobal`
 ///
 /// Operation:
@@ -60,11 +145,17 @@ impl GetNameGlobal {
             let ic = &context.vm.frame().code_block().ic[usize::from(ic_index)];
 
             let object_borrowed = object.borrow();
-            if let Some((shape, slot)) = ic.match_or_reset(object_borrowed.shape()) {
-                let mut result = if slot.attributes.contains(SlotAttributes::PROTOTYPE) {
-                    let prototype = shape.prototype().expect("prototype should have value");
-                    let prototype = prototype.borrow();
-                    prototype.properties().storage[slot.index as usize].clone()
+            let cached = ic.match_or_reset(object_borrowed.shape()).or_else(|| {
+                // The properties of the global object are also cached for the whole realm, so
+                // loads that were never executed don't need a full lookup.
+                let slot = context.realm().global_property_cache().get(&ic.name)?;
+                ic.set(&object, slot);
+                Some((slot, None))
+            });
+            if let Some((slot, holder)) = cached {
+                let mut result = if let Some(holder) = holder {
+                    let holder = holder.borrow();
+                    holder.properties().storage[slot.index as usize].clone()
                 } else {
                     object_borrowed.properties().storage[slot.index as usize].clone()
                 };
@@ -97,9 +188,13 @@ impl GetNameGlobal {
             let slot = *context.slot();
             if slot.is_cachable() {
                 let ic = &context.vm.frame().code_block.ic[usize::from(ic_index)];
-                let object_borrowed = object.borrow();
-                let shape = object_borrowed.shape();
-                ic.set(shape, slot);
+                ic.set(&object, slot);
+                if !slot.attributes.contains(SlotAttributes::PROTOTYPE) {
+                    context
+                        .realm()
+                        .global_property_cache()
+                        .insert(&object, ic.name.clone(), slot);
+                }
             }
 
             context.vm.set_register(dst.into(), result);
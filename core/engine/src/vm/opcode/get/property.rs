@@ -1,5 +1,5 @@
 use crate::{
-    object::{internal_methods::InternalMethodContext, shape::slot::SlotAttributes},
+    object::internal_methods::InternalMethodContext,
     property::PropertyKey,
     vm::opcode::{Operation, VaryingOperand},
     Context, JsResult,
@@ -29,11 +29,10 @@ impl GetPropertyByName {
 
         let ic = &context.vm.frame().code_block().ic[usize::from(index)];
         let object_borrowed = object.borrow();
-        if let Some((shape, slot)) = ic.match_or_reset(object_borrowed.shape()) {
-            let mut result = if slot.attributes.contains(SlotAttributes::PROTOTYPE) {
-                let prototype = shape.prototype().expect("prototype should have value");
-                let prototype = prototype.borrow();
-                prototype.properties().storage[slot.index as usize].clone()
+        if let Some((slot, holder)) = ic.match_or_reset(object_borrowed.shape()) {
+            let mut result = if let Some(holder) = holder {
+                let holder = holder.borrow();
+                holder.properties().storage[slot.index as usize].clone()
             } else {
                 object_borrowed.properties().storage[slot.index as usize].clone()
             };
@@ -61,9 +60,7 @@ impl GetPropertyByName {
         let slot = *context.slot();
         if slot.is_cachable() {
             let ic = &context.vm.frame().code_block.ic[usize::from(index)];
-            let object_borrowed = object.borrow();
-            let shape = object_borrowed.shape();
-            ic.set(shape, slot);
+            ic.set(&object, slot);
         }
 
         context.vm.set_register(dst.into(), result);
@@ -95,16 +95,17 @@ pub(crate) struct IteratorResult;
 impl IteratorResult {
     #[inline(always)]
     pub(crate) fn operation(value: VaryingOperand, context: &mut Context) {
-        let last_result = context
+        let iterator = context
             .vm
-            .frame()
+            .frame_mut()
             .iterators
-            .last()
-            .expect("iterator on the call frame must exist")
-            .last_result()
-            .object()
-            .clone();
+            .pop()
+            .expect("iterator on the call frame must exist");
+
+        let last_result = iterator.last_result(context);
         context.vm.set_register(value.into(), last_result.into());
+
+        context.vm.frame_mut().iterators.push(iterator);
     }
 }
 
@@ -146,6 +147,45 @@ impl Operation for IteratorValue {
     const COST: u8 = 5;
 }
 
+/// `IteratorStepValue` implements the Opcode Operation for `Opcode::IteratorStepValue`
+///
+/// Operation:
+///  - Steps the current iterator if it is not done, and gets its next value or `undefined`.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct IteratorStepValue;
+
+impl IteratorStepValue {
+    #[inline(always)]
+    pub(crate) fn operation(value: VaryingOperand, context: &mut Context) -> JsResult<()> {
+        let mut iterator = context
+            .vm
+            .frame_mut()
+            .iterators
+            .pop()
+            .expect("iterator on the call frame must exist");
+
+        let next = if iterator.done() {
+            Ok(None)
+        } else {
+            iterator.step_value(context)
+        };
+
+        context.vm.frame_mut().iterators.push(iterator);
+
+        context
+            .vm
+            .set_register(value.into(), next?.unwrap_or_default());
+
+        Ok(())
+    }
+}
+
+impl Operation for IteratorStepValue {
+    const NAME: &'static str = "IteratorStepValue";
+    const INSTRUCTION: &'static str = "INST - IteratorStepValue";
+    const COST: u8 = 6;
+}
+
 /// `IteratorDone` implements the Opcode Operation for `Opcode::IteratorDone`
 ///
 /// Operation:
@@ -242,7 +282,7 @@ impl IteratorToArray {
 
         let mut values = Vec::new();
 
-        loop {
+        while !iterator.done() {
             let done = match iterator.step(context) {
                 Ok(done) => done,
                 Err(err) => {
@@ -283,6 +283,206 @@ impl Opcode {
     pub(crate) fn decode(instruction: u8) -> Self {
         Self::from(instruction)
     }
+
+    /// Returns `true` if this opcode can have side effects that are observable by other code,
+    /// which makes it trap in a pure evaluation.
+    ///
+    /// This is an allow-list of the opcodes known to be free of side effects, so new opcodes trap
+    /// until they are added to it. The calls made by these opcodes are checked separately, see
+    /// [`Context::check_pure_call`].
+    ///
+    /// Defining the properties of object and class literals and initializing declarations is not
+    /// a side effect, since the objects and bindings cannot be reached by any other code yet.
+    pub(crate) const fn has_side_effects(self) -> bool {
+        !matches!(
+            self,
+            Self::Pop
+                | Self::PushZero
+                | Self::PushOne
+                | Self::PushInt8
+                | Self::PushInt16
+                | Self::PushInt32
+                | Self::PushFloat
+                | Self::PushDouble
+                | Self::PushNan
+                | Self::PushPositiveInfinity
+                | Self::PushNegativeInfinity
+                | Self::PushNull
+                | Self::PushTrue
+                | Self::PushFalse
+                | Self::PushUndefined
+                | Self::PushLiteral
+                | Self::PushRegexp
+                | Self::PushEmptyObject
+                | Self::PushClassPrototype
+                | Self::SetClassPrototype
+                | Self::SetHomeObject
+                | Self::SetPrototype
+                | Self::PushNewArray
+                | Self::PushValueToArray
+                | Self::PushElisionToArray
+                | Self::PushSpreadToArray
+                | Self::Add
+                | Self::Sub
+                | Self::Div
+                | Self::Mul
+                | Self::Mod
+                | Self::Pow
+                | Self::AddInt32
+                | Self::SubInt32
+                | Self::MulInt32
+                | Self::ShiftRight
+                | Self::ShiftLeft
+                | Self::UnsignedShiftRight
+                | Self::BitOr
+                | Self::BitAnd
+                | Self::BitXor
+                | Self::BitNot
+                | Self::In
+                | Self::InPrivate
+                | Self::Eq
+                | Self::StrictEq
+                | Self::NotEq
+                | Self::StrictNotEq
+                | Self::GreaterThan
+                | Self::GreaterThanOrEq
+                | Self::LessThan
+                | Self::LessThanOrEq
+                | Self::InstanceOf
+                | Self::LogicalAnd
+                | Self::LogicalOr
+                | Self::Coalesce
+                | Self::TypeOf
+                | Self::LogicalNot
+                | Self::Pos
+                | Self::Neg
+                | Self::Inc
+                | Self::Dec
+                | Self::DefVar
+                | Self::DefInitVar
+                | Self::PutLexicalValue
+                | Self::ThrowMutateImmutable
+                | Self::GetArgument
+                | Self::GetName
+                | Self::GetNameGlobal
+                | Self::GetLocator
+                | Self::GetNameAndLocator
+                | Self::GetNameOrUndefined
+                | Self::GetBinding
+                | Self::GetBindingUnchecked
+                | Self::GetPropertyByName
+                | Self::GetPropertyByValue
+                | Self::GetPropertyByValuePush
+                | Self::SetFunctionName
+                | Self::DefineOwnPropertyByName
+                | Self::DefineClassStaticMethodByName
+                | Self::DefineClassMethodByName
+                | Self::DefineOwnPropertyByValue
+                | Self::DefineClassStaticMethodByValue
+                | Self::DefineClassMethodByValue
+                | Self::DefineClassStaticFieldByName
+                | Self::DefineClassStaticFieldByValue
+                | Self::DefineClassAutoAccessorByName
+                | Self::DefineClassAutoAccessorByValue
+                | Self::DefineClassPrivateAutoAccessor
+                | Self::SetPropertyGetterByName
+                | Self::DefineClassStaticGetterByName
+                | Self::DefineClassGetterByName
+                | Self::SetPropertyGetterByValue
+                | Self::DefineClassStaticGetterByValue
+                | Self::DefineClassGetterByValue
+                | Self::SetPropertySetterByName
+                | Self::DefineClassStaticSetterByName
+                | Self::DefineClassSetterByName
+                | Self::SetPropertySetterByValue
+                | Self::DefineClassStaticSetterByValue
+                | Self::DefineClassSetterByValue
+                | Self::DefinePrivateField
+                | Self::SetPrivateMethod
+                | Self::SetPrivateSetter
+                | Self::SetPrivateGetter
+                | Self::GetPrivateField
+                | Self::PushClassField
+                | Self::PushClassFieldPrivate
+                | Self::PushClassPrivateGetter
+                | Self::PushClassPrivateSetter
+                | Self::PushClassPrivateMethod
+                | Self::DeleteSuperThrow
+                | Self::CopyDataProperties
+                | Self::ToPropertyKey
+                | Self::Jump
+                | Self::JumpIfTrue
+                | Self::JumpIfFalse
+                | Self::JumpIfNotUndefined
+                | Self::JumpIfNullOrUndefined
+                | Self::JumpIfNullOrUndefinedSetUndefined
+                | Self::JumpTable
+                | Self::Throw
+                | Self::ReThrow
+                | Self::Exception
+                | Self::MaybeException
+                | Self::ThrowNewTypeError
+                | Self::ThrowNewSyntaxError
+                | Self::ThrowNewReferenceError
+                | Self::This
+                | Self::ThisForObjectEnvironmentName
+                | Self::Super
+                | Self::SuperCallPrepare
+                | Self::SuperCall
+                | Self::SuperCallSpread
+                | Self::SuperCallDerived
+                | Self::BindThisValue
+                | Self::Case
+                | Self::CaseTable
+                | Self::GetFunction
+                | Self::Call
+                | Self::CallSpread
+                | Self::New
+                | Self::NewSpread
+                | Self::CheckReturn
+                | Self::Return
+                | Self::Generator
+                | Self::SetAccumulator
+                | Self::SetRegisterFromAccumulator
+                | Self::Move
+                | Self::PopIntoRegister
+                | Self::PushFromRegister
+                | Self::PushScope
+                | Self::PushObjectEnvironment
+                | Self::PopEnvironment
+                | Self::IncrementLoopIteration
+                | Self::CreateForInIterator
+                | Self::GetIterator
+                | Self::IteratorNext
+                | Self::IteratorDone
+                | Self::IteratorValue
+                | Self::IteratorStepValue
+                | Self::IteratorResult
+                | Self::IteratorToArray
+                | Self::IteratorStackEmpty
+                | Self::CreateIteratorResult
+                | Self::IteratorReturn
+                | Self::ConcatToString
+                | Self::ValueNotNullOrUndefined
+                | Self::RestParameterInit
+                | Self::GeneratorYield
+                | Self::GeneratorNext
+                | Self::JumpIfNotResumeKind
+                | Self::GeneratorDelegateNext
+                | Self::GeneratorDelegateResume
+                | Self::NewTarget
+                | Self::IsObject
+                | Self::TemplateLookup
+                | Self::TemplateCreate
+                | Self::PushPrivateEnvironment
+                | Self::PopPrivateEnvironment
+                | Self::CreateMappedArgumentsObject
+                | Self::CreateUnmappedArgumentsObject
+                | Self::HasRestrictedGlobalProperty
+                | Self::CanDeclareGlobalFunction
+                | Self::CanDeclareGlobalVar
+        )
+    }
 }
 
 fn encode_instruction<A: Argument>(opcode: Opcode, args: A, bytes: &mut Vec<u8>) {
@@ -705,11 +905,19 @@ generate_opcodes! {
     ///   - Input: array
     PushElisionToArray { array: VaryingOperand },
 
-    /// Push all iterator values to an array.
+    /// Push all the values produced by spreading a value to an array.
     ///
+    /// Dense arrays are copied directly if the array iterator is unmodified.
+    ///
+    /// - Operands:
+    ///   - is_array_literal: `bool`, whether the value is an array literal that may be emptied
     /// - Registers:
-    ///   - Input: array
-    PushIteratorToArray { array: VaryingOperand },
+    ///   - Input: value, array
+    PushSpreadToArray {
+        value: VaryingOperand,
+        array: VaryingOperand,
+        is_array_literal: VaryingOperand
+    },
 
     /// Binary `+` operator.
     ///
@@ -753,6 +961,41 @@ generate_opcodes! {
     ///   - Output: dst
     Pow { dst: VaryingOperand, lhs: VaryingOperand, rhs: VaryingOperand },
 
+    /// Binary `+` operator with an `i32` right operand.
+    ///
+    /// If `lhs` is an `i32` and the sum doesn't overflow, it's computed without any conversion.
+    ///
+    /// - Operands:
+    ///   - rhs: `i32`
+    /// - Registers
+    ///   - Input: lhs
+    ///   - Output: dst
+    AddInt32 { dst: VaryingOperand, lhs: VaryingOperand, rhs: i32 },
+
+    /// Binary `-` operator with an `i32` right operand.
+    ///
+    /// If `lhs` is an `i32` and the difference doesn't overflow, it's computed without any
+    /// conversion.
+    ///
+    /// - Operands:
+    ///   - rhs: `i32`
+    /// - Registers
+    ///   - Input: lhs
+    ///   - Output: dst
+    SubInt32 { dst: VaryingOperand, lhs: VaryingOperand, rhs: i32 },
+
+    /// Binary `*` operator with an `i32` right operand.
+    ///
+    /// If `lhs` is an `i32` and the product is a non-zero `i32`, it's computed without any
+    /// conversion.
+    ///
+    /// - Operands:
+    ///   - rhs: `i32`
+    /// - Registers
+    ///   - Input: lhs
+    ///   - Output: dst
+    MulInt32 { dst: VaryingOperand, lhs: VaryingOperand, rhs: i32 },
+
     /// Binary `>>` operator.
     ///
     /// - Registers
@@ -1042,6 +1285,43 @@ generate_opcodes! {
     ///   - Input: src
     SetName { src: VaryingOperand, binding_index: VaryingOperand },
 
+    /// Get the value of the binding at `slot` in the declarative environment at `environment`.
+    ///
+    /// This is used instead of `GetName` if the compiler knows that the binding cannot be
+    /// shadowed at runtime by a `with` statement or a direct `eval` call.
+    ///
+    /// - Operands:
+    ///   - environment: `VaryingOperand`
+    ///   - slot: `VaryingOperand`
+    /// - Registers:
+    ///   - Output: dst
+    GetBinding { dst: VaryingOperand, environment: VaryingOperand, slot: VaryingOperand },
+
+    /// Get the value of the binding at `slot` in the declarative environment at `environment`,
+    /// without checking that it is initialized.
+    ///
+    /// This is used instead of `GetBinding` if the compiler knows that the binding is initialized
+    /// on every path to the read.
+    ///
+    /// - Operands:
+    ///   - environment: `VaryingOperand`
+    ///   - slot: `VaryingOperand`
+    /// - Registers:
+    ///   - Output: dst
+    GetBindingUnchecked { dst: VaryingOperand, environment: VaryingOperand, slot: VaryingOperand },
+
+    /// Assign a value to the binding at `slot` in the declarative environment at `environment`.
+    ///
+    /// This is used instead of `SetName` if the compiler knows that the binding cannot be
+    /// shadowed at runtime by a `with` statement or a direct `eval` call.
+    ///
+    /// - Operands:
+    ///   - environment: `VaryingOperand`
+    ///   - slot: `VaryingOperand`
+    /// - Registers:
+    ///   - Input: src
+    SetBinding { src: VaryingOperand, environment: VaryingOperand, slot: VaryingOperand },
+
     /// Assigns a value to the binding pointed by the top of the `bindings_stack`.
     ///
     /// - Registers:
@@ -1205,6 +1485,70 @@ generate_opcodes! {
         object: VaryingOperand
     },
 
+    /// Defines a static public class field by name.
+    ///
+    /// Uses `CreateDataPropertyOrThrow`, or `Set` if the context uses legacy class field semantics.
+    ///
+    /// - Operands:
+    ///   - name_index: `VaryingOperand`
+    /// - Registers:
+    ///   - Input: object, value
+    DefineClassStaticFieldByName { object: VaryingOperand, value: VaryingOperand, name_index: VaryingOperand },
+
+    /// Defines a static public class field by value.
+    ///
+    /// Uses `CreateDataPropertyOrThrow`, or `Set` if the context uses legacy class field semantics.
+    ///
+    /// - Registers:
+    ///   - Input: object, key, value
+    DefineClassStaticFieldByValue {
+        value: VaryingOperand,
+        key: VaryingOperand,
+        object: VaryingOperand
+    },
+
+    /// Defines a class auto-accessor by name, backed by the private storage of the class.
+    ///
+    /// - Operands:
+    ///   - storage_index: `VaryingOperand`
+    ///   - name_index: `VaryingOperand`
+    /// - Registers:
+    ///   - Input: class, object
+    DefineClassAutoAccessorByName {
+        class: VaryingOperand,
+        object: VaryingOperand,
+        storage_index: VaryingOperand,
+        name_index: VaryingOperand
+    },
+
+    /// Defines a class auto-accessor by value, backed by the private storage of the class.
+    ///
+    /// - Operands:
+    ///   - storage_index: `VaryingOperand`
+    /// - Registers:
+    ///   - Input: class, object, key
+    DefineClassAutoAccessorByValue {
+        class: VaryingOperand,
+        object: VaryingOperand,
+        storage_index: VaryingOperand,
+        key: VaryingOperand
+    },
+
+    /// Defines a private class auto-accessor, backed by the private storage of the class.
+    ///
+    /// - Operands:
+    ///   - storage_index: `VaryingOperand`
+    ///   - name_index: `VaryingOperand`
+    ///   - is_static: `bool`
+    /// - Registers:
+    ///   - Input: class
+    DefineClassPrivateAutoAccessor {
+        class: VaryingOperand,
+        storage_index: VaryingOperand,
+        name_index: VaryingOperand,
+        is_static: VaryingOperand
+    },
+
     /// Sets a getter property by name of an object.
     ///
     /// Like `get name() value`
@@ -1539,6 +1883,17 @@ generate_opcodes! {
     ///   - Output: value
     JumpIfNullOrUndefined { address: u32, value: VaryingOperand },
 
+    /// Conditional jump to address that short-circuits an optional chain.
+    ///
+    /// If the value is null or undefined, set it to undefined and jump to `address`.
+    ///
+    /// - Operands:
+    ///   - address: `u32`
+    /// - Registers:
+    ///   - Input: value
+    ///   - Output: value
+    JumpIfNullOrUndefinedSetUndefined { address: u32, value: VaryingOperand },
+
     /// Jump table that jumps depending on top value of the stack.
     ///
     /// This is used to handle special cases when we call `continue`, `break` or `return` in a try block,
@@ -1674,6 +2029,16 @@ generate_opcodes! {
     ///   - Input: value, condition
     Case { address: u32, value: VaryingOperand, condition: VaryingOperand },
 
+    /// Jumps to the body of the case that is strictly equal to the value, using a jump table.
+    ///
+    /// If no case matches, the execution continues with the next instruction.
+    ///
+    /// - Operands:
+    ///   - table: `VaryingOperand`, the index of the [`SwitchTable`] constant
+    /// - Registers:
+    ///   - Input: value
+    CaseTable { value: VaryingOperand, table: VaryingOperand },
+
     /// Get function from the pre-compiled inner functions.
     ///
     /// - Operands:
@@ -1839,6 +2204,15 @@ generate_opcodes! {
     /// - Iterator Stack: `iterator` **=>** `iterator`
     IteratorValue { dst: VaryingOperand },
 
+    /// Steps the current iterator if it is not done, and gets its next value.
+    ///
+    /// If the iterator is done, `undefined` is returned instead.
+    ///
+    /// - Registers:
+    ///   - Output: dst
+    /// - Iterator Stack: `iterator` **=>** `iterator`
+    IteratorStepValue { dst: VaryingOperand },
+
     /// Gets the last iteration result of the current iterator record.
     ///
     /// - Registers:
@@ -2191,32 +2565,4 @@ generate_opcodes! {
     Reserved48 => Reserved,
     /// Reserved [`Opcode`].
     Reserved49 => Reserved,
-    /// Reserved [`Opcode`].
-    Reserved50 => Reserved,
-    /// Reserved [`Opcode`].
-    Reserved51 => Reserved,
-    /// Reserved [`Opcode`].
-    Reserved52 => Reserved,
-    /// Reserved [`Opcode`].
-    Reserved53 => Reserved,
-    /// Reserved [`Opcode`].
-    Reserved54 => Reserved,
-    /// Reserved [`Opcode`].
-    Reserved55 => Reserved,
-    /// Reserved [`Opcode`].
-    Reserved56 => Reserved,
-    /// Reserved [`Opcode`].
-    Reserved57 => Reserved,
-    /// Reserved [`Opcode`].
-    Reserved58 => Reserved,
-    /// Reserved [`Opcode`].
-    Reserved59 => Reserved,
-    /// Reserved [`Opcode`].
-    Reserved60 => Reserved,
-    /// Reserved [`Opcode`].
-    Reserved61 => Reserved,
-    /// Reserved [`Opcode`].
-    Reserved62 => Reserved,
-    /// Reserved [`Opcode`].
-    Reserved63 => Reserved,
 }
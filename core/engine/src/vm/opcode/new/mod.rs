@@ -1,5 +1,5 @@
-use super::VaryingOperand;
-use crate::{error::JsNativeError, vm::opcode::Operation, Context, JsResult};
+use super::{call::not_constructor_error, VaryingOperand};
+use crate::{vm::opcode::Operation, Context, JsResult};
 
 /// `New` implements the Opcode Operation for `Opcode::New`
 ///
@@ -18,7 +18,8 @@ impl New {
 
         let cons = func
             .as_object()
-            .ok_or_else(|| JsNativeError::typ().with_message("not a constructor"))?
+            .filter(|object| object.is_constructor())
+            .ok_or_else(|| not_constructor_error(context))?
             .clone();
 
         context.vm.stack.push(cons.clone()); // Push new.target
@@ -59,7 +60,8 @@ impl NewSpread {
 
         let cons = func
             .as_object()
-            .ok_or_else(|| JsNativeError::typ().with_message("not a constructor"))?
+            .filter(|object| object.is_constructor())
+            .ok_or_else(|| not_constructor_error(context))?
             .clone();
 
         let argument_count = arguments.len();
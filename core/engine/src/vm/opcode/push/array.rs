@@ -1,9 +1,10 @@
 use crate::{
-    builtins::Array,
+    builtins::{iterable::IteratorHint, Array},
     string::StaticJsStrings,
     vm::opcode::{Operation, VaryingOperand},
     Context, JsResult, JsValue,
 };
+use thin_vec::ThinVec;
 
 /// `PushNewArray` implements the Opcode Operation for `Opcode::PushNewArray`
 ///
@@ -86,32 +87,41 @@ impl Operation for PushElisionToArray {
     const COST: u8 = 3;
 }
 
-/// `PushIteratorToArray` implements the Opcode Operation for `Opcode::PushIteratorToArray`
+/// `PushSpreadToArray` implements the Opcode Operation for `Opcode::PushSpreadToArray`
 ///
 /// Operation:
-///  - Push all iterator values to an array.
+///  - Push all the values produced by spreading a value to an array.
 #[derive(Debug, Clone, Copy)]
-pub(crate) struct PushIteratorToArray;
+pub(crate) struct PushSpreadToArray;
 
-impl PushIteratorToArray {
+impl PushSpreadToArray {
     #[inline(always)]
-    pub(crate) fn operation(array: VaryingOperand, context: &mut Context) -> JsResult<()> {
+    pub(crate) fn operation(
+        (value, array, is_array_literal): (VaryingOperand, VaryingOperand, VaryingOperand),
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let value = context.vm.get_register(value.into()).clone();
         let array = context.vm.get_register(array.into()).clone();
-        let mut iterator = context
-            .vm
-            .frame_mut()
-            .iterators
-            .pop()
-            .expect("iterator stack should have at least an iterator");
+        let array = array.as_object().expect("should be an object");
+
+        // Fast path: spreading a dense array with an unmodified array iterator is not
+        // observable, so its elements can be copied directly.
+        let is_array_literal = u32::from(is_array_literal) != 0;
+        if let Some(values) = Array::spread_values(&value, is_array_literal, context) {
+            return Array::append(array, values, context);
+        }
+
+        let mut iterator = value.get_iterator(IteratorHint::Sync, context)?;
+        let mut values = ThinVec::new();
         while let Some(next) = iterator.step_value(context)? {
-            Array::push(&array, &[next], context)?;
+            values.push(next);
         }
-        Ok(())
+        Array::append(array, values, context)
     }
 }
 
-impl Operation for PushIteratorToArray {
-    const NAME: &'static str = "PushIteratorToArray";
-    const INSTRUCTION: &'static str = "INST - PushIteratorToArray";
+impl Operation for PushSpreadToArray {
+    const NAME: &'static str = "PushSpreadToArray";
+    const INSTRUCTION: &'static str = "INST - PushSpreadToArray";
     const COST: u8 = 8;
 }
@@ -2,7 +2,7 @@ use crate::{
     builtins::function::OrdinaryFunction,
     environments::PrivateEnvironment,
     vm::opcode::{Operation, VaryingOperand},
-    Context, JsResult,
+    Context, JsNativeError, JsResult,
 };
 use boa_gc::Gc;
 use thin_vec::ThinVec;
@@ -41,6 +41,11 @@ pub(crate) struct PushObjectEnvironment;
 impl PushObjectEnvironment {
     #[inline(always)]
     pub(crate) fn operation(value: VaryingOperand, context: &mut Context) -> JsResult<()> {
+        if context.dynamic_scopes_denied() {
+            return Err(JsNativeError::typ()
+                .with_message("with statements are disabled in this context")
+                .into());
+        }
         let object = context.vm.get_register(value.into()).clone();
         let object = object.to_object(context)?;
         context.vm.environments.push_object(object);
@@ -103,6 +103,54 @@ impl Operation for SetNameByLocator {
     const COST: u8 = 4;
 }
 
+/// `SetBinding` implements the Opcode Operation for `Opcode::SetBinding`
+///
+/// Operation:
+///  - Assign a value to the binding at `slot` in the declarative environment at `environment`.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct SetBinding;
+
+impl SetBinding {
+    #[inline(always)]
+    pub(crate) fn operation(
+        (value, environment, slot): (VaryingOperand, VaryingOperand, VaryingOperand),
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let (environment, slot) = (u32::from(environment), u32::from(slot));
+
+        #[cfg(debug_assertions)]
+        context.verify_static_binding(environment, slot);
+
+        let value = context.vm.get_register(value.into()).clone();
+        let env = context
+            .environment_expect(environment)
+            .as_declarative()
+            .expect("statically resolved bindings must be in a declarative environment");
+
+        if env.get(slot).is_none() {
+            let name = context
+                .vm
+                .frame()
+                .code_block()
+                .slot_binding(environment, slot)
+                .map(|locator| locator.name().to_std_string_escaped())
+                .unwrap_or_default();
+            return Err(JsNativeError::reference()
+                .with_message(format!("cannot assign to uninitialized binding `{name}`"))
+                .into());
+        }
+
+        env.set(slot, value);
+        Ok(())
+    }
+}
+
+impl Operation for SetBinding {
+    const NAME: &'static str = "SetBinding";
+    const INSTRUCTION: &'static str = "INST - SetBinding";
+    const COST: u8 = 2;
+}
+
 /// Checks that the binding pointed by `locator` exists and is initialized.
 fn verify_initialized(locator: &BindingLocator, context: &mut Context) -> JsResult<()> {
     if !context.is_initialized_binding(locator)? {
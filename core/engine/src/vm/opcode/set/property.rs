@@ -4,7 +4,7 @@ use crate::value::JsVariant;
 use crate::vm::opcode::VaryingOperand;
 use crate::{
     builtins::function::set_function_name,
-    object::{internal_methods::InternalMethodContext, shape::slot::SlotAttributes},
+    object::internal_methods::InternalMethodContext,
     property::{PropertyDescriptor, PropertyKey},
     vm::opcode::Operation,
     Context, JsNativeError, JsResult,
@@ -36,15 +36,14 @@ impl SetPropertyByName {
         let ic = &context.vm.frame().code_block().ic[usize::from(index)];
 
         let object_borrowed = object.borrow();
-        if let Some((shape, slot)) = ic.match_or_reset(object_borrowed.shape()) {
+        if let Some((slot, holder)) = ic.match_or_reset(object_borrowed.shape()) {
             let slot_index = slot.index as usize;
 
             if slot.attributes.is_accessor_descriptor() {
-                let result = if slot.attributes.contains(SlotAttributes::PROTOTYPE) {
-                    let prototype = shape.prototype().expect("prototype should have value");
-                    let prototype = prototype.borrow();
+                let result = if let Some(holder) = holder {
+                    let holder = holder.borrow();
 
-                    prototype.properties().storage[slot_index + 1].clone()
+                    holder.properties().storage[slot_index + 1].clone()
                 } else {
                     object_borrowed.properties().storage[slot_index + 1].clone()
                 };
@@ -57,11 +56,10 @@ impl SetPropertyByName {
                         context,
                     )?;
                 }
-            } else if slot.attributes.contains(SlotAttributes::PROTOTYPE) {
-                let prototype = shape.prototype().expect("prototype should have value");
-                let mut prototype = prototype.borrow_mut();
+            } else if let Some(holder) = holder {
+                let mut holder = holder.borrow_mut();
 
-                prototype.properties_mut().storage[slot_index] = value.clone();
+                holder.properties_mut().storage[slot_index] = value.clone();
             } else {
                 drop(object_borrowed);
                 let mut object_borrowed = object.borrow_mut();
@@ -85,9 +83,7 @@ impl SetPropertyByName {
         let slot = *context.slot();
         if succeeded && slot.is_cachable() {
             let ic = &context.vm.frame().code_block.ic[usize::from(index)];
-            let object_borrowed = object.borrow();
-            let shape = object_borrowed.shape();
-            ic.set(shape, slot);
+            ic.set(&object, slot);
         }
 
         Ok(())
@@ -132,8 +128,9 @@ impl SetPropertyByValue {
                 if let PropertyKey::Index(index) = &key {
                     let mut object_borrowed = object.borrow_mut();
 
-                    // Cannot modify if not extensible.
-                    if !object_borrowed.extensible {
+                    // Cannot modify if not extensible, and watched objects must go through
+                    // their internal methods.
+                    if !object_borrowed.extensible || object_borrowed.watched {
                         break 'fast_path;
                     }
 
@@ -1,5 +1,10 @@
+use rustc_hash::FxHashMap;
+
 use super::VaryingOperand;
-use crate::{vm::opcode::Operation, Context};
+use crate::{
+    vm::{opcode::Operation, Constant},
+    Context, JsString, JsValue,
+};
 
 /// `Case` implements the Opcode Operation for `Opcode::Case`
 ///
@@ -28,3 +33,89 @@ impl Operation for Case {
     const INSTRUCTION: &'static str = "INST - Case";
     const COST: u8 = 2;
 }
+
+/// The jump table of a `switch` statement whose cases are all string or number literals.
+///
+/// It maps the value of each case to the address of its body.
+#[derive(Debug, Clone)]
+pub(crate) enum SwitchTable {
+    /// The cases are integers in the range `min..min + addresses.len()`.
+    ///
+    /// The integers of the range that are not a case map to the address following the
+    /// [`CaseTable`] instruction.
+    Dense { min: i32, addresses: Box<[u32]> },
+
+    /// The cases are arbitrary strings and numbers.
+    Hashed {
+        strings: FxHashMap<JsString, u32>,
+
+        /// The numbers are keyed by their bits, with `-0` normalized to `+0`.
+        numbers: FxHashMap<u64, u32>,
+    },
+}
+
+impl SwitchTable {
+    /// Returns the key of `number` in [`SwitchTable::Hashed`].
+    ///
+    /// `NaN` is never strictly equal to a case, so it has no key.
+    pub(crate) fn number_key(number: f64) -> Option<u64> {
+        if number.is_nan() {
+            return None;
+        }
+        // `-0 === +0`, so both must have the same key.
+        let number = if number == 0.0 { 0.0 } else { number };
+        Some(number.to_bits())
+    }
+
+    /// Gets the address of the body of the case strictly equal to `value`.
+    pub(crate) fn get(&self, value: &JsValue) -> Option<u32> {
+        match self {
+            Self::Dense { min, addresses } => {
+                let index = value.as_number()? - f64::from(*min);
+                // Also excludes `NaN` and the infinities, whose fractional part is `NaN`.
+                if index.fract() != 0.0 || index < 0.0 {
+                    return None;
+                }
+                addresses.get(index as usize).copied()
+            }
+            Self::Hashed { strings, numbers } => {
+                if let Some(string) = value.as_string() {
+                    return strings.get(string).copied();
+                }
+                numbers.get(&Self::number_key(value.as_number()?)?).copied()
+            }
+        }
+    }
+}
+
+/// `CaseTable` implements the Opcode Operation for `Opcode::CaseTable`
+///
+/// Operation:
+///  - Jumps to the body of the case that is strictly equal to the value, using the
+///    [`SwitchTable`] constant at `table`.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct CaseTable;
+
+impl CaseTable {
+    #[inline(always)]
+    pub(super) fn operation(
+        (value, table): (VaryingOperand, VaryingOperand),
+        context: &mut Context,
+    ) {
+        let value = context.vm.get_register(value.into());
+        let Constant::SwitchTable(table) =
+            &context.vm.frame().code_block().constants[usize::from(table)]
+        else {
+            unreachable!("constant should be a switch table");
+        };
+        if let Some(address) = table.get(value) {
+            context.vm.frame_mut().pc = address;
+        }
+    }
+}
+
+impl Operation for CaseTable {
+    const NAME: &'static str = "CaseTable";
+    const INSTRUCTION: &'static str = "INST - CaseTable";
+    const COST: u8 = 2;
+}
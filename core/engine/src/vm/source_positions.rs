@@ -0,0 +1,58 @@
+use boa_ast::Position;
+use thin_vec::ThinVec;
+
+/// An entry of a [`SourcePositionTable`].
+///
+/// Maps all the instructions starting at `start`, up to the start of the next entry, to
+/// `position`.
+#[derive(Debug, Clone, Copy)]
+struct Entry {
+    start: u32,
+    position: Position,
+}
+
+/// A table mapping instruction offsets of a [`CodeBlock`][crate::vm::CodeBlock] to the line and
+/// column of the source code they were compiled from.
+///
+/// The table is compressed by only recording the offsets where the position changes, so a run of
+/// instructions compiled from the same expression takes a single entry.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct SourcePositionTable {
+    entries: ThinVec<Entry>,
+}
+
+impl SourcePositionTable {
+    /// Maps the instructions starting at `start` to `position`.
+    ///
+    /// Offsets must be pushed in increasing order. Pushing the same offset again replaces its
+    /// position, since no instructions were emitted in between.
+    pub(crate) fn push(&mut self, start: u32, position: Position) {
+        if let Some(last) = self.entries.last_mut() {
+            if last.position == position {
+                return;
+            }
+
+            if last.start == start {
+                last.position = position;
+
+                // The previous entry might now have the same position, in which case they can be
+                // merged.
+                let len = self.entries.len();
+                if len > 1 && self.entries[len - 2].position == position {
+                    self.entries.pop();
+                }
+                return;
+            }
+        }
+
+        self.entries.push(Entry { start, position });
+    }
+
+    /// Finds the source position of the instruction at `pc`.
+    pub(crate) fn find(&self, pc: u32) -> Option<Position> {
+        let index = self.entries.partition_point(|entry| entry.start <= pc);
+        index
+            .checked_sub(1)
+            .map(|index| self.entries[index].position)
+    }
+}
@@ -0,0 +1,156 @@
+//! Stack traces of the running code, used for the `stack` property of error objects.
+//!
+//! See [`ContextBuilder::error_stack_traces`].
+//!
+//! [`ContextBuilder::error_stack_traces`]: crate::context::ContextBuilder::error_stack_traces
+
+use std::{fmt, path::Path, rc::Rc};
+
+use boa_ast::Position;
+
+use super::{CallFrame, Vm};
+use crate::JsString;
+
+/// A function call of a [`StackTrace`].
+#[derive(Debug, Clone)]
+struct StackTraceFrame {
+    function: JsString,
+    path: Option<Rc<Path>>,
+    position: Option<Position>,
+
+    /// Whether the call awaits the async function called by the previous frame, instead of
+    /// running it.
+    is_async: bool,
+}
+
+impl StackTraceFrame {
+    fn new(frame: &CallFrame) -> Self {
+        Self {
+            function: frame.code_block().name().clone(),
+            path: frame.source_path().cloned(),
+            position: frame.source_position(),
+            is_async: false,
+        }
+    }
+}
+
+impl fmt::Display for StackTraceFrame {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("at ")?;
+        if self.is_async {
+            f.write_str("async ")?;
+        }
+        if self.function.is_empty() {
+            f.write_str("<anonymous>")?;
+        } else {
+            write!(f, "{}", self.function.to_std_string_escaped())?;
+        }
+
+        match &self.path {
+            Some(path) => write!(f, " ({}", path.display())?,
+            None => f.write_str(" (<anonymous>")?,
+        }
+        if let Some(position) = self.position {
+            write!(
+                f,
+                ":{}:{}",
+                position.line_number(),
+                position.column_number()
+            )?;
+        }
+        f.write_str(")")
+    }
+}
+
+/// The function calls of the running code, from the innermost to the outermost call.
+///
+/// Calls to async functions are followed across `await`s: when an async function is first
+/// suspended, the calls that led to it are captured and stored in its [`CallFrame`], and
+/// appended to the stack traces captured after it is resumed.
+#[derive(Debug, Clone)]
+pub(crate) struct StackTrace {
+    frames: Rc<[StackTraceFrame]>,
+}
+
+impl StackTrace {
+    /// The maximum number of calls in a stack trace.
+    ///
+    /// This also bounds the calls copied from the stack trace of a suspended async function,
+    /// which would otherwise grow with each level of async calls.
+    const MAX_FRAMES: usize = 10;
+
+    /// Collects the stack trace of `frames`, from the innermost to the outermost call, up to
+    /// [`Self::MAX_FRAMES`] calls.
+    ///
+    /// Collection stops at the first frame of a resumed async function, continuing with the
+    /// calls that were captured when it was first suspended.
+    fn collect<'a, I>(frames: I) -> Vec<StackTraceFrame>
+    where
+        I: IntoIterator<Item = &'a CallFrame>,
+    {
+        let mut trace = Vec::new();
+        for frame in frames {
+            // Frames that have not started running, like the initial dummy frame of the vm,
+            // are not function calls.
+            if frame.pc == 0 {
+                continue;
+            }
+
+            trace.push(StackTraceFrame::new(frame));
+            if trace.len() == Self::MAX_FRAMES {
+                break;
+            }
+
+            if let Some(async_trace) = &frame.async_trace {
+                let remaining = Self::MAX_FRAMES - trace.len();
+                trace.extend(async_trace.frames.iter().take(remaining).cloned());
+                break;
+            }
+        }
+
+        trace
+    }
+
+    /// Returns `true` if the stack trace has no calls.
+    pub(crate) fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+}
+
+impl fmt::Display for StackTrace {
+    /// Formats the stack trace with a line for each call, indented by four spaces.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, frame) in self.frames.iter().enumerate() {
+            if i != 0 {
+                f.write_str("\n")?;
+            }
+            write!(f, "    {frame}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Vm {
+    /// Captures the stack trace of the running code.
+    pub(crate) fn stack_trace(&self) -> StackTrace {
+        let frames =
+            StackTrace::collect(std::iter::once(&self.frame).chain(self.frames.iter().rev()));
+        StackTrace {
+            frames: frames.into(),
+        }
+    }
+
+    /// Captures the calls that led to the current frame, marked as awaiting it.
+    ///
+    /// This is stored in the frame of an async function when it is first suspended, since the
+    /// frames of its callers are gone by the time it is resumed.
+    pub(crate) fn async_stack_trace(&self) -> StackTrace {
+        let mut frames = StackTrace::collect(self.frames.iter().rev());
+        for frame in &mut frames {
+            frame.is_async = true;
+        }
+        StackTrace {
+            frames: frames.into(),
+        }
+    }
+}
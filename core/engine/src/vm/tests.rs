@@ -1,6 +1,6 @@
 use crate::{
-    js_string, property::Attribute, run_test_actions, Context, JsNativeErrorKind, JsValue,
-    TestAction,
+    js_string, property::Attribute, run_test_actions, vm::SuspendableResult, Context,
+    JsNativeError, JsNativeErrorKind, JsValue, NativeFunction, Script, TestAction,
 };
 use boa_macros::js_str;
 use boa_parser::Source;
@@ -432,3 +432,221 @@ fn long_object_chain_gc_trace_stack_overflow() {
         TestAction::inspect_context(|_| boa_gc::force_collect()),
     ]);
 }
+
+#[test]
+fn source_position_table() {
+    use crate::vm::SourcePositionTable;
+    use boa_ast::Position;
+
+    let mut table = SourcePositionTable::default();
+    table.push(0, Position::new(1, 1));
+    table.push(4, Position::new(1, 1));
+    table.push(4, Position::new(2, 3));
+    table.push(10, Position::new(2, 8));
+    table.push(10, Position::new(2, 3));
+    table.push(16, Position::new(5, 1));
+
+    assert_eq!(table.find(0), Some(Position::new(1, 1)));
+    assert_eq!(table.find(3), Some(Position::new(1, 1)));
+    assert_eq!(table.find(4), Some(Position::new(2, 3)));
+    assert_eq!(table.find(12), Some(Position::new(2, 3)));
+    assert_eq!(table.find(20), Some(Position::new(5, 1)));
+    assert_eq!(SourcePositionTable::default().find(0), None);
+}
+
+#[test]
+fn frames_introspection() {
+    let context = &mut Context::default();
+    context
+        .register_global_callable(
+            js_string!("frames"),
+            0,
+            NativeFunction::from_fn_ptr(|_, _, context| {
+                let frames = context
+                    .frames()
+                    .map(|frame| {
+                        let this = frame.this().map(|this| this.display().to_string());
+                        let arguments = frame.arguments().map(|args| {
+                            args.iter()
+                                .map(|arg| arg.display().to_string())
+                                .collect::<Vec<_>>()
+                                .join(",")
+                        });
+                        format!(
+                            "{} this={} args={}/{} function={} line={}",
+                            frame.function_name().to_std_string_escaped(),
+                            this.unwrap_or_default(),
+                            frame.argument_count(),
+                            arguments.unwrap_or_default(),
+                            frame.function().is_some(),
+                            frame.position().map_or(0, |pos| pos.line_number()),
+                        )
+                    })
+                    .collect::<Vec<_>>();
+                Ok(js_string!(frames.join("\n")).into())
+            }),
+        )
+        .unwrap();
+
+    let result = context
+        .eval(Source::from_bytes(indoc! {r#"
+            "use strict";
+            function inner(a, b) {
+                return frames();
+            }
+            function outer() {
+                return inner.call("receiver", 1, 2);
+            }
+            outer(3);
+        "#}))
+        .unwrap();
+
+    assert_eq!(
+        result.as_string().unwrap().to_std_string_escaped(),
+        indoc! {r#"
+            inner this="receiver" args=2/1,2 function=true line=3
+            outer this=undefined args=1/3 function=true line=6
+            <main> this=undefined args=0/ function=false line=8"#}
+    );
+}
+
+#[test]
+fn nested_native_calls_keep_their_arguments() {
+    run_test_actions([
+        TestAction::assert_eq(
+            indoc! {r#"
+                [1, 2, 3]
+                    .map((x) => Math.max(x, ...[4, 5].map((y) => Math.min(x * y, 9))))
+                    .join()
+            "#},
+            js_str!("5,9,9"),
+        ),
+        TestAction::assert_eq(
+            indoc! {r#"
+                const values = Array.from({ length: 100 }, (_, i) => i);
+                Math.max(...values) + String.fromCharCode(...values.slice(65, 68)).length
+            "#},
+            102,
+        ),
+    ]);
+}
+
+/// Registers a `read` function that suspends its caller, and parses `source` as a script.
+fn suspendable_script(source: &str, context: &mut Context) -> Script {
+    context
+        .register_global_callable(
+            js_string!("read"),
+            0,
+            NativeFunction::from_fn_ptr(|_, _, context| {
+                context.suspend()?;
+                Ok(JsValue::undefined())
+            }),
+        )
+        .unwrap();
+
+    Script::parse(Source::from_bytes(source), None, context).unwrap()
+}
+
+#[test]
+fn suspend_and_resume_script() {
+    let context = &mut Context::default();
+    let script = suspendable_script(
+        indoc! {r#"
+            function sum(count) {
+                let total = 0;
+                for (let i = 0; i < count; i++) {
+                    try {
+                        total += read();
+                    } finally {
+                        total *= 2;
+                    }
+                }
+                return total;
+            }
+            [sum(2), read()].join();
+        "#},
+        context,
+    );
+
+    let mut result = script.evaluate_suspendable(context).unwrap();
+    let mut value = 0;
+    while let SuspendableResult::Suspended(continuation) = result {
+        // Other code can run on the context while the script is suspended.
+        let other = context
+            .eval(Source::from_bytes("[1, 2].map((x) => x * 10).join()"))
+            .unwrap();
+        assert_eq!(other, JsValue::from(js_string!("10,20")));
+
+        value += 1;
+        result = continuation.resume(value.into(), context).unwrap();
+    }
+
+    assert_eq!(value, 3);
+    let SuspendableResult::Complete(result) = result else {
+        unreachable!("the script must be complete");
+    };
+    assert_eq!(result, JsValue::from(js_string!("8,3")));
+}
+
+#[test]
+fn throw_into_suspended_script() {
+    let context = &mut Context::default();
+    let script = suspendable_script(
+        indoc! {r#"
+            let message;
+            try {
+                read();
+            } catch (e) {
+                message = e.message;
+            }
+            message + read();
+        "#},
+        context,
+    );
+
+    let SuspendableResult::Suspended(continuation) = script.evaluate_suspendable(context).unwrap()
+    else {
+        unreachable!("the script must be suspended");
+    };
+    let error = JsNativeError::typ().with_message("failed").into();
+    let SuspendableResult::Suspended(continuation) = continuation.throw(error, context).unwrap()
+    else {
+        unreachable!("the script must be suspended");
+    };
+    let error = JsNativeError::range().with_message("uncaught").into();
+    let error = continuation.throw(error, context).unwrap_err();
+    assert_eq!(
+        error.as_native().map(|error| error.kind.clone()),
+        Some(JsNativeErrorKind::Range)
+    );
+}
+
+#[test]
+fn suspend_outside_of_suspendable_script() {
+    let context = &mut Context::default();
+    let script = suspendable_script(
+        indoc! {r#"
+            const errors = [];
+            for (const f of [() => [1].map(read), () => ({ get x() { return read(); } }).x]) {
+                try {
+                    f();
+                } catch (e) {
+                    errors.push(e.name);
+                }
+            }
+            errors.join();
+        "#},
+        context,
+    );
+
+    let SuspendableResult::Complete(result) = script.evaluate_suspendable(context).unwrap() else {
+        unreachable!("the script must be complete");
+    };
+    assert_eq!(result, JsValue::from(js_string!("TypeError,TypeError")));
+
+    let error = context.eval(Source::from_bytes("read()")).unwrap_err();
+    assert_eq!(
+        error.as_native().map(|error| error.kind.clone()),
+        Some(JsNativeErrorKind::Type)
+    );
+}
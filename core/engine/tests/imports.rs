@@ -4,8 +4,8 @@ use std::path::PathBuf;
 use std::rc::Rc;
 
 use boa_engine::builtins::promise::PromiseState;
-use boa_engine::module::SimpleModuleLoader;
-use boa_engine::{js_string, Context, JsValue, Source};
+use boa_engine::module::{ImportMap, IntegrityCheck, ModuleRequest, SimpleModuleLoader};
+use boa_engine::{js_string, Context, JsResult, JsValue, Source};
 
 /// Test that relative imports work with the simple module loader.
 #[test]
@@ -45,3 +45,161 @@ fn subdirectories() {
         }
     }
 }
+
+/// Test that the middlewares of the simple module loader process the sources of the modules in
+/// order, and can reject them.
+#[test]
+fn middlewares() {
+    fn uppercase_file1(
+        request: &ModuleRequest<'_>,
+        source: Vec<u8>,
+        _: &mut Context,
+    ) -> JsResult<Vec<u8>> {
+        if !request
+            .path()
+            .is_some_and(|path| path.ends_with("file1.js"))
+        {
+            return Ok(source);
+        }
+        let source = String::from_utf8(source).unwrap();
+        Ok(source.replace(r#""file1""#, r#""FILE1""#).into_bytes())
+    }
+
+    // FNV-1a, which is enough to tell the assets apart.
+    fn hash(source: &[u8]) -> Vec<u8> {
+        source
+            .iter()
+            .fold(0xcbf2_9ce4_8422_2325_u64, |hash, &byte| {
+                (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+            })
+            .to_le_bytes()
+            .to_vec()
+    }
+
+    let assets_dir =
+        PathBuf::from(std::env::var("CARGO_MANIFEST_DIR").unwrap()).join("tests/assets");
+    let digest = |file: &str| hash(&std::fs::read(assets_dir.join(file)).unwrap());
+
+    let load = |loader: SimpleModuleLoader| {
+        let mut context = Context::builder()
+            .module_loader(Rc::new(loader))
+            .build()
+            .unwrap();
+
+        let source = Source::from_bytes(b"export { file1 } from 'file1.js';");
+        let module = boa_engine::Module::parse(source, None, &mut context).unwrap();
+        let result = module.load_link_evaluate(&mut context);
+        context.run_jobs().unwrap();
+
+        match result.state() {
+            PromiseState::Fulfilled(_) => Ok(module
+                .namespace(&mut context)
+                .get(js_string!("file1"), &mut context)
+                .unwrap()
+                .as_callable()
+                .unwrap()
+                .call(&JsValue::undefined(), &[], &mut context)
+                .unwrap()),
+            PromiseState::Rejected(reason) => Err(reason
+                .as_object()
+                .unwrap()
+                .get(js_string!("message"), &mut context)
+                .unwrap()
+                .to_string(&mut context)
+                .unwrap()
+                .to_std_string_escaped()),
+            PromiseState::Pending => panic!("module load should have finished"),
+        }
+    };
+
+    // The integrity check runs on the sources before they are transformed.
+    let loader = SimpleModuleLoader::new(&assets_dir)
+        .unwrap()
+        .with_middleware(IntegrityCheck::new(hash).allow(digest("file1.js")))
+        .with_middleware(uppercase_file1);
+    assert_eq!(
+        load(loader).unwrap_err(),
+        "module `./dir1/file1_1.js` failed its integrity check"
+    );
+
+    let loader = SimpleModuleLoader::new(&assets_dir)
+        .unwrap()
+        .with_middleware(
+            IntegrityCheck::new(hash)
+                .allow(digest("file1.js"))
+                .allow(digest("dir1/file1_1.js"))
+                .allow(digest("dir1/file1_2.js")),
+        )
+        .with_middleware(uppercase_file1);
+    assert_eq!(
+        load(loader).unwrap(),
+        js_string!("FILE1..file1_1.file1_2").into()
+    );
+}
+
+/// Test that the import map of the context remaps the specifiers of static and dynamic imports
+/// before they reach the module loader.
+#[test]
+fn import_map() {
+    let assets_dir =
+        PathBuf::from(std::env::var("CARGO_MANIFEST_DIR").unwrap()).join("tests/assets");
+    let import_map = ImportMap::parse(
+        r#"{
+            "imports": {
+                "file1": "./file1.js",
+                "lib/": "./dir1/",
+                "blocked": null
+            }
+        }"#,
+        &assets_dir,
+    )
+    .unwrap();
+
+    let loader = Rc::new(SimpleModuleLoader::new(&assets_dir).unwrap());
+    let mut context = Context::builder()
+        .module_loader(loader.clone())
+        .build()
+        .unwrap();
+    context.set_parsed_import_map(Some(import_map));
+
+    let source = Source::from_bytes(
+        b"export { file1 } from 'file1';
+          export const file1_2 = (await import('lib/file1_2.js')).file1_2;
+          export const blocked = await import('blocked').then(() => 'loaded', (e) => e.message);",
+    );
+    let module = boa_engine::Module::parse(source, None, &mut context).unwrap();
+    let result = module.load_link_evaluate(&mut context);
+    context.run_jobs().unwrap();
+
+    match result.state() {
+        PromiseState::Fulfilled(_) => {
+            let namespace = module.namespace(&mut context);
+            let file1 = namespace
+                .get(js_string!("file1"), &mut context)
+                .unwrap()
+                .as_callable()
+                .unwrap()
+                .call(&JsValue::undefined(), &[], &mut context)
+                .unwrap();
+            assert_eq!(file1, js_string!("file1..file1_1.file1_2").into());
+
+            let file1_2 = namespace
+                .get(js_string!("file1_2"), &mut context)
+                .unwrap()
+                .as_callable()
+                .unwrap()
+                .call(&JsValue::undefined(), &[], &mut context)
+                .unwrap();
+            assert_eq!(file1_2, js_string!("file1_2").into());
+
+            assert_eq!(
+                namespace.get(js_string!("blocked"), &mut context).unwrap(),
+                js_string!("module `blocked` is blocked by the import map").into()
+            );
+        }
+        PromiseState::Rejected(reason) => {
+            panic!("Module failed to load: {}", reason.display());
+        }
+        PromiseState::Pending => panic!("module load should have finished"),
+    }
+}
@@ -2,8 +2,11 @@
 
 #![allow(unused_crate_dependencies)]
 
+use boa_engine::builtins::options::{
+    coerce_options_to_object, get_option, get_options_object, OptionType,
+};
 use boa_engine::value::TryFromJs;
-use boa_engine::{js_string, Context, JsResult, JsValue, Source};
+use boa_engine::{js_string, Context, JsNativeErrorKind, JsResult, JsValue, Source};
 use boa_string::JsString;
 
 #[test]
@@ -37,3 +40,48 @@ fn try_from_js_derive() {
         }
     );
 }
+
+#[test]
+fn option_type_derive() {
+    #[derive(Debug, OptionType, Eq, PartialEq)]
+    enum RoundingMode {
+        Ceil,
+        HalfExpand,
+        #[boa(rename = "best fit")]
+        BestFit,
+    }
+
+    let mut context = Context::default();
+    let options = context
+        .eval(Source::from_bytes(
+            br#"({ a: "ceil", b: "halfExpand", c: "best fit", d: "HalfExpand" })"#,
+        ))
+        .unwrap();
+    let options = get_options_object(&options).unwrap();
+
+    let mut get = |property| get_option::<RoundingMode>(&options, property, &mut context);
+    assert_eq!(get(js_string!("a")).unwrap(), Some(RoundingMode::Ceil));
+    assert_eq!(
+        get(js_string!("b")).unwrap(),
+        Some(RoundingMode::HalfExpand)
+    );
+    assert_eq!(get(js_string!("c")).unwrap(), Some(RoundingMode::BestFit));
+    assert_eq!(get(js_string!("e")).unwrap(), None);
+
+    let err = get(js_string!("d")).unwrap_err();
+    let err = err.as_native().unwrap();
+    assert_eq!(err.kind, JsNativeErrorKind::Range);
+    assert_eq!(
+        err.message(),
+        "provided string was not one of `ceil`, `halfExpand`, `best fit`"
+    );
+
+    let err = get_options_object(&JsValue::new(1)).unwrap_err();
+    assert_eq!(err.as_native().unwrap().kind, JsNativeErrorKind::Type);
+
+    let options = coerce_options_to_object(&JsValue::undefined(), &mut context).unwrap();
+    assert_eq!(
+        get_option::<RoundingMode>(&options, js_string!("a"), &mut context).unwrap(),
+        None
+    );
+}
@@ -1,10 +1,18 @@
 #![allow(unused_crate_dependencies, missing_docs)]
 
+use std::cell::RefCell;
 use std::rc::Rc;
 
 use boa_engine::builtins::promise::PromiseState;
-use boa_engine::module::{ModuleLoader, Referrer};
-use boa_engine::{js_string, Context, JsResult, JsString, Module, Source};
+use boa_engine::module::{ModuleLoader, ModuleState, Referrer, SyntheticModuleInitializer};
+use boa_engine::object::ObjectInitializer;
+use boa_engine::property::Attribute;
+use boa_engine::{
+    js_string, Context, JsError, JsNativeError, JsNativeErrorKind, JsResult, JsString, Module,
+    Source,
+};
+use indoc::indoc;
+use rustc_hash::FxHashMap;
 
 #[test]
 fn test_json_module_from_str() {
@@ -63,3 +71,202 @@ fn test_json_module_from_str() {
         json_string
     );
 }
+
+#[test]
+fn test_source_phase_imports() {
+    struct TestModuleLoader;
+    impl ModuleLoader for TestModuleLoader {
+        fn load_imported_module(
+            &self,
+            _referrer: Referrer,
+            specifier: JsString,
+            finish_load: Box<dyn FnOnce(JsResult<Module>, &mut Context)>,
+            context: &mut Context,
+        ) {
+            let module = match specifier.to_std_string_escaped().as_str() {
+                "wasm" => {
+                    // Modules imported in the source phase must never be evaluated.
+                    let module = Module::synthetic(
+                        &[],
+                        SyntheticModuleInitializer::from_copy_closure(|_, _| {
+                            Err(JsNativeError::typ()
+                                .with_message("module was evaluated")
+                                .into())
+                        }),
+                        None,
+                        None,
+                        context,
+                    );
+                    let source = ObjectInitializer::new(context)
+                        .property(js_string!("kind"), js_string!("source"), Attribute::all())
+                        .build();
+                    module.set_source_object(source);
+                    module
+                }
+                "plain" => {
+                    Module::parse(Source::from_bytes(b"export default 1;"), None, context).unwrap()
+                }
+                _ => unreachable!(),
+            };
+
+            finish_load(Ok(module), context);
+        }
+    }
+
+    let mut context = Context::builder()
+        .module_loader(Rc::new(TestModuleLoader))
+        .build()
+        .unwrap();
+
+    let source = Source::from_bytes(
+        b"
+        import source wasm from 'wasm';
+        export let kind = wasm.kind;
+    ",
+    );
+
+    let module = Module::parse(source, None, &mut context).unwrap();
+    let promise = module.load_link_evaluate(&mut context);
+    context.run_jobs().unwrap();
+
+    if let PromiseState::Rejected(e) = promise.state() {
+        panic!("Unexpected error: {:?}", e.to_string(&mut context).unwrap());
+    }
+
+    let kind = module
+        .namespace(&mut context)
+        .get(js_string!("kind"), &mut context)
+        .unwrap();
+    assert_eq!(kind, js_string!("source").into());
+
+    // Source text modules don't have a source phase representation.
+    let source = Source::from_bytes(b"import source plain from 'plain';");
+    let module = Module::parse(source, None, &mut context).unwrap();
+    let promise = module.load_link_evaluate(&mut context);
+    context.run_jobs().unwrap();
+
+    let PromiseState::Rejected(e) = promise.state() else {
+        panic!("importing the source of a source text module must fail");
+    };
+    let err = JsError::from_opaque(e).try_native(&mut context).unwrap();
+    assert_eq!(err.kind, JsNativeErrorKind::Syntax);
+}
+
+#[test]
+fn test_deferred_imports() {
+    struct TestModuleLoader;
+    impl ModuleLoader for TestModuleLoader {
+        fn load_imported_module(
+            &self,
+            _referrer: Referrer,
+            specifier: JsString,
+            finish_load: Box<dyn FnOnce(JsResult<Module>, &mut Context)>,
+            context: &mut Context,
+        ) {
+            assert_eq!(specifier.to_std_string_escaped(), "deferred");
+
+            let source = Source::from_bytes(
+                b"
+                globalThis.evaluated = true;
+                export let value = 42;
+            ",
+            );
+            finish_load(Module::parse(source, None, context), context);
+        }
+    }
+
+    let mut context = Context::builder()
+        .module_loader(Rc::new(TestModuleLoader))
+        .build()
+        .unwrap();
+
+    let source = Source::from_bytes(
+        b"
+        import defer * as ns from 'deferred';
+        export const tag = ns[Symbol.toStringTag];
+        export const then = ns.then;
+        export const before = globalThis.evaluated;
+        export const value = ns.value;
+        export const after = globalThis.evaluated;
+    ",
+    );
+
+    let module = Module::parse(source, None, &mut context).unwrap();
+    let promise = module.load_link_evaluate(&mut context);
+    context.run_jobs().unwrap();
+
+    if let PromiseState::Rejected(e) = promise.state() {
+        panic!("Unexpected error: {:?}", e.to_string(&mut context).unwrap());
+    }
+
+    let namespace = module.namespace(&mut context);
+    let mut get = |name| namespace.get(name, &mut context).unwrap();
+
+    assert_eq!(get(js_string!("tag")), js_string!("Deferred Module").into());
+    assert!(get(js_string!("then")).is_undefined());
+    assert!(get(js_string!("before")).is_undefined());
+    assert_eq!(get(js_string!("value")), 42.into());
+    assert_eq!(get(js_string!("after")), true.into());
+}
+
+#[test]
+fn test_module_graph() {
+    #[derive(Default)]
+    struct TestModuleLoader(RefCell<FxHashMap<JsString, Module>>);
+    impl ModuleLoader for TestModuleLoader {
+        fn load_imported_module(
+            &self,
+            _referrer: Referrer,
+            specifier: JsString,
+            finish_load: Box<dyn FnOnce(JsResult<Module>, &mut Context)>,
+            context: &mut Context,
+        ) {
+            let module = self.0.borrow().get(&specifier).cloned();
+            let module = module.unwrap_or_else(|| {
+                let source = match specifier.to_std_string_escaped().as_str() {
+                    "a" => "import 'b'; export const a = 1;",
+                    "b" => "import 'a'; import 'c';",
+                    "c" => "export default 1;",
+                    _ => unreachable!(),
+                };
+                let module = Module::parse(Source::from_bytes(source), None, context).unwrap();
+                self.0.borrow_mut().insert(specifier, module.clone());
+                module
+            });
+
+            finish_load(Ok(module), context);
+        }
+    }
+
+    let mut context = Context::builder()
+        .module_loader(Rc::new(TestModuleLoader::default()))
+        .build()
+        .unwrap();
+
+    let source = Source::from_bytes(b"import 'a'; import 'c';");
+    let module = Module::parse(source, None, &mut context).unwrap();
+    assert_eq!(module.graph().root().state(), ModuleState::Unlinked);
+
+    let promise = module.load_link_evaluate(&mut context);
+    context.run_jobs().unwrap();
+    assert!(matches!(promise.state(), PromiseState::Fulfilled(_)));
+
+    let graph = module.graph();
+    assert_eq!(graph.nodes().len(), 4);
+    assert_eq!(graph.cycles(), [vec![1, 3]]);
+    assert_eq!(
+        graph.to_string(),
+        indoc! {r#"
+            [0] <anonymous> (evaluated)
+                "a" -> [1]
+                "c" -> [2]
+            [1] <anonymous> (evaluated)
+                "b" -> [3]
+            [2] <anonymous> (evaluated)
+            [3] <anonymous> (evaluated)
+                "a" -> [1]
+                "c" -> [2]
+            cycle: [1], [3]
+        "#}
+    );
+}
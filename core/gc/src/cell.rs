@@ -182,6 +182,9 @@ impl<T: Trace + ?Sized> GcRefCell<T> {
         if self.flags.get().borrowed() != BorrowState::Unused {
             return Err(BorrowMutError);
         }
+
+        // SAFETY: The value isn't borrowed, so it can be traced.
+        crate::write_barrier(unsafe { &*self.cell.get() });
         self.flags.set(self.flags.get().set_writing());
 
         // SAFETY: This is safe as the value is rooted if it was not previously rooted,
@@ -129,6 +129,10 @@ pub(crate) trait ErasedEphemeronBox {
     /// Runs the finalization logic of the `EphemeronBox`'s held value, if the key is still live,
     /// and clears its contents.
     fn finalize_and_clear(&self);
+
+    /// Clears the contents of the `EphemeronBox` if its key is a young object that wasn't marked
+    /// by a minor collection, before the key gets swept.
+    fn clear_if_young_key_is_dead(&self);
 }
 
 impl<K: Trace + ?Sized, V: Trace> ErasedEphemeronBox for EphemeronBox<K, V> {
@@ -178,4 +182,17 @@ impl<K: Trace + ?Sized, V: Trace> ErasedEphemeronBox for EphemeronBox<K, V> {
         // there are no remaining references to the inner data.
         unsafe { (*self.data.get()).take() };
     }
+
+    fn clear_if_young_key_is_dead(&self) {
+        // SAFETY: the garbage collector ensures the ephemeron doesn't mutate until
+        // finalization, and that `key` is valid until it is swept.
+        let is_key_dead = unsafe {
+            self.key()
+                .is_some_and(|key| key.header.is_young() && !key.is_marked())
+        };
+
+        if is_key_dead {
+            self.finalize_and_clear();
+        }
+    }
 }
@@ -1,3 +1,5 @@
+use std::alloc::Layout;
+
 use crate::Trace;
 
 use super::{vtable_of, DropFn, GcHeader, RunFinalizerFn, TraceFn, TraceNonRootsFn, VTable};
@@ -79,7 +81,7 @@ impl<T: Trace + ?Sized> GcBox<T> {
         self.vtable.drop_fn()
     }
 
-    pub(crate) fn size(&self) -> usize {
-        self.vtable.size()
+    pub(crate) fn layout(&self) -> Layout {
+        self.vtable.layout()
     }
 }
@@ -1,14 +1,15 @@
 use std::{cell::Cell, fmt};
 
 const MARK_MASK: u32 = 1 << (u32::BITS - 1);
-const NON_ROOTS_MASK: u32 = !MARK_MASK;
+const YOUNG_MASK: u32 = 1 << (u32::BITS - 2);
+const NON_ROOTS_MASK: u32 = !(MARK_MASK | YOUNG_MASK);
 const NON_ROOTS_MAX: u32 = NON_ROOTS_MASK;
 
 /// The `Gcheader` contains the `GcBox`'s and `EphemeronBox`'s current state for the `Collector`'s
 /// Mark/Sweep as well as a pointer to the next node in the heap.
 ///
 /// `ref_count` is the number of Gc instances, and `non_root_count` is the number of
-/// Gc instances in the heap. `non_root_count` also includes Mark Flag bit.
+/// Gc instances in the heap. `non_root_count` also includes the Mark and Young Flag bits.
 ///
 /// The next node is set by the `Allocator` during initialization and by the
 /// `Collector` during the sweep phase.
@@ -37,9 +38,16 @@ impl GcHeader {
     }
 
     /// Increments [`GcHeader`]'s non-roots count.
+    ///
+    /// A minor collection only counts the handles from young objects to young objects, so the
+    /// count of an old object is left untouched while it runs.
     pub(crate) fn inc_non_root_count(&self) {
         let non_root_count = self.non_root_count.get();
 
+        if non_root_count & YOUNG_MASK == 0 && crate::in_minor_collection() {
+            return;
+        }
+
         if (non_root_count & NON_ROOTS_MASK) < NON_ROOTS_MAX {
             self.non_root_count.set(non_root_count.wrapping_add(1));
         } else {
@@ -91,12 +99,30 @@ impl GcHeader {
         self.non_root_count
             .set(self.non_root_count.get() & !MARK_MASK);
     }
+
+    /// Returns a bool for whether [`GcHeader`] belongs to the young generation.
+    pub(crate) fn is_young(&self) -> bool {
+        self.non_root_count.get() & YOUNG_MASK != 0
+    }
+
+    /// Moves [`GcHeader`] to the young generation.
+    pub(crate) fn set_young(&self) {
+        self.non_root_count
+            .set(self.non_root_count.get() | YOUNG_MASK);
+    }
+
+    /// Moves [`GcHeader`] to the old generation.
+    pub(crate) fn promote(&self) {
+        self.non_root_count
+            .set(self.non_root_count.get() & !YOUNG_MASK);
+    }
 }
 
 impl fmt::Debug for GcHeader {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("GcHeader")
             .field("marked", &self.is_marked())
+            .field("young", &self.is_young())
             .field("ref_count", &self.ref_count.get())
             .field("non_root_count", &self.non_root_count())
             .finish_non_exhaustive()
@@ -1,11 +1,13 @@
 mod ephemeron_box;
 mod gc_box;
 mod gc_header;
+mod pool;
 mod vtable;
 mod weak_map_box;
 
 pub(crate) use self::ephemeron_box::{EphemeronBox, ErasedEphemeronBox};
 pub(crate) use self::gc_header::GcHeader;
+pub(crate) use self::pool::SizeClassPools;
 pub(crate) use self::weak_map_box::{ErasedWeakMapBox, WeakMapBox};
 pub(crate) use vtable::{vtable_of, DropFn, RunFinalizerFn, TraceFn, TraceNonRootsFn, VTable};
 
@@ -0,0 +1,116 @@
+use std::{
+    alloc::{self, Layout},
+    ptr::NonNull,
+};
+
+/// The granularity of the sizes of the pooled allocations.
+const SIZE_CLASS_GRANULARITY: usize = 16;
+
+/// The number of size classes, which cover the allocations of up to 256 bytes.
+const SIZE_CLASSES: usize = 16;
+
+/// The maximum number of free allocations kept by a size class.
+const MAX_POOLED: usize = 4096;
+
+/// Pools of freed [`GcBox`][crate::GcBox] allocations, sorted in size classes.
+///
+/// Most of the allocated values are small objects of a handful of types, so the small
+/// allocations are rounded up to a size class, and their memory is reused by the next
+/// allocations of the same size class instead of being given back to the system allocator.
+#[derive(Debug, Default)]
+pub(crate) struct SizeClassPools {
+    free: [Vec<NonNull<u8>>; SIZE_CLASSES],
+    pooled_bytes: usize,
+}
+
+impl SizeClassPools {
+    /// Returns the size class of `layout`, or `None` if it is too big to be pooled.
+    fn size_class(layout: Layout) -> Option<usize> {
+        if layout.align() > SIZE_CLASS_GRANULARITY {
+            return None;
+        }
+
+        let class = layout
+            .size()
+            .div_ceil(SIZE_CLASS_GRANULARITY)
+            .checked_sub(1)?;
+        (class < SIZE_CLASSES).then_some(class)
+    }
+
+    /// Returns the layout of the allocations of the size class `class`.
+    fn class_layout(class: usize) -> Layout {
+        Layout::from_size_align((class + 1) * SIZE_CLASS_GRANULARITY, SIZE_CLASS_GRANULARITY)
+            .expect("size classes must have a valid layout")
+    }
+
+    /// Returns the number of bytes allocated for a value with `layout`, which is rounded up to
+    /// its size class if it has one.
+    pub(crate) fn allocation_size(layout: Layout) -> usize {
+        Self::size_class(layout).map_or(layout.size(), |class| Self::class_layout(class).size())
+    }
+
+    /// Returns the number of bytes of the free allocations kept by the pools.
+    pub(crate) const fn pooled_bytes(&self) -> usize {
+        self.pooled_bytes
+    }
+
+    /// Allocates memory for a value with `layout`.
+    ///
+    /// `layout` must not be zero-sized.
+    pub(crate) fn alloc(&mut self, layout: Layout) -> NonNull<u8> {
+        debug_assert_ne!(layout.size(), 0, "cannot allocate zero-sized values");
+
+        let layout = match Self::size_class(layout) {
+            Some(class) => {
+                let class_layout = Self::class_layout(class);
+                if let Some(ptr) = self.free[class].pop() {
+                    self.pooled_bytes -= class_layout.size();
+                    return ptr;
+                }
+                class_layout
+            }
+            None => layout,
+        };
+
+        // SAFETY: The layout is not zero-sized per the caller's guarantee.
+        let ptr = unsafe { alloc::alloc(layout) };
+        NonNull::new(ptr).unwrap_or_else(|| alloc::handle_alloc_error(layout))
+    }
+
+    /// Frees the memory of a value with `layout`.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must have been returned by [`SizeClassPools::alloc`] for the same `layout`, and must
+    /// not be used after this call.
+    pub(crate) unsafe fn dealloc(&mut self, ptr: NonNull<u8>, layout: Layout) {
+        let layout = match Self::size_class(layout) {
+            Some(class) => {
+                let class_layout = Self::class_layout(class);
+                let pool = &mut self.free[class];
+                if pool.len() < MAX_POOLED {
+                    pool.push(ptr);
+                    self.pooled_bytes += class_layout.size();
+                    return;
+                }
+                class_layout
+            }
+            None => layout,
+        };
+
+        // SAFETY: The caller must ensure `ptr` was allocated with `layout`.
+        unsafe { alloc::dealloc(ptr.as_ptr(), layout) }
+    }
+}
+
+impl Drop for SizeClassPools {
+    fn drop(&mut self) {
+        for (class, pool) in self.free.iter_mut().enumerate() {
+            let layout = Self::class_layout(class);
+            for ptr in pool.drain(..) {
+                // SAFETY: All the pooled allocations of a size class have the layout of the class.
+                unsafe { alloc::dealloc(ptr.as_ptr(), layout) }
+            }
+        }
+    }
+}
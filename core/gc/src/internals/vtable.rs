@@ -1,3 +1,5 @@
+use std::{alloc::Layout, ptr};
+
 use crate::{GcBox, GcErasedPointer, Trace, Tracer};
 
 // Workaround: https://users.rust-lang.org/t/custom-vtables-with-integers/78508
@@ -38,7 +40,8 @@ pub(crate) const fn vtable_of<T: Trace + 'static>() -> &'static VTable {
             let this = this.cast::<GcBox<Self>>();
 
             // SAFETY: The caller must ensure the erased pointer is not droped or deallocated.
-            let _value = unsafe { Box::from_raw(this.as_ptr()) };
+            // The memory is freed afterwards by the collector.
+            unsafe { ptr::drop_in_place(this.as_ptr()) };
         }
     }
 
@@ -48,7 +51,7 @@ pub(crate) const fn vtable_of<T: Trace + 'static>() -> &'static VTable {
             trace_non_roots_fn: T::trace_non_roots_fn,
             run_finalizer_fn: T::run_finalizer_fn,
             drop_fn: T::drop_fn,
-            size: size_of::<GcBox<T>>(),
+            layout: Layout::new::<GcBox<T>>(),
         };
     }
 
@@ -66,7 +69,7 @@ pub(crate) struct VTable {
     trace_non_roots_fn: TraceNonRootsFn,
     run_finalizer_fn: RunFinalizerFn,
     drop_fn: DropFn,
-    size: usize,
+    layout: Layout,
 }
 
 impl VTable {
@@ -86,7 +89,7 @@ impl VTable {
         self.drop_fn
     }
 
-    pub(crate) fn size(&self) -> usize {
-        self.size
+    pub(crate) fn layout(&self) -> Layout {
+        self.layout
     }
 }
@@ -1,8 +1,11 @@
 //! Boa's **`boa_gc`** crate implements a garbage collector.
 //!
 //! # Crate Overview
-//! **`boa_gc`** is a mark-sweep garbage collector that implements a [`Trace`] and [`Finalize`] trait
-//! for garbage collected values.
+//! **`boa_gc`** is a generational mark-sweep garbage collector that implements a [`Trace`] and
+//! [`Finalize`] trait for garbage collected values.
+//!
+//! The collector can be tuned for each thread with [`set_tuning`], which can also make it mark
+//! the old generation incrementally, to shorten the pauses of major collections.
 #![doc = include_str!("../ABOUT.md")]
 #![doc(
     html_logo_url = "https://raw.githubusercontent.com/boa-dev/boa/main/assets/logo_black.svg",
@@ -24,12 +27,14 @@ mod trace;
 pub(crate) mod internals;
 
 use boa_profiler::Profiler;
-use internals::{EphemeronBox, ErasedEphemeronBox, ErasedWeakMapBox, WeakMapBox};
+use internals::{EphemeronBox, ErasedEphemeronBox, ErasedWeakMapBox, SizeClassPools, WeakMapBox};
 use pointers::{NonTraceable, RawWeakMap};
 use std::{
+    alloc::Layout,
     cell::{Cell, RefCell},
     mem,
     ptr::NonNull,
+    time::{Duration, Instant},
 };
 
 pub use crate::trace::{Finalize, Trace, Tracer};
@@ -43,12 +48,17 @@ type EphemeronPointer = NonNull<dyn ErasedEphemeronBox>;
 type ErasedWeakMapBoxPointer = NonNull<dyn ErasedWeakMapBox>;
 
 thread_local!(static GC_DROPPING: Cell<bool> = const { Cell::new(false) });
+thread_local!(static GC_MINOR: Cell<bool> = const { Cell::new(false) });
+thread_local!(static GC_MARKING: Cell<bool> = const { Cell::new(false) });
 thread_local!(static BOA_GC: RefCell<BoaGc> = RefCell::new( BoaGc {
     config: GcConfig::default(),
     runtime: GcRuntimeData::default(),
+    young: Vec::default(),
     strongs: Vec::default(),
+    pools: SizeClassPools::default(),
     weaks: Vec::default(),
     weak_maps: Vec::default(),
+    marking: None,
 }));
 
 #[derive(Debug, Clone, Copy)]
@@ -57,6 +67,7 @@ struct GcConfig {
     threshold: usize,
     /// The percentage of used space at which the garbage collector will trigger a collection.
     used_space_percentage: usize,
+    tuning: GcTuning,
 }
 
 // Setting the defaults to an arbitrary value currently.
@@ -68,27 +79,151 @@ impl Default for GcConfig {
             // Start at 1MB, the nursary size for V8 is ~1-8MB and SM can be up to 16MB
             threshold: 1_048_576,
             used_space_percentage: 70,
+            tuning: GcTuning::default(),
+        }
+    }
+}
+
+/// The parameters of the garbage collector of the current thread.
+///
+/// See [`set_tuning`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct GcTuning {
+    /// The number of bytes allocated in the young generation after which a minor collection runs.
+    pub nursery_size: usize,
+
+    /// Marks the heap incrementally, a few values on each allocation, instead of all at once.
+    ///
+    /// See [`GcTuning::with_incremental_marking`].
+    incremental_marking: bool,
+
+    /// The number of values marked on each allocation while the heap is marked incrementally.
+    pub mark_step: usize,
+}
+
+impl GcTuning {
+    /// Returns `true` if the heap is marked incrementally.
+    #[must_use]
+    pub const fn incremental_marking(&self) -> bool {
+        self.incremental_marking
+    }
+
+    /// Marks the heap incrementally, a few values on each allocation, instead of all at once.
+    ///
+    /// This shortens the pauses of major collections, since only finding the roots, finalizing and
+    /// sweeping the unreachable values remain in a single pause. The heap is marked as it was when
+    /// the marking started, so some of the values that become unreachable while it runs are only
+    /// collected by the next major collection.
+    ///
+    /// The values moved out of the heap while it is being marked go through a write barrier,
+    /// which marks them first: [`GcRefCell::borrow_mut`] for the values of a [`GcRefCell`],
+    /// [`take_traced`] for the values of a [`Cell`], and [`Ephemeron::new`] for the values moved
+    /// into an ephemeron.
+    #[must_use]
+    pub const fn with_incremental_marking(mut self, incremental_marking: bool) -> Self {
+        self.incremental_marking = incremental_marking;
+        self
+    }
+}
+
+impl Default for GcTuning {
+    fn default() -> Self {
+        Self {
+            nursery_size: 262_144,
+            incremental_marking: false,
+            mark_step: 1024,
         }
     }
 }
 
 #[derive(Default, Debug, Clone, Copy)]
 struct GcRuntimeData {
-    collections: usize,
+    statistics: GcStatistics,
     bytes_allocated: usize,
+    young_bytes_allocated: usize,
+}
+
+/// The pause times of a kind of garbage collection.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PauseTimes {
+    /// The number of collections.
+    pub count: usize,
+    /// The total duration of the collections.
+    pub total: Duration,
+    /// The duration of the longest collection.
+    pub max: Duration,
+}
+
+impl PauseTimes {
+    /// Returns the mean duration of the collections, or zero if there were none.
+    #[must_use]
+    pub fn mean(&self) -> Duration {
+        u32::try_from(self.count)
+            .ok()
+            .and_then(|count| self.total.checked_div(count))
+            .unwrap_or_default()
+    }
+
+    /// Records a collection that started at `start`.
+    fn record(&mut self, start: Option<Instant>) {
+        let pause = start.map(|start| start.elapsed()).unwrap_or_default();
+        self.count += 1;
+        self.total += pause;
+        self.max = self.max.max(pause);
+    }
+}
+
+/// Statistics about the garbage collections of the current thread.
+///
+/// The pause times are always zero on targets without a clock, like `wasm32-unknown-unknown`.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GcStatistics {
+    /// The minor collections, which only collect the young generation.
+    pub minor: PauseTimes,
+    /// The major collections, which collect the whole heap.
+    ///
+    /// If the heap is marked incrementally, these are the pauses that finish the collections.
+    pub major: PauseTimes,
+    /// The steps of the incremental marking of the heap, including the pauses that start it.
+    pub incremental: PauseTimes,
+    /// The number of values that were moved from the young generation to the old generation.
+    pub promoted: usize,
+    /// The number of values that were allocated, including weak values.
+    pub allocations: usize,
+}
+
+/// Returns the current time, or `None` if the target doesn't have a clock.
+fn now() -> Option<Instant> {
+    // `Instant::now` panics on `wasm32-unknown-unknown`.
+    let has_clock = !cfg!(all(
+        target_family = "wasm",
+        not(any(target_os = "emscripten", target_os = "wasi"))
+    ));
+    has_clock.then(Instant::now)
 }
 
 #[derive(Debug)]
 struct BoaGc {
     config: GcConfig,
     runtime: GcRuntimeData,
+    /// The values allocated since the last collection.
+    ///
+    /// Most values die young, so they are first collected by minor collections, which don't
+    /// have to walk the rest of the heap. The values that survive are moved to `strongs`.
+    young: Vec<GcErasedPointer>,
     strongs: Vec<GcErasedPointer>,
+    /// The memory of the swept values, reused by the next allocations.
+    pools: SizeClassPools,
     weaks: Vec<EphemeronPointer>,
     weak_maps: Vec<ErasedWeakMapBoxPointer>,
+    /// The nodes that remain to be traced, if the heap is being marked incrementally.
+    marking: Option<Tracer>,
 }
 
 impl Drop for BoaGc {
     fn drop(&mut self) {
+        GC_MARKING.with(|marking| marking.set(false));
         Collector::dump(self);
     }
 }
@@ -114,6 +249,72 @@ impl Drop for DropGuard {
     }
 }
 
+/// `MinorGuard` flags whether the Collector is currently counting the non-root handles of a minor
+/// collection.
+///
+/// While the `MinorGuard` is active, only the handles to young values are counted.
+#[derive(Debug, Clone)]
+struct MinorGuard;
+
+impl MinorGuard {
+    fn new() -> Self {
+        GC_MINOR.with(|minor| minor.set(true));
+        Self
+    }
+}
+
+impl Drop for MinorGuard {
+    fn drop(&mut self) {
+        GC_MINOR.with(|minor| minor.set(false));
+    }
+}
+
+/// Returns `true` if the non-root handles of a minor collection are being counted.
+pub(crate) fn in_minor_collection() -> bool {
+    GC_MINOR.with(Cell::get)
+}
+
+/// Takes the value out of a traced `cell`, marking it first if the heap is being marked
+/// incrementally.
+///
+/// [`GcRefCell::borrow_mut`] has its own write barrier, but the collector can't see the values
+/// taken out of a [`Cell`], so the traced values kept in one must be taken with this function
+/// instead of [`Cell::take`].
+#[inline]
+pub fn take_traced<T: Trace>(cell: &Cell<Option<T>>) -> Option<T> {
+    write_barrier(cell);
+    cell.take()
+}
+
+/// Marks the values reachable from `value` before it is mutated, if the heap is being marked
+/// incrementally.
+///
+/// The incremental marking traces the heap as it was when it started, so a pointer that is
+/// removed from a value that wasn't traced yet must be marked before it is lost. Pointers that
+/// are copied don't need a barrier: the values allocated while marking are kept alive, and the
+/// other values have an extra handle, which makes them roots when the marking finishes.
+#[inline]
+pub(crate) fn write_barrier<T: Trace + ?Sized>(value: &T) {
+    if GC_MARKING.with(Cell::get) {
+        mark_before_write(value);
+    }
+}
+
+#[cold]
+fn mark_before_write<T: Trace + ?Sized>(value: &T) {
+    BOA_GC.with(|current| {
+        // The collector itself doesn't mutate the heap.
+        let Ok(mut gc) = current.try_borrow_mut() else {
+            return;
+        };
+
+        if let Some(tracer) = &mut gc.marking {
+            // SAFETY: Tracing only queues the nodes, which are traced by the next marking step.
+            unsafe { value.trace(tracer) };
+        }
+    });
+}
+
 /// Returns `true` if it is safe for a type to run [`Finalize::finalize`].
 #[must_use]
 #[inline]
@@ -131,17 +332,22 @@ impl Allocator {
     /// Allocate a new garbage collected value to the Garbage Collector's heap.
     fn alloc_gc<T: Trace>(value: GcBox<T>) -> NonNull<GcBox<T>> {
         let _timer = Profiler::global().start_event("New GcBox", "BoaAlloc");
-        let element_size = size_of_val::<GcBox<T>>(&value);
+        let layout = Layout::new::<GcBox<T>>();
+        let element_size = SizeClassPools::allocation_size(layout);
         BOA_GC.with(|st| {
             let mut gc = st.borrow_mut();
 
             Self::manage_state(&mut gc);
-            // Safety: value cannot be a null pointer, since `Box` cannot return null pointers.
-            let ptr = unsafe { NonNull::new_unchecked(Box::into_raw(Box::new(value))) };
+            value.header.set_young();
+            let ptr = gc.pools.alloc(layout).cast::<GcBox<T>>();
+            // SAFETY: The memory was just allocated with the layout of `GcBox<T>`.
+            unsafe { ptr.as_ptr().write(value) };
             let erased: NonNull<GcBox<NonTraceable>> = ptr.cast();
 
-            gc.strongs.push(erased);
+            gc.young.push(erased);
             gc.runtime.bytes_allocated += element_size;
+            gc.runtime.statistics.allocations += 1;
+            gc.runtime.young_bytes_allocated += element_size;
 
             ptr
         })
@@ -162,6 +368,7 @@ impl Allocator {
 
             gc.weaks.push(erased);
             gc.runtime.bytes_allocated += element_size;
+            gc.runtime.statistics.allocations += 1;
 
             ptr
         })
@@ -191,19 +398,42 @@ impl Allocator {
     }
 
     fn manage_state(gc: &mut BoaGc) {
-        if gc.runtime.bytes_allocated > gc.config.threshold {
-            Collector::collect(gc);
-
-            // Post collection check
-            // If the allocated bytes are still above the threshold, increase the threshold.
-            if gc.runtime.bytes_allocated
-                > gc.config.threshold / 100 * gc.config.used_space_percentage
-            {
-                gc.config.threshold =
-                    gc.runtime.bytes_allocated / gc.config.used_space_percentage * 100;
+        if gc.marking.is_some() {
+            // Minor collections don't run while the heap is being marked, since the marking
+            // could have marked some young nodes.
+            let budget = gc.config.tuning.mark_step;
+            Collector::mark_step(gc, budget);
+
+            if gc.marking.is_none() {
+                Self::update_threshold(gc);
+            }
+        } else if Self::heap_size(gc) > gc.config.threshold {
+            if gc.config.tuning.incremental_marking {
+                Collector::start_marking(gc);
+            } else {
+                Collector::collect(gc);
+                Self::update_threshold(gc);
             }
+        } else if gc.runtime.young_bytes_allocated > gc.config.tuning.nursery_size {
+            Collector::collect_young(gc);
         }
     }
+
+    /// Post collection check
+    ///
+    /// If the allocated bytes are still above the threshold, increase the threshold.
+    fn update_threshold(gc: &mut BoaGc) {
+        let heap_size = Self::heap_size(gc);
+        if heap_size > gc.config.threshold / 100 * gc.config.used_space_percentage {
+            gc.config.threshold = heap_size / gc.config.used_space_percentage * 100;
+        }
+    }
+
+    /// Returns the number of bytes held by the heap, which includes the free allocations kept
+    /// by the pools, since they are not given back to the system allocator.
+    fn heap_size(gc: &BoaGc) -> usize {
+        gc.runtime.bytes_allocated + gc.pools.pooled_bytes()
+    }
 }
 
 struct Unreachables {
@@ -223,19 +453,162 @@ struct Unreachables {
 /// A better approach in a more concurrent structure may be to reorder.
 ///
 /// Mark -> Sweep -> Finalize
+///
+/// The heap is split in two generations. A minor collection runs these phases on the young
+/// generation only, treating every young node that is referenced from the old generation as a
+/// root. Since the old nodes are skipped when counting the non-root handles, the handles from
+/// the old generation are found with the reference counts, so storing a young node in an old one
+/// doesn't need a write barrier.
+///
+/// The marking of a major collection can also run incrementally. The roots are counted in a
+/// single pause, like for a full collection, and the nodes are then traced a few at a time on
+/// each allocation. [`GcRefCell::borrow_mut`] marks the contents of a cell before they can be
+/// removed from it, so every node that was reachable when the marking started gets marked.
+/// Once there are no more nodes to trace, the collection finishes in a single pause, like a full
+/// collection whose roots were already traced.
 struct Collector;
 
 impl Collector {
+    /// Run a collection on the young generation.
+    fn collect_young(gc: &mut BoaGc) {
+        let _timer = Profiler::global().start_event("Gc Minor Collection", "gc");
+        let start = now();
+
+        {
+            let _guard = MinorGuard::new();
+            Self::trace_non_roots_strong(&gc.young);
+        }
+
+        let mut tracer = Tracer::new_minor();
+
+        let unreachables = Self::mark_heap(&mut tracer, &gc.young, &[], &[]);
+
+        assert!(tracer.is_empty(), "The queue should be empty");
+
+        if !unreachables.strong.is_empty() {
+            // SAFETY: All passed pointers are valid, since we won't deallocate until `Self::sweep`.
+            unsafe { Self::finalize(unreachables) };
+
+            let _final_unreachables = Self::mark_heap(&mut tracer, &gc.young, &[], &[]);
+        }
+
+        // The ephemerons with a dead key have to be cleared before the key is swept.
+        for eph in &gc.weaks {
+            // SAFETY: node must be valid as this phase cannot drop any node.
+            let eph_ref = unsafe { eph.as_ref() };
+            eph_ref.clear_if_young_key_is_dead();
+        }
+
+        // SAFETY: The young generation only contains valid nodes per the invariants of our GC.
+        unsafe {
+            Self::sweep(
+                &mut gc.young,
+                &mut Vec::new(),
+                &mut gc.runtime.bytes_allocated,
+                &mut gc.pools,
+            );
+        }
+
+        Self::promote(gc);
+        gc.runtime.statistics.minor.record(start);
+    }
+
+    /// Moves all the nodes of the young generation to the old generation.
+    fn promote(gc: &mut BoaGc) {
+        for node in &gc.young {
+            // SAFETY: node must be valid as this phase cannot drop any node.
+            unsafe { node.as_ref() }.header.promote();
+        }
+
+        gc.runtime.statistics.promoted += gc.young.len();
+        gc.runtime.young_bytes_allocated = 0;
+        gc.strongs.append(&mut gc.young);
+    }
+
     /// Run a collection on the full heap.
     fn collect(gc: &mut BoaGc) {
+        // The values that became unreachable while the heap was being marked incrementally
+        // would survive its collection, so a new one has to run after it.
+        if gc.marking.is_some() {
+            Self::finish_marking(gc);
+        }
+
         let _timer = Profiler::global().start_event("Gc Full Collection", "gc");
-        gc.runtime.collections += 1;
+        let start = now();
+
+        Self::promote(gc);
+        Self::trace_non_roots(gc);
+
+        let mut tracer = Tracer::new();
+        Self::collect_unmarked(gc, &mut tracer);
+
+        gc.runtime.statistics.major.record(start);
+    }
+
+    /// Starts marking the heap incrementally.
+    fn start_marking(gc: &mut BoaGc) {
+        let _timer = Profiler::global().start_event("Gc Start Marking", "gc");
+        let start = now();
 
+        Self::promote(gc);
         Self::trace_non_roots(gc);
 
         let mut tracer = Tracer::new();
+        for node in &gc.strongs {
+            // SAFETY: node must be valid as this phase cannot drop any node.
+            if unsafe { node.as_ref() }.is_rooted() {
+                tracer.enqueue(*node);
+            }
+        }
+
+        gc.marking = Some(tracer);
+        GC_MARKING.with(|marking| marking.set(true));
+
+        gc.runtime.statistics.incremental.record(start);
+    }
+
+    /// Marks up to `budget` nodes of the heap, and finishes the collection if all the reachable
+    /// nodes are marked.
+    fn mark_step(gc: &mut BoaGc, budget: usize) {
+        let Some(tracer) = &mut gc.marking else {
+            return;
+        };
 
-        let unreachables = Self::mark_heap(&mut tracer, &gc.strongs, &gc.weaks, &gc.weak_maps);
+        let _timer = Profiler::global().start_event("Gc Marking Step", "gc");
+        let start = now();
+
+        // SAFETY: No node can be dropped until the marking finishes.
+        let finished = unsafe { tracer.trace_budget(budget) };
+
+        gc.runtime.statistics.incremental.record(start);
+
+        if finished {
+            Self::finish_marking(gc);
+        }
+    }
+
+    /// Finishes the incremental marking of the heap, and collects the unreachable nodes.
+    fn finish_marking(gc: &mut BoaGc) {
+        let Some(mut tracer) = gc.marking.take() else {
+            return;
+        };
+        GC_MARKING.with(|marking| marking.set(false));
+
+        let _timer = Profiler::global().start_event("Gc Finish Marking", "gc");
+        let start = now();
+
+        // The handles to the nodes allocated while marking weren't counted, so the ones that are
+        // still referenced are roots.
+        Self::promote(gc);
+        Self::collect_unmarked(gc, &mut tracer);
+
+        gc.runtime.statistics.major.record(start);
+    }
+
+    /// Marks the rest of the heap with `tracer`, and then finalizes and sweeps the unreachable
+    /// nodes.
+    fn collect_unmarked(gc: &mut BoaGc, tracer: &mut Tracer) {
+        let unreachables = Self::mark_heap(tracer, &gc.strongs, &gc.weaks, &gc.weak_maps);
 
         assert!(tracer.is_empty(), "The queue should be empty");
 
@@ -247,7 +620,7 @@ impl Collector {
 
             // Reuse the tracer's already allocated capacity.
             let _final_unreachables =
-                Self::mark_heap(&mut tracer, &gc.strongs, &gc.weaks, &gc.weak_maps);
+                Self::mark_heap(tracer, &gc.strongs, &gc.weaks, &gc.weak_maps);
         }
 
         // SAFETY: The head of our linked list is always valid per the invariants of our GC.
@@ -256,6 +629,7 @@ impl Collector {
                 &mut gc.strongs,
                 &mut gc.weaks,
                 &mut gc.runtime.bytes_allocated,
+                &mut gc.pools,
             );
         }
 
@@ -284,9 +658,19 @@ impl Collector {
     }
 
     fn trace_non_roots(gc: &BoaGc) {
+        Self::trace_non_roots_strong(&gc.strongs);
+
+        for eph in &gc.weaks {
+            // SAFETY: node must be valid as this phase cannot drop any node.
+            let eph_ref = unsafe { eph.as_ref() };
+            eph_ref.trace_non_roots();
+        }
+    }
+
+    fn trace_non_roots_strong(strongs: &[GcErasedPointer]) {
         // Count all the handles located in GC heap.
         // Then, we can find whether there is a reference from other places, and they are the roots.
-        for node in &gc.strongs {
+        for node in strongs {
             // SAFETY: node must be valid as this phase cannot drop any node.
             let trace_non_roots_fn = unsafe { node.as_ref() }.trace_non_roots_fn();
 
@@ -295,12 +679,6 @@ impl Collector {
                 trace_non_roots_fn(*node);
             }
         }
-
-        for eph in &gc.weaks {
-            // SAFETY: node must be valid as this phase cannot drop any node.
-            let eph_ref = unsafe { eph.as_ref() };
-            eph_ref.trace_non_roots();
-        }
     }
 
     /// Walk the heap and mark any nodes deemed reachable
@@ -450,12 +828,14 @@ impl Collector {
     ///
     /// - Providing an invalid pointer in the `heap_start` or in any of the headers of each
     ///   node will result in Undefined Behaviour.
-    /// - Providing a list of pointers that weren't allocated by `Box::into_raw(Box::new(..))`
-    ///   will result in Undefined Behaviour.
+    /// - Providing a list of strong pointers that weren't allocated by `pools`, or a list of weak
+    ///   pointers that weren't allocated by `Box::into_raw(Box::new(..))` will result in
+    ///   Undefined Behaviour.
     unsafe fn sweep(
         strong: &mut Vec<GcErasedPointer>,
         weak: &mut Vec<EphemeronPointer>,
         total_allocated: &mut usize,
+        pools: &mut SizeClassPools,
     ) {
         let _timer = Profiler::global().start_event("Gc Sweeping", "gc");
         let _guard = DropGuard::new();
@@ -470,14 +850,15 @@ impl Collector {
                 true
             } else {
                 // SAFETY: The algorithm ensures only unmarked/unreachable pointers are dropped.
-                // The caller must ensure all pointers were allocated by `Box::into_raw(Box::new(..))`.
+                // The caller must ensure all pointers were allocated by `pools`.
                 let drop_fn = node_ref.drop_fn();
-                let size = node_ref.size();
-                *total_allocated -= size;
+                let layout = node_ref.layout();
+                *total_allocated -= SizeClassPools::allocation_size(layout);
 
                 // SAFETY: The function pointer is appropriate for this node type because we extract it from it's VTable.
                 unsafe {
                     drop_fn(*node);
+                    pools.dealloc(node.cast(), layout);
                 }
 
                 false
@@ -519,15 +900,21 @@ impl Collector {
         // Not initializing a dropguard since this should only be invoked when BOA_GC is being dropped.
         let _guard = DropGuard::new();
 
-        for node in mem::take(&mut gc.strongs) {
+        for node in mem::take(&mut gc.young)
+            .into_iter()
+            .chain(mem::take(&mut gc.strongs))
+        {
             // SAFETY:
             // The `Allocator` must always ensure its start node is a valid, non-null pointer that
-            // was allocated by `Box::from_raw(Box::new(..))`.
-            let drop_fn = unsafe { node.as_ref() }.drop_fn();
+            // was allocated by `gc.pools`.
+            let node_ref = unsafe { node.as_ref() };
+            let drop_fn = node_ref.drop_fn();
+            let layout = node_ref.layout();
 
             // SAFETY: The function pointer is appropriate for this node type because we extract it from it's VTable.
             unsafe {
                 drop_fn(node);
+                gc.pools.dealloc(node.cast(), layout);
             }
         }
 
@@ -551,6 +938,25 @@ pub fn force_collect() {
     });
 }
 
+/// Returns the parameters of the garbage collector of the current thread.
+#[must_use]
+pub fn tuning() -> GcTuning {
+    BOA_GC.with(|current| current.borrow().config.tuning)
+}
+
+/// Sets the parameters of the garbage collector of the current thread.
+///
+/// If the heap is being marked incrementally, the marking continues with the new parameters.
+pub fn set_tuning(tuning: GcTuning) {
+    BOA_GC.with(|current| current.borrow_mut().config.tuning = tuning);
+}
+
+/// Returns the statistics about the garbage collections of the current thread.
+#[must_use]
+pub fn statistics() -> GcStatistics {
+    BOA_GC.with(|current| current.borrow().runtime.statistics)
+}
+
 #[cfg(test)]
 mod test;
 
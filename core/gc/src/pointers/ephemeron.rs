@@ -67,6 +67,9 @@ impl<K: Trace + ?Sized, V: Trace> Ephemeron<K, V> {
     /// Creates a new `Ephemeron`.
     #[must_use]
     pub fn new(key: &Gc<K>, value: V) -> Self {
+        // The ephemerons are only traced once the heap is marked, so a value that was moved out
+        // of a node that isn't traced yet must be marked before it is only reachable from here.
+        crate::write_barrier(&value);
         let inner_ptr = Allocator::alloc_ephemeron(EphemeronBox::new(key, value));
         Self { inner_ptr }
     }
@@ -112,7 +115,12 @@ impl<K: Trace + ?Sized, V: Trace> Finalize for Ephemeron<K, V> {
 // SAFETY: `Ephemeron`s trace implementation only marks its inner box because we want to stop
 // tracing through weakly held pointers.
 unsafe impl<K: Trace + ?Sized, V: Trace> Trace for Ephemeron<K, V> {
-    unsafe fn trace(&self, _tracer: &mut Tracer) {
+    unsafe fn trace(&self, tracer: &mut Tracer) {
+        // Ephemerons are never young, so a minor collection doesn't mark them.
+        if tracer.is_minor() {
+            return;
+        }
+
         // SAFETY: We need to mark the inner box of the `Ephemeron` since it is reachable
         // from a root and this means it cannot be dropped.
         unsafe {
@@ -1,6 +1,6 @@
 use boa_macros::{Finalize, Trace};
 
-use super::{run_test, Harness};
+use super::{allocation_size, run_test, Harness};
 use crate::{force_collect, Gc, GcBox, GcRefCell};
 
 #[test]
@@ -32,6 +32,28 @@ fn gc_basic_pointer_alloc() {
     });
 }
 
+#[test]
+fn gc_reuses_swept_allocations() {
+    run_test(|| {
+        let size = allocation_size::<GcBox<u64>>();
+        let gc = Gc::new(1_u64);
+        let address = gc.inner_ptr().cast::<u8>();
+        Harness::assert_exact_bytes_allocated(size);
+        Harness::assert_pooled_bytes(0);
+
+        drop(gc);
+        force_collect();
+        Harness::assert_empty_gc();
+        Harness::assert_pooled_bytes(size);
+
+        let gc = Gc::new(2_u64);
+        assert_eq!(gc.inner_ptr().cast::<u8>(), address);
+        assert_eq!(*gc, 2);
+        Harness::assert_exact_bytes_allocated(size);
+        Harness::assert_pooled_bytes(0);
+    });
+}
+
 #[test]
 // Takes too long to finish in miri
 #[cfg_attr(miri, ignore)]
@@ -43,8 +65,8 @@ fn gc_recursion() {
             next: Option<Gc<S>>,
         }
 
-        const SIZE: usize = size_of::<GcBox<S>>();
         const COUNT: usize = 1_000_000;
+        let size = allocation_size::<GcBox<S>>();
 
         let mut root = Gc::new(S { i: 0, next: None });
         for i in 1..COUNT {
@@ -55,7 +77,7 @@ fn gc_recursion() {
         }
 
         Harness::assert_bytes_allocated();
-        Harness::assert_exact_bytes_allocated(SIZE * COUNT);
+        Harness::assert_exact_bytes_allocated(size * COUNT);
 
         drop(root);
         force_collect();
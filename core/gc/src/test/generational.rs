@@ -0,0 +1,85 @@
+use super::{allocation_size, run_test, Harness};
+use crate::{force_collect, statistics, Gc, GcBox, GcRefCell, WeakGc};
+
+#[test]
+fn minor_collection_frees_young_garbage() {
+    run_test(|| {
+        let root = Gc::new(1_u64);
+        for i in 0..10 {
+            let _garbage = Gc::new(i);
+        }
+
+        Harness::collect_young();
+        Harness::assert_young(0);
+        Harness::assert_exact_bytes_allocated(allocation_size::<GcBox<u64>>());
+        assert_eq!(*root, 1);
+
+        let statistics = statistics();
+        assert_eq!(statistics.minor.count, 1);
+        assert_eq!(statistics.major.count, 0);
+        assert_eq!(statistics.promoted, 1);
+        assert_eq!(statistics.allocations, 11);
+    });
+}
+
+#[test]
+fn minor_collection_keeps_values_referenced_by_old_values() {
+    run_test(|| {
+        let old = Gc::new(GcRefCell::new(None));
+        force_collect();
+        Harness::assert_young(0);
+
+        *old.borrow_mut() = Some(Gc::new(GcRefCell::new(String::from("young"))));
+        Harness::collect_young();
+        Harness::assert_young(0);
+
+        let young = old.borrow().clone().expect("value was stored");
+        assert_eq!(*young.borrow(), "young");
+
+        drop(young);
+        *old.borrow_mut() = None;
+        force_collect();
+        Harness::assert_exact_bytes_allocated(allocation_size::<
+            GcBox<GcRefCell<Option<Gc<GcRefCell<String>>>>>,
+        >());
+    });
+}
+
+#[test]
+fn minor_collection_clears_weak_references() {
+    run_test(|| {
+        let weak = WeakGc::new(&Gc::new(3));
+        let live = Gc::new(4);
+        let weak_live = WeakGc::new(&live);
+
+        Harness::collect_young();
+
+        assert!(weak.upgrade().is_none());
+        assert_eq!(*weak_live.upgrade().expect("value is rooted"), 4);
+    });
+}
+
+#[test]
+// Takes too long to finish in miri
+#[cfg_attr(miri, ignore)]
+fn allocation_triggers_minor_collections() {
+    run_test(|| {
+        let root = Gc::new(GcRefCell::new(Vec::new()));
+        for i in 0..100_000_usize {
+            let value = Gc::new(i);
+            if i % 1000 == 0 {
+                root.borrow_mut().push(value);
+            }
+        }
+
+        let statistics = statistics();
+        assert!(statistics.minor.count > 0);
+        assert_eq!(statistics.major.count, 0);
+        assert!(statistics.minor.max >= statistics.minor.mean());
+        assert!(root
+            .borrow()
+            .iter()
+            .enumerate()
+            .all(|(i, value)| **value == i * 1000));
+    });
+}
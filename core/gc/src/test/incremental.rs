@@ -0,0 +1,149 @@
+use super::{allocation_size, run_test, Harness};
+use std::cell::Cell;
+
+use crate::{force_collect, set_tuning, statistics, take_traced, tuning, Gc, GcBox, GcRefCell};
+
+type Slot = GcRefCell<Option<Gc<u64>>>;
+
+/// Stops the allocations from marking the heap, so the tests can run each step.
+fn pause_marking() {
+    let mut tuning = tuning();
+    tuning.mark_step = 0;
+    set_tuning(tuning);
+}
+
+#[test]
+fn incremental_marking_collects_unreachable_values() {
+    run_test(|| {
+        let root = Gc::new(1_u64);
+        for i in 0..10 {
+            let _garbage = Gc::new(i);
+        }
+
+        Harness::start_marking();
+        Harness::assert_marking(true);
+        Harness::mark_step(usize::MAX);
+        Harness::assert_marking(false);
+
+        Harness::assert_exact_bytes_allocated(allocation_size::<GcBox<u64>>());
+        assert_eq!(*root, 1);
+
+        let statistics = statistics();
+        assert_eq!(statistics.major.count, 1);
+        assert_eq!(statistics.incremental.count, 2);
+    });
+}
+
+#[test]
+fn incremental_marking_keeps_values_moved_during_marking() {
+    run_test(|| {
+        pause_marking();
+
+        let black = Gc::new(Slot::new(None));
+        let white = Gc::new(Slot::new(Some(Gc::new(7))));
+
+        Harness::start_marking();
+        Harness::mark_step(1);
+
+        // `black` was traced, so moving the value to it from `white`, which wasn't traced yet,
+        // hides it from the marking without the write barrier.
+        let value = white.borrow_mut().take();
+        *black.borrow_mut() = value;
+
+        Harness::mark_step(usize::MAX);
+        Harness::assert_marking(false);
+
+        assert_eq!(**black.borrow().as_ref().expect("value was moved"), 7);
+        Harness::assert_exact_bytes_allocated(
+            2 * allocation_size::<GcBox<Slot>>() + allocation_size::<GcBox<u64>>(),
+        );
+    });
+}
+
+#[test]
+fn incremental_marking_keeps_values_taken_out_of_cells() {
+    run_test(|| {
+        pause_marking();
+
+        let black = Gc::new(Slot::new(None));
+        let white = Gc::new(Cell::new(Some(Gc::new(7_u64))));
+
+        Harness::start_marking();
+        Harness::mark_step(1);
+
+        // Like for a `GcRefCell`, the value is marked before it is taken out of the cell.
+        *black.borrow_mut() = take_traced(&white);
+
+        Harness::mark_step(usize::MAX);
+        Harness::assert_marking(false);
+
+        assert_eq!(**black.borrow().as_ref().expect("value was moved"), 7);
+        Harness::assert_exact_bytes_allocated(
+            allocation_size::<GcBox<Slot>>()
+                + allocation_size::<GcBox<Cell<Option<Gc<u64>>>>>()
+                + allocation_size::<GcBox<u64>>(),
+        );
+    });
+}
+
+#[test]
+fn values_allocated_during_marking_survive() {
+    run_test(|| {
+        pause_marking();
+
+        let root = Gc::new(Slot::new(None));
+
+        Harness::start_marking();
+        *root.borrow_mut() = Some(Gc::new(5));
+        drop(Gc::new(6_u64));
+        Harness::mark_step(usize::MAX);
+
+        Harness::assert_marking(false);
+        Harness::assert_young(0);
+        assert_eq!(**root.borrow().as_ref().expect("value was stored"), 5);
+
+        // The values allocated while marking are only kept alive by their handles.
+        Harness::assert_exact_bytes_allocated(
+            allocation_size::<GcBox<Slot>>() + allocation_size::<GcBox<u64>>(),
+        );
+    });
+}
+
+#[test]
+fn force_collect_finishes_marking() {
+    run_test(|| {
+        pause_marking();
+
+        let root = Gc::new(1_u64);
+        Harness::start_marking();
+        drop(Gc::new(2_u64));
+
+        force_collect();
+
+        Harness::assert_marking(false);
+        Harness::assert_collections(2);
+        Harness::assert_exact_bytes_allocated(allocation_size::<GcBox<u64>>());
+        assert_eq!(*root, 1);
+    });
+}
+
+#[test]
+fn allocations_mark_the_heap_incrementally() {
+    run_test(|| {
+        let mut tuning = tuning().with_incremental_marking(true);
+        tuning.mark_step = 16;
+        set_tuning(tuning);
+
+        let root = Gc::new(Slot::new(None));
+        for i in 0..20_000_u64 {
+            *root.borrow_mut() = Some(Gc::new(i));
+            let _garbage = Gc::new([0_u8; 128]);
+        }
+        force_collect();
+
+        let statistics = statistics();
+        assert!(statistics.incremental.count > 1);
+        assert!(statistics.major.count > 1);
+        assert_eq!(**root.borrow().as_ref().expect("value was stored"), 19_999);
+    });
+}
@@ -1,18 +1,51 @@
-use crate::BOA_GC;
+use std::alloc::Layout;
+
+use crate::{internals::SizeClassPools, Collector, BOA_GC};
 
 mod allocation;
 mod cell;
+mod generational;
+mod incremental;
 mod weak;
 mod weak_map;
 
 struct Harness;
 
 impl Harness {
+    fn collect_young() {
+        BOA_GC.with(|current| {
+            let mut gc = current.borrow_mut();
+            Collector::collect_young(&mut gc);
+        });
+    }
+
+    fn start_marking() {
+        BOA_GC.with(|current| {
+            let mut gc = current.borrow_mut();
+            Collector::start_marking(&mut gc);
+        });
+    }
+
+    fn mark_step(budget: usize) {
+        BOA_GC.with(|current| {
+            let mut gc = current.borrow_mut();
+            Collector::mark_step(&mut gc, budget);
+        });
+    }
+
+    #[track_caller]
+    fn assert_marking(marking: bool) {
+        BOA_GC.with(|current| {
+            let gc = current.borrow();
+            assert_eq!(gc.marking.is_some(), marking);
+        });
+    }
+
     #[track_caller]
     fn assert_collections(o: usize) {
         BOA_GC.with(|current| {
             let gc = current.borrow();
-            assert_eq!(gc.runtime.collections, o);
+            assert_eq!(gc.runtime.statistics.major.count, o);
         });
     }
 
@@ -21,11 +54,20 @@ impl Harness {
         BOA_GC.with(|current| {
             let gc = current.borrow();
 
+            assert!(gc.young.is_empty());
             assert!(gc.strongs.is_empty());
             assert!(gc.runtime.bytes_allocated == 0);
         });
     }
 
+    #[track_caller]
+    fn assert_young(o: usize) {
+        BOA_GC.with(|current| {
+            let gc = current.borrow();
+            assert_eq!(gc.young.len(), o);
+        });
+    }
+
     #[track_caller]
     fn assert_bytes_allocated() {
         BOA_GC.with(|current| {
@@ -41,6 +83,19 @@ impl Harness {
             assert_eq!(gc.runtime.bytes_allocated, bytes);
         });
     }
+
+    #[track_caller]
+    fn assert_pooled_bytes(bytes: usize) {
+        BOA_GC.with(|current| {
+            let gc = current.borrow();
+            assert_eq!(gc.pools.pooled_bytes(), bytes);
+        });
+    }
+}
+
+/// Returns the number of bytes accounted for an allocation of a `T`.
+fn allocation_size<T>() -> usize {
+    SizeClassPools::allocation_size(Layout::new::<T>())
 }
 
 #[track_caller]
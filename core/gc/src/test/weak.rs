@@ -1,6 +1,6 @@
 use std::{cell::Cell, rc::Rc};
 
-use super::run_test;
+use super::{allocation_size, run_test};
 use crate::{
     force_collect, internals::EphemeronBox, test::Harness, Ephemeron, Finalize, Gc, GcBox,
     GcRefCell, Trace, WeakGc,
@@ -155,7 +155,7 @@ fn eph_self_referential() {
                 inner: GcRefCell::new(None),
             }),
         };
-        let root_size = size_of::<GcBox<InnerCell>>();
+        let root_size = allocation_size::<GcBox<InnerCell>>();
 
         Harness::assert_exact_bytes_allocated(root_size);
 
@@ -184,7 +184,7 @@ fn eph_self_referential_chain() {
     }
     run_test(|| {
         let root = Gc::new(GcRefCell::new(None));
-        let root_size = size_of::<GcBox<GcRefCell<Option<Ephemeron<u8, TestCell>>>>>();
+        let root_size = allocation_size::<GcBox<GcRefCell<Option<Ephemeron<u8, TestCell>>>>>();
 
         Harness::assert_exact_bytes_allocated(root_size);
 
@@ -211,7 +211,7 @@ fn eph_self_referential_chain() {
 
             assert!(eph_start.value().is_some());
             assert!(eph_chain2.value().is_some());
-            Harness::assert_exact_bytes_allocated(168);
+            Harness::assert_exact_bytes_allocated(176);
         }
 
         *root.borrow_mut() = None;
@@ -303,12 +303,12 @@ fn eph_strong_self_reference() {
         let root = TestCell {
             inner: Gc::new(GcRefCell::new((None, None))),
         };
-        let root_size = size_of::<GcBox<Inner>>();
+        let root_size = allocation_size::<GcBox<Inner>>();
 
         Harness::assert_exact_bytes_allocated(root_size);
 
         let watched = Gc::new(0);
-        let watched_size = size_of::<GcBox<i32>>();
+        let watched_size = allocation_size::<GcBox<i32>>();
 
         {
             let eph = Ephemeron::new(&watched, root.clone());
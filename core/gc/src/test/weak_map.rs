@@ -1,5 +1,5 @@
 use super::run_test;
-use crate::{force_collect, has_weak_maps, Gc, WeakMap};
+use crate::{force_collect, has_weak_maps, Gc, WeakGc, WeakMap};
 
 #[test]
 fn weak_map_basic() {
@@ -136,3 +136,79 @@ fn weak_map_key_live() {
         assert!(map.contains_key(&key_copy));
     });
 }
+
+#[test]
+fn weak_map_value_does_not_keep_key_alive() {
+    run_test(|| {
+        let key = Gc::new(String::from("key"));
+        let weak = WeakGc::new(&key);
+
+        let mut map = WeakMap::new();
+        map.insert(&key, key.clone());
+
+        drop(key);
+        force_collect();
+
+        assert!(weak.upgrade().is_none());
+    });
+}
+
+#[test]
+fn weak_map_cycle_through_values() {
+    run_test(|| {
+        let first_key = Gc::new(String::from("first"));
+        let second_key = Gc::new(String::from("second"));
+        let first_weak = WeakGc::new(&first_key);
+        let second_weak = WeakGc::new(&second_key);
+
+        let mut first = WeakMap::new();
+        let mut second = WeakMap::new();
+        first.insert(&first_key, second_key.clone());
+        second.insert(&second_key, first_key.clone());
+
+        drop(first_key);
+        drop(second_key);
+        force_collect();
+
+        assert!(first_weak.upgrade().is_none());
+        assert!(second_weak.upgrade().is_none());
+    });
+}
+
+#[test]
+fn weak_map_chained_ephemerons() {
+    run_test(|| {
+        let keys: Vec<_> = (0..4).map(|i| Gc::new(i.to_string())).collect();
+        let weaks: Vec<_> = keys.iter().map(WeakGc::new).collect();
+
+        // Inserted from the end of the chain, so that each entry is only found to be reachable
+        // after the entries traced before it.
+        let mut map = WeakMap::new();
+        for pair in keys.windows(2).rev() {
+            map.insert(&pair[0], pair[1].clone());
+        }
+
+        let mut keys = keys.into_iter();
+        let root = keys.next().expect("there are four keys");
+        drop(keys);
+
+        force_collect();
+
+        assert!(weaks.iter().all(|weak| weak.upgrade().is_some()));
+        let last = weaks[3]
+            .upgrade()
+            .expect("the chain is reachable from the root");
+        let second = map.get(&root).expect("the root is live");
+        let third = map.get(&second).expect("the second key is live");
+        assert!(Gc::ptr_eq(
+            &map.get(&third).expect("the third key is live"),
+            &last
+        ));
+        drop((last, second, third));
+
+        drop(root);
+        force_collect();
+
+        assert!(weaks.iter().all(|weak| weak.upgrade().is_none()));
+    });
+}
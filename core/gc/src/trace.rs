@@ -18,18 +18,33 @@ use crate::GcErasedPointer;
 
 /// A queue used to trace [`crate::Gc<T>`] non-recursively.
 #[doc(hidden)]
-#[allow(missing_debug_implementations)]
+#[derive(Debug)]
 pub struct Tracer {
     queue: VecDeque<GcErasedPointer>,
+    minor: bool,
 }
 
 impl Tracer {
     pub(crate) fn new() -> Self {
         Self {
             queue: VecDeque::default(),
+            minor: false,
         }
     }
 
+    /// Creates a [`Tracer`] for a minor collection, which only marks the young generation.
+    pub(crate) fn new_minor() -> Self {
+        Self {
+            queue: VecDeque::default(),
+            minor: true,
+        }
+    }
+
+    /// Returns `true` if this [`Tracer`] only marks the young generation.
+    pub(crate) const fn is_minor(&self) -> bool {
+        self.minor
+    }
+
     pub(crate) fn enqueue(&mut self, node: GcErasedPointer) {
         self.queue.push_back(node);
     }
@@ -41,17 +56,56 @@ impl Tracer {
     /// All the pointers inside of the queue must point to valid memory.
     pub(crate) unsafe fn trace_until_empty(&mut self) {
         while let Some(node) = self.queue.pop_front() {
-            let node_ref = unsafe { node.as_ref() };
-            if node_ref.is_marked() {
-                continue;
+            // SAFETY: The node pointer is valid per the caller's guarantee.
+            unsafe {
+                self.trace_node(node);
             }
-            node_ref.header.mark();
-            let trace_fn = node_ref.trace_fn();
+        }
+    }
 
-            // SAFETY: The function pointer is appropriate for this node type because we extract it from it's VTable.
-            // Additionally, the node pointer is valid per the caller's guarantee.
-            unsafe { trace_fn(node, self) }
+    /// Traces through the queued nodes until `budget` nodes were marked or the queue is empty.
+    ///
+    /// Returns `true` if the queue is empty.
+    ///
+    /// # Safety
+    ///
+    /// All the pointers inside of the queue must point to valid memory.
+    pub(crate) unsafe fn trace_budget(&mut self, mut budget: usize) -> bool {
+        while budget > 0 {
+            let Some(node) = self.queue.pop_front() else {
+                return true;
+            };
+
+            // SAFETY: The node pointer is valid per the caller's guarantee.
+            if unsafe { self.trace_node(node) } {
+                budget -= 1;
+            }
         }
+
+        self.queue.is_empty()
+    }
+
+    /// Marks `node` and queues its children, returning `false` if it doesn't need to be traced.
+    ///
+    /// # Safety
+    ///
+    /// `node` must point to valid memory.
+    unsafe fn trace_node(&mut self, node: GcErasedPointer) -> bool {
+        // SAFETY: The node pointer is valid per the caller's guarantee.
+        let node_ref = unsafe { node.as_ref() };
+        // The old generation is not collected by a minor collection, so the references
+        // from it don't need to be traced.
+        if node_ref.is_marked() || (self.minor && !node_ref.header.is_young()) {
+            return false;
+        }
+        node_ref.header.mark();
+        let trace_fn = node_ref.trace_fn();
+
+        // SAFETY: The function pointer is appropriate for this node type because we extract it from it's VTable.
+        // Additionally, the node pointer is valid per the caller's guarantee.
+        unsafe { trace_fn(node, self) }
+
+        true
     }
 
     pub(crate) fn is_empty(&mut self) -> bool {
@@ -498,6 +552,9 @@ where
 impl<T: Trace> Finalize for Cell<Option<T>> {}
 // SAFETY: Taking and setting is done in a single action, and recursive traces should find a `None`
 // value instead of the original `T`, making this safe.
+//
+// Note that the collector can't see the values that are moved out of the cell, so they must be
+// taken with `take_traced`, which marks them if the heap is being marked incrementally.
 unsafe impl<T: Trace> Trace for Cell<Option<T>> {
     custom_trace!(this, mark, {
         if let Some(v) = this.take() {
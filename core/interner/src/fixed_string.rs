@@ -33,6 +33,27 @@ impl<Char> FixedString<Char> {
     pub(super) fn is_empty(&self) -> bool {
         self.inner.is_empty()
     }
+
+    /// Returns the length that the [`FixedString`] needs to keep storing `string`, or `None` if
+    /// `string` is not stored in it.
+    pub(super) fn end_of(&self, string: InternedStr<Char>) -> Option<usize> {
+        let size = size_of::<Char>();
+        let start = self.inner.as_ptr().addr();
+        let end = start + self.inner.len() * size;
+        let string_start = string.as_ptr().cast::<Char>().as_ptr().addr();
+
+        (start..=end)
+            .contains(&string_start)
+            .then(|| (string_start - start) / size + string.len())
+    }
+
+    /// Shortens the [`FixedString`] to `len`, without changing its capacity.
+    ///
+    /// The caller must ensure no [`InternedStr`] pointing past `len` is used afterwards, since
+    /// its memory could be overwritten by the next strings pushed.
+    pub(super) fn truncate(&mut self, len: usize) {
+        self.inner.truncate(len);
+    }
 }
 
 impl<Char> FixedString<Char>
@@ -30,6 +30,16 @@ impl<Char> InternedStr<Char> {
         Self { ptr }
     }
 
+    /// Returns the pointer to the underlying string.
+    pub(super) const fn as_ptr(&self) -> NonNull<[Char]> {
+        self.ptr
+    }
+
+    /// Returns the length of the underlying string.
+    pub(super) const fn len(&self) -> usize {
+        self.ptr.len()
+    }
+
     /// Returns a shared reference to the underlying string.
     ///
     /// # Safety
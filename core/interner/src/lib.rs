@@ -27,6 +27,8 @@ extern crate alloc;
 mod fixed_string;
 mod interned_str;
 mod raw;
+#[cfg(feature = "serde")]
+mod serialization;
 mod sym;
 
 #[cfg(test)]
@@ -165,6 +167,24 @@ impl core::fmt::Display for JSInternedStrRef<'_, '_> {
 }
 
 /// The string interner for Boa.
+///
+/// # Releasing strings
+///
+/// The interner keeps every interned string alive until it is dropped, which can grow the memory
+/// of long-lived interners used to parse many sources. To avoid this, the strings interned after a
+/// [`Interner::checkpoint`] can be released by calling [`Interner::rollback`], or by interning them
+/// inside [`Interner::scoped`].
+///
+/// The [`Sym`]s of released strings must not be used after the rollback, since they may resolve
+/// to nothing or to a string interned afterwards. Any data that must outlive the rollback, e.g.
+/// identifiers in an AST, must be converted into owned strings before it.
+///
+/// # Serialization
+///
+/// With the `serde` feature, the interner can be serialized together with data that contains its
+/// [`Sym`]s, e.g. an AST. Deserializing it creates an interner that resolves the same [`Sym`]s to
+/// the same strings, as long as it is deserialized by a compatible version of this crate, which
+/// is validated on deserialization.
 #[derive(Debug, Default)]
 pub struct Interner {
     utf8_interner: RawInterner<u8>,
@@ -358,6 +378,56 @@ impl Interner {
         self.resolve(symbol).expect("string disappeared")
     }
 
+    /// Returns a checkpoint of the strings interned by the interner, which can be used to release
+    /// the strings interned after it with [`Interner::rollback`].
+    #[inline]
+    #[must_use]
+    pub fn checkpoint(&self) -> InternerCheckpoint {
+        InternerCheckpoint {
+            len: self.utf16_interner.len(),
+        }
+    }
+
+    /// Releases the strings interned after `checkpoint` was created.
+    ///
+    /// The [`Sym`]s of the released strings must not be used anymore, while the [`Sym`]s of
+    /// the strings interned before `checkpoint` stay valid. Rolling back to a checkpoint that is
+    /// older than the last rollback is allowed, and rolling back to a newer one does nothing.
+    pub fn rollback(&mut self, checkpoint: InternerCheckpoint) {
+        self.utf8_interner.truncate(checkpoint.len);
+        self.utf16_interner.truncate(checkpoint.len);
+    }
+
+    /// Calls `f` with the interner, releasing all the strings interned by `f` after it returns.
+    ///
+    /// See [`Interner::rollback`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use boa_interner::Interner;
+    /// let mut interner = Interner::default();
+    /// let outer = interner.get_or_intern("outer");
+    ///
+    /// let len = interner.scoped(|interner| {
+    ///     let inner = interner.get_or_intern("a string that is only used in the scope");
+    ///     interner.resolve_expect(inner).utf16().len()
+    /// });
+    ///
+    /// assert_eq!(len, 39);
+    /// assert!(interner.get("a string that is only used in the scope").is_none());
+    /// assert_eq!(interner.resolve_expect(outer).utf8(), Some("outer"));
+    /// ```
+    pub fn scoped<T, F>(&mut self, f: F) -> T
+    where
+        F: FnOnce(&mut Self) -> T,
+    {
+        let checkpoint = self.checkpoint();
+        let result = f(self);
+        self.rollback(checkpoint);
+        result
+    }
+
     /// Gets the symbol of the common string if one of them
     fn get_common(string: JStrRef<'_>) -> Option<Sym> {
         match string {
@@ -377,6 +447,14 @@ impl Interner {
     }
 }
 
+/// A checkpoint of the strings interned by an [`Interner`].
+///
+/// See [`Interner::checkpoint`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InternerCheckpoint {
+    len: usize,
+}
+
 /// Implements the display formatting with indentation.
 pub trait ToIndentedString {
     /// Converts the element to a string using an interner, with the given indentation.
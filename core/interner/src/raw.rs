@@ -25,6 +25,9 @@ pub(super) struct RawInterner<Char> {
     spans: Vec<InternedStr<Char>>,
     head: FixedString<Char>,
     full: Vec<FixedString<Char>>,
+    /// The number of interned strings at the moment each buffer of `full` stopped being the
+    /// `head`, which is used to know which strings are stored in each buffer.
+    retired_at: Vec<usize>,
 }
 
 impl<Char> Default for RawInterner<Char> {
@@ -34,6 +37,7 @@ impl<Char> Default for RawInterner<Char> {
             spans: Vec::default(),
             head: FixedString::default(),
             full: Vec::default(),
+            retired_at: Vec::default(),
         }
     }
 }
@@ -46,6 +50,7 @@ impl<Char> RawInterner<Char> {
             spans: Vec::with_capacity(capacity),
             head: FixedString::new(capacity),
             full: Vec::new(),
+            retired_at: Vec::new(),
         }
     }
 
@@ -126,6 +131,43 @@ where
         self.symbol_cache.insert(string, next);
         next
     }
+
+    /// Removes all the strings interned after the first `len` strings, releasing the memory that
+    /// is only used by them.
+    ///
+    /// Does nothing if the interner has `len` or less strings.
+    pub(super) fn truncate(&mut self, len: usize) {
+        if len >= self.len() {
+            return;
+        }
+
+        for string in self.spans.drain(len..) {
+            // The memory of `string` is still alive, so it can be hashed to find its entry.
+            self.symbol_cache.remove(&string);
+        }
+
+        // Strings are stored in the order they are interned, so the buffers retired after the
+        // first `len` strings were interned only store removed strings, except for the first one,
+        // which can store both and becomes the new `head`.
+        let kept = self
+            .retired_at
+            .partition_point(|&retired_at| retired_at <= len);
+        if kept < self.full.len() {
+            self.retired_at.truncate(kept);
+            self.full.truncate(kept + 1);
+            self.head = self.full.pop().expect("`full` must contain the new `head`");
+        }
+
+        // The remaining strings that are stored in `head` were interned after the last buffer
+        // was retired, and `head` only needs to keep up to the end of the last one of them.
+        let head_start = self.retired_at.last().copied().unwrap_or_default();
+        let head_len = self.spans[head_start..]
+            .iter()
+            .rev()
+            .find_map(|string| self.head.end_of(*string))
+            .unwrap_or_default();
+        self.head.truncate(head_len);
+    }
 }
 
 impl<Char> RawInterner<Char>
@@ -181,6 +223,7 @@ where
                 // This prevents that case.
                 if !old_head.is_empty() {
                     self.full.push(old_head);
+                    self.retired_at.push(self.spans.len());
                 }
                 self.head.push_unchecked(string)
             })
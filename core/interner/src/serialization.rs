@@ -0,0 +1,124 @@
+//! Serialization of the strings of an [`Interner`].
+//!
+//! An interner is serialized as the list of the strings it interned, in the order they were
+//! interned, which allows deserializing it into an interner that resolves the same [`Sym`]s to
+//! the same strings. This allows persisting data that contains [`Sym`]s, e.g. an AST, by
+//! serializing it together with its interner.
+//!
+//! The serialized form stores a format version and the number of common strings, which are
+//! validated on deserialization, since the [`Sym`]s of an interner serialized by an incompatible
+//! version of this crate cannot be kept valid.
+
+use alloc::{string::String, vec::Vec};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{Interner, JStrRef, Sym, COMMON_STRINGS_UTF8};
+
+/// The version of the serialized form of [`Interner`].
+///
+/// This must be incremented on any change to the serialized form or to the way [`Sym`]s are
+/// assigned.
+const FORMAT_VERSION: u32 = 1;
+
+/// Serialized form of an [`Interner`], borrowing the interned strings.
+#[derive(Serialize)]
+#[serde(rename = "Interner")]
+struct InternerRef<'a> {
+    version: u32,
+    common_strings: usize,
+    strings: Vec<StrRef<'a>>,
+}
+
+/// Serialized form of an interned string, borrowed from an [`Interner`].
+///
+/// Strings are stored as `UTF-8` whenever possible, since it is more compact in most formats.
+#[derive(Serialize)]
+#[serde(rename = "InternedStr")]
+enum StrRef<'a> {
+    Utf8(&'a str),
+    Utf16(&'a [u16]),
+}
+
+/// Deserialized form of an [`Interner`].
+#[derive(Deserialize)]
+#[serde(rename = "Interner")]
+struct InternerData {
+    version: u32,
+    common_strings: usize,
+    strings: Vec<OwnedStr>,
+}
+
+/// Deserialized form of an interned string.
+#[derive(Deserialize)]
+#[serde(rename = "InternedStr")]
+enum OwnedStr {
+    Utf8(String),
+    Utf16(Vec<u16>),
+}
+
+impl Serialize for Interner {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let strings = (0..self.len() - COMMON_STRINGS_UTF8.len())
+            .map(|index| {
+                let sym = Sym::new(index + 1 + COMMON_STRINGS_UTF8.len())
+                    .expect("symbol indices must not be zero");
+                let string = self.resolve_expect(sym);
+                string
+                    .utf8()
+                    .map_or(StrRef::Utf16(string.utf16()), StrRef::Utf8)
+            })
+            .collect();
+
+        InternerRef {
+            version: FORMAT_VERSION,
+            common_strings: COMMON_STRINGS_UTF8.len(),
+            strings,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Interner {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let data = InternerData::deserialize(deserializer)?;
+
+        if data.version != FORMAT_VERSION {
+            return Err(de::Error::custom(format_args!(
+                "unsupported interner format version {}, expected version {FORMAT_VERSION}",
+                data.version
+            )));
+        }
+        if data.common_strings != COMMON_STRINGS_UTF8.len() {
+            return Err(de::Error::custom(format_args!(
+                "the interner was serialized with {} common strings instead of {}",
+                data.common_strings,
+                COMMON_STRINGS_UTF8.len()
+            )));
+        }
+
+        let mut interner = Self::default();
+        for (index, string) in data.strings.iter().enumerate() {
+            let string = match string {
+                OwnedStr::Utf8(s) => JStrRef::Utf8(s),
+                OwnedStr::Utf16(s) => JStrRef::Utf16(s),
+            };
+
+            // Strings that were already interned would get an older symbol, shifting the symbols
+            // of all the following strings.
+            let expected = index + 1 + COMMON_STRINGS_UTF8.len();
+            if interner.get_or_intern(string).get() != expected {
+                return Err(de::Error::custom(format_args!(
+                    "duplicate interned string at index {index}"
+                )));
+            }
+        }
+
+        Ok(interner)
+    }
+}
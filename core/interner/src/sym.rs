@@ -149,5 +149,8 @@ static_syms! {
     "name",
     "await",
     ("*default*", DEFAULT_EXPORT),
-    "meta"
+    "meta",
+    "source",
+    "defer",
+    "accessor"
 }
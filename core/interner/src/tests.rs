@@ -1,3 +1,5 @@
+use alloc::{format, string::String, vec::Vec};
+
 use crate::{Interner, Sym, COMMON_STRINGS_UTF16, COMMON_STRINGS_UTF8};
 use boa_macros::utf16;
 
@@ -130,3 +132,137 @@ fn check_capacity() {
 
     assert!(interner.resolve(sym).is_none());
 }
+
+#[test]
+fn check_rollback() {
+    let mut interner = Interner::default();
+
+    let kept = interner.get_or_intern("kept");
+    let kept_unp = interner.get_or_intern(&[0xDC15u16, 'a' as u16][..]);
+    let checkpoint = interner.checkpoint();
+    let len = interner.len();
+
+    let released = interner.get_or_intern("released");
+    let released_unp = interner.get_or_intern(&[0xDC19u16, 'b' as u16][..]);
+    assert_eq!(interner.get_or_intern("kept"), kept);
+
+    interner.rollback(checkpoint);
+
+    assert_eq!(interner.len(), len);
+    assert!(interner.get("released").is_none());
+    assert!(interner.get(&[0xDC19u16, 'b' as u16][..]).is_none());
+    assert!(interner.resolve(released_unp).is_none());
+    assert_eq!(interner.resolve_expect(kept).utf8(), Some("kept"));
+    assert_eq!(
+        interner.resolve_expect(kept_unp).utf16(),
+        &[0xDC15u16, 'a' as u16][..]
+    );
+
+    // The released symbols are reused by the next interned strings.
+    let new = interner.get_or_intern("new");
+    assert_eq!(new, released);
+    assert_eq!(interner.resolve_expect(new).utf8(), Some("new"));
+
+    // Rolling back to a newer checkpoint does nothing.
+    let newer = interner.checkpoint();
+    interner.rollback(checkpoint);
+    interner.rollback(newer);
+    assert!(interner.get("new").is_none());
+    assert_eq!(interner.resolve_expect(kept).utf8(), Some("kept"));
+}
+
+#[test]
+fn check_rollback_across_buffers() {
+    let mut interner = Interner::with_capacity(8);
+    let strings: Vec<String> = (0..200).map(|i| format!("string number {i}")).collect();
+
+    let outer = interner.checkpoint();
+    let kept: Vec<_> = strings[..50]
+        .iter()
+        .map(|s| interner.get_or_intern(s.as_str()))
+        .collect();
+    let inner = interner.checkpoint();
+
+    for s in &strings[50..] {
+        interner.get_or_intern(s.as_str());
+    }
+    interner.rollback(inner);
+
+    for (s, sym) in strings.iter().zip(&kept) {
+        assert_eq!(interner.resolve_expect(*sym).utf8(), Some(s.as_str()));
+    }
+    for s in &strings[50..] {
+        assert!(interner.get(s.as_str()).is_none());
+    }
+
+    // Interning again reuses the memory of the kept buffers without overwriting the kept strings.
+    let scoped = interner.scoped(|interner| {
+        strings[100..]
+            .iter()
+            .map(|s| interner.get_or_intern(s.as_str()))
+            .collect::<Vec<_>>()
+    });
+    assert_eq!(scoped[0], sym_from_usize(kept[49].get() + 1));
+    for (s, sym) in strings.iter().zip(&kept) {
+        assert_eq!(interner.resolve_expect(*sym).utf8(), Some(s.as_str()));
+    }
+
+    interner.rollback(outer);
+    assert_eq!(interner.len(), COMMON_STRINGS_UTF8.len());
+    assert!(interner.get(strings[0].as_str()).is_none());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn check_serialization() {
+    let mut interner = Interner::default();
+
+    let unp = &[0xDC15u16, 'a' as u16][..];
+    let syms = [
+        interner.get_or_intern("first"),
+        interner.get_or_intern(unp),
+        interner.get_or_intern(utf16!("third")),
+        interner.get_or_intern("arguments"),
+    ];
+
+    let json = serde_json::to_string(&(&interner, syms)).unwrap();
+    let (mut deserialized, deserialized_syms): (Interner, [Sym; 4]) =
+        serde_json::from_str(&json).unwrap();
+
+    assert_eq!(deserialized_syms, syms);
+    assert_eq!(deserialized.len(), interner.len());
+    assert_eq!(deserialized.resolve_expect(syms[0]).utf8(), Some("first"));
+    assert_eq!(deserialized.resolve_expect(syms[1]).utf16(), unp);
+    assert_eq!(deserialized.resolve_expect(syms[2]).utf8(), Some("third"));
+    assert_eq!(
+        deserialized.resolve_expect(syms[3]).utf8(),
+        Some("arguments")
+    );
+    assert_eq!(
+        deserialized.get_or_intern("fourth"),
+        interner.get_or_intern("fourth")
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn check_serialization_validation() {
+    let common = COMMON_STRINGS_UTF8.len();
+
+    let valid = format!(r#"{{"version":1,"common_strings":{common},"strings":[{{"Utf8":"a"}}]}}"#);
+    assert!(serde_json::from_str::<Interner>(&valid).is_ok());
+
+    let version = format!(r#"{{"version":0,"common_strings":{common},"strings":[]}}"#);
+    assert!(serde_json::from_str::<Interner>(&version).is_err());
+
+    let common_strings = format!(
+        r#"{{"version":1,"common_strings":{},"strings":[]}}"#,
+        common + 1
+    );
+    assert!(serde_json::from_str::<Interner>(&common_strings).is_err());
+
+    let duplicate = format!(
+        r#"{{"version":1,"common_strings":{common},"strings":[{{"Utf8":"a"}},{{"Utf16":[97]}}]}}"#
+    );
+    assert!(serde_json::from_str::<Interner>(&duplicate).is_err());
+}
@@ -659,3 +659,103 @@ fn generate_obj_properties(fields: FieldsNamed) -> Result<proc_macro2::TokenStre
 
     Ok(quote! { #(#prop_ctors)* })
 }
+
+/// Derives the `OptionType` trait for an enum of unit variants, with the `#[boa()]` attribute.
+///
+/// Each variant is parsed from its name with the first letter in lowercase (e.g. `HalfExpand`
+/// from `"halfExpand"`), unless renamed with `#[boa(rename = "...")]`. Any other string throws a
+/// `RangeError` listing the allowed values.
+#[proc_macro_derive(OptionType, attributes(boa))]
+pub fn derive_option_type(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    let arms = match generate_option_arms(&input) {
+        Ok(arms) => arms,
+        Err(err) => return err.to_compile_error().into(),
+    };
+    let (values, variants): (Vec<_>, Vec<_>) = arms.into_iter().unzip();
+
+    let expected = values
+        .iter()
+        .map(|value| format!("`{value}`"))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let error_str = format!("provided string was not one of {expected}");
+
+    let type_name = input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let expanded = quote! {
+        impl #impl_generics ::boa_engine::builtins::options::OptionType
+            for #type_name #ty_generics #where_clause
+        {
+            fn from_value(
+                value: ::boa_engine::JsValue,
+                context: &mut ::boa_engine::Context,
+            ) -> ::boa_engine::JsResult<Self> {
+                match value.to_string(context)?.to_std_string_escaped().as_str() {
+                    #(#values => ::boa_engine::JsResult::Ok(Self::#variants),)*
+                    _ => ::boa_engine::JsResult::Err(
+                        ::boa_engine::JsNativeError::range()
+                            .with_message(#error_str)
+                            .into(),
+                    ),
+                }
+            }
+        }
+    };
+
+    expanded.into()
+}
+
+/// Generates the string value of each variant of an enum deriving `OptionType`.
+fn generate_option_arms(input: &DeriveInput) -> Result<Vec<(String, Ident)>, syn::Error> {
+    let Data::Enum(data) = &input.data else {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "you can only derive `OptionType` for enums",
+        ));
+    };
+
+    let mut arms = Vec::with_capacity(data.variants.len());
+    for variant in &data.variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(syn::Error::new_spanned(
+                variant,
+                "you can only derive `OptionType` for enums of unit variants",
+            ));
+        }
+
+        let name = variant.ident.to_string();
+        let mut chars = name.chars();
+        let mut value: String = chars
+            .next()
+            .into_iter()
+            .flat_map(char::to_lowercase)
+            .collect();
+        value.push_str(chars.as_str());
+
+        for attr in variant
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("boa"))
+        {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rename") {
+                    value = meta.value()?.parse::<LitStr>()?.value();
+                    Ok(())
+                } else {
+                    Err(meta.error(
+                        "invalid syntax in the `#[boa()]` attribute. \
+                              Note that this attribute only accepts the following syntax: \
+                            \n* `#[boa(rename = \"optionValue\")]`",
+                    ))
+                }
+            })?;
+        }
+
+        arms.push((value, variant.ident.clone()));
+    }
+
+    Ok(arms)
+}
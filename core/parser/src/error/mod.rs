@@ -5,7 +5,7 @@ mod tests;
 
 use crate::lexer::Error as LexError;
 use boa_ast::{Position, Span};
-use std::fmt;
+use std::{borrow::Cow, fmt};
 
 /// Result of a parsing operation.
 pub type ParseResult<T> = Result<T, Error>;
@@ -176,6 +176,32 @@ impl Error {
     pub(crate) const fn lex(e: LexError) -> Self {
         Self::Lex { err: e }
     }
+
+    /// Gets a suggested fix for the error, if any.
+    ///
+    /// For `Expected` errors, the suggestion is derived from the expected and found tokens.
+    #[must_use]
+    pub fn suggestion(&self) -> Option<Cow<'_, str>> {
+        match self {
+            Self::Expected {
+                expected, found, ..
+            } => missing_delimiter(expected, found)
+                .map(|delimiter| format!("did you forget a `{delimiter}`?").into()),
+            Self::Lex { err } => err.suggestion().map(Cow::Borrowed),
+            _ => None,
+        }
+    }
+
+    /// Gets the position of the source code where the error occurred, if any.
+    #[must_use]
+    pub const fn position(&self) -> Option<Position> {
+        match self {
+            Self::Expected { span, .. } | Self::Unexpected { span, .. } => Some(span.start()),
+            Self::General { position, .. } => Some(*position),
+            Self::Lex { err } => err.position(),
+            Self::AbruptEnd => None,
+        }
+    }
 }
 
 impl fmt::Display for Error {
@@ -209,7 +235,11 @@ impl fmt::Display for Error {
                     ", got '{found}' in {context} at line {}, col {}",
                     span.start().line_number(),
                     span.start().column_number()
-                )
+                )?;
+                if let Some(suggestion) = self.suggestion() {
+                    write!(f, " (help: {suggestion})")?;
+                }
+                Ok(())
             }
             Self::Unexpected {
                 found,
@@ -234,3 +264,29 @@ impl fmt::Display for Error {
 }
 
 impl std::error::Error for Error {}
+
+/// Finds the delimiter that was most likely forgotten, given the tokens that were `expected` and
+/// the token that was `found` instead.
+fn missing_delimiter<'a>(expected: &'a [String], found: &str) -> Option<&'a str> {
+    // An identifier or literal where a comma could go usually starts the next element of a list.
+    let starts_operand = found != "end of file"
+        && found != "line terminator"
+        && found.starts_with(|c: char| c.is_alphanumeric() || matches!(c, '_' | '$'));
+    if starts_operand {
+        if let Some(comma) = expected.iter().find(|token| *token == ",") {
+            return Some(comma.as_str());
+        }
+    }
+
+    let mut closing = expected
+        .iter()
+        .filter(|token| matches!(token.as_str(), ")" | "]" | "}"));
+    match (closing.next(), closing.next()) {
+        (Some(token), None) => Some(token.as_str()),
+        (None, _) => expected
+            .iter()
+            .find(|token| *token == ";")
+            .map(String::as_str),
+        _ => None,
+    }
+}
@@ -164,3 +164,60 @@ fn display() {
 
     assert_eq!(err.to_string(), "testing at line 1, col 1");
 }
+
+#[test]
+fn position() {
+    let err = Error::unexpected(
+        "nottesting",
+        Span::new(Position::new(2, 5), Position::new(2, 8)),
+        "error message",
+    );
+    assert_eq!(err.position(), Some(Position::new(2, 5)));
+
+    let err = Error::general("this is a general error message", Position::new(3, 1));
+    assert_eq!(err.position(), Some(Position::new(3, 1)));
+
+    let err = Error::lex(LexError::syntax("testing", Position::new(4, 2)));
+    assert_eq!(err.position(), Some(Position::new(4, 2)));
+
+    assert_eq!(Error::AbruptEnd.position(), None);
+}
+
+#[test]
+fn suggestion() {
+    let span = Span::new(Position::new(1, 5), Position::new(1, 6));
+
+    let err = Error::expected([",".to_owned(), ")".to_owned()], "b", span, "argument list");
+    assert_eq!(err.suggestion().as_deref(), Some("did you forget a `,`?"));
+    assert_eq!(
+        err.to_string(),
+        "expected one of ',' or ')', got 'b' in argument list at line 1, col 5 (help: did you forget a `,`?)"
+    );
+
+    let err = Error::expected(
+        [",".to_owned(), ")".to_owned()],
+        "=>",
+        span,
+        "argument list",
+    );
+    assert_eq!(err.suggestion().as_deref(), Some("did you forget a `)`?"));
+
+    let err = Error::expected([";".to_owned()], "2", span, "expression statement");
+    assert_eq!(err.suggestion().as_deref(), Some("did you forget a `;`?"));
+
+    let err = Error::expected(["identifier".to_owned()], "=", span, "identifier parsing");
+    assert_eq!(err.suggestion().as_deref(), None);
+
+    // Changing the context keeps the suggestion.
+    let result: ParseResult<()> = Err(Error::expected(
+        [",".to_owned(), "]".to_owned()],
+        "end of file",
+        span,
+        "before",
+    ));
+    let err = result.set_context("after").unwrap_err();
+    assert_eq!(err.context(), Some("after"));
+    assert_eq!(err.suggestion().as_deref(), Some("did you forget a `]`?"));
+
+    assert!(Error::AbruptEnd.suggestion().is_none());
+}
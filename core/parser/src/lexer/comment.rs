@@ -31,7 +31,11 @@ impl<R> Tokenizer<R> for SingleLineComment {
         let _timer = Profiler::global().start_event("SingleLineComment", "Lexing");
 
         // Skip either to the end of the line or to the end of the input
-        while let Some(ch) = cursor.peek_char()? {
+        loop {
+            cursor.skip_ascii_run(single_line_comment_run)?;
+            let Some(ch) = cursor.peek_char()? else {
+                break;
+            };
             let tried_ch = char::try_from(ch);
             match tried_ch {
                 Ok(c) if c == '\r' || c == '\n' || c == '\u{2028}' || c == '\u{2029}' => break,
@@ -72,7 +76,11 @@ impl<R> Tokenizer<R> for MultiLineComment {
         let _timer = Profiler::global().start_event("MultiLineComment", "Lexing");
 
         let mut new_line = false;
-        while let Some(ch) = cursor.next_char()? {
+        loop {
+            cursor.skip_ascii_run(multi_line_comment_run)?;
+            let Some(ch) = cursor.next_char()? else {
+                break;
+            };
             let tried_ch = char::try_from(ch);
             match tried_ch {
                 Ok(c) if c == '*' && cursor.next_if(0x2F /* / */)? => {
@@ -100,6 +108,32 @@ impl<R> Tokenizer<R> for MultiLineComment {
     }
 }
 
+/// Returns the length of the ASCII characters at the start of a single line comment, up to the
+/// first line terminator.
+fn single_line_comment_run(bytes: &[u8]) -> usize {
+    let end = memchr::memchr2(b'\n', b'\r', bytes).unwrap_or(bytes.len());
+    ascii_prefix_len(&bytes[..end])
+}
+
+/// Returns the length of the ASCII characters at the start of a multi line comment, up to the
+/// first `*` or line terminator.
+fn multi_line_comment_run(bytes: &[u8]) -> usize {
+    let end = memchr::memchr3(b'*', b'\n', b'\r', bytes).unwrap_or(bytes.len());
+    ascii_prefix_len(&bytes[..end])
+}
+
+/// Returns the length of the ASCII characters at the start of `bytes`.
+fn ascii_prefix_len(bytes: &[u8]) -> usize {
+    if bytes.is_ascii() {
+        bytes.len()
+    } else {
+        bytes
+            .iter()
+            .position(|byte| !byte.is_ascii())
+            .unwrap_or(bytes.len())
+    }
+}
+
 /// Lexes a first line Hashbang comment
 ///
 /// More information:
@@ -192,6 +192,34 @@ impl<R: ReadChar> Cursor<R> {
         }
     }
 
+    /// Consumes a run of ASCII characters that are not line terminators, reading them in bulk
+    /// from the input if possible.
+    ///
+    /// `run_len` returns the length of the run at the start of the given bytes. The run might
+    /// continue after this returns, either because some characters were already peeked or
+    /// because the input cannot be read in bulk, so the rest of it must be consumed with
+    /// [`Cursor::next_char`].
+    pub(super) fn skip_ascii_run(&mut self, run_len: fn(&[u8]) -> usize) -> io::Result<()> {
+        let _timer = Profiler::global().start_event("cursor::skip_ascii_run()", "Lexing");
+
+        if self.peeked[0].is_some() {
+            return Ok(());
+        }
+
+        loop {
+            let run = self.iter.next_ascii_run(run_len)?;
+            if run.is_empty() {
+                return Ok(());
+            }
+
+            self.source_collector.collect_ascii(run);
+
+            #[allow(clippy::cast_possible_truncation)]
+            let next_column = self.pos.column_number() + run.len() as u32;
+            self.pos = Position::new(self.pos.line_number(), next_column);
+        }
+    }
+
     /// Retrieves the next UTF-8 character.
     pub(crate) fn next_char(&mut self) -> Result<Option<u32>, Error> {
         let _timer = Profiler::global().start_event("cursor::next_char()", "Lexing");
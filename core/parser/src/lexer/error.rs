@@ -5,7 +5,7 @@
 //!
 //! [spec]: https://tc39.es/ecma262/#sec-native-error-types-used-in-this-standard
 
-use boa_ast::Position;
+use boa_ast::{Position, Span};
 use std::{error, fmt, io};
 
 /// An error that occurred during the lexing.
@@ -22,6 +22,27 @@ pub enum Error {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-native-error-types-used-in-this-standard-syntaxerror
     Syntax(Box<str>, Position),
+
+    /// Indicates an invalid numeric literal, such as a misplaced numeric separator or a legacy
+    /// octal literal in strict mode.
+    ///
+    /// More information:
+    /// - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-literals-numeric-literals
+    NumericLiteral {
+        /// The description of the error.
+        message: Box<str>,
+
+        /// The position of the offending character.
+        position: Position,
+
+        /// The span of the whole numeric literal.
+        span: Span,
+
+        /// A suggested fix for the literal, if any.
+        suggestion: Option<Box<str>>,
+    },
 }
 
 impl From<io::Error> for Error {
@@ -41,6 +62,43 @@ impl Error {
     {
         Self::Syntax(err.into(), pos.into())
     }
+
+    /// Creates a new numeric literal error.
+    ///
+    /// The span initially only covers the offending position, and is widened to the whole
+    /// literal once the lexer has skipped past it.
+    #[inline]
+    pub(crate) fn numeric_literal<M, P>(message: M, pos: P, suggestion: Option<Box<str>>) -> Self
+    where
+        M: Into<Box<str>>,
+        P: Into<Position>,
+    {
+        let position = pos.into();
+        Self::NumericLiteral {
+            message: message.into(),
+            position,
+            span: position.into(),
+            suggestion,
+        }
+    }
+
+    /// Gets a suggested fix for the error, if any.
+    #[must_use]
+    pub fn suggestion(&self) -> Option<&str> {
+        match self {
+            Self::NumericLiteral { suggestion, .. } => suggestion.as_deref(),
+            Self::IO(_) | Self::Syntax(_, _) => None,
+        }
+    }
+
+    /// Gets the position of the source code where the error occurred, if any.
+    #[must_use]
+    pub const fn position(&self) -> Option<Position> {
+        match self {
+            Self::IO(_) => None,
+            Self::Syntax(_, position) | Self::NumericLiteral { position, .. } => Some(*position),
+        }
+    }
 }
 
 impl fmt::Display for Error {
@@ -54,6 +112,23 @@ impl fmt::Display for Error {
                 pos.line_number(),
                 pos.column_number()
             ),
+            Self::NumericLiteral {
+                message,
+                position,
+                suggestion,
+                ..
+            } => {
+                write!(
+                    f,
+                    "{message} at line {}, col {}",
+                    position.line_number(),
+                    position.column_number()
+                )?;
+                if let Some(suggestion) = suggestion {
+                    write!(f, " (help: {suggestion})")?;
+                }
+                Ok(())
+            }
         }
     }
 }
@@ -63,7 +138,7 @@ impl error::Error for Error {
     fn source(&self) -> Option<&(dyn error::Error + 'static)> {
         match self {
             Self::IO(err) => Some(err),
-            Self::Syntax(_, _) => None,
+            Self::Syntax(_, _) | Self::NumericLiteral { .. } => None,
         }
     }
 }
@@ -88,6 +163,38 @@ mod tests {
         assert!(err.source().is_none());
     }
 
+    #[test]
+    fn numeric_literal() {
+        let err = Error::numeric_literal(
+            "testing",
+            Position::new(1, 3),
+            Some("remove the `_`".into()),
+        );
+        if let Error::NumericLiteral {
+            message,
+            position,
+            span,
+            suggestion,
+        } = &err
+        {
+            assert_eq!(message.as_ref(), "testing");
+            assert_eq!(*position, Position::new(1, 3));
+            assert_eq!(*span, Span::from(Position::new(1, 3)));
+            assert_eq!(suggestion.as_deref(), Some("remove the `_`"));
+        } else {
+            unreachable!()
+        }
+
+        assert_eq!(
+            err.to_string(),
+            "testing at line 1, col 3 (help: remove the `_`)"
+        );
+        assert!(err.source().is_none());
+
+        let err = Error::numeric_literal("testing", Position::new(1, 1), None);
+        assert_eq!(err.to_string(), "testing at line 1, col 1");
+    }
+
     #[test]
     fn io() {
         let custom_error = io::Error::other("I/O error");
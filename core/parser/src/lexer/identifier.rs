@@ -4,8 +4,8 @@ use crate::lexer::{
     token::ContainsEscapeSequence, Cursor, Error, StringLiteral, Token, TokenKind, Tokenizer,
 };
 use crate::source::ReadChar;
-use boa_ast::PositionGroup;
-use boa_interner::Interner;
+use boa_ast::{Keyword, PositionGroup};
+use boa_interner::{Interner, Sym};
 use boa_profiler::Profiler;
 use icu_properties::props::{IdContinue, IdStart};
 use icu_properties::{CodePointSetData, CodePointSetDataBorrowed};
@@ -28,32 +28,53 @@ impl Identifier {
     pub(super) const fn new(init: char) -> Self {
         Self { init }
     }
+}
 
-    /// Checks if a character is `IdentifierStart` as per ECMAScript standards.
-    ///
-    /// More information:
-    ///  - [ECMAScript reference][spec]
-    ///
-    /// [spec]: https://tc39.es/ecma262/#sec-names-and-keywords
-    pub(super) fn is_identifier_start(ch: u32) -> bool {
-        const ID_START: CodePointSetDataBorrowed<'static> = CodePointSetData::new::<IdStart>();
-        matches!(ch, 0x0024 /* $ */ | 0x005F /* _ */) || ID_START.contains32(ch)
-    }
+/// Checks if a code point is an `IdentifierStartChar` as per ECMAScript standards.
+///
+/// This includes every code point with the `ID_Start` Unicode property, including those outside
+/// the Basic Multilingual Plane, plus `$` and `_`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-IdentifierStartChar
+#[must_use]
+pub fn is_identifier_start(ch: u32) -> bool {
+    const ID_START: CodePointSetDataBorrowed<'static> = CodePointSetData::new::<IdStart>();
+    matches!(ch, 0x0024 /* $ */ | 0x005F /* _ */) || ID_START.contains32(ch)
+}
 
-    /// Checks if a character is `IdentifierPart` as per ECMAScript standards.
-    ///
-    /// More information:
-    ///  - [ECMAScript reference][spec]
-    ///
-    /// [spec]: https://tc39.es/ecma262/#sec-names-and-keywords
-    fn is_identifier_part(ch: u32) -> bool {
-        const ID_CONTINUE: CodePointSetDataBorrowed<'static> =
-            CodePointSetData::new::<IdContinue>();
-        matches!(
-            ch,
-            0x0024 /* $ */ | 0x005F /* _ */ | 0x200C /* <ZWNJ> */ | 0x200D /* <ZWJ> */
-        ) || ID_CONTINUE.contains32(ch)
-    }
+/// Checks if a code point is an `IdentifierPartChar` as per ECMAScript standards.
+///
+/// This includes every code point with the `ID_Continue` Unicode property, including those
+/// outside the Basic Multilingual Plane, plus `$`, `<ZWNJ>` and `<ZWJ>`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-IdentifierPartChar
+#[must_use]
+pub fn is_identifier_part(ch: u32) -> bool {
+    const ID_CONTINUE: CodePointSetDataBorrowed<'static> = CodePointSetData::new::<IdContinue>();
+    matches!(
+        ch,
+        0x0024 /* $ */ | 0x005F /* _ */ | 0x200C /* <ZWNJ> */ | 0x200D /* <ZWJ> */
+    ) || ID_CONTINUE.contains32(ch)
+}
+
+/// Checks if a string is a valid `IdentifierName` without escape sequences.
+///
+/// Note that this doesn't check if the name is a reserved word.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-IdentifierName
+#[must_use]
+pub fn is_identifier_name(name: &str) -> bool {
+    let mut chars = name.chars().map(u32::from);
+    chars.next().is_some_and(is_identifier_start) && chars.all(is_identifier_part)
 }
 
 impl<R> Tokenizer<R> for Identifier {
@@ -71,21 +92,23 @@ impl<R> Tokenizer<R> for Identifier {
         let (identifier_name, contains_escaped_chars) =
             Self::take_identifier_name(cursor, start_pos, self.init)?;
 
-        let token_kind = match identifier_name.parse() {
-            Ok(keyword) => TokenKind::Keyword((keyword, contains_escaped_chars)),
-            Err(_) if identifier_name == "true" => {
+        // The reserved words are static strings of the interner, which finds them with a perfect
+        // hash, so they can be recognized by their symbol.
+        let sym = interner.get_or_intern(identifier_name.as_str());
+        let token_kind = match (Keyword::from_sym(sym), sym) {
+            (Some(keyword), _) => TokenKind::Keyword((keyword, contains_escaped_chars)),
+            (None, Sym::TRUE) => {
                 TokenKind::BooleanLiteral((true, ContainsEscapeSequence(contains_escaped_chars)))
             }
-            Err(_) if identifier_name == "false" => {
+            (None, Sym::FALSE) => {
                 TokenKind::BooleanLiteral((false, ContainsEscapeSequence(contains_escaped_chars)))
             }
-            Err(_) if identifier_name == "null" => {
+            (None, Sym::NULL) => {
                 TokenKind::NullLiteral(ContainsEscapeSequence(contains_escaped_chars))
             }
-            Err(_) => TokenKind::IdentifierName((
-                interner.get_or_intern(identifier_name.as_str()),
-                ContainsEscapeSequence(contains_escaped_chars),
-            )),
+            (None, _) => {
+                TokenKind::IdentifierName((sym, ContainsEscapeSequence(contains_escaped_chars)))
+            }
         };
 
         Ok(Token::new_by_position_group(
@@ -111,7 +134,7 @@ impl Identifier {
         let mut identifier_name = if init == '\\' && cursor.next_if(0x75 /* u */)? {
             let ch = StringLiteral::take_unicode_escape_sequence(cursor, start_pos.position())?;
 
-            if Self::is_identifier_start(ch) {
+            if is_identifier_start(ch) {
                 contains_escaped_chars = true;
                 String::from(
                     char::try_from(ch)
@@ -133,14 +156,14 @@ impl Identifier {
                     let _next = cursor.next_char();
                     let ch = StringLiteral::take_unicode_escape_sequence(cursor, pos)?;
 
-                    if Self::is_identifier_part(ch) {
+                    if is_identifier_part(ch) {
                         contains_escaped_chars = true;
                         ch
                     } else {
                         return Err(Error::Syntax("invalid identifier part".into(), pos));
                     }
                 }
-                Some(ch) if Self::is_identifier_part(ch) => {
+                Some(ch) if is_identifier_part(ch) => {
                     cursor.next_char()?;
                     ch
                 },
@@ -48,6 +48,7 @@ use boa_profiler::Profiler;
 
 pub use self::{
     error::Error,
+    identifier::{is_identifier_name, is_identifier_part, is_identifier_start},
     token::{Token, TokenKind},
 };
 
@@ -242,6 +243,7 @@ impl<R> Lexer<R> {
         // Ignore whitespace
         if is_whitespace(next_ch) {
             loop {
+                self.cursor.skip_ascii_run(ascii_whitespace_run)?;
                 start = self.cursor.pos_group();
                 let Some(next) = self.cursor.next_char()? else {
                     return Ok(None);
@@ -339,7 +341,7 @@ impl<R> Lexer<R> {
                 '\\' if self.cursor.peek_char()? == Some(0x0075 /* u */) => {
                     Identifier::new(c).lex(&mut self.cursor, start, interner)
                 }
-                _ if Identifier::is_identifier_start(c as u32) => {
+                _ if is_identifier_start(c as u32) => {
                     Identifier::new(c).lex(&mut self.cursor, start, interner)
                 }
                 #[allow(clippy::cast_possible_truncation)]
@@ -447,3 +449,11 @@ const fn is_whitespace(ch: u32) -> bool {
             0x1680 | 0x2000..=0x200A | 0x202F | 0x205F | 0x3000
     )
 }
+
+/// Returns the length of the ASCII whitespace at the start of `bytes`.
+fn ascii_whitespace_run(bytes: &[u8]) -> usize {
+    bytes
+        .iter()
+        .position(|&byte| !matches!(byte, b' ' | b'\t' | 0x0B | 0x0C))
+        .unwrap_or(bytes.len())
+}
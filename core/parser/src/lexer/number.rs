@@ -2,7 +2,7 @@
 
 use crate::lexer::{token::Numeric, Cursor, Error, Token, TokenKind, Tokenizer};
 use crate::source::ReadChar;
-use boa_ast::PositionGroup;
+use boa_ast::{PositionGroup, Span};
 use boa_interner::Interner;
 use boa_profiler::Profiler;
 use num_bigint::BigInt;
@@ -69,9 +69,11 @@ where
 {
     // The next part must be SignedInteger.
     // This is optionally a '+' or '-' followed by 1 or more DecimalDigits.
+    check_no_separator_after(cursor, "exponent indicator")?;
     match cursor.next_char()? {
         Some(0x2B /* + */) => {
             buf.push(b'+');
+            check_no_separator_after(cursor, "exponent sign")?;
             if !cursor.next_is_ascii_pred(&|ch| ch.is_digit(kind.base()))? {
                 // A digit must follow the + or - symbol.
                 return Err(Error::syntax("No digit found after + symbol", cursor.pos()));
@@ -79,6 +81,7 @@ where
         }
         Some(0x2D /* - */) => {
             buf.push(b'-');
+            check_no_separator_after(cursor, "exponent sign")?;
             if !cursor.next_is_ascii_pred(&|ch| ch.is_digit(kind.base()))? {
                 // A digit must follow the + or - symbol.
                 return Err(Error::syntax("No digit found after - symbol", cursor.pos()));
@@ -132,15 +135,20 @@ where
         match cursor.next_char()? {
             Some(0x5F /* _ */) if separator_allowed => {
                 if prev_is_underscore {
-                    return Err(Error::syntax(
+                    return Err(Error::numeric_literal(
                         "only one underscore is allowed as numeric separator",
-                        cursor.pos(),
+                        pos,
+                        Some("remove the repeated `_`".into()),
                     ));
                 }
                 prev_is_underscore = true;
             }
             Some(0x5F /* _ */) if !separator_allowed => {
-                return Err(Error::syntax("separator is not allowed", pos));
+                return Err(Error::numeric_literal(
+                    "numeric separators are not allowed in numbers with a leading 0",
+                    pos,
+                    Some("remove the `_`".into()),
+                ));
             }
             Some(c) => {
                 if char::from_u32(c).map(|ch| ch.is_digit(kind.base())) == Some(true) {
@@ -153,14 +161,44 @@ where
         }
     }
     if prev_is_underscore {
-        return Err(Error::syntax(
+        return Err(Error::numeric_literal(
             "underscores are not allowed at the end of numeric literals",
             pos,
+            Some("remove the trailing `_`".into()),
+        ));
+    }
+    Ok(())
+}
+
+/// Utility function for checking that a numeric separator does not directly follow a part of the
+/// literal that cannot be followed by one, like a base prefix or a decimal point.
+fn check_no_separator_after<R>(cursor: &mut Cursor<R>, after: &str) -> Result<(), Error>
+where
+    R: ReadChar,
+{
+    if cursor.peek_char()? == Some(0x5F /* _ */) {
+        return Err(Error::numeric_literal(
+            format!("numeric separator not allowed after {after}"),
+            cursor.pos(),
+            Some("remove the `_`".into()),
         ));
     }
     Ok(())
 }
 
+/// Skips the remaining characters of an invalid numeric literal, so that lexing can resume at the
+/// next token.
+fn skip_invalid_literal<R>(cursor: &mut Cursor<R>) -> Result<(), Error>
+where
+    R: ReadChar,
+{
+    let mut rest = Vec::new();
+    cursor.take_while_ascii_pred(&mut rest, &|ch| {
+        ch.is_ascii_alphanumeric() || ch == '$' || ch == '_'
+    })?;
+    Ok(())
+}
+
 /// Utility function for checking the `NumericLiteral` is not followed by an `IdentifierStart` or `DecimalDigit` character.
 ///
 /// More information:
@@ -193,6 +231,43 @@ impl<R> Tokenizer<R> for NumberLiteral {
     {
         let _timer = Profiler::global().start_event("NumberLiteral", "Lexing");
 
+        match self.lex_literal(cursor, start_pos) {
+            Ok(token) => Ok(token),
+            Err(err @ Error::IO(_)) => Err(err),
+            Err(err) => {
+                // Skip the rest of the invalid literal, leaving the cursor at the start of the
+                // next token.
+                skip_invalid_literal(cursor)?;
+
+                Err(match err {
+                    Error::NumericLiteral {
+                        message,
+                        position,
+                        suggestion,
+                        ..
+                    } => Error::NumericLiteral {
+                        message,
+                        position,
+                        span: Span::new(start_pos.position(), cursor.pos()),
+                        suggestion,
+                    },
+                    err => err,
+                })
+            }
+        }
+    }
+}
+
+impl NumberLiteral {
+    /// Lexes the numeric literal, returning at the first invalid character.
+    fn lex_literal<R>(
+        self,
+        cursor: &mut Cursor<R>,
+        start_pos: PositionGroup,
+    ) -> Result<Token, Error>
+    where
+        R: ReadChar,
+    {
         let mut buf = vec![self.init];
 
         // Default assume the number is a base 10 integer.
@@ -213,6 +288,8 @@ impl<R> Tokenizer<R> for NumberLiteral {
                         // HexIntegerLiteral
                         kind = NumericKind::Integer(16);
 
+                        check_no_separator_after(cursor, "number base prefix")?;
+
                         // Checks if the next char after '0x' is a digit of that base. if not return an error.
                         if !cursor.next_is_ascii_pred(&|ch| ch.is_ascii_hexdigit())? {
                             return Err(Error::syntax(
@@ -230,6 +307,8 @@ impl<R> Tokenizer<R> for NumberLiteral {
                         // OctalIntegerLiteral
                         kind = NumericKind::Integer(8);
 
+                        check_no_separator_after(cursor, "number base prefix")?;
+
                         // Checks if the next char after '0o' is a digit of that base. if not return an error.
                         if !cursor.next_is_ascii_pred(&|ch| ch.is_digit(8))? {
                             return Err(Error::syntax(
@@ -247,6 +326,8 @@ impl<R> Tokenizer<R> for NumberLiteral {
                         // BinaryIntegerLiteral
                         kind = NumericKind::Integer(2);
 
+                        check_no_separator_after(cursor, "number base prefix")?;
+
                         // Checks if the next char after '0b' is a digit of that base. if not return an error.
                         if !cursor.next_is_ascii_pred(&|ch| ch.is_digit(2))? {
                             return Err(Error::syntax(
@@ -273,9 +354,23 @@ impl<R> Tokenizer<R> for NumberLiteral {
                                 // LegacyOctalIntegerLiteral, or a number with leading 0s.
                                 if cursor.strict() {
                                     // LegacyOctalIntegerLiteral is forbidden with strict mode true.
-                                    return Err(Error::syntax(
-                                        "implicit octal literals are not allowed in strict mode",
+                                    // Consume the digits to suggest the equivalent octal literal.
+                                    let mut digits = Vec::new();
+                                    cursor.take_while_ascii_pred(&mut digits, &|c| {
+                                        c.is_ascii_digit()
+                                    })?;
+                                    if digits.iter().all(|d| (b'0'..=b'7').contains(d)) {
+                                        let digits = String::from_utf8_lossy(&digits);
+                                        return Err(Error::numeric_literal(
+                                            "implicit octal literals are not allowed in strict mode",
+                                            start_pos,
+                                            Some(format!("use `0o{digits}` instead").into()),
+                                        ));
+                                    }
+                                    return Err(Error::numeric_literal(
+                                        "leading 0's are not allowed in strict mode",
                                         start_pos,
+                                        Some("remove the leading `0`".into()),
                                     ));
                                 }
 
@@ -298,9 +393,10 @@ impl<R> Tokenizer<R> for NumberLiteral {
                                 // so therefore this must be a number with an unneeded leading 0. This is
                                 // forbidden in strict mode.
                                 if cursor.strict() {
-                                    return Err(Error::syntax(
+                                    return Err(Error::numeric_literal(
                                         "leading 0's are not allowed in strict mode",
                                         start_pos,
+                                        Some("remove the leading `0`".into()),
                                     ));
                                 }
                             }
@@ -356,12 +452,7 @@ impl<R> Tokenizer<R> for NumberLiteral {
                     }
                     kind = NumericKind::Rational;
 
-                    if cursor.peek_char()? == Some(0x005F /* _ */) {
-                        return Err(Error::syntax(
-                            "numeric separator not allowed after '.'",
-                            cursor.pos(),
-                        ));
-                    }
+                    check_no_separator_after(cursor, "'.'")?;
 
                     // Consume digits and separators until a non-digit non-separator
                     // character is encountered or all the characters are consumed.
@@ -1,6 +1,6 @@
 //! Boa's lexing for ECMAScript regex literals.
 
-use crate::lexer::{Cursor, Error, Token, TokenKind, Tokenizer};
+use crate::lexer::{is_identifier_part, Cursor, Error, Token, TokenKind, Tokenizer};
 use crate::source::ReadChar;
 use bitflags::bitflags;
 use boa_ast::{Position, PositionGroup};
@@ -121,6 +121,18 @@ impl<R> Tokenizer<R> for RegexLiteral {
         let flags_start = cursor.pos();
         cursor.take_while_ascii_pred(&mut flags, &char::is_alphabetic)?;
 
+        // Flags are `IdentifierPartChar`s, so any other identifier part following them, including
+        // escape sequences and non-ASCII characters, is an invalid flag.
+        if cursor
+            .peek_char()?
+            .is_some_and(|ch| ch == 0x005C /* \ */ || is_identifier_part(ch))
+        {
+            return Err(Error::syntax(
+                "invalid regular expression flag",
+                cursor.pos(),
+            ));
+        }
+
         // SAFETY: We have already checked that the bytes are valid UTF-8.
         let flags_str = unsafe { str::from_utf8_unchecked(flags.as_slice()) };
 
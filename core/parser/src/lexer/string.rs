@@ -270,7 +270,7 @@ impl StringLiteral {
 
             let mut s = String::with_capacity(code_point_buf.len());
             for c in code_point_buf {
-                if let Some(c) = char::from_u32(c) {
+                if let Some(c) = char::from_u32(c).filter(char::is_ascii_hexdigit) {
                     s.push(c);
                 } else {
                     return Err(Error::syntax(
@@ -315,7 +315,7 @@ impl StringLiteral {
 
             let mut s = String::with_capacity(buffer.len());
             for c in buffer {
-                if let Some(c) = char::from_u32(c) {
+                if let Some(c) = char::from_u32(c).filter(char::is_ascii_hexdigit) {
                     s.push(c);
                 } else {
                     return Err(Error::syntax("invalid Unicode escape sequence", start_pos));
@@ -347,7 +347,7 @@ impl StringLiteral {
 
         let mut s = String::with_capacity(buffer.len());
         for c in buffer {
-            if let Some(c) = char::from_u32(c) {
+            if let Some(c) = char::from_u32(c).filter(char::is_ascii_hexdigit) {
                 s.push(c);
             } else {
                 return Err(Error::syntax(
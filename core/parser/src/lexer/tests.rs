@@ -1,11 +1,12 @@
 //! Tests for the lexer.
 
 use crate::lexer::{
+    is_identifier_name, is_identifier_part, is_identifier_start,
     template::TemplateString,
     token::{ContainsEscapeSequence, EscapeSequence, Numeric},
     Cursor, Error, Interner, Lexer, Punctuator, TokenKind,
 };
-use crate::source::ReadChar;
+use crate::source::{ReadChar, UTF16Input};
 use boa_ast::{Keyword, Position, Span};
 use boa_interner::Sym;
 use boa_macros::utf16;
@@ -77,6 +78,28 @@ fn check_multi_line_comment() {
     expect_tokens(&mut lexer, &expected, interner);
 }
 
+#[test]
+fn check_long_comment_positions() {
+    // The comments are longer than the chunks in which the input is read, and contain non-ASCII
+    // characters that are not skipped in bulk.
+    let comment = format!("ä{}é", "a".repeat(10_000));
+    let s = format!("  //{comment}\n    /*{comment}*/ x");
+    let mut lexer = Lexer::from(s.as_bytes());
+    let interner = &mut Interner::default();
+
+    let line_terminator = lexer.next(interner).unwrap().unwrap();
+    assert_eq!(line_terminator.kind(), &TokenKind::LineTerminator);
+    assert_eq!(line_terminator.span(), span((1, 10007), (2, 1)));
+
+    let x = lexer.next(interner).unwrap().unwrap();
+    assert_eq!(
+        x.kind(),
+        &TokenKind::identifier(interner.get_or_intern_static("x", utf16!("x")))
+    );
+    assert_eq!(x.span(), span((2, 10012), (2, 10013)));
+    assert_eq!(x.linear_span().start().pos(), 21018);
+}
+
 #[test]
 fn check_identifier() {
     let s = "x x1 _x $x __ $$ Ѐ ЀЀ x\u{200C}\u{200D} \\u0078 \\u0078\\u0078 \\u{0078}x\\u{0078}";
@@ -142,6 +165,66 @@ fn check_invalid_identifier_part() {
     }
 }
 
+#[test]
+fn check_astral_identifier() {
+    let s = "\u{102A7} x\u{1D7CE} \\u{102A7}\\u{1D7CE} \u{1D49C}b";
+    let interner = &mut Interner::default();
+
+    let expected = [
+        TokenKind::identifier(interner.get_or_intern("\u{102A7}")),
+        TokenKind::identifier(interner.get_or_intern("x\u{1D7CE}")),
+        TokenKind::IdentifierName((
+            interner.get_or_intern("\u{102A7}\u{1D7CE}"),
+            ContainsEscapeSequence(true),
+        )),
+        TokenKind::identifier(interner.get_or_intern("\u{1D49C}b")),
+    ];
+
+    let mut lexer = Lexer::from(s.as_bytes());
+    expect_tokens(&mut lexer, &expected, interner);
+
+    let utf16 = s.encode_utf16().collect::<Vec<_>>();
+    let mut lexer = Lexer::new(UTF16Input::new(&utf16));
+    expect_tokens(&mut lexer, &expected, interner);
+}
+
+#[test]
+fn check_invalid_identifier_escape() {
+    let invalid_identifiers = [
+        "\\u{1D7CE}",
+        "\\u{}",
+        "\\u{+78}",
+        "x\\u{+78}",
+        "\\u+078",
+        "x\\u{110000}",
+    ];
+
+    for s in &invalid_identifiers {
+        let mut lexer = Lexer::from(s.as_bytes());
+        let interner = &mut Interner::default();
+        lexer
+            .next(interner)
+            .expect_err("Invalid identifier escape not rejected as expected");
+    }
+}
+
+#[test]
+fn check_identifier_classification() {
+    assert!(is_identifier_start(u32::from('$')));
+    assert!(is_identifier_start(0x1_02A7));
+    assert!(!is_identifier_start(0x1_D7CE));
+    assert!(!is_identifier_start(0x200C));
+    assert!(is_identifier_part(0x1_D7CE));
+    assert!(is_identifier_part(0x200D));
+    assert!(!is_identifier_part(u32::from('-')));
+
+    assert!(is_identifier_name("x"));
+    assert!(is_identifier_name("\u{102A7}\u{1D7CE}"));
+    assert!(!is_identifier_name(""));
+    assert!(!is_identifier_name("\u{1D7CE}"));
+    assert!(!is_identifier_name("a-b"));
+}
+
 #[test]
 fn check_string() {
     let s = "'aaa' \"bbb\"";
@@ -635,6 +718,106 @@ fn numbers_with_bad_separators() {
     }
 }
 
+#[test]
+fn bad_separator_diagnostics() {
+    let cases = [
+        ("10__00", (1, 4), (1, 7), "remove the repeated `_`"),
+        ("10_", (1, 3), (1, 4), "remove the trailing `_`"),
+        ("0x_10", (1, 3), (1, 6), "remove the `_`"),
+        ("1._10", (1, 3), (1, 6), "remove the `_`"),
+        ("1e+_10", (1, 4), (1, 7), "remove the `_`"),
+        ("05_6", (1, 3), (1, 5), "remove the `_`"),
+    ];
+
+    for (n, position, end, expected) in cases {
+        let mut lexer = Lexer::from(n.as_bytes());
+        let interner = &mut Interner::default();
+
+        let err = lexer
+            .next(interner)
+            .expect_err("invalid separator not rejected");
+        if let Error::NumericLiteral {
+            position: pos,
+            span: literal_span,
+            suggestion,
+            ..
+        } = err
+        {
+            assert_eq!(pos, Position::new(position.0, position.1), "{n}");
+            assert_eq!(literal_span, span((1, 1), end), "{n}");
+            assert_eq!(suggestion.as_deref(), Some(expected), "{n}");
+        } else {
+            panic!("invalid error type for {n}");
+        }
+    }
+}
+
+#[test]
+fn strict_legacy_octal_diagnostics() {
+    let mut lexer = Lexer::from(&b"0755 089"[..]);
+    lexer.set_strict(true);
+    let interner = &mut Interner::default();
+
+    let err = lexer
+        .next(interner)
+        .expect_err("legacy octal in strict mode not rejected");
+    assert_eq!(
+        err.to_string(),
+        "implicit octal literals are not allowed in strict mode at line 1, col 1 (help: use `0o755` instead)"
+    );
+    if let Error::NumericLiteral {
+        span: literal_span, ..
+    } = err
+    {
+        assert_eq!(literal_span, span((1, 1), (1, 5)));
+    } else {
+        panic!("invalid error type");
+    }
+
+    let err = lexer
+        .next(interner)
+        .expect_err("leading zero in strict mode not rejected");
+    assert_eq!(
+        err.to_string(),
+        "leading 0's are not allowed in strict mode at line 1, col 6 (help: remove the leading `0`)"
+    );
+    if let Error::NumericLiteral {
+        span: literal_span, ..
+    } = err
+    {
+        assert_eq!(literal_span, span((1, 6), (1, 9)));
+    } else {
+        panic!("invalid error type");
+    }
+
+    assert!(lexer.next(interner).unwrap().is_none());
+}
+
+#[test]
+fn invalid_numeric_literal_recovery() {
+    let mut lexer = Lexer::from(&b"1__0 + 2; 0b_1x * 3"[..]);
+    let interner = &mut Interner::default();
+
+    assert!(lexer.next(interner).is_err());
+    for expected in [
+        TokenKind::Punctuator(Punctuator::Add),
+        TokenKind::numeric_literal(2),
+        TokenKind::Punctuator(Punctuator::Semicolon),
+    ] {
+        assert_eq!(lexer.next(interner).unwrap().unwrap().kind(), &expected);
+    }
+
+    assert!(lexer.next(interner).is_err());
+    expect_tokens(
+        &mut lexer,
+        &[
+            TokenKind::Punctuator(Punctuator::Mul),
+            TokenKind::numeric_literal(3),
+        ],
+        interner,
+    );
+}
+
 #[test]
 fn big_exp_numbers() {
     let mut lexer = Lexer::from(&b"1.0e25 1.0e36 9.0e50"[..]);
@@ -778,6 +961,18 @@ fn regex_literal_flags_err() {
         .expect_err("Lexer did not handle regex literal with error");
 }
 
+#[test]
+fn regex_literal_non_ascii_flags_err() {
+    for s in ["/a/g\u{E9}", "/a/g\\u0069", "/a/g1"] {
+        let mut lexer = Lexer::from(s.as_bytes());
+        let interner = &mut Interner::default();
+
+        lexer
+            .next(interner)
+            .expect_err("Lexer did not reject invalid regex flag");
+    }
+}
+
 #[test]
 fn addition_no_spaces() {
     let mut lexer = Lexer::from(&b"1+1"[..]);
@@ -1,35 +1,26 @@
 use crate::{
-    lexer::{InputElement, Lexer, Token, TokenKind},
+    lexer::{Error as LexError, InputElement, Lexer, Token, TokenKind},
     parser::ParseResult,
     source::{ReadChar, UTF8Input},
     Error,
 };
-use boa_ast::{LinearPosition, PositionGroup};
+use boa_ast::{LinearPosition, Position, PositionGroup};
 use boa_interner::Interner;
 use boa_profiler::Profiler;
+use std::collections::VecDeque;
 
 #[cfg(test)]
 mod tests;
 
-/// The maximum number of tokens which can be peeked ahead.
-const MAX_PEEK_SKIP: usize = 3;
-
-/// The fixed size of the buffer used for storing values that are peeked ahead.
+/// Token buffer of the cursor, which allows peeking an arbitrary number of tokens ahead.
 ///
-/// The size is calculated for a worst case scenario, where we want to peek `MAX_PEEK_SKIP` tokens
-/// skipping line terminators, and the stream ends just after:
-/// ```text
-/// [\n, B, \n, C, \n, D, \n, E, \n, F, None]
-///   0  0   1  1   2  2   3  3   4  4  5
-/// ```
-const PEEK_BUF_SIZE: usize = (MAX_PEEK_SKIP + 1) * 2 + 1;
-
+/// Tokens are stored in a growable ring buffer of the peeked tokens. A `None` entry marks the end
+/// of the input, and is never consumed.
 #[derive(Debug)]
 pub(super) struct BufferedLexer<R> {
     lexer: Lexer<R>,
-    peeked: [Option<Token>; PEEK_BUF_SIZE],
-    read_index: usize,
-    write_index: usize,
+    tokens: VecDeque<Option<Token>>,
+    last_pos: Position,
     last_linear_pos: LinearPosition,
 }
 
@@ -40,19 +31,8 @@ where
     fn from(lexer: Lexer<R>) -> Self {
         Self {
             lexer,
-            peeked: [
-                None::<Token>,
-                None::<Token>,
-                None::<Token>,
-                None::<Token>,
-                None::<Token>,
-                None::<Token>,
-                None::<Token>,
-                None::<Token>,
-                None::<Token>,
-            ],
-            read_index: 0,
-            write_index: 0,
+            tokens: VecDeque::new(),
+            last_pos: Position::default(),
             last_linear_pos: LinearPosition::default(),
         }
     }
@@ -126,54 +106,72 @@ where
         self.lexer.set_module(module);
     }
 
-    /// Fills the peeking buffer with the next token.
+    /// Fills the buffer with the next token.
     ///
-    /// It will not fill two line terminators one after the other.
+    /// It will not fill two line terminators one after the other, and it won't fill anything
+    /// after the end of the input.
     fn fill(&mut self, interner: &mut Interner) -> ParseResult<()> {
-        debug_assert!(
-            self.write_index < PEEK_BUF_SIZE,
-            "write index went out of bounds"
-        );
-
-        let previous_index = self.write_index.checked_sub(1).unwrap_or(PEEK_BUF_SIZE - 1);
-
-        if let Some(ref token) = self.peeked[previous_index] {
-            if token.kind() == &TokenKind::LineTerminator {
+        let next = match self.tokens.back() {
+            Some(None) => return Ok(()),
+            Some(Some(token)) if token.kind() == &TokenKind::LineTerminator => {
                 // We don't want to have multiple contiguous line terminators in the buffer, since
                 // they have no meaning.
-                let next = loop {
-                    self.lexer.skip_html_close(interner)?;
-                    let next = self.lexer.next_no_skip(interner)?;
-                    if let Some(ref token) = next {
-                        match token.kind() {
-                            TokenKind::LineTerminator => { /* skip */ }
-                            TokenKind::Comment => self.lexer.skip_html_close(interner)?,
-                            _ => break next,
-                        }
-                    } else {
-                        break None;
-                    }
-                };
+                self.lex_after_line_terminator(interner)
+            }
+            _ => self.lexer.next(interner),
+        };
 
-                self.peeked[self.write_index] = next;
+        self.tokens.push_back(next?);
+        Ok(())
+    }
+
+    /// Lexes the next token which is not a line terminator.
+    fn lex_after_line_terminator(
+        &mut self,
+        interner: &mut Interner,
+    ) -> Result<Option<Token>, LexError> {
+        loop {
+            self.lexer.skip_html_close(interner)?;
+            let next = self.lexer.next_no_skip(interner)?;
+            if let Some(ref token) = next {
+                match token.kind() {
+                    TokenKind::LineTerminator => { /* skip */ }
+                    TokenKind::Comment => self.lexer.skip_html_close(interner)?,
+                    _ => return Ok(next),
+                }
             } else {
-                self.peeked[self.write_index] = self.lexer.next(interner)?;
+                return Ok(None);
             }
-        } else {
-            self.peeked[self.write_index] = self.lexer.next(interner)?;
         }
+    }
 
-        self.write_index = (self.write_index + 1) % PEEK_BUF_SIZE;
-        debug_assert_ne!(
-            self.read_index, self.write_index,
-            "we reached the read index with the write index"
-        );
-        debug_assert!(
-            self.read_index < PEEK_BUF_SIZE,
-            "read index went out of bounds"
-        );
+    /// Returns the index of the `skip_n`th token after the next token in the buffer, lexing as
+    /// many tokens as needed.
+    ///
+    /// If `skip_line_terminators` is `true`, then line terminators are not counted. The index
+    /// points to the end of the input if there are not enough tokens.
+    fn peek_index(
+        &mut self,
+        skip_n: usize,
+        skip_line_terminators: bool,
+        interner: &mut Interner,
+    ) -> ParseResult<usize> {
+        let mut index = 0;
+        let mut count = 0;
+        loop {
+            if index == self.tokens.len() {
+                self.fill(interner)?;
+            }
 
-        Ok(())
+            match &self.tokens[index] {
+                None => return Ok(index),
+                Some(token)
+                    if skip_line_terminators && token.kind() == &TokenKind::LineTerminator => {}
+                Some(_) if count == skip_n => return Ok(index),
+                Some(_) => count += 1,
+            }
+            index += 1;
+        }
     }
 
     /// Moves the cursor to the next token and returns the token.
@@ -188,36 +186,23 @@ where
         skip_line_terminators: bool,
         interner: &mut Interner,
     ) -> ParseResult<Option<Token>> {
-        if self.read_index == self.write_index {
-            self.fill(interner)?;
-        }
+        let index = self.peek_index(0, skip_line_terminators, interner)?;
 
-        if let Some(ref token) = self.peeked[self.read_index] {
-            if skip_line_terminators && token.kind() == &TokenKind::LineTerminator {
-                // We only store 1 contiguous line terminator, so if the one at `self.read_index`
-                // was a line terminator, we know that the next won't be one.
-                self.read_index = (self.read_index + 1) % PEEK_BUF_SIZE;
-                if self.read_index == self.write_index {
-                    self.fill(interner)?;
-                }
-            }
-            let tok = self.peeked[self.read_index].take();
-            self.read_index = (self.read_index + 1) % PEEK_BUF_SIZE;
+        // We do not consume the end of the input, since we should always return `None` from now
+        // on.
+        self.tokens.drain(..index);
+        let Some(token) = &self.tokens[0] else {
+            return Ok(None);
+        };
 
-            if let Some(tok) = &tok {
-                self.last_linear_pos = tok.linear_span().end();
-            }
+        self.last_pos = token.span().end();
+        self.last_linear_pos = token.linear_span().end();
 
-            Ok(tok)
-        } else {
-            // We do not update the read index, since we should always return `None` from now on.
-            Ok(None)
-        }
+        Ok(self.tokens.pop_front().flatten())
     }
 
     /// Peeks the `n`th token after the next token.
     ///
-    /// **Note:** `n` must be in the range `[0, 3]`.
     /// i.e. if there are tokens `A`, `B`, `C`, `D`, `E` and `peek(0, false)` returns `A` then:
     ///  - `peek(1, false) == peek(1, true) == B`.
     ///  - `peek(2, false)` will return `C`.
@@ -238,38 +223,14 @@ where
         skip_line_terminators: bool,
         interner: &mut Interner,
     ) -> ParseResult<Option<&Token>> {
-        assert!(
-            skip_n <= MAX_PEEK_SKIP,
-            "you cannot skip more than {MAX_PEEK_SKIP} elements",
-        );
-
-        let mut read_index = self.read_index;
-        let mut count = 0;
-        let res_token = loop {
-            if read_index == self.write_index {
-                self.fill(interner)?;
-            }
-
-            if let Some(ref token) = self.peeked[read_index] {
-                if skip_line_terminators && token.kind() == &TokenKind::LineTerminator {
-                    read_index = (read_index + 1) % PEEK_BUF_SIZE;
-                    // We only store 1 contiguous line terminator, so if the one at `self.read_index`
-                    // was a line terminator, we know that the next won't be one.
-                    if read_index == self.write_index {
-                        self.fill(interner)?;
-                    }
-                }
-                if count == skip_n {
-                    break self.peeked[read_index].as_ref();
-                }
-            } else {
-                break None;
-            }
-            read_index = (read_index + 1) % PEEK_BUF_SIZE;
-            count += 1;
-        };
+        let index = self.peek_index(skip_n, skip_line_terminators, interner)?;
+        Ok(self.tokens[index].as_ref())
+    }
 
-        Ok(res_token)
+    /// Gets the position at the end of the last consumed token.
+    #[inline]
+    pub(super) const fn last_pos(&self) -> Position {
+        self.last_pos
     }
 
     /// Gets current linear position in the source code.
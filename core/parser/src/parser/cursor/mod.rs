@@ -7,7 +7,7 @@ use crate::{
     source::ReadChar,
     Error,
 };
-use boa_ast::{LinearPosition, PositionGroup, Punctuator};
+use boa_ast::{LinearPosition, Position, PositionGroup, Punctuator};
 use boa_interner::Interner;
 use buffered_lexer::BufferedLexer;
 
@@ -31,12 +31,12 @@ pub(super) struct Cursor<R> {
     /// Indicate if the cursor is used in `JSON.parse`.
     json_parse: bool,
 
+    /// The end of the last arguments list with a trailing comma after a spread argument.
+    spread_trailing_comma: Option<Position>,
+
     /// A unique identifier for each parser instance.
     /// This is used to generate unique identifiers tagged template literals.
     identifier: u32,
-
-    /// Tracks the number of tagged templates that are currently being parsed.
-    tagged_templates_count: u32,
 }
 
 impl<R> Cursor<R>
@@ -49,8 +49,8 @@ where
             buffered_lexer: Lexer::new(reader).into(),
             arrow: false,
             json_parse: false,
+            spread_trailing_comma: None,
             identifier: 0,
-            tagged_templates_count: 0,
         }
     }
 
@@ -157,21 +157,33 @@ where
         self.json_parse = json_parse;
     }
 
+    /// Returns `true` if the arguments list ending at `end` has a trailing comma after a spread
+    /// argument, which cannot be refined to the parameters of an async arrow function.
+    pub(super) fn spread_trailing_comma(&self, end: Position) -> bool {
+        self.spread_trailing_comma == Some(end)
+    }
+
+    /// Sets the end of the last arguments list with a trailing comma after a spread argument.
+    pub(super) fn set_spread_trailing_comma(&mut self, end: Position) {
+        self.spread_trailing_comma = Some(end);
+    }
+
     /// Set the identifier of the cursor.
     #[inline]
     pub(super) fn set_identifier(&mut self, identifier: u32) {
         self.identifier = identifier;
     }
 
-    /// Get the identifier for a tagged template.
+    /// Get the identifier for a tagged template starting at the given position.
+    ///
+    /// The identifier combines the identifier of the cursor with the source location of the
+    /// template, so that each template site in the source gets its own identifier.
     #[inline]
-    pub(super) fn tagged_template_identifier(&mut self) -> u64 {
-        self.tagged_templates_count += 1;
-
+    pub(super) fn tagged_template_identifier(&self, start: PositionGroup) -> u64 {
         let identifier = u64::from(self.identifier);
-        let count = u64::from(self.tagged_templates_count);
+        let position = start.linear_position().pos() as u64;
 
-        (count << 32) | identifier
+        (position << 32) | identifier
     }
 
     /// Returns an error if the next token is not of kind `kind`.
@@ -305,6 +317,15 @@ where
         })
     }
 
+    /// Gets the position at the end of the last consumed token.
+    ///
+    /// This is the start of the source code if no tokens have been consumed yet. Useful to
+    /// anchor errors at the end of the input, where there is no token to point at.
+    #[inline]
+    pub(super) const fn last_pos(&self) -> Position {
+        self.buffered_lexer.last_pos()
+    }
+
     /// Gets current linear position in the source code.
     #[inline]
     pub(super) fn linear_pos(&self) -> LinearPosition {
@@ -12,24 +12,27 @@ use crate::{
     error::{Error, ErrorContext, ParseResult},
     lexer::{Error as LexError, TokenKind},
     parser::{
-        expression::BindingIdentifier,
-        function::{FormalParameters, FunctionBody},
+        expression::{primary::expression_to_formal_parameters, BindingIdentifier},
+        function::FunctionBody,
         name_in_lexically_declared_names, AllowIn, AllowYield, Cursor, OrAbrupt, TokenParser,
     },
     source::ReadChar,
 };
 use ast::{
-    operations::{bound_names, contains, lexically_declared_names, ContainsSymbol},
+    operations::{
+        bound_names, contains, contains_identifier, lexically_declared_names, ContainsSymbol,
+    },
     Keyword,
 };
 use boa_ast::{
     self as ast,
     declaration::Variable,
+    expression::Call,
     function::{FormalParameter, FormalParameterList},
     statement::Return,
-    Punctuator, Span, StatementList,
+    LinearSpan, Position, Punctuator, Span, StatementList,
 };
-use boa_interner::Interner;
+use boa_interner::{Interner, Sym};
 use boa_profiler::Profiler;
 
 /// Async arrow function parsing.
@@ -75,36 +78,118 @@ where
         let async_token_span = async_token.span();
         cursor.peek_expect_no_lineterminator(0, "async arrow function", interner)?;
 
-        let next_token = cursor.peek(0, interner).or_abrupt()?;
-        let (params, params_start_position) =
-            if next_token.kind() == &TokenKind::Punctuator(Punctuator::OpenParen) {
-                let params_start_position = cursor
-                    .expect(Punctuator::OpenParen, "async arrow function", interner)?
-                    .span()
-                    .end();
-
-                let params = FormalParameters::new(false, true).parse(cursor, interner)?;
-                cursor.expect(Punctuator::CloseParen, "async arrow function", interner)?;
-                (params, params_start_position)
-            } else {
-                let params_start_position = next_token.span().start();
-                let param = BindingIdentifier::new(self.allow_yield, true)
-                    .parse(cursor, interner)
-                    .set_context("async arrow function")?;
-                (
-                    FormalParameterList::from(FormalParameter::new(
-                        Variable::from_identifier(param, None),
-                        false,
-                    )),
-                    params_start_position,
-                )
+        let params_start_position = cursor.peek(0, interner).or_abrupt()?.span().start();
+        let param = BindingIdentifier::new(self.allow_yield, true)
+            .parse(cursor, interner)
+            .set_context("async arrow function")?;
+        let params = FormalParameterList::from(FormalParameter::new(
+            Variable::from_identifier(param, None),
+            false,
+        ));
+
+        self.parse_body(
+            params,
+            params_start_position,
+            start_linear_span,
+            async_token_span.start(),
+            cursor,
+            interner,
+        )
+    }
+}
+
+impl AsyncArrowFunction {
+    /// Parses an async arrow function whose head `async (...)` was parsed as a call to `async`,
+    /// refining the arguments of the [`CoverCallExpressionAndAsyncArrowHead`][spec] to the
+    /// parameters of the function.
+    ///
+    /// [spec]: https://tc39.es/ecma262/#prod-CoverCallExpressionAndAsyncArrowHead
+    pub(in crate::parser) fn parse_cover_head<R>(
+        self,
+        head: &Call,
+        start: Position,
+        start_linear_span: LinearSpan,
+        cursor: &mut Cursor<R>,
+        interner: &mut Interner,
+    ) -> ParseResult<ast::function::AsyncArrowFunction>
+    where
+        R: ReadChar,
+    {
+        let _timer = Profiler::global().start_event("AsyncArrowFunction", "Parsing");
+
+        let params_start_position = head.span().start();
+        let mut params = Vec::with_capacity(head.args().len());
+        for (i, arg) in head.args().iter().enumerate() {
+            let ast::Expression::Spread(spread) = arg else {
+                expression_to_formal_parameters(arg, &mut params, cursor.strict(), head.span())?;
+                continue;
             };
 
+            if i + 1 != head.args().len() || cursor.spread_trailing_comma(head.span().end()) {
+                return Err(Error::general(
+                    "rest parameter must be last formal parameter",
+                    spread.span().start(),
+                ));
+            }
+
+            let mut rest = Vec::with_capacity(1);
+            expression_to_formal_parameters(
+                spread.target(),
+                &mut rest,
+                cursor.strict(),
+                spread.span(),
+            )?;
+            match rest.pop() {
+                Some(param) if rest.is_empty() && param.init().is_none() => {
+                    params.push(FormalParameter::new(param.variable().clone(), true));
+                }
+                _ => {
+                    return Err(Error::general(
+                        "invalid rest parameter in async arrow function",
+                        spread.span().start(),
+                    ));
+                }
+            }
+        }
+        let params = FormalParameterList::from(params);
+
+        // The arguments were parsed in the context of the call, so `await` might have been parsed
+        // as an identifier, which is a reserved word in the parameters.
+        if contains_identifier(&params, Sym::AWAIT) {
+            return Err(Error::general(
+                "invalid use of `await` in the parameters of an async arrow function",
+                params_start_position,
+            ));
+        }
+
+        self.parse_body(
+            params,
+            params_start_position,
+            start_linear_span,
+            start,
+            cursor,
+            interner,
+        )
+    }
+
+    /// Parses the arrow and the body of an async arrow function, and checks the early errors of
+    /// the function.
+    fn parse_body<R>(
+        self,
+        params: FormalParameterList,
+        params_start_position: Position,
+        start_linear_span: LinearSpan,
+        start: Position,
+        cursor: &mut Cursor<R>,
+        interner: &mut Interner,
+    ) -> ParseResult<ast::function::AsyncArrowFunction>
+    where
+        R: ReadChar,
+    {
         cursor.peek_expect_no_lineterminator(0, "async arrow function", interner)?;
         cursor.expect(Punctuator::Arrow, "async arrow function", interner)?;
 
         let body = AsyncConciseBody::new(self.allow_in).parse(cursor, interner)?;
-
         // Early Error: ArrowFormalParameters are UniqueFormalParameters.
         if params.has_duplicates() {
             return Err(Error::lex(LexError::Syntax(
@@ -156,7 +241,7 @@ where
             params,
             body,
             linear_span,
-            Span::new(async_token_span.start(), body_span_end),
+            Span::new(start, body_span_end),
         ))
     }
 }
@@ -33,7 +33,7 @@ use boa_ast::{
     operations::{bound_names, contains, lexically_declared_names, ContainsSymbol},
     Expression, Keyword, Punctuator, Span,
 };
-use boa_interner::Interner;
+use boa_interner::{Interner, Sym};
 use boa_profiler::Profiler;
 
 pub(super) use exponentiation::ExponentiationExpression;
@@ -89,6 +89,9 @@ where
         let _timer = Profiler::global().start_event("AssignmentExpression", "Parsing");
         cursor.set_goal(InputElement::RegExp);
 
+        // If `async (` starts the expression, it might be the head of an async arrow function.
+        let mut async_arrow_head = false;
+
         match cursor.peek(0, interner).or_abrupt()?.kind() {
             // [+Yield]YieldExpression[?In, ?Await]
             TokenKind::Keyword((Keyword::Yield, _)) if self.allow_yield.0 => {
@@ -128,21 +131,25 @@ where
                 };
 
                 let peek_1 = cursor.peek(1, interner).or_abrupt()?.kind().clone();
-                if !cursor
+                let line_terminator = cursor
                     .peek_is_line_terminator(skip_n, interner)
-                    .or_abrupt()?
-                    && (matches!(peek_1, TokenKind::Punctuator(Punctuator::OpenParen))
-                        || (matches!(
-                            peek_1,
-                            TokenKind::IdentifierName(_)
-                                | TokenKind::Keyword((
-                                    Keyword::Yield | Keyword::Await | Keyword::Of,
-                                    _
-                                ))
-                        ) && matches!(
-                            cursor.peek(2, interner).or_abrupt()?.kind(),
-                            TokenKind::Punctuator(Punctuator::Arrow)
-                        )))
+                    .or_abrupt()?;
+                async_arrow_head = !line_terminator
+                    && matches!(peek_1, TokenKind::Punctuator(Punctuator::OpenParen));
+
+                if !line_terminator
+                    && matches!(
+                        peek_1,
+                        TokenKind::IdentifierName(_)
+                            | TokenKind::Keyword((
+                                Keyword::Yield | Keyword::Await | Keyword::Of,
+                                _
+                            ))
+                    )
+                    && matches!(
+                        cursor.peek(2, interner).or_abrupt()?.kind(),
+                        TokenKind::Punctuator(Punctuator::Arrow)
+                    )
                 {
                     return Ok(AsyncArrowFunction::new(self.allow_in, self.allow_yield)
                         .parse(cursor, interner)?
@@ -160,6 +167,21 @@ where
         let mut lhs = ConditionalExpression::new(self.allow_in, self.allow_yield, self.allow_await)
             .parse(cursor, interner)?;
 
+        // `async (...)` is parsed as a call with the `CoverCallExpressionAndAsyncArrowHead` cover
+        // grammar, and its arguments are refined to parameters if an arrow follows.
+        if let Expression::Call(call) = &lhs {
+            if async_arrow_head
+                && matches!(call.function(), Expression::Identifier(ident) if *ident == Sym::ASYNC)
+                && cursor
+                    .peek_no_skip_line_term(0, interner)?
+                    .is_some_and(|tok| tok.kind() == &TokenKind::Punctuator(Punctuator::Arrow))
+            {
+                return Ok(AsyncArrowFunction::new(self.allow_in, self.allow_yield)
+                    .parse_cover_head(call, position, start_linear_span, cursor, interner)?
+                    .into());
+            }
+        }
+
         // If the left hand side is a parameter list, we must parse an arrow function.
         if let Expression::FormalParameterList(parameters) = lhs {
             cursor.peek_expect_no_lineterminator(0, "arrow function", interner)?;
@@ -86,7 +86,11 @@ where
                     }
 
                     if let Some(next) = cursor.next_if(Punctuator::CloseParen, interner)? {
-                        break next.span().end();
+                        let end = next.span().end();
+                        if matches!(args.last(), Some(Expression::Spread(_))) {
+                            cursor.set_spread_trailing_comma(end);
+                        }
+                        break end;
                     }
                 }
                 _ => {
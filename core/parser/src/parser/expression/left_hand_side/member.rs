@@ -195,10 +195,11 @@ where
                                 ));
                             }
                             _ => {
-                                return Err(Error::unexpected(
+                                return Err(Error::expected(
+                                    ["identifier".to_owned()],
                                     token.to_string(interner),
                                     token.span(),
-                                    "expected super property",
+                                    "super property",
                                 ));
                             }
                         };
@@ -220,10 +221,11 @@ where
                         )
                     }
                     _ => {
-                        return Err(Error::unexpected(
+                        return Err(Error::expected(
+                            [".".to_owned(), "[".to_owned()],
                             token.to_string(interner),
                             token.span(),
-                            "expected super property",
+                            "super property",
                         ))
                     }
                 }
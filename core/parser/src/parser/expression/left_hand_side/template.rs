@@ -7,7 +7,11 @@ use crate::{
     source::ReadChar,
     Error,
 };
-use boa_ast::{self as ast, expression::TaggedTemplate, PositionGroup, Punctuator, Span};
+use boa_ast::{
+    self as ast,
+    expression::{literal::TemplateString, TaggedTemplate},
+    PositionGroup, Punctuator, Span,
+};
 use boa_interner::Interner;
 use boa_profiler::Profiler;
 
@@ -55,36 +59,46 @@ where
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
         let _timer = Profiler::global().start_event("TaggedTemplateLiteral", "Parsing");
 
-        let mut raws = Vec::new();
-        let mut cookeds = Vec::new();
+        let mut strings = Vec::new();
         let mut exprs = Vec::new();
 
         let mut token = cursor.next(interner).or_abrupt()?;
 
+        // The template object of a tagged template is cached per site, which is identified by
+        // the position of the template literal in the source code.
+        let identifier = cursor.tagged_template_identifier(token.start_group());
+
         loop {
-            match token.kind() {
+            let next_start = match token.kind() {
                 TokenKind::TemplateMiddle(template_string) => {
-                    raws.push(template_string.raw());
-                    cookeds.push(template_string.cooked());
+                    strings.push(TemplateString::new(
+                        template_string.cooked(),
+                        template_string.raw(),
+                        token.span(),
+                    ));
                     exprs.push(
                         Expression::new(true, self.allow_yield, self.allow_await)
                             .parse(cursor, interner)?,
                     );
-                    cursor.expect(
-                        TokenKind::Punctuator(Punctuator::CloseBlock),
-                        "template literal",
-                        interner,
-                    )?;
+                    cursor
+                        .expect(
+                            TokenKind::Punctuator(Punctuator::CloseBlock),
+                            "template literal",
+                            interner,
+                        )?
+                        .start_group()
                 }
                 TokenKind::TemplateNoSubstitution(template_string) => {
-                    raws.push(template_string.raw());
-                    cookeds.push(template_string.cooked());
+                    strings.push(TemplateString::new(
+                        template_string.cooked(),
+                        template_string.raw(),
+                        token.span(),
+                    ));
                     return Ok(TaggedTemplate::new(
                         self.tag,
-                        raws.into_boxed_slice(),
-                        cookeds.into_boxed_slice(),
+                        strings.into_boxed_slice(),
                         exprs.into_boxed_slice(),
-                        cursor.tagged_template_identifier(),
+                        identifier,
                         Span::new(self.start.position(), token.span().end()),
                     ));
                 }
@@ -94,8 +108,8 @@ where
                         self.start,
                     ))
                 }
-            }
-            token = cursor.lex_template(self.start, interner)?;
+            };
+            token = cursor.lex_template(next_start, interner)?;
         }
     }
 }
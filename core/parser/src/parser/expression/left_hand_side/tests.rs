@@ -1,7 +1,8 @@
-use crate::parser::tests::check_script_parser;
+use crate::{parser::tests::check_script_parser, Parser, Source};
 use boa_ast::{
-    expression::{access::SimplePropertyAccess, Call, Identifier},
-    Expression, Span, Statement,
+    expression::{access::SimplePropertyAccess, Call, Identifier, TaggedTemplate},
+    scope::Scope,
+    Expression, Span, Statement, StatementListItem,
 };
 use boa_interner::Interner;
 use boa_macros::utf16;
@@ -84,3 +85,48 @@ fn check_member_properties() {
     check_member_property_identifier!("null");
     check_member_property_identifier!("let");
 }
+
+#[test]
+fn check_tagged_template_strings() {
+    let interner = &mut Interner::default();
+    let script = Parser::new(Source::from_bytes("tag`a\\n${b}c`;\ntag`a\\n${b}c`;"))
+        .parse_script(&Scope::new_global(), interner)
+        .expect("failed to parse");
+
+    let templates = script
+        .statements()
+        .statements()
+        .iter()
+        .map(|item| match item {
+            StatementListItem::Statement(statement) => match &**statement {
+                Statement::Expression(Expression::TaggedTemplate(template)) => template,
+                _ => panic!("expected a tagged template"),
+            },
+            StatementListItem::Declaration(_) => panic!("expected a tagged template"),
+        })
+        .collect::<Vec<&TaggedTemplate>>();
+
+    let [first, second] = templates[..] else {
+        panic!("expected two tagged templates");
+    };
+
+    let strings = first.strings();
+    assert_eq!(strings.len(), 2);
+    assert_eq!(
+        strings[0]
+            .cooked()
+            .map(|s| interner.resolve_expect(s).to_string()),
+        Some("a\n".to_owned())
+    );
+    assert_eq!(
+        interner.resolve_expect(strings[0].raw()).to_string(),
+        "a\\n"
+    );
+    assert_eq!(strings[0].span(), Span::new((1, 4), (1, 10)));
+    assert_eq!(interner.resolve_expect(strings[1].raw()).to_string(), "c");
+    assert_eq!(strings[1].span(), Span::new((1, 11), (1, 14)));
+    assert_eq!(first.span(), Span::new((1, 1), (1, 14)));
+
+    // Each template site gets its own identifier, even if the sources are identical.
+    assert_ne!(first.identifier(), second.identifier());
+}
@@ -513,6 +513,48 @@ impl RelationalExpression {
             allow_await: allow_await.into(),
         }
     }
+
+    /// Parses a `PrivateIdentifier in ShiftExpression` expression, if the next tokens start one.
+    fn parse_private_in<R>(
+        self,
+        cursor: &mut Cursor<R>,
+        interner: &mut Interner,
+    ) -> ParseResult<Option<BinaryInPrivate>>
+    where
+        R: ReadChar,
+    {
+        if !self.allow_in.0 {
+            return Ok(None);
+        }
+
+        let token = cursor.peek(0, interner).or_abrupt()?;
+        let TokenKind::PrivateIdentifier(identifier) = token.kind() else {
+            return Ok(None);
+        };
+        let identifier = *identifier;
+        let identifier_span = token.span();
+
+        let token = cursor.peek(1, interner).or_abrupt()?;
+        match token.kind() {
+            TokenKind::Keyword((Keyword::In, true)) => Err(Error::general(
+                "Keyword must not contain escaped characters",
+                token.span().start(),
+            )),
+            TokenKind::Keyword((Keyword::In, false)) => {
+                cursor.advance(interner);
+                cursor.advance(interner);
+
+                let rhs = ShiftExpression::new(self.allow_yield, self.allow_await)
+                    .parse(cursor, interner)?;
+
+                Ok(Some(BinaryInPrivate::new(
+                    PrivateName::new(identifier, identifier_span),
+                    rhs,
+                )))
+            }
+            _ => Ok(None),
+        }
+    }
 }
 
 impl<R> TokenParser<R> for RelationalExpression
@@ -524,39 +566,13 @@ where
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
         let _timer = Profiler::global().start_event("Relation Expression", "Parsing");
 
-        if self.allow_in.0 {
-            let token = cursor.peek(0, interner).or_abrupt()?;
-            if let TokenKind::PrivateIdentifier(identifier) = token.kind() {
-                let identifier = *identifier;
-                let identifier_span = token.span();
-                let token = cursor.peek(1, interner).or_abrupt()?;
-                match token.kind() {
-                    TokenKind::Keyword((Keyword::In, true)) => {
-                        return Err(Error::general(
-                            "Keyword must not contain escaped characters",
-                            token.span().start(),
-                        ));
-                    }
-                    TokenKind::Keyword((Keyword::In, false)) => {
-                        cursor.advance(interner);
-                        cursor.advance(interner);
-
-                        let rhs = ShiftExpression::new(self.allow_yield, self.allow_await)
-                            .parse(cursor, interner)?;
-
-                        return Ok(BinaryInPrivate::new(
-                            PrivateName::new(identifier, identifier_span),
-                            rhs,
-                        )
-                        .into());
-                    }
-                    _ => {}
-                }
-            }
-        }
-
-        let mut lhs =
-            ShiftExpression::new(self.allow_yield, self.allow_await).parse(cursor, interner)?;
+        // `#x in obj` is only valid as the leftmost operand of a relational expression, but it
+        // can still be followed by other relational operators, like in `#x in a in b`.
+        let mut lhs = if let Some(private_in) = self.parse_private_in(cursor, interner)? {
+            private_in.into()
+        } else {
+            ShiftExpression::new(self.allow_yield, self.allow_await).parse(cursor, interner)?
+        };
 
         while let Some(tok) = cursor.peek(0, interner)? {
             match *tok.kind() {
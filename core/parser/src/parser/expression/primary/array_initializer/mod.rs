@@ -100,10 +100,11 @@ where
                     elements.push(None);
                 }
                 TokenKind::Punctuator(Punctuator::Spread) if next_comma => {
-                    return Err(Error::unexpected(
+                    return Err(Error::expected(
+                        [",".to_owned(), "]".to_owned()],
                         token.to_string(interner),
                         token.span(),
-                        "expected comma or end of array",
+                        "array literal",
                     ));
                 }
                 TokenKind::Punctuator(Punctuator::Spread) => {
@@ -123,10 +124,11 @@ where
                     last_spread = true;
                 }
                 _ if next_comma => {
-                    return Err(Error::unexpected(
+                    return Err(Error::expected(
+                        [",".to_owned(), "]".to_owned()],
                         token.to_string(interner),
                         token.span(),
-                        "expected comma or end of array",
+                        "array literal",
                     ));
                 }
                 _ => {
@@ -47,7 +47,7 @@ use boa_ast::{
     self as ast,
     declaration::Variable,
     expression::{
-        literal::{self, Literal, LiteralKind, TemplateElement},
+        literal::{self, Literal, LiteralKind, TemplateElement, TemplateString},
         operator::{assign::AssignTarget, binary::BinaryOp},
         Identifier, Parenthesized, This,
     },
@@ -215,7 +215,11 @@ where
                     ));
                 };
                 let temp = literal::TemplateLiteral::new(
-                    Box::new([TemplateElement::String(cooked)]),
+                    Box::new([TemplateElement::String(TemplateString::new(
+                        Some(cooked),
+                        template_string.raw(),
+                        tok.span(),
+                    ))]),
                     tok.span(),
                 );
                 cursor.advance(interner);
@@ -270,7 +274,7 @@ where
                     self.allow_yield,
                     self.allow_await,
                     tok.start_group(),
-                    cooked,
+                    TemplateString::new(Some(cooked), template_string.raw(), tok.span()),
                 );
                 cursor.advance(interner);
                 parser.parse(cursor, interner).map(Into::into)
@@ -493,9 +497,9 @@ where
                     "multiple expressions in parenthesized expression",
                 ));
             }
-            if let InnerExpression::Expression(expression) = &expressions[0] {
+            if let Some(InnerExpression::Expression(expression)) = expressions.pop() {
                 return Ok(ast::Expression::Parenthesized(Parenthesized::new(
-                    expression.clone(),
+                    expression,
                     Span::new(span_start.start(), span.end()),
                 )));
             }
@@ -562,7 +566,7 @@ where
 }
 
 /// Convert an expression to a formal parameter and append it to the given parameter list.
-fn expression_to_formal_parameters(
+pub(in crate::parser) fn expression_to_formal_parameters(
     node: &ast::Expression,
     parameters: &mut Vec<FormalParameter>,
     strict: bool,
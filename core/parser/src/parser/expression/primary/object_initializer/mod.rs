@@ -35,6 +35,7 @@ use boa_ast::{
         ClassElementName as ClassElementNameNode, FormalParameterList,
         FunctionBody as FunctionBodyAst, PrivateName,
     },
+    node_id::NodeIdSlot,
     operations::{
         bound_names, contains, has_direct_super_new, lexically_declared_names, ContainsSymbol,
     },
@@ -86,30 +87,14 @@ where
 
         let mut elements = Vec::new();
 
-        let mut has_proto = false;
-        let mut duplicate_proto_position = None;
-
         let end = loop {
             if let Some(token) = cursor.next_if(Punctuator::CloseBlock, interner)? {
                 break token.span().end();
             }
 
-            let position = cursor.peek(0, interner).or_abrupt()?.span().start();
-
             let property = PropertyDefinition::new(self.allow_yield, self.allow_await)
                 .parse(cursor, interner)?;
 
-            if let PropertyDefinitionNode::Property(PropertyNameNode::Literal(ident), _) = property
-            {
-                if ident.sym() == Sym::__PROTO__ {
-                    if has_proto && duplicate_proto_position.is_none() {
-                        duplicate_proto_position = Some(position);
-                    } else {
-                        has_proto = true;
-                    }
-                }
-            }
-
             elements.push(property);
 
             if let Some(token) = cursor.next_if(Punctuator::CloseBlock, interner)? {
@@ -127,19 +112,6 @@ where
             }
         };
 
-        if let Some(position) = duplicate_proto_position {
-            if !cursor.json_parse()
-                && cursor
-                    .peek(0, interner)?
-                    .is_none_or(|token| token.kind() != &TokenKind::Punctuator(Punctuator::Assign))
-            {
-                return Err(Error::general(
-                    "Duplicate __proto__ fields are not allowed in object literals.",
-                    position,
-                ));
-            }
-        }
-
         let start = open_block_token.span().start();
         Ok(literal::ObjectLiteral::new(elements, Span::new(start, end)))
     }
@@ -197,7 +169,10 @@ where
         if cursor.next_if(Punctuator::Spread, interner)?.is_some() {
             let node = AssignmentExpression::new(true, self.allow_yield, self.allow_await)
                 .parse(cursor, interner)?;
-            return Ok(PropertyDefinitionNode::SpreadObject(node));
+            return Ok(PropertyDefinitionNode::SpreadObject(
+                node,
+                NodeIdSlot::EMPTY,
+            ));
         }
 
         //Async [AsyncMethod, AsyncGeneratorMethod] object methods
@@ -340,7 +315,11 @@ where
                 }
             }
 
-            return Ok(PropertyDefinitionNode::Property(property_name, value));
+            return Ok(PropertyDefinitionNode::Property(
+                property_name,
+                value,
+                NodeIdSlot::EMPTY,
+            ));
         }
 
         let ordinary_method = cursor.peek(0, interner).or_abrupt()?.kind()
@@ -1025,6 +1004,10 @@ where
         let expr = AssignmentExpression::new(true, self.allow_yield, self.allow_await)
             .parse(cursor, interner)?;
 
-        Ok(PropertyDefinitionNode::CoverInitializedName(ident, expr))
+        Ok(PropertyDefinitionNode::CoverInitializedName(
+            ident,
+            expr,
+            NodeIdSlot::EMPTY,
+        ))
     }
 }
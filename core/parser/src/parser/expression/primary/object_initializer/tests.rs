@@ -1,4 +1,7 @@
-use crate::parser::tests::{check_invalid_script, check_script_parser};
+use crate::{
+    parser::tests::{check_invalid_script, check_script_parser},
+    Error, Parser, Source,
+};
 use boa_ast::{
     declaration::{LexicalDeclaration, Variable},
     expression::{
@@ -6,8 +9,10 @@ use boa_ast::{
         Identifier,
     },
     function::{FormalParameter, FormalParameterList, FormalParameterListFlags, FunctionBody},
+    node_id::NodeIdSlot,
     property::MethodDefinitionKind,
-    Declaration, Span, StatementList,
+    scope::Scope,
+    Declaration, Position, Span, StatementList,
 };
 use boa_interner::{Interner, Sym};
 use boa_macros::utf16;
@@ -28,6 +33,7 @@ fn check_object_literal() {
             )
             .into(),
             Literal::new(true, Span::new((2, 8), (2, 12))).into(),
+            NodeIdSlot::EMPTY,
         ),
         PropertyDefinition::Property(
             Identifier::new(
@@ -36,6 +42,7 @@ fn check_object_literal() {
             )
             .into(),
             Literal::new(false, Span::new((3, 8), (3, 13))).into(),
+            NodeIdSlot::EMPTY,
         ),
     ];
 
@@ -75,6 +82,7 @@ fn check_object_short_function() {
             )
             .into(),
             Literal::new(true, Span::new((2, 8), (2, 12))).into(),
+            NodeIdSlot::EMPTY,
         ),
         PropertyDefinition::MethodDefinition(ObjectMethodDefinition::new(
             Identifier::new(
@@ -139,6 +147,7 @@ fn check_object_short_function_arguments() {
             )
             .into(),
             Literal::new(true, Span::new((2, 8), (2, 12))).into(),
+            NodeIdSlot::EMPTY,
         ),
         PropertyDefinition::MethodDefinition(ObjectMethodDefinition::new(
             Identifier::new(
@@ -188,6 +197,7 @@ fn check_object_getter() {
             )
             .into(),
             Literal::new(true, Span::new((2, 8), (2, 12))).into(),
+            NodeIdSlot::EMPTY,
         ),
         PropertyDefinition::MethodDefinition(ObjectMethodDefinition::new(
             Identifier::new(
@@ -251,6 +261,7 @@ fn check_object_setter() {
             )
             .into(),
             Literal::new(true, Span::new((2, 8), (2, 12))).into(),
+            NodeIdSlot::EMPTY,
         ),
         PropertyDefinition::MethodDefinition(ObjectMethodDefinition::new(
             Identifier::new(
@@ -479,6 +490,7 @@ fn check_object_spread() {
             )
             .into(),
             Literal::new(1, Span::new((1, 16), (1, 17))).into(),
+            NodeIdSlot::EMPTY,
         ),
         PropertyDefinition::SpreadObject(
             Identifier::new(
@@ -486,6 +498,7 @@ fn check_object_spread() {
                 Span::new((1, 22), (1, 23)),
             )
             .into(),
+            NodeIdSlot::EMPTY,
         ),
     ];
 
@@ -660,6 +673,7 @@ fn check_async_property() {
         )
         .into(),
         Literal::new(true, Span::new((2, 12), (2, 16))).into(),
+        NodeIdSlot::EMPTY,
     )];
 
     check_script_parser(
@@ -683,3 +697,51 @@ fn check_async_property() {
         interner,
     );
 }
+
+/// Checks that invalid object literals are reported at the offending property.
+#[test]
+fn check_invalid_object_literal_position() {
+    fn error_of(js: &str) -> (Box<str>, Position) {
+        match Parser::new(Source::from_bytes(js))
+            .parse_script(&Scope::new_global(), &mut Interner::default())
+        {
+            Err(Error::General { message, position }) => (message, position),
+            result => panic!("expected a general error for `{js}`, got {result:?}"),
+        }
+    }
+
+    let (message, position) = error_of("function f() {\n  return { a, b = 1 };\n}");
+    assert_eq!(&*message, "invalid shorthand property initializer");
+    assert_eq!(position, Position::new(2, 15));
+
+    let (message, position) = error_of("x = {\n  __proto__: a,\n  \"__proto__\": b,\n};");
+    assert_eq!(
+        &*message,
+        "Duplicate __proto__ fields are not allowed in object literals."
+    );
+    assert_eq!(position, Position::new(3, 3));
+
+    let (_, position) = error_of("class C {\n  static {\n    ({ a = 1 }).a;\n  }\n}");
+    assert_eq!(position, Position::new(3, 8));
+}
+
+/// Checks that duplicate `__proto__` properties are allowed in destructuring patterns.
+#[test]
+fn check_duplicate_proto_in_pattern() {
+    for js in [
+        "({ __proto__: a, __proto__: b } = {});",
+        "[{ __proto__: a, __proto__: b }] = [{}];",
+        "({ __proto__: a, __proto__: b }) => {};",
+        "for ({ __proto__: a, __proto__: b } of []);",
+        "({ __proto__: a, ['__proto__']: b, __proto__ });",
+    ] {
+        assert!(
+            Parser::new(Source::from_bytes(js))
+                .parse_script(&Scope::new_global(), &mut Interner::default())
+                .is_ok(),
+            "`{js}` should be valid"
+        );
+    }
+
+    check_invalid_script("[{ __proto__: a, __proto__: b }.c] = [];");
+}
@@ -14,10 +14,10 @@ use crate::{
     Error,
 };
 use boa_ast::{
-    expression::literal::{self, TemplateElement},
+    expression::literal::{self, TemplateElement, TemplateString},
     PositionGroup, Punctuator, Span,
 };
-use boa_interner::{Interner, Sym};
+use boa_interner::Interner;
 use boa_profiler::Profiler;
 
 /// Parses a template literal.
@@ -33,7 +33,7 @@ pub(super) struct TemplateLiteral {
     allow_yield: AllowYield,
     allow_await: AllowAwait,
     start: PositionGroup,
-    first: Sym,
+    first: TemplateString,
 }
 
 impl TemplateLiteral {
@@ -42,7 +42,7 @@ impl TemplateLiteral {
         allow_yield: Y,
         allow_await: A,
         start: PositionGroup,
-        first: Sym,
+        first: TemplateString,
     ) -> Self
     where
         Y: Into<AllowYield>,
@@ -73,14 +73,14 @@ where
                     .parse(cursor, interner)?,
             ),
         ];
-        cursor.expect(
+        let mut close_block = cursor.expect(
             TokenKind::Punctuator(Punctuator::CloseBlock),
             "template literal",
             interner,
         )?;
 
         loop {
-            let token = cursor.lex_template(self.start, interner)?;
+            let token = cursor.lex_template(close_block.start_group(), interner)?;
             match token.kind() {
                 TokenKind::TemplateMiddle(template_string) => {
                     let Some(cooked) = template_string.cooked() else {
@@ -89,12 +89,16 @@ where
                             self.start,
                         ));
                     };
-                    elements.push(TemplateElement::String(cooked));
+                    elements.push(TemplateElement::String(TemplateString::new(
+                        Some(cooked),
+                        template_string.raw(),
+                        token.span(),
+                    )));
                     elements.push(TemplateElement::Expr(
                         Expression::new(true, self.allow_yield, self.allow_await)
                             .parse(cursor, interner)?,
                     ));
-                    cursor.expect(
+                    close_block = cursor.expect(
                         TokenKind::Punctuator(Punctuator::CloseBlock),
                         "template literal",
                         interner,
@@ -107,7 +111,11 @@ where
                             self.start,
                         ));
                     };
-                    elements.push(TemplateElement::String(cooked));
+                    elements.push(TemplateElement::String(TemplateString::new(
+                        Some(cooked),
+                        template_string.raw(),
+                        token.span(),
+                    )));
                     return Ok(literal::TemplateLiteral::new(
                         elements.into(),
                         Span::new(self.start.position(), token.span().end()),
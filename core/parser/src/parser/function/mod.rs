@@ -27,11 +27,10 @@ use ast::{
 use boa_ast::{
     self as ast,
     declaration::Variable,
-    expression::Identifier,
     function::{FormalParameterList, FormalParameterListFlags, FunctionBody as AstFunctionBody},
     Punctuator, Span,
 };
-use boa_interner::{Interner, Sym};
+use boa_interner::Interner;
 use boa_profiler::Profiler;
 
 /// Formal parameters parsing.
@@ -121,7 +120,15 @@ where
                 ));
             }
 
-            cursor.expect(Punctuator::Comma, "parameter list", interner)?;
+            if cursor.next_if(Punctuator::Comma, interner)?.is_none() {
+                let next = cursor.next(interner).or_abrupt()?;
+                return Err(Error::expected(
+                    [",".to_owned(), ")".to_owned()],
+                    next.to_string(interner),
+                    next.span(),
+                    "parameter list",
+                ));
+            }
             if cursor
                 .peek(0, interner)?
                 .is_none_or(|tok| tok.kind() == &TokenKind::Punctuator(Punctuator::CloseParen))
@@ -305,13 +312,7 @@ where
             };
             Ok(Self::Output::new(declaration, true))
         } else {
-            Ok(Self::Output::new(
-                Variable::from_identifier(
-                    Identifier::new(Sym::EMPTY_STRING, Span::new((1234, 1234), (1234, 1234))),
-                    None,
-                ),
-                true,
-            ))
+            Err(unexpected_end_of_parameter(cursor, "rest parameter"))
         }
     }
 }
@@ -407,17 +408,26 @@ where
             };
             Ok(Self::Output::new(declaration, false))
         } else {
-            Ok(Self::Output::new(
-                Variable::from_identifier(
-                    Identifier::new(Sym::EMPTY_STRING, Span::new((1234, 1234), (1234, 1234))),
-                    None,
-                ),
-                false,
-            ))
+            Err(unexpected_end_of_parameter(cursor, "formal parameter"))
         }
     }
 }
 
+/// Creates the error for a parameter that is missing because the input ended, anchored at the
+/// end of the last consumed token.
+fn unexpected_end_of_parameter<R>(cursor: &Cursor<R>, context: &'static str) -> Error
+where
+    R: ReadChar,
+{
+    let position = cursor.last_pos();
+    Error::expected(
+        ["identifier".to_owned(), "[".to_owned(), "{".to_owned()],
+        "end of file",
+        Span::new(position, position),
+        context,
+    )
+}
+
 /// A `FunctionBody` is equivalent to a `FunctionStatementList`.
 ///
 /// More information:
@@ -506,11 +516,8 @@ where
             )));
         }
 
-        if contains_invalid_object_literal(&body) {
-            return Err(Error::lex(LexError::Syntax(
-                "invalid object literal in function statement list".into(),
-                Position::new(1, 1),
-            )));
+        if let Some(error) = contains_invalid_object_literal(&body) {
+            return Err(Error::general(error.message(), error.span().start()));
         }
 
         let end = if self.parse_full_input {
@@ -1,4 +1,7 @@
-use crate::parser::tests::{check_invalid_script, check_script_parser};
+use crate::{
+    parser::tests::{check_invalid_script, check_script_parser},
+    Error, Parser, Source,
+};
 use boa_ast::{
     declaration::{LexicalDeclaration, Variable},
     expression::{
@@ -9,6 +12,7 @@ use boa_ast::{
         ArrowFunction, FormalParameter, FormalParameterList, FormalParameterListFlags,
         FunctionBody, FunctionDeclaration,
     },
+    scope::Scope,
     statement::Return,
     Declaration, Span, Statement, StatementList, StatementListItem,
 };
@@ -1206,3 +1210,23 @@ fn check_arrow_assignment_3arg_nobrackets() {
         interner,
     );
 }
+
+/// Checks that a rest parameter cut off by the end of the input is reported at the end of the
+/// last token.
+#[test]
+fn check_rest_parameter_at_end_of_input() {
+    match Parser::new(Source::from_bytes("function f(a, ..."))
+        .parse_script(&Scope::new_global(), &mut Interner::default())
+    {
+        Err(Error::Expected { found, span, .. }) => {
+            assert_eq!(&*found, "end of file");
+            assert_eq!(span, Span::new((1, 18), (1, 18)));
+        }
+        result => panic!("expected an `Expected` error, got {result:?}"),
+    }
+
+    let mut parser = Parser::new(Source::from_bytes("a, ..."));
+    assert!(parser
+        .parse_formal_parameters(&mut Interner::default(), false, false)
+        .is_err());
+}
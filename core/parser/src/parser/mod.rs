@@ -21,9 +21,10 @@ use crate::{
 };
 use boa_ast::{
     function::{FormalParameterList, FunctionBody},
+    node_id::assign_node_ids,
     operations::{
-        all_private_identifiers_valid, check_labels, contains, contains_invalid_object_literal,
-        lexically_declared_names, var_declared_names, ContainsSymbol,
+        check_labels, contains, contains_invalid_object_literal, first_invalid_private_identifier,
+        lexically_declared_names, var_declared_names, ContainsSymbol, InvalidObjectLiteralError,
     },
     scope::Scope,
     Position, StatementList,
@@ -153,6 +154,8 @@ impl<'a, R: ReadChar> Parser<'a, R> {
     /// Parse the full input as a [ECMAScript Script][spec] into the boa AST representation with source text.
     /// The resulting `Script` can be compiled into boa bytecode and executed in the boa vm.
     ///
+    /// The nodes of the resulting `Script` are assigned their [`NodeId`][boa_ast::node_id::NodeId]s.
+    ///
     /// # Errors
     ///
     /// Will return `Err` on any parsing error, including invalid reads of the bytes being parsed.
@@ -171,6 +174,7 @@ impl<'a, R: ReadChar> Parser<'a, R> {
                 Position::new(1, 1),
             ));
         }
+        assign_node_ids(&mut ast);
         Ok((ast, source))
     }
 
@@ -196,6 +200,8 @@ impl<'a, R: ReadChar> Parser<'a, R> {
     /// Parse the full input as an [ECMAScript Module][spec] into the boa AST representation with source text.
     /// The resulting `ModuleItemList` can be compiled into boa bytecode and executed in the boa vm.
     ///
+    /// The nodes of the resulting `Module` are assigned their [`NodeId`][boa_ast::node_id::NodeId]s.
+    ///
     /// # Errors
     ///
     /// Will return `Err` on any parsing error, including invalid reads of the bytes being parsed.
@@ -217,6 +223,7 @@ impl<'a, R: ReadChar> Parser<'a, R> {
                 Position::new(1, 1),
             ));
         }
+        assign_node_ids(&mut module);
         Ok((module, source))
     }
 
@@ -235,7 +242,9 @@ impl<'a, R: ReadChar> Parser<'a, R> {
         interner: &mut Interner,
     ) -> ParseResult<ScriptParseOutput> {
         self.cursor.set_goal(InputElement::HashbangOrRegExp);
-        ScriptParser::new(direct).parse(&mut self.cursor, interner)
+        let (mut ast, source) = ScriptParser::new(direct).parse(&mut self.cursor, interner)?;
+        assign_node_ids(&mut ast);
+        Ok((ast, source))
     }
 
     /// Parses the full input as an [ECMAScript `FunctionBody`][spec] into the boa AST representation.
@@ -253,7 +262,9 @@ impl<'a, R: ReadChar> Parser<'a, R> {
     ) -> ParseResult<FunctionBody> {
         let mut parser = FunctionStatementList::new(allow_yield, allow_await, "function body");
         parser.parse_full_input(true);
-        parser.parse(&mut self.cursor, interner)
+        let mut body = parser.parse(&mut self.cursor, interner)?;
+        assign_node_ids(&mut body);
+        Ok(body)
     }
 
     /// Parses the full input as an [ECMAScript `FormalParameterList`][spec] into the boa AST representation.
@@ -269,7 +280,10 @@ impl<'a, R: ReadChar> Parser<'a, R> {
         allow_yield: bool,
         allow_await: bool,
     ) -> ParseResult<FormalParameterList> {
-        FormalParameters::new(allow_yield, allow_await).parse(&mut self.cursor, interner)
+        let mut parameters =
+            FormalParameters::new(allow_yield, allow_await).parse(&mut self.cursor, interner)?;
+        assign_node_ids(&mut parameters);
+        Ok(parameters)
     }
 }
 
@@ -417,10 +431,13 @@ where
             // It is a Syntax Error if AllPrivateIdentifiersValid of StatementList with
             // argument « » is false unless the source text containing ScriptBody is
             // eval code that is being processed by a direct eval.
-            if !all_private_identifiers_valid(&body, Vec::new()) {
+            if let Some(name) = first_invalid_private_identifier(&body, Vec::new()) {
                 return Err(Error::general(
-                    "invalid private identifier usage",
-                    Position::new(1, 1),
+                    format!(
+                        "undeclared private name `#{}`",
+                        interner.resolve_expect(name.description())
+                    ),
+                    name.span().start(),
                 ));
             }
         }
@@ -432,11 +449,14 @@ where
             )));
         }
 
-        if contains_invalid_object_literal(&body) {
-            return Err(Error::lex(LexError::Syntax(
-                "invalid object literal in script statement list".into(),
-                Position::new(1, 1),
-            )));
+        if let Some(error) = contains_invalid_object_literal(&body) {
+            // `JSON.parse` allows duplicate `__proto__` properties, since they are just regular
+            // properties in JSON.
+            if !(cursor.json_parse()
+                && matches!(error, InvalidObjectLiteralError::DuplicateProto(_)))
+            {
+                return Err(Error::general(error.message(), error.span().start()));
+            }
         }
 
         Ok(body)
@@ -548,10 +568,13 @@ where
         })?;
 
         // It is a Syntax Error if AllPrivateIdentifiersValid of ModuleItemList with argument « » is false.
-        if !all_private_identifiers_valid(&module, Vec::new()) {
+        if let Some(name) = first_invalid_private_identifier(&module, Vec::new()) {
             return Err(Error::general(
-                "invalid private identifier usage",
-                Position::new(1, 1),
+                format!(
+                    "undeclared private name `#{}`",
+                    interner.resolve_expect(name.description())
+                ),
+                name.span().start(),
             ));
         }
 
@@ -91,10 +91,11 @@ where
             }
             _ if self.is_default.0 => Identifier::new(Sym::DEFAULT, span),
             _ => {
-                return Err(Error::unexpected(
+                return Err(Error::expected(
+                    ["identifier".to_owned()],
                     token.to_string(interner),
                     token.span(),
-                    "expected class identifier",
+                    "class declaration",
                 ))
             }
         };
@@ -357,7 +358,7 @@ where
                                     Some(_) => {
                                         return Err(Error::general(
                                             "private identifier has already been declared",
-                                            position,
+                                            name.span().start(),
                                         ));
                                     }
                                     None => {
@@ -387,7 +388,7 @@ where
                                     Some(_) => {
                                         return Err(Error::general(
                                             "private identifier has already been declared",
-                                            position,
+                                            name.span().start(),
                                         ));
                                     }
                                     None => {
@@ -416,7 +417,7 @@ where
                                 {
                                     return Err(Error::general(
                                         "private identifier has already been declared",
-                                        position,
+                                        name.span().start(),
                                     ));
                                 }
                             }
@@ -438,7 +439,7 @@ where
                     {
                         return Err(Error::general(
                             "private identifier has already been declared",
-                            position,
+                            field.name().span().start(),
                         ));
                     }
                 }
@@ -457,7 +458,7 @@ where
                     {
                         return Err(Error::general(
                             "private identifier has already been declared",
-                            position,
+                            field.name().span().start(),
                         ));
                     }
                 }
@@ -565,6 +566,45 @@ where
             _ => false,
         };
 
+        // `accessor` is only a keyword if it is followed by a class element name on the same
+        // line. Otherwise, it is the name of a field or a method.
+        let accessor = match cursor.peek(0, interner).or_abrupt()?.kind() {
+            TokenKind::IdentifierName((Sym::ACCESSOR, ContainsEscapeSequence(contains_escape))) => {
+                let contains_escape = *contains_escape;
+                let skip_n = if cursor.peek_is_line_terminator(0, interner).or_abrupt()? {
+                    2
+                } else {
+                    1
+                };
+                let is_line_terminator = cursor
+                    .peek_is_line_terminator(skip_n, interner)?
+                    .unwrap_or(true);
+                let token = cursor.peek(1, interner).or_abrupt()?;
+                match token.kind() {
+                    TokenKind::IdentifierName(_)
+                    | TokenKind::StringLiteral(_)
+                    | TokenKind::NumericLiteral(_)
+                    | TokenKind::Keyword(_)
+                    | TokenKind::NullLiteral(_)
+                    | TokenKind::PrivateIdentifier(_)
+                    | TokenKind::Punctuator(Punctuator::OpenBracket)
+                        if !is_line_terminator =>
+                    {
+                        if contains_escape {
+                            return Err(Error::general(
+                                "keyword must not contain escaped characters",
+                                token.span().start(),
+                            ));
+                        }
+                        cursor.advance(interner);
+                        true
+                    }
+                    _ => false,
+                }
+            }
+            _ => false,
+        };
+
         let is_keyword = !matches!(
             cursor.peek(1, interner).or_abrupt()?.kind(),
             TokenKind::Punctuator(
@@ -581,6 +621,95 @@ where
 
         let position = token.span().start();
         let element = match token.kind() {
+            _ if accessor => {
+                let name = match token.kind() {
+                    TokenKind::PrivateIdentifier(Sym::CONSTRUCTOR) => {
+                        return Err(Error::general(
+                            "class may not have a private auto-accessor named '#constructor'",
+                            position,
+                        ));
+                    }
+                    TokenKind::PrivateIdentifier(name) => {
+                        let name = PrivateName::new(*name, token.span());
+                        cursor.advance(interner);
+                        ClassElementName::PrivateName(name)
+                    }
+                    _ => {
+                        let name = PropertyName::new(self.allow_yield, self.allow_await)
+                            .parse(cursor, interner)?;
+                        if let Some(name) = name.literal() {
+                            if r#static {
+                                if [Sym::CONSTRUCTOR, Sym::PROTOTYPE].contains(&name.sym()) {
+                                    return Err(Error::general(
+                                        "class may not have static auto-accessors named 'constructor' or 'prototype'",
+                                        position,
+                                    ));
+                                }
+                            } else if name == Sym::CONSTRUCTOR {
+                                return Err(Error::general(
+                                    "class may not have auto-accessors named 'constructor'",
+                                    position,
+                                ));
+                            }
+                        }
+                        ClassElementName::PropertyName(name)
+                    }
+                };
+
+                let initializer = if cursor.next_if(Punctuator::Assign, interner)?.is_some() {
+                    let strict = cursor.strict();
+                    cursor.set_strict(true);
+                    let mut rhs =
+                        AssignmentExpression::new(true, self.allow_yield, self.allow_await)
+                            .parse(cursor, interner)?;
+                    cursor.expect_semicolon("expected semicolon", interner)?;
+                    cursor.set_strict(strict);
+                    match &name {
+                        ClassElementName::PropertyName(name) => {
+                            if let Some(name) = name.literal() {
+                                rhs.set_anonymous_function_definition_name(&name);
+                            }
+                        }
+                        ClassElementName::PrivateName(name) => {
+                            let function_name = interner.get_or_intern(
+                                [
+                                    utf16!("#"),
+                                    interner.resolve_expect(name.description()).utf16(),
+                                ]
+                                .concat()
+                                .as_slice(),
+                            );
+                            rhs.set_anonymous_function_definition_name(&Identifier::new(
+                                function_name,
+                                name.span(),
+                            ));
+                        }
+                    }
+                    Some(rhs)
+                } else {
+                    cursor.expect_semicolon("expected semicolon", interner)?;
+                    None
+                };
+
+                match name {
+                    ClassElementName::PropertyName(name) => {
+                        let field = ClassFieldDefinition::new(name, initializer, true);
+                        if r#static {
+                            function::ClassElement::StaticFieldDefinition(field)
+                        } else {
+                            function::ClassElement::FieldDefinition(field)
+                        }
+                    }
+                    ClassElementName::PrivateName(name) => {
+                        let field = PrivateFieldDefinition::new(name, initializer, true);
+                        if r#static {
+                            function::ClassElement::PrivateStaticFieldDefinition(field)
+                        } else {
+                            function::ClassElement::PrivateFieldDefinition(field)
+                        }
+                    }
+                }
+            }
             TokenKind::IdentifierName((Sym::CONSTRUCTOR, _)) if !r#static => {
                 cursor.advance(interner);
                 let strict = cursor.strict();
@@ -674,11 +803,8 @@ where
                         return Err(Error::general("invalid await usage", position));
                     }
 
-                    if contains_invalid_object_literal(&statement_list) {
-                        return Err(Error::lex(LexError::Syntax(
-                            "invalid object literal in class static block statement list".into(),
-                            position,
-                        )));
+                    if let Some(error) = contains_invalid_object_literal(&statement_list) {
+                        return Err(Error::general(error.message(), error.span().start()));
                     }
 
                     let end = cursor
@@ -955,8 +1081,11 @@ where
                     _ => {
                         let span = token.span();
                         cursor.expect_semicolon("expected semicolon", interner)?;
-                        let field =
-                            ClassFieldDefinition::new(Identifier::new(Sym::GET, span).into(), None);
+                        let field = ClassFieldDefinition::new(
+                            Identifier::new(Sym::GET, span).into(),
+                            None,
+                            false,
+                        );
                         if r#static {
                             function::ClassElement::StaticFieldDefinition(field)
                         } else {
@@ -1056,8 +1185,11 @@ where
                     _ => {
                         let span = token.span();
                         cursor.expect_semicolon("expected semicolon", interner)?;
-                        let field =
-                            ClassFieldDefinition::new(Identifier::new(Sym::SET, span).into(), None);
+                        let field = ClassFieldDefinition::new(
+                            Identifier::new(Sym::SET, span).into(),
+                            None,
+                            false,
+                        );
                         if r#static {
                             function::ClassElement::StaticFieldDefinition(field)
                         } else {
@@ -1095,11 +1227,12 @@ where
                         );
                         rhs.set_anonymous_function_definition_name(&Identifier::new(
                             function_name,
-                            Span::new((1234, 1234), (1234, 1234)),
+                            name_span,
                         ));
                         let field = PrivateFieldDefinition::new(
                             PrivateName::new(name, name_span),
                             Some(rhs),
+                            false,
                         );
                         if r#static {
                             function::ClassElement::PrivateStaticFieldDefinition(field)
@@ -1135,8 +1268,11 @@ where
                     }
                     _ => {
                         cursor.expect_semicolon("expected semicolon", interner)?;
-                        let field =
-                            PrivateFieldDefinition::new(PrivateName::new(name, name_span), None);
+                        let field = PrivateFieldDefinition::new(
+                            PrivateName::new(name, name_span),
+                            None,
+                            false,
+                        );
                         if r#static {
                             function::ClassElement::PrivateStaticFieldDefinition(field)
                         } else {
@@ -1183,7 +1319,7 @@ where
                         if let Some(name) = name.literal() {
                             rhs.set_anonymous_function_definition_name(&name);
                         }
-                        let field = ClassFieldDefinition::new(name, Some(rhs));
+                        let field = ClassFieldDefinition::new(name, Some(rhs), false);
                         if r#static {
                             function::ClassElement::StaticFieldDefinition(field)
                         } else {
@@ -1241,7 +1377,7 @@ where
                             }
                         }
                         cursor.expect_semicolon("expected semicolon", interner)?;
-                        let field = ClassFieldDefinition::new(name, None);
+                        let field = ClassFieldDefinition::new(name, None, false);
                         if r#static {
                             function::ClassElement::StaticFieldDefinition(field)
                         } else {
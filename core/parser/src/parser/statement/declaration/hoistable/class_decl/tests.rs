@@ -70,6 +70,7 @@ fn check_async_field_initialization() {
         )
         .into(),
         Some(Literal::new(1, Span::new((3, 7), (3, 8))).into()),
+        false,
     ))];
 
     check_script_parser(
@@ -106,6 +107,7 @@ fn check_async_field() {
         )
         .into(),
         None,
+        false,
     ))];
 
     check_script_parser(
@@ -230,3 +232,87 @@ fn check_new_target_with_property_access() {
         interner,
     );
 }
+
+#[test]
+fn check_auto_accessor() {
+    let interner = &mut Interner::default();
+
+    let elements = vec![ClassElement::FieldDefinition(ClassFieldDefinition::new(
+        Identifier::new(
+            interner.get_or_intern_static("x", utf16!("x")),
+            Span::new((2, 14), (2, 15)),
+        )
+        .into(),
+        Some(Literal::new(1, Span::new((2, 18), (2, 19))).into()),
+        true,
+    ))];
+
+    check_script_parser(
+        indoc! {"
+            class A {
+                accessor x = 1;
+            }
+        "},
+        [
+            Declaration::ClassDeclaration(Box::new(ClassDeclaration::new(
+                Identifier::new(
+                    interner.get_or_intern_static("A", utf16!("A")),
+                    Span::new((1, 7), (1, 8)),
+                ),
+                None,
+                None,
+                elements.into(),
+            )))
+            .into(),
+        ],
+        interner,
+    );
+}
+
+#[test]
+fn check_accessor_field_before_line_terminator() {
+    let interner = &mut Interner::default();
+
+    let elements = vec![
+        ClassElement::FieldDefinition(ClassFieldDefinition::new(
+            Identifier::new(
+                interner.get_or_intern_static("accessor", utf16!("accessor")),
+                Span::new((2, 5), (2, 13)),
+            )
+            .into(),
+            None,
+            false,
+        )),
+        ClassElement::FieldDefinition(ClassFieldDefinition::new(
+            Identifier::new(
+                interner.get_or_intern_static("x", utf16!("x")),
+                Span::new((3, 5), (3, 6)),
+            )
+            .into(),
+            None,
+            false,
+        )),
+    ];
+
+    check_script_parser(
+        indoc! {"
+            class A {
+                accessor
+                x
+            }
+        "},
+        [
+            Declaration::ClassDeclaration(Box::new(ClassDeclaration::new(
+                Identifier::new(
+                    interner.get_or_intern_static("A", utf16!("A")),
+                    Span::new((1, 7), (1, 8)),
+                ),
+                None,
+                None,
+                elements.into(),
+            )))
+            .into(),
+        ],
+        interner,
+    );
+}
@@ -155,7 +155,8 @@ fn parse_callable_declaration<R: ReadChar, C: CallableDeclaration>(
     let name = match token.kind() {
         TokenKind::Punctuator(Punctuator::OpenParen) => {
             if !c.is_default() {
-                return Err(Error::unexpected(
+                return Err(Error::expected(
+                    ["identifier".to_owned()],
                     token.to_string(interner),
                     token.span(),
                     c.error_context(),
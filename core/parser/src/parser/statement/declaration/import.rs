@@ -10,7 +10,7 @@
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/import
 
 use crate::{
-    lexer::TokenKind,
+    lexer::{token::ContainsEscapeSequence, Token, TokenKind},
     parser::{
         cursor::Cursor,
         statement::{declaration::FromClause, BindingIdentifier},
@@ -80,6 +80,24 @@ where
 
         cursor.expect((Keyword::Import, false), "import declaration", interner)?;
 
+        if is_source_phase_import(cursor, interner)? {
+            cursor.advance(interner);
+            let binding = ImportedBinding.parse(cursor, interner)?;
+            let module_identifier =
+                FromClause::new("import declaration").parse(cursor, interner)?;
+
+            return Ok(ImportClause::Source(binding).with_specifier(module_identifier));
+        }
+
+        if is_deferred_import(cursor, interner)? {
+            cursor.advance(interner);
+            let binding = NameSpaceImport.parse(cursor, interner)?;
+            let module_identifier =
+                FromClause::new("import declaration").parse(cursor, interner)?;
+
+            return Ok(ImportClause::Deferred(binding).with_specifier(module_identifier));
+        }
+
         let tok = cursor.peek(0, interner).or_abrupt()?;
 
         let import_clause = match tok.kind() {
@@ -160,6 +178,71 @@ where
     }
 }
 
+/// Checks if the next tokens start a source phase import (`import source x from "module-name"`).
+///
+/// `source` can also be the name of a default import (`import source from "module-name"`), so
+/// this looks ahead for the binding of the source object.
+///
+/// More information:
+///  - [Source phase imports proposal][proposal]
+///
+/// [proposal]: https://tc39.es/proposal-source-phase-imports/#sec-imports
+fn is_source_phase_import<R: ReadChar>(
+    cursor: &mut Cursor<R>,
+    interner: &mut Interner,
+) -> ParseResult<bool> {
+    if !matches!(
+        cursor.peek(0, interner)?.map(Token::kind),
+        Some(TokenKind::IdentifierName((
+            Sym::SOURCE,
+            ContainsEscapeSequence(false)
+        )))
+    ) {
+        return Ok(false);
+    }
+
+    match cursor.peek(1, interner)?.map(Token::kind) {
+        // `import source from "module-name"` is a default import, but
+        // `import source from from "module-name"` imports the source as `from`.
+        Some(TokenKind::IdentifierName((Sym::FROM, _))) => Ok(matches!(
+            cursor.peek(2, interner)?.map(Token::kind),
+            Some(TokenKind::IdentifierName((Sym::FROM, _)))
+        )),
+        Some(
+            TokenKind::IdentifierName(_) | TokenKind::Keyword((Keyword::Await | Keyword::Yield, _)),
+        ) => Ok(true),
+        _ => Ok(false),
+    }
+}
+
+/// Checks if the next tokens start a deferred import (`import defer * as x from "module-name"`).
+///
+/// More information:
+///  - [Deferred module evaluation proposal][proposal]
+///
+/// [proposal]: https://tc39.es/proposal-defer-import-eval/#sec-imports
+fn is_deferred_import<R: ReadChar>(
+    cursor: &mut Cursor<R>,
+    interner: &mut Interner,
+) -> ParseResult<bool> {
+    if !matches!(
+        cursor.peek(0, interner)?.map(Token::kind),
+        Some(TokenKind::IdentifierName((
+            Sym::DEFER,
+            ContainsEscapeSequence(false)
+        )))
+    ) {
+        return Ok(false);
+    }
+
+    // `import defer from "module-name"` is a default import, and deferred imports can only
+    // import namespaces.
+    Ok(matches!(
+        cursor.peek(1, interner)?.map(Token::kind),
+        Some(TokenKind::Punctuator(Punctuator::Mul))
+    ))
+}
+
 /// Parses an imported binding
 ///
 /// More information:
@@ -256,6 +339,8 @@ where
 enum ImportClause {
     Namespace(Option<Identifier>, Identifier),
     ImportList(Option<Identifier>, Box<[AstImportSpecifier]>),
+    Source(Identifier),
+    Deferred(Identifier),
 }
 
 impl ImportClause {
@@ -273,6 +358,12 @@ impl ImportClause {
                     AstImportDeclaration::new(default, ImportKind::Named { names }, specifier)
                 }
             }
+            Self::Source(binding) => {
+                AstImportDeclaration::new(None, ImportKind::Source { binding }, specifier)
+            }
+            Self::Deferred(binding) => {
+                AstImportDeclaration::new(None, ImportKind::Deferred { binding }, specifier)
+            }
         }
     }
 }
@@ -1,7 +1,8 @@
 use crate::parser::tests::{check_invalid_script, check_module_parser, check_script_parser};
 use boa_ast::{
     declaration::{
-        ExportDeclaration, ExportSpecifier, LexicalDeclaration, VarDeclaration, Variable,
+        ExportDeclaration, ExportSpecifier, ImportDeclaration, ImportKind, LexicalDeclaration,
+        ModuleSpecifier, VarDeclaration, Variable,
     },
     expression::{
         literal::{Literal, LiteralKind},
@@ -452,3 +453,83 @@ fn module_export_reserved() {
         interner,
     );
 }
+
+/// Checks source phase imports.
+#[test]
+fn module_import_source() {
+    let interner = &mut Interner::default();
+    let module = interner.get_or_intern_static("mod.wasm", utf16!("mod.wasm"));
+    let x = interner.get_or_intern_static("x", utf16!("x"));
+    check_module_parser(
+        "import source x from 'mod.wasm';",
+        vec![ModuleItem::ImportDeclaration(ImportDeclaration::new(
+            None,
+            ImportKind::Source {
+                binding: Identifier::new(x, Span::new((1, 15), (1, 16))),
+            },
+            ModuleSpecifier::new(module),
+        ))],
+        interner,
+    );
+
+    let interner = &mut Interner::default();
+    let module = interner.get_or_intern_static("mod.wasm", utf16!("mod.wasm"));
+    check_module_parser(
+        "import source from from 'mod.wasm';",
+        vec![ModuleItem::ImportDeclaration(ImportDeclaration::new(
+            None,
+            ImportKind::Source {
+                binding: Identifier::new(Sym::FROM, Span::new((1, 15), (1, 19))),
+            },
+            ModuleSpecifier::new(module),
+        ))],
+        interner,
+    );
+}
+
+/// Checks that `source` can still be used as the name of a default import.
+#[test]
+fn module_import_default_named_source() {
+    let interner = &mut Interner::default();
+    let module = interner.get_or_intern_static("mod.js", utf16!("mod.js"));
+    check_module_parser(
+        "import source from 'mod.js';",
+        vec![ModuleItem::ImportDeclaration(ImportDeclaration::new(
+            Some(Identifier::new(Sym::SOURCE, Span::new((1, 8), (1, 14)))),
+            ImportKind::DefaultOrUnnamed,
+            ModuleSpecifier::new(module),
+        ))],
+        interner,
+    );
+}
+
+/// Checks deferred imports.
+#[test]
+fn module_import_defer() {
+    let interner = &mut Interner::default();
+    let module = interner.get_or_intern_static("mod.js", utf16!("mod.js"));
+    let ns = interner.get_or_intern_static("ns", utf16!("ns"));
+    check_module_parser(
+        "import defer * as ns from 'mod.js';",
+        vec![ModuleItem::ImportDeclaration(ImportDeclaration::new(
+            None,
+            ImportKind::Deferred {
+                binding: Identifier::new(ns, Span::new((1, 19), (1, 21))),
+            },
+            ModuleSpecifier::new(module),
+        ))],
+        interner,
+    );
+
+    let interner = &mut Interner::default();
+    let module = interner.get_or_intern_static("mod.js", utf16!("mod.js"));
+    check_module_parser(
+        "import defer from 'mod.js';",
+        vec![ModuleItem::ImportDeclaration(ImportDeclaration::new(
+            Some(Identifier::new(Sym::DEFER, Span::new((1, 8), (1, 13)))),
+            ImportKind::DefaultOrUnnamed,
+            ModuleSpecifier::new(module),
+        ))],
+        interner,
+    );
+}
@@ -102,7 +102,13 @@ where
                     .end()
             }
             _ => {
-                return Err(Error::unexpected(
+                let expected = if self.allow_await.0 {
+                    vec!["(".to_owned(), "await".to_owned()]
+                } else {
+                    vec!["(".to_owned()]
+                };
+                return Err(Error::expected(
+                    expected,
                     next.to_string(interner),
                     next.span(),
                     "for statement",
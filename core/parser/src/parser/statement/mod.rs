@@ -48,7 +48,7 @@ use crate::{
     Error,
 };
 use ast::{
-    operations::{all_private_identifiers_valid, check_labels, contains_invalid_object_literal},
+    operations::{check_labels, contains_invalid_object_literal, first_invalid_private_identifier},
     Position,
 };
 use boa_ast::{
@@ -702,13 +702,17 @@ where
                 }
             }
 
-            if let Some(peek_token) = cursor.peek(0, interner)? {
-                if peek_token.kind() == &TokenKind::Punctuator(Punctuator::Comma) {
-                    cursor.expect(
-                        TokenKind::Punctuator(Punctuator::Comma),
+            let token = cursor.peek(0, interner).or_abrupt()?;
+            match token.kind() {
+                TokenKind::Punctuator(Punctuator::Comma) => cursor.advance(interner),
+                TokenKind::Punctuator(Punctuator::CloseBlock) => {}
+                _ => {
+                    return Err(Error::expected(
+                        [",".to_owned(), "}".to_owned()],
+                        token.to_string(interner),
+                        token.span(),
                         "object binding pattern",
-                        interner,
-                    )?;
+                    ))
                 }
             }
         }
@@ -912,19 +916,25 @@ where
                 }
             }
 
-            if let Some(peek_token) = cursor.peek(0, interner)? {
-                if peek_token.kind() == &TokenKind::Punctuator(Punctuator::Comma) {
-                    cursor.expect(
-                        TokenKind::Punctuator(Punctuator::Comma),
-                        "array binding pattern",
-                        interner,
-                    )?;
+            let token = cursor.peek(0, interner).or_abrupt()?;
+            match token.kind() {
+                TokenKind::Punctuator(Punctuator::Comma) => {
+                    cursor.advance(interner);
                     if last_elision_or_first {
                         patterns.push(ArrayPatternElement::Elision);
                     } else {
                         last_elision_or_first = true;
                     }
                 }
+                TokenKind::Punctuator(Punctuator::CloseBracket) => {}
+                _ => {
+                    return Err(Error::expected(
+                        [",".to_owned(), "]".to_owned()],
+                        token.to_string(interner),
+                        token.span(),
+                        "array binding pattern",
+                    ))
+                }
             }
         }
     }
@@ -957,11 +967,8 @@ where
                 )));
             }
 
-            if contains_invalid_object_literal(&item) {
-                return Err(Error::lex(LexError::Syntax(
-                    "invalid object literal in module item list".into(),
-                    Position::new(1, 1),
-                )));
+            if let Some(error) = contains_invalid_object_literal(&item) {
+                return Err(Error::general(error.message(), error.span().start()));
             }
 
             list.push(item);
@@ -970,10 +977,13 @@ where
         let list = list.into();
 
         // It is a Syntax Error if AllPrivateIdentifiersValid of ModuleItemList with argument « » is false.
-        if !all_private_identifiers_valid(&list, Vec::new()) {
+        if let Some(name) = first_invalid_private_identifier(&list, Vec::new()) {
             return Err(Error::general(
-                "invalid private identifier usage",
-                Position::new(1, 1),
+                format!(
+                    "undeclared private name `#{}`",
+                    interner.resolve_expect(name.description())
+                ),
+                name.span().start(),
             ));
         }
 
@@ -97,6 +97,7 @@ fn template() {
         }
         let a = 10;
         tag`result: ${a} \x26 ${a + 10}`;
+        let b = `result: ${a} \x26 ${a + 10}`;
         ",
     );
 }
@@ -2,7 +2,7 @@
 
 mod format;
 
-use std::convert::TryInto;
+use std::{borrow::Cow, convert::TryInto, ops::ControlFlow};
 
 use crate::{Parser, Source};
 use boa_ast::{
@@ -19,11 +19,13 @@ use boa_ast::{
         Call, Identifier, New, Parenthesized,
     },
     function::{
-        ArrowFunction, FormalParameter, FormalParameterList, FormalParameterListFlags,
-        FunctionBody, FunctionDeclaration,
+        ArrowFunction, ClassElement, FormalParameter, FormalParameterList,
+        FormalParameterListFlags, FunctionBody, FunctionDeclaration,
     },
+    node_id::{assign_node_ids, NodeId, NodeIdSlot, SideTable},
     scope::Scope,
     statement::{If, Return},
+    visitor::{VisitWith, Visitor},
     Expression, LinearPosition, LinearSpan, Module, ModuleItem, ModuleItemList, Script, Span,
     Statement, StatementList, StatementListItem,
 };
@@ -586,6 +588,7 @@ fn spread_in_object() {
             )
             .into(),
             Literal::new(1, Span::new((2, 8), (2, 9))).into(),
+            NodeIdSlot::EMPTY,
         ),
         PropertyDefinition::SpreadObject(
             Identifier::new(
@@ -593,6 +596,7 @@ fn spread_in_object() {
                 Span::new((3, 8), (3, 9)),
             )
             .into(),
+            NodeIdSlot::EMPTY,
         ),
     ];
 
@@ -774,3 +778,164 @@ fn stress_test_operations() {
         .parse_script(&Scope::new_global(), &mut Interner::default())
         .is_ok());
 }
+
+#[test]
+fn node_ids_are_assigned_in_pre_order() {
+    let interner = &mut Interner::default();
+    let mut script = Parser::new(Source::from_bytes("a + b;"))
+        .parse_script(&Scope::new_global(), interner)
+        .expect("failed to parse");
+
+    let statements = script.statements();
+    let [StatementListItem::Statement(statement)] = statements.statements() else {
+        panic!("expected a single statement");
+    };
+    let Statement::Expression(Expression::Binary(binary)) = &**statement else {
+        panic!("expected a binary expression");
+    };
+    let (Expression::Identifier(lhs), Expression::Identifier(rhs)) = (binary.lhs(), binary.rhs())
+    else {
+        panic!("expected identifier operands");
+    };
+
+    assert_eq!(script.id(), Some(NodeId::new(0)));
+    assert_eq!(statements.id(), Some(NodeId::new(1)));
+    assert_eq!(binary.id(), Some(NodeId::new(2)));
+    assert_eq!(lhs.id(), Some(NodeId::new(3)));
+    assert_eq!(rhs.id(), Some(NodeId::new(4)));
+
+    let mut names = SideTable::new();
+    for identifier in [lhs, rhs] {
+        names.insert(identifier.id().expect("missing node id"), identifier.sym());
+    }
+    assert_eq!(names.len(), 2);
+    assert_eq!(
+        names.get(NodeId::new(3)),
+        Some(&interner.get_or_intern_static("a", utf16!("a")))
+    );
+    assert_eq!(names.get(NodeId::new(2)), None);
+
+    // Nodes built by hand are not assigned ids, but still compare equal to parsed ones.
+    let lhs_by_hand = Identifier::new(lhs.sym(), lhs.span());
+    assert_eq!(lhs_by_hand.id(), None);
+    assert_eq!(lhs_by_hand, *lhs);
+
+    // Assigning the ids again gives the same ids.
+    assert_eq!(assign_node_ids(&mut script), 5);
+    assert_eq!(script.id(), Some(NodeId::new(0)));
+    assert_eq!(script.statements().id(), Some(NodeId::new(1)));
+}
+
+#[test]
+fn node_ids_cover_class_elements_and_property_definitions() {
+    /// Collects the ids of the class elements and property definitions of an AST.
+    #[derive(Default)]
+    struct ElementIds(Vec<Option<NodeId>>);
+
+    impl<'ast> Visitor<'ast> for ElementIds {
+        type BreakTy = ();
+
+        fn visit_class_element(&mut self, node: &'ast ClassElement) -> ControlFlow<()> {
+            self.0.push(node.id());
+            node.visit_with(self)
+        }
+
+        fn visit_property_definition(&mut self, node: &'ast PropertyDefinition) -> ControlFlow<()> {
+            self.0.push(node.id());
+            node.visit_with(self)
+        }
+    }
+
+    let interner = &mut Interner::default();
+    let script = Parser::new(Source::from_bytes(
+        "({ a: 1, b, ...c, m() {} }); (class { x = 1; #y; m() {} static {} });",
+    ))
+    .parse_script(&Scope::new_global(), interner)
+    .expect("failed to parse");
+
+    let mut ids = ElementIds::default();
+    let _ = ids.visit_script(&script);
+    assert_eq!(ids.0.len(), 8);
+    assert!(ids.0.iter().all(Option::is_some));
+    let mut sorted = ids.0.clone();
+    sorted.sort_unstable();
+    sorted.dedup();
+    assert_eq!(sorted.len(), 8);
+
+    // Ids are stored in the nodes, so they survive clones and moves.
+    let moved = Box::new(script);
+    let mut moved_ids = ElementIds::default();
+    let _ = moved_ids.visit_script(&moved);
+    assert_eq!(moved_ids.0, ids.0);
+}
+
+#[test]
+fn expected_token_suggestions() {
+    let suggestion_of = |js: &str| {
+        Parser::new(Source::from_bytes(js))
+            .parse_script(&Scope::new_global(), &mut Interner::default())
+            .expect_err("the script should be invalid")
+            .suggestion()
+            .map(Cow::into_owned)
+    };
+
+    assert_eq!(
+        suggestion_of("f(a b);").as_deref(),
+        Some("did you forget a `,`?")
+    );
+    assert_eq!(
+        suggestion_of("[1 2];").as_deref(),
+        Some("did you forget a `,`?")
+    );
+    assert_eq!(
+        suggestion_of("f(a;").as_deref(),
+        Some("did you forget a `)`?")
+    );
+    assert_eq!(
+        suggestion_of("function f(a b) {}").as_deref(),
+        Some("did you forget a `,`?")
+    );
+    assert_eq!(
+        suggestion_of("({ a: 1;").as_deref(),
+        Some("did you forget a `}`?")
+    );
+    assert_eq!(
+        suggestion_of("let a = 1 2").as_deref(),
+        Some("did you forget a `;`?")
+    );
+    assert_eq!(
+        suggestion_of("let [a b] = c;").as_deref(),
+        Some("did you forget a `,`?")
+    );
+    assert_eq!(
+        suggestion_of("let { a b } = c;").as_deref(),
+        Some("did you forget a `,`?")
+    );
+    assert_eq!(
+        suggestion_of("let { a = 1; } = c;").as_deref(),
+        Some("did you forget a `}`?")
+    );
+}
+
+#[test]
+fn expected_token_sets() {
+    let error_of = |js: &str| {
+        Parser::new(Source::from_bytes(js))
+            .parse_script(&Scope::new_global(), &mut Interner::default())
+            .expect_err("the script should be invalid")
+            .to_string()
+    };
+
+    assert_eq!(
+        error_of("({ m() { super + 1; } });"),
+        "expected one of '.' or '[', got '+' in super property at line 1, col 16"
+    );
+    assert_eq!(
+        error_of("({ m() { super.; } });"),
+        "expected token 'identifier', got ';' in super property at line 1, col 16"
+    );
+    assert_eq!(
+        error_of("class {}"),
+        "expected token 'identifier', got '{' in class declaration at line 1, col 7"
+    );
+}
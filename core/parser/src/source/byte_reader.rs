@@ -0,0 +1,78 @@
+use std::io::{self, Read};
+
+/// Size of the chunks in which the input is read.
+const BUFFER_SIZE: usize = 8 * 1024;
+
+/// Buffered reader of the bytes of an input, shared by the inputs of the byte based encodings.
+#[derive(Debug)]
+pub(super) struct ByteReader<R> {
+    input: R,
+    buffer: Box<[u8]>,
+    start: usize,
+    end: usize,
+}
+
+impl<R: Read> ByteReader<R> {
+    /// Creates a new `ByteReader` that reads from `input`.
+    pub(super) fn new(input: R) -> Self {
+        Self {
+            input,
+            buffer: Box::default(),
+            start: 0,
+            end: 0,
+        }
+    }
+
+    /// Reads the next chunk of the input into the buffer, returning `false` at the end of the
+    /// input.
+    ///
+    /// The buffer is only allocated the first time, so that it is not allocated for empty inputs.
+    fn fill_buffer(&mut self) -> io::Result<bool> {
+        if self.buffer.is_empty() {
+            self.buffer = vec![0; BUFFER_SIZE].into_boxed_slice();
+        }
+
+        loop {
+            match self.input.read(&mut self.buffer) {
+                Ok(len) => {
+                    self.start = 0;
+                    self.end = len;
+                    return Ok(len > 0);
+                }
+                Err(err) if err.kind() == io::ErrorKind::Interrupted => {}
+                Err(err) => return Err(err),
+            }
+        }
+    }
+
+    /// Retrieves the next byte
+    pub(super) fn next_byte(&mut self) -> io::Result<Option<u8>> {
+        if self.start == self.end && !self.fill_buffer()? {
+            return Ok(None);
+        }
+
+        let byte = self.buffer[self.start];
+        self.start += 1;
+        Ok(Some(byte))
+    }
+
+    /// Consumes the run of bytes at the start of the buffered bytes, with the length returned by
+    /// `run_len`.
+    ///
+    /// See [`ReadChar::next_ascii_run`][super::ReadChar::next_ascii_run].
+    pub(super) fn next_ascii_run(&mut self, run_len: fn(&[u8]) -> usize) -> io::Result<&[u8]> {
+        if self.start == self.end && !self.fill_buffer()? {
+            return Ok(&[]);
+        }
+
+        let start = self.start;
+        let len = run_len(&self.buffer[start..self.end]);
+        self.start += len;
+
+        let run = &self.buffer[start..self.start];
+        debug_assert!(run
+            .iter()
+            .all(|b| b.is_ascii() && !matches!(b, b'\n' | b'\r')));
+        Ok(run)
+    }
+}
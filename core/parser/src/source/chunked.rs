@@ -0,0 +1,116 @@
+use std::{
+    io::{self, Read},
+    sync::mpsc::{self, Receiver, SyncSender},
+};
+
+/// A reader of the chunks of an input pushed through a [`ChunkSender`], e.g. as they are received
+/// from the network.
+///
+/// Reading blocks until the next chunk is pushed, so the sender must be used from a different
+/// thread than the one parsing the source. The input ends when all the senders are dropped.
+///
+/// # Examples
+///
+/// ```
+/// # use boa_parser::{source::ChunkReader, Parser, Source};
+/// # use boa_ast::scope::Scope;
+/// # use boa_interner::Interner;
+/// let (sender, reader) = ChunkReader::channel(4);
+///
+/// std::thread::spawn(move || {
+///     for chunk in ["let answer ", "= 4", "2;"] {
+///         sender.push(chunk).unwrap();
+///     }
+/// });
+///
+/// let mut parser = Parser::new(Source::from_reader(reader, None));
+/// let script = parser.parse_script(&Scope::new_global(), &mut Interner::default());
+/// assert!(script.is_ok());
+/// ```
+#[derive(Debug)]
+pub struct ChunkReader {
+    receiver: Receiver<io::Result<Vec<u8>>>,
+    chunk: Vec<u8>,
+    position: usize,
+}
+
+impl ChunkReader {
+    /// Creates a new `ChunkReader`, and the [`ChunkSender`] that pushes the chunks of its input.
+    ///
+    /// Pushing a chunk blocks while there are `capacity` chunks that have not been read yet, so
+    /// that a fast sender doesn't buffer the whole input in memory.
+    #[must_use]
+    pub fn channel(capacity: usize) -> (ChunkSender, Self) {
+        let (sender, receiver) = mpsc::sync_channel(capacity);
+        (
+            ChunkSender { sender },
+            Self {
+                receiver,
+                chunk: Vec::new(),
+                position: 0,
+            },
+        )
+    }
+}
+
+impl Read for ChunkReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        while self.position == self.chunk.len() {
+            match self.receiver.recv() {
+                Ok(Ok(chunk)) => {
+                    self.chunk = chunk;
+                    self.position = 0;
+                }
+                Ok(Err(err)) => return Err(err),
+                // All the senders were dropped, so this is the end of the input.
+                Err(_) => return Ok(0),
+            }
+        }
+
+        let len = buf.len().min(self.chunk.len() - self.position);
+        buf[..len].copy_from_slice(&self.chunk[self.position..self.position + len]);
+        self.position += len;
+        Ok(len)
+    }
+}
+
+/// The sending half of a [`ChunkReader`], created by [`ChunkReader::channel`].
+#[derive(Debug, Clone)]
+pub struct ChunkSender {
+    sender: SyncSender<io::Result<Vec<u8>>>,
+}
+
+impl ChunkSender {
+    /// Pushes the next chunk of the input.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the reader was dropped, e.g. because the parser found an error before
+    /// reading the whole input.
+    pub fn push<T: Into<Vec<u8>>>(&self, chunk: T) -> io::Result<()> {
+        self.send(Ok(chunk.into()))
+    }
+
+    /// Ends the input with an error, e.g. if the connection it was received from was closed. The
+    /// reader returns the error after reading the chunks that were pushed before.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the reader was dropped.
+    pub fn abort(self, error: io::Error) -> io::Result<()> {
+        self.send(Err(error))
+    }
+
+    fn send(&self, chunk: io::Result<Vec<u8>>) -> io::Result<()> {
+        self.sender.send(chunk).map_err(|_| {
+            io::Error::new(
+                io::ErrorKind::BrokenPipe,
+                "the reader of the chunks was dropped",
+            )
+        })
+    }
+}
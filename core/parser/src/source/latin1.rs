@@ -0,0 +1,28 @@
+use super::{byte_reader::ByteReader, ReadChar};
+use std::io::{self, Read};
+
+/// Input for Latin-1 (ISO-8859-1) encoded sources, where every byte is a code point.
+#[derive(Debug)]
+pub struct Latin1Input<R> {
+    input: ByteReader<R>,
+}
+
+impl<R: Read> Latin1Input<R> {
+    /// Creates a new `Latin1Input` from a Latin-1 encoded source.
+    pub fn new(input: R) -> Self {
+        Self {
+            input: ByteReader::new(input),
+        }
+    }
+}
+
+impl<R: Read> ReadChar for Latin1Input<R> {
+    fn next_char(&mut self) -> io::Result<Option<u32>> {
+        Ok(self.input.next_byte()?.map(u32::from))
+    }
+
+    fn next_ascii_run(&mut self, run_len: fn(&[u8]) -> usize) -> io::Result<&[u8]> {
+        // ASCII is encoded in the same way as in UTF-8.
+        self.input.next_ascii_run(run_len)
+    }
+}
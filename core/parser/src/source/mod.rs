@@ -6,9 +6,14 @@ use std::{
     path::Path,
 };
 
-pub use utf16::UTF16Input;
+pub use chunked::{ChunkReader, ChunkSender};
+pub use latin1::Latin1Input;
+pub use utf16::{ByteOrder, UTF16ByteInput, UTF16Input};
 pub use utf8::UTF8Input;
 
+mod byte_reader;
+mod chunked;
+mod latin1;
 mod utf16;
 mod utf8;
 
@@ -119,6 +124,54 @@ impl<'path, R: Read> Source<'path, UTF8Input<R>> {
     }
 }
 
+impl<'path, R: Read> Source<'path, UTF16ByteInput<R>> {
+    /// Creates a new `Source` from a [`Read`] instance of UTF-16 encoded bytes and an optional
+    /// [`Path`].
+    ///
+    /// The byte order is detected from the byte order mark at the start of the input, and is
+    /// little endian if there is no byte order mark.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use boa_parser::Source;
+    /// let bytes: Vec<u8> = [0xFEFF]
+    ///     .into_iter()
+    ///     .chain("var array = [5, 4, 3, 2, 1];".encode_utf16())
+    ///     .flat_map(u16::to_be_bytes)
+    ///     .collect();
+    ///
+    /// let source = Source::from_utf16_reader(bytes.as_slice(), None);
+    /// ```
+    pub fn from_utf16_reader(reader: R, path: Option<&'path Path>) -> Self {
+        Self {
+            reader: UTF16ByteInput::new(reader, ByteOrder::default()),
+            path,
+        }
+    }
+}
+
+impl<'path, R: Read> Source<'path, Latin1Input<R>> {
+    /// Creates a new `Source` from a [`Read`] instance of Latin-1 (ISO-8859-1) encoded bytes and
+    /// an optional [`Path`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use boa_parser::Source;
+    /// // "var café = 'crème';"
+    /// let bytes = b"var caf\xE9 = 'cr\xE8me';";
+    ///
+    /// let source = Source::from_latin1_reader(&bytes[..], None);
+    /// ```
+    pub fn from_latin1_reader(reader: R, path: Option<&'path Path>) -> Self {
+        Self {
+            reader: Latin1Input::new(reader),
+            path,
+        }
+    }
+}
+
 impl<'path, R> Source<'path, R> {
     /// Sets the path of this [`Source`].
     pub fn with_path(self, new_path: &Path) -> Source<'_, R> {
@@ -142,6 +195,24 @@ pub trait ReadChar {
     ///
     /// Returns an error if the next input in the input is not a valid unicode code point.
     fn next_char(&mut self) -> io::Result<Option<u32>>;
+
+    /// Consumes a run of ASCII characters at the start of the input, returning it.
+    ///
+    /// `run_len` receives the bytes of the input that can be scanned without decoding them, and
+    /// returns the length of the run at their start, which must only contain ASCII characters
+    /// that are not line terminators. The returned run is empty if there were no bytes to scan,
+    /// in which case the input must be read with [`ReadChar::next_char`].
+    ///
+    /// This allows the lexer to skip whitespace and comments in bulk. The default
+    /// implementation doesn't consume anything.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input could not be read.
+    fn next_ascii_run(&mut self, run_len: fn(&[u8]) -> usize) -> io::Result<&[u8]> {
+        let _ = run_len;
+        Ok(&[])
+    }
 }
 
 #[cfg(test)]
@@ -207,4 +278,89 @@ mod tests {
 
         assert_eq!(content, "'Hello' + 'World';");
     }
+
+    fn read_content<R: ReadChar>(reader: &mut R) -> io::Result<String> {
+        let mut content = String::new();
+        while let Some(c) = reader.next_char()? {
+            content.push(char::from_u32(c).unwrap());
+        }
+        Ok(content)
+    }
+
+    #[test]
+    fn from_utf16_reader() {
+        let code = "'Hello' + '🌍';";
+        let units = || code.encode_utf16();
+
+        // Without byte order mark
+        let bytes: Vec<u8> = units().flat_map(u16::to_le_bytes).collect();
+        let mut source = Source::from_utf16_reader(bytes.as_slice(), None);
+        assert_eq!(read_content(&mut source.reader).unwrap(), code);
+
+        // Little endian byte order mark
+        let bytes: Vec<u8> = [0xFEFF]
+            .into_iter()
+            .chain(units())
+            .flat_map(u16::to_le_bytes)
+            .collect();
+        let mut source = Source::from_utf16_reader(bytes.as_slice(), None);
+        assert_eq!(read_content(&mut source.reader).unwrap(), code);
+
+        // Big endian byte order mark
+        let bytes: Vec<u8> = [0xFEFF]
+            .into_iter()
+            .chain(units())
+            .flat_map(u16::to_be_bytes)
+            .collect();
+        let mut source = Source::from_utf16_reader(bytes.as_slice(), None);
+        assert_eq!(read_content(&mut source.reader).unwrap(), code);
+
+        // Unpaired surrogates
+        let bytes: Vec<u8> = [0xD83C, 0x61, 0xDF0D]
+            .into_iter()
+            .flat_map(u16::to_le_bytes)
+            .collect();
+        let mut source = Source::from_utf16_reader(bytes.as_slice(), None);
+        let mut chars = Vec::new();
+        while let Some(c) = source.reader.next_char().unwrap() {
+            chars.push(c);
+        }
+        assert_eq!(chars, [0xD83C, 0x61, 0xDF0D]);
+
+        // Odd number of bytes
+        let mut source = Source::from_utf16_reader(&b"a\0b"[..], None);
+        assert!(read_content(&mut source.reader).is_err());
+    }
+
+    #[test]
+    fn from_latin1_reader() {
+        let mut source = Source::from_latin1_reader(&b"'caf\xE9' + '\xA9';"[..], None);
+        assert_eq!(read_content(&mut source.reader).unwrap(), "'café' + '©';");
+    }
+
+    #[test]
+    fn from_chunk_reader() {
+        let (sender, reader) = ChunkReader::channel(1);
+        let handle = std::thread::spawn(move || {
+            let chunks: [&[u8]; 4] = [b"'Hel", b"", b"lo' + 'W\xC3", b"\xB6rld';"];
+            for chunk in chunks {
+                sender.push(chunk).unwrap();
+            }
+            sender.abort(io::Error::other("connection closed")).unwrap();
+        });
+
+        let mut source = Source::from_reader(reader, None);
+        let mut content = String::new();
+        let error = loop {
+            match source.reader.next_char() {
+                Ok(Some(c)) => content.push(char::from_u32(c).unwrap()),
+                Ok(None) => panic!("the input should end with an error"),
+                Err(err) => break err,
+            }
+        };
+        handle.join().unwrap();
+
+        assert_eq!(content, "'Hello' + 'Wörld';");
+        assert_eq!(error.to_string(), "connection closed");
+    }
 }
@@ -1,5 +1,5 @@
-use super::ReadChar;
-use std::io;
+use super::{byte_reader::ByteReader, ReadChar};
+use std::io::{self, Read};
 
 /// Input for UTF-16 encoded sources.
 #[derive(Debug)]
@@ -47,6 +47,113 @@ impl ReadChar for UTF16Input<'_> {
     }
 }
 
+/// Byte order of a UTF-16 encoded input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ByteOrder {
+    /// The least significant byte of each code unit comes first.
+    #[default]
+    LittleEndian,
+
+    /// The most significant byte of each code unit comes first.
+    BigEndian,
+}
+
+/// Input for UTF-16 encoded sources that are read as bytes, e.g. from a file or from the network.
+///
+/// The byte order is detected from the byte order mark at the start of the input, which is not
+/// part of the source code. Inputs without a byte order mark use the given default byte order.
+#[derive(Debug)]
+pub struct UTF16ByteInput<R> {
+    input: ByteReader<R>,
+    byte_order: Option<ByteOrder>,
+    default_byte_order: ByteOrder,
+    pending: Option<u16>,
+}
+
+impl<R: Read> UTF16ByteInput<R> {
+    /// Creates a new `UTF16ByteInput` from a UTF-16 encoded source, using `default_byte_order`
+    /// if the input doesn't start with a byte order mark.
+    pub fn new(input: R, default_byte_order: ByteOrder) -> Self {
+        Self {
+            input: ByteReader::new(input),
+            byte_order: None,
+            default_byte_order,
+            pending: None,
+        }
+    }
+
+    /// Reads the next two bytes of the input as a code unit, without detecting the byte order.
+    fn read_code_unit(&mut self, byte_order: ByteOrder) -> io::Result<Option<u16>> {
+        let Some(first) = self.input.next_byte()? else {
+            return Ok(None);
+        };
+        let Some(second) = self.input.next_byte()? else {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "UTF-16 input with an odd number of bytes",
+            ));
+        };
+
+        Ok(Some(match byte_order {
+            ByteOrder::LittleEndian => u16::from_le_bytes([first, second]),
+            ByteOrder::BigEndian => u16::from_be_bytes([first, second]),
+        }))
+    }
+
+    /// Retrieves the next code unit, detecting the byte order at the start of the input.
+    fn next_code_unit(&mut self) -> io::Result<Option<u16>> {
+        if let Some(unit) = self.pending.take() {
+            return Ok(Some(unit));
+        }
+
+        if let Some(byte_order) = self.byte_order {
+            return self.read_code_unit(byte_order);
+        }
+
+        // The byte order mark is U+FEFF, so reading it with the wrong byte order gives U+FFFE.
+        self.byte_order = Some(self.default_byte_order);
+        match self.read_code_unit(self.default_byte_order)? {
+            Some(0xFEFF) => self.read_code_unit(self.default_byte_order),
+            Some(0xFFFE) => {
+                let byte_order = match self.default_byte_order {
+                    ByteOrder::LittleEndian => ByteOrder::BigEndian,
+                    ByteOrder::BigEndian => ByteOrder::LittleEndian,
+                };
+                self.byte_order = Some(byte_order);
+                self.read_code_unit(byte_order)
+            }
+            unit => Ok(unit),
+        }
+    }
+}
+
+impl<R: Read> ReadChar for UTF16ByteInput<R> {
+    /// Retrieves the next unchecked char in u32 code point.
+    fn next_char(&mut self) -> io::Result<Option<u32>> {
+        let Some(u1) = self.next_code_unit()? else {
+            return Ok(None);
+        };
+
+        // If the code unit is not a high surrogate, it is not the start of a surrogate pair.
+        if !is_high_surrogate(u1) {
+            return Ok(Some(u1.into()));
+        }
+
+        let Some(u2) = self.next_code_unit()? else {
+            return Ok(Some(u1.into()));
+        };
+
+        // If the code unit is not a low surrogate, it is not a surrogate pair, so it is read again
+        // as the next character.
+        if !is_low_surrogate(u2) {
+            self.pending = Some(u2);
+            return Ok(Some(u1.into()));
+        }
+
+        Ok(Some(code_point_from_surrogates(u1, u2)))
+    }
+}
+
 const SURROGATE_HIGH_START: u16 = 0xD800;
 const SURROGATE_HIGH_END: u16 = 0xDBFF;
 const SURROGATE_LOW_START: u16 = 0xDC00;
@@ -1,18 +1,17 @@
-use super::ReadChar;
-use std::io::{self, Bytes, Read};
+use super::{byte_reader::ByteReader, ReadChar};
+use std::io::{self, Read};
 
 /// Input for UTF-8 encoded sources.
 #[derive(Debug)]
 pub struct UTF8Input<R> {
-    input: Bytes<R>,
+    input: ByteReader<R>,
 }
 
 impl<R: Read> UTF8Input<R> {
     /// Creates a new `UTF8Input` from a UTF-8 encoded source.
     pub(crate) fn new(iter: R) -> Self {
         Self {
-            #[allow(clippy::unbuffered_bytes)]
-            input: iter.bytes(),
+            input: ByteReader::new(iter),
         }
     }
 }
@@ -20,7 +19,7 @@ impl<R: Read> UTF8Input<R> {
 impl<R: Read> UTF8Input<R> {
     /// Retrieves the next byte
     fn next_byte(&mut self) -> io::Result<Option<u8>> {
-        self.input.next().transpose()
+        self.input.next_byte()
     }
 }
 
@@ -55,6 +54,10 @@ impl<R: Read> ReadChar for UTF8Input<R> {
 
         Ok(Some(ch))
     }
+
+    fn next_ascii_run(&mut self, run_len: fn(&[u8]) -> usize) -> io::Result<&[u8]> {
+        self.input.next_ascii_run(run_len)
+    }
 }
 
 /// Mask of the value bits of a continuation byte.
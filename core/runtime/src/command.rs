@@ -0,0 +1,364 @@
+//! Boa's implementation of the `Command` class, which runs other programs.
+//!
+//! The `Command` class is modeled after Deno's `Deno.Command`, and is meant for scripts that
+//! automate tasks:
+//!
+//! ```js
+//! const command = new Command("git", { args: ["status"], cwd: "repo", env: { LANG: "C" } });
+//!
+//! // Runs the program, and captures its output.
+//! const { code, success, stdout, stderr } = await command.output();
+//! const output = command.outputSync();
+//!
+//! // Runs the program with the standard streams of the host.
+//! const child = command.spawn();
+//! const { code, success } = await child.status;
+//! ```
+//!
+//! The programs are run on other threads, and the promises are settled by [`NativeAsyncJob`]s,
+//! so the job executor of the context must support them. Each program must also be allowed by
+//! the [`Permissions`] of the context.
+//!
+//! Since running programs gives scripts the same access to the system as the host, this relies
+//! on the `command` feature, which isn't part of the default features.
+#![cfg(feature = "command")]
+
+#[cfg(test)]
+mod tests;
+
+use std::{
+    future::Future,
+    io,
+    path::PathBuf,
+    pin::Pin,
+    process::{self, ExitStatus, Stdio},
+    sync::{Arc, Mutex, PoisonError},
+    task::{Poll, Waker},
+    thread,
+};
+
+use boa_engine::{
+    class::{Class, ClassBuilder},
+    job::NativeAsyncJob,
+    js_string,
+    native_function::NativeFunction,
+    object::{builtins::JsPromise, ObjectInitializer},
+    property::{Attribute, PropertyKey},
+    Context, Finalize, JsArgs, JsData, JsError, JsNativeError, JsObject, JsResult, JsString,
+    JsValue, Trace,
+};
+
+use crate::permissions::Permissions;
+
+/// The `Command` class, which holds a program and the way to run it.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Trace, Finalize, JsData)]
+#[boa_gc(empty_trace)]
+pub struct Command {
+    program: String,
+    args: Vec<String>,
+    cwd: Option<PathBuf>,
+    env: Vec<(String, String)>,
+    clear_env: bool,
+}
+
+impl Command {
+    /// Register the `Command` class into the realm.
+    ///
+    /// # Errors
+    /// This will error if the context or realm cannot register the class.
+    pub fn register(context: &mut Context) -> JsResult<()> {
+        context.register_global_class::<Self>()?;
+        Ok(())
+    }
+
+    /// Gets the `Command` of `this`.
+    fn this_command(this: &JsValue) -> JsResult<Self> {
+        let command = this
+            .as_object()
+            .and_then(JsObject::downcast_ref::<Self>)
+            .map(|command| Self::clone(&command));
+
+        command.ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("`this` is not a `Command` object")
+                .into()
+        })
+    }
+
+    /// Creates the standard library command to run this command, after checking that it is
+    /// allowed.
+    ///
+    /// Besides the program, the environment variables it sets must be allowed, and so must
+    /// reading its working directory.
+    fn to_process(&self, context: &Context) -> JsResult<process::Command> {
+        let permissions = Permissions::of(context);
+        permissions.check_run(&self.program)?;
+        for (name, _) in &self.env {
+            permissions.check_env(name)?;
+        }
+        if let Some(cwd) = &self.cwd {
+            permissions.check_read(cwd)?;
+        }
+
+        let mut command = process::Command::new(&self.program);
+        command.args(&self.args);
+        if let Some(cwd) = &self.cwd {
+            command.current_dir(cwd);
+        }
+        if self.clear_env {
+            command.env_clear();
+        }
+        command.envs(self.env.iter().map(|(name, value)| (name, value)));
+        Ok(command)
+    }
+
+    /// Creates the error thrown when the program couldn't be run.
+    fn io_error(&self, err: &io::Error) -> JsError {
+        JsNativeError::error()
+            .with_message(format!("could not run `{}`: {err}", self.program))
+            .into()
+    }
+
+    /// `Command.prototype.output()`
+    fn output(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let command = Self::this_command(this)?;
+        let mut process = command.to_process(context)?;
+        process.stdin(Stdio::null());
+
+        let output = Background::spawn(move || process.output());
+        Ok(settle_with(output, context, move |output, context| {
+            let output = output.map_err(|err| command.io_error(&err))?;
+            Ok(output_object(&output, context))
+        })
+        .into())
+    }
+
+    /// `Command.prototype.outputSync()`
+    fn output_sync(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let command = Self::this_command(this)?;
+        let output = command
+            .to_process(context)?
+            .stdin(Stdio::null())
+            .output()
+            .map_err(|err| command.io_error(&err))?;
+
+        Ok(output_object(&output, context))
+    }
+
+    /// `Command.prototype.spawn()`
+    fn spawn(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let command = Self::this_command(this)?;
+        let mut child = command
+            .to_process(context)?
+            .spawn()
+            .map_err(|err| command.io_error(&err))?;
+        let pid = child.id();
+
+        let status = Background::spawn(move || child.wait());
+        let status = settle_with(status, context, move |status, context| {
+            let status = status.map_err(|err| command.io_error(&err))?;
+            Ok(status_object(status, context).build().into())
+        });
+
+        Ok(ObjectInitializer::new(context)
+            .property(js_string!("pid"), pid, Attribute::all())
+            .property(js_string!("status"), status, Attribute::all())
+            .build()
+            .into())
+    }
+}
+
+impl Class for Command {
+    const NAME: &'static str = "Command";
+    const LENGTH: usize = 1;
+
+    fn init(class: &mut ClassBuilder<'_>) -> JsResult<()> {
+        class
+            .method(
+                js_string!("output"),
+                0,
+                NativeFunction::from_fn_ptr(Self::output),
+            )
+            .method(
+                js_string!("outputSync"),
+                0,
+                NativeFunction::from_fn_ptr(Self::output_sync),
+            )
+            .method(
+                js_string!("spawn"),
+                0,
+                NativeFunction::from_fn_ptr(Self::spawn),
+            );
+        Ok(())
+    }
+
+    fn data_constructor(
+        _new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<Self> {
+        let mut command = Self {
+            program: to_string(args.get_or_undefined(0), context)?,
+            ..Self::default()
+        };
+
+        let options = args.get_or_undefined(1);
+        if options.is_undefined() {
+            return Ok(command);
+        }
+        let Some(options) = options.as_object() else {
+            return Err(JsNativeError::typ()
+                .with_message("the options of a `Command` must be an object")
+                .into());
+        };
+
+        let args = options.get(js_string!("args"), context)?;
+        if let Some(args) = args.as_object() {
+            let length = args
+                .get(js_string!("length"), context)?
+                .to_length(context)?;
+            for index in 0..length {
+                let arg = args.get(index, context)?;
+                command.args.push(to_string(&arg, context)?);
+            }
+        } else if !args.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("the `args` of a `Command` must be an array")
+                .into());
+        }
+
+        let cwd = options.get(js_string!("cwd"), context)?;
+        if !cwd.is_undefined() {
+            command.cwd = Some(to_string(&cwd, context)?.into());
+        }
+
+        let env = options.get(js_string!("env"), context)?;
+        if let Some(env) = env.as_object() {
+            for key in env.own_property_keys(context)? {
+                if matches!(key, PropertyKey::Symbol(_)) {
+                    continue;
+                }
+                let value = env.get(key.clone(), context)?;
+                command
+                    .env
+                    .push((key.to_string(), to_string(&value, context)?));
+            }
+        }
+
+        command.clear_env = options.get(js_string!("clearEnv"), context)?.to_boolean();
+
+        Ok(command)
+    }
+}
+
+/// Converts `value` to a string, replacing the unpaired surrogates.
+fn to_string(value: &JsValue, context: &mut Context) -> JsResult<String> {
+    Ok(value.to_string(context)?.to_std_string_lossy())
+}
+
+/// Creates the `{ code, success }` object of an exit `status`, where the code is `null` if the
+/// program was terminated by a signal.
+fn status_object(status: ExitStatus, context: &mut Context) -> ObjectInitializer<'_> {
+    let code = status.code().map_or_else(JsValue::null, JsValue::from);
+    let mut object = ObjectInitializer::new(context);
+    object
+        .property(js_string!("code"), code, Attribute::all())
+        .property(js_string!("success"), status.success(), Attribute::all());
+    object
+}
+
+/// Creates the `{ code, success, stdout, stderr }` object of the `output` of a program.
+fn output_object(output: &process::Output, context: &mut Context) -> JsValue {
+    let stdout = JsString::from(String::from_utf8_lossy(&output.stdout).as_ref());
+    let stderr = JsString::from(String::from_utf8_lossy(&output.stderr).as_ref());
+
+    status_object(output.status, context)
+        .property(js_string!("stdout"), stdout, Attribute::all())
+        .property(js_string!("stderr"), stderr, Attribute::all())
+        .build()
+        .into()
+}
+
+/// Returns a promise settled with the result of `map` on the value of `task`, which is awaited
+/// by a job.
+fn settle_with<T, F>(task: Background<T>, context: &mut Context, map: F) -> JsPromise
+where
+    T: 'static,
+    F: FnOnce(T, &mut Context) -> JsResult<JsValue> + 'static,
+{
+    let (promise, resolvers) = JsPromise::new_pending(context);
+
+    context.enqueue_job(
+        NativeAsyncJob::new(move |context| {
+            Box::pin(async move {
+                let value = task.await;
+
+                let context = &mut context.borrow_mut();
+                match map(value, context) {
+                    Ok(v) => resolvers.resolve.call(&JsValue::undefined(), &[v], context),
+                    Err(e) => {
+                        let e = e.to_opaque(context);
+                        resolvers.reject.call(&JsValue::undefined(), &[e], context)
+                    }
+                }
+            })
+        })
+        .into(),
+    );
+
+    promise
+}
+
+/// A value computed on another thread, which can be awaited.
+struct Background<T> {
+    state: Arc<Mutex<BackgroundState<T>>>,
+}
+
+/// The shared state of a [`Background`] value.
+struct BackgroundState<T> {
+    value: Option<T>,
+    waker: Option<Waker>,
+}
+
+impl<T: Send + 'static> Background<T> {
+    /// Computes `f` on a new thread.
+    fn spawn<F>(f: F) -> Self
+    where
+        F: FnOnce() -> T + Send + 'static,
+    {
+        let state = Arc::new(Mutex::new(BackgroundState {
+            value: None,
+            waker: None,
+        }));
+
+        let shared = state.clone();
+        thread::spawn(move || {
+            let value = f();
+            let waker = {
+                let mut state = shared.lock().unwrap_or_else(PoisonError::into_inner);
+                state.value = Some(value);
+                state.waker.take()
+            };
+            if let Some(waker) = waker {
+                waker.wake();
+            }
+        });
+
+        Self { state }
+    }
+}
+
+impl<T> Future for Background<T> {
+    type Output = T;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut std::task::Context<'_>) -> Poll<T> {
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        match state.value.take() {
+            Some(value) => Poll::Ready(value),
+            None => {
+                state.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
@@ -0,0 +1,142 @@
+use boa_engine::{js_string, Context, JsString, JsValue, Source};
+
+use super::Command;
+use crate::permissions::{Allow, Permissions};
+
+/// The path of Cargo, which is always available while the tests run.
+const CARGO: &str = env!("CARGO");
+
+/// Creates a context with the `Command` class and `permissions`.
+fn context(permissions: Permissions) -> Context {
+    let mut context = Context::default();
+    permissions.install(&mut context);
+    Command::register(&mut context).expect("the `Command` class should be registered");
+    context
+        .register_global_property(
+            js_string!("CARGO"),
+            JsString::from(CARGO),
+            Default::default(),
+        )
+        .expect("the `CARGO` global should be registered");
+    context
+}
+
+#[track_caller]
+fn eval(context: &mut Context, source: &str) -> JsValue {
+    let value = context
+        .eval(Source::from_bytes(source))
+        .expect("the script should not throw");
+    context.run_jobs().expect("the jobs should not throw");
+    value
+}
+
+#[test]
+fn output_sync() {
+    let context = &mut context(Permissions::new().with_run(Allow::only([CARGO])));
+
+    let value = eval(
+        context,
+        r#"
+        const output = new Command(CARGO, { args: ["--version"] }).outputSync();
+        output.success && output.code === 0 && output.stdout.startsWith("cargo ")
+        "#,
+    );
+    assert_eq!(value, JsValue::from(true));
+
+    let value = eval(
+        context,
+        r#"
+        const failure = new Command(CARGO, { args: ["not-a-subcommand"] }).outputSync();
+        !failure.success && failure.code !== 0 && failure.stderr.length > 0
+        "#,
+    );
+    assert_eq!(value, JsValue::from(true));
+}
+
+#[test]
+fn output_and_spawn_settle_promises() {
+    let context = &mut context(Permissions::new().with_run(Allow::All));
+
+    eval(
+        context,
+        r#"
+        let stdout, status;
+        new Command(CARGO, { args: ["--version"] })
+            .output()
+            .then(output => { stdout = output.stdout; });
+
+        const child = new Command(CARGO, { args: ["--version"] }).spawn();
+        child.status.then(s => { status = s; });
+        "#,
+    );
+
+    let value = eval(
+        context,
+        r#"stdout.startsWith("cargo ") && typeof child.pid === "number" && status.success"#,
+    );
+    assert_eq!(value, JsValue::from(true));
+
+    eval(
+        context,
+        r#"
+        let error;
+        new Command("this program does not exist").output().catch(e => { error = e; });
+        "#,
+    );
+    let value = eval(
+        context,
+        "error instanceof Error && error.message.startsWith('could not run')",
+    );
+    assert_eq!(value, JsValue::from(true));
+}
+
+#[test]
+fn programs_must_be_allowed() {
+    let context = &mut context(Permissions::new().with_run(Allow::only(["git"])));
+
+    let value = eval(
+        context,
+        r#"
+        try {
+            new Command(CARGO).outputSync();
+            false
+        } catch (e) {
+            e instanceof TypeError
+        }
+        "#,
+    );
+    assert_eq!(value, JsValue::from(true));
+}
+
+#[test]
+fn env_and_cwd_must_be_allowed() {
+    let context = &mut context(
+        Permissions::new()
+            .with_run(Allow::only([CARGO]))
+            .with_env(Allow::only(["LANG"]))
+            .with_read(Allow::only([env!("CARGO_MANIFEST_DIR")])),
+    );
+
+    let value = eval(
+        context,
+        &format!(
+            r#"
+            const attempt = options => {{
+                try {{
+                    new Command(CARGO, {{ args: ["--version"], ...options }}).outputSync();
+                    return "ok";
+                }} catch (e) {{
+                    return e instanceof TypeError ? "denied" : String(e);
+                }}
+            }};
+            [
+                attempt({{ env: {{ LANG: "C" }}, cwd: {:?} }}),
+                attempt({{ env: {{ SECRET: "1" }} }}),
+                attempt({{ cwd: "/" }}),
+            ].join()
+            "#,
+            env!("CARGO_MANIFEST_DIR")
+        ),
+    );
+    assert_eq!(value, JsValue::from(js_string!("ok,denied,denied")));
+}
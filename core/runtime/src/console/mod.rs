@@ -285,8 +285,16 @@ impl Console {
     }
 
     /// Initializes the `console` with a special logger.
-    #[allow(clippy::too_many_lines)]
     pub fn init_with_logger<L>(context: &mut Context, logger: L) -> JsObject
+    where
+        L: Logger + 'static,
+    {
+        Self::init_with_shared_logger(context, Rc::new(logger))
+    }
+
+    /// Initializes the `console` with a logger that can be shared with other `console` objects.
+    #[allow(clippy::too_many_lines)]
+    pub(crate) fn init_with_shared_logger<L>(context: &mut Context, logger: Rc<L>) -> JsObject
     where
         L: Logger + 'static,
     {
@@ -317,7 +325,6 @@ impl Console {
         // let _timer = Profiler::global().start_event(std::any::type_name::<Self>(), "init");
 
         let state = Rc::new(RefCell::new(Self::default()));
-        let logger = Rc::new(logger);
 
         ObjectInitializer::with_native_data_and_proto(
             Self::default(),
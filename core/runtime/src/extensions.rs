@@ -0,0 +1,398 @@
+//! [`Extension`]s installing the objects and functions of the `WebAPI` runtime.
+//!
+//! Each extension can be registered individually with [`Context::register_extension`], and
+//! removed again with [`Context::unregister_extension`] using its name.
+//!
+//! ```
+//! use boa_engine::{Context, Source};
+//! use boa_runtime::extensions::{ConsoleExtension, TimersExtension};
+//!
+//! let mut context = Context::default();
+//! context
+//!     .register_extension(ConsoleExtension::default())
+//!     .unwrap();
+//! context.register_extension(TimersExtension).unwrap();
+//!
+//! assert!(context.has_extension("console"));
+//! assert!(context.unregister_extension("timers").unwrap());
+//! ```
+
+use std::rc::Rc;
+
+use boa_engine::{
+    class::Class, context::Extension, js_string, property::Attribute, Context, JsResult, JsString,
+};
+
+use crate::{
+    console::DefaultLogger, interval, Console, Logger, Performance, TextDecoder, TextEncoder,
+};
+
+/// Installs the `console` object.
+pub struct ConsoleExtension<L: Logger = DefaultLogger> {
+    logger: Rc<L>,
+}
+
+impl<L: Logger> std::fmt::Debug for ConsoleExtension<L> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ConsoleExtension").finish_non_exhaustive()
+    }
+}
+
+impl Default for ConsoleExtension<DefaultLogger> {
+    fn default() -> Self {
+        Self::with_logger(DefaultLogger)
+    }
+}
+
+impl<L: Logger> ConsoleExtension<L> {
+    /// Creates a `console` extension that forwards its messages to `logger`.
+    pub fn with_logger(logger: L) -> Self {
+        Self {
+            logger: Rc::new(logger),
+        }
+    }
+}
+
+impl<L: Logger + 'static> Extension for ConsoleExtension<L> {
+    fn name(&self) -> &'static str {
+        "console"
+    }
+
+    fn globals(&self) -> Vec<JsString> {
+        vec![Console::NAME]
+    }
+
+    fn install(&self, context: &mut Context) -> JsResult<()> {
+        let console = Console::init_with_shared_logger(context, self.logger.clone());
+        context.register_global_property(
+            Console::NAME,
+            console,
+            Attribute::WRITABLE | Attribute::CONFIGURABLE,
+        )
+    }
+}
+
+/// Installs the `TextDecoder` and `TextEncoder` classes.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct TextEncodingExtension;
+
+impl Extension for TextEncodingExtension {
+    fn name(&self) -> &'static str {
+        "text"
+    }
+
+    fn globals(&self) -> Vec<JsString> {
+        vec![js_string!(TextDecoder::NAME), js_string!(TextEncoder::NAME)]
+    }
+
+    fn install(&self, context: &mut Context) -> JsResult<()> {
+        TextDecoder::register(context)?;
+        TextEncoder::register(context)
+    }
+
+    fn uninstall(&self, context: &mut Context) -> JsResult<()> {
+        context.unregister_global_class::<TextDecoder>()?;
+        context.unregister_global_class::<TextEncoder>()?;
+        Ok(())
+    }
+}
+
+/// Installs the `URL` class.
+#[cfg(feature = "url")]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct UrlExtension;
+
+#[cfg(feature = "url")]
+impl Extension for UrlExtension {
+    fn name(&self) -> &'static str {
+        "url"
+    }
+
+    fn globals(&self) -> Vec<JsString> {
+        vec![js_string!(crate::url::Url::NAME)]
+    }
+
+    fn install(&self, context: &mut Context) -> JsResult<()> {
+        crate::url::Url::register(context)
+    }
+
+    fn uninstall(&self, context: &mut Context) -> JsResult<()> {
+        context.unregister_global_class::<crate::url::Url>()?;
+        Ok(())
+    }
+}
+
+/// Installs the `fs` object, which runs its operations with a [`FileSystem`][crate::fs::FileSystem].
+///
+/// This extension isn't registered by [`register`][crate::register], since scripts shouldn't get
+/// access to the filesystem unless the host opts in.
+#[cfg(feature = "fs")]
+pub struct FsExtension {
+    file_system: Rc<dyn crate::fs::FileSystem>,
+}
+
+#[cfg(feature = "fs")]
+impl std::fmt::Debug for FsExtension {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FsExtension").finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "fs")]
+impl Default for FsExtension {
+    fn default() -> Self {
+        Self::with_file_system(crate::fs::DefaultFileSystem)
+    }
+}
+
+#[cfg(feature = "fs")]
+impl FsExtension {
+    /// Creates an `fs` extension that runs its operations with `file_system`.
+    pub fn with_file_system<F: crate::fs::FileSystem + 'static>(file_system: F) -> Self {
+        Self {
+            file_system: Rc::new(file_system),
+        }
+    }
+}
+
+#[cfg(feature = "fs")]
+impl Extension for FsExtension {
+    fn name(&self) -> &'static str {
+        "fs"
+    }
+
+    fn globals(&self) -> Vec<JsString> {
+        vec![crate::fs::Fs::NAME]
+    }
+
+    fn install(&self, context: &mut Context) -> JsResult<()> {
+        let fs = crate::fs::Fs::init_with_shared_file_system(context, self.file_system.clone());
+        context.register_global_property(
+            crate::fs::Fs::NAME,
+            fs,
+            Attribute::WRITABLE | Attribute::CONFIGURABLE,
+        )
+    }
+}
+
+/// Installs the `process` object, which gets the environment of the scripts from an
+/// [`Environment`][crate::process::Environment].
+///
+/// This extension isn't registered by [`register`][crate::register], and should be registered
+/// after the [`Permissions`][crate::permissions::Permissions] of the context are set, since only
+/// the environment variables they allow are added to `process.env`.
+#[cfg(feature = "process")]
+pub struct ProcessExtension {
+    environment: Rc<dyn crate::process::Environment>,
+}
+
+#[cfg(feature = "process")]
+impl std::fmt::Debug for ProcessExtension {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ProcessExtension").finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "process")]
+impl Default for ProcessExtension {
+    fn default() -> Self {
+        Self::with_environment(crate::process::DefaultEnvironment::default())
+    }
+}
+
+#[cfg(feature = "process")]
+impl ProcessExtension {
+    /// Creates a `process` extension that gets the environment of the scripts from `environment`.
+    pub fn with_environment<E: crate::process::Environment + 'static>(environment: E) -> Self {
+        Self {
+            environment: Rc::new(environment),
+        }
+    }
+}
+
+#[cfg(feature = "process")]
+impl Extension for ProcessExtension {
+    fn name(&self) -> &'static str {
+        "process"
+    }
+
+    fn globals(&self) -> Vec<JsString> {
+        vec![crate::process::Process::NAME]
+    }
+
+    fn install(&self, context: &mut Context) -> JsResult<()> {
+        let process = crate::process::Process::init_with_shared_environment(
+            context,
+            self.environment.clone(),
+        );
+        context.register_global_property(
+            crate::process::Process::NAME,
+            process,
+            Attribute::WRITABLE | Attribute::CONFIGURABLE,
+        )
+    }
+}
+
+/// Installs the `Command` class, which runs other programs.
+///
+/// This extension isn't registered by [`register`][crate::register], and the programs must also
+/// be allowed by the [`Permissions`][crate::permissions::Permissions] of the context.
+#[cfg(feature = "command")]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct CommandExtension;
+
+#[cfg(feature = "command")]
+impl Extension for CommandExtension {
+    fn name(&self) -> &'static str {
+        "command"
+    }
+
+    fn globals(&self) -> Vec<JsString> {
+        vec![js_string!(crate::command::Command::NAME)]
+    }
+
+    fn install(&self, context: &mut Context) -> JsResult<()> {
+        crate::command::Command::register(context)
+    }
+
+    fn uninstall(&self, context: &mut Context) -> JsResult<()> {
+        context.unregister_global_class::<crate::command::Command>()?;
+        Ok(())
+    }
+}
+
+/// Installs the `WebSocket` class, which opens its connections with a
+/// [`WebSocketTransport`][crate::websocket::WebSocketTransport].
+///
+/// This extension isn't registered by [`register`][crate::register], since the runtime doesn't
+/// have a network stack.
+#[cfg(feature = "websocket")]
+pub struct WebSocketExtension {
+    transport: Rc<dyn crate::websocket::WebSocketTransport>,
+}
+
+#[cfg(feature = "websocket")]
+impl std::fmt::Debug for WebSocketExtension {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WebSocketExtension").finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "websocket")]
+impl WebSocketExtension {
+    /// Creates a `WebSocket` extension that opens its connections with `transport`.
+    pub fn with_transport<T: crate::websocket::WebSocketTransport + 'static>(transport: T) -> Self {
+        Self {
+            transport: Rc::new(transport),
+        }
+    }
+}
+
+#[cfg(feature = "websocket")]
+impl Extension for WebSocketExtension {
+    fn name(&self) -> &'static str {
+        "websocket"
+    }
+
+    fn globals(&self) -> Vec<JsString> {
+        vec![js_string!(crate::websocket::WebSocket::NAME)]
+    }
+
+    fn install(&self, context: &mut Context) -> JsResult<()> {
+        crate::websocket::WebSocket::register_with_shared_transport(context, self.transport.clone())
+    }
+
+    fn uninstall(&self, context: &mut Context) -> JsResult<()> {
+        context.unregister_global_class::<crate::websocket::WebSocket>()?;
+        Ok(())
+    }
+}
+
+/// Installs the `storage` object, which keeps its values in an
+/// [`AsyncKeyValueStore`][crate::storage::AsyncKeyValueStore].
+///
+/// This extension isn't registered by [`register`][crate::register], since the values must be
+/// kept by the host.
+#[cfg(feature = "storage")]
+pub struct StorageExtension {
+    store: Rc<dyn crate::storage::AsyncKeyValueStore>,
+}
+
+#[cfg(feature = "storage")]
+impl std::fmt::Debug for StorageExtension {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StorageExtension").finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "storage")]
+impl StorageExtension {
+    /// Creates a `storage` extension that keeps its values in `store`, which can be any
+    /// [`KeyValueStore`][crate::storage::KeyValueStore] too.
+    pub fn with_store<S: crate::storage::AsyncKeyValueStore + 'static>(store: S) -> Self {
+        Self {
+            store: Rc::new(store),
+        }
+    }
+}
+
+#[cfg(feature = "storage")]
+impl Extension for StorageExtension {
+    fn name(&self) -> &'static str {
+        "storage"
+    }
+
+    fn globals(&self) -> Vec<JsString> {
+        vec![crate::storage::Storage::NAME]
+    }
+
+    fn install(&self, context: &mut Context) -> JsResult<()> {
+        let storage = crate::storage::Storage::init_with_shared_store(context, self.store.clone());
+        context.register_global_property(
+            crate::storage::Storage::NAME,
+            storage,
+            Attribute::WRITABLE | Attribute::CONFIGURABLE,
+        )
+    }
+}
+
+/// Installs the `setTimeout`, `setInterval`, `clearTimeout` and `clearInterval` functions.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct TimersExtension;
+
+impl Extension for TimersExtension {
+    fn name(&self) -> &'static str {
+        "timers"
+    }
+
+    fn globals(&self) -> Vec<JsString> {
+        vec![
+            js_string!("setTimeout"),
+            js_string!("setInterval"),
+            js_string!("clearTimeout"),
+            js_string!("clearInterval"),
+        ]
+    }
+
+    fn install(&self, context: &mut Context) -> JsResult<()> {
+        interval::register(context)
+    }
+}
+
+/// Installs the `performance` object.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct PerformanceExtension;
+
+impl Extension for PerformanceExtension {
+    fn name(&self) -> &'static str {
+        "performance"
+    }
+
+    fn globals(&self) -> Vec<JsString> {
+        vec![Performance::NAME]
+    }
+
+    fn install(&self, context: &mut Context) -> JsResult<()> {
+        Performance::register(context)
+    }
+}
@@ -0,0 +1,441 @@
+//! Boa's implementation of the `fs` object, giving scripts a basic access to the filesystem.
+//!
+//! The `fs` object is not a Web API, but embedders that automate tasks with scripts frequently
+//! need to read and write files. It has the following functions:
+//!  - `fs.readTextFile(path)` reads a file as UTF-8 text.
+//!  - `fs.writeTextFile(path, data)` writes `data` to a file, replacing its contents if it
+//!    exists.
+//!  - `fs.readDir(path)` lists the entries of a directory, as `{ name, isFile, isDirectory,
+//!    isSymlink }` objects.
+//!  - `fs.stat(path)` gets the `{ isFile, isDirectory, size, mtime }` of a file.
+//!
+//! Each function returns a promise, which is settled by a job after the current script, and has a
+//! synchronous variant with a `Sync` suffix, like `fs.readTextFileSync(path)`.
+//!
+//! Every access is checked with the [`Permissions`] of the context, so scripts can't access any
+//! file unless the host allows it. The operations are then run by a [`FileSystem`], which hosts
+//! can implement to sandbox the scripts further, or to give them a virtual filesystem.
+//!
+//! This relies on the `fs` feature.
+#![cfg(feature = "fs")]
+
+#[cfg(test)]
+mod tests;
+
+use std::{
+    io,
+    path::{Path, PathBuf},
+    rc::Rc,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use boa_engine::{
+    job::PromiseJob,
+    js_string,
+    native_function::NativeFunction,
+    object::{
+        builtins::{JsArray, JsDate, JsPromise},
+        JsObject, ObjectInitializer,
+    },
+    property::Attribute,
+    Context, JsArgs, JsError, JsNativeError, JsResult, JsString, JsSymbol, JsValue,
+};
+
+use crate::permissions::Permissions;
+
+/// The filesystem used by the `fs` object to run its operations.
+///
+/// Hosts can implement this trait to restrict the files that scripts can access, or to give
+/// them a virtual filesystem.
+pub trait FileSystem {
+    /// Reads the file at `path` as UTF-8 text.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read, or if it isn't valid UTF-8.
+    fn read_to_string(&self, path: &Path) -> io::Result<String>;
+
+    /// Writes `contents` to the file at `path`, creating it if it doesn't exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be written.
+    fn write(&self, path: &Path, contents: &str) -> io::Result<()>;
+
+    /// Lists the entries of the directory at `path`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the directory cannot be read.
+    fn read_dir(&self, path: &Path) -> io::Result<Vec<DirEntry>>;
+
+    /// Gets information about the file at `path`, following symbolic links.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file doesn't exist or cannot be accessed.
+    fn stat(&self, path: &Path) -> io::Result<FileInfo>;
+
+    /// Resolves the symbolic links of `path`, so that the permissions are checked for the file
+    /// that it points to.
+    ///
+    /// If `path` doesn't exist, like a file that is about to be written, only its parent
+    /// directory is resolved. A symbolic link to a missing file must not be resolved this way,
+    /// since writing to it would create that file.
+    ///
+    /// The default implementation returns `path` unchanged, for filesystems without links.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `path` cannot be resolved.
+    fn canonicalize(&self, path: &Path) -> io::Result<PathBuf> {
+        Ok(path.to_path_buf())
+    }
+}
+
+/// The default [`FileSystem`], which accesses the filesystem of the host with [`std::fs`].
+#[derive(Debug, Default, Clone, Copy)]
+pub struct DefaultFileSystem;
+
+impl FileSystem for DefaultFileSystem {
+    fn read_to_string(&self, path: &Path) -> io::Result<String> {
+        std::fs::read_to_string(path)
+    }
+
+    fn write(&self, path: &Path, contents: &str) -> io::Result<()> {
+        std::fs::write(path, contents)
+    }
+
+    fn read_dir(&self, path: &Path) -> io::Result<Vec<DirEntry>> {
+        std::fs::read_dir(path)?
+            .map(|entry| {
+                let entry = entry?;
+                let file_type = entry.file_type()?;
+                Ok(DirEntry {
+                    name: entry.file_name().to_string_lossy().into_owned(),
+                    is_file: file_type.is_file(),
+                    is_directory: file_type.is_dir(),
+                    is_symlink: file_type.is_symlink(),
+                })
+            })
+            .collect()
+    }
+
+    fn stat(&self, path: &Path) -> io::Result<FileInfo> {
+        let metadata = std::fs::metadata(path)?;
+        Ok(FileInfo {
+            is_file: metadata.is_file(),
+            is_directory: metadata.is_dir(),
+            size: metadata.len(),
+            modified: metadata.modified().ok(),
+        })
+    }
+
+    fn canonicalize(&self, path: &Path) -> io::Result<PathBuf> {
+        match std::fs::canonicalize(path) {
+            Err(err)
+                if err.kind() == io::ErrorKind::NotFound
+                    && std::fs::symlink_metadata(path).is_err() =>
+            {
+                let (Some(parent), Some(name)) = (path.parent(), path.file_name()) else {
+                    return Err(err);
+                };
+                let parent = if parent.as_os_str().is_empty() {
+                    Path::new(".")
+                } else {
+                    parent
+                };
+                Ok(std::fs::canonicalize(parent)?.join(name))
+            }
+            result => result,
+        }
+    }
+}
+
+/// An entry of a directory listed by [`FileSystem::read_dir`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DirEntry {
+    /// The name of the entry, without the path of the directory.
+    pub name: String,
+    /// `true` if the entry is a file.
+    pub is_file: bool,
+    /// `true` if the entry is a directory.
+    pub is_directory: bool,
+    /// `true` if the entry is a symbolic link.
+    pub is_symlink: bool,
+}
+
+/// Information about a file, returned by [`FileSystem::stat`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FileInfo {
+    /// `true` if this is a file.
+    pub is_file: bool,
+    /// `true` if this is a directory.
+    pub is_directory: bool,
+    /// The size of the file, in bytes.
+    pub size: u64,
+    /// The last time the file was modified, if the platform supports it.
+    pub modified: Option<SystemTime>,
+}
+
+/// An operation of the `fs` object, with its arguments converted from JavaScript values.
+#[derive(Debug)]
+enum Operation {
+    ReadTextFile(PathBuf),
+    WriteTextFile(PathBuf, String),
+    ReadDir(PathBuf),
+    Stat(PathBuf),
+}
+
+impl Operation {
+    /// Checks the permissions of the operation, and runs it with `file_system`.
+    fn run(self, file_system: &dyn FileSystem, context: &mut Context) -> JsResult<JsValue> {
+        let permissions = Permissions::of(context);
+        let read = |path: &Path| permissions.check_read(path);
+        let write = |path: &Path| permissions.check_write(path);
+        match self {
+            Self::ReadTextFile(path) => {
+                let resolved = check_path(file_system, &path, read, "read")?;
+                let text = file_system
+                    .read_to_string(&resolved)
+                    .map_err(|err| io_error(&err, "read", &path))?;
+                Ok(JsString::from(text.as_str()).into())
+            }
+            Self::WriteTextFile(path, data) => {
+                let resolved = check_path(file_system, &path, write, "write")?;
+                file_system
+                    .write(&resolved, &data)
+                    .map_err(|err| io_error(&err, "write", &path))?;
+                Ok(JsValue::undefined())
+            }
+            Self::ReadDir(path) => {
+                let resolved = check_path(file_system, &path, read, "read directory")?;
+                let entries = file_system
+                    .read_dir(&resolved)
+                    .map_err(|err| io_error(&err, "read directory", &path))?;
+                let entries = entries
+                    .into_iter()
+                    .map(|entry| {
+                        ObjectInitializer::new(context)
+                            .property(
+                                js_string!("name"),
+                                JsString::from(entry.name.as_str()),
+                                Attribute::all(),
+                            )
+                            .property(js_string!("isFile"), entry.is_file, Attribute::all())
+                            .property(
+                                js_string!("isDirectory"),
+                                entry.is_directory,
+                                Attribute::all(),
+                            )
+                            .property(js_string!("isSymlink"), entry.is_symlink, Attribute::all())
+                            .build()
+                            .into()
+                    })
+                    .collect::<Vec<JsValue>>();
+                Ok(JsArray::from_iter(entries, context).into())
+            }
+            Self::Stat(path) => {
+                let resolved = check_path(file_system, &path, read, "stat")?;
+                let info = file_system
+                    .stat(&resolved)
+                    .map_err(|err| io_error(&err, "stat", &path))?;
+                let mtime = match info.modified {
+                    Some(modified) => {
+                        let date = JsDate::new(context);
+                        date.set_time(time_value(modified), context)?;
+                        date.into()
+                    }
+                    None => JsValue::null(),
+                };
+                Ok(ObjectInitializer::new(context)
+                    .property(js_string!("isFile"), info.is_file, Attribute::all())
+                    .property(
+                        js_string!("isDirectory"),
+                        info.is_directory,
+                        Attribute::all(),
+                    )
+                    .property(js_string!("size"), info.size, Attribute::all())
+                    .property(js_string!("mtime"), mtime, Attribute::all())
+                    .build()
+                    .into())
+            }
+        }
+    }
+}
+
+/// Checks that `path` can be accessed with `check`, both as it is given and once its symbolic
+/// links are resolved by `file_system`, and returns the resolved path.
+///
+/// The path is checked before it is resolved to not reveal which files exist outside of the
+/// allowed paths, and after to not follow a link out of them.
+fn check_path(
+    file_system: &dyn FileSystem,
+    path: &Path,
+    check: impl Fn(&Path) -> JsResult<()>,
+    action: &str,
+) -> JsResult<PathBuf> {
+    check(path)?;
+    let resolved = file_system
+        .canonicalize(path)
+        .map_err(|err| io_error(&err, action, path))?;
+    check(&resolved)?;
+    Ok(resolved)
+}
+
+/// Converts `time` to the number of milliseconds since the epoch, like the time value of a
+/// `Date`.
+fn time_value(time: SystemTime) -> f64 {
+    match time.duration_since(UNIX_EPOCH) {
+        Ok(duration) => duration.as_secs_f64() * 1000.0,
+        Err(err) => -err.duration().as_secs_f64() * 1000.0,
+    }
+}
+
+/// Creates the error thrown when the `action` on `path` failed with `err`.
+fn io_error(err: &io::Error, action: &str, path: &Path) -> JsError {
+    JsNativeError::error()
+        .with_message(format!("could not {action} `{}`: {err}", path.display()))
+        .into()
+}
+
+/// Converts the argument at `index` to a path.
+fn path_arg(args: &[JsValue], index: usize, context: &mut Context) -> JsResult<PathBuf> {
+    let path = args.get_or_undefined(index).to_string(context)?;
+    path.to_std_string().map(PathBuf::from).map_err(|_| {
+        JsNativeError::typ()
+            .with_message("the path must not contain unpaired surrogates")
+            .into()
+    })
+}
+
+/// The conversion of the arguments of a function of the `fs` object to its operation.
+type Arguments = fn(&[JsValue], &mut Context) -> JsResult<Operation>;
+
+/// The `fs` object.
+#[derive(Debug, Clone, Copy)]
+pub struct Fs;
+
+impl Fs {
+    /// Name of the built-in `fs` property.
+    pub const NAME: JsString = js_string!("fs");
+
+    /// Initializes the `fs` object, which runs its operations with `file_system`.
+    pub fn init<F: FileSystem + 'static>(context: &mut Context, file_system: F) -> JsObject {
+        Self::init_with_shared_file_system(context, Rc::new(file_system))
+    }
+
+    /// Initializes the `fs` object with a file system that can be shared with other `fs` objects.
+    pub(crate) fn init_with_shared_file_system(
+        context: &mut Context,
+        file_system: Rc<dyn FileSystem>,
+    ) -> JsObject {
+        fn sync_method(arguments: Arguments, file_system: Rc<dyn FileSystem>) -> NativeFunction {
+            // SAFETY: The file system doesn't contain types that need tracing.
+            unsafe {
+                NativeFunction::from_closure(move |_, args, context| {
+                    arguments(args, context)?.run(&*file_system, context)
+                })
+            }
+        }
+        fn async_method(arguments: Arguments, file_system: Rc<dyn FileSystem>) -> NativeFunction {
+            // SAFETY: The file system doesn't contain types that need tracing.
+            unsafe {
+                NativeFunction::from_closure(move |_, args, context| {
+                    let (promise, resolvers) = JsPromise::new_pending(context);
+                    let operation = arguments(args, context);
+                    let file_system = file_system.clone();
+
+                    context.enqueue_job(
+                        PromiseJob::new(move |context| {
+                            match operation.and_then(|op| op.run(&*file_system, context)) {
+                                Ok(value) => {
+                                    resolvers
+                                        .resolve
+                                        .call(&JsValue::undefined(), &[value], context)
+                                }
+                                Err(err) => {
+                                    let err = err.to_opaque(context);
+                                    resolvers
+                                        .reject
+                                        .call(&JsValue::undefined(), &[err], context)
+                                }
+                            }
+                        })
+                        .into(),
+                    );
+
+                    Ok(promise.into())
+                })
+            }
+        }
+
+        let mut object = ObjectInitializer::new(context);
+        object.property(
+            JsSymbol::to_string_tag(),
+            Self::NAME,
+            Attribute::CONFIGURABLE,
+        );
+
+        let functions: [(&str, Arguments, usize); 4] = [
+            ("readTextFile", Self::read_text_file, 1),
+            ("writeTextFile", Self::write_text_file, 2),
+            ("readDir", Self::read_dir, 1),
+            ("stat", Self::stat, 1),
+        ];
+        for (name, arguments, length) in functions {
+            object
+                .function(
+                    async_method(arguments, file_system.clone()),
+                    JsString::from(name),
+                    length,
+                )
+                .function(
+                    sync_method(arguments, file_system.clone()),
+                    JsString::from(format!("{name}Sync").as_str()),
+                    length,
+                );
+        }
+
+        object.build()
+    }
+
+    /// Registers the `fs` object as a global property, which runs its operations with
+    /// `file_system`.
+    ///
+    /// # Errors
+    /// This will error if the `fs` global property already exists.
+    pub fn register<F: FileSystem + 'static>(
+        context: &mut Context,
+        file_system: F,
+    ) -> JsResult<()> {
+        let fs = Self::init(context, file_system);
+        context.register_global_property(
+            Self::NAME,
+            fs,
+            Attribute::WRITABLE | Attribute::CONFIGURABLE,
+        )
+    }
+
+    /// `fs.readTextFile(path)`
+    fn read_text_file(args: &[JsValue], context: &mut Context) -> JsResult<Operation> {
+        Ok(Operation::ReadTextFile(path_arg(args, 0, context)?))
+    }
+
+    /// `fs.writeTextFile(path, data)`
+    fn write_text_file(args: &[JsValue], context: &mut Context) -> JsResult<Operation> {
+        let path = path_arg(args, 0, context)?;
+        let data = args.get_or_undefined(1).to_string(context)?;
+        Ok(Operation::WriteTextFile(path, data.to_std_string_lossy()))
+    }
+
+    /// `fs.readDir(path)`
+    fn read_dir(args: &[JsValue], context: &mut Context) -> JsResult<Operation> {
+        Ok(Operation::ReadDir(path_arg(args, 0, context)?))
+    }
+
+    /// `fs.stat(path)`
+    fn stat(args: &[JsValue], context: &mut Context) -> JsResult<Operation> {
+        Ok(Operation::Stat(path_arg(args, 0, context)?))
+    }
+}
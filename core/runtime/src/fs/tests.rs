@@ -0,0 +1,244 @@
+use std::{
+    cell::RefCell,
+    collections::BTreeMap,
+    io,
+    path::{Path, PathBuf},
+    time::{Duration, UNIX_EPOCH},
+};
+
+use boa_engine::{js_string, Context, JsValue, Source};
+
+use super::{DirEntry, FileInfo, FileSystem, Fs};
+use crate::permissions::{Allow, Permissions};
+
+/// A file system that keeps its files in memory, in a flat list.
+#[derive(Debug, Default)]
+struct MemoryFileSystem {
+    files: RefCell<BTreeMap<PathBuf, String>>,
+}
+
+impl FileSystem for MemoryFileSystem {
+    fn read_to_string(&self, path: &Path) -> io::Result<String> {
+        self.files
+            .borrow()
+            .get(path)
+            .cloned()
+            .ok_or_else(|| io::ErrorKind::NotFound.into())
+    }
+
+    fn write(&self, path: &Path, contents: &str) -> io::Result<()> {
+        self.files
+            .borrow_mut()
+            .insert(path.to_path_buf(), contents.to_owned());
+        Ok(())
+    }
+
+    fn read_dir(&self, path: &Path) -> io::Result<Vec<DirEntry>> {
+        Ok(self
+            .files
+            .borrow()
+            .keys()
+            .filter_map(|file| file.strip_prefix(path).ok())
+            .map(|name| DirEntry {
+                name: name.to_string_lossy().into_owned(),
+                is_file: true,
+                is_directory: false,
+                is_symlink: false,
+            })
+            .collect())
+    }
+
+    fn stat(&self, path: &Path) -> io::Result<FileInfo> {
+        let contents = self.read_to_string(path)?;
+        Ok(FileInfo {
+            is_file: true,
+            is_directory: false,
+            size: contents.len() as u64,
+            modified: Some(UNIX_EPOCH + Duration::from_secs(1)),
+        })
+    }
+}
+
+/// Creates a context with an in-memory `fs` object and `permissions`.
+fn context(permissions: Permissions) -> Context {
+    let mut context = Context::default();
+    permissions.install(&mut context);
+    let file_system = MemoryFileSystem::default();
+    file_system
+        .write(Path::new("/data/hello.txt"), "Hello, world!")
+        .expect("writing to memory should not fail");
+    Fs::register(&mut context, file_system).expect("the `fs` object should be registered");
+    context
+}
+
+#[track_caller]
+fn eval(context: &mut Context, source: &str) -> JsValue {
+    let value = context
+        .eval(Source::from_bytes(source))
+        .expect("the script should not throw");
+    context.run_jobs().expect("the jobs should not throw");
+    value
+}
+
+#[test]
+fn sync_functions() {
+    let context = &mut context(Permissions::all());
+
+    let value = eval(context, r#"fs.readTextFileSync("/data/hello.txt")"#);
+    assert_eq!(value, js_string!("Hello, world!").into());
+
+    eval(context, r#"fs.writeTextFileSync("/data/new.txt", "new")"#);
+    let value = eval(
+        context,
+        r#"fs.readDirSync("/data").map(entry => entry.name).join()"#,
+    );
+    assert_eq!(value, js_string!("hello.txt,new.txt").into());
+
+    let value = eval(
+        context,
+        r#"
+        const info = fs.statSync("/data/hello.txt");
+        info.isFile && !info.isDirectory && info.size === 13 && info.mtime.getTime() === 1000
+        "#,
+    );
+    assert_eq!(value, JsValue::from(true));
+}
+
+#[test]
+fn async_functions() {
+    let context = &mut context(Permissions::all());
+
+    eval(
+        context,
+        r#"
+        let result;
+        fs.writeTextFile("/data/new.txt", "new")
+            .then(() => fs.readTextFile("/data/new.txt"))
+            .then(text => { result = text; });
+        "#,
+    );
+    assert_eq!(eval(context, "result"), js_string!("new").into());
+
+    eval(
+        context,
+        r#"
+        let error;
+        fs.stat("/data/missing.txt").catch(e => { error = e; });
+        "#,
+    );
+    let value = eval(
+        context,
+        "error instanceof Error && error.message.startsWith('could not stat')",
+    );
+    assert_eq!(value, JsValue::from(true));
+}
+
+#[test]
+fn operations_check_permissions() {
+    let permissions = Permissions::new()
+        .with_read(Allow::only(["/data"]))
+        .with_write(Allow::only(["/tmp"]));
+    let context = &mut context(permissions);
+
+    let value = eval(context, r#"fs.readTextFileSync("/data/hello.txt")"#);
+    assert_eq!(value, js_string!("Hello, world!").into());
+
+    let value = eval(
+        context,
+        r#"
+        try {
+            fs.writeTextFileSync("/data/hello.txt", "overwritten");
+            false
+        } catch (e) {
+            e instanceof TypeError
+        }
+        "#,
+    );
+    assert_eq!(value, JsValue::from(true));
+
+    eval(
+        context,
+        r#"
+        let denied = false;
+        fs.readDir("/etc").catch(e => { denied = e instanceof TypeError; });
+        "#,
+    );
+    assert_eq!(eval(context, "denied"), JsValue::from(true));
+    assert_eq!(
+        eval(context, r#"fs.readTextFileSync("/data/hello.txt")"#),
+        js_string!("Hello, world!").into()
+    );
+}
+
+#[test]
+fn default_file_system_reads_real_files() {
+    let context = &mut Context::default();
+    Permissions::all().install(context);
+    Fs::register(context, super::DefaultFileSystem).expect("the `fs` object should be registered");
+
+    let manifest = concat!(env!("CARGO_MANIFEST_DIR"), "/Cargo.toml");
+    let value = eval(
+        context,
+        &format!("fs.readTextFileSync({manifest:?}).includes('boa_runtime')"),
+    );
+    assert_eq!(value, JsValue::from(true));
+
+    let value = eval(
+        context,
+        &format!("fs.statSync({manifest:?}).mtime instanceof Date"),
+    );
+    assert_eq!(value, JsValue::from(true));
+}
+
+#[cfg(unix)]
+#[test]
+fn symbolic_links_cannot_escape_allowed_paths() {
+    let root = std::env::temp_dir().join(format!("boa-fs-symlinks-{}", std::process::id()));
+    let sandbox = root.join("sandbox");
+    let outside = root.join("outside");
+    std::fs::create_dir_all(&sandbox).expect("the sandbox should be created");
+    std::fs::create_dir_all(&outside).expect("the outside directory should be created");
+    std::fs::write(outside.join("secret.txt"), "secret").expect("the secret should be written");
+    std::os::unix::fs::symlink(&outside, sandbox.join("link")).expect("the link should be created");
+    std::os::unix::fs::symlink(outside.join("created.txt"), sandbox.join("dangling"))
+        .expect("the dangling link should be created");
+
+    let context = &mut Context::default();
+    Permissions::new()
+        .with_read(Allow::only([sandbox.clone()]))
+        .with_write(Allow::only([sandbox.clone()]))
+        .install(context);
+    Fs::register(context, super::DefaultFileSystem).expect("the `fs` object should be registered");
+
+    let sandbox = sandbox.display();
+    let value = eval(
+        context,
+        &format!(
+            r#"
+            const denied = [];
+            const attempts = [
+                () => fs.readTextFileSync("{sandbox}/link/secret.txt"),
+                () => fs.readTextFileSync("{sandbox}/link/../outside/secret.txt"),
+                () => fs.statSync("{sandbox}/link"),
+                () => fs.readDirSync("{sandbox}/link"),
+                () => fs.writeTextFileSync("{sandbox}/link/created.txt", "escaped"),
+                () => fs.writeTextFileSync("{sandbox}/dangling", "escaped"),
+            ];
+            for (const attempt of attempts) {{
+                try {{
+                    attempt();
+                }} catch (e) {{
+                    denied.push(e instanceof Error);
+                }}
+            }}
+            fs.writeTextFileSync("{sandbox}/inside.txt", "inside");
+            denied.length + fs.readTextFileSync("{sandbox}/inside.txt")
+            "#
+        ),
+    );
+    let created = outside.join("created.txt").exists();
+    std::fs::remove_dir_all(&root).expect("the test directory should be removed");
+
+    assert_eq!(value, js_string!("6inside").into());
+    assert!(!created, "a file was written outside of the allowed paths");
+}
@@ -61,20 +61,45 @@ mod text;
 #[doc(inline)]
 pub use text::{TextDecoder, TextEncoder};
 
+mod performance;
+
+#[doc(inline)]
+pub use performance::{Performance, PerformanceEntry, PerformanceEntryType};
+
 pub mod url;
 
+pub mod websocket;
+
+pub mod loader;
+
 pub mod interval;
 
+pub mod command;
+
+pub mod fs;
+
+pub mod process;
+
+pub mod storage;
+
+pub mod extensions;
+
+pub mod permissions;
+
+use permissions::Permissions;
+
 /// Options used when registering all built-in objects and functions of the `WebAPI` runtime.
 #[derive(Debug)]
 pub struct RegisterOptions<L: Logger> {
     console_logger: L,
+    permissions: Permissions,
 }
 
 impl Default for RegisterOptions<console::DefaultLogger> {
     fn default() -> Self {
         Self {
             console_logger: console::DefaultLogger,
+            permissions: Permissions::new(),
         }
     }
 }
@@ -92,26 +117,42 @@ impl<L: Logger> RegisterOptions<L> {
     pub fn with_console_logger<L2: Logger>(self, logger: L2) -> RegisterOptions<L2> {
         RegisterOptions::<L2> {
             console_logger: logger,
+            permissions: self.permissions,
         }
     }
+
+    /// Set the permissions of the scripts to access the system, which deny all the accesses by
+    /// default.
+    #[must_use]
+    pub fn with_permissions(mut self, permissions: Permissions) -> Self {
+        self.permissions = permissions;
+        self
+    }
 }
 
 /// Register all the built-in objects and functions of the `WebAPI` runtime.
 ///
+/// Each group of globals is registered as a separate [`Extension`][boa_engine::context::Extension]
+/// (see the [`extensions`] module), so they can be listed or removed individually afterwards.
+///
 /// # Errors
 /// This will error is any of the built-in objects or functions cannot be registered.
 pub fn register(
     ctx: &mut boa_engine::Context,
     options: RegisterOptions<impl Logger + 'static>,
 ) -> boa_engine::JsResult<()> {
-    Console::register_with_logger(ctx, options.console_logger)?;
-    TextDecoder::register(ctx)?;
-    TextEncoder::register(ctx)?;
+    options.permissions.install(ctx);
+
+    ctx.register_extension(extensions::ConsoleExtension::with_logger(
+        options.console_logger,
+    ))?;
+    ctx.register_extension(extensions::TextEncodingExtension)?;
 
     #[cfg(feature = "url")]
-    url::Url::register(ctx)?;
+    ctx.register_extension(extensions::UrlExtension)?;
 
-    interval::register(ctx)?;
+    ctx.register_extension(extensions::TimersExtension)?;
+    ctx.register_extension(extensions::PerformanceExtension)?;
 
     Ok(())
 }
@@ -0,0 +1,667 @@
+//! A filesystem [`ModuleLoader`] resolving modules like Node.js does.
+//!
+//! [`NodeModuleLoader`] implements the resolution algorithm of Node.js for ECMAScript modules,
+//! which allows running packages installed in `node_modules` directories:
+//!  - Relative and absolute specifiers are resolved against the importing module, probing the
+//!    file extensions and the `index` files of directories.
+//!  - Bare specifiers are looked up in the `node_modules` directories of the importing module
+//!    and its ancestors, using the `"exports"` map of the package if it has one, or its
+//!    `"main"` entry point otherwise.
+//!  - Specifiers starting with `#` are resolved with the `"imports"` map of the package of the
+//!    importing module.
+//!
+//! This relies on the `loader` feature.
+//!
+//! More information:
+//!  - [Node.js documentation][node]
+//!
+//! [node]: https://nodejs.org/api/packages.html
+#![cfg(feature = "loader")]
+
+#[cfg(test)]
+mod tests;
+
+use std::{
+    cell::RefCell,
+    fs::File,
+    io::BufReader,
+    path::{Component, Path, PathBuf},
+    rc::Rc,
+};
+
+use boa_engine::{
+    js_error, js_string,
+    module::{ModuleLoader, ModuleMiddleware, ModuleMiddlewares, ModuleRequest, Referrer},
+    Context, JsError, JsNativeError, JsResult, JsString, JsValue, Module, Source,
+};
+use boa_gc::GcRefCell;
+use rustc_hash::FxHashMap;
+
+/// A JSON value of a `package.json` file, keeping the order of the keys of its objects.
+///
+/// The order of the keys matters for the conditions of the `"exports"` and `"imports"` maps,
+/// which are matched in the order they are written.
+#[derive(Debug)]
+enum Json {
+    Null,
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+    Other,
+}
+
+impl Json {
+    /// Converts a parsed JSON `value` into a `Json`.
+    fn from_value(value: &JsValue, context: &mut Context) -> JsResult<Self> {
+        if value.is_null() {
+            return Ok(Self::Null);
+        }
+        if let Some(string) = value.as_string() {
+            return Ok(Self::String(string.to_std_string_escaped()));
+        }
+        let Some(object) = value.as_object() else {
+            return Ok(Self::Other);
+        };
+
+        if object.is_array() {
+            let length = object
+                .get(js_string!("length"), context)?
+                .to_length(context)?;
+            let mut elements = Vec::new();
+            for index in 0..length {
+                let element = object.get(index, context)?;
+                elements.push(Self::from_value(&element, context)?);
+            }
+            return Ok(Self::Array(elements));
+        }
+
+        let mut entries = Vec::new();
+        for key in object.own_property_keys(context)? {
+            let value = object.get(key.clone(), context)?;
+            entries.push((key.to_string(), Self::from_value(&value, context)?));
+        }
+        Ok(Self::Object(entries))
+    }
+
+    /// Gets the value of `key`, if this is an object that has it.
+    fn get(&self, key: &str) -> Option<&Self> {
+        match self {
+            Self::Object(entries) => entries.iter().find(|(k, _)| k == key).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    /// Gets the value of `key`, if this is an object that has it and it is a string.
+    fn get_str(&self, key: &str) -> Option<&str> {
+        match self.get(key)? {
+            Self::String(string) => Some(string),
+            _ => None,
+        }
+    }
+}
+
+/// A [`ModuleLoader`] that loads modules from the filesystem, resolving their specifiers with
+/// the resolution algorithm of Node.js.
+///
+/// See the [module documentation][self] for the supported specifiers.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::rc::Rc;
+///
+/// use boa_engine::{context::ContextBuilder, Source};
+/// use boa_runtime::loader::NodeModuleLoader;
+///
+/// let loader = NodeModuleLoader::new("./app")
+///     .unwrap()
+///     .with_conditions(["import", "browser"]);
+/// let mut context = ContextBuilder::new()
+///     .module_loader(Rc::new(loader))
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug)]
+pub struct NodeModuleLoader {
+    root: PathBuf,
+    conditions: Vec<String>,
+    extensions: Vec<String>,
+    module_map: GcRefCell<FxHashMap<PathBuf, Module>>,
+    packages: RefCell<FxHashMap<PathBuf, Option<Rc<Json>>>>,
+    middlewares: ModuleMiddlewares,
+}
+
+impl NodeModuleLoader {
+    /// Creates a new `NodeModuleLoader`, resolving the specifiers imported by scripts and modules
+    /// without a path from the `root` directory.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `root` cannot be canonicalized.
+    pub fn new<P: AsRef<Path>>(root: P) -> JsResult<Self> {
+        let root = root.as_ref();
+        let root = root.canonicalize().map_err(|e| {
+            JsNativeError::typ()
+                .with_message(format!("could not set module root `{}`", root.display()))
+                .with_cause(JsError::from_opaque(js_string!(e.to_string()).into()))
+        })?;
+
+        Ok(Self {
+            root,
+            conditions: vec![String::from("import")],
+            extensions: [".js", ".mjs", ".json"].map(String::from).to_vec(),
+            module_map: GcRefCell::default(),
+            packages: RefCell::default(),
+            middlewares: ModuleMiddlewares::new(),
+        })
+    }
+
+    /// Sets the conditions matched by the conditional entries of the `"exports"` and
+    /// `"imports"` maps, in addition to `"default"`, which always matches.
+    ///
+    /// Defaults to `["import"]`.
+    #[must_use]
+    pub fn with_conditions<I, S>(mut self, conditions: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.conditions = conditions.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets the extensions probed, in order, when a relative specifier or the `"main"` entry
+    /// point of a package don't name an existing file.
+    ///
+    /// Defaults to `[".js", ".mjs", ".json"]`.
+    #[must_use]
+    pub fn with_extensions<I, S>(mut self, extensions: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.extensions = extensions.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Adds a middleware that processes the sources of the modules loaded from the filesystem,
+    /// after the middlewares that were already added.
+    ///
+    /// The sources of JSON modules are processed as well.
+    #[must_use]
+    pub fn with_middleware<M: ModuleMiddleware + 'static>(mut self, middleware: M) -> Self {
+        self.middlewares.push(middleware);
+        self
+    }
+
+    /// Inserts a new module onto the module map.
+    #[inline]
+    pub fn insert(&self, path: PathBuf, module: Module) {
+        self.module_map.borrow_mut().insert(path, module);
+    }
+
+    /// Gets a module from its canonical path.
+    #[inline]
+    pub fn get(&self, path: &Path) -> Option<Module> {
+        self.module_map.borrow().get(path).cloned()
+    }
+
+    /// Resolves `specifier`, imported by the module at `referrer`, to the canonical path of the
+    /// module it refers to.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TypeError` if the specifier is invalid or doesn't refer to an existing file.
+    pub fn resolve(
+        &self,
+        specifier: &str,
+        referrer: Option<&Path>,
+        context: &mut Context,
+    ) -> JsResult<PathBuf> {
+        let base = referrer
+            .and_then(Path::parent)
+            .map_or_else(|| self.root.clone(), |dir| self.root.join(dir));
+        let base = base.canonicalize().unwrap_or(base);
+
+        let path = if is_path(specifier) {
+            let path = normalize(&base.join(specifier));
+            self.resolve_file(&path, context)?
+                .ok_or_else(|| js_error!(TypeError: "cannot find module `{}`", specifier))?
+        } else if specifier.starts_with('#') {
+            self.resolve_import(specifier, &base, context)?
+        } else {
+            self.resolve_package(specifier, &base, context)?
+        };
+
+        path.canonicalize()
+            .map_err(|_| js_error!(TypeError: "cannot find module `{}`", specifier))
+    }
+
+    /// Resolves `path` as a file, probing the extensions, or as a directory.
+    fn resolve_file(&self, path: &Path, context: &mut Context) -> JsResult<Option<PathBuf>> {
+        if let Some(file) = self.probe_file(path) {
+            return Ok(Some(file));
+        }
+        if !path.is_dir() {
+            return Ok(None);
+        }
+
+        if let Some(package) = self.package(path, context)? {
+            if let Some(main) = package.get_str("main") {
+                let main = normalize(&path.join(main));
+                if let Some(file) = self.probe_file(&main).or_else(|| self.probe_index(&main)) {
+                    return Ok(Some(file));
+                }
+            }
+        }
+
+        Ok(self.probe_index(path))
+    }
+
+    /// Returns `path` if it is a file, or the first file named `path` with one of the
+    /// extensions appended.
+    fn probe_file(&self, path: &Path) -> Option<PathBuf> {
+        if path.is_file() {
+            return Some(path.to_path_buf());
+        }
+        self.extensions.iter().find_map(|extension| {
+            let mut file = path.as_os_str().to_owned();
+            file.push(extension);
+            let file = PathBuf::from(file);
+            file.is_file().then_some(file)
+        })
+    }
+
+    /// Returns the first `index` file of the directory `path`, probing the extensions.
+    fn probe_index(&self, path: &Path) -> Option<PathBuf> {
+        self.extensions.iter().find_map(|extension| {
+            let file = path.join(format!("index{extension}"));
+            file.is_file().then_some(file)
+        })
+    }
+
+    /// Resolves the bare `specifier`, imported from the directory `base`.
+    fn resolve_package(
+        &self,
+        specifier: &str,
+        base: &Path,
+        context: &mut Context,
+    ) -> JsResult<PathBuf> {
+        let (name, subpath) = parse_package_specifier(specifier)
+            .ok_or_else(|| js_error!(TypeError: "invalid module specifier `{}`", specifier))?;
+
+        // A package can import itself by its name, if it has an `"exports"` map.
+        if let Some((scope, package)) = self.package_scope(base, context)? {
+            if package.get_str("name") == Some(name) {
+                if let Some(exports) = package.get("exports") {
+                    return self.resolve_exports(&scope, &subpath, exports, context);
+                }
+            }
+        }
+
+        for directory in base.ancestors() {
+            if directory.ends_with("node_modules") {
+                continue;
+            }
+            let package_dir = directory.join("node_modules").join(name);
+            if !package_dir.is_dir() {
+                continue;
+            }
+
+            if let Some(package) = self.package(&package_dir, context)? {
+                if let Some(exports) = package.get("exports") {
+                    return self.resolve_exports(&package_dir, &subpath, exports, context);
+                }
+            }
+
+            return self
+                .resolve_file(&normalize(&package_dir.join(&subpath)), context)?
+                .ok_or_else(|| js_error!(TypeError: "cannot find module `{}`", specifier));
+        }
+
+        Err(js_error!(TypeError: "cannot find package `{}`", name))
+    }
+
+    /// Resolves `subpath` with the `"exports"` map of the package in `package_dir`.
+    fn resolve_exports(
+        &self,
+        package_dir: &Path,
+        subpath: &str,
+        exports: &Json,
+        context: &mut Context,
+    ) -> JsResult<PathBuf> {
+        let resolved = match exports {
+            Json::Object(entries) if entries.iter().any(|(key, _)| key.starts_with('.')) => {
+                self.resolve_map(package_dir, subpath, entries, false, context)?
+            }
+            // Anything else is the target of the main entry point.
+            _ if subpath == "." => {
+                self.resolve_target(package_dir, exports, None, false, context)?
+            }
+            _ => None,
+        };
+
+        resolved.ok_or_else(|| {
+            js_error!(
+                TypeError: "package subpath `{}` is not exported by `{}`",
+                subpath,
+                package_dir.join("package.json").display()
+            )
+        })
+    }
+
+    /// Resolves the `#` `specifier` with the `"imports"` map of the package of the directory
+    /// `base`.
+    fn resolve_import(
+        &self,
+        specifier: &str,
+        base: &Path,
+        context: &mut Context,
+    ) -> JsResult<PathBuf> {
+        if specifier == "#" || specifier.starts_with("#/") {
+            return Err(js_error!(TypeError: "invalid module specifier `{}`", specifier));
+        }
+
+        if let Some((scope, package)) = self.package_scope(base, context)? {
+            if let Some(Json::Object(imports)) = package.get("imports") {
+                if let Some(path) = self.resolve_map(&scope, specifier, imports, true, context)? {
+                    return Ok(path);
+                }
+            }
+        }
+
+        Err(js_error!(TypeError: "package import `{}` is not defined", specifier))
+    }
+
+    /// Resolves `key` with the entries of an `"exports"` or `"imports"` map, matching the
+    /// exact entry first, and the longest `*` pattern otherwise.
+    fn resolve_map(
+        &self,
+        package_dir: &Path,
+        key: &str,
+        entries: &[(String, Json)],
+        is_imports: bool,
+        context: &mut Context,
+    ) -> JsResult<Option<PathBuf>> {
+        if !key.contains('*') {
+            if let Some((_, target)) = entries.iter().find(|(k, _)| k == key) {
+                return self.resolve_target(package_dir, target, None, is_imports, context);
+            }
+        }
+
+        let mut best: Option<(&str, &Json, &str)> = None;
+        for (pattern, target) in entries {
+            let Some((prefix, suffix)) = pattern.split_once('*') else {
+                continue;
+            };
+            if suffix.contains('*') {
+                continue;
+            }
+            let Some(matched) = key
+                .strip_prefix(prefix)
+                .and_then(|rest| rest.strip_suffix(suffix))
+            else {
+                continue;
+            };
+            if matched.is_empty() {
+                continue;
+            }
+
+            // Longer prefixes are more specific, then longer patterns.
+            let is_better = best.is_none_or(|(best, _, _)| {
+                let best_prefix = best.find('*').unwrap_or(best.len());
+                (prefix.len(), pattern.len()) > (best_prefix, best.len())
+            });
+            if is_better {
+                best = Some((pattern, target, matched));
+            }
+        }
+
+        match best {
+            Some((_, target, matched)) => {
+                self.resolve_target(package_dir, target, Some(matched), is_imports, context)
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Resolves a `target` of an `"exports"` or `"imports"` map, substituting the `*` of the
+    /// target with `matched` for pattern entries.
+    fn resolve_target(
+        &self,
+        package_dir: &Path,
+        target: &Json,
+        matched: Option<&str>,
+        is_imports: bool,
+        context: &mut Context,
+    ) -> JsResult<Option<PathBuf>> {
+        match target {
+            Json::String(target) => {
+                let substitute =
+                    |s: &str| matched.map_or_else(|| s.to_owned(), |m| s.replace('*', m));
+
+                let Some(relative) = target.strip_prefix("./") else {
+                    // The imports of a package can map to other packages.
+                    if is_imports && !is_path(target) && !target.contains(':') {
+                        return self
+                            .resolve_package(&substitute(target), package_dir, context)
+                            .map(Some);
+                    }
+                    return Err(invalid_target(target, package_dir));
+                };
+
+                let is_invalid_segment =
+                    |segment: &str| matches!(segment, "" | "." | ".." | "node_modules");
+                if relative.split('/').any(is_invalid_segment)
+                    || matched.is_some_and(|m| m.split('/').any(is_invalid_segment))
+                {
+                    return Err(invalid_target(target, package_dir));
+                }
+
+                Ok(Some(package_dir.join(substitute(relative))))
+            }
+            Json::Array(targets) => {
+                let mut last_error = None;
+                for target in targets {
+                    match self.resolve_target(package_dir, target, matched, is_imports, context) {
+                        Ok(Some(path)) => return Ok(Some(path)),
+                        Ok(None) => {}
+                        Err(err) => last_error = Some(err),
+                    }
+                }
+                last_error.map_or(Ok(None), Err)
+            }
+            Json::Object(conditions) => {
+                for (condition, target) in conditions {
+                    if condition != "default" && !self.conditions.contains(condition) {
+                        continue;
+                    }
+                    if let Some(path) =
+                        self.resolve_target(package_dir, target, matched, is_imports, context)?
+                    {
+                        return Ok(Some(path));
+                    }
+                }
+                Ok(None)
+            }
+            Json::Null => Ok(None),
+            Json::Other => Err(js_error!(
+                TypeError: "invalid package target in `{}`",
+                package_dir.join("package.json").display()
+            )),
+        }
+    }
+
+    /// Finds the closest package containing the directory `base`.
+    fn package_scope(
+        &self,
+        base: &Path,
+        context: &mut Context,
+    ) -> JsResult<Option<(PathBuf, Rc<Json>)>> {
+        for directory in base.ancestors() {
+            if directory.ends_with("node_modules") {
+                break;
+            }
+            if let Some(package) = self.package(directory, context)? {
+                return Ok(Some((directory.to_path_buf(), package)));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Reads the `package.json` file of the directory `directory`, if it has one.
+    fn package(&self, directory: &Path, context: &mut Context) -> JsResult<Option<Rc<Json>>> {
+        if let Some(package) = self.packages.borrow().get(directory) {
+            return Ok(package.clone());
+        }
+
+        let path = directory.join("package.json");
+        let package = match File::open(&path) {
+            Ok(file) => {
+                let value =
+                    JsValue::from_json_reader(BufReader::new(file), context).map_err(|err| {
+                        JsNativeError::typ()
+                            .with_message(format!("invalid package file `{}`", path.display()))
+                            .with_cause(err)
+                    })?;
+                Some(Rc::new(Json::from_value(&value, context)?))
+            }
+            Err(_) => None,
+        };
+
+        self.packages
+            .borrow_mut()
+            .insert(directory.to_path_buf(), package.clone());
+        Ok(package)
+    }
+
+    /// Loads the module at the canonical `path`, imported as `specifier` by `referrer`.
+    fn load(
+        &self,
+        referrer: &Referrer,
+        specifier: &JsString,
+        path: PathBuf,
+        context: &mut Context,
+    ) -> JsResult<Module> {
+        let short_path = specifier.to_std_string_escaped();
+
+        let source = std::fs::read(&path).map_err(|err| {
+            JsNativeError::typ()
+                .with_message(format!("could not open file `{short_path}`"))
+                .with_cause(JsError::from_opaque(js_string!(err.to_string()).into()))
+        })?;
+        let request = ModuleRequest::new(referrer, specifier, Some(&path));
+        let source = self.middlewares.apply(&request, source, context)?;
+
+        let module = if path
+            .extension()
+            .is_some_and(|extension| extension == "json")
+        {
+            let json = String::from_utf8(source).map_err(
+                |_| js_error!(SyntaxError: "JSON module `{}` is not valid UTF-8", short_path),
+            )?;
+            Module::parse_json(js_string!(json), context)
+        } else {
+            Module::parse(
+                Source::from_reader(source.as_slice(), Some(&path)),
+                None,
+                context,
+            )
+        }
+        .map_err(|err| {
+            JsNativeError::syntax()
+                .with_message(format!("could not parse module `{short_path}`"))
+                .with_cause(err)
+        })?;
+
+        self.insert(path, module.clone());
+        Ok(module)
+    }
+}
+
+impl ModuleLoader for NodeModuleLoader {
+    fn load_imported_module(
+        &self,
+        referrer: Referrer,
+        specifier: JsString,
+        finish_load: Box<dyn FnOnce(JsResult<Module>, &mut Context)>,
+        context: &mut Context,
+    ) {
+        let result = (|| {
+            let path =
+                self.resolve(&specifier.to_std_string_escaped(), referrer.path(), context)?;
+            if let Some(module) = self.get(&path) {
+                return Ok(module);
+            }
+            self.load(&referrer, &specifier, path, context)
+        })();
+
+        finish_load(result, context);
+    }
+
+    fn register_module(&self, specifier: JsString, module: Module) {
+        let path = PathBuf::from(specifier.to_std_string_escaped());
+
+        self.insert(path, module);
+    }
+
+    fn get_module(&self, specifier: JsString) -> Option<Module> {
+        let path = specifier.to_std_string_escaped();
+
+        self.get(Path::new(&path))
+    }
+}
+
+/// Checks if `specifier` is a relative or absolute path, instead of a bare specifier.
+fn is_path(specifier: &str) -> bool {
+    specifier == "."
+        || specifier == ".."
+        || specifier.starts_with("./")
+        || specifier.starts_with("../")
+        || Path::new(specifier).is_absolute()
+}
+
+/// Removes the `.` and `..` components of `path`, without accessing the filesystem.
+fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            component => normalized.push(component),
+        }
+    }
+    normalized
+}
+
+/// Splits a bare `specifier` into the name of the package and the subpath in the package,
+/// which is `.` for the main entry point.
+fn parse_package_specifier(specifier: &str) -> Option<(&str, String)> {
+    let name_len = if specifier.starts_with('@') {
+        let scope_len = specifier.find('/')?;
+        specifier[scope_len + 1..]
+            .find('/')
+            .map_or(specifier.len(), |len| scope_len + 1 + len)
+    } else {
+        specifier.find('/').unwrap_or(specifier.len())
+    };
+
+    let name = &specifier[..name_len];
+    if name.is_empty() || name.ends_with('/') || name.starts_with('.') || name.contains(['\\', '%'])
+    {
+        return None;
+    }
+
+    Some((name, format!(".{}", &specifier[name_len..])))
+}
+
+/// Creates the error thrown for an invalid target of the package in `package_dir`.
+fn invalid_target(target: &str, package_dir: &Path) -> JsError {
+    js_error!(
+        TypeError: "invalid package target `{}` in `{}`",
+        target,
+        package_dir.join("package.json").display()
+    )
+}
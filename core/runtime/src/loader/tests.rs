@@ -0,0 +1,171 @@
+use std::{fs, path::PathBuf, rc::Rc};
+
+use boa_engine::{
+    builtins::promise::PromiseState, context::ContextBuilder, js_string, Context, JsValue, Module,
+    Source,
+};
+
+use super::NodeModuleLoader;
+
+/// A temporary directory with the given files, removed when dropped.
+struct Fixture(PathBuf);
+
+impl Fixture {
+    fn new(name: &str, files: &[(&str, &str)]) -> Self {
+        let root = std::env::temp_dir().join(format!("boa_loader_{name}_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        for (path, content) in files {
+            let path = root.join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, content).unwrap();
+        }
+        Self(root.canonicalize().unwrap())
+    }
+
+    fn path(&self, path: &str) -> PathBuf {
+        self.0.join(path)
+    }
+}
+
+impl Drop for Fixture {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
+
+#[test]
+fn resolve() {
+    let fixture = Fixture::new(
+        "resolve",
+        &[
+            (
+                "package.json",
+                r##"{ "name": "app", "imports": { "#utils/*": "./src/utils/*.js", "#dep": "dep" } }"##,
+            ),
+            ("main.js", ""),
+            ("src/a.mjs", ""),
+            ("src/dir/index.js", ""),
+            ("src/utils/format.js", ""),
+            (
+                "node_modules/dep/package.json",
+                r#"{
+                    "exports": {
+                        ".": { "require": "./main.cjs", "import": "./main.mjs" },
+                        "./feature": { "browser": "./feature.browser.js", "default": "./feature.js" },
+                        "./lib/*": "./dist/*.js",
+                        "./lib/internal/*": null
+                    }
+                }"#,
+            ),
+            ("node_modules/dep/main.mjs", ""),
+            ("node_modules/dep/main.cjs", ""),
+            ("node_modules/dep/feature.js", ""),
+            ("node_modules/dep/feature.browser.js", ""),
+            ("node_modules/dep/dist/x.js", ""),
+            ("node_modules/dep/dist/internal/y.js", ""),
+            (
+                "node_modules/@scope/legacy/package.json",
+                r#"{ "main": "lib/entry" }"#,
+            ),
+            ("node_modules/@scope/legacy/lib/entry.js", ""),
+            ("node_modules/@scope/legacy/other.js", ""),
+        ],
+    );
+    let loader = NodeModuleLoader::new(&fixture.0).unwrap();
+    let context = &mut Context::default();
+    let referrer = fixture.path("main.js");
+    let mut resolve = |specifier: &str| {
+        loader
+            .resolve(specifier, Some(&referrer), context)
+            .map_err(|e| e.to_string())
+    };
+
+    assert_eq!(resolve("./src/a"), Ok(fixture.path("src/a.mjs")));
+    assert_eq!(resolve("./src/dir"), Ok(fixture.path("src/dir/index.js")));
+    assert_eq!(
+        resolve("dep"),
+        Ok(fixture.path("node_modules/dep/main.mjs"))
+    );
+    assert_eq!(
+        resolve("dep/feature"),
+        Ok(fixture.path("node_modules/dep/feature.js"))
+    );
+    assert_eq!(
+        resolve("dep/lib/x"),
+        Ok(fixture.path("node_modules/dep/dist/x.js"))
+    );
+    assert!(resolve("dep/lib/internal/y").is_err());
+    assert!(resolve("dep/main.mjs").is_err());
+    assert_eq!(
+        resolve("@scope/legacy"),
+        Ok(fixture.path("node_modules/@scope/legacy/lib/entry.js"))
+    );
+    assert_eq!(
+        resolve("@scope/legacy/other"),
+        Ok(fixture.path("node_modules/@scope/legacy/other.js"))
+    );
+    assert_eq!(
+        resolve("#utils/format"),
+        Ok(fixture.path("src/utils/format.js"))
+    );
+    assert_eq!(
+        resolve("#dep"),
+        Ok(fixture.path("node_modules/dep/main.mjs"))
+    );
+    assert!(resolve("#missing").is_err());
+    assert!(resolve("missing").is_err());
+
+    let loader = NodeModuleLoader::new(&fixture.0)
+        .unwrap()
+        .with_conditions(["require", "browser"]);
+    assert_eq!(
+        loader.resolve("dep", Some(&referrer), context).ok(),
+        Some(fixture.path("node_modules/dep/main.cjs"))
+    );
+    assert_eq!(
+        loader.resolve("dep/feature", Some(&referrer), context).ok(),
+        Some(fixture.path("node_modules/dep/feature.browser.js"))
+    );
+}
+
+#[test]
+fn load_package() {
+    let fixture = Fixture::new(
+        "load_package",
+        &[
+            (
+                "main.mjs",
+                "import { greet } from 'greeter'; export const result = greet();",
+            ),
+            (
+                "node_modules/greeter/package.json",
+                r#"{ "name": "greeter", "exports": { ".": "./index.js", "./data": "./data.json" } }"#,
+            ),
+            (
+                "node_modules/greeter/index.js",
+                "import data from 'greeter/data'; \
+                 export const greet = () => `hello ${data.name}`;",
+            ),
+            ("node_modules/greeter/data.json", r#"{ "name": "boa" }"#),
+        ],
+    );
+    let loader = Rc::new(NodeModuleLoader::new(&fixture.0).unwrap());
+    let context = &mut ContextBuilder::new()
+        .module_loader(loader.clone())
+        .build()
+        .unwrap();
+
+    let main = fixture.path("main.mjs");
+    let module = Module::parse(Source::from_filepath(&main).unwrap(), None, context).unwrap();
+    loader.insert(main, module.clone());
+
+    let promise = module.load_link_evaluate(context);
+    context.run_jobs().unwrap();
+    assert!(matches!(promise.state(), PromiseState::Fulfilled(_)));
+
+    let result = module
+        .namespace(context)
+        .get(js_string!("result"), context)
+        .unwrap();
+    assert_eq!(result, JsValue::from(js_string!("hello boa")));
+}
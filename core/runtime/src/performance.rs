@@ -0,0 +1,645 @@
+//! Boa's implementation of the `performance` Web API object.
+//!
+//! The `performance` object measures time with the [`Clock`] of the context, so hosts can
+//! virtualize it by building the context with a custom clock.
+//!
+//! The marks and measures created by scripts with `performance.mark()` and
+//! `performance.measure()` are recorded in the performance timeline of the context, which
+//! embedders can read with [`Performance::entries`] or observe with [`Performance::observe`].
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [W3C High Resolution Time specification][spec]
+//!  - [W3C User Timing specification][user-timing]
+//!
+//! [spec]: https://w3c.github.io/hr-time/
+//! [user-timing]: https://w3c.github.io/user-timing/
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/Performance
+//! [`Clock`]: boa_engine::context::Clock
+
+#[cfg(test)]
+mod tests;
+
+use std::{fmt, rc::Rc};
+
+use boa_engine::{
+    context::time::JsInstant,
+    js_string,
+    native_function::NativeFunction,
+    object::{builtins::JsArray, JsObject, ObjectInitializer},
+    property::Attribute,
+    Context, Finalize, JsArgs, JsNativeError, JsResult, JsString, JsSymbol, JsValue, Trace,
+};
+use boa_gc::{Gc, GcRefCell};
+
+/// The type of a [`PerformanceEntry`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PerformanceEntryType {
+    /// An entry created by `performance.mark()`.
+    Mark,
+    /// An entry created by `performance.measure()`.
+    Measure,
+}
+
+impl PerformanceEntryType {
+    /// Gets the name of the type, as returned by the `entryType` property of the entries.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Mark => "mark",
+            Self::Measure => "measure",
+        }
+    }
+
+    /// Parses the name of a type, returning `None` for unsupported types.
+    fn from_name(name: &JsString) -> Option<Self> {
+        if name == "mark" {
+            Some(Self::Mark)
+        } else if name == "measure" {
+            Some(Self::Measure)
+        } else {
+            None
+        }
+    }
+}
+
+/// An entry of the performance timeline, created by `performance.mark()` or
+/// `performance.measure()`.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct PerformanceEntry {
+    name: JsString,
+    #[unsafe_ignore_trace]
+    entry_type: PerformanceEntryType,
+    start_time: f64,
+    duration: f64,
+    detail: JsValue,
+}
+
+impl PerformanceEntry {
+    /// Gets the name of the mark or measure.
+    #[must_use]
+    pub const fn name(&self) -> &JsString {
+        &self.name
+    }
+
+    /// Gets the type of the entry.
+    #[must_use]
+    pub const fn entry_type(&self) -> PerformanceEntryType {
+        self.entry_type
+    }
+
+    /// Gets the start time of the entry, in milliseconds since the time origin of the
+    /// `performance` object.
+    #[must_use]
+    pub const fn start_time(&self) -> f64 {
+        self.start_time
+    }
+
+    /// Gets the duration of the entry in milliseconds, which is `0` for marks.
+    #[must_use]
+    pub const fn duration(&self) -> f64 {
+        self.duration
+    }
+
+    /// Gets the `detail` passed to `performance.mark()` or `performance.measure()`, or `null`.
+    #[must_use]
+    pub const fn detail(&self) -> &JsValue {
+        &self.detail
+    }
+
+    /// Creates the object representing the entry in scripts.
+    fn to_object(&self, context: &mut Context) -> JsValue {
+        ObjectInitializer::new(context)
+            .property(js_string!("name"), self.name.clone(), Attribute::all())
+            .property(
+                js_string!("entryType"),
+                js_string!(self.entry_type.name()),
+                Attribute::all(),
+            )
+            .property(js_string!("startTime"), self.start_time, Attribute::all())
+            .property(js_string!("duration"), self.duration, Attribute::all())
+            .property(js_string!("detail"), self.detail.clone(), Attribute::all())
+            .build()
+            .into()
+    }
+}
+
+/// A function notified of the new entries of the performance timeline.
+type Observer = Rc<dyn Fn(&PerformanceEntry)>;
+
+/// The performance timeline of a context.
+#[derive(Trace, Finalize)]
+struct Timeline {
+    /// The time origin on the monotonic clock of the context.
+    #[unsafe_ignore_trace]
+    origin: JsInstant,
+    entries: Vec<PerformanceEntry>,
+    #[unsafe_ignore_trace]
+    observers: Vec<Observer>,
+}
+
+impl fmt::Debug for Timeline {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Timeline")
+            .field("origin", &self.origin)
+            .field("entries", &self.entries)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Timeline {
+    /// Gets the start time of the last mark named `name`.
+    fn mark_time(&self, name: &JsString) -> Option<f64> {
+        self.entries
+            .iter()
+            .rev()
+            .find(|entry| entry.entry_type == PerformanceEntryType::Mark && &entry.name == name)
+            .map(|entry| entry.start_time)
+    }
+}
+
+/// The `performance` object.
+#[derive(Debug, Clone, Copy)]
+pub struct Performance;
+
+impl Performance {
+    /// Name of the built-in `performance` property.
+    pub const NAME: JsString = js_string!("performance");
+
+    /// Initializes the `performance` object, using the current time as its time origin.
+    ///
+    /// `performance.now()` returns the number of milliseconds elapsed since the time origin on
+    /// the monotonic clock of the context, and `performance.timeOrigin` is the time origin on
+    /// its wall clock, in milliseconds since the Unix epoch.
+    ///
+    /// This also starts a new, empty performance timeline for the context.
+    pub fn init(context: &mut Context) -> JsObject {
+        let clock = context.clock();
+        let origin = clock.monotonic_now();
+        let time_origin = millis(clock.now().nanos_since_epoch());
+
+        let timeline = Gc::new(GcRefCell::new(Timeline {
+            origin,
+            entries: Vec::new(),
+            observers: Vec::new(),
+        }));
+        context.insert_data(timeline.clone());
+
+        let method = |f: PerformanceMethod| {
+            NativeFunction::from_copy_closure_with_captures(f, timeline.clone())
+        };
+
+        ObjectInitializer::new(context)
+            .property(
+                JsSymbol::to_string_tag(),
+                js_string!("Performance"),
+                Attribute::CONFIGURABLE,
+            )
+            .property(
+                js_string!("timeOrigin"),
+                time_origin,
+                Attribute::READONLY | Attribute::ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .function(method(Self::now), js_string!("now"), 0)
+            .function(method(Self::mark), js_string!("mark"), 1)
+            .function(method(Self::measure), js_string!("measure"), 1)
+            .function(method(Self::get_entries), js_string!("getEntries"), 0)
+            .function(
+                method(Self::get_entries_by_name),
+                js_string!("getEntriesByName"),
+                1,
+            )
+            .function(
+                method(Self::get_entries_by_type),
+                js_string!("getEntriesByType"),
+                1,
+            )
+            .function(method(Self::clear_marks), js_string!("clearMarks"), 0)
+            .function(method(Self::clear_measures), js_string!("clearMeasures"), 0)
+            .build()
+    }
+
+    /// Registers the `performance` object as a global property.
+    ///
+    /// # Errors
+    /// This will error if the `performance` global property already exists.
+    pub fn register(context: &mut Context) -> JsResult<()> {
+        let performance = Self::init(context);
+        context.register_global_property(
+            Self::NAME,
+            performance,
+            Attribute::WRITABLE | Attribute::CONFIGURABLE,
+        )
+    }
+
+    /// Gets the entries of the performance timeline of `context`, in the order they were
+    /// created.
+    ///
+    /// Returns an empty list if the `performance` object wasn't initialized in `context`.
+    #[must_use]
+    pub fn entries(context: &Context) -> Vec<PerformanceEntry> {
+        context
+            .get_data::<Gc<GcRefCell<Timeline>>>()
+            .map(|timeline| timeline.borrow().entries.clone())
+            .unwrap_or_default()
+    }
+
+    /// Removes all the entries from the performance timeline of `context`, and returns them in
+    /// the order they were created.
+    ///
+    /// This is useful to periodically collect the entries for telemetry.
+    pub fn take_entries(context: &Context) -> Vec<PerformanceEntry> {
+        context
+            .get_data::<Gc<GcRefCell<Timeline>>>()
+            .map(|timeline| std::mem::take(&mut timeline.borrow_mut().entries))
+            .unwrap_or_default()
+    }
+
+    /// Registers a function called with each new entry of the performance timeline of
+    /// `context`, right after the entry is created by a script.
+    ///
+    /// Returns `false` if the `performance` object wasn't initialized in `context`.
+    pub fn observe(context: &Context, observer: impl Fn(&PerformanceEntry) + 'static) -> bool {
+        let Some(timeline) = context.get_data::<Gc<GcRefCell<Timeline>>>() else {
+            return false;
+        };
+        timeline.borrow_mut().observers.push(Rc::new(observer));
+        true
+    }
+
+    /// `performance.now()`
+    ///
+    /// Returns the number of milliseconds elapsed since the time origin.
+    ///
+    /// More information:
+    ///  - [W3C High Resolution Time specification][spec]
+    ///
+    /// [spec]: https://w3c.github.io/hr-time/#now-method
+    fn now(
+        _: &JsValue,
+        _: &[JsValue],
+        timeline: &Gc<GcRefCell<Timeline>>,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        Ok(JsValue::from(current_time(timeline, context)))
+    }
+
+    /// `performance.mark(markName, markOptions)`
+    ///
+    /// Creates a mark named `markName` in the performance timeline.
+    ///
+    /// More information:
+    ///  - [W3C User Timing specification][spec]
+    ///
+    /// [spec]: https://w3c.github.io/user-timing/#mark-method
+    fn mark(
+        _: &JsValue,
+        args: &[JsValue],
+        timeline: &Gc<GcRefCell<Timeline>>,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let name = args.get_or_undefined(0).to_string(context)?;
+
+        let (start_time, detail) = match args.get_or_undefined(1).as_object() {
+            Some(options) => (
+                options.get(js_string!("startTime"), context)?,
+                options.get(js_string!("detail"), context)?,
+            ),
+            None => (JsValue::undefined(), JsValue::undefined()),
+        };
+
+        let start_time = if start_time.is_undefined() {
+            current_time(timeline, context)
+        } else {
+            let start_time = start_time.to_number(context)?;
+            if start_time < 0.0 {
+                return Err(JsNativeError::typ()
+                    .with_message("the start time of a mark cannot be negative")
+                    .into());
+            }
+            start_time
+        };
+
+        let entry = PerformanceEntry {
+            name,
+            entry_type: PerformanceEntryType::Mark,
+            start_time,
+            duration: 0.0,
+            detail: null_if_undefined(detail),
+        };
+        Ok(record(timeline, entry, context))
+    }
+
+    /// `performance.measure(measureName, startOrMeasureOptions, endMark)`
+    ///
+    /// Creates a measure named `measureName` in the performance timeline, between two marks or
+    /// timestamps.
+    ///
+    /// More information:
+    ///  - [W3C User Timing specification][spec]
+    ///
+    /// [spec]: https://w3c.github.io/user-timing/#measure-method
+    fn measure(
+        _: &JsValue,
+        args: &[JsValue],
+        timeline: &Gc<GcRefCell<Timeline>>,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let name = args.get_or_undefined(0).to_string(context)?;
+        let start_or_options = args.get_or_undefined(1);
+        let end_mark = args.get_or_undefined(2);
+
+        let mut start = start_or_options.clone();
+        let mut end = JsValue::undefined();
+        let mut duration = JsValue::undefined();
+        let mut detail = JsValue::undefined();
+        if let Some(options) = start_or_options.as_object() {
+            start = options.get(js_string!("start"), context)?;
+            end = options.get(js_string!("end"), context)?;
+            duration = options.get(js_string!("duration"), context)?;
+            detail = options.get(js_string!("detail"), context)?;
+
+            let is_empty = start.is_undefined()
+                && end.is_undefined()
+                && duration.is_undefined()
+                && detail.is_undefined();
+            if !is_empty {
+                if !end_mark.is_undefined() {
+                    return Err(JsNativeError::typ()
+                        .with_message("an end mark cannot be used with measure options")
+                        .into());
+                }
+                if start.is_undefined() && end.is_undefined() {
+                    return Err(JsNativeError::typ()
+                        .with_message("measure options must contain a start or an end")
+                        .into());
+                }
+                if !start.is_undefined() && !end.is_undefined() && !duration.is_undefined() {
+                    return Err(JsNativeError::typ()
+                        .with_message(
+                            "measure options cannot contain a start, an end and a duration",
+                        )
+                        .into());
+                }
+            }
+        }
+        let duration = if duration.is_undefined() {
+            None
+        } else {
+            Some(duration.to_number(context)?)
+        };
+
+        let end_time = if !end_mark.is_undefined() {
+            timestamp(end_mark, timeline, context)?
+        } else if !end.is_undefined() {
+            timestamp(&end, timeline, context)?
+        } else if let Some(duration) = duration.filter(|_| !start.is_undefined()) {
+            timestamp(&start, timeline, context)? + duration
+        } else {
+            current_time(timeline, context)
+        };
+
+        let start_time = if !start.is_undefined() {
+            timestamp(&start, timeline, context)?
+        } else if let Some(duration) = duration {
+            end_time - duration
+        } else {
+            0.0
+        };
+
+        let entry = PerformanceEntry {
+            name,
+            entry_type: PerformanceEntryType::Measure,
+            start_time,
+            duration: end_time - start_time,
+            detail: null_if_undefined(detail),
+        };
+        Ok(record(timeline, entry, context))
+    }
+
+    /// `performance.getEntries()`
+    ///
+    /// Gets all the entries of the performance timeline, sorted by start time.
+    ///
+    /// More information:
+    ///  - [W3C Performance Timeline specification][spec]
+    ///
+    /// [spec]: https://w3c.github.io/performance-timeline/#getentries-method
+    fn get_entries(
+        _: &JsValue,
+        _: &[JsValue],
+        timeline: &Gc<GcRefCell<Timeline>>,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        Ok(entry_list(timeline, |_| true, context))
+    }
+
+    /// `performance.getEntriesByName(name, type)`
+    ///
+    /// Gets the entries of the performance timeline named `name`, and optionally of type
+    /// `type`, sorted by start time.
+    ///
+    /// More information:
+    ///  - [W3C Performance Timeline specification][spec]
+    ///
+    /// [spec]: https://w3c.github.io/performance-timeline/#getentriesbyname-method
+    fn get_entries_by_name(
+        _: &JsValue,
+        args: &[JsValue],
+        timeline: &Gc<GcRefCell<Timeline>>,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let name = args.get_or_undefined(0).to_string(context)?;
+        let entry_type = args.get_or_undefined(1);
+        let entry_type = if entry_type.is_undefined() {
+            None
+        } else {
+            let entry_type = entry_type.to_string(context)?;
+            let Some(entry_type) = PerformanceEntryType::from_name(&entry_type) else {
+                return Ok(JsArray::new(context).into());
+            };
+            Some(entry_type)
+        };
+
+        Ok(entry_list(
+            timeline,
+            |entry| entry.name == name && entry_type.is_none_or(|ty| entry.entry_type == ty),
+            context,
+        ))
+    }
+
+    /// `performance.getEntriesByType(type)`
+    ///
+    /// Gets the entries of the performance timeline of type `type`, sorted by start time.
+    ///
+    /// More information:
+    ///  - [W3C Performance Timeline specification][spec]
+    ///
+    /// [spec]: https://w3c.github.io/performance-timeline/#getentriesbytype-method
+    fn get_entries_by_type(
+        _: &JsValue,
+        args: &[JsValue],
+        timeline: &Gc<GcRefCell<Timeline>>,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let entry_type = args.get_or_undefined(0).to_string(context)?;
+        let Some(entry_type) = PerformanceEntryType::from_name(&entry_type) else {
+            return Ok(JsArray::new(context).into());
+        };
+
+        Ok(entry_list(
+            timeline,
+            |entry| entry.entry_type == entry_type,
+            context,
+        ))
+    }
+
+    /// `performance.clearMarks(markName)`
+    ///
+    /// Removes the marks named `markName` from the performance timeline, or all the marks if
+    /// `markName` is omitted.
+    ///
+    /// More information:
+    ///  - [W3C User Timing specification][spec]
+    ///
+    /// [spec]: https://w3c.github.io/user-timing/#clearmarks-method
+    fn clear_marks(
+        _: &JsValue,
+        args: &[JsValue],
+        timeline: &Gc<GcRefCell<Timeline>>,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        clear_entries(timeline, PerformanceEntryType::Mark, args, context)
+    }
+
+    /// `performance.clearMeasures(measureName)`
+    ///
+    /// Removes the measures named `measureName` from the performance timeline, or all the
+    /// measures if `measureName` is omitted.
+    ///
+    /// More information:
+    ///  - [W3C User Timing specification][spec]
+    ///
+    /// [spec]: https://w3c.github.io/user-timing/#clearmeasures-method
+    fn clear_measures(
+        _: &JsValue,
+        args: &[JsValue],
+        timeline: &Gc<GcRefCell<Timeline>>,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        clear_entries(timeline, PerformanceEntryType::Measure, args, context)
+    }
+}
+
+/// The signature of the methods of the `performance` object.
+type PerformanceMethod =
+    fn(&JsValue, &[JsValue], &Gc<GcRefCell<Timeline>>, &mut Context) -> JsResult<JsValue>;
+
+/// Gets the number of milliseconds elapsed since the time origin of `timeline`.
+fn current_time(timeline: &Gc<GcRefCell<Timeline>>, context: &Context) -> f64 {
+    let elapsed = context.clock().monotonic_now() - timeline.borrow().origin;
+    millis(elapsed.as_nanos())
+}
+
+/// Converts the start or the end of a measure to a timestamp, which is either given directly
+/// or the start time of a mark.
+fn timestamp(
+    value: &JsValue,
+    timeline: &Gc<GcRefCell<Timeline>>,
+    context: &mut Context,
+) -> JsResult<f64> {
+    if let Some(timestamp) = value.as_number() {
+        if timestamp < 0.0 {
+            return Err(JsNativeError::typ()
+                .with_message("the timestamp of a measure cannot be negative")
+                .into());
+        }
+        return Ok(timestamp);
+    }
+
+    let name = value.to_string(context)?;
+    timeline.borrow().mark_time(&name).ok_or_else(|| {
+        JsNativeError::syntax()
+            .with_message(format!(
+                "the mark `{}` does not exist",
+                name.to_std_string_escaped()
+            ))
+            .into()
+    })
+}
+
+/// Notifies the observers of `timeline` of `entry`, adds it to the timeline, and returns the object representing the
+/// entry.
+fn record(
+    timeline: &Gc<GcRefCell<Timeline>>,
+    entry: PerformanceEntry,
+    context: &mut Context,
+) -> JsValue {
+    let object = entry.to_object(context);
+
+    let observers = timeline.borrow().observers.clone();
+    for observer in observers {
+        observer(&entry);
+    }
+    timeline.borrow_mut().entries.push(entry);
+
+    object
+}
+
+/// Creates an array with the entries of `timeline` matching `filter`, sorted by start time.
+fn entry_list(
+    timeline: &Gc<GcRefCell<Timeline>>,
+    filter: impl Fn(&PerformanceEntry) -> bool,
+    context: &mut Context,
+) -> JsValue {
+    let mut entries: Vec<_> = timeline
+        .borrow()
+        .entries
+        .iter()
+        .filter(|entry| filter(entry))
+        .cloned()
+        .collect();
+    entries.sort_by(|a, b| a.start_time.total_cmp(&b.start_time));
+
+    let entries: Vec<_> = entries
+        .iter()
+        .map(|entry| entry.to_object(context))
+        .collect();
+    JsArray::from_iter(entries, context).into()
+}
+
+/// Removes the entries of type `entry_type` named by the first argument from `timeline`, or all
+/// of them if there is no argument.
+fn clear_entries(
+    timeline: &Gc<GcRefCell<Timeline>>,
+    entry_type: PerformanceEntryType,
+    args: &[JsValue],
+    context: &mut Context,
+) -> JsResult<JsValue> {
+    let name = args.get_or_undefined(0);
+    let name = if name.is_undefined() {
+        None
+    } else {
+        Some(name.to_string(context)?)
+    };
+
+    timeline.borrow_mut().entries.retain(|entry| {
+        entry.entry_type != entry_type || name.as_ref().is_some_and(|name| &entry.name != name)
+    });
+    Ok(JsValue::undefined())
+}
+
+/// Replaces an `undefined` detail with `null`, like the default value of the options.
+fn null_if_undefined(value: JsValue) -> JsValue {
+    if value.is_undefined() {
+        JsValue::null()
+    } else {
+        value
+    }
+}
+
+/// Converts a number of nanoseconds to milliseconds, keeping the sub-millisecond precision.
+#[allow(clippy::cast_precision_loss)]
+fn millis(nanos: u128) -> f64 {
+    nanos as f64 / 1_000_000.0
+}
@@ -0,0 +1,198 @@
+use crate::test::{run_test_actions, run_test_actions_with, TestAction};
+use crate::{Performance, PerformanceEntryType};
+use boa_engine::context::time::FixedClock;
+use boa_engine::context::ContextBuilder;
+use boa_engine::{js_string, Context, JsValue};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+fn create_context(clock: Rc<FixedClock>) -> Context {
+    let mut context = ContextBuilder::default().clock(clock).build().unwrap();
+    Performance::register(&mut context).unwrap();
+    context
+}
+
+const TEST_HARNESS: &str = r#"
+function assert(condition, message) {
+    if (!condition) {
+        if (!message) {
+            message = "Assertion failed";
+        }
+        throw new Error(message);
+    }
+}
+
+function assert_eq(a, b, message) {
+    if (a !== b) {
+        throw new Error(`${message} (${JSON.stringify(a)} !== ${JSON.stringify(b)})`);
+    }
+}
+"#;
+
+#[test]
+fn performance_basic() {
+    run_test_actions([
+        TestAction::run(TEST_HARNESS),
+        TestAction::run(
+            r#"
+                assert_eq(typeof performance.now, "function");
+                assert_eq(performance.now.length, 0);
+                assert_eq(Object.prototype.toString.call(performance), "[object Performance]");
+
+                const t1 = performance.now();
+                const t2 = performance.now();
+                assert(t1 >= 0);
+                assert(t2 >= t1);
+                assert(performance.timeOrigin <= Date.now());
+            "#,
+        ),
+    ]);
+}
+
+#[test]
+fn performance_fixed_clock() {
+    let clock = Rc::new(FixedClock::from_millis(1_000_000));
+    let context = &mut create_context(clock.clone());
+
+    run_test_actions_with(
+        [
+            TestAction::run(TEST_HARNESS),
+            TestAction::run(
+                r#"
+                    assert_eq(performance.timeOrigin, 1000000);
+                    assert_eq(performance.now(), 0);
+                "#,
+            ),
+            TestAction::inspect_context(move |_| clock.forward(1500)),
+            TestAction::run(
+                r#"
+                    assert_eq(performance.now(), 1500);
+                    assert_eq(Date.now(), 1001500);
+                    assert_eq(performance.timeOrigin + performance.now(), Date.now());
+                "#,
+            ),
+        ],
+        context,
+    );
+}
+
+#[test]
+fn performance_mark_measure() {
+    let clock = Rc::new(FixedClock::from_millis(1_000_000));
+    let context = &mut create_context(clock.clone());
+    let clock1 = clock.clone();
+
+    run_test_actions_with(
+        [
+            TestAction::run(TEST_HARNESS),
+            TestAction::inspect_context(move |_| clock1.forward(10)),
+            TestAction::run(
+                r#"
+                    const start = performance.mark("start", { detail: { step: 1 } });
+                    assert_eq(start.name, "start");
+                    assert_eq(start.entryType, "mark");
+                    assert_eq(start.startTime, 10);
+                    assert_eq(start.duration, 0);
+                    assert_eq(start.detail.step, 1);
+                    assert_eq(performance.mark("early", { startTime: 5 }).startTime, 5);
+                "#,
+            ),
+            TestAction::inspect_context(move |_| clock.forward(25)),
+            TestAction::run(
+                r#"
+                    performance.mark("end");
+
+                    const between = performance.measure("between", "start", "end");
+                    assert_eq(between.entryType, "measure");
+                    assert_eq(between.startTime, 10);
+                    assert_eq(between.duration, 25);
+                    assert_eq(between.detail, null);
+
+                    assert_eq(performance.measure("sinceStart", "start").duration, 25);
+                    assert_eq(performance.measure("sinceOrigin").duration, 35);
+                    assert_eq(performance.measure("options", { start: 2, duration: 3 }).duration, 3);
+                    assert_eq(performance.measure("toEnd", { end: "end", duration: 5 }).startTime, 30);
+
+                    const names = performance.getEntries().map(entry => entry.name);
+                    assert_eq(names.join(), "sinceOrigin,options,early,start,between,sinceStart,toEnd,end");
+                    assert_eq(performance.getEntriesByType("mark").length, 3);
+                    assert_eq(performance.getEntriesByType("resource").length, 0);
+                    assert_eq(performance.getEntriesByName("start").length, 1);
+                    assert_eq(performance.getEntriesByName("between", "mark").length, 0);
+
+                    performance.clearMarks("early");
+                    assert_eq(performance.getEntriesByType("mark").length, 2);
+                    performance.clearMeasures();
+                    assert_eq(performance.getEntriesByType("measure").length, 0);
+                    performance.clearMarks();
+                    assert_eq(performance.getEntries().length, 0);
+                "#,
+            ),
+        ],
+        context,
+    );
+}
+
+#[test]
+fn performance_measure_errors() {
+    run_test_actions([
+        TestAction::run(TEST_HARNESS),
+        TestAction::run(
+            r#"
+                function assert_throws(f, type) {
+                    try {
+                        f();
+                    } catch (e) {
+                        assert(e instanceof type, `expected ${type.name}, got ${e}`);
+                        return;
+                    }
+                    throw new Error(`expected ${type.name}`);
+                }
+
+                assert_throws(() => performance.mark("negative", { startTime: -1 }), TypeError);
+                assert_throws(() => performance.measure("missing", "missing"), SyntaxError);
+                assert_throws(() => performance.measure("both", { start: 0 }, "end"), TypeError);
+                assert_throws(() => performance.measure("neither", { detail: 1 }), TypeError);
+                assert_throws(
+                    () => performance.measure("all", { start: 0, end: 1, duration: 1 }),
+                    TypeError,
+                );
+            "#,
+        ),
+    ]);
+}
+
+#[test]
+fn performance_entries_from_rust() {
+    let context = &mut create_context(Rc::new(FixedClock::from_millis(0)));
+
+    let observed = Rc::new(RefCell::new(Vec::new()));
+    let observed_clone = observed.clone();
+    assert!(Performance::observe(context, move |entry| {
+        observed_clone
+            .borrow_mut()
+            .push(entry.name().to_std_string_escaped());
+    }));
+
+    run_test_actions_with(
+        [TestAction::run(
+            r#"
+                performance.mark("a", { detail: "payload" });
+                performance.measure("b", "a");
+            "#,
+        )],
+        context,
+    );
+
+    assert_eq!(*observed.borrow(), ["a", "b"]);
+
+    let entries = Performance::entries(context);
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0].entry_type(), PerformanceEntryType::Mark);
+    assert_eq!(entries[0].detail(), &JsValue::from(js_string!("payload")));
+    assert_eq!(entries[1].entry_type(), PerformanceEntryType::Measure);
+    assert_eq!(entries[1].name(), &js_string!("b"));
+
+    assert_eq!(Performance::take_entries(context).len(), 2);
+    assert!(Performance::entries(context).is_empty());
+}
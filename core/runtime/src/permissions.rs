@@ -0,0 +1,292 @@
+//! Permissions of the scripts to access the system, like the filesystem or the network.
+//!
+//! Scripts are sandboxed by default: the runtime extensions that access a resource of the system
+//! must check the [`Permissions`] of the context first, and no access is granted unless the host
+//! allows it. Extensions get them with [`Permissions::of`], and the host sets them with
+//! [`RegisterOptions::with_permissions`][crate::RegisterOptions::with_permissions] or
+//! [`Permissions::install`].
+//!
+//! ```
+//! use boa_engine::Context;
+//! use boa_runtime::permissions::{Allow, Permissions};
+//!
+//! let mut context = Context::default();
+//! assert!(Permissions::of(&context).check_env("HOME").is_err());
+//!
+//! Permissions::new()
+//!     .with_env(Allow::only(["HOME"]))
+//!     .with_net(Allow::All)
+//!     .install(&mut context);
+//!
+//! let permissions = Permissions::of(&context);
+//! assert!(permissions.check_env("HOME").is_ok());
+//! assert!(permissions.check_env("PATH").is_err());
+//! assert!(permissions.check_net("example.com", Some(443)).is_ok());
+//! ```
+
+#[cfg(test)]
+mod tests;
+
+use std::{
+    fmt::Display,
+    path::{self, Component, Path, PathBuf},
+};
+
+use boa_engine::{Context, Finalize, JsData, JsNativeError, JsResult, Trace};
+
+/// The resources of a kind that the scripts are allowed to access.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Allow<T> {
+    /// No resource can be accessed.
+    None,
+
+    /// Every resource can be accessed.
+    All,
+
+    /// Only the listed resources can be accessed.
+    Only(Vec<T>),
+}
+
+impl<T> Default for Allow<T> {
+    fn default() -> Self {
+        Self::None
+    }
+}
+
+impl<T> Allow<T> {
+    /// Allows access to the resources of `list`.
+    #[must_use]
+    pub fn only<I>(list: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<T>,
+    {
+        Self::Only(list.into_iter().map(Into::into).collect())
+    }
+
+    /// Returns `true` if a resource of the list matches `predicate`.
+    fn allows(&self, predicate: impl Fn(&T) -> bool) -> bool {
+        match self {
+            Self::None => false,
+            Self::All => true,
+            Self::Only(list) => list.iter().any(predicate),
+        }
+    }
+}
+
+/// The permissions of the scripts of a context.
+///
+/// All the accesses are denied by default.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Trace, Finalize, JsData)]
+#[boa_gc(empty_trace)]
+pub struct Permissions {
+    read: Allow<PathBuf>,
+    write: Allow<PathBuf>,
+    net: Allow<String>,
+    env: Allow<String>,
+    run: Allow<String>,
+}
+
+impl Permissions {
+    /// Creates a new `Permissions` that denies all the accesses.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            read: Allow::None,
+            write: Allow::None,
+            net: Allow::None,
+            env: Allow::None,
+            run: Allow::None,
+        }
+    }
+
+    /// Creates a new `Permissions` that allows all the accesses.
+    #[must_use]
+    pub const fn all() -> Self {
+        Self {
+            read: Allow::All,
+            write: Allow::All,
+            net: Allow::All,
+            env: Allow::All,
+            run: Allow::All,
+        }
+    }
+
+    /// Sets the paths that can be read, including the files inside of the allowed directories.
+    #[must_use]
+    pub fn with_read(mut self, read: Allow<PathBuf>) -> Self {
+        self.read = read;
+        self
+    }
+
+    /// Sets the paths that can be written, including the files inside of the allowed directories.
+    #[must_use]
+    pub fn with_write(mut self, write: Allow<PathBuf>) -> Self {
+        self.write = write;
+        self
+    }
+
+    /// Sets the hosts that can be connected to.
+    ///
+    /// A host allows all of its ports, while a host with a port, like `example.com:443`, only
+    /// allows that port.
+    #[must_use]
+    pub fn with_net(mut self, net: Allow<String>) -> Self {
+        self.net = net;
+        self
+    }
+
+    /// Sets the environment variables that can be accessed.
+    #[must_use]
+    pub fn with_env(mut self, env: Allow<String>) -> Self {
+        self.env = env;
+        self
+    }
+
+    /// Sets the programs that can be run, by name or by path.
+    ///
+    /// Running a program with `Command` also checks the environment variables it sets with
+    /// [`Permissions::check_env`], and its working directory with [`Permissions::check_read`].
+    #[must_use]
+    pub fn with_run(mut self, run: Allow<String>) -> Self {
+        self.run = run;
+        self
+    }
+
+    /// Sets these permissions as the permissions of the scripts of `context`.
+    pub fn install(self, context: &mut Context) {
+        context.insert_data(self);
+    }
+
+    /// Gets the permissions of the scripts of `context`, which deny all the accesses if the host
+    /// didn't set them.
+    #[must_use]
+    pub fn of(context: &Context) -> &Self {
+        static DENY_ALL: Permissions = Permissions::new();
+
+        context.get_data::<Self>().unwrap_or(&DENY_ALL)
+    }
+
+    /// Checks that `path` can be read.
+    ///
+    /// The path is made absolute and its `.` and `..` components are removed before it is
+    /// checked, but its symbolic links are not resolved, so an extension should also check the
+    /// canonicalized path before accessing it. The allowed paths match both as they are given
+    /// and with their symbolic links resolved.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TypeError` if reading `path` is not allowed.
+    pub fn check_read(&self, path: &Path) -> JsResult<()> {
+        check_path(&self.read, path, "read")
+    }
+
+    /// Checks that `path` can be written.
+    ///
+    /// See [`Permissions::check_read`] for how the path is compared to the allowed paths.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TypeError` if writing `path` is not allowed.
+    pub fn check_write(&self, path: &Path) -> JsResult<()> {
+        check_path(&self.write, path, "write")
+    }
+
+    /// Checks that the `port` of `host` can be connected to.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TypeError` if connecting to `host` is not allowed.
+    pub fn check_net(&self, host: &str, port: Option<u16>) -> JsResult<()> {
+        let allowed = self.net.allows(|allowed| {
+            if allowed.eq_ignore_ascii_case(host) {
+                return true;
+            }
+
+            port.is_some_and(|port| {
+                allowed
+                    .rsplit_once(':')
+                    .is_some_and(|(allowed_host, allowed_port)| {
+                        allowed_host.eq_ignore_ascii_case(host)
+                            && allowed_port.parse::<u16>().ok() == Some(port)
+                    })
+            })
+        });
+
+        match port {
+            Some(port) => check(allowed, "net", &format!("{host}:{port}")),
+            None => check(allowed, "net", &host),
+        }
+    }
+
+    /// Checks that the environment variable `name` can be accessed.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TypeError` if accessing `name` is not allowed.
+    pub fn check_env(&self, name: &str) -> JsResult<()> {
+        // The names of the environment variables are case insensitive on Windows.
+        let allowed = self.env.allows(|allowed| {
+            if cfg!(windows) {
+                allowed.eq_ignore_ascii_case(name)
+            } else {
+                allowed == name
+            }
+        });
+
+        check(allowed, "env", &name)
+    }
+
+    /// Checks that `program` can be run.
+    ///
+    /// The program must be allowed with the same name or path it is run with, since the programs
+    /// found in the `PATH` depend on the environment.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TypeError` if running `program` is not allowed.
+    pub fn check_run(&self, program: &str) -> JsResult<()> {
+        let allowed = self.run.allows(|allowed| allowed == program);
+
+        check(allowed, "run", &program)
+    }
+}
+
+/// Checks that `path` is inside one of the `allowed` paths.
+fn check_path(allowed: &Allow<PathBuf>, path: &Path, kind: &str) -> JsResult<()> {
+    let path = normalize(path);
+    let allowed = allowed.allows(|allowed| {
+        path.starts_with(normalize(allowed))
+            || std::fs::canonicalize(allowed).is_ok_and(|allowed| path.starts_with(allowed))
+    });
+
+    check(allowed, kind, &path.display())
+}
+
+/// Returns an error for the `kind` access to `resource` if it isn't `allowed`.
+fn check(allowed: bool, kind: &str, resource: &dyn Display) -> JsResult<()> {
+    if allowed {
+        return Ok(());
+    }
+
+    Err(JsNativeError::typ()
+        .with_message(format!("{kind} access to `{resource}` is not allowed"))
+        .into())
+}
+
+/// Makes `path` absolute, and removes its `.` and `..` components.
+fn normalize(path: &Path) -> PathBuf {
+    let path = path::absolute(path).unwrap_or_else(|_| path.to_path_buf());
+
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            component => normalized.push(component),
+        }
+    }
+    normalized
+}
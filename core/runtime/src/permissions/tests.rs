@@ -0,0 +1,76 @@
+use std::path::{Path, PathBuf};
+
+use boa_engine::Context;
+
+use super::{Allow, Permissions};
+use crate::{register, RegisterOptions};
+
+#[test]
+fn everything_is_denied_by_default() {
+    let context = &mut Context::default();
+    register(context, RegisterOptions::default()).expect("failed to register WebAPI objects");
+
+    let permissions = Permissions::of(context);
+    assert!(permissions.check_read(Path::new("file.txt")).is_err());
+    assert!(permissions.check_write(Path::new("file.txt")).is_err());
+    assert!(permissions.check_net("example.com", None).is_err());
+    assert!(permissions.check_env("HOME").is_err());
+    assert!(permissions.check_run("git").is_err());
+    assert_eq!(Permissions::of(&Context::default()), &Permissions::new());
+}
+
+#[test]
+fn registered_permissions_are_installed() {
+    let context = &mut Context::default();
+    let options = RegisterOptions::default().with_permissions(Permissions::all());
+    register(context, options).expect("failed to register WebAPI objects");
+
+    assert_eq!(Permissions::of(context), &Permissions::all());
+}
+
+#[test]
+fn paths_inside_allowed_directories() {
+    let permissions = Permissions::new().with_read(Allow::only(["data", "/etc/hosts"]));
+
+    assert!(permissions.check_read(Path::new("data")).is_ok());
+    assert!(permissions.check_read(Path::new("./data/file.txt")).is_ok());
+    assert!(permissions.check_read(Path::new("/etc/hosts")).is_ok());
+    assert!(permissions
+        .check_read(Path::new("data/../secret.txt"))
+        .is_err());
+    assert!(permissions.check_read(Path::new("database")).is_err());
+    assert!(permissions.check_write(Path::new("data/file.txt")).is_err());
+
+    let cwd = std::env::current_dir().expect("the current directory should be accessible");
+    assert!(permissions.check_read(&cwd.join("data/file.txt")).is_ok());
+
+    let permissions = Permissions::new().with_write(Allow::<PathBuf>::All);
+    assert!(permissions.check_write(Path::new("/")).is_ok());
+}
+
+#[test]
+fn hosts_with_and_without_ports() {
+    let permissions = Permissions::new().with_net(Allow::only(["example.com", "localhost:8080"]));
+
+    assert!(permissions.check_net("example.com", None).is_ok());
+    assert!(permissions.check_net("EXAMPLE.com", Some(443)).is_ok());
+    assert!(permissions.check_net("localhost", Some(8080)).is_ok());
+    assert!(permissions.check_net("localhost", Some(8081)).is_err());
+    assert!(permissions.check_net("localhost", None).is_err());
+    assert!(permissions.check_net("sub.example.com", None).is_err());
+}
+
+#[test]
+fn denied_accesses_throw_type_errors() {
+    let context = &mut Context::default();
+    let permissions = Permissions::new().with_env(Allow::only(["HOME"]));
+
+    assert!(permissions.check_env("HOME").is_ok());
+
+    let error = permissions
+        .check_env("PATH")
+        .expect_err("PATH should not be allowed")
+        .try_native(context)
+        .expect("should be a native error");
+    assert_eq!(error.message(), "env access to `PATH` is not allowed");
+}
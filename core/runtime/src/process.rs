@@ -0,0 +1,250 @@
+//! Boa's implementation of the `process` object, giving scripts access to their environment.
+//!
+//! Like in Node.js, the `process` object has the following properties:
+//!  - `process.env` is an object with the environment variables that the scripts are allowed to
+//!    access by the [`Permissions`] of the context.
+//!  - `process.argv` is an array with the arguments of the program.
+//!  - `process.exitCode` is the exit code of the program, if the host uses it.
+//!  - `process.exit(code)` stops the execution of the current script, and sets the exit code if
+//!    it is given. Hosts should then stop running scripts and jobs, see [`Process::has_exited`].
+//!
+//! The environment is provided by an [`Environment`], which hosts can implement to filter or fake
+//! the variables and arguments of each context, and [`Process::exit_code`] and
+//! [`Process::has_exited`] let them act on the exit code of the scripts.
+//!
+//! This relies on the `process` feature.
+#![cfg(feature = "process")]
+
+#[cfg(test)]
+mod tests;
+
+use std::rc::Rc;
+
+use boa_engine::{
+    js_string,
+    native_function::NativeFunction,
+    object::{builtins::JsArray, FunctionObjectBuilder, JsObject, ObjectInitializer},
+    property::Attribute,
+    Context, Finalize, JsArgs, JsData, JsNativeError, JsResult, JsString, JsSymbol, JsValue, Trace,
+};
+
+use crate::permissions::Permissions;
+
+/// The environment of the scripts, used by the `process` object.
+///
+/// Hosts can implement this trait to filter the environment of the scripts, or to give each
+/// context a different environment.
+pub trait Environment {
+    /// Returns the environment variables, as `(name, value)` pairs.
+    fn vars(&self) -> Vec<(String, String)>;
+
+    /// Returns the arguments of the program, starting with the program itself.
+    fn args(&self) -> Vec<String>;
+}
+
+/// The default [`Environment`], which returns the variables of the current process and the
+/// given arguments.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DefaultEnvironment {
+    args: Vec<String>,
+}
+
+impl Default for DefaultEnvironment {
+    /// Creates an environment with the arguments of the current process.
+    fn default() -> Self {
+        Self::with_args(std::env::args())
+    }
+}
+
+impl DefaultEnvironment {
+    /// Creates an environment with the given arguments, instead of the arguments of the current
+    /// process.
+    pub fn with_args<I>(args: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        Self {
+            args: args.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl Environment for DefaultEnvironment {
+    fn vars(&self) -> Vec<(String, String)> {
+        std::env::vars_os()
+            .filter_map(|(name, value)| Some((name.into_string().ok()?, value.into_string().ok()?)))
+            .collect()
+    }
+
+    fn args(&self) -> Vec<String> {
+        self.args.clone()
+    }
+}
+
+/// The exit state of the scripts of a context.
+#[derive(Debug, Clone, Copy, Default, Trace, Finalize, JsData)]
+#[boa_gc(empty_trace)]
+struct ExitState {
+    code: Option<i32>,
+    exited: bool,
+}
+
+/// The `process` object.
+#[derive(Debug, Clone, Copy)]
+pub struct Process;
+
+impl Process {
+    /// Name of the built-in `process` property.
+    pub const NAME: JsString = js_string!("process");
+
+    /// Initializes the `process` object with `environment`.
+    ///
+    /// Only the environment variables allowed by the [`Permissions`] of the context when the
+    /// object is initialized are added to `process.env`.
+    pub fn init<E: Environment + 'static>(context: &mut Context, environment: E) -> JsObject {
+        Self::init_with_shared_environment(context, Rc::new(environment))
+    }
+
+    /// Initializes the `process` object with an environment that can be shared with other
+    /// `process` objects.
+    pub(crate) fn init_with_shared_environment(
+        context: &mut Context,
+        environment: Rc<dyn Environment>,
+    ) -> JsObject {
+        context.insert_data(ExitState::default());
+
+        let mut env = ObjectInitializer::new(context);
+        for (name, value) in environment.vars() {
+            if Permissions::of(env.context()).check_env(&name).is_ok() {
+                env.property(
+                    JsString::from(name.as_str()),
+                    JsString::from(value.as_str()),
+                    Attribute::all(),
+                );
+            }
+        }
+        let env = env.build();
+
+        let argv = environment
+            .args()
+            .into_iter()
+            .map(|arg| JsString::from(arg.as_str()).into())
+            .collect::<Vec<JsValue>>();
+        let argv = JsArray::from_iter(argv, context);
+
+        let get_exit_code = FunctionObjectBuilder::new(
+            context.realm(),
+            NativeFunction::from_fn_ptr(Self::get_exit_code),
+        )
+        .name(js_string!("get exitCode"))
+        .length(0)
+        .build();
+        let set_exit_code = FunctionObjectBuilder::new(
+            context.realm(),
+            NativeFunction::from_fn_ptr(Self::set_exit_code),
+        )
+        .name(js_string!("set exitCode"))
+        .length(1)
+        .build();
+
+        ObjectInitializer::new(context)
+            .property(
+                JsSymbol::to_string_tag(),
+                Self::NAME,
+                Attribute::CONFIGURABLE,
+            )
+            .property(js_string!("env"), env, Attribute::all())
+            .property(js_string!("argv"), argv, Attribute::all())
+            .accessor(
+                js_string!("exitCode"),
+                Some(get_exit_code),
+                Some(set_exit_code),
+                Attribute::ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .function(
+                NativeFunction::from_fn_ptr(Self::exit),
+                js_string!("exit"),
+                1,
+            )
+            .build()
+    }
+
+    /// Registers the `process` object as a global property, with `environment`.
+    ///
+    /// # Errors
+    /// This will error if the `process` global property already exists.
+    pub fn register<E: Environment + 'static>(
+        context: &mut Context,
+        environment: E,
+    ) -> JsResult<()> {
+        let process = Self::init(context, environment);
+        context.register_global_property(
+            Self::NAME,
+            process,
+            Attribute::WRITABLE | Attribute::CONFIGURABLE,
+        )
+    }
+
+    /// Returns the exit code set by the scripts of `context` with `process.exitCode` or
+    /// `process.exit(code)`, if any.
+    #[must_use]
+    pub fn exit_code(context: &Context) -> Option<i32> {
+        context.get_data::<ExitState>().and_then(|state| state.code)
+    }
+
+    /// Returns `true` if a script of `context` called `process.exit()`.
+    ///
+    /// The call stops the execution of the script with an uncatchable error, which hosts should
+    /// not report, and no other script or job should run afterwards.
+    #[must_use]
+    pub fn has_exited(context: &Context) -> bool {
+        context
+            .get_data::<ExitState>()
+            .is_some_and(|state| state.exited)
+    }
+
+    /// Converts `value` to an exit code, where `undefined` unsets the exit code.
+    fn to_exit_code(value: &JsValue, context: &mut Context) -> JsResult<Option<i32>> {
+        if value.is_undefined() {
+            return Ok(None);
+        }
+        value.to_i32(context).map(Some)
+    }
+
+    /// Returns the state of the `process` object of `context`.
+    fn state(context: &mut Context) -> JsResult<&mut ExitState> {
+        context.get_data_mut::<ExitState>().ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("the `process` object was not initialized")
+                .into()
+        })
+    }
+
+    /// `get process.exitCode`
+    #[allow(clippy::unnecessary_wraps)]
+    fn get_exit_code(_: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        Ok(Self::exit_code(context).map_or_else(JsValue::undefined, JsValue::from))
+    }
+
+    /// `set process.exitCode`
+    fn set_exit_code(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let code = Self::to_exit_code(args.get_or_undefined(0), context)?;
+        Self::state(context)?.code = code;
+        Ok(JsValue::undefined())
+    }
+
+    /// `process.exit(code)`
+    fn exit(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let code = Self::to_exit_code(args.get_or_undefined(0), context)?;
+        let state = Self::state(context)?;
+        if code.is_some() {
+            state.code = code;
+        }
+        state.exited = true;
+
+        Err(JsNativeError::runtime_limit()
+            .with_message("process.exit() was called")
+            .into())
+    }
+}
@@ -0,0 +1,106 @@
+use boa_engine::{js_string, Context, JsValue, Source};
+
+use super::{Environment, Process};
+use crate::permissions::{Allow, Permissions};
+
+/// An environment with fixed variables and arguments.
+struct FakeEnvironment;
+
+impl Environment for FakeEnvironment {
+    fn vars(&self) -> Vec<(String, String)> {
+        vec![
+            ("HOME".to_owned(), "/home/boa".to_owned()),
+            ("SECRET".to_owned(), "hunter2".to_owned()),
+        ]
+    }
+
+    fn args(&self) -> Vec<String> {
+        vec![
+            "boa".to_owned(),
+            "script.js".to_owned(),
+            "--verbose".to_owned(),
+        ]
+    }
+}
+
+/// Creates a context with a fake `process` object and `permissions`.
+fn context(permissions: Permissions) -> Context {
+    let mut context = Context::default();
+    permissions.install(&mut context);
+    Process::register(&mut context, FakeEnvironment)
+        .expect("the `process` object should be registered");
+    context
+}
+
+#[track_caller]
+fn eval(context: &mut Context, source: &str) -> JsValue {
+    context
+        .eval(Source::from_bytes(source))
+        .expect("the script should not throw")
+}
+
+#[test]
+fn env_only_has_allowed_variables() {
+    let context = &mut context(Permissions::new().with_env(Allow::only(["HOME"])));
+
+    assert_eq!(
+        eval(context, "process.env.HOME"),
+        js_string!("/home/boa").into()
+    );
+    assert_eq!(
+        eval(context, "Object.keys(process.env).join()"),
+        js_string!("HOME").into()
+    );
+
+    let context = &mut self::context(Permissions::new());
+    assert_eq!(
+        eval(context, "Object.keys(process.env).length"),
+        JsValue::from(0)
+    );
+}
+
+#[test]
+fn argv() {
+    let context = &mut context(Permissions::new());
+
+    assert_eq!(
+        eval(context, "process.argv.join(' ')"),
+        js_string!("boa script.js --verbose").into()
+    );
+}
+
+#[test]
+fn exit_code() {
+    let context = &mut context(Permissions::new());
+    assert_eq!(Process::exit_code(context), None);
+    assert_eq!(eval(context, "process.exitCode"), JsValue::undefined());
+
+    eval(context, "process.exitCode = 3");
+    assert_eq!(Process::exit_code(context), Some(3));
+    assert_eq!(eval(context, "process.exitCode"), JsValue::from(3));
+    assert!(!Process::has_exited(context));
+
+    eval(context, "process.exitCode = undefined");
+    assert_eq!(Process::exit_code(context), None);
+}
+
+#[test]
+fn exit_stops_the_script() {
+    let context = &mut context(Permissions::new());
+
+    let result = context.eval(Source::from_bytes(
+        r#"
+        var reached = false;
+        try {
+            process.exit(42);
+        } catch {}
+        reached = true;
+        "#,
+    ));
+
+    let error = result.expect_err("`process.exit()` should stop the script");
+    assert!(error.as_native().is_some_and(|err| err.is_runtime_limit()));
+    assert!(Process::has_exited(context));
+    assert_eq!(Process::exit_code(context), Some(42));
+    assert_eq!(eval(context, "reached"), JsValue::from(false));
+}
@@ -0,0 +1,300 @@
+//! Boa's implementation of the `storage` object, a key-value store for the state of the scripts.
+//!
+//! The `storage` object is similar to `localStorage`, but its functions return promises, so the
+//! values can be stored by a database or a remote service:
+//!  - `storage.get(key)` resolves to the value of `key`, or `null` if it isn't set.
+//!  - `storage.set(key, value)` sets the value of `key`, converting it to a string.
+//!  - `storage.delete(key)` removes `key`, and resolves to `true` if it was set.
+//!  - `storage.list(prefix)` resolves to the keys starting with `prefix`, or all the keys if no
+//!    prefix is given.
+//!
+//! The values are kept by the host, by implementing [`KeyValueStore`] for stores with a
+//! synchronous API, or [`AsyncKeyValueStore`] for stores with an asynchronous API. The promises
+//! are settled by [`NativeAsyncJob`]s, so the job executor of the context must support them.
+//!
+//! This relies on the `storage` feature.
+#![cfg(feature = "storage")]
+
+#[cfg(test)]
+mod tests;
+
+use std::{future::Future, io, pin::Pin, rc::Rc};
+
+use boa_engine::{
+    job::NativeAsyncJob,
+    js_string,
+    native_function::NativeFunction,
+    object::{
+        builtins::{JsArray, JsPromise},
+        JsObject, ObjectInitializer,
+    },
+    property::Attribute,
+    Context, JsArgs, JsNativeError, JsResult, JsString, JsSymbol, JsValue,
+};
+
+/// A key-value store with a synchronous API, used by the `storage` object.
+pub trait KeyValueStore {
+    /// Gets the value of `key`, or `None` if it isn't set.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the store cannot be read.
+    fn get(&self, key: &str) -> io::Result<Option<String>>;
+
+    /// Sets the value of `key`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the store cannot be written.
+    fn set(&self, key: &str, value: &str) -> io::Result<()>;
+
+    /// Removes `key`, and returns `true` if it was set.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the store cannot be written.
+    fn delete(&self, key: &str) -> io::Result<bool>;
+
+    /// Returns the keys that start with `prefix`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the store cannot be read.
+    fn list(&self, prefix: &str) -> io::Result<Vec<String>>;
+}
+
+/// The future returned by the operations of an [`AsyncKeyValueStore`].
+pub type StoreFuture<'a, T> = Pin<Box<dyn Future<Output = io::Result<T>> + 'a>>;
+
+/// A key-value store with an asynchronous API, used by the `storage` object.
+///
+/// This is implemented for all the [`KeyValueStore`]s.
+pub trait AsyncKeyValueStore {
+    /// Gets the value of `key`, or `None` if it isn't set.
+    fn get(&self, key: String) -> StoreFuture<'_, Option<String>>;
+
+    /// Sets the value of `key`.
+    fn set(&self, key: String, value: String) -> StoreFuture<'_, ()>;
+
+    /// Removes `key`, and returns `true` if it was set.
+    fn delete(&self, key: String) -> StoreFuture<'_, bool>;
+
+    /// Returns the keys that start with `prefix`.
+    fn list(&self, prefix: String) -> StoreFuture<'_, Vec<String>>;
+}
+
+impl<S: KeyValueStore + ?Sized> AsyncKeyValueStore for S {
+    fn get(&self, key: String) -> StoreFuture<'_, Option<String>> {
+        Box::pin(std::future::ready(KeyValueStore::get(self, &key)))
+    }
+
+    fn set(&self, key: String, value: String) -> StoreFuture<'_, ()> {
+        Box::pin(std::future::ready(KeyValueStore::set(self, &key, &value)))
+    }
+
+    fn delete(&self, key: String) -> StoreFuture<'_, bool> {
+        Box::pin(std::future::ready(KeyValueStore::delete(self, &key)))
+    }
+
+    fn list(&self, prefix: String) -> StoreFuture<'_, Vec<String>> {
+        Box::pin(std::future::ready(KeyValueStore::list(self, &prefix)))
+    }
+}
+
+/// An operation of the `storage` object, with its arguments converted from JavaScript values.
+#[derive(Debug)]
+enum Operation {
+    Get(String),
+    Set(String, String),
+    Delete(String),
+    List(String),
+}
+
+/// The result of an [`Operation`].
+#[derive(Debug)]
+enum Outcome {
+    Value(Option<String>),
+    Unit,
+    Deleted(bool),
+    Keys(Vec<String>),
+}
+
+impl Operation {
+    /// Runs the operation with `store`.
+    async fn run(self, store: &dyn AsyncKeyValueStore) -> JsResult<Outcome> {
+        let (action, key) = match &self {
+            Self::Get(key) => ("get", key.clone()),
+            Self::Set(key, _) => ("set", key.clone()),
+            Self::Delete(key) => ("delete", key.clone()),
+            Self::List(prefix) => ("list the keys starting with", prefix.clone()),
+        };
+
+        let outcome = match self {
+            Self::Get(key) => store.get(key).await.map(Outcome::Value),
+            Self::Set(key, value) => store.set(key, value).await.map(|()| Outcome::Unit),
+            Self::Delete(key) => store.delete(key).await.map(Outcome::Deleted),
+            Self::List(prefix) => store.list(prefix).await.map(Outcome::Keys),
+        };
+
+        outcome.map_err(|err| {
+            JsNativeError::error()
+                .with_message(format!("could not {action} `{key}`: {err}"))
+                .into()
+        })
+    }
+}
+
+impl Outcome {
+    /// Converts the outcome to the value of the promise.
+    fn into_value(self, context: &mut Context) -> JsValue {
+        match self {
+            Self::Value(value) => {
+                value.map_or_else(JsValue::null, |value| JsString::from(value.as_str()).into())
+            }
+            Self::Unit => JsValue::undefined(),
+            Self::Deleted(deleted) => deleted.into(),
+            Self::Keys(keys) => {
+                let keys = keys
+                    .iter()
+                    .map(|key| JsString::from(key.as_str()).into())
+                    .collect::<Vec<JsValue>>();
+                JsArray::from_iter(keys, context).into()
+            }
+        }
+    }
+}
+
+/// Converts the argument at `index` to a string.
+fn string_arg(args: &[JsValue], index: usize, context: &mut Context) -> JsResult<String> {
+    Ok(args
+        .get_or_undefined(index)
+        .to_string(context)?
+        .to_std_string_lossy())
+}
+
+/// The conversion of the arguments of a function of the `storage` object to its operation.
+type Arguments = fn(&[JsValue], &mut Context) -> JsResult<Operation>;
+
+/// The `storage` object.
+#[derive(Debug, Clone, Copy)]
+pub struct Storage;
+
+impl Storage {
+    /// Name of the built-in `storage` property.
+    pub const NAME: JsString = js_string!("storage");
+
+    /// Initializes the `storage` object, which keeps its values in `store`.
+    pub fn init<S: AsyncKeyValueStore + 'static>(context: &mut Context, store: S) -> JsObject {
+        Self::init_with_shared_store(context, Rc::new(store))
+    }
+
+    /// Initializes the `storage` object with a store that can be shared with other `storage`
+    /// objects.
+    pub(crate) fn init_with_shared_store(
+        context: &mut Context,
+        store: Rc<dyn AsyncKeyValueStore>,
+    ) -> JsObject {
+        fn method(arguments: Arguments, store: Rc<dyn AsyncKeyValueStore>) -> NativeFunction {
+            // SAFETY: The store doesn't contain types that need tracing.
+            unsafe {
+                NativeFunction::from_closure(move |_, args, context| {
+                    let operation = arguments(args, context)?;
+                    let (promise, resolvers) = JsPromise::new_pending(context);
+                    let store = store.clone();
+
+                    context.enqueue_job(
+                        NativeAsyncJob::new(move |context| {
+                            Box::pin(async move {
+                                let outcome = operation.run(&*store).await;
+
+                                let context = &mut context.borrow_mut();
+                                match outcome {
+                                    Ok(outcome) => {
+                                        let value = outcome.into_value(context);
+                                        resolvers.resolve.call(
+                                            &JsValue::undefined(),
+                                            &[value],
+                                            context,
+                                        )
+                                    }
+                                    Err(e) => {
+                                        let e = e.to_opaque(context);
+                                        resolvers.reject.call(&JsValue::undefined(), &[e], context)
+                                    }
+                                }
+                            })
+                        })
+                        .into(),
+                    );
+
+                    Ok(promise.into())
+                })
+            }
+        }
+
+        let mut object = ObjectInitializer::new(context);
+        object.property(
+            JsSymbol::to_string_tag(),
+            Self::NAME,
+            Attribute::CONFIGURABLE,
+        );
+
+        let functions: [(&str, Arguments, usize); 4] = [
+            ("get", Self::get, 1),
+            ("set", Self::set, 2),
+            ("delete", Self::delete, 1),
+            ("list", Self::list, 0),
+        ];
+        for (name, arguments, length) in functions {
+            object.function(
+                method(arguments, store.clone()),
+                JsString::from(name),
+                length,
+            );
+        }
+
+        object.build()
+    }
+
+    /// Registers the `storage` object as a global property, which keeps its values in `store`.
+    ///
+    /// # Errors
+    /// This will error if the `storage` global property already exists.
+    pub fn register<S: AsyncKeyValueStore + 'static>(
+        context: &mut Context,
+        store: S,
+    ) -> JsResult<()> {
+        let storage = Self::init(context, store);
+        context.register_global_property(
+            Self::NAME,
+            storage,
+            Attribute::WRITABLE | Attribute::CONFIGURABLE,
+        )
+    }
+
+    /// `storage.get(key)`
+    fn get(args: &[JsValue], context: &mut Context) -> JsResult<Operation> {
+        Ok(Operation::Get(string_arg(args, 0, context)?))
+    }
+
+    /// `storage.set(key, value)`
+    fn set(args: &[JsValue], context: &mut Context) -> JsResult<Operation> {
+        let key = string_arg(args, 0, context)?;
+        let value = string_arg(args, 1, context)?;
+        Ok(Operation::Set(key, value))
+    }
+
+    /// `storage.delete(key)`
+    fn delete(args: &[JsValue], context: &mut Context) -> JsResult<Operation> {
+        Ok(Operation::Delete(string_arg(args, 0, context)?))
+    }
+
+    /// `storage.list(prefix)`
+    fn list(args: &[JsValue], context: &mut Context) -> JsResult<Operation> {
+        let prefix = args.get_or_undefined(0);
+        if prefix.is_undefined() {
+            return Ok(Operation::List(String::new()));
+        }
+        Ok(Operation::List(string_arg(args, 0, context)?))
+    }
+}
@@ -0,0 +1,146 @@
+use std::{cell::RefCell, collections::BTreeMap, future::poll_fn, io, rc::Rc, task::Poll};
+
+use boa_engine::{js_string, Context, JsValue, Source};
+
+use super::{AsyncKeyValueStore, KeyValueStore, Storage, StoreFuture};
+
+/// A store that keeps its values in memory.
+#[derive(Debug, Default, Clone)]
+struct MemoryStore {
+    values: Rc<RefCell<BTreeMap<String, String>>>,
+}
+
+impl KeyValueStore for MemoryStore {
+    fn get(&self, key: &str) -> io::Result<Option<String>> {
+        Ok(self.values.borrow().get(key).cloned())
+    }
+
+    fn set(&self, key: &str, value: &str) -> io::Result<()> {
+        if key.is_empty() {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, "empty key"));
+        }
+        self.values
+            .borrow_mut()
+            .insert(key.to_owned(), value.to_owned());
+        Ok(())
+    }
+
+    fn delete(&self, key: &str) -> io::Result<bool> {
+        Ok(self.values.borrow_mut().remove(key).is_some())
+    }
+
+    fn list(&self, prefix: &str) -> io::Result<Vec<String>> {
+        Ok(self
+            .values
+            .borrow()
+            .keys()
+            .filter(|key| key.starts_with(prefix))
+            .cloned()
+            .collect())
+    }
+}
+
+/// A store with an asynchronous API, whose operations wait once before completing.
+#[derive(Debug, Default)]
+struct SlowStore(MemoryStore);
+
+/// Returns a future that waits once before returning `value`.
+fn slow<T>(value: io::Result<T>) -> StoreFuture<'static, T>
+where
+    T: 'static,
+{
+    let mut value = Some(value);
+    let mut waited = false;
+    Box::pin(poll_fn(move |cx| {
+        if waited {
+            return Poll::Ready(
+                value
+                    .take()
+                    .expect("the future should not be polled after completion"),
+            );
+        }
+        waited = true;
+        cx.waker().wake_by_ref();
+        Poll::Pending
+    }))
+}
+
+impl AsyncKeyValueStore for SlowStore {
+    fn get(&self, key: String) -> StoreFuture<'_, Option<String>> {
+        slow(KeyValueStore::get(&self.0, &key))
+    }
+
+    fn set(&self, key: String, value: String) -> StoreFuture<'_, ()> {
+        slow(KeyValueStore::set(&self.0, &key, &value))
+    }
+
+    fn delete(&self, key: String) -> StoreFuture<'_, bool> {
+        slow(KeyValueStore::delete(&self.0, &key))
+    }
+
+    fn list(&self, prefix: String) -> StoreFuture<'_, Vec<String>> {
+        slow(KeyValueStore::list(&self.0, &prefix))
+    }
+}
+
+#[track_caller]
+fn eval(context: &mut Context, source: &str) -> JsValue {
+    let value = context
+        .eval(Source::from_bytes(source))
+        .expect("the script should not throw");
+    context.run_jobs().expect("the jobs should not throw");
+    value
+}
+
+/// Runs operations on the `storage` object of `context`.
+fn run_operations(context: &mut Context) {
+    eval(
+        context,
+        r#"
+        const log = [];
+        (async () => {
+            await storage.set("user.name", "Boa");
+            await storage.set("user.age", 7);
+            await storage.set("theme", "dark");
+            log.push(await storage.get("user.name"));
+            log.push(await storage.get("user.age"));
+            log.push(await storage.get("missing"));
+            log.push((await storage.list("user.")).join("|"));
+            log.push(await storage.delete("theme"));
+            log.push(await storage.delete("theme"));
+            log.push((await storage.list()).join("|"));
+            await storage.set("", "value").catch(e => log.push(e.message));
+        })();
+        "#,
+    );
+
+    assert_eq!(
+        eval(context, "log.join()"),
+        js_string!(
+            "Boa,7,,user.age|user.name,true,false,user.age|user.name,could not set ``: empty key"
+        )
+        .into()
+    );
+}
+
+#[test]
+fn sync_store() {
+    let store = MemoryStore::default();
+    let context = &mut Context::default();
+    Storage::register(context, store.clone()).expect("the `storage` object should be registered");
+
+    run_operations(context);
+    assert_eq!(
+        store.values.borrow().get("user.name").map(String::as_str),
+        Some("Boa")
+    );
+}
+
+#[test]
+fn async_store() {
+    let context = &mut Context::default();
+    Storage::register(context, SlowStore::default())
+        .expect("the `storage` object should be registered");
+
+    run_operations(context);
+}
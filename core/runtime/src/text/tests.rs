@@ -150,3 +150,27 @@ fn roundtrip() {
         context,
     );
 }
+
+#[test]
+fn text_extension_can_be_removed_and_registered_again() {
+    use crate::extensions::TextEncodingExtension;
+
+    let context = &mut Context::default();
+    context.register_extension(TextEncodingExtension).unwrap();
+    assert!(context.has_extension("text"));
+
+    assert!(context.unregister_extension("text").unwrap());
+    assert!(!context.has_extension("text"));
+    assert!(!context.has_global_class::<TextDecoder>());
+
+    context.register_extension(TextEncodingExtension).unwrap();
+    run_test_actions_with(
+        [TestAction::inspect_context(|context| {
+            let encoding = context
+                .eval(boa_engine::Source::from_bytes("new TextDecoder().encoding"))
+                .unwrap();
+            assert_eq!(encoding, boa_engine::JsValue::from(js_string!("utf-8")));
+        })],
+        context,
+    );
+}
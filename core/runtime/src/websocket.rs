@@ -0,0 +1,604 @@
+//! Boa's implementation of JavaScript's `WebSocket` Web API class.
+//!
+//! The runtime doesn't include a network stack, so the socket IO is delegated to a
+//! [`WebSocketTransport`] implemented by the host, which reports what happens to the connection
+//! by sending [`WebSocketEvent`]s to an [`EventSender`]. The events can be sent from any thread,
+//! and they are dispatched to the `onopen`, `onmessage`, `onerror` and `onclose` handlers of the
+//! socket by [`NativeAsyncJob`]s, so the job executor of the context must support them.
+//!
+//! The hosts of the URLs must be allowed by the [`Permissions`] of the context.
+//!
+//! This relies on the `websocket` feature.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [WHATWG `WebSocket` specification][spec]
+//!
+//! [spec]: https://websockets.spec.whatwg.org/
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/WebSocket
+#![cfg(feature = "websocket")]
+
+#[cfg(test)]
+mod tests;
+
+use std::{
+    collections::VecDeque,
+    fmt, io,
+    rc::Rc,
+    sync::{Arc, Mutex, PoisonError},
+    task::{Poll, Waker},
+};
+
+use boa_engine::{
+    class::{Class, ClassBuilder},
+    job::NativeAsyncJob,
+    js_string,
+    native_function::NativeFunction,
+    object::{
+        builtins::{JsArrayBuffer, JsUint8Array},
+        ObjectInitializer,
+    },
+    property::Attribute,
+    Context, Finalize, JsArgs, JsData, JsError, JsNativeError, JsObject, JsResult, JsString,
+    JsValue, Trace,
+};
+
+use crate::permissions::Permissions;
+
+/// A message sent or received by a `WebSocket`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Message {
+    /// A text message.
+    Text(String),
+    /// A binary message.
+    Binary(Vec<u8>),
+}
+
+/// Something that happened to the connection of a `WebSocket`, reported by the host.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WebSocketEvent {
+    /// The connection was established, with the given subprotocol, which is empty if the server
+    /// didn't select one.
+    Open {
+        /// The subprotocol selected by the server.
+        protocol: String,
+    },
+    /// A message was received.
+    Message(Message),
+    /// The connection failed. It must be followed by a [`WebSocketEvent::Close`] event.
+    Error(String),
+    /// The connection was closed. No event is dispatched after this one.
+    Close {
+        /// The close code sent by the server, or `1006` if the connection was lost.
+        code: u16,
+        /// The reason sent by the server.
+        reason: String,
+        /// `true` if the closing handshake was completed.
+        was_clean: bool,
+    },
+}
+
+/// Sends the [`WebSocketEvent`]s of a connection to its `WebSocket`.
+///
+/// The sender can be cloned, and used from any thread.
+#[derive(Debug, Clone)]
+pub struct EventSender {
+    queue: Arc<Mutex<EventQueue>>,
+}
+
+impl EventSender {
+    /// Sends `event` to the `WebSocket`, which dispatches it in a job.
+    pub fn send(&self, event: WebSocketEvent) {
+        let waker = {
+            let mut queue = self.queue.lock().unwrap_or_else(PoisonError::into_inner);
+            queue.events.push_back(event);
+            queue.waker.take()
+        };
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
+/// The events sent to a `WebSocket` that weren't dispatched yet.
+#[derive(Debug, Default)]
+struct EventQueue {
+    events: VecDeque<WebSocketEvent>,
+    waker: Option<Waker>,
+}
+
+/// Receives the events sent by an [`EventSender`].
+#[derive(Debug)]
+struct EventReceiver {
+    queue: Arc<Mutex<EventQueue>>,
+}
+
+impl EventReceiver {
+    /// Creates a connected sender and receiver.
+    fn channel() -> (EventSender, Self) {
+        let queue = Arc::new(Mutex::new(EventQueue::default()));
+        (
+            EventSender {
+                queue: queue.clone(),
+            },
+            Self { queue },
+        )
+    }
+
+    /// Waits for the next event.
+    async fn recv(&self) -> WebSocketEvent {
+        std::future::poll_fn(|cx| {
+            let mut queue = self.queue.lock().unwrap_or_else(PoisonError::into_inner);
+            match queue.events.pop_front() {
+                Some(event) => Poll::Ready(event),
+                None => {
+                    queue.waker = Some(cx.waker().clone());
+                    Poll::Pending
+                }
+            }
+        })
+        .await
+    }
+}
+
+/// The host transport of the `WebSocket` class, which opens the connections.
+pub trait WebSocketTransport {
+    /// Starts opening a connection to `url`, requesting one of the subprotocols of `protocols`.
+    ///
+    /// The transport must report the outcome by sending a [`WebSocketEvent::Open`] event, or a
+    /// [`WebSocketEvent::Close`] event, to `events`, and every connection must eventually send a
+    /// [`WebSocketEvent::Close`] event.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the connection cannot be started at all.
+    fn connect(
+        &self,
+        url: &str,
+        protocols: &[String],
+        events: EventSender,
+    ) -> io::Result<Box<dyn WebSocketConnection>>;
+}
+
+/// A connection opened by a [`WebSocketTransport`].
+pub trait WebSocketConnection {
+    /// Sends `message` to the server.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the message cannot be sent.
+    fn send(&mut self, message: Message) -> io::Result<()>;
+
+    /// Starts the closing handshake, with the given close code and reason.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the connection cannot be closed.
+    fn close(&mut self, code: Option<u16>, reason: &str) -> io::Result<()>;
+}
+
+/// The transport of the `WebSocket` class of a context.
+#[derive(Trace, Finalize, JsData)]
+#[boa_gc(empty_trace)]
+struct Transport(Rc<dyn WebSocketTransport>);
+
+/// The `readyState` of a `WebSocket` that is connecting.
+const CONNECTING: u16 = 0;
+/// The `readyState` of an open `WebSocket`.
+const OPEN: u16 = 1;
+/// The `readyState` of a `WebSocket` that is closing.
+const CLOSING: u16 = 2;
+/// The `readyState` of a closed `WebSocket`.
+const CLOSED: u16 = 3;
+
+/// The `WebSocket` class, a client connection to a WebSocket server.
+#[derive(Trace, Finalize, JsData)]
+#[boa_gc(empty_trace)]
+pub struct WebSocket {
+    url: String,
+    protocol: String,
+    ready_state: u16,
+    connection: Option<Box<dyn WebSocketConnection>>,
+    events: Option<EventReceiver>,
+}
+
+impl fmt::Debug for WebSocket {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WebSocket")
+            .field("url", &self.url)
+            .field("protocol", &self.protocol)
+            .field("ready_state", &self.ready_state)
+            .finish_non_exhaustive()
+    }
+}
+
+impl WebSocket {
+    /// Register the `WebSocket` class into the realm, with the `transport` that opens its
+    /// connections.
+    ///
+    /// # Errors
+    /// This will error if the context or realm cannot register the class.
+    pub fn register<T: WebSocketTransport + 'static>(
+        context: &mut Context,
+        transport: T,
+    ) -> JsResult<()> {
+        Self::register_with_shared_transport(context, Rc::new(transport))
+    }
+
+    /// Register the `WebSocket` class into the realm, with a transport that can be shared with
+    /// other contexts.
+    pub(crate) fn register_with_shared_transport(
+        context: &mut Context,
+        transport: Rc<dyn WebSocketTransport>,
+    ) -> JsResult<()> {
+        context.insert_data(Transport(transport));
+        context.register_global_class::<Self>()?;
+        Ok(())
+    }
+
+    /// Gets the `WebSocket` of `this`.
+    fn this_object(this: &JsValue) -> JsResult<JsObject> {
+        this.as_object()
+            .filter(|object| object.is::<Self>())
+            .cloned()
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("`this` is not a `WebSocket` object")
+                    .into()
+            })
+    }
+
+    /// Calls `f` with the `WebSocket` of `this`.
+    fn with_this<R>(this: &JsValue, f: impl FnOnce(&mut Self) -> R) -> JsResult<R> {
+        let object = Self::this_object(this)?;
+        let mut socket = object.downcast_mut::<Self>().ok_or_else(|| {
+            JsNativeError::typ().with_message("`this` is not a `WebSocket` object")
+        })?;
+        Ok(f(&mut socket))
+    }
+
+    /// `get WebSocket.prototype.url`
+    fn get_url(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        Self::with_this(this, |socket| JsString::from(socket.url.as_str()).into())
+    }
+
+    /// `get WebSocket.prototype.protocol`
+    fn get_protocol(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        Self::with_this(this, |socket| {
+            JsString::from(socket.protocol.as_str()).into()
+        })
+    }
+
+    /// `get WebSocket.prototype.readyState`
+    fn get_ready_state(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        Self::with_this(this, |socket| socket.ready_state.into())
+    }
+
+    /// `WebSocket.prototype.send(data)`
+    fn send(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        Self::this_object(this)?;
+        let message = to_message(args.get_or_undefined(0), context)?;
+
+        Self::with_this(this, |socket| -> JsResult<JsValue> {
+            match socket.ready_state {
+                CONNECTING => {
+                    return Err(JsNativeError::error()
+                        .with_message("the `WebSocket` is still connecting")
+                        .into())
+                }
+                // Like in browsers, the messages sent after closing the socket are discarded.
+                CLOSING | CLOSED => return Ok(JsValue::undefined()),
+                _ => {}
+            }
+            if let Some(connection) = &mut socket.connection {
+                connection.send(message).map_err(|err| {
+                    JsNativeError::error()
+                        .with_message(format!("could not send the message: {err}"))
+                })?;
+            }
+            Ok(JsValue::undefined())
+        })?
+    }
+
+    /// `WebSocket.prototype.close(code, reason)`
+    fn close(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        Self::this_object(this)?;
+
+        let code = args.get_or_undefined(0);
+        let code = if code.is_undefined() {
+            None
+        } else {
+            let code = code.to_u32(context)?;
+            match u16::try_from(code) {
+                Ok(code @ (1000 | 3000..=4999)) => Some(code),
+                _ => {
+                    return Err(JsNativeError::range()
+                        .with_message(format!("invalid close code {code}"))
+                        .into())
+                }
+            }
+        };
+        let reason = args.get_or_undefined(1);
+        let reason = if reason.is_undefined() {
+            String::new()
+        } else {
+            reason.to_string(context)?.to_std_string_lossy()
+        };
+        if reason.len() > 123 {
+            return Err(JsNativeError::syntax()
+                .with_message("the close reason must not be longer than 123 bytes")
+                .into());
+        }
+
+        Self::with_this(this, |socket| -> JsResult<JsValue> {
+            if matches!(socket.ready_state, CLOSING | CLOSED) {
+                return Ok(JsValue::undefined());
+            }
+            socket.ready_state = CLOSING;
+            if let Some(connection) = &mut socket.connection {
+                connection.close(code, &reason).map_err(|err| {
+                    JsNativeError::error()
+                        .with_message(format!("could not close the connection: {err}"))
+                })?;
+            }
+            Ok(JsValue::undefined())
+        })?
+    }
+}
+
+impl Class for WebSocket {
+    const NAME: &'static str = "WebSocket";
+    const LENGTH: usize = 1;
+
+    fn init(class: &mut ClassBuilder<'_>) -> JsResult<()> {
+        let realm = class.context().realm().clone();
+        let getters: [(
+            &str,
+            fn(&JsValue, &[JsValue], &mut Context) -> JsResult<JsValue>,
+        ); 3] = [
+            ("url", Self::get_url),
+            ("protocol", Self::get_protocol),
+            ("readyState", Self::get_ready_state),
+        ];
+        for (name, getter) in getters {
+            let getter = NativeFunction::from_fn_ptr(getter).to_js_function(&realm);
+            class.accessor(
+                JsString::from(name),
+                Some(getter),
+                None,
+                Attribute::CONFIGURABLE | Attribute::ENUMERABLE,
+            );
+        }
+
+        for (name, value) in [
+            ("CONNECTING", CONNECTING),
+            ("OPEN", OPEN),
+            ("CLOSING", CLOSING),
+            ("CLOSED", CLOSED),
+        ] {
+            class
+                .property(JsString::from(name), value, Attribute::ENUMERABLE)
+                .static_property(JsString::from(name), value, Attribute::ENUMERABLE);
+        }
+
+        class
+            .method(
+                js_string!("send"),
+                1,
+                NativeFunction::from_fn_ptr(Self::send),
+            )
+            .method(
+                js_string!("close"),
+                0,
+                NativeFunction::from_fn_ptr(Self::close),
+            );
+        Ok(())
+    }
+
+    fn data_constructor(
+        _new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<Self> {
+        let url = args
+            .get_or_undefined(0)
+            .to_string(context)?
+            .to_std_string_lossy();
+        let url = parse_url(&url)?;
+        let host = url
+            .host_str()
+            .unwrap_or_default()
+            .trim_start_matches('[')
+            .trim_end_matches(']');
+        Permissions::of(context).check_net(host, url.port_or_known_default())?;
+        let url = String::from(url);
+
+        let protocols = args.get_or_undefined(1);
+        let protocols = if protocols.is_undefined() {
+            Vec::new()
+        } else if let Some(list) = protocols.as_object() {
+            let length = list
+                .get(js_string!("length"), context)?
+                .to_length(context)?;
+            let mut protocols = Vec::new();
+            for index in 0..length {
+                let protocol = list.get(index, context)?.to_string(context)?;
+                protocols.push(protocol.to_std_string_lossy());
+            }
+            protocols
+        } else {
+            vec![protocols.to_string(context)?.to_std_string_lossy()]
+        };
+
+        let transport = context
+            .get_data::<Transport>()
+            .map(|transport| transport.0.clone())
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message("the `WebSocket` class has no transport")
+            })?;
+        let (sender, events) = EventReceiver::channel();
+        let connection = transport.connect(&url, &protocols, sender).map_err(|err| {
+            JsNativeError::error().with_message(format!("could not connect to `{url}`: {err}"))
+        })?;
+
+        Ok(Self {
+            url,
+            protocol: String::new(),
+            ready_state: CONNECTING,
+            connection: Some(connection),
+            events: Some(events),
+        })
+    }
+
+    fn object_constructor(
+        instance: &JsObject,
+        _args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<()> {
+        for handler in ["onopen", "onmessage", "onerror", "onclose"] {
+            instance.create_data_property_or_throw(
+                JsString::from(handler),
+                JsValue::null(),
+                context,
+            )?;
+        }
+
+        let events = instance
+            .downcast_mut::<Self>()
+            .and_then(|mut socket| socket.events.take());
+        if let Some(events) = events {
+            listen(instance.clone(), events, context);
+        }
+        Ok(())
+    }
+}
+
+/// Parses a `ws:` or `wss:` URL.
+///
+/// The permissions are checked against the host of the parsed URL, and the transport receives
+/// its serialization, so both see the same host.
+fn parse_url(url: &str) -> JsResult<url::Url> {
+    let syntax_error = |message: &str| -> JsError {
+        JsNativeError::syntax()
+            .with_message(format!("invalid `WebSocket` URL `{url}`: {message}"))
+            .into()
+    };
+
+    let parsed = url::Url::parse(url).map_err(|err| syntax_error(&err.to_string()))?;
+    if !matches!(parsed.scheme(), "ws" | "wss") {
+        return Err(syntax_error("the scheme must be `ws:` or `wss:`"));
+    }
+    if parsed.fragment().is_some() {
+        return Err(syntax_error("the URL must not have a fragment"));
+    }
+    if parsed.host_str().is_none_or(str::is_empty) {
+        return Err(syntax_error("the host is empty"));
+    }
+
+    Ok(parsed)
+}
+
+/// Converts the data passed to `send` to a message.
+fn to_message(data: &JsValue, context: &mut Context) -> JsResult<Message> {
+    if let Some(object) = data.as_object() {
+        if let Ok(buffer) = JsArrayBuffer::from_object(object.clone()) {
+            let bytes = buffer
+                .data()
+                .map(|bytes| bytes.to_vec())
+                .unwrap_or_default();
+            return Ok(Message::Binary(bytes));
+        }
+        if let Ok(array) = JsUint8Array::from_object(object.clone()) {
+            return Ok(Message::Binary(array.iter(context).collect()));
+        }
+    }
+
+    Ok(Message::Text(
+        data.to_string(context)?.to_std_string_lossy(),
+    ))
+}
+
+/// Dispatches the next event of `events` to `socket` in a job, and keeps listening until the
+/// connection is closed.
+fn listen(socket: JsObject, events: EventReceiver, context: &mut Context) {
+    context.enqueue_job(
+        NativeAsyncJob::new(move |context| {
+            Box::pin(async move {
+                let event = events.recv().await;
+
+                let context = &mut context.borrow_mut();
+                let closed = matches!(event, WebSocketEvent::Close { .. });
+                if !closed {
+                    listen(socket.clone(), events, context);
+                }
+                dispatch(&socket, event, context)
+            })
+        })
+        .into(),
+    );
+}
+
+/// Updates the state of `socket` for `event`, and calls the handler of the event.
+fn dispatch(socket: &JsObject, event: WebSocketEvent, context: &mut Context) -> JsResult<JsValue> {
+    let mut init = ObjectInitializer::new(context);
+    init.property(js_string!("target"), socket.clone(), Attribute::all());
+
+    let kind = {
+        let Some(mut state) = socket.downcast_mut::<WebSocket>() else {
+            return Ok(JsValue::undefined());
+        };
+        match event {
+            WebSocketEvent::Open { protocol } => {
+                // The socket was closed by the script before the connection was established.
+                if state.ready_state != CONNECTING {
+                    return Ok(JsValue::undefined());
+                }
+                state.ready_state = OPEN;
+                state.protocol = protocol;
+                "open"
+            }
+            WebSocketEvent::Message(message) => {
+                let data: JsValue = match message {
+                    Message::Text(text) => JsString::from(text.as_str()).into(),
+                    Message::Binary(bytes) => {
+                        JsArrayBuffer::from_byte_block(bytes, init.context())?.into()
+                    }
+                };
+                init.property(js_string!("data"), data, Attribute::all());
+                "message"
+            }
+            WebSocketEvent::Error(message) => {
+                init.property(
+                    js_string!("message"),
+                    JsString::from(message.as_str()),
+                    Attribute::all(),
+                );
+                "error"
+            }
+            WebSocketEvent::Close {
+                code,
+                reason,
+                was_clean,
+            } => {
+                state.ready_state = CLOSED;
+                state.connection = None;
+                init.property(js_string!("code"), code, Attribute::all())
+                    .property(
+                        js_string!("reason"),
+                        JsString::from(reason.as_str()),
+                        Attribute::all(),
+                    )
+                    .property(js_string!("wasClean"), was_clean, Attribute::all());
+                "close"
+            }
+        }
+    };
+    init.property(js_string!("type"), JsString::from(kind), Attribute::all());
+    let event = init.build();
+
+    let handler = socket.get(JsString::from(format!("on{kind}").as_str()), context)?;
+    if let Some(handler) = handler.as_callable() {
+        handler.call(&socket.clone().into(), &[event.into()], context)?;
+    }
+    Ok(JsValue::undefined())
+}
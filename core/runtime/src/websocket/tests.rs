@@ -0,0 +1,228 @@
+use std::{cell::RefCell, io, rc::Rc};
+
+use boa_engine::{js_string, Context, JsString, JsValue, Source};
+
+use super::{
+    EventSender, Message, WebSocket, WebSocketConnection, WebSocketEvent, WebSocketTransport,
+};
+use crate::permissions::{Allow, Permissions};
+
+/// A transport whose connections echo the messages they receive.
+#[derive(Debug, Default, Clone)]
+struct EchoTransport {
+    urls: Rc<RefCell<Vec<String>>>,
+    sent: Rc<RefCell<Vec<Message>>>,
+}
+
+/// A connection of an [`EchoTransport`].
+struct EchoConnection {
+    events: EventSender,
+    sent: Rc<RefCell<Vec<Message>>>,
+}
+
+impl WebSocketTransport for EchoTransport {
+    fn connect(
+        &self,
+        url: &str,
+        protocols: &[String],
+        events: EventSender,
+    ) -> io::Result<Box<dyn WebSocketConnection>> {
+        self.urls.borrow_mut().push(url.to_owned());
+        if url.contains("unreachable") {
+            events.send(WebSocketEvent::Error("the host is unreachable".to_owned()));
+            events.send(WebSocketEvent::Close {
+                code: 1006,
+                reason: String::new(),
+                was_clean: false,
+            });
+        } else {
+            events.send(WebSocketEvent::Open {
+                protocol: protocols.first().cloned().unwrap_or_default(),
+            });
+        }
+
+        Ok(Box::new(EchoConnection {
+            events,
+            sent: self.sent.clone(),
+        }))
+    }
+}
+
+impl WebSocketConnection for EchoConnection {
+    fn send(&mut self, message: Message) -> io::Result<()> {
+        self.sent.borrow_mut().push(message.clone());
+        self.events.send(WebSocketEvent::Message(message));
+        Ok(())
+    }
+
+    fn close(&mut self, code: Option<u16>, reason: &str) -> io::Result<()> {
+        self.events.send(WebSocketEvent::Close {
+            code: code.unwrap_or(1005),
+            reason: reason.to_owned(),
+            was_clean: true,
+        });
+        Ok(())
+    }
+}
+
+/// Creates a context with the `WebSocket` class, which can only connect to `example.com`.
+fn context(transport: EchoTransport) -> Context {
+    let mut context = Context::default();
+    Permissions::new()
+        .with_net(Allow::only(["example.com"]))
+        .install(&mut context);
+    WebSocket::register(&mut context, transport)
+        .expect("the `WebSocket` class should be registered");
+    context
+}
+
+#[track_caller]
+fn eval(context: &mut Context, source: &str) -> JsValue {
+    let value = context
+        .eval(Source::from_bytes(source))
+        .expect("the script should not throw");
+    context.run_jobs().expect("the jobs should not throw");
+    value
+}
+
+#[test]
+fn events_are_dispatched_to_handlers() {
+    let transport = EchoTransport::default();
+    let context = &mut context(transport.clone());
+
+    eval(
+        context,
+        r#"
+        const log = [];
+        const socket = new WebSocket("wss://example.com/chat", ["chat", "superchat"]);
+        log.push(`state ${socket.readyState}`);
+        socket.onopen = () => {
+            log.push(`open ${socket.protocol} ${socket.readyState === WebSocket.OPEN}`);
+            socket.send("hello");
+            socket.send(new Uint8Array([1, 2, 3]));
+        };
+        socket.onmessage = event => {
+            if (typeof event.data === "string") {
+                log.push(`message ${event.data}`);
+            } else {
+                log.push(`binary ${event.data.byteLength}`);
+                socket.close(1000, "bye");
+            }
+        };
+        socket.onclose = event => {
+            log.push(`close ${event.code} ${event.reason} ${event.wasClean} ${socket.readyState}`);
+        };
+        "#,
+    );
+
+    assert_eq!(
+        eval(context, "log.join()"),
+        js_string!("state 0,open chat true,message hello,binary 3,close 1000 bye true 3").into()
+    );
+    assert_eq!(
+        *transport.sent.borrow(),
+        [
+            Message::Text("hello".to_owned()),
+            Message::Binary(vec![1, 2, 3])
+        ]
+    );
+}
+
+#[test]
+fn failed_connections() {
+    let context = &mut context(EchoTransport::default());
+
+    eval(
+        context,
+        r#"
+        const log = [];
+        const socket = new WebSocket("ws://example.com:8080/unreachable");
+        socket.onerror = event => log.push(`error ${event.message}`);
+        socket.onclose = event => log.push(`close ${event.code} ${event.wasClean}`);
+        "#,
+    );
+
+    assert_eq!(
+        eval(context, "log.join()"),
+        js_string!("error the host is unreachable,close 1006 false").into()
+    );
+}
+
+#[test]
+fn invalid_sockets_throw() {
+    let context = &mut context(EchoTransport::default());
+
+    let value = eval(
+        context,
+        r#"
+        function error(f) {
+            try {
+                f();
+            } catch (e) {
+                return e.constructor.name;
+            }
+        }
+        const socket = new WebSocket("ws://example.com");
+        const errors = [
+            error(() => new WebSocket("https://example.com")),
+            error(() => new WebSocket("ws://example.com/#fragment")),
+            error(() => new WebSocket("ws://example.org")),
+            error(() => socket.send("too early")),
+            error(() => socket.close(1001)),
+        ];
+        socket.close();
+        errors.join()
+        "#,
+    );
+    assert_eq!(
+        value,
+        js_string!("SyntaxError,SyntaxError,TypeError,Error,RangeError").into()
+    );
+}
+
+#[test]
+fn permissions_are_checked_against_the_parsed_host() {
+    let transport = EchoTransport::default();
+    let context = &mut context(transport.clone());
+
+    let value = eval(
+        context,
+        r#"
+        function error(f) {
+            try {
+                f();
+            } catch (e) {
+                return e.constructor.name;
+            }
+        }
+        const errors = [
+            error(() => new WebSocket("ws://example.org\\@example.com")),
+            error(() => new WebSocket("ws://example.org\\.example.com")),
+            error(() => new WebSocket("ws://example.com@example.org")),
+            error(() => new WebSocket("ws://example.com:pass@example.org/")),
+        ];
+        const sockets = [
+            new WebSocket("WS://user:pass@EXAMPLE.com:80/a b"),
+            new WebSocket("wss://example.com\\chat?x"),
+        ];
+        [errors.join(), ...sockets.map(socket => socket.url)].join()
+        "#,
+    );
+    assert_eq!(
+        value
+            .as_string()
+            .map(JsString::to_std_string_escaped)
+            .as_deref(),
+        Some(
+            "TypeError,TypeError,TypeError,TypeError,\
+             ws://user:pass@example.com/a%20b,wss://example.com/chat?x"
+        )
+    );
+    assert_eq!(
+        *transport.urls.borrow(),
+        [
+            "ws://user:pass@example.com/a%20b".to_owned(),
+            "wss://example.com/chat?x".to_owned()
+        ]
+    );
+}
@@ -374,7 +374,7 @@ impl<D: Copy> JsStringBuilder<D> {
         // meaning we can write to its pointed memory.
         unsafe {
             inner.as_ptr().write(RawJsString {
-                tagged_len: TaggedLen::new(len, latin1),
+                tagged_len: Cell::new(TaggedLen::new(len, latin1)),
                 refcount: RefCount {
                     read_write: ManuallyDrop::new(Cell::new(1)),
                 },
@@ -0,0 +1,173 @@
+//! Strict and [WTF-8][wtf8] conversions between [`JsStr`]ings and byte strings.
+//!
+//! [wtf8]: https://simonsapin.github.io/wtf-8/
+
+use std::fmt;
+
+use crate::{CodePoint, JsStr, JsStrVariant};
+
+/// The error returned by [`JsStr::to_std_string_strict`] when a string contains an unpaired
+/// surrogate, which cannot be represented in UTF-8.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnpairedSurrogateError {
+    index: usize,
+    surrogate: u16,
+}
+
+impl UnpairedSurrogateError {
+    /// Gets the index of the first unpaired surrogate, in UTF-16 code units.
+    #[inline]
+    #[must_use]
+    pub const fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Gets the code unit of the first unpaired surrogate.
+    #[inline]
+    #[must_use]
+    pub const fn surrogate(&self) -> u16 {
+        self.surrogate
+    }
+}
+
+impl fmt::Display for UnpairedSurrogateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "unpaired surrogate {:#06X} found at index {}",
+            self.surrogate, self.index
+        )
+    }
+}
+
+impl std::error::Error for UnpairedSurrogateError {}
+
+/// The error returned by [`JsString::from_wtf8`](crate::JsString::from_wtf8) when the bytes are
+/// not valid WTF-8.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidWtf8Error {
+    index: usize,
+}
+
+impl InvalidWtf8Error {
+    /// Gets the index of the first invalid byte sequence, in bytes.
+    #[inline]
+    #[must_use]
+    pub const fn index(&self) -> usize {
+        self.index
+    }
+}
+
+impl fmt::Display for InvalidWtf8Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid WTF-8 sequence found at index {}", self.index)
+    }
+}
+
+impl std::error::Error for InvalidWtf8Error {}
+
+/// Decodes `string` into a [`String`], failing on the first unpaired surrogate.
+pub(crate) fn to_utf8_strict(string: JsStr<'_>) -> Result<String, UnpairedSurrogateError> {
+    let units = match string.variant() {
+        JsStrVariant::Latin1(v) => return Ok(v.iter().copied().map(char::from).collect()),
+        JsStrVariant::Utf16(v) => v,
+    };
+
+    let mut result = String::with_capacity(units.len());
+    let mut index = 0;
+    for c in char::decode_utf16(units.iter().copied()) {
+        match c {
+            Ok(c) => {
+                result.push(c);
+                index += c.len_utf16();
+            }
+            Err(e) => {
+                return Err(UnpairedSurrogateError {
+                    index,
+                    surrogate: e.unpaired_surrogate(),
+                })
+            }
+        }
+    }
+    Ok(result)
+}
+
+/// Encodes `string` into WTF-8, which is UTF-8 extended to encode unpaired surrogates as if
+/// they were code points.
+#[allow(clippy::cast_possible_truncation)]
+pub(crate) fn to_wtf8(string: JsStr<'_>) -> Vec<u8> {
+    if let JsStrVariant::Latin1(v) = string.variant() {
+        if v.is_ascii() {
+            return v.to_vec();
+        }
+    }
+
+    let mut bytes = Vec::with_capacity(string.len());
+    for code_point in string.code_points() {
+        match code_point {
+            CodePoint::Unicode(c) => {
+                bytes.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes());
+            }
+            CodePoint::UnpairedSurrogate(surrogate) => bytes.extend_from_slice(&[
+                0xE0 | (surrogate >> 12) as u8,
+                0x80 | ((surrogate >> 6) & 0x3F) as u8,
+                0x80 | (surrogate & 0x3F) as u8,
+            ]),
+        }
+    }
+    bytes
+}
+
+/// Decodes WTF-8 `bytes` into UTF-16 code units.
+///
+/// Surrogate pairs must be encoded as supplementary code points, like in UTF-8, so that each
+/// string has a single WTF-8 representation.
+#[allow(clippy::cast_possible_truncation)]
+pub(crate) fn from_wtf8(bytes: &[u8]) -> Result<Vec<u16>, InvalidWtf8Error> {
+    let mut units = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+    // Whether the last decoded code point is a lead surrogate.
+    let mut after_lead_surrogate = false;
+
+    while index < bytes.len() {
+        let error = InvalidWtf8Error { index };
+        let first = bytes[index];
+        let (len, min, initial) = match first {
+            0x00..=0x7F => (1, 0, u32::from(first)),
+            0xC2..=0xDF => (2, 0x80, u32::from(first & 0x1F)),
+            0xE0..=0xEF => (3, 0x800, u32::from(first & 0x0F)),
+            0xF0..=0xF4 => (4, 0x1_0000, u32::from(first & 0x07)),
+            _ => return Err(error),
+        };
+        let continuation = bytes.get(index + 1..index + len).ok_or(error)?;
+
+        let mut code_point = initial;
+        for &byte in continuation {
+            if byte & 0xC0 != 0x80 {
+                return Err(error);
+            }
+            code_point = (code_point << 6) | u32::from(byte & 0x3F);
+        }
+        if code_point < min || code_point > 0x10_FFFF {
+            return Err(error);
+        }
+
+        let is_lead_surrogate = (0xD800..=0xDBFF).contains(&code_point);
+        let is_trail_surrogate = (0xDC00..=0xDFFF).contains(&code_point);
+        if is_trail_surrogate && after_lead_surrogate {
+            return Err(error);
+        }
+        after_lead_surrogate = is_lead_surrogate;
+
+        if let Ok(unit) = u16::try_from(code_point) {
+            units.push(unit);
+        } else {
+            let code_point = code_point - 0x1_0000;
+            units.push(0xD800 | (code_point >> 10) as u16);
+            units.push(0xDC00 | (code_point & 0x3FF) as u16);
+        }
+        index += len;
+    }
+
+    Ok(units)
+}
@@ -15,7 +15,9 @@
 mod builder;
 mod common;
 mod display;
+mod encoding;
 mod iter;
+mod normalization;
 mod str;
 
 #[cfg(test)]
@@ -27,7 +29,9 @@ use crate::display::{JsStrDisplayEscaped, JsStrDisplayLossy};
 pub use crate::{
     builder::{CommonJsStringBuilder, Latin1JsStringBuilder, Utf16JsStringBuilder},
     common::StaticJsStrings,
+    encoding::{InvalidWtf8Error, UnpairedSurrogateError},
     iter::Iter,
+    normalization::{NormalizationForm, Normalizers},
     str::{JsStr, JsStrVariant},
 };
 use std::borrow::Cow;
@@ -161,20 +165,23 @@ impl std::fmt::Display for CodePoint {
     }
 }
 
-/// A `usize` contains a flag and the length of Latin1/UTF-16 .
+/// A `usize` contains the flags and the length of Latin1/UTF-16 .
 /// ```text
-/// ┌────────────────────────────────────┐
-/// │ length (usize::BITS - 1) │ flag(1) │
-/// └────────────────────────────────────┘
+/// ┌─────────────────────────────────────┐
+/// │ length (usize::BITS - 3) │ flags(3) │
+/// └─────────────────────────────────────┘
 /// ```
-/// The latin1/UTF-16 flag is stored in the bottom bit.
+/// The latin1/UTF-16 flag is stored in the bottom bit, followed by the cached well-formedness of
+/// the string, which is only cached on heap allocated strings.
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
 #[repr(transparent)]
 struct TaggedLen(usize);
 
 impl TaggedLen {
     const LATIN1_BITFLAG: usize = 1 << 0;
-    const BITFLAG_COUNT: usize = 1;
+    const WELL_FORMED_CHECKED_BITFLAG: usize = 1 << 1;
+    const WELL_FORMED_BITFLAG: usize = 1 << 2;
+    const BITFLAG_COUNT: usize = 3;
 
     const fn new(len: usize, latin1: bool) -> Self {
         Self((len << Self::BITFLAG_COUNT) | (latin1 as usize))
@@ -187,6 +194,24 @@ impl TaggedLen {
     const fn len(self) -> usize {
         self.0 >> Self::BITFLAG_COUNT
     }
+
+    /// Gets the cached well-formedness of the string, if it was checked.
+    const fn well_formed(self) -> Option<bool> {
+        if self.0 & Self::WELL_FORMED_CHECKED_BITFLAG == 0 {
+            None
+        } else {
+            Some(self.0 & Self::WELL_FORMED_BITFLAG != 0)
+        }
+    }
+
+    const fn with_well_formed(self, well_formed: bool) -> Self {
+        let flag = if well_formed {
+            Self::WELL_FORMED_BITFLAG
+        } else {
+            0
+        };
+        Self((self.0 & !Self::WELL_FORMED_BITFLAG) | Self::WELL_FORMED_CHECKED_BITFLAG | flag)
+    }
 }
 
 /// The raw representation of a [`JsString`] from a string literal.
@@ -233,18 +258,18 @@ union RefCount {
 /// The raw representation of a [`JsString`] in the heap.
 #[repr(C)]
 struct RawJsString {
-    tagged_len: TaggedLen,
+    tagged_len: Cell<TaggedLen>,
     refcount: RefCount,
     data: [u8; 0],
 }
 
 impl RawJsString {
-    const fn is_latin1(&self) -> bool {
-        self.tagged_len.is_latin1()
+    fn is_latin1(&self) -> bool {
+        self.tagged_len.get().is_latin1()
     }
 
-    const fn len(&self) -> usize {
-        self.tagged_len.len()
+    fn len(&self) -> usize {
+        self.tagged_len.get().len()
     }
 }
 
@@ -316,6 +341,111 @@ impl JsString {
         self.display_lossy().to_string()
     }
 
+    /// Decodes a [`JsString`] into a [`str`], replacing unpaired surrogates with the
+    /// replacement character U+FFFD.
+    ///
+    /// This borrows the data of the string if it only contains ASCII characters.
+    #[inline]
+    #[must_use]
+    pub fn to_string_lossy(&self) -> Cow<'_, str> {
+        self.as_str().to_string_lossy()
+    }
+
+    /// Decodes a [`JsString`] into a [`String`], returning an error with the position of the
+    /// first unpaired surrogate if it contains any.
+    ///
+    /// # Errors
+    ///
+    /// [`UnpairedSurrogateError`] if the string contains an unpaired surrogate.
+    #[inline]
+    pub fn to_std_string_strict(&self) -> Result<String, UnpairedSurrogateError> {
+        self.as_str().to_std_string_strict()
+    }
+
+    /// Encodes a [`JsString`] into [WTF-8][wtf8], which preserves unpaired surrogates.
+    ///
+    /// [wtf8]: https://simonsapin.github.io/wtf-8/
+    #[inline]
+    #[must_use]
+    pub fn to_wtf8(&self) -> Vec<u8> {
+        self.as_str().to_wtf8()
+    }
+
+    /// Decodes a [`JsString`] from [WTF-8][wtf8] bytes, such as the ones returned by
+    /// [`JsString::to_wtf8`].
+    ///
+    /// Since WTF-8 is a superset of UTF-8, this also accepts any UTF-8 string.
+    ///
+    /// # Errors
+    ///
+    /// [`InvalidWtf8Error`] if the bytes are not valid WTF-8, which includes a surrogate pair
+    /// encoded as two separate surrogates.
+    ///
+    /// [wtf8]: https://simonsapin.github.io/wtf-8/
+    pub fn from_wtf8(bytes: &[u8]) -> Result<Self, InvalidWtf8Error> {
+        if let Ok(s) = std::str::from_utf8(bytes) {
+            return Ok(Self::from(s));
+        }
+        encoding::from_wtf8(bytes).map(|units| Self::from(&units[..]))
+    }
+
+    /// Abstract operation [`IsStringWellFormedUnicode ( string )`][spec], checking if the string
+    /// doesn't contain unpaired surrogates.
+    ///
+    /// The result is cached on heap allocated strings, so checking the same string again doesn't
+    /// rescan it.
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-isstringwellformedunicode
+    #[inline]
+    #[must_use]
+    pub fn is_well_formed(&self) -> bool {
+        let inner = match self.ptr.unwrap() {
+            UnwrappedTagged::Ptr(inner) if self.refcount().is_some() => inner,
+            // Static strings live in read-only memory, so they cannot cache the result.
+            _ => return self.as_str().is_well_formed(),
+        };
+        // SAFETY: `NonNull` and the constructions of `JsString` guarantee that `inner` is always
+        // valid, and we checked above that it is a heap allocated string.
+        let inner = unsafe { inner.as_ref() };
+        let tagged_len = inner.tagged_len.get();
+        if let Some(well_formed) = tagged_len.well_formed() {
+            return well_formed;
+        }
+
+        let well_formed = self.as_str().is_well_formed();
+        inner
+            .tagged_len
+            .set(tagged_len.with_well_formed(well_formed));
+        well_formed
+    }
+
+    /// Returns a copy of the string with its unpaired surrogates replaced by the replacement
+    /// character U+FFFD, as in `String.prototype.toWellFormed`.
+    ///
+    /// This doesn't allocate if the string is already well-formed.
+    #[inline]
+    #[must_use]
+    pub fn to_well_formed(&self) -> Self {
+        if self.is_well_formed() {
+            return self.clone();
+        }
+        let units = self
+            .code_points()
+            .flat_map(|code_point| {
+                let mut buf = [0; 2];
+                let len = match code_point {
+                    CodePoint::Unicode(c) => c.encode_utf16(&mut buf).len(),
+                    CodePoint::UnpairedSurrogate(_) => {
+                        buf[0] = 0xFFFD;
+                        1
+                    }
+                };
+                buf.into_iter().take(len)
+            })
+            .collect::<Vec<_>>();
+        Self::from(&units[..])
+    }
+
     /// Decodes a [`JsString`] into a [`String`], returning an error if the string contains unpaired
     /// surrogates.
     ///
@@ -550,9 +680,12 @@ impl JsString {
                 //
                 // - `Cell<usize>` is readable as an usize as long as we don't try to mutate the pointed variable,
                 //   which means it is safe to read the `refcount` as `read_only` here.
+                //
+                // - `Cell<TaggedLen>` has the same memory layout as `TaggedLen`, and reading it
+                //   through a raw pointer avoids creating a reference to read-only static memory.
                 unsafe {
                     let h = h.as_ptr();
-                    let tagged_len = (*h).tagged_len;
+                    let tagged_len = (&raw const (*h).tagged_len).cast::<TaggedLen>().read();
                     let len = tagged_len.len();
                     let is_latin1 = tagged_len.is_latin1();
                     let ptr = if (*h).refcount.read_only == 0 {
@@ -706,7 +839,7 @@ impl JsString {
         unsafe {
             // Write the first part, the `RawJsString`.
             inner.as_ptr().write(RawJsString {
-                tagged_len: TaggedLen::new(str_len, latin1),
+                tagged_len: Cell::new(TaggedLen::new(str_len, latin1)),
                 refcount: RefCount {
                     read_write: ManuallyDrop::new(Cell::new(1)),
                 },
@@ -910,27 +1043,52 @@ impl std::fmt::Debug for JsString {
 
 impl Eq for JsString {}
 
-macro_rules! impl_from_number_for_js_string {
-    ($($module: ident => $($ty:ty),+)+) => {
+thread_local! {
+    /// The strings of the integers from `0` to `255`, which are converted to strings much more
+    /// often than other numbers, e.g. when they are used as array indices.
+    static SMALL_INTEGER_STRINGS: [JsString; 256] = std::array::from_fn(|i| {
+        JsString::from_slice_skip_interning(JsStr::latin1(itoa::Buffer::new().format(i).as_bytes()))
+    });
+}
+
+macro_rules! impl_from_integer_for_js_string {
+    ($($ty:ty),+) => {
         $(
-            $(
-                impl From<$ty> for JsString {
-                    #[inline]
-                    fn from(value: $ty) -> Self {
-                        JsString::from_slice_skip_interning(JsStr::latin1(
-                            $module::Buffer::new().format(value).as_bytes(),
-                        ))
+            impl From<$ty> for JsString {
+                #[inline]
+                #[allow(irrefutable_let_patterns, clippy::unnecessary_fallible_conversions)]
+                fn from(value: $ty) -> Self {
+                    if let Ok(index) = u8::try_from(value) {
+                        return SMALL_INTEGER_STRINGS
+                            .with(|strings| strings[usize::from(index)].clone());
                     }
+                    JsString::from_slice_skip_interning(JsStr::latin1(
+                        itoa::Buffer::new().format(value).as_bytes(),
+                    ))
                 }
-            )+
+            }
+        )+
+    };
+}
+
+impl_from_integer_for_js_string!(i8, i16, i32, i64, i128, u8, u16, u32, u64, u128, isize, usize);
+
+macro_rules! impl_from_float_for_js_string {
+    ($($ty:ty),+) => {
+        $(
+            impl From<$ty> for JsString {
+                #[inline]
+                fn from(value: $ty) -> Self {
+                    JsString::from_slice_skip_interning(JsStr::latin1(
+                        ryu_js::Buffer::new().format(value).as_bytes(),
+                    ))
+                }
+            }
         )+
     };
 }
 
-impl_from_number_for_js_string!(
-    itoa => i8, i16, i32, i64, i128, u8, u16, u32, u64, u128, isize, usize
-    ryu_js => f32, f64
-);
+impl_from_float_for_js_string!(f32, f64);
 
 impl From<&[u16]> for JsString {
     #[inline]
@@ -0,0 +1,164 @@
+//! Unicode normalization of [`JsString`]s.
+
+use std::fmt;
+
+use icu_normalizer::{
+    ComposingNormalizer, ComposingNormalizerBorrowed, DecomposingNormalizer,
+    DecomposingNormalizerBorrowed,
+};
+
+use crate::{JsStr, JsStrVariant, JsString};
+
+/// The normalization forms of the [Unicode® Standard Annex #15][uax15].
+///
+/// [uax15]: https://unicode.org/reports/tr15/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NormalizationForm {
+    /// Canonical decomposition, followed by canonical composition.
+    Nfc,
+    /// Canonical decomposition.
+    Nfd,
+    /// Compatibility decomposition, followed by canonical composition.
+    Nfkc,
+    /// Compatibility decomposition.
+    Nfkd,
+}
+
+impl NormalizationForm {
+    /// Gets the normalization form named `name`, which must be one of `"NFC"`, `"NFD"`, `"NFKC"`
+    /// or `"NFKD"`.
+    #[must_use]
+    pub fn from_name(name: JsStr<'_>) -> Option<Self> {
+        [
+            (JsStr::latin1(b"NFC"), Self::Nfc),
+            (JsStr::latin1(b"NFD"), Self::Nfd),
+            (JsStr::latin1(b"NFKC"), Self::Nfkc),
+            (JsStr::latin1(b"NFKD"), Self::Nfkd),
+        ]
+        .into_iter()
+        .find_map(|(form_name, form)| (name == form_name).then_some(form))
+    }
+}
+
+/// The normalizers used to normalize strings into each [`NormalizationForm`].
+///
+/// [`Normalizers::new`] uses the Unicode data compiled into the binary, while hosts that load
+/// their Unicode data at runtime can build the normalizers from their own data provider.
+pub struct Normalizers<'a> {
+    /// The normalizer for [`NormalizationForm::Nfc`].
+    pub nfc: ComposingNormalizerBorrowed<'a>,
+    /// The normalizer for [`NormalizationForm::Nfd`].
+    pub nfd: DecomposingNormalizerBorrowed<'a>,
+    /// The normalizer for [`NormalizationForm::Nfkc`].
+    pub nfkc: ComposingNormalizerBorrowed<'a>,
+    /// The normalizer for [`NormalizationForm::Nfkd`].
+    pub nfkd: DecomposingNormalizerBorrowed<'a>,
+}
+
+impl Normalizers<'static> {
+    /// Creates the normalizers of the Unicode data compiled into the binary.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            nfc: ComposingNormalizer::new_nfc(),
+            nfd: DecomposingNormalizer::new_nfd(),
+            nfkc: ComposingNormalizer::new_nfkc(),
+            nfkd: DecomposingNormalizer::new_nfkd(),
+        }
+    }
+}
+
+impl Default for Normalizers<'static> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for Normalizers<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Normalizers").finish_non_exhaustive()
+    }
+}
+
+impl Normalizers<'_> {
+    /// Checks if the UTF-16 `text` is already in the normalization `form`.
+    fn is_normalized_utf16(&self, form: NormalizationForm, text: &[u16]) -> bool {
+        match form {
+            NormalizationForm::Nfc => self.nfc.is_normalized_utf16(text),
+            NormalizationForm::Nfd => self.nfd.is_normalized_utf16(text),
+            NormalizationForm::Nfkc => self.nfkc.is_normalized_utf16(text),
+            NormalizationForm::Nfkd => self.nfkd.is_normalized_utf16(text),
+        }
+    }
+
+    /// Normalizes the UTF-16 `text` into the normalization `form`.
+    fn normalize_utf16(&self, form: NormalizationForm, text: &[u16]) -> JsString {
+        let result = match form {
+            NormalizationForm::Nfc => self.nfc.normalize_utf16(text),
+            NormalizationForm::Nfd => self.nfd.normalize_utf16(text),
+            NormalizationForm::Nfkc => self.nfkc.normalize_utf16(text),
+            NormalizationForm::Nfkd => self.nfkd.normalize_utf16(text),
+        };
+        JsString::from(&result[..])
+    }
+}
+
+/// Checks if the Latin1 `text` is trivially in the normalization `form`.
+///
+/// ASCII text is unchanged by all the normalization forms, and Latin1 text is unchanged by NFC,
+/// since it has no combining characters nor characters with a canonical decomposition that
+/// doesn't compose back.
+fn is_trivially_normalized_latin1(form: NormalizationForm, text: &[u8]) -> bool {
+    form == NormalizationForm::Nfc || text.is_ascii()
+}
+
+impl JsString {
+    /// Checks if the string is in the normalization `form`, using the Unicode data compiled
+    /// into the binary.
+    #[inline]
+    #[must_use]
+    pub fn is_normalized(&self, form: NormalizationForm) -> bool {
+        self.is_normalized_with(form, &Normalizers::new())
+    }
+
+    /// Checks if the string is in the normalization `form`, using `normalizers`.
+    #[must_use]
+    pub fn is_normalized_with(
+        &self,
+        form: NormalizationForm,
+        normalizers: &Normalizers<'_>,
+    ) -> bool {
+        match self.as_str().variant() {
+            JsStrVariant::Latin1(text) if is_trivially_normalized_latin1(form, text) => true,
+            JsStrVariant::Latin1(_) => normalizers.is_normalized_utf16(form, &self.to_vec()),
+            JsStrVariant::Utf16(text) => normalizers.is_normalized_utf16(form, text),
+        }
+    }
+
+    /// Normalizes the string into the normalization `form`, using the Unicode data compiled into
+    /// the binary.
+    ///
+    /// See [`JsString::normalize_with`].
+    #[inline]
+    #[must_use]
+    pub fn normalize(&self, form: NormalizationForm) -> Self {
+        self.normalize_with(form, &Normalizers::new())
+    }
+
+    /// Normalizes the string into the normalization `form`, using `normalizers`.
+    ///
+    /// Strings that are already normalized are returned as they are, without allocating.
+    #[must_use]
+    pub fn normalize_with(&self, form: NormalizationForm, normalizers: &Normalizers<'_>) -> Self {
+        match self.as_str().variant() {
+            JsStrVariant::Latin1(text) if is_trivially_normalized_latin1(form, text) => {
+                self.clone()
+            }
+            JsStrVariant::Latin1(_) => normalizers.normalize_utf16(form, &self.to_vec()),
+            JsStrVariant::Utf16(text) if normalizers.is_normalized_utf16(form, text) => {
+                self.clone()
+            }
+            JsStrVariant::Utf16(text) => normalizers.normalize_utf16(form, text),
+        }
+    }
+}
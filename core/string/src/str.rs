@@ -1,8 +1,10 @@
 use crate::{
     display::{JsStrDisplayEscaped, JsStrDisplayLossy},
+    encoding::{self, UnpairedSurrogateError},
     is_trimmable_whitespace, is_trimmable_whitespace_latin1, CodePoint, Iter,
 };
 use std::{
+    borrow::Cow,
     hash::{Hash, Hasher},
     slice::SliceIndex,
 };
@@ -360,56 +362,19 @@ impl<'a> JsStr<'a> {
     pub fn to_number(&self) -> f64 {
         // 1. Let text be ! StringToCodePoints(str).
         // 2. Let literal be ParseText(text, StringNumericLiteral).
-        let Ok(string) = self.to_std_string() else {
-            // 3. If literal is a List of errors, return NaN.
-            return f64::NAN;
-        };
-        // 4. Return StringNumericValue of literal.
-        let string = string.trim_matches(is_trimmable_whitespace);
-        match string {
-            "" => return 0.0,
-            "-Infinity" => return f64::NEG_INFINITY,
-            "Infinity" | "+Infinity" => return f64::INFINITY,
-            _ => {}
-        }
-
-        let mut s = string.bytes();
-        let base = match (s.next(), s.next()) {
-            (Some(b'0'), Some(b'b' | b'B')) => Some(2),
-            (Some(b'0'), Some(b'o' | b'O')) => Some(8),
-            (Some(b'0'), Some(b'x' | b'X')) => Some(16),
-            // Make sure that no further variants of "infinity" are parsed.
-            (Some(b'i' | b'I'), _) => {
-                return f64::NAN;
-            }
-            _ => None,
-        };
-
-        // Parse numbers that begin with `0b`, `0o` and `0x`.
-        if let Some(base) = base {
-            let string = &string[2..];
-            if string.is_empty() {
-                return f64::NAN;
-            }
-
-            // Fast path
-            if let Ok(value) = u32::from_str_radix(string, base) {
-                return f64::from(value);
+        match self.variant() {
+            // ASCII strings are valid UTF-8, so they can be parsed without copying them.
+            JsStrVariant::Latin1(bytes) if bytes.is_ascii() => {
+                string_to_number(std::str::from_utf8(bytes).expect("ASCII strings are valid UTF-8"))
             }
-
-            // Slow path
-            let mut value: f64 = 0.0;
-            for c in s {
-                if let Some(digit) = char::from(c).to_digit(base) {
-                    value = value.mul_add(f64::from(base), f64::from(digit));
-                } else {
+            _ => {
+                let Ok(string) = self.to_std_string() else {
+                    // 3. If literal is a List of errors, return NaN.
                     return f64::NAN;
-                }
+                };
+                string_to_number(&string)
             }
-            return value;
         }
-
-        fast_float2::parse(string).unwrap_or(f64::NAN)
     }
 
     /// Gets an iterator of all the Unicode codepoints of a [`JsStr`].
@@ -473,6 +438,33 @@ impl<'a> JsStr<'a> {
         })
     }
 
+    /// Abstract operation [`IsStringWellFormedUnicode ( string )`][spec], checking if the string
+    /// doesn't contain unpaired surrogates.
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-isstringwellformedunicode
+    #[inline]
+    #[must_use]
+    pub fn is_well_formed(&self) -> bool {
+        const fn is_surrogate(unit: u16) -> bool {
+            unit & 0xF800 == 0xD800
+        }
+
+        let JsStrVariant::Utf16(mut units) = self.variant() else {
+            return true;
+        };
+        // Skip the runs of non-surrogates in bulk, and only inspect the surrogates.
+        while let Some(i) = units.iter().position(|&unit| is_surrogate(unit)) {
+            let is_lead = units[i] <= 0xDBFF;
+            match units.get(i + 1) {
+                Some(&next) if is_lead && (0xDC00..=0xDFFF).contains(&next) => {
+                    units = &units[i + 2..];
+                }
+                _ => return false,
+            }
+        }
+        true
+    }
+
     /// Decodes a [`JsStr`] into a [`String`], returning an error if it contains any invalid data.
     ///
     /// # Errors
@@ -502,6 +494,45 @@ impl<'a> JsStr<'a> {
         self.display_lossy().to_string()
     }
 
+    /// Decodes a [`JsStr`] into a [`str`], replacing unpaired surrogates with the
+    /// replacement character U+FFFD.
+    ///
+    /// This borrows the data of the string if it only contains ASCII characters.
+    #[inline]
+    #[must_use]
+    pub fn to_string_lossy(&self) -> Cow<'a, str> {
+        if let JsStrVariant::Latin1(v) = self.variant() {
+            if v.is_ascii() {
+                // SAFETY: ASCII is valid UTF-8.
+                return Cow::Borrowed(unsafe { std::str::from_utf8_unchecked(v) });
+            }
+        }
+        Cow::Owned(self.to_std_string_lossy())
+    }
+
+    /// Decodes a [`JsStr`] into a [`String`], returning an error with the position of the first
+    /// unpaired surrogate if it contains any.
+    ///
+    /// # Errors
+    ///
+    /// [`UnpairedSurrogateError`] if the string contains an unpaired surrogate.
+    #[inline]
+    pub fn to_std_string_strict(&self) -> Result<String, UnpairedSurrogateError> {
+        encoding::to_utf8_strict(*self)
+    }
+
+    /// Encodes a [`JsStr`] into [WTF-8][wtf8], which preserves unpaired surrogates.
+    ///
+    /// The bytes are valid UTF-8 if the string doesn't contain unpaired surrogates, and can be
+    /// decoded back with [`JsString::from_wtf8`](crate::JsString::from_wtf8).
+    ///
+    /// [wtf8]: https://simonsapin.github.io/wtf-8/
+    #[inline]
+    #[must_use]
+    pub fn to_wtf8(&self) -> Vec<u8> {
+        encoding::to_wtf8(*self)
+    }
+
     /// Gets a displayable escaped string.
     ///
     /// This may be faster and has fewer
@@ -524,6 +555,79 @@ impl<'a> JsStr<'a> {
     }
 }
 
+/// Computes the `StringNumericValue` of `string`, or `NaN` if it is not a `StringNumericLiteral`.
+fn string_to_number(string: &str) -> f64 {
+    if let Some(value) = parse_small_integer(string) {
+        return value;
+    }
+
+    // 4. Return StringNumericValue of literal.
+    let string = string.trim_matches(is_trimmable_whitespace);
+    match string {
+        "" => return 0.0,
+        "-Infinity" => return f64::NEG_INFINITY,
+        "Infinity" | "+Infinity" => return f64::INFINITY,
+        _ => {}
+    }
+
+    let mut s = string.bytes();
+    let base = match (s.next(), s.next()) {
+        (Some(b'0'), Some(b'b' | b'B')) => Some(2),
+        (Some(b'0'), Some(b'o' | b'O')) => Some(8),
+        (Some(b'0'), Some(b'x' | b'X')) => Some(16),
+        // Make sure that no further variants of "infinity" are parsed.
+        (Some(b'i' | b'I'), _) => {
+            return f64::NAN;
+        }
+        _ => None,
+    };
+
+    // Parse numbers that begin with `0b`, `0o` and `0x`.
+    if let Some(base) = base {
+        let string = &string[2..];
+        if string.is_empty() {
+            return f64::NAN;
+        }
+
+        // Fast path
+        if let Ok(value) = u32::from_str_radix(string, base) {
+            return f64::from(value);
+        }
+
+        // Slow path
+        let mut value: f64 = 0.0;
+        for c in s {
+            if let Some(digit) = char::from(c).to_digit(base) {
+                value = value.mul_add(f64::from(base), f64::from(digit));
+            } else {
+                return f64::NAN;
+            }
+        }
+        return value;
+    }
+
+    fast_float2::parse(string).unwrap_or(f64::NAN)
+}
+
+/// Parses a string of at most 9 ASCII digits with an optional minus sign, which covers most of
+/// the numeric strings converted in practice and can't lose precision.
+fn parse_small_integer(string: &str) -> Option<f64> {
+    let (negative, digits) = match string.as_bytes() {
+        [b'-', digits @ ..] => (true, digits),
+        digits => (false, digits),
+    };
+    if digits.is_empty() || digits.len() > 9 || !digits.iter().all(u8::is_ascii_digit) {
+        return None;
+    }
+
+    let value = f64::from(
+        digits
+            .iter()
+            .fold(0u32, |value, digit| value * 10 + u32::from(digit - b'0')),
+    );
+    Some(if negative { -value } else { value })
+}
+
 impl Hash for JsStr<'_> {
     #[inline]
     fn hash<H: Hasher>(&self, state: &mut H) {
@@ -1,10 +1,13 @@
 #![allow(clippy::redundant_clone)]
 
-use std::hash::{BuildHasher, BuildHasherDefault, Hash};
+use std::{
+    borrow::Cow,
+    hash::{BuildHasher, BuildHasherDefault, Hash},
+};
 
 use crate::{
-    CommonJsStringBuilder, JsStr, JsString, Latin1JsStringBuilder, StaticJsString, StaticJsStrings,
-    Utf16JsStringBuilder,
+    CommonJsStringBuilder, JsStr, JsString, Latin1JsStringBuilder, NormalizationForm,
+    StaticJsString, StaticJsStrings, Utf16JsStringBuilder,
 };
 
 use rustc_hash::FxHasher;
@@ -471,3 +474,208 @@ fn common_js_string_builder() {
         "Déjà vu2024年5月21日🎹"
     );
 }
+
+#[test]
+fn normalize() {
+    let forms = [
+        NormalizationForm::Nfc,
+        NormalizationForm::Nfd,
+        NormalizationForm::Nfkc,
+        NormalizationForm::Nfkd,
+    ];
+
+    // Already normalized strings are returned without allocating a new string.
+    let ascii = JsString::from("Lorem ipsum");
+    for form in forms {
+        assert!(ascii.is_normalized(form));
+        let normalized = ascii.normalize(form);
+        assert_eq!(normalized, ascii);
+        assert_eq!(ascii.refcount(), Some(2));
+    }
+
+    let latin1 = JsString::from(JsStr::latin1(b"D\xE9j\xE0 vu \xBD"));
+    assert!(latin1.is_normalized(NormalizationForm::Nfc));
+    assert_eq!(latin1.normalize(NormalizationForm::Nfc), latin1);
+    assert_eq!(
+        latin1.normalize(NormalizationForm::Nfd),
+        "De\u{301}ja\u{300} vu \u{bd}"
+    );
+    assert_eq!(
+        latin1.normalize(NormalizationForm::Nfkc),
+        "D\u{e9}j\u{e0} vu 1\u{2044}2"
+    );
+    assert_eq!(
+        latin1.normalize(NormalizationForm::Nfkd),
+        "De\u{301}ja\u{300} vu 1\u{2044}2"
+    );
+
+    let utf16 = JsString::from("e\u{301}\u{1e9b}\u{323}");
+    assert!(!utf16.is_normalized(NormalizationForm::Nfc));
+    assert!(!utf16.is_normalized(NormalizationForm::Nfd));
+    let nfd = utf16.normalize(NormalizationForm::Nfd);
+    assert_eq!(nfd, "e\u{301}\u{17f}\u{323}\u{307}");
+    assert!(nfd.is_normalized(NormalizationForm::Nfd));
+    assert_eq!(nfd.normalize(NormalizationForm::Nfd).refcount(), Some(2));
+    assert_eq!(
+        utf16.normalize(NormalizationForm::Nfc),
+        "\u{e9}\u{1e9b}\u{323}"
+    );
+    assert_eq!(utf16.normalize(NormalizationForm::Nfkc), "\u{e9}\u{1e69}");
+
+    assert_eq!(
+        NormalizationForm::from_name(JsStr::latin1(b"NFKD")),
+        Some(NormalizationForm::Nfkd)
+    );
+    assert_eq!(NormalizationForm::from_name(JsStr::latin1(b"nfc")), None);
+}
+
+#[test]
+fn to_number() {
+    let cases: &[(JsString, f64)] = &[
+        (JsString::from(""), 0.0),
+        (JsString::from("42"), 42.0),
+        (JsString::from("-42"), -42.0),
+        (JsString::from("007"), 7.0),
+        (JsString::from("999999999"), 999_999_999.0),
+        (JsString::from("12345678901"), 12_345_678_901.0),
+        (JsString::from("  1.5e3\n"), 1500.0),
+        (JsString::from("+8"), 8.0),
+        (JsString::from("0x1F"), 31.0),
+        (JsString::from("-Infinity"), f64::NEG_INFINITY),
+        (JsString::from(JsStr::latin1(b"\xA012\xA0")), 12.0),
+        (JsString::from("\u{2003}12"), 12.0),
+        (JsString::from("12\u{3b1}"), f64::NAN),
+        (JsString::from("-"), f64::NAN),
+        (JsString::from("1_000"), f64::NAN),
+        (JsString::from("inf"), f64::NAN),
+    ];
+    for (string, expected) in cases {
+        let number = string.to_number();
+        assert!(
+            number.to_bits() == expected.to_bits() || (number.is_nan() && expected.is_nan()),
+            "{string:?} was converted to {number}, expected {expected}"
+        );
+    }
+
+    assert_eq!(
+        JsString::from("-0").to_number().to_bits(),
+        (-0.0f64).to_bits()
+    );
+}
+
+#[test]
+fn small_integer_strings() {
+    assert_eq!(JsString::from(0), "0");
+    assert_eq!(JsString::from(255u8), "255");
+    assert_eq!(JsString::from(256), "256");
+    assert_eq!(JsString::from(-1), "-1");
+    assert_eq!(JsString::from(u128::MAX), u128::MAX.to_string().as_str());
+
+    // The strings of small integers are cached.
+    let a = JsString::from(42usize);
+    let b = JsString::from(42i64);
+    assert_eq!(a.ptr.addr(), b.ptr.addr());
+}
+
+#[test]
+fn lossy_and_strict_conversions() {
+    let ascii = JsString::from("hello");
+    assert!(matches!(ascii.to_string_lossy(), Cow::Borrowed("hello")));
+    assert_eq!(ascii.to_std_string_strict().as_deref(), Ok("hello"));
+
+    let latin1 = JsString::from(JsStr::latin1(b"caf\xE9"));
+    assert_eq!(latin1.to_string_lossy(), "café");
+    assert_eq!(latin1.to_std_string_strict().as_deref(), Ok("café"));
+
+    let pair = JsString::from(&[0x61, 0xD83D, 0xDE00][..]);
+    assert_eq!(pair.to_string_lossy(), "a\u{1F600}");
+    assert_eq!(pair.to_std_string_strict().as_deref(), Ok("a\u{1F600}"));
+
+    let unpaired = JsString::from(&[0xD83D, 0xDE00, 0x62, 0xDC00, 0xD800][..]);
+    assert_eq!(unpaired.to_string_lossy(), "\u{1F600}b\u{FFFD}\u{FFFD}");
+    let error = unpaired.to_std_string_strict().unwrap_err();
+    assert_eq!(error.index(), 3);
+    assert_eq!(error.surrogate(), 0xDC00);
+}
+
+#[test]
+fn wtf8_round_trip() {
+    let strings = [
+        JsString::from(""),
+        JsString::from("hello"),
+        JsString::from(JsStr::latin1(b"caf\xE9")),
+        JsString::from("\u{1F600}\u{10FFFF}"),
+        JsString::from(&[0xD800][..]),
+        JsString::from(&[0xDFFF, 0xD800, 0x61][..]),
+        JsString::from(&[0xD83D, 0xD83D, 0xDE00][..]),
+    ];
+    for string in strings {
+        let bytes = string.to_wtf8();
+        assert_eq!(
+            std::str::from_utf8(&bytes).is_ok(),
+            string.to_std_string_strict().is_ok()
+        );
+        assert_eq!(JsString::from_wtf8(&bytes), Ok(string));
+    }
+
+    assert_eq!(JsString::from(&[0xD800][..]).to_wtf8(), b"\xED\xA0\x80");
+    assert_eq!(
+        JsString::from("\u{1F600}").to_wtf8(),
+        "\u{1F600}".as_bytes()
+    );
+}
+
+#[test]
+fn invalid_wtf8() {
+    let cases: [(&[u8], usize); 7] = [
+        (b"a\x80", 1),
+        (b"\xC0\x80", 0),
+        (b"ab\xE0\x80\x80", 2),
+        (b"\xF4\x90\x80\x80", 0),
+        (b"\xED\xA0", 0),
+        (b"\xF8", 0),
+        // A surrogate pair encoded as two surrogates.
+        (b"\xED\xA0\xBD\xED\xB8\x80", 3),
+    ];
+    for (bytes, index) in cases {
+        let error = JsString::from_wtf8(bytes).unwrap_err();
+        assert_eq!(error.index(), index, "{bytes:?}");
+    }
+}
+
+#[test]
+fn well_formed() {
+    assert!(JsString::from("").is_well_formed());
+    assert!(JsString::from(JsStr::latin1(b"caf\xE9")).is_well_formed());
+    assert!(JsString::from("a\u{1F600}b").is_well_formed());
+
+    let cases: [&[u16]; 5] = [
+        &[0xD800],
+        &[0xDC00, 0xD800],
+        &[0x61, 0xD83D, 0xD83D, 0xDE00],
+        &[0x61, 0xDE00],
+        &[0xD83D, 0xDE00, 0xD83D],
+    ];
+    for units in cases {
+        let string = JsString::from(units);
+        assert!(!string.as_str().is_well_formed(), "{units:X?}");
+        assert!(!string.is_well_formed(), "{units:X?}");
+        // The cached result is returned the second time.
+        assert!(!string.is_well_formed(), "{units:X?}");
+
+        let well_formed = string.to_well_formed();
+        assert!(well_formed.is_well_formed());
+        assert_eq!(well_formed.len(), string.len());
+        assert_eq!(
+            well_formed.to_std_string().as_deref(),
+            Ok(string.to_std_string_lossy().as_str())
+        );
+    }
+
+    // Caching doesn't change the string.
+    let string = JsString::from(&[0x61, 0xD800, 0x62][..]);
+    assert!(!string.is_well_formed());
+    assert_eq!(string.len(), 3);
+    assert_eq!(string, JsString::from(&[0x61, 0xD800, 0x62][..]));
+    assert_eq!(string.to_well_formed(), JsString::from("a\u{FFFD}b"));
+}
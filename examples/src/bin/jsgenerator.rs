@@ -0,0 +1,59 @@
+// This example shows how to drive a Javascript generator from Rust code.
+use boa_engine::{
+    js_string,
+    object::builtins::{GeneratorStep, JsGenerator},
+    Context, JsError, JsValue, Source,
+};
+
+fn main() -> Result<(), JsError> {
+    // New `Context` for a new Javascript executor.
+    let context = &mut Context::default();
+
+    let generator = context.eval(Source::from_bytes(
+        r#"
+        function* patrol() {
+            let steps = 0;
+            try {
+                while (true) {
+                    const command = yield steps;
+                    if (command === "run") {
+                        steps += 2;
+                    } else {
+                        steps += 1;
+                    }
+                }
+            } catch (e) {
+                return `stopped after ${steps} steps: ${e}`;
+            }
+        }
+
+        patrol()
+        "#,
+    ))?;
+    let generator: JsGenerator = generator.try_js_into(context)?;
+
+    // The first resume runs the generator up to the first `yield`.
+    let step = generator.resume(JsValue::undefined(), context)?;
+    assert!(matches!(step, GeneratorStep::Yield(ref v) if *v == JsValue::new(0)));
+
+    // Each following resume sends a value back into the suspended `yield`.
+    generator.resume(js_string!("walk"), context)?;
+    let step = generator.resume(js_string!("run"), context)?;
+    assert_eq!(step.value(), &JsValue::new(3));
+
+    // Errors can be injected into the generator, which may catch them.
+    let step = generator.resume_throw(JsError::from_opaque(js_string!("tired").into()), context)?;
+    assert!(step.is_return());
+    assert_eq!(
+        step.value(),
+        &JsValue::from(js_string!("stopped after 3 steps: tired"))
+    );
+    assert!(generator.is_completed());
+
+    // Returning from a finished generator completes with the given value.
+    let step = generator.resume_return(42, context)?;
+    assert!(step.is_return());
+    assert_eq!(step.value(), &JsValue::new(42));
+
+    Ok(())
+}
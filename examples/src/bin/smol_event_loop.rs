@@ -52,7 +52,7 @@ impl Queue {
     }
 
     fn drain_timeout_jobs(&self, context: &mut Context) {
-        let now = context.clock().now();
+        let now = context.clock().monotonic_now();
 
         let mut timeouts_borrow = self.timeout_jobs.borrow_mut();
         // `split_off` returns the jobs after (or equal to) the key. So we need to add 1ms to
@@ -88,7 +88,7 @@ impl JobExecutor for Queue {
             Job::PromiseJob(job) => self.promise_jobs.borrow_mut().push_back(job),
             Job::AsyncJob(job) => self.async_jobs.borrow_mut().push_back(job),
             Job::TimeoutJob(t) => {
-                let now = context.clock().now();
+                let now = context.clock().monotonic_now();
                 self.timeout_jobs.borrow_mut().insert(now + t.timeout(), t);
             }
             _ => panic!("unsupported job type"),
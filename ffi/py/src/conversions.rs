@@ -0,0 +1,266 @@
+//! Conversions between Python objects and [`JsValue`]s.
+//!
+//! The mapping is:
+//!
+//! | Python                  | JavaScript                  |
+//! |-------------------------|-----------------------------|
+//! | `None`                  | `null` (`undefined` → None) |
+//! | `bool`                  | `boolean`                   |
+//! | `int`                   | `number` (or `bigint`)      |
+//! | `float`                 | `number`                    |
+//! | `str`                   | `string`                    |
+//! | `list` / `tuple`        | `Array`                     |
+//! | `dict` (string keys)    | ordinary `Object`           |
+//! | `datetime.datetime`     | `Date`                      |
+//! | callable                | `Function`                  |
+
+use std::collections::HashSet;
+
+use boa_engine::{
+    js_string,
+    native_function::NativeFunction,
+    object::{
+        builtins::{JsArray, JsDate, JsFunction},
+        JsObject,
+    },
+    property::PropertyKey,
+    Context, JsBigInt, JsError, JsNativeError, JsResult, JsValue, JsVariant,
+};
+use pyo3::{
+    exceptions::{PyTypeError, PyValueError},
+    prelude::*,
+    types::{PyBool, PyDict, PyFloat, PyInt, PyList, PyString, PyTuple},
+};
+
+use crate::{JsException, PyJsFunction};
+
+/// Converts a [`JsError`] into a Python `pyboa.JsException`.
+pub(crate) fn js_error_to_py(err: &JsError) -> PyErr {
+    JsException::new_err(err.to_string())
+}
+
+/// Converts a Python exception into a JavaScript `Error`.
+fn py_error_to_js(err: &PyErr) -> JsError {
+    JsNativeError::error().with_message(err.to_string()).into()
+}
+
+/// Converts a Python object into a [`JsValue`].
+pub(crate) fn py_to_js(object: &Bound<'_, PyAny>, context: &mut Context) -> PyResult<JsValue> {
+    if object.is_none() {
+        return Ok(JsValue::null());
+    }
+
+    // `bool` is a subclass of `int`, so it must be checked first.
+    if let Ok(boolean) = object.downcast::<PyBool>() {
+        return Ok(JsValue::new(boolean.is_true()));
+    }
+
+    if let Ok(int) = object.downcast::<PyInt>() {
+        if let Ok(int) = int.extract::<i32>() {
+            return Ok(JsValue::new(int));
+        }
+        if let Ok(int) = int.extract::<i64>() {
+            if int.unsigned_abs() <= (1 << 53) {
+                return Ok(JsValue::new(int as f64));
+            }
+        }
+        // Integers outside of the safe range are kept lossless as a `BigInt`.
+        let repr = int.str()?;
+        let bigint = JsBigInt::from_string(repr.to_str()?)
+            .ok_or_else(|| PyValueError::new_err("could not convert int to a JS BigInt"))?;
+        return Ok(JsValue::new(bigint));
+    }
+
+    if let Ok(float) = object.downcast::<PyFloat>() {
+        return Ok(JsValue::new(float.value()));
+    }
+
+    if let Ok(string) = object.downcast::<PyString>() {
+        return Ok(JsValue::new(js_string!(string.to_str()?)));
+    }
+
+    if let Ok(function) = object.downcast::<PyJsFunction>() {
+        return Ok(function.borrow().inner.clone().into());
+    }
+
+    let py = object.py();
+    let datetime = py.import("datetime")?.getattr("datetime")?;
+    if object.is_instance(&datetime)? {
+        let timestamp: f64 = object.call_method0("timestamp")?.extract()?;
+        let date = JsDate::new(context);
+        date.set_time(timestamp * 1000.0, context)
+            .map_err(|e| js_error_to_py(&e))?;
+        return Ok(date.into());
+    }
+
+    if let Ok(list) = object.downcast::<PyList>() {
+        let values = list
+            .iter()
+            .map(|item| py_to_js(&item, context))
+            .collect::<PyResult<Vec<_>>>()?;
+        return Ok(JsArray::from_iter(values, context).into());
+    }
+
+    if let Ok(tuple) = object.downcast::<PyTuple>() {
+        let values = tuple
+            .iter()
+            .map(|item| py_to_js(&item, context))
+            .collect::<PyResult<Vec<_>>>()?;
+        return Ok(JsArray::from_iter(values, context).into());
+    }
+
+    if let Ok(dict) = object.downcast::<PyDict>() {
+        let js_object = JsObject::with_object_proto(context.intrinsics());
+        for (key, value) in dict.iter() {
+            let key = key
+                .downcast::<PyString>()
+                .map_err(|_| PyTypeError::new_err("only dicts with `str` keys can be converted"))?
+                .to_str()?
+                .to_owned();
+            let value = py_to_js(&value, context)?;
+            js_object
+                .create_data_property_or_throw(js_string!(key), value, context)
+                .map_err(|e| js_error_to_py(&e))?;
+        }
+        return Ok(js_object.into());
+    }
+
+    if object.is_callable() {
+        let function = py_callable_to_native(object.clone().unbind());
+        return Ok(function.to_js_function(context.realm()).into());
+    }
+
+    Err(PyTypeError::new_err(format!(
+        "cannot convert object of type `{}` to a JS value",
+        object.get_type().name()?
+    )))
+}
+
+/// Wraps a Python callable into a [`NativeFunction`].
+///
+/// The arguments of the call are converted into Python objects, and the return value of the
+/// callable is converted back into a [`JsValue`]. Python exceptions are rethrown as JS `Error`s.
+pub(crate) fn py_callable_to_native(callable: Py<PyAny>) -> NativeFunction {
+    // SAFETY: The closure only captures a Python object, which doesn't contain any
+    // garbage collected value from the engine.
+    unsafe {
+        NativeFunction::from_closure(move |_this, args, context| {
+            Python::with_gil(|py| {
+                let args = args
+                    .iter()
+                    .map(|arg| js_to_py(arg, py, context))
+                    .collect::<JsResult<Vec<_>>>()?;
+                let args = PyTuple::new(py, args).map_err(|e| py_error_to_js(&e))?;
+                let result = callable
+                    .bind(py)
+                    .call1(args)
+                    .map_err(|e| py_error_to_js(&e))?;
+                py_to_js(&result, context).map_err(|e| py_error_to_js(&e))
+            })
+        })
+    }
+}
+
+/// Converts a [`JsValue`] into a Python object.
+///
+/// Functions are returned as opaque `pyboa.JsFunction` handles that can be called through
+/// `Context.call`.
+pub(crate) fn js_to_py(
+    value: &JsValue,
+    py: Python<'_>,
+    context: &mut Context,
+) -> JsResult<PyObject> {
+    let mut seen = HashSet::new();
+    js_to_py_inner(value, py, context, &mut seen)
+}
+
+fn js_to_py_inner(
+    value: &JsValue,
+    py: Python<'_>,
+    context: &mut Context,
+    seen: &mut HashSet<JsObject>,
+) -> JsResult<PyObject> {
+    let py_err = |e: PyErr| -> JsError { py_error_to_js(&e) };
+
+    let object = match value.variant() {
+        JsVariant::Null | JsVariant::Undefined => return Ok(py.None()),
+        JsVariant::Boolean(b) => return Ok(PyBool::new(py, b).to_owned().into_any().unbind()),
+        JsVariant::Integer32(i) => return Ok(PyInt::new(py, i).into_any().unbind()),
+        JsVariant::Float64(f) => return Ok(PyFloat::new(py, f).into_any().unbind()),
+        JsVariant::String(s) => {
+            return Ok(PyString::new(py, &s.to_std_string_escaped())
+                .into_any()
+                .unbind())
+        }
+        JsVariant::BigInt(bigint) => {
+            let int = py
+                .get_type::<PyInt>()
+                .call1((bigint.to_string(),))
+                .map_err(py_err)?;
+            return Ok(int.unbind());
+        }
+        JsVariant::Symbol(_) => {
+            return Err(JsNativeError::typ()
+                .with_message("cannot convert a Symbol to a Python object")
+                .into())
+        }
+        JsVariant::Object(object) => object,
+    };
+
+    if let Ok(date) = JsDate::from_object(object.clone()) {
+        let time = date.get_time(context)?.to_number(context)?;
+        if time.is_nan() {
+            return Err(JsNativeError::range()
+                .with_message("cannot convert an invalid Date to a Python datetime")
+                .into());
+        }
+        let datetime = py.import("datetime").map_err(py_err)?;
+        let utc = datetime
+            .getattr("timezone")
+            .and_then(|tz| tz.getattr("utc"))
+            .map_err(py_err)?;
+        let value = datetime
+            .getattr("datetime")
+            .and_then(|dt| dt.call_method1("fromtimestamp", (time / 1000.0, utc)))
+            .map_err(py_err)?;
+        return Ok(value.unbind());
+    }
+
+    if let Some(function) = JsFunction::from_object(object.clone()) {
+        let handle = Py::new(py, PyJsFunction { inner: function }).map_err(py_err)?;
+        return Ok(handle.into_any());
+    }
+
+    if !seen.insert(object.clone()) {
+        return Err(JsNativeError::typ()
+            .with_message("cyclic object value")
+            .into());
+    }
+
+    let result = if object.is_array() {
+        let array = JsArray::from_object(object.clone())?;
+        let list = PyList::empty(py);
+        for index in 0..array.length(context)? {
+            let item = array.get(index, context)?;
+            let item = js_to_py_inner(&item, py, context, seen)?;
+            list.append(item).map_err(py_err)?;
+        }
+        list.into_any().unbind()
+    } else {
+        let dict = PyDict::new(py);
+        for key in object.own_property_keys(context)? {
+            let name = match &key {
+                PropertyKey::String(name) => name.to_std_string_escaped(),
+                PropertyKey::Index(index) => index.get().to_string(),
+                PropertyKey::Symbol(_) => continue,
+            };
+            let item = object.get(key, context)?;
+            let item = js_to_py_inner(&item, py, context, seen)?;
+            dict.set_item(name, item).map_err(py_err)?;
+        }
+        dict.into_any().unbind()
+    };
+
+    seen.remove(object);
+    Ok(result)
+}
@@ -0,0 +1,169 @@
+//! Python bindings for the Boa JavaScript engine, built on top of [`boa_engine`]'s public API.
+//!
+//! The extension module is named `pyboa` and can be built with [maturin]:
+//!
+//! ```text
+//! cd ffi/py
+//! maturin develop
+//! ```
+//!
+//! ```python
+//! import pyboa
+//!
+//! ctx = pyboa.Context()
+//! ctx.register_callable("add", lambda a, b: a + b)
+//! assert ctx.eval("add(1, 2)") == 3
+//! assert ctx.eval("({ a: [1, 'two'] })") == {"a": [1, "two"]}
+//! ```
+//!
+//! The crate is not a member of the Boa workspace, since building it needs a Python interpreter.
+//! Its tests, which run Python code against the module, can be run from this directory with
+//! `cargo test`.
+//!
+//! [maturin]: https://www.maturin.rs
+#![cfg_attr(not(test), forbid(clippy::unwrap_used))]
+#![allow(unused_crate_dependencies)]
+
+mod conversions;
+
+#[cfg(test)]
+mod tests;
+
+use boa_engine::{
+    js_string, object::builtins::JsFunction, property::Attribute, Context, JsValue, Source,
+};
+use pyo3::{create_exception, exceptions::PyException, prelude::*, types::PyTuple};
+
+use crate::conversions::{js_error_to_py, js_to_py, py_callable_to_native, py_to_js};
+
+create_exception!(
+    pyboa,
+    JsException,
+    PyException,
+    "Raised when the evaluation of JavaScript code throws."
+);
+
+/// An ECMAScript execution context.
+///
+/// Contexts are not thread safe, and must be used from the thread that created them.
+#[pyclass(unsendable, module = "pyboa", name = "Context")]
+#[derive(Debug)]
+pub struct PyContext {
+    inner: Context,
+}
+
+#[pymethods]
+impl PyContext {
+    /// Creates a new context with the default global bindings.
+    #[new]
+    fn new() -> PyResult<Self> {
+        Context::builder()
+            .build()
+            .map(|inner| Self { inner })
+            .map_err(|e| js_error_to_py(&e))
+    }
+
+    /// Evaluates `source` as a script, returning the completion value converted to Python.
+    ///
+    /// Pending promise jobs are run after the evaluation.
+    fn eval(&mut self, py: Python<'_>, source: &str) -> PyResult<PyObject> {
+        let context = &mut self.inner;
+        let result = context
+            .eval(Source::from_bytes(source))
+            .and_then(|value| {
+                context.run_jobs()?;
+                Ok(value)
+            })
+            .map_err(|e| js_error_to_py(&e))?;
+        js_to_py(&result, py, context).map_err(|e| js_error_to_py(&e))
+    }
+
+    /// Defines a writable, enumerable and configurable global property named `name`.
+    fn set_global(&mut self, name: &str, value: &Bound<'_, PyAny>) -> PyResult<()> {
+        let context = &mut self.inner;
+        let value = py_to_js(value, context)?;
+        context
+            .register_global_property(js_string!(name), value, Attribute::all())
+            .map_err(|e| js_error_to_py(&e))
+    }
+
+    /// Gets the global property named `name`, converted to Python.
+    fn get_global(&mut self, py: Python<'_>, name: &str) -> PyResult<PyObject> {
+        let context = &mut self.inner;
+        let value = context
+            .global_object()
+            .get(js_string!(name), context)
+            .map_err(|e| js_error_to_py(&e))?;
+        js_to_py(&value, py, context).map_err(|e| js_error_to_py(&e))
+    }
+
+    /// Registers the Python `callable` as a global function named `name`.
+    ///
+    /// Arguments are converted to Python objects before calling `callable`, and its return value is
+    /// converted back to a JS value. Python exceptions are thrown as JS `Error`s.
+    #[pyo3(signature = (name, callable, length = 0))]
+    fn register_callable(
+        &mut self,
+        name: &str,
+        callable: &Bound<'_, PyAny>,
+        length: usize,
+    ) -> PyResult<()> {
+        if !callable.is_callable() {
+            return Err(pyo3::exceptions::PyTypeError::new_err(
+                "`callable` must be callable",
+            ));
+        }
+        let function = py_callable_to_native(callable.clone().unbind());
+        self.inner
+            .register_global_builtin_callable(js_string!(name), length, function)
+            .map_err(|e| js_error_to_py(&e))
+    }
+
+    /// Calls the JS `function` with `args`, using `undefined` as the `this` value.
+    #[pyo3(signature = (function, *args))]
+    fn call(
+        &mut self,
+        py: Python<'_>,
+        function: PyRef<'_, PyJsFunction>,
+        args: &Bound<'_, PyTuple>,
+    ) -> PyResult<PyObject> {
+        let context = &mut self.inner;
+        let args = args
+            .iter()
+            .map(|arg| py_to_js(&arg, context))
+            .collect::<PyResult<Vec<_>>>()?;
+        let result = function
+            .inner
+            .call(&JsValue::undefined(), &args, context)
+            .map_err(|e| js_error_to_py(&e))?;
+        js_to_py(&result, py, context).map_err(|e| js_error_to_py(&e))
+    }
+
+    /// Runs all pending promise jobs.
+    fn run_jobs(&mut self) -> PyResult<()> {
+        self.inner.run_jobs().map_err(|e| js_error_to_py(&e))
+    }
+}
+
+/// An opaque handle to a JS function, obtained from values returned by a `Context`.
+#[pyclass(unsendable, module = "pyboa", name = "JsFunction")]
+#[derive(Debug)]
+pub struct PyJsFunction {
+    pub(crate) inner: JsFunction,
+}
+
+#[pymethods]
+impl PyJsFunction {
+    fn __repr__(&self) -> String {
+        "<pyboa.JsFunction>".to_owned()
+    }
+}
+
+/// The `pyboa` Python module.
+#[pymodule]
+fn pyboa(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyContext>()?;
+    m.add_class::<PyJsFunction>()?;
+    m.add("JsException", m.py().get_type::<JsException>())?;
+    Ok(())
+}
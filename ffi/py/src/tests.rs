@@ -0,0 +1,127 @@
+use std::{ffi::CStr, sync::Once};
+
+use pyo3::Python;
+
+use super::pyboa;
+
+/// Runs the Python `code`, which can import the `pyboa` module, panicking if it raises.
+#[track_caller]
+fn run_python(code: &CStr) {
+    static INIT: Once = Once::new();
+    INIT.call_once(|| {
+        pyo3::append_to_inittab!(pyboa);
+        pyo3::prepare_freethreaded_python();
+    });
+
+    Python::with_gil(|py| {
+        if let Err(err) = py.run(code, None, None) {
+            panic!("{}", err.value(py));
+        }
+    });
+}
+
+#[test]
+fn eval_converts_values() {
+    run_python(
+        c"
+import pyboa
+
+ctx = pyboa.Context()
+assert ctx.eval('1 + 2') == 3
+assert ctx.eval('0.5') == 0.5
+assert ctx.eval('true') is True
+assert ctx.eval('null') is None
+assert ctx.eval('undefined') is None
+assert ctx.eval(\"'a' + 'b'\") == 'ab'
+assert ctx.eval('2n ** 64n') == 2 ** 64
+assert ctx.eval(\"({ a: [1, 'two', null], 0: 'zero' })\") == {'0': 'zero', 'a': [1, 'two', None]}
+",
+    );
+}
+
+#[test]
+fn globals_round_trip() {
+    run_python(
+        c"
+import pyboa
+
+ctx = pyboa.Context()
+ctx.set_global('value', {'list': [1, 2.5, 'three'], 'tuple': (True, None), 'big': 2 ** 60})
+assert ctx.eval('value.list.length + value.tuple.length') == 5
+assert ctx.eval('typeof value.big') == 'bigint'
+assert ctx.get_global('value') == {'list': [1, 2.5, 'three'], 'tuple': [True, None], 'big': 2 ** 60}
+",
+    );
+}
+
+#[test]
+fn dates_convert_to_datetimes() {
+    run_python(
+        c"
+from datetime import datetime, timezone
+import pyboa
+
+ctx = pyboa.Context()
+ctx.set_global('date', datetime(2020, 1, 1, tzinfo=timezone.utc))
+assert ctx.eval('date instanceof Date')
+assert ctx.eval('date.getTime()') == 1577836800000
+assert ctx.eval('new Date(0)') == datetime(1970, 1, 1, tzinfo=timezone.utc)
+",
+    );
+}
+
+#[test]
+fn callables_and_functions() {
+    run_python(
+        c"
+import pyboa
+
+ctx = pyboa.Context()
+ctx.register_callable('add', lambda a, b: a + b, 2)
+assert ctx.eval('add(1, 2)') == 3
+assert ctx.eval('add.length') == 2
+
+ctx.set_global('concat', lambda *parts: ''.join(parts))
+assert ctx.eval(\"concat('a', 'b', 'c')\") == 'abc'
+
+double = ctx.eval('(x) => x * 2')
+assert isinstance(double, pyboa.JsFunction)
+assert ctx.call(double, 21) == 42
+",
+    );
+}
+
+#[test]
+fn exceptions_propagate() {
+    run_python(
+        c"
+import pyboa
+
+ctx = pyboa.Context()
+
+try:
+    ctx.eval(\"throw new TypeError('boom')\")
+    raise AssertionError('expected a JsException')
+except pyboa.JsException as err:
+    assert 'boom' in str(err)
+
+def fail():
+    raise ValueError('from python')
+
+ctx.register_callable('fail', fail)
+assert ctx.eval('try { fail(); } catch (e) { e instanceof Error && e.message; }').endswith('from python')
+
+try:
+    ctx.eval('const cycle = {}; cycle.cycle = cycle; cycle')
+    raise AssertionError('expected a JsException')
+except pyboa.JsException as err:
+    assert 'cyclic' in str(err)
+
+try:
+    ctx.register_callable('nope', 1)
+    raise AssertionError('expected a TypeError')
+except TypeError:
+    pass
+",
+    );
+}
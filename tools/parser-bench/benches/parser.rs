@@ -0,0 +1,60 @@
+#![allow(unused_crate_dependencies, missing_docs)]
+
+//! Benchmarks of Boa's parser against swc's parser, on representative bundles.
+
+use boa_ast::scope::Scope;
+use boa_interner::Interner;
+use boa_parser::{Parser, Source};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use std::hint::black_box;
+use swc_core::{
+    common::{sync::Lrc, FileName, SourceMap},
+    ecma::parser::{lexer::Lexer, Parser as SwcParser, StringInput, Syntax},
+};
+
+/// A webpack bundle of a small application. It is also concatenated with itself to get a bundle of
+/// a realistic size.
+static APP_BUNDLE: &str = include_str!("../bundles/app_bundle.js");
+
+fn parse_boa(code: &str) {
+    let mut parser = Parser::new(Source::from_bytes(code));
+    parser
+        .parse_script(&Scope::new_global(), &mut Interner::default())
+        .expect("the bundle should be a valid script");
+}
+
+fn parse_swc(code: &str) {
+    let source_map: Lrc<SourceMap> = Lrc::default();
+    let file = source_map.new_source_file(FileName::Anon.into(), code.to_owned());
+    let lexer = Lexer::new(
+        Syntax::Es(Default::default()),
+        Default::default(),
+        StringInput::from(&*file),
+        None,
+    );
+    SwcParser::new_from(lexer)
+        .parse_script()
+        .expect("the bundle should be a valid script");
+}
+
+fn bench_bundles(c: &mut Criterion) {
+    let bundles = [
+        ("app_bundle", APP_BUNDLE.to_owned()),
+        ("app_bundle x50", APP_BUNDLE.repeat(50)),
+    ];
+
+    for (name, code) in &bundles {
+        let mut group = c.benchmark_group(format!("Parser ({name})"));
+        group.throughput(Throughput::Bytes(code.len() as u64));
+        group.bench_with_input(BenchmarkId::new("boa", code.len()), code, |b, code| {
+            b.iter(|| parse_boa(black_box(code)));
+        });
+        group.bench_with_input(BenchmarkId::new("swc", code.len()), code, |b, code| {
+            b.iter(|| parse_swc(black_box(code)));
+        });
+        group.finish();
+    }
+}
+
+criterion_group!(benches, bench_bundles);
+criterion_main!(benches);
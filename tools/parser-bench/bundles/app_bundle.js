@@ -0,0 +1,339 @@
+/******/ (() => { // webpackBootstrap
+/******/ 	"use strict";
+/******/ 	var __webpack_modules__ = ({
+
+/***/ "./src/utils/format.js":
+/*!*****************************!*\
+  !*** ./src/utils/format.js ***!
+  \*****************************/
+/***/ ((__unused_webpack_module, __webpack_exports__, __webpack_require__) => {
+
+__webpack_require__.r(__webpack_exports__);
+/* harmony export */ __webpack_require__.d(__webpack_exports__, {
+/* harmony export */   "formatCurrency": () => (/* binding */ formatCurrency),
+/* harmony export */   "formatDate": () => (/* binding */ formatDate),
+/* harmony export */   "slugify": () => (/* binding */ slugify),
+/* harmony export */   "truncate": () => (/* binding */ truncate)
+/* harmony export */ });
+/**
+ * Formats an amount of cents as a currency string.
+ *
+ * @param {number} cents The amount, in cents.
+ * @param {string} [currency] The ISO 4217 currency code.
+ * @returns {string} The formatted amount.
+ */
+const formatCurrency = (cents, currency = "USD") => {
+  const sign = cents < 0 ? "-" : "";
+  const value = Math.abs(cents) / 100;
+  const [whole, fraction = "00"] = value.toFixed(2).split(".");
+  const grouped = whole.replace(/\B(?=(\d{3})+(?!\d))/g, ",");
+  return `${sign}${currency === "USD" ? "$" : currency + " "}${grouped}.${fraction}`;
+};
+
+/**
+ * Formats a date as `YYYY-MM-DD`.
+ *
+ * @param {Date} date The date to format.
+ * @returns {string} The formatted date.
+ */
+const formatDate = (date) => {
+  const pad = (n) => String(n).padStart(2, "0");
+  return `${date.getFullYear()}-${pad(date.getMonth() + 1)}-${pad(date.getDate())}`;
+};
+
+/** Converts a title to a URL friendly slug. */
+const slugify = (text) =>
+  text
+    .toLowerCase()
+    .normalize("NFD")
+    .replace(/[̀-ͯ]/g, "")
+    .replace(/[^a-z0-9]+/g, "-")
+    .replace(/(^-|-$)/g, "");
+
+/** Truncates a string to `length` characters, adding an ellipsis if needed. */
+const truncate = (text, length = 80) =>
+  text.length <= length ? text : `${text.slice(0, length - 1).trimEnd()}…`;
+
+
+/***/ }),
+
+/***/ "./src/core/emitter.js":
+/*!*****************************!*\
+  !*** ./src/core/emitter.js ***!
+  \*****************************/
+/***/ ((__unused_webpack_module, __webpack_exports__, __webpack_require__) => {
+
+__webpack_require__.r(__webpack_exports__);
+/* harmony export */ __webpack_require__.d(__webpack_exports__, {
+/* harmony export */   "Emitter": () => (/* binding */ Emitter)
+/* harmony export */ });
+class Emitter {
+  #listeners = new Map();
+
+  /**
+   * Registers a listener, returning a function that removes it.
+   */
+  on(event, listener) {
+    let listeners = this.#listeners.get(event);
+    if (!listeners) {
+      listeners = new Set();
+      this.#listeners.set(event, listeners);
+    }
+    listeners.add(listener);
+    return () => this.off(event, listener);
+  }
+
+  once(event, listener) {
+    const off = this.on(event, (...args) => {
+      off();
+      listener.apply(this, args);
+    });
+    return off;
+  }
+
+  off(event, listener) {
+    const listeners = this.#listeners.get(event);
+    if (listeners?.delete(listener) && listeners.size === 0) {
+      this.#listeners.delete(event);
+    }
+  }
+
+  emit(event, ...args) {
+    for (const listener of [...(this.#listeners.get(event) ?? [])]) {
+      try {
+        listener.apply(this, args);
+      } catch (error) {
+        // Errors in listeners must not prevent the other listeners from running.
+        setTimeout(() => {
+          throw error;
+        }, 0);
+      }
+    }
+    return this;
+  }
+}
+
+
+/***/ }),
+
+/***/ "./src/core/store.js":
+/*!***************************!*\
+  !*** ./src/core/store.js ***!
+  \***************************/
+/***/ ((__unused_webpack_module, __webpack_exports__, __webpack_require__) => {
+
+__webpack_require__.r(__webpack_exports__);
+/* harmony export */ __webpack_require__.d(__webpack_exports__, {
+/* harmony export */   "createStore": () => (/* binding */ createStore),
+/* harmony export */   "combineReducers": () => (/* binding */ combineReducers)
+/* harmony export */ });
+/* harmony import */ var _emitter__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! ./emitter */ "./src/core/emitter.js");
+
+const INIT = "@@store/INIT";
+
+function createStore(reducer, preloadedState, enhancer) {
+  if (typeof enhancer === "function") {
+    return enhancer(createStore)(reducer, preloadedState);
+  }
+
+  const emitter = new _emitter__WEBPACK_IMPORTED_MODULE_0__.Emitter();
+  let state = preloadedState;
+  let dispatching = false;
+
+  const getState = () => {
+    if (dispatching) {
+      throw new Error("The state cannot be read while a reducer is running.");
+    }
+    return state;
+  };
+
+  const subscribe = (listener) => emitter.on("change", listener);
+
+  const dispatch = (action) => {
+    if (action === null || typeof action !== "object" || Array.isArray(action)) {
+      throw new TypeError(`Actions must be plain objects, got ${typeof action}.`);
+    }
+    if (dispatching) {
+      throw new Error("Reducers may not dispatch actions.");
+    }
+
+    try {
+      dispatching = true;
+      state = reducer(state, action);
+    } finally {
+      dispatching = false;
+    }
+
+    emitter.emit("change", state, action);
+    return action;
+  };
+
+  dispatch({ type: INIT });
+
+  return { dispatch, subscribe, getState };
+}
+
+function combineReducers(reducers) {
+  const keys = Object.keys(reducers).filter((key) => typeof reducers[key] === "function");
+  return (state = {}, action) => {
+    let changed = false;
+    const next = {};
+    for (const key of keys) {
+      const previous = state[key];
+      next[key] = reducers[key](previous, action);
+      changed ||= next[key] !== previous;
+    }
+    return changed || keys.length !== Object.keys(state).length ? next : state;
+  };
+}
+
+
+/***/ }),
+
+/***/ "./src/app.js":
+/*!********************!*\
+  !*** ./src/app.js ***!
+  \********************/
+/***/ ((__unused_webpack_module, __webpack_exports__, __webpack_require__) => {
+
+__webpack_require__.r(__webpack_exports__);
+/* harmony import */ var _core_store__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! ./core/store */ "./src/core/store.js");
+/* harmony import */ var _utils_format__WEBPACK_IMPORTED_MODULE_1__ = __webpack_require__(/*! ./utils/format */ "./src/utils/format.js");
+
+
+const cart = (state = { items: [], coupon: null }, { type, payload }) => {
+  switch (type) {
+    case "cart/add": {
+      const existing = state.items.find(({ id }) => id === payload.id);
+      const items = existing
+        ? state.items.map((item) => (item.id === payload.id ? { ...item, quantity: item.quantity + 1 } : item))
+        : [...state.items, { ...payload, quantity: 1 }];
+      return { ...state, items };
+    }
+    case "cart/remove":
+      return { ...state, items: state.items.filter(({ id }) => id !== payload) };
+    case "cart/coupon":
+      return { ...state, coupon: /^[A-Z]{4}-\d{2}$/.test(payload) ? payload : null };
+    default:
+      return state;
+  }
+};
+
+const total = ({ items, coupon }) => {
+  const subtotal = items.reduce((sum, { price, quantity }) => sum + price * quantity, 0);
+  return coupon ? Math.round(subtotal * 0.9) : subtotal;
+};
+
+const render = (state) => {
+  const rows = state.cart.items.map(
+    ({ id, name, price, quantity }) => `
+      <tr data-id="${id}">
+        <td>${(0,_utils_format__WEBPACK_IMPORTED_MODULE_1__.truncate)(name, 32)}</td>
+        <td>${quantity}</td>
+        <td>${(0,_utils_format__WEBPACK_IMPORTED_MODULE_1__.formatCurrency)(price * quantity)}</td>
+      </tr>`
+  );
+  return `<table>${rows.join("")}</table><p>Total: ${(0,_utils_format__WEBPACK_IMPORTED_MODULE_1__.formatCurrency)(total(state.cart))}</p>`;
+};
+
+async function loadProducts(url, { retries = 3, signal } = {}) {
+  for (let attempt = 1; ; attempt++) {
+    try {
+      const response = await fetch(url, { signal });
+      if (!response.ok) {
+        throw new Error(`Request failed with status ${response.status}`);
+      }
+      return (await response.json()).map(({ slug, ...product }) => ({
+        ...product,
+        slug: slug ?? (0,_utils_format__WEBPACK_IMPORTED_MODULE_1__.slugify)(product.name),
+      }));
+    } catch (error) {
+      if (attempt >= retries || error?.name === "AbortError") {
+        throw error;
+      }
+      await new Promise((resolve) => setTimeout(resolve, 2 ** attempt * 100));
+    }
+  }
+}
+
+const store = (0,_core_store__WEBPACK_IMPORTED_MODULE_0__.createStore)((0,_core_store__WEBPACK_IMPORTED_MODULE_0__.combineReducers)({ cart }));
+store.subscribe((state) => {
+  if (typeof document !== "undefined") {
+    document.querySelector("#cart").innerHTML = render(state);
+  }
+});
+
+if (typeof window !== "undefined") {
+  window.addEventListener("load", async () => {
+    const products = await loadProducts("/api/products");
+    products.slice(0, 3).forEach((product) => store.dispatch({ type: "cart/add", payload: product }));
+  });
+}
+
+
+/***/ })
+
+/******/ 	});
+/************************************************************************/
+/******/ 	// The module cache
+/******/ 	var __webpack_module_cache__ = {};
+/******/
+/******/ 	// The require function
+/******/ 	function __webpack_require__(moduleId) {
+/******/ 		// Check if module is in cache
+/******/ 		var cachedModule = __webpack_module_cache__[moduleId];
+/******/ 		if (cachedModule !== undefined) {
+/******/ 			return cachedModule.exports;
+/******/ 		}
+/******/ 		// Create a new module (and put it into the cache)
+/******/ 		var module = __webpack_module_cache__[moduleId] = {
+/******/ 			// no module.id needed
+/******/ 			// no module.loaded needed
+/******/ 			exports: {}
+/******/ 		};
+/******/
+/******/ 		// Execute the module function
+/******/ 		__webpack_modules__[moduleId](module, module.exports, __webpack_require__);
+/******/
+/******/ 		// Return the exports of the module
+/******/ 		return module.exports;
+/******/ 	}
+/******/
+/************************************************************************/
+/******/ 	/* webpack/runtime/define property getters */
+/******/ 	(() => {
+/******/ 		// define getter functions for harmony exports
+/******/ 		__webpack_require__.d = (exports, definition) => {
+/******/ 			for(var key in definition) {
+/******/ 				if(__webpack_require__.o(definition, key) && !__webpack_require__.o(exports, key)) {
+/******/ 					Object.defineProperty(exports, key, { enumerable: true, get: definition[key] });
+/******/ 				}
+/******/ 			}
+/******/ 		};
+/******/ 	})();
+/******/
+/******/ 	/* webpack/runtime/hasOwnProperty shorthand */
+/******/ 	(() => {
+/******/ 		__webpack_require__.o = (obj, prop) => (Object.prototype.hasOwnProperty.call(obj, prop))
+/******/ 	})();
+/******/
+/******/ 	/* webpack/runtime/make namespace object */
+/******/ 	(() => {
+/******/ 		// define __esModule on exports
+/******/ 		__webpack_require__.r = (exports) => {
+/******/ 			if(typeof Symbol !== 'undefined' && Symbol.toStringTag) {
+/******/ 				Object.defineProperty(exports, Symbol.toStringTag, { value: 'Module' });
+/******/ 			}
+/******/ 			Object.defineProperty(exports, '__esModule', { value: true });
+/******/ 		};
+/******/ 	})();
+/******/
+/************************************************************************/
+/******/
+/******/ 	// startup
+/******/ 	// Load entry module and return exports
+/******/ 	// This entry module can't be inlined because the eval devtool is used.
+/******/ 	var __webpack_exports__ = __webpack_require__("./src/app.js");
+/******/
+/******/ })()
+;
@@ -0,0 +1,4 @@
+//! Benchmarks comparing Boa's parser with other JavaScript parsers.
+//!
+//! The benchmarks are in the `benches` directory, and can be run with
+//! `cargo bench --manifest-path tools/parser-bench/Cargo.toml`.